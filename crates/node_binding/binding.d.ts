@@ -58,7 +58,7 @@ export class JsCompilation {
 export class JsStats {
   getAssets(): JsStatsGetAssets
   getModules(reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean, usedExports: boolean, providedExports: boolean): Array<JsStatsModule>
-  getChunks(chunkModules: boolean, chunksRelations: boolean, reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean, usedExports: boolean, providedExports: boolean): Array<JsStatsChunk>
+  getChunks(chunkModules: boolean, chunksRelations: boolean, chunkOrigins: boolean, reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean, usedExports: boolean, providedExports: boolean): Array<JsStatsChunk>
   getEntrypoints(): Array<JsStatsChunkGroup>
   getNamedChunkGroups(): Array<JsStatsChunkGroup>
   getErrors(): Array<JsStatsError>
@@ -68,8 +68,20 @@ export class JsStats {
 }
 
 export class Rspack {
-  constructor(options: RawOptions, builtinPlugins: Array<BuiltinPlugin>, registerJsTaps: RegisterJsTaps, outputFilesystem: ThreadsafeNodeFS)
+  constructor(options: RawOptions, builtinPlugins: Array<BuiltinPlugin>, registerJsTaps: RegisterJsTaps, outputFilesystem?: ThreadsafeNodeFS | undefined | null)
   setNonSkippableRegisters(kinds: Array<RegisterJsTapKind>): void
+  /**
+   * Write a module which only exists in memory, it can be resolved and read
+   * like a file on the disk.
+   */
+  writeVirtualModule(path: string, content: string | Buffer): void
+  /**
+   * Paths of the files emitted into memory, empty if the assets are emitted
+   * to the output file system.
+   */
+  memoryOutputFiles(): Array<string>
+  /** Read a file emitted into memory, returns `null` if it doesn't exist. */
+  readMemoryOutputFile(path: string): Buffer | null
   /** Build with the given option passed to the constructor */
   build(callback: (err: null | Error) => void): void
   /** Rebuild with the given option passed to the constructor */
@@ -136,10 +148,16 @@ export enum BuiltinPluginName {
   ProvideSharedPlugin = 'ProvideSharedPlugin',
   ConsumeSharedPlugin = 'ConsumeSharedPlugin',
   ModuleFederationRuntimePlugin = 'ModuleFederationRuntimePlugin',
+  ModuleFederationManifestPlugin = 'ModuleFederationManifestPlugin',
+  DllEntryPlugin = 'DllEntryPlugin',
+  DllReferenceAgencyPlugin = 'DllReferenceAgencyPlugin',
+  LibManifestPlugin = 'LibManifestPlugin',
+  FlagAllModulesAsUsedPlugin = 'FlagAllModulesAsUsedPlugin',
   NamedModuleIdsPlugin = 'NamedModuleIdsPlugin',
   DeterministicModuleIdsPlugin = 'DeterministicModuleIdsPlugin',
   NamedChunkIdsPlugin = 'NamedChunkIdsPlugin',
   DeterministicChunkIdsPlugin = 'DeterministicChunkIdsPlugin',
+  RecordIdsPlugin = 'RecordIdsPlugin',
   RealContentHashPlugin = 'RealContentHashPlugin',
   RemoveEmptyChunksPlugin = 'RemoveEmptyChunksPlugin',
   EnsureChunkConditionsPlugin = 'EnsureChunkConditionsPlugin',
@@ -151,6 +169,7 @@ export enum BuiltinPluginName {
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
   JavascriptModulesPlugin = 'JavascriptModulesPlugin',
   AsyncWebAssemblyModulesPlugin = 'AsyncWebAssemblyModulesPlugin',
+  WebAssemblyModulesPlugin = 'WebAssemblyModulesPlugin',
   AssetModulesPlugin = 'AssetModulesPlugin',
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   EvalSourceMapDevToolPlugin = 'EvalSourceMapDevToolPlugin',
@@ -164,18 +183,32 @@ export enum BuiltinPluginName {
   APIPlugin = 'APIPlugin',
   RuntimeChunkPlugin = 'RuntimeChunkPlugin',
   SizeLimitsPlugin = 'SizeLimitsPlugin',
+  LazyCompilationPlugin = 'LazyCompilationPlugin',
   HttpExternalsRspackPlugin = 'HttpExternalsRspackPlugin',
+  NodeModulesExternalsRspackPlugin = 'NodeModulesExternalsRspackPlugin',
   CopyRspackPlugin = 'CopyRspackPlugin',
   HtmlRspackPlugin = 'HtmlRspackPlugin',
   SwcJsMinimizerRspackPlugin = 'SwcJsMinimizerRspackPlugin',
   SwcCssMinimizerRspackPlugin = 'SwcCssMinimizerRspackPlugin',
+  ImageMinimizerRspackPlugin = 'ImageMinimizerRspackPlugin',
+  BundleAnalysisRspackPlugin = 'BundleAnalysisRspackPlugin',
   BundlerInfoRspackPlugin = 'BundlerInfoRspackPlugin',
+  DuplicatePackageCheckerRspackPlugin = 'DuplicatePackageCheckerRspackPlugin',
+  NodePolyfillRspackPlugin = 'NodePolyfillRspackPlugin',
   CssExtractRspackPlugin = 'CssExtractRspackPlugin',
+  CustomModuleTypeRspackPlugin = 'CustomModuleTypeRspackPlugin',
+  CustomRuntimeModuleRspackPlugin = 'CustomRuntimeModuleRspackPlugin',
   JsLoaderRspackPlugin = 'JsLoaderRspackPlugin'
 }
 
 export function cleanupGlobalTrace(): void
 
+/**
+ * Starts or stops recording the time spent in the taps of the Rust hooks,
+ * the JavaScript taps are recorded as the taps calling into JavaScript.
+ */
+export function enableHookTiming(enabled: boolean): void
+
 export interface JsAfterResolveData {
   request: string
   context: string
@@ -223,6 +256,8 @@ export interface JsAssetInfo {
   javascriptModule?: boolean
   /** related object to other assets, keyed by type of relation (only points from parent to child) */
   related: JsAssetInfoRelated
+  /** unstable field for plugins to attach additional information to the asset */
+  extras: Record<string, any>
 }
 
 export interface JsAssetInfoRelated {
@@ -234,6 +269,29 @@ export interface JsBeforeResolveArgs {
   context: string
 }
 
+export interface JsBuildInfo {
+  cacheable: boolean
+  strict: boolean
+  /** Custom fields set by plugins */
+  extras: Record<string, any>
+}
+
+export interface JsBuildMeta {
+  strictHarmonyModule: boolean
+  hasTopLevelAwait: boolean
+  esModule: boolean
+  exportsType: 'unset' | 'default' | 'namespace' | 'flagged' | 'dynamic'
+  defaultObject: 'false' | 'redirect' | 'redirect-warn'
+  moduleArgument: 'module' | '__webpack_module__'
+  exportsArgument: 'exports' | '__webpack_exports__'
+  sideEffectFree?: boolean
+  /**
+   * Whether the module is an async module, only known once the modules are
+   * finished, it's not synced back to the module
+   */
+  isAsync?: boolean
+}
+
 export interface JsBuildTimeExecutionOption {
   publicPath?: string
   baseUri?: string
@@ -298,12 +356,15 @@ export interface JsContextModuleFactoryAfterResolveData {
   resource: string
   context: string
   request: string
+  recursive: boolean
   regExp?: string
 }
 
 export interface JsContextModuleFactoryBeforeResolveData {
   context: string
   request?: string
+  recursive: boolean
+  regExp?: string
 }
 
 export interface JsCreateData {
@@ -334,6 +395,14 @@ export interface JsExecuteModuleResult {
   id: number
 }
 
+export interface JsHookTiming {
+  hook: string
+  tap: string
+  calls: number
+  /** The wall time spent in the tap, in milliseconds. */
+  duration: number
+}
+
 export interface JsLoaderContext {
   /** Content maybe empty in pitching stage */
   content: null | Buffer
@@ -375,6 +444,9 @@ export interface JsLoaderContext {
    */
   diagnosticsExternal: ExternalObject<'Diagnostic[]'>
   _moduleIdentifier: string
+  /** The `buildInfo` and `buildMeta` of the module set by the previous loaders */
+  buildInfo: JsBuildInfo
+  buildMeta: JsBuildMeta
   hot: boolean
 }
 
@@ -393,6 +465,8 @@ export interface JsLoaderResult {
   cacheable: boolean
   /** Used to instruct how rust loaders should execute */
   isPitching: boolean
+  buildInfo?: JsBuildInfo
+  buildMeta?: JsBuildMeta
 }
 
 export interface JsModule {
@@ -402,6 +476,14 @@ export interface JsModule {
   moduleIdentifier: string
   nameForCondition?: string
   rawRequest?: string
+  buildInfo?: JsBuildInfo
+  buildMeta?: JsBuildMeta
+}
+
+/** The build info and build meta modified by the JS plugins */
+export interface JsModuleBuildResult {
+  buildInfo?: JsBuildInfo
+  buildMeta?: JsBuildMeta
 }
 
 export interface JsNormalModuleFactoryCreateModuleArgs {
@@ -412,6 +494,15 @@ export interface JsNormalModuleFactoryCreateModuleArgs {
   matchResource?: string
 }
 
+export interface JsOriginRecord {
+  module: string
+  moduleId: string
+  moduleIdentifier: string
+  moduleName: string
+  loc: string
+  request: string
+}
+
 export interface JsPathData {
   filename?: string
   hash?: string
@@ -480,10 +571,12 @@ export interface JsStatsChunk {
   initial: boolean
   names: Array<string>
   size: number
+  sizes: Array<JsStatsSize>
   modules?: Array<JsStatsModule>
   parents?: Array<string>
   children?: Array<string>
   siblings?: Array<string>
+  origins?: Array<JsOriginRecord>
   childrenByOrder: Record<string, Array<string>>
 }
 
@@ -500,6 +593,7 @@ export interface JsStatsChunkGroupAsset {
 }
 
 export interface JsStatsError {
+  code: string
   message: string
   formatted: string
   moduleIdentifier?: string
@@ -532,6 +626,7 @@ export interface JsStatsModule {
   id?: string
   chunks: Array<string | undefined | null>
   size: number
+  sizes: Array<JsStatsSize>
   issuer?: string
   issuerName?: string
   issuerId?: string
@@ -539,8 +634,10 @@ export interface JsStatsModule {
   nameForCondition?: string
   reasons?: Array<JsStatsModuleReason>
   assets?: Array<string>
+  modules?: Array<JsStatsModule>
   source?: string | Buffer
   profile?: JsStatsModuleProfile
+  depth?: number
   orphan: boolean
   providedExports?: Array<string>
   usedExports?: string | Array<string>
@@ -571,7 +668,13 @@ export interface JsStatsOptimizationBailout {
   inner: string
 }
 
+export interface JsStatsSize {
+  sourceType: string
+  size: number
+}
+
 export interface JsStatsWarning {
+  code: string
   message: string
   formatted: string
   moduleIdentifier?: string
@@ -604,6 +707,7 @@ export interface RawAliasOptionItem {
 export interface RawAssetGeneratorDataUrlFnArgs {
   filename: string
   content: string
+  module: JsModule
 }
 
 export interface RawAssetGeneratorDataUrlOptions {
@@ -613,9 +717,17 @@ export interface RawAssetGeneratorDataUrlOptions {
 
 export interface RawAssetGeneratorOptions {
   emit?: boolean
-  filename?: string
+  filename?: JsFilename
+  outputPath?: string
   publicPath?: string
   dataUrl?: RawAssetGeneratorDataUrlOptions | ((arg: RawAssetGeneratorDataUrlFnArgs) => string)
+  responsive?: RawAssetGeneratorResponsiveOptions
+}
+
+export interface RawAssetGeneratorResponsiveOptions {
+  sizes: Array<number>
+  formats: Array<"png" | "jpeg" | "webp">
+  quality: number
 }
 
 export interface RawAssetInlineGeneratorOptions {
@@ -637,8 +749,10 @@ export interface RawAssetParserOptions {
 
 export interface RawAssetResourceGeneratorOptions {
   emit?: boolean
-  filename?: string
+  filename?: JsFilename
+  outputPath?: string
   publicPath?: string
+  responsive?: RawAssetGeneratorResponsiveOptions
 }
 
 export interface RawBannerContentFnCtx {
@@ -661,6 +775,10 @@ export interface RawBuiltins {
   treeShaking: string
 }
 
+export interface RawBundleAnalysisRspackPluginOptions {
+  filename: string
+}
+
 export interface RawBundlerInfoPluginOptions {
   version: string
   force: boolean | string[]
@@ -702,6 +820,12 @@ export interface RawCacheOptions {
   version: string
 }
 
+export interface RawChunkLoadRetry {
+  times: number
+  delay: number
+  publicPaths: Array<string>
+}
+
 export interface RawChunkOptionNameCtx {
   module: JsModule
 }
@@ -741,6 +865,10 @@ export interface RawContainerReferencePluginOptions {
   enhanced: boolean
 }
 
+export interface RawContextInfo {
+  issuer: string
+}
+
 export interface RawCopyGlobOptions {
   caseSensitiveMatch?: boolean
   dot?: boolean
@@ -810,6 +938,71 @@ export interface RawCssParserOptions {
   namedExports?: boolean
 }
 
+export interface RawCustomModuleTypeFnCtx {
+  source: string
+  /** The source map of `source` as a JSON string */
+  map?: string
+  resource: string
+}
+
+export interface RawCustomModuleTypeResult {
+  code: string
+  /** The source map from `source` to `code` as a JSON string */
+  map?: string
+}
+
+export interface RawCustomModuleTypeRspackPluginOptions {
+  type: string
+  parse?: (ctx: RawCustomModuleTypeFnCtx) => RawCustomModuleTypeResult
+  generate?: (ctx: RawCustomModuleTypeFnCtx) => RawCustomModuleTypeResult
+}
+
+export interface RawCustomRuntimeModuleFnCtx {
+  chunkId?: string
+  chunkName?: string
+}
+
+export interface RawCustomRuntimeModuleRspackPluginOptions {
+  name: string
+  stage: "normal" | "basic" | "attach" | "trigger"
+  chunks?: Array<string>
+  runtimeRequirements: Array<string>
+  generate: (ctx: RawCustomRuntimeModuleFnCtx) => string
+}
+
+export interface RawDefinePluginOptions {
+  define: Record<string, string>
+  runtimeValues: Record<string, RawDefineRuntimeValue>
+}
+
+export interface RawDefineRuntimeValue {
+  get: () => string
+  fileDependencies: Array<string>
+  cacheable: boolean
+}
+
+export interface RawDllEntryPluginOptions {
+  context: string
+  entries: Array<string>
+  name: string
+}
+
+export interface RawDllReferenceAgencyPluginOptions {
+  context?: string
+  name: string
+  extensions: Array<string>
+  scope?: string
+  type: string
+  /** JSON string of the `content` of the dll manifest */
+  content: string
+}
+
+export interface RawDuplicatePackageCheckerRspackPluginOptions {
+  emitError: boolean
+  filename?: string
+  exclude: Array<string>
+}
+
 export interface RawDynamicEntryPluginOptions {
   context: string
   entry: () => Promise<RawEntryDynamicResult[]>
@@ -844,9 +1037,23 @@ export interface RawEvalDevToolModulePluginOptions {
   sourceUrlComment?: string
 }
 
+export interface RawEnvironment {
+  arrowFunction?: boolean
+  bigIntLiteral?: boolean
+  const?: boolean
+  destructuring?: boolean
+  dynamicImport?: boolean
+  forOf?: boolean
+  globalThis?: boolean
+  module?: boolean
+  optionalChaining?: boolean
+  templateLiteral?: boolean
+}
+
 export interface RawExperiments {
   newSplitChunks: boolean
   topLevelAwait: boolean
+  pathIndependent?: boolean
   rspackFuture: RawRspackFuture
 }
 
@@ -860,6 +1067,7 @@ export interface RawExternalItemFnCtx {
   request: string
   context: string
   dependencyType: string
+  contextInfo: RawContextInfo
 }
 
 export interface RawExternalItemFnResult {
@@ -884,6 +1092,7 @@ export interface RawExternalsPresets {
 export interface RawExtractComments {
   banner?: string | boolean
   condition?: string
+  filename?: string
 }
 
 export interface RawFallbackCacheGroupOptions {
@@ -946,6 +1155,20 @@ export interface RawIgnorePluginOptions {
   checkResource?: (resource: string, context: string) => boolean
 }
 
+export interface RawImageMinimizerFormatOptions {
+  preset: "lossless" | "lossy"
+  quality: number
+}
+
+export interface RawImageMinimizerRspackPluginOptions {
+  test?: string | RegExp | (string | RegExp)[]
+  include?: string | RegExp | (string | RegExp)[]
+  exclude?: string | RegExp | (string | RegExp)[]
+  png?: RawImageMinimizerFormatOptions
+  jpeg?: RawImageMinimizerFormatOptions
+  webp?: RawImageMinimizerFormatOptions
+}
+
 export interface RawInfo {
   immutable?: boolean
   minimized?: boolean
@@ -964,6 +1187,32 @@ export interface RawJavascriptParserOptions {
   url: string
   exprContextCritical: boolean
   wrappedContextCritical: boolean
+  importMetaUrl: string
+  importMetaEnv: string
+}
+
+export interface RawJsonParserOptions {
+  exportsDepth?: number
+  /** Returns the value of the json module serialized by `JSON.stringify` */
+  parse?: (source: string) => string
+}
+
+export interface RawLazyCompilationModuleArgs {
+  module: string
+  path: string
+}
+
+export interface RawLazyCompilationModuleInfo {
+  active: boolean
+  data: string
+  client: string
+}
+
+export interface RawLazyCompilationOptions {
+  module: (args: RawLazyCompilationModuleArgs) => RawLazyCompilationModuleInfo
+  entries: boolean
+  imports: boolean
+  test?: RegExp | ((module: JsModule) => boolean)
 }
 
 export interface RawLibraryAuxiliaryComment {
@@ -995,12 +1244,46 @@ export interface RawLibraryOptions {
   amdContainer?: string
 }
 
+export interface RawLibManifestPluginOptions {
+  context?: string
+  entryOnly?: boolean
+  name?: string
+  path: string
+  format?: boolean
+  type?: string
+}
+
 export interface RawLimitChunkCountPluginOptions {
   chunkOverhead?: number
   entryChunkMultiplicator?: number
   maxChunks: number
 }
 
+export interface RawManifestRemoteEntry {
+  name: string
+  path: string
+  type: string
+}
+
+export interface RawManifestRemoteInfo {
+  alias: string
+  name?: string
+  entry?: string
+}
+
+export interface RawModuleConcatenationPluginOptions {
+  maxSize?: number
+  allowCjsLeaves?: boolean
+}
+
+export interface RawModuleFederationManifestPluginOptions {
+  name: string
+  globalName: string
+  fileName: string
+  remoteEntry?: RawManifestRemoteEntry
+  remotes: Array<RawManifestRemoteInfo>
+}
+
 export interface RawModuleFilenameTemplateFnCtx {
   identifier: string
   shortIdentifier: string
@@ -1076,12 +1359,28 @@ export interface RawModuleRuleUses {
   funcUse?: (arg: RawFuncUseCtx) => RawModuleRuleUse[]
 }
 
+export interface RawNodeModulesExternalsRspackPluginOptions {
+  allowlist: (string | RegExp)[]
+  importType: string
+}
+
 export interface RawNodeOption {
   dirname: string
   filename: string
   global: string
 }
 
+export interface RawNodePolyfillGlobals {
+  process: boolean
+  Buffer: boolean
+}
+
+export interface RawNodePolyfillRspackPluginOptions {
+  includeAliases?: Array<string>
+  excludeAliases: Array<string>
+  globals: RawNodePolyfillGlobals
+}
+
 export interface RawOptimizationOptions {
   removeAvailableModules: boolean
   sideEffects: string
@@ -1117,13 +1416,14 @@ export interface RawOutputOptions {
   pathinfo: boolean | "verbose"
   clean: boolean
   publicPath: string
-  assetModuleFilename: string
+  assetModuleFilename: JsFilename
   wasmLoading: string
   enabledWasmLoadingTypes: Array<string>
   webassemblyModuleFilename: string
   filename: JsFilename
   chunkFilename: JsFilename
   crossOriginLoading: RawCrossOriginLoading
+  charset: boolean
   cssFilename: JsFilename
   cssChunkFilename: JsFilename
   hotUpdateMainFilename: string
@@ -1141,8 +1441,10 @@ export interface RawOutputOptions {
   chunkLoading: string
   enabledChunkLoadingTypes?: Array<string>
   trustedTypes?: RawTrustedTypes
+  chunkLoadTimeout: number
+  chunkLoadRetry?: RawChunkLoadRetry
   sourceMapFilename: string
-  hashFunction: string
+  hashFunction: string | ((data: Buffer) => Buffer)
   hashDigest: string
   hashDigestLength: number
   hashSalt?: string
@@ -1151,15 +1453,17 @@ export interface RawOutputOptions {
   workerWasmLoading: string
   workerPublicPath: string
   scriptType: "module" | "text/javascript" | "false"
+  environment: RawEnvironment
 }
 
 export interface RawParserOptions {
-  type: "asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm"
+  type: "asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json"
   asset?: RawAssetParserOptions
   css?: RawCssParserOptions
   cssAuto?: RawCssAutoParserOptions
   cssModule?: RawCssModuleParserOptions
   javascript?: RawJavascriptParserOptions
+  json?: RawJsonParserOptions
 }
 
 export interface RawPathData {
@@ -1183,6 +1487,7 @@ export interface RawPluginImportConfig {
 export interface RawProgressPluginOptions {
   prefix: string
   profile: boolean
+  handler?: (percent: number, msg: string, items: string[]) => void
 }
 
 export interface RawProvideOptions {
@@ -1205,6 +1510,11 @@ export interface RawReactOptions {
   refresh?: boolean
 }
 
+export interface RawRecordIdsPluginOptions {
+  inputPath?: string
+  outputPath?: string
+}
+
 export interface RawRegexMatcher {
   source: string
   flags: string
@@ -1223,6 +1533,7 @@ export interface RawRemoteOptions {
   key: string
   external: Array<string>
   shareScope: string
+  fallback?: string | Record<string, string>
 }
 
 export interface RawResolveOptions {
@@ -1257,6 +1568,7 @@ export interface RawResolveTsconfigOptions {
 
 export interface RawRspackFuture {
   newTreeshaking: boolean
+  inlineEntryModules: boolean
 }
 
 export interface RawRuleSetCondition {
@@ -1345,6 +1657,16 @@ export interface RawStyleConfig {
   bool?: boolean
 }
 
+export interface RawSwcCssMinimizerRspackPluginOptions {
+  test?: string | RegExp | (string | RegExp)[]
+  include?: string | RegExp | (string | RegExp)[]
+  exclude?: string | RegExp | (string | RegExp)[]
+  preserveComments?: string | RegExp | (string | RegExp)[]
+  compress?: boolean
+  discardUnusedKeyframes?: boolean
+  calcPrecision?: number
+}
+
 export interface RawSwcJsMinimizerRspackPluginOptions {
   extractComments?: RawExtractComments
   compress: boolean | string
@@ -1386,18 +1708,19 @@ export enum RegisterJsTapKind {
   CompilationOptimizeModules = 13,
   CompilationAfterOptimizeModules = 14,
   CompilationOptimizeTree = 15,
-  CompilationOptimizeChunkModules = 16,
-  CompilationRuntimeModule = 17,
-  CompilationChunkAsset = 18,
-  CompilationProcessAssets = 19,
-  CompilationAfterProcessAssets = 20,
-  CompilationAfterSeal = 21,
-  NormalModuleFactoryBeforeResolve = 22,
-  NormalModuleFactoryAfterResolve = 23,
-  NormalModuleFactoryCreateModule = 24,
-  NormalModuleFactoryResolveForScheme = 25,
-  ContextModuleFactoryBeforeResolve = 26,
-  ContextModuleFactoryAfterResolve = 27
+  CompilationAfterOptimizeTree = 16,
+  CompilationOptimizeChunkModules = 17,
+  CompilationRuntimeModule = 18,
+  CompilationChunkAsset = 19,
+  CompilationProcessAssets = 20,
+  CompilationAfterProcessAssets = 21,
+  CompilationAfterSeal = 22,
+  NormalModuleFactoryBeforeResolve = 23,
+  NormalModuleFactoryAfterResolve = 24,
+  NormalModuleFactoryCreateModule = 25,
+  NormalModuleFactoryResolveForScheme = 26,
+  ContextModuleFactoryBeforeResolve = 27,
+  ContextModuleFactoryAfterResolve = 28
 }
 
 export interface RegisterJsTaps {
@@ -1409,15 +1732,16 @@ export interface RegisterJsTaps {
   registerCompilerEmitTaps: (stages: Array<number>) => Array<{ function: (() => Promise<void>); stage: number; }>
   registerCompilerAfterEmitTaps: (stages: Array<number>) => Array<{ function: (() => Promise<void>); stage: number; }>
   registerCompilerAssetEmittedTaps: (stages: Array<number>) => Array<{ function: ((arg: JsAssetEmittedArgs) => Promise<void>); stage: number; }>
-  registerCompilationBuildModuleTaps: (stages: Array<number>) => Array<{ function: ((arg: JsModule) => void); stage: number; }>
-  registerCompilationStillValidModuleTaps: (stages: Array<number>) => Array<{ function: ((arg: JsModule) => void); stage: number; }>
-  registerCompilationSucceedModuleTaps: (stages: Array<number>) => Array<{ function: ((arg: JsModule) => void); stage: number; }>
+  registerCompilationBuildModuleTaps: (stages: Array<number>) => Array<{ function: ((arg: JsModule) => JsModuleBuildResult | undefined); stage: number; }>
+  registerCompilationStillValidModuleTaps: (stages: Array<number>) => Array<{ function: ((arg: JsModule) => JsModuleBuildResult | undefined); stage: number; }>
+  registerCompilationSucceedModuleTaps: (stages: Array<number>) => Array<{ function: ((arg: JsModule) => JsModuleBuildResult | undefined); stage: number; }>
   registerCompilationExecuteModuleTaps: (stages: Array<number>) => Array<{ function: ((arg: JsExecuteModuleArg) => void); stage: number; }>
   registerCompilationRuntimeModuleTaps: (stages: Array<number>) => Array<{ function: ((arg: JsRuntimeModuleArg) => JsRuntimeModule | undefined); stage: number; }>
   registerCompilationFinishModulesTaps: (stages: Array<number>) => Array<{ function: ((arg: JsCompilation) => Promise<void>); stage: number; }>
   registerCompilationOptimizeModulesTaps: (stages: Array<number>) => Array<{ function: (() => boolean | undefined); stage: number; }>
   registerCompilationAfterOptimizeModulesTaps: (stages: Array<number>) => Array<{ function: (() => void); stage: number; }>
   registerCompilationOptimizeTreeTaps: (stages: Array<number>) => Array<{ function: (() => Promise<void>); stage: number; }>
+  registerCompilationAfterOptimizeTreeTaps: (stages: Array<number>) => Array<{ function: (() => void); stage: number; }>
   registerCompilationOptimizeChunkModulesTaps: (stages: Array<number>) => Array<{ function: (() => Promise<boolean | undefined>); stage: number; }>
   registerCompilationChunkAssetTaps: (stages: Array<number>) => Array<{ function: ((arg: JsChunkAssetArgs) => void); stage: number; }>
   registerCompilationProcessAssetsTaps: (stages: Array<number>) => Array<{ function: ((arg: JsCompilation) => Promise<void>); stage: number; }>
@@ -1434,6 +1758,8 @@ export interface RegisterJsTaps {
 /** Builtin loader runner */
 export function runBuiltinLoader(builtin: string, options: string | undefined | null, loaderContext: JsLoaderContext): Promise<JsLoaderContext>
 
+export function takeHookTimings(): Array<JsHookTiming>
+
 export interface ThreadsafeNodeFS {
   writeFile: (name: string, content: Buffer) => void
   removeFile: (name: string) => void
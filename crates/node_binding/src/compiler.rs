@@ -1,15 +1,66 @@
 use std::{
   marker::PhantomPinned,
   ops::{Deref, DerefMut},
+  path::Path,
   sync::{
     atomic::{AtomicBool, Ordering},
     Arc,
   },
 };
 
+use futures::future::BoxFuture;
+use rspack_fs::{AsyncWritableFileSystem, MemoryFileSystem};
 use rspack_fs_node::AsyncNodeWritableFileSystem;
 
-type CompilerInner = rspack_core::Compiler<AsyncNodeWritableFileSystem>;
+type CompilerInner = rspack_core::Compiler<OutputFileSystem>;
+
+/// Where the compiler emits the assets, either the output file system passed
+/// from js, or a file system in memory which is queried through the binding.
+pub(crate) enum OutputFileSystem {
+  Node(AsyncNodeWritableFileSystem),
+  Memory(MemoryFileSystem),
+}
+
+impl AsyncWritableFileSystem for OutputFileSystem {
+  fn create_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    match self {
+      Self::Node(fs) => fs.create_dir(dir),
+      Self::Memory(fs) => AsyncWritableFileSystem::create_dir(fs, dir),
+    }
+  }
+
+  fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    match self {
+      Self::Node(fs) => fs.create_dir_all(dir),
+      Self::Memory(fs) => AsyncWritableFileSystem::create_dir_all(fs, dir),
+    }
+  }
+
+  fn write<P: AsRef<Path>, D: AsRef<[u8]>>(
+    &self,
+    file: P,
+    data: D,
+  ) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    match self {
+      Self::Node(fs) => fs.write(file, data),
+      Self::Memory(fs) => AsyncWritableFileSystem::write(fs, file, data),
+    }
+  }
+
+  fn remove_file<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    match self {
+      Self::Node(fs) => fs.remove_file(file),
+      Self::Memory(fs) => fs.remove_file(file),
+    }
+  }
+
+  fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    match self {
+      Self::Node(fs) => fs.remove_dir_all(dir),
+      Self::Memory(fs) => fs.remove_dir_all(dir),
+    }
+  }
+}
 
 /// `Compiler` struct that is `!Unpin`.
 pub(crate) struct Compiler(CompilerInner, PhantomPinned);
@@ -8,11 +8,12 @@ extern crate rspack_allocator;
 use std::pin::Pin;
 use std::sync::Mutex;
 
-use compiler::{Compiler, CompilerState, CompilerStateGuard};
+use compiler::{Compiler, CompilerState, CompilerStateGuard, OutputFileSystem};
 use napi::bindgen_prelude::*;
 use rspack_binding_options::BuiltinPlugin;
 use rspack_core::PluginExt;
 use rspack_error::Diagnostic;
+use rspack_fs::{MemoryFileSystem, ReadableFileSystem};
 use rspack_fs_node::{AsyncNodeWritableFileSystem, ThreadsafeNodeFS};
 
 mod compiler;
@@ -31,6 +32,7 @@ pub struct Rspack {
   js_plugin: JsHooksAdapterPlugin,
   compiler: Pin<Box<Compiler>>,
   state: CompilerState,
+  memory_fs: Option<MemoryFileSystem>,
 }
 
 #[napi]
@@ -41,7 +43,7 @@ impl Rspack {
     options: RawOptions,
     builtin_plugins: Vec<BuiltinPlugin>,
     register_js_taps: RegisterJsTaps,
-    output_filesystem: ThreadsafeNodeFS,
+    output_filesystem: Option<ThreadsafeNodeFS>,
   ) -> Result<Self> {
     tracing::info!("raw_options: {:#?}", &options);
 
@@ -59,17 +61,27 @@ impl Rspack {
 
     tracing::info!("normalized_options: {:#?}", &compiler_options);
 
-    let rspack = rspack_core::Compiler::new(
-      compiler_options,
-      plugins,
-      AsyncNodeWritableFileSystem::new(output_filesystem)
-        .map_err(|e| Error::from_reason(format!("Failed to create writable filesystem: {e}",)))?,
-    );
+    // the assets are emitted into memory if no output file system is passed
+    let (output_filesystem, memory_fs) = match output_filesystem {
+      Some(output_filesystem) => (
+        OutputFileSystem::Node(AsyncNodeWritableFileSystem::new(output_filesystem).map_err(
+          |e| Error::from_reason(format!("Failed to create writable filesystem: {e}",)),
+        )?),
+        None,
+      ),
+      None => {
+        let memory_fs = MemoryFileSystem::default();
+        (OutputFileSystem::Memory(memory_fs.clone()), Some(memory_fs))
+      }
+    };
+
+    let rspack = rspack_core::Compiler::new(compiler_options, plugins, output_filesystem);
 
     Ok(Self {
       compiler: Box::pin(Compiler::from(rspack)),
       state: CompilerState::init(),
       js_plugin,
+      memory_fs,
     })
   }
 
@@ -78,6 +90,40 @@ impl Rspack {
     self.js_plugin.set_non_skippable_registers(kinds)
   }
 
+  /// Write a module which only exists in memory, it can be resolved and read
+  /// like a file on the disk.
+  #[napi]
+  pub fn write_virtual_module(&self, path: String, content: Either<String, Buffer>) {
+    let content: Vec<u8> = match content {
+      Either::A(content) => content.into(),
+      Either::B(content) => content.into(),
+    };
+    self.compiler.virtual_modules.write(path, content);
+  }
+
+  /// Paths of the files emitted into memory, empty if the assets are emitted
+  /// to the output file system.
+  #[napi]
+  pub fn memory_output_files(&self) -> Vec<String> {
+    self
+      .memory_fs
+      .as_ref()
+      .map(|fs| {
+        fs.files()
+          .into_iter()
+          .map(|file| file.to_string_lossy().to_string())
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  /// Read a file emitted into memory, returns `null` if it doesn't exist.
+  #[napi]
+  pub fn read_memory_output_file(&self, path: String) -> Option<Buffer> {
+    let fs = self.memory_fs.as_ref()?;
+    ReadableFileSystem::read(fs, path).ok().map(Buffer::from)
+  }
+
   /// Build with the given option passed to the constructor
   #[napi(ts_args_type = "callback: (err: null | Error) => void")]
   pub fn build(&mut self, env: Env, reference: Reference<Rspack>, f: JsFunction) -> Result<()> {
@@ -238,3 +284,32 @@ pub fn cleanup_global_trace() {
     *state = new_state;
   }
 }
+
+#[napi(object)]
+pub struct JsHookTiming {
+  pub hook: String,
+  pub tap: String,
+  pub calls: u32,
+  /// The wall time spent in the tap, in milliseconds.
+  pub duration: f64,
+}
+
+/// Starts or stops recording the time spent in the taps of the Rust hooks,
+/// the JavaScript taps are recorded as the taps calling into JavaScript.
+#[napi]
+pub fn enable_hook_timing(enabled: bool) {
+  rspack_hook::enable_hook_timing(enabled);
+}
+
+#[napi]
+pub fn take_hook_timings() -> Vec<JsHookTiming> {
+  rspack_hook::take_hook_timings()
+    .into_iter()
+    .map(|timing| JsHookTiming {
+      hook: timing.hook.to_string(),
+      tap: timing.tap.to_string(),
+      calls: timing.calls,
+      duration: timing.duration.as_secs_f64() * 1000.0,
+    })
+    .collect()
+}
@@ -14,33 +14,35 @@ use rspack_binding_values::{
   JsBeforeResolveOutput, JsChunk, JsChunkAssetArgs, JsCompilation,
   JsContextModuleFactoryAfterResolveData, JsContextModuleFactoryAfterResolveResult,
   JsContextModuleFactoryBeforeResolveData, JsContextModuleFactoryBeforeResolveResult, JsCreateData,
-  JsExecuteModuleArg, JsModule, JsNormalModuleFactoryCreateModuleArgs, JsResolveForSchemeArgs,
-  JsResolveForSchemeOutput, JsRuntimeModule, JsRuntimeModuleArg, ToJsCompatSource, ToJsModule,
+  JsExecuteModuleArg, JsModule, JsModuleBuildResult, JsNormalModuleFactoryCreateModuleArgs,
+  JsResolveForSchemeArgs, JsResolveForSchemeOutput, JsRuntimeModule, JsRuntimeModuleArg,
+  ToJsCompatSource, ToJsModule,
 };
 use rspack_core::{
   rspack_sources::SourceExt, AfterResolveData, AfterResolveResult, AssetEmittedInfo,
   BeforeResolveData, BeforeResolveResult, BoxModule, Chunk, ChunkUkey, CodeGenerationResults,
   Compilation, CompilationAfterOptimizeModules, CompilationAfterOptimizeModulesHook,
-  CompilationAfterProcessAssets, CompilationAfterProcessAssetsHook, CompilationAfterSeal,
-  CompilationAfterSealHook, CompilationBuildModule, CompilationBuildModuleHook,
-  CompilationChunkAsset, CompilationChunkAssetHook, CompilationExecuteModule,
-  CompilationExecuteModuleHook, CompilationFinishModules, CompilationFinishModulesHook,
-  CompilationOptimizeChunkModules, CompilationOptimizeChunkModulesHook, CompilationOptimizeModules,
-  CompilationOptimizeModulesHook, CompilationOptimizeTree, CompilationOptimizeTreeHook,
-  CompilationParams, CompilationProcessAssets, CompilationProcessAssetsHook,
-  CompilationRuntimeModule, CompilationRuntimeModuleHook, CompilationStillValidModule,
-  CompilationStillValidModuleHook, CompilationSucceedModule, CompilationSucceedModuleHook,
-  CompilerAfterEmit, CompilerAfterEmitHook, CompilerAssetEmitted, CompilerAssetEmittedHook,
-  CompilerCompilation, CompilerCompilationHook, CompilerEmit, CompilerEmitHook, CompilerFinishMake,
-  CompilerFinishMakeHook, CompilerMake, CompilerMakeHook, CompilerShouldEmit,
-  CompilerShouldEmitHook, CompilerThisCompilation, CompilerThisCompilationHook,
-  ContextModuleFactoryAfterResolve, ContextModuleFactoryAfterResolveHook,
-  ContextModuleFactoryBeforeResolve, ContextModuleFactoryBeforeResolveHook, ExecuteModuleId,
-  ModuleFactoryCreateData, ModuleIdentifier, NormalModuleCreateData,
-  NormalModuleFactoryAfterResolve, NormalModuleFactoryAfterResolveHook,
-  NormalModuleFactoryBeforeResolve, NormalModuleFactoryBeforeResolveHook,
-  NormalModuleFactoryCreateModule, NormalModuleFactoryCreateModuleHook,
-  NormalModuleFactoryResolveForScheme, NormalModuleFactoryResolveForSchemeHook, ResourceData,
+  CompilationAfterOptimizeTree, CompilationAfterOptimizeTreeHook, CompilationAfterProcessAssets,
+  CompilationAfterProcessAssetsHook, CompilationAfterSeal, CompilationAfterSealHook,
+  CompilationBuildModule, CompilationBuildModuleHook, CompilationChunkAsset,
+  CompilationChunkAssetHook, CompilationExecuteModule, CompilationExecuteModuleHook,
+  CompilationFinishModules, CompilationFinishModulesHook, CompilationOptimizeChunkModules,
+  CompilationOptimizeChunkModulesHook, CompilationOptimizeModules, CompilationOptimizeModulesHook,
+  CompilationOptimizeTree, CompilationOptimizeTreeHook, CompilationParams,
+  CompilationProcessAssets, CompilationProcessAssetsHook, CompilationRuntimeModule,
+  CompilationRuntimeModuleHook, CompilationStillValidModule, CompilationStillValidModuleHook,
+  CompilationSucceedModule, CompilationSucceedModuleHook, CompilerAfterEmit, CompilerAfterEmitHook,
+  CompilerAssetEmitted, CompilerAssetEmittedHook, CompilerCompilation, CompilerCompilationHook,
+  CompilerEmit, CompilerEmitHook, CompilerFinishMake, CompilerFinishMakeHook, CompilerMake,
+  CompilerMakeHook, CompilerShouldEmit, CompilerShouldEmitHook, CompilerThisCompilation,
+  CompilerThisCompilationHook, ContextModuleFactoryAfterResolve,
+  ContextModuleFactoryAfterResolveHook, ContextModuleFactoryBeforeResolve,
+  ContextModuleFactoryBeforeResolveHook, ExecuteModuleId, ModuleFactoryCreateData,
+  ModuleIdentifier, NormalModuleCreateData, NormalModuleFactoryAfterResolve,
+  NormalModuleFactoryAfterResolveHook, NormalModuleFactoryBeforeResolve,
+  NormalModuleFactoryBeforeResolveHook, NormalModuleFactoryCreateModule,
+  NormalModuleFactoryCreateModuleHook, NormalModuleFactoryResolveForScheme,
+  NormalModuleFactoryResolveForSchemeHook, ResourceData,
 };
 use rspack_hook::{Hook, Interceptor};
 use rspack_identifier::IdentifierSet;
@@ -299,6 +301,7 @@ pub enum RegisterJsTapKind {
   CompilationOptimizeModules,
   CompilationAfterOptimizeModules,
   CompilationOptimizeTree,
+  CompilationAfterOptimizeTree,
   CompilationOptimizeChunkModules,
   CompilationRuntimeModule,
   CompilationChunkAsset,
@@ -363,17 +366,20 @@ pub struct RegisterJsTaps {
   )]
   pub register_compiler_asset_emitted_taps: RegisterFunction<JsAssetEmittedArgs, Promise<()>>,
   #[napi(
-    ts_type = "(stages: Array<number>) => Array<{ function: ((arg: JsModule) => void); stage: number; }>"
+    ts_type = "(stages: Array<number>) => Array<{ function: ((arg: JsModule) => JsModuleBuildResult | undefined); stage: number; }>"
   )]
-  pub register_compilation_build_module_taps: RegisterFunction<JsModule, ()>,
+  pub register_compilation_build_module_taps:
+    RegisterFunction<JsModule, Option<JsModuleBuildResult>>,
   #[napi(
-    ts_type = "(stages: Array<number>) => Array<{ function: ((arg: JsModule) => void); stage: number; }>"
+    ts_type = "(stages: Array<number>) => Array<{ function: ((arg: JsModule) => JsModuleBuildResult | undefined); stage: number; }>"
   )]
-  pub register_compilation_still_valid_module_taps: RegisterFunction<JsModule, ()>,
+  pub register_compilation_still_valid_module_taps:
+    RegisterFunction<JsModule, Option<JsModuleBuildResult>>,
   #[napi(
-    ts_type = "(stages: Array<number>) => Array<{ function: ((arg: JsModule) => void); stage: number; }>"
+    ts_type = "(stages: Array<number>) => Array<{ function: ((arg: JsModule) => JsModuleBuildResult | undefined); stage: number; }>"
   )]
-  pub register_compilation_succeed_module_taps: RegisterFunction<JsModule, ()>,
+  pub register_compilation_succeed_module_taps:
+    RegisterFunction<JsModule, Option<JsModuleBuildResult>>,
   #[napi(
     ts_type = "(stages: Array<number>) => Array<{ function: ((arg: JsExecuteModuleArg) => void); stage: number; }>"
   )]
@@ -397,6 +403,8 @@ pub struct RegisterJsTaps {
     ts_type = "(stages: Array<number>) => Array<{ function: (() => Promise<void>); stage: number; }>"
   )]
   pub register_compilation_optimize_tree_taps: RegisterFunction<(), Promise<()>>,
+  #[napi(ts_type = "(stages: Array<number>) => Array<{ function: (() => void); stage: number; }>")]
+  pub register_compilation_after_optimize_tree_taps: RegisterFunction<(), ()>,
   #[napi(
     ts_type = "(stages: Array<number>) => Array<{ function: (() => Promise<boolean | undefined>); stage: number; }>"
   )]
@@ -522,7 +530,7 @@ define_register!(
 /* Compilation Hooks */
 define_register!(
   RegisterCompilationBuildModuleTaps,
-  tap = CompilationBuildModuleTap<JsModule, ()> @ CompilationBuildModuleHook,
+  tap = CompilationBuildModuleTap<JsModule, Option<JsModuleBuildResult>> @ CompilationBuildModuleHook,
   cache = true,
   sync = false,
   kind = RegisterJsTapKind::CompilationBuildModule,
@@ -530,7 +538,7 @@ define_register!(
 );
 define_register!(
   RegisterCompilationStillValidModuleTaps,
-  tap = CompilationStillValidModuleTap<JsModule, ()> @ CompilationStillValidModuleHook,
+  tap = CompilationStillValidModuleTap<JsModule, Option<JsModuleBuildResult>> @ CompilationStillValidModuleHook,
   cache = true,
   sync = false,
   kind = RegisterJsTapKind::CompilationStillValidModule,
@@ -538,7 +546,7 @@ define_register!(
 );
 define_register!(
   RegisterCompilationSucceedModuleTaps,
-  tap = CompilationSucceedModuleTap<JsModule, ()> @ CompilationSucceedModuleHook,
+  tap = CompilationSucceedModuleTap<JsModule, Option<JsModuleBuildResult>> @ CompilationSucceedModuleHook,
   cache = true,
   sync = false,
   kind = RegisterJsTapKind::CompilationSucceedModule,
@@ -584,6 +592,14 @@ define_register!(
   kind = RegisterJsTapKind::CompilationOptimizeTree,
   skip = true,
 );
+define_register!(
+  RegisterCompilationAfterOptimizeTreeTaps,
+  tap = CompilationAfterOptimizeTreeTap<(), ()> @ CompilationAfterOptimizeTreeHook,
+  cache = false,
+  sync = false,
+  kind = RegisterJsTapKind::CompilationAfterOptimizeTree,
+  skip = true,
+);
 define_register!(
   RegisterCompilationOptimizeChunkModulesTaps,
   tap = CompilationOptimizeChunkModulesTap<(), Promise<Option<bool>>> @ CompilationOptimizeChunkModulesHook,
@@ -824,10 +840,14 @@ impl CompilerAssetEmitted for CompilerAssetEmittedTap {
 #[async_trait]
 impl CompilationBuildModule for CompilationBuildModuleTap {
   async fn run(&self, module: &mut BoxModule) -> rspack_error::Result<()> {
-    self
+    let result = self
       .function
       .call_with_sync(module.to_js_module().expect("Convert to js_module failed."))
-      .await
+      .await?;
+    if let Some(result) = result {
+      result.apply(module.as_mut())?;
+    }
+    Ok(())
   }
 
   fn stage(&self) -> i32 {
@@ -838,10 +858,14 @@ impl CompilationBuildModule for CompilationBuildModuleTap {
 #[async_trait]
 impl CompilationStillValidModule for CompilationStillValidModuleTap {
   async fn run(&self, module: &mut BoxModule) -> rspack_error::Result<()> {
-    self
+    let result = self
       .function
       .call_with_sync(module.to_js_module().expect("Convert to js_module failed."))
-      .await
+      .await?;
+    if let Some(result) = result {
+      result.apply(module.as_mut())?;
+    }
+    Ok(())
   }
 
   fn stage(&self) -> i32 {
@@ -852,10 +876,14 @@ impl CompilationStillValidModule for CompilationStillValidModuleTap {
 #[async_trait]
 impl CompilationSucceedModule for CompilationSucceedModuleTap {
   async fn run(&self, module: &mut BoxModule) -> rspack_error::Result<()> {
-    self
+    let result = self
       .function
       .call_with_sync(module.to_js_module().expect("Convert to js_module failed."))
-      .await
+      .await?;
+    if let Some(result) = result {
+      result.apply(module.as_mut())?;
+    }
+    Ok(())
   }
 
   fn stage(&self) -> i32 {
@@ -935,6 +963,17 @@ impl CompilationOptimizeTree for CompilationOptimizeTreeTap {
   }
 }
 
+#[async_trait]
+impl CompilationAfterOptimizeTree for CompilationAfterOptimizeTreeTap {
+  async fn run(&self, _compilation: &mut Compilation) -> rspack_error::Result<()> {
+    self.function.call_with_sync(()).await
+  }
+
+  fn stage(&self) -> i32 {
+    self.stage
+  }
+}
+
 #[async_trait]
 impl CompilationOptimizeChunkModules for CompilationOptimizeChunkModulesTap {
   async fn run(&self, _compilation: &mut Compilation) -> rspack_error::Result<Option<bool>> {
@@ -1213,6 +1252,8 @@ impl ContextModuleFactoryBeforeResolve for ContextModuleFactoryBeforeResolveTap
         JsContextModuleFactoryBeforeResolveResult::B(JsContextModuleFactoryBeforeResolveData {
           context: d.context,
           request: d.request,
+          recursive: d.recursive,
+          reg_exp: d.reg_exp.map(|r| r.to_string()),
         })
       }
     };
@@ -1223,6 +1264,11 @@ impl ContextModuleFactoryBeforeResolve for ContextModuleFactoryBeforeResolveTap
           let data = BeforeResolveData {
             context: d.context,
             request: d.request,
+            recursive: d.recursive,
+            reg_exp: match d.reg_exp {
+              Some(r) => Some(parse_reg_exp(&r)?),
+              None => None,
+            },
           };
           Ok(BeforeResolveResult::Data(Box::new(data)))
         }
@@ -1236,6 +1282,16 @@ impl ContextModuleFactoryBeforeResolve for ContextModuleFactoryBeforeResolveTap
   }
 }
 
+/// Parses the `/source/flags` form of `RegExp.prototype.toString`.
+fn parse_reg_exp(reg_exp: &str) -> rspack_error::Result<RspackRegex> {
+  match reg_exp.rfind('/') {
+    Some(end) if end > 0 && reg_exp.starts_with('/') => {
+      RspackRegex::with_flags(&reg_exp[1..end], &reg_exp[end + 1..])
+    }
+    _ => RspackRegex::new(reg_exp),
+  }
+}
+
 #[async_trait]
 impl ContextModuleFactoryAfterResolve for ContextModuleFactoryAfterResolveTap {
   async fn run(&self, result: AfterResolveResult) -> rspack_error::Result<AfterResolveResult> {
@@ -1246,6 +1302,7 @@ impl ContextModuleFactoryAfterResolve for ContextModuleFactoryAfterResolveTap {
           resource: d.resource.to_owned(),
           context: d.context.to_owned(),
           request: d.request.to_owned(),
+          recursive: d.recursive,
           reg_exp: d.reg_exp.clone().map(|r| r.to_string()),
         })
       }
@@ -1257,8 +1314,9 @@ impl ContextModuleFactoryAfterResolve for ContextModuleFactoryAfterResolveTap {
           resource: d.resource,
           context: d.context,
           request: d.request,
+          recursive: d.recursive,
           reg_exp: match d.reg_exp {
-            Some(r) => Some(RspackRegex::new(&r)?),
+            Some(r) => Some(parse_reg_exp(&r)?),
             None => None,
           },
         };
@@ -29,6 +29,7 @@ pub struct JsHooksAdapterPlugin {
   register_compilation_optimize_modules_taps: RegisterCompilationOptimizeModulesTaps,
   register_compilation_after_optimize_modules_taps: RegisterCompilationAfterOptimizeModulesTaps,
   register_compilation_optimize_tree_taps: RegisterCompilationOptimizeTreeTaps,
+  register_compilation_after_optimize_tree_taps: RegisterCompilationAfterOptimizeTreeTaps,
   register_compilation_optimize_chunk_modules_taps: RegisterCompilationOptimizeChunkModulesTaps,
   register_compilation_runtime_module_taps: RegisterCompilationRuntimeModuleTaps,
   register_compilation_chunk_asset_taps: RegisterCompilationChunkAssetTaps,
@@ -147,6 +148,11 @@ impl rspack_core::Plugin for JsHooksAdapterPlugin {
       .compilation_hooks
       .optimize_tree
       .intercept(self.register_compilation_optimize_tree_taps.clone());
+    ctx
+      .context
+      .compilation_hooks
+      .after_optimize_tree
+      .intercept(self.register_compilation_after_optimize_tree_taps.clone());
     ctx
       .context
       .compilation_hooks
@@ -309,6 +315,10 @@ impl JsHooksAdapterPlugin {
         register_js_taps.register_compilation_optimize_tree_taps,
         non_skippable_registers.clone(),
       ),
+      register_compilation_after_optimize_tree_taps: RegisterCompilationAfterOptimizeTreeTaps::new(
+        register_js_taps.register_compilation_after_optimize_tree_taps,
+        non_skippable_registers.clone(),
+      ),
       register_compilation_optimize_chunk_modules_taps:
         RegisterCompilationOptimizeChunkModulesTaps::new(
           register_js_taps.register_compilation_optimize_chunk_modules_taps,
@@ -84,6 +84,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
       },
       new_split_chunks: value.experiments.new_split_chunks,
       top_level_await: value.experiments.top_level_await,
+      path_independent: value.experiments.path_independent.unwrap_or_default(),
       rspack_future: value.experiments.rspack_future.into(),
     };
     let optimization: Optimization = IS_ENABLE_NEW_SPLIT_CHUNKS
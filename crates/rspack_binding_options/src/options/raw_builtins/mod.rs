@@ -1,28 +1,41 @@
 mod raw_banner;
+mod raw_bundle_analysis;
 mod raw_bundle_info;
 mod raw_copy;
 mod raw_css_extract;
+mod raw_custom_module_type;
+mod raw_custom_runtime_module;
+mod raw_define;
+mod raw_dll;
+mod raw_duplicate_package_checker;
 mod raw_html;
 mod raw_ignore;
+mod raw_image_minimizer;
+mod raw_lazy_compilation;
 mod raw_limit_chunk_count;
 mod raw_mf;
+mod raw_module_concatenation;
+mod raw_node_polyfill;
 mod raw_progress;
+mod raw_record_ids;
 mod raw_runtime_chunk;
 mod raw_size_limits;
+mod raw_swc_css_minimizer;
 mod raw_swc_js_minimizer;
 mod raw_to_be_deprecated;
 
 use napi::{bindgen_prelude::FromNapiValue, Env, JsUnknown};
 use napi_derive::napi;
-use rspack_core::{BoxPlugin, Define, DefinePlugin, PluginExt, Provide, ProvidePlugin};
+use rspack_core::{BoxPlugin, DefinePlugin, PluginExt, Provide, ProvidePlugin};
 use rspack_error::Result;
 use rspack_ids::{
   DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedChunkIdsPlugin,
-  NamedModuleIdsPlugin,
+  NamedModuleIdsPlugin, RecordIdsPlugin,
 };
 use rspack_napi::NapiResultExt;
 use rspack_plugin_asset::AssetPlugin;
 use rspack_plugin_banner::BannerPlugin;
+use rspack_plugin_bundle_analysis::BundleAnalysisRspackPlugin;
 use rspack_plugin_copy::{CopyRspackPlugin, CopyRspackPluginOptions};
 use rspack_plugin_css::CssPlugin;
 use rspack_plugin_devtool::{
@@ -30,15 +43,21 @@ use rspack_plugin_devtool::{
   SourceMapDevToolModuleOptionsPluginOptions, SourceMapDevToolPlugin,
   SourceMapDevToolPluginOptions,
 };
+use rspack_plugin_dll::{
+  DllEntryPlugin, DllReferenceAgencyPlugin, FlagAllModulesAsUsedPlugin, LibManifestPlugin,
+};
+use rspack_plugin_duplicate_package_checker::DuplicatePackageCheckerRspackPlugin;
 use rspack_plugin_dynamic_entry::DynamicEntryPlugin;
 use rspack_plugin_ensure_chunk_conditions::EnsureChunkConditionsPlugin;
 use rspack_plugin_entry::EntryPlugin;
 use rspack_plugin_externals::{
   electron_target_plugin, http_externals_rspack_plugin, node_target_plugin, ExternalsPlugin,
+  NodeModulesExternalsPlugin,
 };
 use rspack_plugin_hmr::HotModuleReplacementPlugin;
 use rspack_plugin_html::HtmlRspackPlugin;
 use rspack_plugin_ignore::IgnorePlugin;
+use rspack_plugin_image_minimizer::ImageMinimizerRspackPlugin;
 use rspack_plugin_javascript::{
   api_plugin::APIPlugin, FlagDependencyExportsPlugin, FlagDependencyUsagePlugin,
   InferAsyncModulesPlugin, JsPlugin, MangleExportsPlugin, ModuleConcatenationPlugin,
@@ -49,15 +68,17 @@ use rspack_plugin_library::enable_library_plugin;
 use rspack_plugin_limit_chunk_count::LimitChunkCountPlugin;
 use rspack_plugin_merge_duplicate_chunks::MergeDuplicateChunksPlugin;
 use rspack_plugin_mf::{
-  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ModuleFederationRuntimePlugin,
-  ProvideSharedPlugin, ShareRuntimePlugin,
+  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ModuleFederationManifestPlugin,
+  ModuleFederationRuntimePlugin, ProvideSharedPlugin, ShareRuntimePlugin,
 };
+use rspack_plugin_node_polyfill::NodePolyfillRspackPlugin;
 use rspack_plugin_progress::ProgressPlugin;
 use rspack_plugin_real_content_hash::RealContentHashPlugin;
 use rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin;
 use rspack_plugin_runtime::{
   enable_chunk_loading_plugin, ArrayPushCallbackChunkFormatPlugin, BundlerInfoPlugin,
-  ChunkPrefetchPreloadPlugin, CommonJsChunkFormatPlugin, ModuleChunkFormatPlugin, RuntimePlugin,
+  ChunkPrefetchPreloadPlugin, CommonJsChunkFormatPlugin, LazyCompilationPlugin,
+  ModuleChunkFormatPlugin, RuntimePlugin,
 };
 use rspack_plugin_runtime_chunk::RuntimeChunkPlugin;
 use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin};
@@ -65,29 +86,57 @@ use rspack_plugin_size_limits::SizeLimitsPlugin;
 use rspack_plugin_swc_css_minimizer::SwcCssMinimizerRspackPlugin;
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin;
-use rspack_plugin_wasm::{enable_wasm_loading_plugin, AsyncWasmPlugin};
+use rspack_plugin_wasm::{enable_wasm_loading_plugin, AsyncWasmPlugin, SyncWasmPlugin};
 use rspack_plugin_web_worker_template::web_worker_template_plugin;
 use rspack_plugin_worker::WorkerPlugin;
 
 pub use self::{
-  raw_banner::RawBannerPluginOptions, raw_copy::RawCopyRspackPluginOptions,
-  raw_html::RawHtmlRspackPluginOptions, raw_ignore::RawIgnorePluginOptions,
-  raw_limit_chunk_count::RawLimitChunkCountPluginOptions, raw_mf::RawContainerPluginOptions,
+  raw_banner::RawBannerPluginOptions,
+  raw_copy::RawCopyRspackPluginOptions,
+  raw_custom_module_type::{
+    RawCustomModuleTypeFnCtx, RawCustomModuleTypeResult, RawCustomModuleTypeRspackPluginOptions,
+  },
+  raw_custom_runtime_module::{
+    RawCustomRuntimeModuleFnCtx, RawCustomRuntimeModuleRspackPluginOptions,
+  },
+  raw_html::RawHtmlRspackPluginOptions,
+  raw_ignore::RawIgnorePluginOptions,
+  raw_limit_chunk_count::RawLimitChunkCountPluginOptions,
+  raw_mf::RawContainerPluginOptions,
   raw_progress::RawProgressPluginOptions,
   raw_swc_js_minimizer::RawSwcJsMinimizerRspackPluginOptions,
 };
 use self::{
+  raw_bundle_analysis::RawBundleAnalysisRspackPluginOptions,
   raw_bundle_info::{RawBundlerInfoModeWrapper, RawBundlerInfoPluginOptions},
   raw_css_extract::RawCssExtractPluginOption,
-  raw_mf::{RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions},
+  raw_define::RawDefinePluginOptions,
+  raw_dll::{
+    RawDllEntryPluginOptions, RawDllReferenceAgencyPluginOptions, RawLibManifestPluginOptions,
+  },
+  raw_duplicate_package_checker::RawDuplicatePackageCheckerRspackPluginOptions,
+  raw_image_minimizer::RawImageMinimizerRspackPluginOptions,
+  raw_lazy_compilation::RawLazyCompilationOptions,
+  raw_mf::{
+    RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions,
+    RawModuleFederationManifestPluginOptions, RawProvideOptions,
+  },
+  raw_module_concatenation::RawModuleConcatenationPluginOptions,
+  raw_node_polyfill::RawNodePolyfillRspackPluginOptions,
+  raw_record_ids::RawRecordIdsPluginOptions,
   raw_runtime_chunk::RawRuntimeChunkOptions,
   raw_size_limits::RawSizeLimitsPluginOptions,
+  raw_swc_css_minimizer::RawSwcCssMinimizerRspackPluginOptions,
 };
 use crate::{
-  plugins::{CssExtractRspackAdditionalDataPlugin, JsLoaderResolverPlugin},
+  plugins::{
+    CssExtractRspackAdditionalDataPlugin, CustomModuleTypeRspackPlugin,
+    CustomRuntimeModuleRspackPlugin, JsLoaderResolverPlugin,
+  },
   JsLoaderRunner, RawDynamicEntryPluginOptions, RawEntryPluginOptions,
   RawEvalDevToolModulePluginOptions, RawExternalItemWrapper, RawExternalsPluginOptions,
-  RawHttpExternalsRspackPluginOptions, RawSourceMapDevToolPluginOptions, RawSplitChunksOptions,
+  RawHttpExternalsRspackPluginOptions, RawNodeModulesExternalsRspackPluginOptions,
+  RawSourceMapDevToolPluginOptions, RawSplitChunksOptions,
 };
 
 #[napi(string_enum)]
@@ -123,10 +172,16 @@ pub enum BuiltinPluginName {
   ProvideSharedPlugin,
   ConsumeSharedPlugin,
   ModuleFederationRuntimePlugin,
+  ModuleFederationManifestPlugin,
+  DllEntryPlugin,
+  DllReferenceAgencyPlugin,
+  LibManifestPlugin,
+  FlagAllModulesAsUsedPlugin,
   NamedModuleIdsPlugin,
   DeterministicModuleIdsPlugin,
   NamedChunkIdsPlugin,
   DeterministicChunkIdsPlugin,
+  RecordIdsPlugin,
   RealContentHashPlugin,
   RemoveEmptyChunksPlugin,
   EnsureChunkConditionsPlugin,
@@ -138,6 +193,7 @@ pub enum BuiltinPluginName {
   InferAsyncModulesPlugin,
   JavascriptModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
+  WebAssemblyModulesPlugin,
   AssetModulesPlugin,
   SourceMapDevToolPlugin,
   EvalSourceMapDevToolPlugin,
@@ -151,16 +207,24 @@ pub enum BuiltinPluginName {
   APIPlugin,
   RuntimeChunkPlugin,
   SizeLimitsPlugin,
+  LazyCompilationPlugin,
 
   // rspack specific plugins
   // naming format follow XxxRspackPlugin
   HttpExternalsRspackPlugin,
+  NodeModulesExternalsRspackPlugin,
   CopyRspackPlugin,
   HtmlRspackPlugin,
   SwcJsMinimizerRspackPlugin,
   SwcCssMinimizerRspackPlugin,
+  ImageMinimizerRspackPlugin,
+  BundleAnalysisRspackPlugin,
   BundlerInfoRspackPlugin,
+  DuplicatePackageCheckerRspackPlugin,
+  NodePolyfillRspackPlugin,
   CssExtractRspackPlugin,
+  CustomModuleTypeRspackPlugin,
+  CustomRuntimeModuleRspackPlugin,
 
   // rspack js adapter plugins
   // naming format follow XxxRspackPlugin
@@ -179,7 +243,8 @@ impl BuiltinPlugin {
     match self.name {
       // webpack also have these plugins
       BuiltinPluginName::DefinePlugin => {
-        let plugin = DefinePlugin::new(downcast_into::<Define>(self.options)?).boxed();
+        let plugin =
+          DefinePlugin::new(downcast_into::<RawDefinePluginOptions>(self.options)?.into()).boxed();
         plugins.push(plugin);
       }
       BuiltinPluginName::ProvidePlugin => {
@@ -317,6 +382,28 @@ impl BuiltinPlugin {
       BuiltinPluginName::ModuleFederationRuntimePlugin => {
         plugins.push(ModuleFederationRuntimePlugin::default().boxed())
       }
+      BuiltinPluginName::ModuleFederationManifestPlugin => plugins.push(
+        ModuleFederationManifestPlugin::new(
+          downcast_into::<RawModuleFederationManifestPluginOptions>(self.options)?.into(),
+        )
+        .boxed(),
+      ),
+      BuiltinPluginName::DllEntryPlugin => plugins.push(
+        DllEntryPlugin::new(downcast_into::<RawDllEntryPluginOptions>(self.options)?.into())
+          .boxed(),
+      ),
+      BuiltinPluginName::DllReferenceAgencyPlugin => plugins.push(
+        DllReferenceAgencyPlugin::new(
+          downcast_into::<RawDllReferenceAgencyPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed(),
+      ),
+      BuiltinPluginName::LibManifestPlugin => plugins.push(
+        LibManifestPlugin::new(downcast_into::<RawLibManifestPluginOptions>(self.options)?.into())
+          .boxed(),
+      ),
+      BuiltinPluginName::FlagAllModulesAsUsedPlugin => plugins
+        .push(FlagAllModulesAsUsedPlugin::new(downcast_into::<String>(self.options)?).boxed()),
       BuiltinPluginName::NamedModuleIdsPlugin => {
         plugins.push(NamedModuleIdsPlugin::default().boxed())
       }
@@ -329,6 +416,12 @@ impl BuiltinPlugin {
       BuiltinPluginName::DeterministicChunkIdsPlugin => {
         plugins.push(DeterministicChunkIdsPlugin::default().boxed())
       }
+      BuiltinPluginName::RecordIdsPlugin => {
+        let plugin =
+          RecordIdsPlugin::new(downcast_into::<RawRecordIdsPluginOptions>(self.options)?.into())
+            .boxed();
+        plugins.push(plugin);
+      }
       BuiltinPluginName::RealContentHashPlugin => {
         plugins.push(RealContentHashPlugin::default().boxed())
       }
@@ -352,6 +445,9 @@ impl BuiltinPlugin {
       BuiltinPluginName::AsyncWebAssemblyModulesPlugin => {
         plugins.push(AsyncWasmPlugin::default().boxed())
       }
+      BuiltinPluginName::WebAssemblyModulesPlugin => {
+        plugins.push(SyncWasmPlugin::default().boxed())
+      }
       BuiltinPluginName::AssetModulesPlugin => plugins.push(AssetPlugin::default().boxed()),
       BuiltinPluginName::SourceMapDevToolPlugin => {
         let options: SourceMapDevToolPluginOptions =
@@ -398,7 +494,11 @@ impl BuiltinPlugin {
         plugins.push(MangleExportsPlugin::new(downcast_into::<bool>(self.options)?).boxed())
       }
       BuiltinPluginName::ModuleConcatenationPlugin => {
-        plugins.push(ModuleConcatenationPlugin::default().boxed())
+        let plugin = ModuleConcatenationPlugin::new(
+          downcast_into::<RawModuleConcatenationPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
       }
       BuiltinPluginName::CssModulesPlugin => plugins.push(CssPlugin::default().boxed()),
       BuiltinPluginName::APIPlugin => plugins.push(APIPlugin::default().boxed()),
@@ -412,6 +512,13 @@ impl BuiltinPlugin {
             .boxed();
         plugins.push(plugin)
       }
+      BuiltinPluginName::LazyCompilationPlugin => {
+        let plugin = LazyCompilationPlugin::new(
+          downcast_into::<RawLazyCompilationOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin)
+      }
 
       // rspack specific plugins
       BuiltinPluginName::HttpExternalsRspackPlugin => {
@@ -419,6 +526,11 @@ impl BuiltinPlugin {
         let plugin = http_externals_rspack_plugin(plugin_options.css, plugin_options.web_async);
         plugins.push(plugin);
       }
+      BuiltinPluginName::NodeModulesExternalsRspackPlugin => {
+        let plugin_options =
+          downcast_into::<RawNodeModulesExternalsRspackPluginOptions>(self.options)?;
+        plugins.push(NodeModulesExternalsPlugin::new(plugin_options.into()).boxed());
+      }
       BuiltinPluginName::SwcJsMinimizerRspackPlugin => {
         let plugin = SwcJsMinimizerRspackPlugin::new(
           downcast_into::<RawSwcJsMinimizerRspackPluginOptions>(self.options)?.try_into()?,
@@ -427,7 +539,18 @@ impl BuiltinPlugin {
         plugins.push(plugin);
       }
       BuiltinPluginName::SwcCssMinimizerRspackPlugin => {
-        plugins.push(SwcCssMinimizerRspackPlugin::default().boxed())
+        let plugin = SwcCssMinimizerRspackPlugin::new(
+          downcast_into::<RawSwcCssMinimizerRspackPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::ImageMinimizerRspackPlugin => {
+        let plugin = ImageMinimizerRspackPlugin::new(
+          downcast_into::<RawImageMinimizerRspackPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
       }
       BuiltinPluginName::CopyRspackPlugin => {
         let plugin = CopyRspackPlugin::new(
@@ -443,6 +566,13 @@ impl BuiltinPlugin {
             .boxed();
         plugins.push(plugin);
       }
+      BuiltinPluginName::BundleAnalysisRspackPlugin => {
+        let plugin = BundleAnalysisRspackPlugin::new(
+          downcast_into::<RawBundleAnalysisRspackPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
       BuiltinPluginName::BundlerInfoRspackPlugin => {
         let plugin_options = downcast_into::<RawBundlerInfoPluginOptions>(self.options)?;
         plugins.push(
@@ -453,6 +583,20 @@ impl BuiltinPlugin {
           .boxed(),
         )
       }
+      BuiltinPluginName::DuplicatePackageCheckerRspackPlugin => {
+        let plugin = DuplicatePackageCheckerRspackPlugin::new(
+          downcast_into::<RawDuplicatePackageCheckerRspackPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::NodePolyfillRspackPlugin => {
+        let plugin = NodePolyfillRspackPlugin::new(
+          downcast_into::<RawNodePolyfillRspackPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
       BuiltinPluginName::CssExtractRspackPlugin => {
         let additional_data_plugin = CssExtractRspackAdditionalDataPlugin::new(env)?.boxed();
         plugins.push(additional_data_plugin);
@@ -462,6 +606,20 @@ impl BuiltinPlugin {
         .boxed();
         plugins.push(plugin);
       }
+      BuiltinPluginName::CustomModuleTypeRspackPlugin => {
+        let plugin = CustomModuleTypeRspackPlugin::new(downcast_into::<
+          RawCustomModuleTypeRspackPluginOptions,
+        >(self.options)?)
+        .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::CustomRuntimeModuleRspackPlugin => {
+        let plugin = CustomRuntimeModuleRspackPlugin::new(downcast_into::<
+          RawCustomRuntimeModuleRspackPluginOptions,
+        >(self.options)?)?
+        .boxed();
+        plugins.push(plugin);
+      }
       // rspack js adapter plugins
       BuiltinPluginName::JsLoaderRspackPlugin => {
         plugins.push(
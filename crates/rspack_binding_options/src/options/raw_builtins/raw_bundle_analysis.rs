@@ -0,0 +1,16 @@
+use napi_derive::napi;
+use rspack_plugin_bundle_analysis::BundleAnalysisRspackPluginOptions;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawBundleAnalysisRspackPluginOptions {
+  pub filename: String,
+}
+
+impl From<RawBundleAnalysisRspackPluginOptions> for BundleAnalysisRspackPluginOptions {
+  fn from(value: RawBundleAnalysisRspackPluginOptions) -> Self {
+    Self {
+      filename: value.filename,
+    }
+  }
+}
@@ -0,0 +1,31 @@
+use derivative::Derivative;
+use napi_derive::napi;
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
+
+#[napi(object)]
+pub struct RawCustomModuleTypeFnCtx {
+  pub source: String,
+  /// The source map of `source` as a JSON string
+  pub map: Option<String>,
+  pub resource: String,
+}
+
+#[napi(object)]
+pub struct RawCustomModuleTypeResult {
+  pub code: String,
+  /// The source map from `source` to `code` as a JSON string
+  pub map: Option<String>,
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawCustomModuleTypeRspackPluginOptions {
+  pub r#type: String,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(ctx: RawCustomModuleTypeFnCtx) => RawCustomModuleTypeResult")]
+  pub parse: Option<ThreadsafeFunction<RawCustomModuleTypeFnCtx, RawCustomModuleTypeResult>>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(ctx: RawCustomModuleTypeFnCtx) => RawCustomModuleTypeResult")]
+  pub generate: Option<ThreadsafeFunction<RawCustomModuleTypeFnCtx, RawCustomModuleTypeResult>>,
+}
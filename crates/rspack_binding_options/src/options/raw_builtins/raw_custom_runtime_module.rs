@@ -0,0 +1,23 @@
+use derivative::Derivative;
+use napi_derive::napi;
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
+
+#[napi(object)]
+pub struct RawCustomRuntimeModuleFnCtx {
+  pub chunk_id: Option<String>,
+  pub chunk_name: Option<String>,
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawCustomRuntimeModuleRspackPluginOptions {
+  pub name: String,
+  #[napi(ts_type = r#""normal" | "basic" | "attach" | "trigger""#)]
+  pub stage: String,
+  pub chunks: Option<Vec<String>>,
+  pub runtime_requirements: Vec<String>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(ctx: RawCustomRuntimeModuleFnCtx) => string")]
+  pub generate: ThreadsafeFunction<RawCustomRuntimeModuleFnCtx, String>,
+}
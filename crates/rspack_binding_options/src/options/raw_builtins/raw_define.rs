@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use derivative::Derivative;
+use napi_derive::napi;
+use rspack_core::{DefinePluginOptions, DefineRuntimeValue};
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawDefineRuntimeValue {
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "() => string")]
+  pub get: ThreadsafeFunction<(), String>,
+  pub file_dependencies: Vec<String>,
+  pub cacheable: bool,
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawDefinePluginOptions {
+  pub define: HashMap<String, String>,
+  pub runtime_values: HashMap<String, RawDefineRuntimeValue>,
+}
+
+impl From<RawDefinePluginOptions> for DefinePluginOptions {
+  fn from(value: RawDefinePluginOptions) -> Self {
+    Self {
+      define: value.define,
+      runtime_values: value
+        .runtime_values
+        .into_iter()
+        .map(|(key, runtime_value)| {
+          let get = runtime_value.get;
+          (
+            key,
+            DefineRuntimeValue {
+              get: Box::new(move || {
+                let f = get.clone();
+                Box::pin(async move { f.call_with_sync(()).await })
+              }),
+              file_dependencies: runtime_value
+                .file_dependencies
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+              cacheable: runtime_value.cacheable,
+            },
+          )
+        })
+        .collect(),
+    }
+  }
+}
@@ -0,0 +1,74 @@
+use napi_derive::napi;
+use rspack_error::{miette::IntoDiagnostic, Result};
+use rspack_plugin_dll::{
+  DllEntryPluginOptions, DllReferenceAgencyPluginOptions, LibManifestPluginOptions,
+};
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawDllEntryPluginOptions {
+  pub context: String,
+  pub entries: Vec<String>,
+  pub name: String,
+}
+
+impl From<RawDllEntryPluginOptions> for DllEntryPluginOptions {
+  fn from(value: RawDllEntryPluginOptions) -> Self {
+    Self {
+      name: value.name,
+      context: value.context.into(),
+      entries: value.entries,
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawLibManifestPluginOptions {
+  pub context: Option<String>,
+  pub entry_only: Option<bool>,
+  pub name: Option<String>,
+  pub path: String,
+  pub format: Option<bool>,
+  pub r#type: Option<String>,
+}
+
+impl From<RawLibManifestPluginOptions> for LibManifestPluginOptions {
+  fn from(value: RawLibManifestPluginOptions) -> Self {
+    Self {
+      context: value.context.map(Into::into),
+      entry_only: value.entry_only,
+      name: value.name.map(Into::into),
+      path: value.path.into(),
+      format: value.format,
+      r#type: value.r#type,
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawDllReferenceAgencyPluginOptions {
+  pub context: Option<String>,
+  pub name: String,
+  pub extensions: Vec<String>,
+  pub scope: Option<String>,
+  pub r#type: String,
+  /// JSON string of the `content` of the dll manifest
+  pub content: String,
+}
+
+impl TryFrom<RawDllReferenceAgencyPluginOptions> for DllReferenceAgencyPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawDllReferenceAgencyPluginOptions) -> Result<Self> {
+    Ok(Self {
+      context: value.context.map(Into::into),
+      name: value.name,
+      content: serde_json::from_str(&value.content).into_diagnostic()?,
+      extensions: value.extensions,
+      scope: value.scope,
+      r#type: value.r#type,
+    })
+  }
+}
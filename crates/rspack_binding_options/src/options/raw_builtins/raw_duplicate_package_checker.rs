@@ -0,0 +1,22 @@
+use napi_derive::napi;
+use rspack_plugin_duplicate_package_checker::DuplicatePackageCheckerRspackPluginOptions;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawDuplicatePackageCheckerRspackPluginOptions {
+  pub emit_error: bool,
+  pub filename: Option<String>,
+  pub exclude: Vec<String>,
+}
+
+impl From<RawDuplicatePackageCheckerRspackPluginOptions>
+  for DuplicatePackageCheckerRspackPluginOptions
+{
+  fn from(value: RawDuplicatePackageCheckerRspackPluginOptions) -> Self {
+    Self {
+      emit_error: value.emit_error,
+      filename: value.filename,
+      exclude: value.exclude,
+    }
+  }
+}
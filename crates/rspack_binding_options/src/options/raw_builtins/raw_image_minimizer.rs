@@ -0,0 +1,81 @@
+use napi::Either;
+use napi_derive::napi;
+use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
+use rspack_plugin_image_minimizer::{
+  ImageMinimizerFormatOptions, ImageMinimizerRspackPluginOptions, ImageMinimizerRule,
+  ImageMinimizerRules,
+};
+
+type RawImageMinimizerRule = Either<String, JsRegExp>;
+type RawImageMinimizerRules = Either<RawImageMinimizerRule, Vec<RawImageMinimizerRule>>;
+struct RawImageMinimizerRuleWrapper(RawImageMinimizerRule);
+struct RawImageMinimizerRulesWrapper(RawImageMinimizerRules);
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawImageMinimizerFormatOptions {
+  #[napi(ts_type = r#""lossless" | "lossy""#)]
+  pub preset: String,
+  pub quality: u8,
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawImageMinimizerRspackPluginOptions {
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub test: Option<RawImageMinimizerRules>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub include: Option<RawImageMinimizerRules>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub exclude: Option<RawImageMinimizerRules>,
+  pub png: Option<RawImageMinimizerFormatOptions>,
+  pub jpeg: Option<RawImageMinimizerFormatOptions>,
+  pub webp: Option<RawImageMinimizerFormatOptions>,
+}
+
+impl From<RawImageMinimizerRuleWrapper> for ImageMinimizerRule {
+  fn from(x: RawImageMinimizerRuleWrapper) -> Self {
+    match x.0 {
+      Either::A(s) => ImageMinimizerRule::String(s),
+      Either::B(r) => ImageMinimizerRule::Regexp(r.to_rspack_regex()),
+    }
+  }
+}
+
+impl From<RawImageMinimizerRulesWrapper> for ImageMinimizerRules {
+  fn from(x: RawImageMinimizerRulesWrapper) -> Self {
+    match x.0 {
+      Either::A(v) => ImageMinimizerRules::Single(RawImageMinimizerRuleWrapper(v).into()),
+      Either::B(v) => v
+        .into_iter()
+        .map(|v| RawImageMinimizerRuleWrapper(v).into())
+        .collect(),
+    }
+  }
+}
+
+impl From<RawImageMinimizerFormatOptions> for ImageMinimizerFormatOptions {
+  fn from(value: RawImageMinimizerFormatOptions) -> Self {
+    Self {
+      preset: value.preset.into(),
+      quality: value.quality,
+    }
+  }
+}
+
+impl From<RawImageMinimizerRspackPluginOptions> for ImageMinimizerRspackPluginOptions {
+  fn from(value: RawImageMinimizerRspackPluginOptions) -> Self {
+    Self {
+      test: value.test.map(|v| RawImageMinimizerRulesWrapper(v).into()),
+      include: value
+        .include
+        .map(|v| RawImageMinimizerRulesWrapper(v).into()),
+      exclude: value
+        .exclude
+        .map(|v| RawImageMinimizerRulesWrapper(v).into()),
+      png: value.png.map(Into::into),
+      jpeg: value.jpeg.map(Into::into),
+      webp: value.webp.map(Into::into),
+    }
+  }
+}
@@ -0,0 +1,87 @@
+use derivative::Derivative;
+use napi::Either;
+use napi_derive::napi;
+use rspack_binding_values::{JsModule, ToJsModule};
+use rspack_napi::{
+  regexp::{JsRegExp, JsRegExpExt},
+  threadsafe_function::ThreadsafeFunction,
+  NapiResultExt,
+};
+use rspack_plugin_runtime::{
+  LazyCompilationBackend, LazyCompilationModuleArgs, LazyCompilationModuleInfo,
+  LazyCompilationOptions, LazyCompilationTest, LazyCompilationTestFn,
+};
+
+#[napi(object)]
+pub struct RawLazyCompilationModuleArgs {
+  pub module: String,
+  pub path: String,
+}
+
+impl From<LazyCompilationModuleArgs> for RawLazyCompilationModuleArgs {
+  fn from(value: LazyCompilationModuleArgs) -> Self {
+    Self {
+      module: value.module,
+      path: value.path,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct RawLazyCompilationModuleInfo {
+  pub active: bool,
+  pub data: String,
+  pub client: String,
+}
+
+impl From<RawLazyCompilationModuleInfo> for LazyCompilationModuleInfo {
+  fn from(value: RawLazyCompilationModuleInfo) -> Self {
+    Self {
+      active: value.active,
+      data: value.data,
+      client: value.client,
+    }
+  }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawLazyCompilationOptions {
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(args: RawLazyCompilationModuleArgs) => RawLazyCompilationModuleInfo")]
+  pub module: ThreadsafeFunction<RawLazyCompilationModuleArgs, RawLazyCompilationModuleInfo>,
+  pub entries: bool,
+  pub imports: bool,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "RegExp | ((module: JsModule) => boolean)")]
+  pub test: Option<Either<JsRegExp, ThreadsafeFunction<JsModule, Option<bool>>>>,
+}
+
+impl From<RawLazyCompilationOptions> for LazyCompilationOptions {
+  fn from(value: RawLazyCompilationOptions) -> Self {
+    let module = value.module;
+    let backend: LazyCompilationBackend = Box::new(move |args| {
+      let f = module.clone();
+
+      Box::pin(async move { f.call(args.into()).await.map(Into::into) })
+    });
+    Self {
+      backend,
+      entries: value.entries,
+      imports: value.imports,
+      test: value.test.map(|test| match test {
+        Either::A(regexp) => LazyCompilationTest::Regex(regexp.to_rspack_regex()),
+        Either::B(test) => {
+          let test_fn: LazyCompilationTestFn = Box::new(move |module| {
+            let f = test.clone();
+            let module = module.to_js_module().into_rspack_result();
+
+            Box::pin(async move { f.call(module?).await.map(|res| res.unwrap_or_default()) })
+          });
+          LazyCompilationTest::Fn(test_fn)
+        }
+      }),
+    }
+  }
+}
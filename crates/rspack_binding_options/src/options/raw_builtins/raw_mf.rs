@@ -1,10 +1,12 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use napi::Either;
 use napi_derive::napi;
 use rspack_plugin_mf::{
   ConsumeOptions, ConsumeSharedPluginOptions, ConsumeVersion, ContainerPluginOptions,
-  ContainerReferencePluginOptions, ExposeOptions, ProvideOptions, ProvideVersion, RemoteOptions,
+  ContainerReferencePluginOptions, ExposeOptions, ManifestRemoteEntry, ManifestRemoteInfo,
+  ModuleFederationManifestPluginOptions, ProvideOptions, ProvideVersion, RemoteFallback,
+  RemoteOptions,
 };
 
 use crate::{RawEntryRuntime, RawEntryRuntimeWrapper, RawLibraryOptions};
@@ -82,6 +84,8 @@ pub struct RawRemoteOptions {
   pub key: String,
   pub external: Vec<String>,
   pub share_scope: String,
+  #[napi(ts_type = "string | Record<string, string>")]
+  pub fallback: Option<Either<String, HashMap<String, String>>>,
 }
 
 impl From<RawRemoteOptions> for (String, RemoteOptions) {
@@ -91,6 +95,10 @@ impl From<RawRemoteOptions> for (String, RemoteOptions) {
       RemoteOptions {
         external: value.external,
         share_scope: value.share_scope,
+        fallback: value.fallback.map(|fallback| match fallback {
+          Either::A(s) => RemoteFallback::Remote(s),
+          Either::B(m) => RemoteFallback::Exposes(m),
+        }),
       },
     )
   }
@@ -177,6 +185,64 @@ impl From<RawConsumeOptions> for (String, ConsumeOptions) {
   }
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawModuleFederationManifestPluginOptions {
+  pub name: String,
+  pub global_name: String,
+  pub file_name: String,
+  pub remote_entry: Option<RawManifestRemoteEntry>,
+  pub remotes: Vec<RawManifestRemoteInfo>,
+}
+
+impl From<RawModuleFederationManifestPluginOptions> for ModuleFederationManifestPluginOptions {
+  fn from(value: RawModuleFederationManifestPluginOptions) -> Self {
+    Self {
+      name: value.name,
+      global_name: value.global_name,
+      file_name: value.file_name,
+      remote_entry: value.remote_entry.map(|e| e.into()),
+      remotes: value.remotes.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawManifestRemoteEntry {
+  pub name: String,
+  pub path: String,
+  pub r#type: String,
+}
+
+impl From<RawManifestRemoteEntry> for ManifestRemoteEntry {
+  fn from(value: RawManifestRemoteEntry) -> Self {
+    Self {
+      name: value.name,
+      path: value.path,
+      r#type: value.r#type,
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawManifestRemoteInfo {
+  pub alias: String,
+  pub name: Option<String>,
+  pub entry: Option<String>,
+}
+
+impl From<RawManifestRemoteInfo> for ManifestRemoteInfo {
+  fn from(value: RawManifestRemoteInfo) -> Self {
+    Self {
+      alias: value.alias,
+      name: value.name,
+      entry: value.entry,
+    }
+  }
+}
+
 pub type RawVersion = Either<String, bool>;
 
 struct RawVersionWrapper(RawVersion);
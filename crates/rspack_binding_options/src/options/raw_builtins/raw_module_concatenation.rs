@@ -0,0 +1,18 @@
+use napi_derive::napi;
+use rspack_plugin_javascript::ModuleConcatenationPluginOptions;
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct RawModuleConcatenationPluginOptions {
+  pub max_size: Option<f64>,
+  pub allow_cjs_leaves: Option<bool>,
+}
+
+impl From<RawModuleConcatenationPluginOptions> for ModuleConcatenationPluginOptions {
+  fn from(value: RawModuleConcatenationPluginOptions) -> Self {
+    Self {
+      max_size: value.max_size,
+      allow_cjs_leaves: value.allow_cjs_leaves.unwrap_or_default(),
+    }
+  }
+}
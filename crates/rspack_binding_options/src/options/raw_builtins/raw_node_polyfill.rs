@@ -0,0 +1,31 @@
+use napi_derive::napi;
+use rspack_plugin_node_polyfill::{NodePolyfillGlobals, NodePolyfillRspackPluginOptions};
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawNodePolyfillRspackPluginOptions {
+  pub include_aliases: Option<Vec<String>>,
+  pub exclude_aliases: Vec<String>,
+  pub globals: RawNodePolyfillGlobals,
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawNodePolyfillGlobals {
+  pub process: bool,
+  #[napi(js_name = "Buffer")]
+  pub buffer: bool,
+}
+
+impl From<RawNodePolyfillRspackPluginOptions> for NodePolyfillRspackPluginOptions {
+  fn from(value: RawNodePolyfillRspackPluginOptions) -> Self {
+    Self {
+      include_aliases: value.include_aliases,
+      exclude_aliases: value.exclude_aliases,
+      globals: NodePolyfillGlobals {
+        process: value.globals.process,
+        buffer: value.globals.buffer,
+      },
+    }
+  }
+}
@@ -1,11 +1,21 @@
+use std::sync::Arc;
+
+use derivative::Derivative;
 use napi_derive::napi;
-use rspack_plugin_progress::ProgressPluginOptions;
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
+use rspack_plugin_progress::{HandlerFn, ProgressPluginOptions};
+
+type RawHandler = ThreadsafeFunction<(f64, String, Vec<String>), ()>;
 
-#[derive(Debug, Clone)]
-#[napi(object)]
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
 pub struct RawProgressPluginOptions {
   pub prefix: String,
   pub profile: bool,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(percent: number, msg: string, items: string[]) => void")]
+  pub handler: Option<RawHandler>,
 }
 
 impl From<RawProgressPluginOptions> for ProgressPluginOptions {
@@ -13,6 +23,12 @@ impl From<RawProgressPluginOptions> for ProgressPluginOptions {
     Self {
       prefix: value.prefix,
       profile: value.profile,
+      handler: value.handler.map(|handler| {
+        let handler_fn: HandlerFn = Arc::new(move |percent, msg, items| {
+          handler.blocking_call_with_sync((percent, msg, items))
+        });
+        handler_fn
+      }),
     }
   }
 }
@@ -0,0 +1,18 @@
+use napi_derive::napi;
+use rspack_ids::RecordIdsPluginOptions;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawRecordIdsPluginOptions {
+  pub input_path: Option<String>,
+  pub output_path: Option<String>,
+}
+
+impl From<RawRecordIdsPluginOptions> for RecordIdsPluginOptions {
+  fn from(value: RawRecordIdsPluginOptions) -> Self {
+    Self {
+      input_path: value.input_path.map(Into::into),
+      output_path: value.output_path.map(Into::into),
+    }
+  }
+}
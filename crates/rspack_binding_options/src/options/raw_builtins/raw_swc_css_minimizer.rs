@@ -0,0 +1,37 @@
+use napi_derive::napi;
+use rspack_plugin_css::swc_css_compiler::SwcCssMinifyOptions;
+use rspack_plugin_swc_css_minimizer::SwcCssMinimizerRspackPluginOptions;
+
+use super::raw_swc_js_minimizer::{into_condition, RawSwcJsMinimizerRules};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawSwcCssMinimizerRspackPluginOptions {
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub test: Option<RawSwcJsMinimizerRules>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub include: Option<RawSwcJsMinimizerRules>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub exclude: Option<RawSwcJsMinimizerRules>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub preserve_comments: Option<RawSwcJsMinimizerRules>,
+  pub compress: Option<bool>,
+  pub discard_unused_keyframes: Option<bool>,
+  pub calc_precision: Option<u32>,
+}
+
+impl From<RawSwcCssMinimizerRspackPluginOptions> for SwcCssMinimizerRspackPluginOptions {
+  fn from(value: RawSwcCssMinimizerRspackPluginOptions) -> Self {
+    Self {
+      test: into_condition(value.test),
+      include: into_condition(value.include),
+      exclude: into_condition(value.exclude),
+      preserve_comments: into_condition(value.preserve_comments),
+      minify_options: SwcCssMinifyOptions {
+        compress: value.compress.unwrap_or(true),
+        discard_unused_keyframes: value.discard_unused_keyframes.unwrap_or(false),
+        calc_precision: value.calc_precision,
+      },
+    }
+  }
+}
@@ -3,14 +3,14 @@ use napi_derive::napi;
 use rspack_error::{miette::IntoDiagnostic, Result};
 use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
 use rspack_plugin_swc_js_minimizer::{
-  ExtractComments, OptionWrapper, SwcJsMinimizerRspackPluginOptions, SwcJsMinimizerRule,
-  SwcJsMinimizerRules,
+  ExtractComments, MangleOptions, OptionWrapper, SwcJsMinimizerRspackPluginOptions,
+  SwcJsMinimizerRule, SwcJsMinimizerRules,
 };
 use serde::Deserialize;
 use swc_config::config_types::BoolOrDataConfig;
 
 type RawSwcJsMinimizerRule = Either<String, JsRegExp>;
-type RawSwcJsMinimizerRules = Either3<String, JsRegExp, Vec<RawSwcJsMinimizerRule>>;
+pub(super) type RawSwcJsMinimizerRules = Either3<String, JsRegExp, Vec<RawSwcJsMinimizerRule>>;
 struct RawSwcJsMinimizerRuleWrapper(RawSwcJsMinimizerRule);
 struct RawSwcJsMinimizerRulesWrapper(RawSwcJsMinimizerRules);
 
@@ -19,6 +19,7 @@ struct RawSwcJsMinimizerRulesWrapper(RawSwcJsMinimizerRules);
 pub struct RawExtractComments {
   pub banner: Option<Either<String, bool>>,
   pub condition: Option<String>,
+  pub filename: Option<String>,
 }
 
 #[derive(Debug)]
@@ -46,7 +47,28 @@ fn try_deserialize_into<'de, T: 'de + Deserialize<'de>>(
   })
 }
 
-fn into_condition(c: Option<RawSwcJsMinimizerRules>) -> Option<SwcJsMinimizerRules> {
+/// `keep_quoted` of `mangle.props` is not supported by swc,
+/// it is taken out of the options and handled by the plugin.
+fn try_deserialize_mangle(
+  value: &Either<bool, String>,
+) -> Result<(BoolOrDataConfig<MangleOptions>, bool)> {
+  let Either::B(s) = value else {
+    return Ok((try_deserialize_into(value)?, false));
+  };
+  let mut mangle: serde_json::Value = serde_json::from_str(s).into_diagnostic()?;
+  let keep_quoted_props = mangle
+    .get_mut("props")
+    .and_then(|props| props.as_object_mut())
+    .and_then(|props| props.remove("keep_quoted"))
+    .and_then(|keep_quoted| keep_quoted.as_bool())
+    .unwrap_or(false);
+  Ok((
+    BoolOrDataConfig::from_obj(serde_json::from_value(mangle).into_diagnostic()?),
+    keep_quoted_props,
+  ))
+}
+
+pub(super) fn into_condition(c: Option<RawSwcJsMinimizerRules>) -> Option<SwcJsMinimizerRules> {
   c.map(|test| RawSwcJsMinimizerRulesWrapper(test).into())
 }
 
@@ -67,17 +89,23 @@ fn into_extract_comments(c: Option<RawExtractComments>) -> Option<ExtractComment
     None => OptionWrapper::Default,
   };
 
-  Some(ExtractComments { condition, banner })
+  Some(ExtractComments {
+    condition,
+    banner,
+    filename: c.filename,
+  })
 }
 
 impl TryFrom<RawSwcJsMinimizerRspackPluginOptions> for SwcJsMinimizerRspackPluginOptions {
   type Error = rspack_error::Error;
 
   fn try_from(value: RawSwcJsMinimizerRspackPluginOptions) -> Result<Self> {
+    let (mangle, keep_quoted_props) = try_deserialize_mangle(&value.mangle)?;
     Ok(Self {
       extract_comments: into_extract_comments(value.extract_comments),
       compress: try_deserialize_into(&value.compress)?,
-      mangle: try_deserialize_into(&value.mangle)?,
+      mangle,
+      keep_quoted_props,
       format: serde_json::from_str(&value.format).into_diagnostic()?,
       module: value.module,
       test: into_condition(value.test),
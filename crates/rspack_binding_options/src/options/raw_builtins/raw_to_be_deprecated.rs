@@ -147,6 +147,7 @@ impl RawBuiltins {
   pub fn apply(self) -> rspack_error::Result<Builtins> {
     Ok(Builtins {
       define: Default::default(),
+      runtime_define: Default::default(),
       provide: Default::default(),
       tree_shaking: self.tree_shaking.into(),
     })
@@ -5,6 +5,7 @@ use rspack_core::RspackFuture;
 #[napi(object)]
 pub struct RawRspackFuture {
   pub new_treeshaking: bool,
+  pub inline_entry_modules: bool,
 }
 
 #[derive(Debug, Default)]
@@ -12,6 +13,7 @@ pub struct RawRspackFuture {
 pub struct RawExperiments {
   pub new_split_chunks: bool,
   pub top_level_await: bool,
+  pub path_independent: Option<bool>,
   pub rspack_future: RawRspackFuture,
 }
 
@@ -19,6 +21,7 @@ impl From<RawRspackFuture> for RspackFuture {
   fn from(value: RawRspackFuture) -> Self {
     Self {
       new_treeshaking: value.new_treeshaking,
+      inline_entry_modules: value.inline_entry_modules,
     }
   }
 }
@@ -1,12 +1,13 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 
-use napi::bindgen_prelude::Either4;
+use napi::bindgen_prelude::{Either, Either4};
 use napi_derive::napi;
 use rspack_core::ExternalItemFnCtx;
 use rspack_core::{ExternalItem, ExternalItemFnResult, ExternalItemValue};
 use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
+use rspack_plugin_externals::{NodeModulesExternalsAllowlistItem, NodeModulesExternalsOptions};
 
 #[napi(object)]
 pub struct RawHttpExternalsRspackPluginOptions {
@@ -14,6 +15,29 @@ pub struct RawHttpExternalsRspackPluginOptions {
   pub web_async: bool,
 }
 
+#[napi(object, object_to_js = false)]
+pub struct RawNodeModulesExternalsRspackPluginOptions {
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub allowlist: Vec<Either<String, JsRegExp>>,
+  pub import_type: String,
+}
+
+impl From<RawNodeModulesExternalsRspackPluginOptions> for NodeModulesExternalsOptions {
+  fn from(value: RawNodeModulesExternalsRspackPluginOptions) -> Self {
+    Self {
+      allowlist: value
+        .allowlist
+        .into_iter()
+        .map(|item| match item {
+          Either::A(s) => NodeModulesExternalsAllowlistItem::String(s),
+          Either::B(r) => NodeModulesExternalsAllowlistItem::RegExp(r.to_rspack_regex()),
+        })
+        .collect(),
+      import_type: value.import_type,
+    }
+  }
+}
+
 #[napi(object, object_to_js = false)]
 pub struct RawExternalsPluginOptions {
   pub r#type: String,
@@ -62,12 +86,19 @@ impl From<RawExternalItemFnResult> for ExternalItemFnResult {
   }
 }
 
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct RawContextInfo {
+  pub issuer: String,
+}
+
 #[derive(Debug, Clone)]
 #[napi(object)]
 pub struct RawExternalItemFnCtx {
   pub request: String,
   pub context: String,
   pub dependency_type: String,
+  pub context_info: RawContextInfo,
 }
 
 impl From<ExternalItemFnCtx> for RawExternalItemFnCtx {
@@ -76,6 +107,9 @@ impl From<ExternalItemFnCtx> for RawExternalItemFnCtx {
       request: value.request,
       dependency_type: value.dependency_type,
       context: value.context,
+      context_info: RawContextInfo {
+        issuer: value.context_info.issuer,
+      },
     }
   }
 }
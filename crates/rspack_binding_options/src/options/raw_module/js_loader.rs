@@ -1,7 +1,8 @@
 use std::{ops::Deref, path::PathBuf, str::FromStr};
 
 use napi_derive::napi;
-use rspack_core::{rspack_sources::SourceMap, Content, ResourceData};
+use rspack_binding_values::{JsBuildInfo, JsBuildMeta};
+use rspack_core::{rspack_sources::SourceMap, Content, LoaderModuleBuild, ResourceData};
 use rspack_error::Diagnostic;
 use rspack_loader_runner::AdditionalData;
 use rustc_hash::FxHashSet as HashSet;
@@ -146,6 +147,16 @@ fn sync_loader_context(
       .remove::<ThreadsafeJsValueRef<Unknown>>();
   }
   loader_context.asset_filenames = loader_result.asset_filenames.into_iter().collect();
+  if let Some(build_info) = loader_result.build_info
+    && let Some(build_meta) = loader_result.build_meta
+  {
+    let mut loader_module_build = LoaderModuleBuild {
+      build_info_extras: build_info.extras,
+      ..Default::default()
+    };
+    build_meta.apply(&mut loader_module_build.build_meta)?;
+    loader_context.additional_data.insert(loader_module_build);
+  }
 
   Ok(())
 }
@@ -191,6 +202,9 @@ pub struct JsLoaderContext {
 
   #[napi(js_name = "_moduleIdentifier")]
   pub module_identifier: String,
+  /// The `buildInfo` and `buildMeta` of the module set by the previous loaders
+  pub build_info: JsBuildInfo,
+  pub build_meta: JsBuildMeta,
 
   pub hot: bool,
 }
@@ -203,6 +217,11 @@ impl TryFrom<&mut rspack_core::LoaderContext<'_, rspack_core::LoaderRunnerContex
   fn try_from(
     cx: &mut rspack_core::LoaderContext<'_, rspack_core::LoaderRunnerContext>,
   ) -> std::result::Result<Self, Self::Error> {
+    let loader_module_build = cx
+      .additional_data
+      .get::<LoaderModuleBuild>()
+      .cloned()
+      .unwrap_or_default();
     Ok(JsLoaderContext {
       content: match &cx.content {
         Some(c) => Either::B(c.to_owned().into_bytes().into()),
@@ -254,6 +273,12 @@ impl TryFrom<&mut rspack_core::LoaderContext<'_, rspack_core::LoaderRunnerContex
       context_external: External::new(cx.context.clone()),
       diagnostics_external: External::new(cx.__diagnostics.drain(..).collect()),
       module_identifier: cx.context.module.to_string(),
+      build_info: JsBuildInfo {
+        cacheable: cx.cacheable,
+        strict: false,
+        extras: loader_module_build.build_info_extras,
+      },
+      build_meta: JsBuildMeta::from(&loader_module_build.build_meta),
       hot: cx.hot,
     })
   }
@@ -362,6 +387,8 @@ pub struct JsLoaderResult {
   pub cacheable: bool,
   /// Used to instruct how rust loaders should execute
   pub is_pitching: bool,
+  pub build_info: Option<JsBuildInfo>,
+  pub build_meta: Option<JsBuildMeta>,
 }
 
 /// Only for dts generation
@@ -381,6 +408,8 @@ pub struct JsLoaderResult_ {
   pub cacheable: bool,
   /// Used to instruct how rust loaders should execute
   pub is_pitching: bool,
+  pub build_info: Option<JsBuildInfo>,
+  pub build_meta: Option<JsBuildMeta>,
 }
 
 impl napi::bindgen_prelude::TypeName for JsLoaderResult {
@@ -456,6 +485,8 @@ impl napi::bindgen_prelude::FromNapiValue for JsLoaderResult {
         format!("Missing field `{}`", "isPitching"),
       )
     })?;
+    let build_info_: Option<JsBuildInfo> = obj.get("buildInfo")?;
+    let build_meta_: Option<JsBuildMeta> = obj.get("buildMeta")?;
     let val = Self {
       content: content_,
       file_dependencies: file_dependencies_,
@@ -468,6 +499,8 @@ impl napi::bindgen_prelude::FromNapiValue for JsLoaderResult {
       additional_data_external: additional_data_external_,
       cacheable: cacheable_,
       is_pitching: is_pitching_,
+      build_info: build_info_,
+      build_meta: build_meta_,
     };
     Ok(val)
   }
@@ -7,15 +7,18 @@ use derivative::Derivative;
 use napi::bindgen_prelude::Either3;
 use napi::Either;
 use napi_derive::napi;
+use rspack_binding_values::{JsFilename, JsModule, ToJsModule};
 use rspack_core::{
   AssetGeneratorDataUrl, AssetGeneratorDataUrlFnArgs, AssetGeneratorDataUrlOptions,
-  AssetGeneratorOptions, AssetInlineGeneratorOptions, AssetParserDataUrl,
-  AssetParserDataUrlOptions, AssetParserOptions, AssetResourceGeneratorOptions, BoxLoader,
-  CssAutoGeneratorOptions, CssAutoParserOptions, CssGeneratorOptions, CssModuleGeneratorOptions,
-  CssModuleParserOptions, CssParserOptions, DescriptionData, DynamicImportMode, FuncUseCtx,
-  GeneratorOptions, GeneratorOptionsByModuleType, JavascriptParserOptions, JavascriptParserOrder,
-  JavascriptParserUrl, ModuleNoParseRule, ModuleNoParseRules, ModuleNoParseTestFn, ModuleOptions,
-  ModuleRule, ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, ModuleType, ParserOptions,
+  AssetGeneratorOptions, AssetGeneratorResponsiveOptions, AssetInlineGeneratorOptions,
+  AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions, AssetResourceGeneratorOptions,
+  BoxLoader, CssAutoGeneratorOptions, CssAutoParserOptions, CssGeneratorOptions,
+  CssModuleGeneratorOptions, CssModuleParserOptions, CssParserOptions, DescriptionData,
+  DynamicImportMode, FuncUseCtx, GeneratorOptions, GeneratorOptionsByModuleType,
+  JavascriptParserImportMetaEnv, JavascriptParserImportMetaUrl, JavascriptParserOptions,
+  JavascriptParserOrder, JavascriptParserUrl, JsonParse, JsonParseFn, JsonParserOptions,
+  ModuleNoParseRule, ModuleNoParseRules, ModuleNoParseTestFn, ModuleOptions, ModuleRule,
+  ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, ModuleType, ParserOptions,
   ParserOptionsByModuleType,
 };
 use rspack_error::error;
@@ -23,6 +26,7 @@ use rspack_loader_react_refresh::REACT_REFRESH_LOADER_IDENTIFIER;
 use rspack_loader_swc::SWC_LOADER_IDENTIFIER;
 use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
+use rspack_napi::NapiResultExt;
 use tokio::runtime::Handle;
 
 pub use self::js_loader::JsLoaderAdapter;
@@ -259,10 +263,10 @@ pub struct RawModuleRule {
 }
 
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawParserOptions {
   #[napi(
-    ts_type = r#""asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm""#
+    ts_type = r#""asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json""#
   )]
   pub r#type: String,
   pub asset: Option<RawAssetParserOptions>,
@@ -270,6 +274,7 @@ pub struct RawParserOptions {
   pub css_auto: Option<RawCssAutoParserOptions>,
   pub css_module: Option<RawCssModuleParserOptions>,
   pub javascript: Option<RawJavascriptParserOptions>,
+  pub json: Option<RawJsonParserOptions>,
 }
 
 impl From<RawParserOptions> for ParserOptions {
@@ -307,6 +312,12 @@ impl From<RawParserOptions> for ParserOptions {
           .expect("should have an \"css_module\" when RawParserOptions.type is \"css/module\"")
           .into(),
       ),
+      "json" => Self::Json(
+        value
+          .json
+          .expect("should have an \"json\" when RawParserOptions.type is \"json\"")
+          .into(),
+      ),
       _ => panic!(
         "Failed to resolve the RawParserOptions.type {}.",
         value.r#type
@@ -324,6 +335,8 @@ pub struct RawJavascriptParserOptions {
   pub url: String,
   pub expr_context_critical: bool,
   pub wrapped_context_critical: bool,
+  pub import_meta_url: String,
+  pub import_meta_env: String,
 }
 
 impl From<RawJavascriptParserOptions> for JavascriptParserOptions {
@@ -335,6 +348,8 @@ impl From<RawJavascriptParserOptions> for JavascriptParserOptions {
       url: JavascriptParserUrl::from(value.url.as_str()),
       expr_context_critical: value.expr_context_critical,
       wrapped_context_critical: value.wrapped_context_critical,
+      import_meta_url: JavascriptParserImportMetaUrl::from(value.import_meta_url.as_str()),
+      import_meta_env: JavascriptParserImportMetaEnv::from(value.import_meta_env.as_str()),
     }
   }
 }
@@ -435,6 +450,44 @@ impl From<RawCssModuleParserOptions> for CssModuleParserOptions {
   }
 }
 
+#[derive(Derivative, Default)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawJsonParserOptions {
+  pub exports_depth: Option<f64>,
+  /// Returns the value of the json module serialized by `JSON.stringify`
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(source: string) => string")]
+  pub parse: Option<ThreadsafeFunction<String, String>>,
+}
+
+impl From<RawJsonParserOptions> for JsonParserOptions {
+  fn from(value: RawJsonParserOptions) -> Self {
+    Self {
+      // `Infinity` saturates to `u32::MAX`
+      exports_depth: value.exports_depth.map(|depth| depth as u32),
+      parse: value
+        .parse
+        .map(|parse| Arc::new(RawJsonParse(parse)) as JsonParseFn),
+    }
+  }
+}
+
+struct RawJsonParse(ThreadsafeFunction<String, String>);
+
+impl Debug for RawJsonParse {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.debug_tuple("RawJsonParse").finish()
+  }
+}
+
+impl JsonParse for RawJsonParse {
+  fn parse(&self, source: &str) -> rspack_error::Result<json::JsonValue> {
+    let value = self.0.blocking_call_with_sync(source.to_owned())?;
+    json::parse(&value).map_err(|e| error!("Failed to parse the value returned by `parse`: {e}"))
+  }
+}
+
 #[derive(Debug, Default)]
 #[napi(object, object_to_js = false)]
 pub struct RawGeneratorOptions {
@@ -506,13 +559,15 @@ impl From<RawGeneratorOptions> for GeneratorOptions {
 #[napi(object, object_to_js = false)]
 pub struct RawAssetGeneratorOptions {
   pub emit: Option<bool>,
-  pub filename: Option<String>,
+  pub filename: Option<JsFilename>,
+  pub output_path: Option<String>,
   pub public_path: Option<String>,
   #[derivative(Debug = "ignore")]
   #[napi(
     ts_type = "RawAssetGeneratorDataUrlOptions | ((arg: RawAssetGeneratorDataUrlFnArgs) => string)"
   )]
   pub data_url: Option<RawAssetGeneratorDataUrl>,
+  pub responsive: Option<RawAssetGeneratorResponsiveOptions>,
 }
 
 impl From<RawAssetGeneratorOptions> for AssetGeneratorOptions {
@@ -520,10 +575,12 @@ impl From<RawAssetGeneratorOptions> for AssetGeneratorOptions {
     Self {
       emit: value.emit,
       filename: value.filename.map(|i| i.into()),
+      output_path: value.output_path.map(|i| i.into()),
       public_path: value.public_path.map(|i| i.into()),
       data_url: value
         .data_url
         .map(|i| RawAssetGeneratorDataUrlWrapper(i).into()),
+      responsive: value.responsive.map(|i| i.into()),
     }
   }
 }
@@ -550,11 +607,13 @@ impl From<RawAssetInlineGeneratorOptions> for AssetInlineGeneratorOptions {
 }
 
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawAssetResourceGeneratorOptions {
   pub emit: Option<bool>,
-  pub filename: Option<String>,
+  pub filename: Option<JsFilename>,
+  pub output_path: Option<String>,
   pub public_path: Option<String>,
+  pub responsive: Option<RawAssetGeneratorResponsiveOptions>,
 }
 
 impl From<RawAssetResourceGeneratorOptions> for AssetResourceGeneratorOptions {
@@ -562,7 +621,28 @@ impl From<RawAssetResourceGeneratorOptions> for AssetResourceGeneratorOptions {
     Self {
       emit: value.emit,
       filename: value.filename.map(|i| i.into()),
+      output_path: value.output_path.map(|i| i.into()),
       public_path: value.public_path.map(|i| i.into()),
+      responsive: value.responsive.map(|i| i.into()),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+#[napi(object)]
+pub struct RawAssetGeneratorResponsiveOptions {
+  pub sizes: Vec<u32>,
+  #[napi(ts_type = r#"Array<"png" | "jpeg" | "webp">"#)]
+  pub formats: Vec<String>,
+  pub quality: u8,
+}
+
+impl From<RawAssetGeneratorResponsiveOptions> for AssetGeneratorResponsiveOptions {
+  fn from(value: RawAssetGeneratorResponsiveOptions) -> Self {
+    Self {
+      sizes: value.sizes,
+      formats: value.formats,
+      quality: value.quality,
     }
   }
 }
@@ -573,19 +653,22 @@ type RawAssetGeneratorDataUrl = Either<
 >;
 struct RawAssetGeneratorDataUrlWrapper(RawAssetGeneratorDataUrl);
 
-#[derive(Debug, Clone)]
 #[napi(object)]
 pub struct RawAssetGeneratorDataUrlFnArgs {
   pub filename: String,
   pub content: String,
+  pub module: JsModule,
 }
 
-impl From<AssetGeneratorDataUrlFnArgs> for RawAssetGeneratorDataUrlFnArgs {
-  fn from(value: AssetGeneratorDataUrlFnArgs) -> Self {
-    Self {
+impl<'a> TryFrom<AssetGeneratorDataUrlFnArgs<'a>> for RawAssetGeneratorDataUrlFnArgs {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: AssetGeneratorDataUrlFnArgs<'a>) -> rspack_error::Result<Self> {
+    Ok(Self {
       filename: value.filename,
       content: value.content,
-    }
+      module: value.module.to_js_module().into_rspack_result()?,
+    })
   }
 }
 
@@ -594,7 +677,10 @@ impl From<RawAssetGeneratorDataUrlWrapper> for AssetGeneratorDataUrl {
     let handle = Handle::current();
     match value.0 {
       Either::A(a) => Self::Options(a.into()),
-      Either::B(b) => Self::Func(Arc::new(move |ctx| handle.block_on(b.call(ctx.into())))),
+      Either::B(b) => Self::Func(Arc::new(move |ctx| {
+        let ctx = RawAssetGeneratorDataUrlFnArgs::try_from(ctx)?;
+        handle.block_on(b.call(ctx))
+      })),
     }
   }
 }
@@ -1,11 +1,17 @@
-use napi::Either;
+use std::sync::Arc;
+
+use napi::{bindgen_prelude::Buffer, Either};
 use napi_derive::napi;
 use rspack_binding_values::JsFilename;
+use rspack_core::{
+  ChunkLoadRetry, CustomHashFunction, Environment, HashFunction, LibraryAuxiliaryComment,
+  OutputOptions, TrustedTypes,
+};
 use rspack_core::{
   CrossOriginLoading, LibraryCustomUmdObject, LibraryName, LibraryNonUmdObject, LibraryOptions,
   PathInfo,
 };
-use rspack_core::{LibraryAuxiliaryComment, OutputOptions, TrustedTypes};
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
 
 #[derive(Debug)]
 #[napi(object)]
@@ -21,6 +27,56 @@ impl From<RawTrustedTypes> for TrustedTypes {
   }
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawChunkLoadRetry {
+  pub times: u32,
+  pub delay: u32,
+  pub public_paths: Vec<String>,
+}
+
+impl From<RawChunkLoadRetry> for ChunkLoadRetry {
+  fn from(value: RawChunkLoadRetry) -> Self {
+    Self {
+      times: value.times,
+      delay: value.delay,
+      public_paths: value.public_paths,
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawEnvironment {
+  pub arrow_function: Option<bool>,
+  pub big_int_literal: Option<bool>,
+  pub r#const: Option<bool>,
+  pub destructuring: Option<bool>,
+  pub dynamic_import: Option<bool>,
+  pub for_of: Option<bool>,
+  pub global_this: Option<bool>,
+  pub module: Option<bool>,
+  pub optional_chaining: Option<bool>,
+  pub template_literal: Option<bool>,
+}
+
+impl From<RawEnvironment> for Environment {
+  fn from(value: RawEnvironment) -> Self {
+    Self {
+      arrow_function: value.arrow_function.unwrap_or(false),
+      big_int_literal: value.big_int_literal.unwrap_or(false),
+      r#const: value.r#const.unwrap_or(false),
+      destructuring: value.destructuring.unwrap_or(false),
+      dynamic_import: value.dynamic_import.unwrap_or(false),
+      for_of: value.for_of.unwrap_or(false),
+      global_this: value.global_this.unwrap_or(false),
+      module: value.module.unwrap_or(false),
+      optional_chaining: value.optional_chaining.unwrap_or(false),
+      template_literal: value.template_literal.unwrap_or(false),
+    }
+  }
+}
+
 #[derive(Debug)]
 #[napi(object)]
 pub struct RawLibraryName {
@@ -149,13 +205,14 @@ pub struct RawOutputOptions {
   pub pathinfo: Either<bool, String>,
   pub clean: bool,
   pub public_path: String,
-  pub asset_module_filename: String,
+  pub asset_module_filename: JsFilename,
   pub wasm_loading: String,
   pub enabled_wasm_loading_types: Vec<String>,
   pub webassembly_module_filename: String,
   pub filename: JsFilename,
   pub chunk_filename: JsFilename,
   pub cross_origin_loading: RawCrossOriginLoading,
+  pub charset: bool,
   pub css_filename: JsFilename,
   pub css_chunk_filename: JsFilename,
   pub hot_update_main_filename: String,
@@ -173,8 +230,11 @@ pub struct RawOutputOptions {
   pub chunk_loading: String,
   pub enabled_chunk_loading_types: Option<Vec<String>>,
   pub trusted_types: Option<RawTrustedTypes>,
+  pub chunk_load_timeout: u32,
+  pub chunk_load_retry: Option<RawChunkLoadRetry>,
   pub source_map_filename: String,
-  pub hash_function: String,
+  #[napi(ts_type = "string | ((data: Buffer) => Buffer)")]
+  pub hash_function: Either<String, ThreadsafeFunction<Buffer, Buffer>>,
   pub hash_digest: String,
   pub hash_digest_length: u32,
   pub hash_salt: Option<String>,
@@ -184,6 +244,7 @@ pub struct RawOutputOptions {
   pub worker_public_path: String,
   #[napi(ts_type = r#""module" | "text/javascript" | "false""#)]
   pub script_type: String,
+  pub environment: RawEnvironment,
 }
 
 impl TryFrom<RawOutputOptions> for OutputOptions {
@@ -209,6 +270,7 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       filename: value.filename.into(),
       chunk_filename: value.chunk_filename.into(),
       cross_origin_loading: value.cross_origin_loading.into(),
+      charset: value.charset,
       css_filename: value.css_filename.into(),
       css_chunk_filename: value.css_chunk_filename.into(),
       hot_update_main_filename: value.hot_update_main_filename.into(),
@@ -222,9 +284,14 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       iife: value.iife,
       module: value.module,
       trusted_types: value.trusted_types.map(Into::into),
+      chunk_load_timeout: value.chunk_load_timeout,
+      chunk_load_retry: value.chunk_load_retry.map(Into::into),
       source_map_filename: value.source_map_filename.into(),
-      hash_function: value.hash_function.as_str().into(),
-      hash_digest: value.hash_digest.as_str().into(),
+      hash_function: match value.hash_function {
+        Either::A(name) => name.as_str().try_into()?,
+        Either::B(function) => HashFunction::Custom(Arc::new(JsHashFunction(function))),
+      },
+      hash_digest: value.hash_digest.as_str().try_into()?,
       hash_digest_length: value.hash_digest_length as usize,
       hash_salt: value.hash_salt.into(),
       async_chunks: value.async_chunks,
@@ -232,6 +299,21 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       worker_wasm_loading: value.worker_wasm_loading.as_str().into(),
       worker_public_path: value.worker_public_path,
       script_type: value.script_type,
+      environment: value.environment.into(),
     })
   }
 }
+
+/// A hash function implemented in JS, e.g. a hash of node's `crypto` or a custom hash class.
+#[derive(Debug)]
+struct JsHashFunction(ThreadsafeFunction<Buffer, Buffer>);
+
+impl CustomHashFunction for JsHashFunction {
+  fn digest(&self, data: &[u8]) -> Vec<u8> {
+    self
+      .0
+      .blocking_call_with_sync(data.to_vec().into())
+      .expect("should call the custom hash function")
+      .to_vec()
+  }
+}
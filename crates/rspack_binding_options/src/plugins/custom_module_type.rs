@@ -0,0 +1,191 @@
+use std::fmt::Debug;
+
+use rspack_core::{
+  rspack_sources::{
+    BoxSource, MapOptions, OriginalSource, Source, SourceExt, SourceMap, SourceMapSource,
+    SourceMapSourceOptions,
+  },
+  ApplyContext, ChunkGraph, CompilerOptions, GenerateContext, Module, ModuleGraph, ModuleType,
+  ParseContext, ParseResult, ParserAndGenerator, Plugin, PluginContext, SourceType,
+};
+use rspack_error::{error, Result, TWithDiagnosticArray};
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
+use rspack_plugin_javascript::parser_and_generator::JavaScriptParserAndGenerator;
+
+use crate::{
+  RawCustomModuleTypeFnCtx, RawCustomModuleTypeResult, RawCustomModuleTypeRspackPluginOptions,
+};
+
+type CustomModuleTypeFn = ThreadsafeFunction<RawCustomModuleTypeFnCtx, RawCustomModuleTypeResult>;
+
+fn call_custom_module_type_fn(
+  f: &CustomModuleTypeFn,
+  input: &BoxSource,
+  resource: &str,
+) -> Result<BoxSource> {
+  let ctx = RawCustomModuleTypeFnCtx {
+    source: input.source().into_owned(),
+    map: input
+      .map(&MapOptions::default())
+      .and_then(|map| map.to_json().ok()),
+    resource: resource.to_string(),
+  };
+  let RawCustomModuleTypeResult { code, map } = f.blocking_call_with_sync(ctx)?;
+  match map {
+    Some(map) => {
+      let source_map = SourceMap::from_json(&map)
+        .map_err(|e| error!("Invalid source map returned for {resource}: {e}"))?;
+      Ok(
+        SourceMapSource::new(SourceMapSourceOptions {
+          value: code,
+          name: resource.to_string(),
+          source_map,
+          original_source: Some(input.source().into_owned()),
+          inner_source_map: input.map(&MapOptions::default()),
+          remove_original_source: false,
+        })
+        .boxed(),
+      )
+    }
+    // Without a returned map, the mappings of the input can only be kept when it's untouched
+    None if code == input.source() => Ok(input.clone()),
+    None => Ok(OriginalSource::new(code, resource).boxed()),
+  }
+}
+
+/// Registers a module type whose source is turned into javascript by `parse`,
+/// and whose generated javascript can be post-processed by `generate`.
+pub(crate) struct CustomModuleTypeRspackPlugin {
+  module_type: ModuleType,
+  parse: Option<CustomModuleTypeFn>,
+  generate: Option<CustomModuleTypeFn>,
+}
+
+impl CustomModuleTypeRspackPlugin {
+  pub fn new(options: RawCustomModuleTypeRspackPluginOptions) -> Self {
+    Self {
+      module_type: ModuleType::from(options.r#type.as_str()),
+      parse: options.parse,
+      generate: options.generate,
+    }
+  }
+}
+
+impl Debug for CustomModuleTypeRspackPlugin {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("CustomModuleTypeRspackPlugin")
+      .field("module_type", &self.module_type)
+      .finish()
+  }
+}
+
+impl Plugin for CustomModuleTypeRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.CustomModuleTypeRspackPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let parse = self.parse.clone();
+    let generate = self.generate.clone();
+    ctx.context.register_parser_and_generator_builder(
+      self.module_type,
+      Box::new(move |_, _| {
+        Box::new(CustomModuleTypeParserAndGenerator {
+          parse: parse.clone(),
+          generate: generate.clone(),
+          inner: JavaScriptParserAndGenerator,
+        }) as Box<dyn ParserAndGenerator>
+      }),
+    );
+    Ok(())
+  }
+}
+
+struct CustomModuleTypeParserAndGenerator {
+  parse: Option<CustomModuleTypeFn>,
+  generate: Option<CustomModuleTypeFn>,
+  inner: JavaScriptParserAndGenerator,
+}
+
+impl Debug for CustomModuleTypeParserAndGenerator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("CustomModuleTypeParserAndGenerator")
+      .finish_non_exhaustive()
+  }
+}
+
+impl ParserAndGenerator for CustomModuleTypeParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    self.inner.source_types()
+  }
+
+  fn size(&self, module: &dyn Module, source_type: &SourceType) -> f64 {
+    self.inner.size(module, source_type)
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    let source = if let Some(parse) = &self.parse {
+      call_custom_module_type_fn(
+        parse,
+        &parse_context.source,
+        &parse_context.resource_data.resource,
+      )?
+    } else {
+      parse_context.source.clone()
+    };
+    // The converted source is parsed as `javascript/auto`, so fall back to the
+    // global javascript parser options when the module type has no own ones.
+    let compiler_options = parse_context.compiler_options;
+    let module_parser_options = parse_context
+      .module_parser_options
+      .filter(|options| options.get_javascript().is_some())
+      .or_else(|| {
+        compiler_options
+          .module
+          .parser
+          .as_ref()
+          .and_then(|parser| parser.get(&ModuleType::Js))
+      });
+    self.inner.parse(ParseContext {
+      source,
+      module_type: &ModuleType::Js,
+      module_parser_options,
+      ..parse_context
+    })
+  }
+
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let generated = self.inner.generate(source, module, generate_context)?;
+    let Some(generate) = &self.generate else {
+      return Ok(generated);
+    };
+    let resource = module
+      .as_normal_module()
+      .map(|module| module.resource_resolved_data().resource.clone())
+      .unwrap_or_default();
+    call_custom_module_type_fn(generate, &generated, &resource)
+  }
+
+  fn get_concatenation_bailout_reason(
+    &self,
+    module: &dyn Module,
+    mg: &ModuleGraph,
+    cg: &ChunkGraph,
+  ) -> Option<String> {
+    // the `generate` function works on the whole module, which is not possible
+    // inside a concatenated module
+    if self.generate.is_some() {
+      return Some("Module has a custom `generate` function".to_string());
+    }
+    self.inner.get_concatenation_bailout_reason(module, mg, cg)
+  }
+}
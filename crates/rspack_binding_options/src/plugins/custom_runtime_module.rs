@@ -0,0 +1,213 @@
+use std::fmt::Debug;
+
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  ApplyContext, ChunkUkey, Compilation, CompilationAdditionalTreeRuntimeRequirements,
+  CompilationRuntimeRequirementInTree, CompilerOptions, Plugin, PluginContext, RuntimeGlobals,
+  RuntimeModule, RuntimeModuleExt, RuntimeModuleStage,
+};
+use rspack_error::{error, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_identifier::Identifier;
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
+use rspack_util::source_map::SourceMapKind;
+
+use crate::{RawCustomRuntimeModuleFnCtx, RawCustomRuntimeModuleRspackPluginOptions};
+
+type CustomRuntimeModuleFn = ThreadsafeFunction<RawCustomRuntimeModuleFnCtx, String>;
+
+/// Adds a runtime module whose code is generated by a js function to the
+/// runtime chunks, either unconditionally or when one of the runtime
+/// requirements is used by the chunk tree.
+#[plugin]
+pub(crate) struct CustomRuntimeModuleRspackPlugin {
+  name: String,
+  stage: RuntimeModuleStage,
+  chunks: Option<Vec<String>>,
+  runtime_requirements: RuntimeGlobals,
+  generate: CustomRuntimeModuleFn,
+}
+
+impl CustomRuntimeModuleRspackPlugin {
+  pub fn new(options: RawCustomRuntimeModuleRspackPluginOptions) -> Result<Self> {
+    let stage = match options.stage.as_str() {
+      "normal" => RuntimeModuleStage::Normal,
+      "basic" => RuntimeModuleStage::Basic,
+      "attach" => RuntimeModuleStage::Attach,
+      "trigger" => RuntimeModuleStage::Trigger,
+      stage => return Err(error!("Unknown runtime module stage: {stage}")),
+    };
+    let mut runtime_requirements = RuntimeGlobals::default();
+    for requirement in &options.runtime_requirements {
+      let globals = RuntimeGlobals::all()
+        .iter()
+        .filter(|globals| globals.name() == requirement)
+        .collect::<RuntimeGlobals>();
+      if globals.is_empty() {
+        return Err(error!("Unknown runtime requirement: {requirement}"));
+      }
+      runtime_requirements.insert(globals);
+    }
+    Ok(Self::new_inner(
+      options.name,
+      stage,
+      options.chunks,
+      runtime_requirements,
+      options.generate,
+    ))
+  }
+
+  fn add_runtime_module(
+    &self,
+    compilation: &mut Compilation,
+    chunk_ukey: &ChunkUkey,
+  ) -> Result<()> {
+    let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+    if let Some(chunks) = &self.chunks
+      && !chunk
+        .name
+        .as_ref()
+        .is_some_and(|name| chunks.contains(name))
+    {
+      return Ok(());
+    }
+    let id = Identifier::from(format!("webpack/runtime/{}", self.name));
+    // the runtime requirements are collected repeatedly for the same chunk
+    let added = compilation
+      .chunk_graph
+      .get_chunk_runtime_modules_iterable(chunk_ukey)
+      .any(|identifier| {
+        compilation
+          .runtime_modules
+          .get(identifier)
+          .is_some_and(|module| module.name() == id)
+      });
+    if added {
+      return Ok(());
+    }
+    compilation.add_runtime_module(
+      chunk_ukey,
+      CustomRuntimeModule::new(id, self.stage, self.generate.clone()).boxed(),
+    )
+  }
+}
+
+impl Debug for CustomRuntimeModuleRspackPlugin {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("CustomRuntimeModuleRspackPlugin")
+      .field("name", &self.name)
+      .field("stage", &self.stage)
+      .field("chunks", &self.chunks)
+      .field("runtime_requirements", &self.runtime_requirements)
+      .finish()
+  }
+}
+
+#[plugin_hook(CompilationAdditionalTreeRuntimeRequirements for CustomRuntimeModuleRspackPlugin)]
+fn additional_tree_runtime_requirements(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _runtime_requirements: &mut RuntimeGlobals,
+) -> Result<()> {
+  if self.runtime_requirements.is_empty() {
+    self.add_runtime_module(compilation, chunk_ukey)?;
+  }
+  Ok(())
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for CustomRuntimeModuleRspackPlugin)]
+fn runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &RuntimeGlobals,
+  _runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.intersects(self.runtime_requirements) {
+    self.add_runtime_module(compilation, chunk_ukey)?;
+  }
+  Ok(None)
+}
+
+impl Plugin for CustomRuntimeModuleRspackPlugin {
+  fn name(&self) -> &'static str {
+    "CustomRuntimeModuleRspackPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .additional_tree_runtime_requirements
+      .tap(additional_tree_runtime_requirements::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(runtime_requirements_in_tree::new(self));
+    Ok(())
+  }
+}
+
+#[impl_runtime_module]
+struct CustomRuntimeModule {
+  id: Identifier,
+  stage: RuntimeModuleStage,
+  chunk: Option<ChunkUkey>,
+  generate: CustomRuntimeModuleFn,
+}
+
+impl CustomRuntimeModule {
+  fn new(id: Identifier, stage: RuntimeModuleStage, generate: CustomRuntimeModuleFn) -> Self {
+    Self {
+      id,
+      stage,
+      chunk: None,
+      generate,
+      source_map_kind: SourceMapKind::empty(),
+      custom_source: None,
+    }
+  }
+}
+
+impl Debug for CustomRuntimeModule {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("CustomRuntimeModule")
+      .field("id", &self.id)
+      .field("stage", &self.stage)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Eq for CustomRuntimeModule {}
+
+impl RuntimeModule for CustomRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    self.stage
+  }
+
+  fn generate(&self, compilation: &Compilation) -> Result<BoxSource> {
+    let chunk = self
+      .chunk
+      .map(|chunk_ukey| compilation.chunk_by_ukey.expect_get(&chunk_ukey));
+    let ctx = RawCustomRuntimeModuleFnCtx {
+      chunk_id: chunk.and_then(|chunk| chunk.id.clone()),
+      chunk_name: chunk.and_then(|chunk| chunk.name.clone()),
+    };
+    Ok(RawSource::from(self.generate.blocking_call_with_sync(ctx)?).boxed())
+  }
+}
@@ -1,4 +1,8 @@
 mod css_extract_additional_data;
+mod custom_module_type;
+mod custom_runtime_module;
 mod js_loader_resolver;
 pub(super) use css_extract_additional_data::CssExtractRspackAdditionalDataPlugin;
+pub(super) use custom_module_type::CustomModuleTypeRspackPlugin;
+pub(super) use custom_runtime_module::CustomRuntimeModuleRspackPlugin;
 pub(super) use js_loader_resolver::JsLoaderResolverPlugin;
@@ -38,6 +38,9 @@ pub struct JsAssetInfo {
   pub javascript_module: Option<bool>,
   /// related object to other assets, keyed by type of relation (only points from parent to child)
   pub related: JsAssetInfoRelated,
+  /// unstable field for plugins to attach additional information to the asset
+  #[napi(ts_type = "Record<string, any>")]
+  pub extras: serde_json::Map<String, serde_json::Value>,
 }
 
 impl From<JsAssetInfo> for rspack_core::AssetInfo {
@@ -53,6 +56,7 @@ impl From<JsAssetInfo> for rspack_core::AssetInfo {
       version: String::from(""),
       source_filename: i.source_filename,
       javascript_module: i.javascript_module,
+      extras: i.extras,
     }
   }
 }
@@ -83,6 +87,7 @@ impl From<rspack_core::AssetInfo> for JsAssetInfo {
       content_hash: info.content_hash.into_iter().collect(),
       source_filename: info.source_filename,
       javascript_module: info.javascript_module,
+      extras: info.extras,
     }
   }
 }
@@ -14,7 +14,7 @@ pub fn get_chunk_modules(js_chunk_ukey: u32, compilation: &JsCompilation) -> Vec
 
   return modules
     .iter()
-    .filter_map(|module| module.to_js_module().ok())
+    .filter_map(|module| module.to_js_module_with_graph(&module_graph).ok())
     .collect::<Vec<_>>();
 }
 
@@ -28,7 +28,7 @@ pub fn get_chunk_entry_modules(js_chunk_ukey: u32, compilation: &JsCompilation)
   return modules
     .iter()
     .filter_map(|module| module_graph.module_by_identifier(module))
-    .filter_map(|module| module.to_js_module().ok())
+    .filter_map(|module| module.to_js_module_with_graph(&module_graph).ok())
     .collect::<Vec<_>>();
 }
 
@@ -59,15 +59,16 @@ pub fn get_chunk_modules_iterable_by_source_type(
   compilation: &JsCompilation,
 ) -> Result<Vec<JsModule>> {
   let compilation = &compilation.0;
+  let module_graph = compilation.get_module_graph();
   Ok(
     compilation
       .chunk_graph
       .get_chunk_modules_iterable_by_source_type(
         &ChunkUkey::from(js_chunk_ukey as usize),
         SourceType::from(source_type.as_str()),
-        &compilation.get_module_graph(),
+        &module_graph,
       )
-      .filter_map(|module| module.to_js_module().ok())
+      .filter_map(|module| module.to_js_module_with_graph(&module_graph).ok())
       .collect(),
   )
 }
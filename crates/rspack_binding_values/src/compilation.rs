@@ -151,12 +151,11 @@ impl JsCompilation {
 
   #[napi]
   pub fn get_modules(&self) -> Vec<JsModule> {
-    self
-      .0
-      .get_module_graph()
+    let module_graph = self.0.get_module_graph();
+    module_graph
       .modules()
       .values()
-      .filter_map(|module| module.to_js_module().ok())
+      .filter_map(|module| module.to_js_module_with_graph(&module_graph).ok())
       .collect::<Vec<_>>()
   }
 
@@ -464,11 +463,12 @@ impl JsCompilation {
     callback: JsFunction,
   ) -> Result<()> {
     callbackify(env, callback, async {
-      let module_executor = self
-        .0
-        .module_executor
-        .as_ref()
-        .expect("should have module executor");
+      let Some(module_executor) = self.0.module_executor.as_ref() else {
+        return Err(Error::new(
+          napi::Status::GenericFailure,
+          "The compilation is not able to import modules".to_string(),
+        ));
+      };
       let result = module_executor
         .import_module(
           request,
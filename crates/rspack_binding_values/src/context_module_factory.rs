@@ -5,6 +5,8 @@ use napi_derive::napi;
 pub struct JsContextModuleFactoryBeforeResolveData {
   pub context: String,
   pub request: Option<String>,
+  pub recursive: bool,
+  pub reg_exp: Option<String>,
 }
 
 pub type JsContextModuleFactoryBeforeResolveResult =
@@ -15,6 +17,7 @@ pub struct JsContextModuleFactoryAfterResolveData {
   pub resource: String,
   pub context: String,
   pub request: String,
+  pub recursive: bool,
   pub reg_exp: Option<String>,
 }
 
@@ -1,5 +1,8 @@
 use napi_derive::napi;
-use rspack_core::Module;
+use rspack_core::{
+  BuildInfo, BuildMeta, BuildMetaDefaultObject, BuildMetaExportsType, ExportsArgument, Module,
+  ModuleArgument, ModuleGraph,
+};
 use rspack_napi::napi::bindgen_prelude::*;
 
 use super::{JsCompatSource, ToJsCompatSource};
@@ -14,10 +17,162 @@ pub struct JsModule {
   pub module_identifier: String,
   pub name_for_condition: Option<String>,
   pub raw_request: Option<String>,
+  pub build_info: Option<JsBuildInfo>,
+  pub build_meta: Option<JsBuildMeta>,
+}
+
+#[napi(object)]
+pub struct JsBuildInfo {
+  pub cacheable: bool,
+  pub strict: bool,
+  /// Custom fields set by plugins
+  #[napi(ts_type = "Record<string, any>")]
+  pub extras: serde_json::Map<String, serde_json::Value>,
+}
+
+impl From<&BuildInfo> for JsBuildInfo {
+  fn from(build_info: &BuildInfo) -> Self {
+    Self {
+      cacheable: build_info.cacheable,
+      strict: build_info.strict,
+      extras: build_info.extras.clone(),
+    }
+  }
+}
+
+impl JsBuildInfo {
+  pub fn apply(self, build_info: &mut BuildInfo) {
+    build_info.cacheable = self.cacheable;
+    build_info.strict = self.strict;
+    build_info.extras = self.extras;
+  }
+}
+
+#[napi(object)]
+pub struct JsBuildMeta {
+  pub strict_harmony_module: bool,
+  pub has_top_level_await: bool,
+  pub es_module: bool,
+  #[napi(ts_type = "'unset' | 'default' | 'namespace' | 'flagged' | 'dynamic'")]
+  pub exports_type: String,
+  #[napi(ts_type = "'false' | 'redirect' | 'redirect-warn'")]
+  pub default_object: String,
+  #[napi(ts_type = "'module' | '__webpack_module__'")]
+  pub module_argument: String,
+  #[napi(ts_type = "'exports' | '__webpack_exports__'")]
+  pub exports_argument: String,
+  pub side_effect_free: Option<bool>,
+  /// Whether the module is an async module, only known once the modules are
+  /// finished, it's not synced back to the module
+  pub is_async: Option<bool>,
+}
+
+impl From<&BuildMeta> for JsBuildMeta {
+  fn from(build_meta: &BuildMeta) -> Self {
+    Self {
+      strict_harmony_module: build_meta.strict_harmony_module,
+      has_top_level_await: build_meta.has_top_level_await,
+      es_module: build_meta.esm,
+      exports_type: match build_meta.exports_type {
+        BuildMetaExportsType::Unset => "unset",
+        BuildMetaExportsType::Default => "default",
+        BuildMetaExportsType::Namespace => "namespace",
+        BuildMetaExportsType::Flagged => "flagged",
+        BuildMetaExportsType::Dynamic => "dynamic",
+      }
+      .to_string(),
+      default_object: match build_meta.default_object {
+        BuildMetaDefaultObject::False => "false",
+        BuildMetaDefaultObject::Redirect => "redirect",
+        BuildMetaDefaultObject::RedirectWarn => "redirect-warn",
+      }
+      .to_string(),
+      module_argument: build_meta.module_argument.to_string(),
+      exports_argument: build_meta.exports_argument.to_string(),
+      side_effect_free: build_meta.side_effect_free,
+      is_async: None,
+    }
+  }
+}
+
+impl JsBuildMeta {
+  pub fn apply(self, build_meta: &mut BuildMeta) -> rspack_error::Result<()> {
+    build_meta.strict_harmony_module = self.strict_harmony_module;
+    build_meta.has_top_level_await = self.has_top_level_await;
+    build_meta.esm = self.es_module;
+    build_meta.exports_type = match self.exports_type.as_str() {
+      "unset" => BuildMetaExportsType::Unset,
+      "default" => BuildMetaExportsType::Default,
+      "namespace" => BuildMetaExportsType::Namespace,
+      "flagged" => BuildMetaExportsType::Flagged,
+      "dynamic" => BuildMetaExportsType::Dynamic,
+      other => {
+        return Err(rspack_error::error!(
+          "Invalid buildMeta.exportsType: {other}"
+        ))
+      }
+    };
+    build_meta.default_object = match self.default_object.as_str() {
+      "false" => BuildMetaDefaultObject::False,
+      "redirect" => BuildMetaDefaultObject::Redirect,
+      "redirect-warn" => BuildMetaDefaultObject::RedirectWarn,
+      other => {
+        return Err(rspack_error::error!(
+          "Invalid buildMeta.defaultObject: {other}"
+        ))
+      }
+    };
+    build_meta.module_argument = match self.module_argument.as_str() {
+      "module" => ModuleArgument::Module,
+      "__webpack_module__" => ModuleArgument::WebpackModule,
+      other => {
+        return Err(rspack_error::error!(
+          "Invalid buildMeta.moduleArgument: {other}"
+        ))
+      }
+    };
+    build_meta.exports_argument = match self.exports_argument.as_str() {
+      "exports" => ExportsArgument::Exports,
+      "__webpack_exports__" => ExportsArgument::WebpackExports,
+      other => {
+        return Err(rspack_error::error!(
+          "Invalid buildMeta.exportsArgument: {other}"
+        ))
+      }
+    };
+    build_meta.side_effect_free = self.side_effect_free;
+    Ok(())
+  }
+}
+
+/// The build info and build meta modified by the JS plugins
+#[napi(object)]
+pub struct JsModuleBuildResult {
+  pub build_info: Option<JsBuildInfo>,
+  pub build_meta: Option<JsBuildMeta>,
+}
+
+impl JsModuleBuildResult {
+  pub fn apply(self, module: &mut dyn Module) -> rspack_error::Result<()> {
+    if let Some(js_build_info) = self.build_info {
+      let mut build_info = module.build_info().cloned().unwrap_or_default();
+      js_build_info.apply(&mut build_info);
+      module.set_build_info(build_info);
+    }
+    if let Some(js_build_meta) = self.build_meta {
+      let mut build_meta = module.build_meta().cloned().unwrap_or_default();
+      js_build_meta.apply(&mut build_meta)?;
+      module.set_build_meta(build_meta);
+    }
+    Ok(())
+  }
 }
 
 pub trait ToJsModule {
   fn to_js_module(&self) -> Result<JsModule>;
+
+  /// Same as `to_js_module`, with the async flag of the module graph
+  fn to_js_module_with_graph(&self, module_graph: &ModuleGraph) -> Result<JsModule>;
 }
 
 impl ToJsModule for dyn Module {
@@ -30,6 +185,8 @@ impl ToJsModule for dyn Module {
     let name_for_condition = || self.name_for_condition().map(|s| s.to_string());
     let module_identifier = || self.identifier().to_string();
     let context = || self.get_context().map(|c| c.to_string());
+    let build_info = || self.build_info().map(JsBuildInfo::from);
+    let build_meta = || self.build_meta().map(JsBuildMeta::from);
 
     self
       .try_as_normal_module()
@@ -46,6 +203,8 @@ impl ToJsModule for dyn Module {
         module_identifier: module_identifier(),
         name_for_condition: name_for_condition(),
         raw_request: Some(normal_module.raw_request().to_string()),
+        build_info: build_info(),
+        build_meta: build_meta(),
       })
       .or_else(|_| {
         self.try_as_raw_module().map(|_| JsModule {
@@ -55,6 +214,8 @@ impl ToJsModule for dyn Module {
           module_identifier: module_identifier(),
           name_for_condition: name_for_condition(),
           raw_request: None,
+          build_info: build_info(),
+          build_meta: build_meta(),
         })
       })
       .or_else(|_| {
@@ -65,6 +226,8 @@ impl ToJsModule for dyn Module {
           module_identifier: module_identifier(),
           name_for_condition: name_for_condition(),
           raw_request: None,
+          build_info: build_info(),
+          build_meta: build_meta(),
         })
       })
       .or_else(|_| {
@@ -75,6 +238,8 @@ impl ToJsModule for dyn Module {
           module_identifier: module_identifier(),
           name_for_condition: name_for_condition(),
           raw_request: None,
+          build_info: build_info(),
+          build_meta: build_meta(),
         })
       })
       .or_else(|_| {
@@ -82,10 +247,20 @@ impl ToJsModule for dyn Module {
           context: context(),
           module_identifier: module_identifier(),
           name_for_condition: name_for_condition(),
+          build_info: build_info(),
+          build_meta: build_meta(),
           ..Default::default()
         })
       })
   }
+
+  fn to_js_module_with_graph(&self, module_graph: &ModuleGraph) -> Result<JsModule> {
+    let mut module = self.to_js_module()?;
+    if let Some(build_meta) = &mut module.build_meta {
+      build_meta.is_async = module_graph.is_async(&self.identifier());
+    }
+    Ok(module)
+  }
 }
 
 #[napi(object)]
@@ -13,6 +13,7 @@ use super::{JsCompilation, ToJsCompatSource};
 #[napi(object)]
 #[derive(Debug)]
 pub struct JsStatsError {
+  pub code: String,
   pub message: String,
   pub formatted: String,
   pub module_identifier: Option<String>,
@@ -23,6 +24,7 @@ pub struct JsStatsError {
 impl From<rspack_core::StatsError> for JsStatsError {
   fn from(stats: rspack_core::StatsError) -> Self {
     Self {
+      code: stats.code,
       message: stats.message,
       formatted: stats.formatted,
       module_identifier: stats.module_identifier,
@@ -34,6 +36,7 @@ impl From<rspack_core::StatsError> for JsStatsError {
 
 #[napi(object)]
 pub struct JsStatsWarning {
+  pub code: String,
   pub message: String,
   pub formatted: String,
   pub module_identifier: Option<String>,
@@ -44,6 +47,7 @@ pub struct JsStatsWarning {
 impl From<rspack_core::StatsWarning> for JsStatsWarning {
   fn from(stats: rspack_core::StatsWarning) -> Self {
     Self {
+      code: stats.code,
       message: stats.message,
       formatted: stats.formatted,
       module_identifier: stats.module_identifier,
@@ -229,6 +233,7 @@ pub struct JsStatsModule {
   pub id: Option<String>,
   pub chunks: Vec<Option<String>>,
   pub size: f64,
+  pub sizes: Vec<JsStatsSize>,
   pub issuer: Option<String>,
   pub issuer_name: Option<String>,
   pub issuer_id: Option<String>,
@@ -236,8 +241,10 @@ pub struct JsStatsModule {
   pub name_for_condition: Option<String>,
   pub reasons: Option<Vec<JsStatsModuleReason>>,
   pub assets: Option<Vec<String>>,
+  pub modules: Option<Vec<JsStatsModule>>,
   pub source: Option<Either<String, Buffer>>,
   pub profile: Option<JsStatsModuleProfile>,
+  pub depth: Option<u32>,
   pub orphan: bool,
   pub provided_exports: Option<Vec<String>>,
   pub used_exports: Option<Either<String, Vec<String>>>,
@@ -265,6 +272,7 @@ impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
       r#type: stats.r#type,
       name: stats.name,
       size: stats.size,
+      sizes: stats.sizes.into_iter().map(Into::into).collect(),
       chunks: stats.chunks,
       module_type: stats.module_type.as_str().to_string(),
       identifier: stats.identifier.to_string(),
@@ -278,8 +286,13 @@ impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
         .reasons
         .map(|i| i.into_iter().map(Into::into).collect()),
       assets: stats.assets,
+      modules: stats
+        .modules
+        .map(|modules| modules.into_iter().map(TryInto::try_into).collect())
+        .transpose()?,
       source,
       profile: stats.profile.map(|p| p.into()),
+      depth: stats.depth.map(|d| d as u32),
       orphan: stats.orphan,
       provided_exports: stats.provided_exports,
       used_exports: stats.used_exports.map(|used_exports| match used_exports {
@@ -324,6 +337,21 @@ impl From<rspack_core::StatsMillisecond> for JsStatsMillisecond {
   }
 }
 
+#[napi(object)]
+pub struct JsStatsSize {
+  pub source_type: String,
+  pub size: f64,
+}
+
+impl From<rspack_core::StatsSourceTypeSize> for JsStatsSize {
+  fn from(stats: rspack_core::StatsSourceTypeSize) -> Self {
+    Self {
+      source_type: stats.source_type,
+      size: stats.size,
+    }
+  }
+}
+
 #[napi(object)]
 pub struct JsStatsModuleIssuer {
   pub identifier: String,
@@ -362,6 +390,29 @@ impl From<rspack_core::StatsModuleReason> for JsStatsModuleReason {
   }
 }
 
+#[napi(object)]
+pub struct JsOriginRecord {
+  pub module: String,
+  pub module_id: String,
+  pub module_identifier: String,
+  pub module_name: String,
+  pub loc: String,
+  pub request: String,
+}
+
+impl From<rspack_core::StatsOriginRecord> for JsOriginRecord {
+  fn from(stats: rspack_core::StatsOriginRecord) -> Self {
+    Self {
+      module: stats.module,
+      module_id: stats.module_id,
+      module_identifier: stats.module_identifier,
+      module_name: stats.module_name,
+      loc: stats.loc,
+      request: stats.request,
+    }
+  }
+}
+
 #[napi(object)]
 pub struct JsStatsChunk {
   pub r#type: &'static str,
@@ -372,10 +423,12 @@ pub struct JsStatsChunk {
   pub initial: bool,
   pub names: Vec<String>,
   pub size: f64,
+  pub sizes: Vec<JsStatsSize>,
   pub modules: Option<Vec<JsStatsModule>>,
   pub parents: Option<Vec<String>>,
   pub children: Option<Vec<String>>,
   pub siblings: Option<Vec<String>>,
+  pub origins: Option<Vec<JsOriginRecord>>,
   pub children_by_order: HashMap<String, Vec<String>>,
 }
 
@@ -391,6 +444,7 @@ impl TryFrom<rspack_core::StatsChunk<'_>> for JsStatsChunk {
       initial: stats.initial,
       names: stats.names,
       size: stats.size,
+      sizes: stats.sizes.into_iter().map(Into::into).collect(),
       modules: stats
         .modules
         .map(|i| i.into_iter().map(|m| m.try_into()).collect::<Result<_>>())
@@ -398,6 +452,9 @@ impl TryFrom<rspack_core::StatsChunk<'_>> for JsStatsChunk {
       parents: stats.parents,
       children: stats.children,
       siblings: stats.siblings,
+      origins: stats
+        .origins
+        .map(|origins| origins.into_iter().map(Into::into).collect()),
       children_by_order: stats
         .children_by_order
         .iter()
@@ -521,6 +578,7 @@ impl JsStats {
     &self,
     chunk_modules: bool,
     chunks_relations: bool,
+    chunk_origins: bool,
     reasons: bool,
     module_assets: bool,
     nested_modules: bool,
@@ -533,6 +591,7 @@ impl JsStats {
       .get_chunks(
         chunk_modules,
         chunks_relations,
+        chunk_origins,
         reasons,
         module_assets,
         nested_modules,
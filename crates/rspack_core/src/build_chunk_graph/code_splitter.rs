@@ -262,6 +262,18 @@ impl<'me> CodeSplitter<'me> {
             .cloned()
         })
         .collect::<Vec<_>>();
+      let requests = entry_data
+        .dependencies
+        .iter()
+        .filter_map(|dep| {
+          self
+            .compilation
+            .get_module_graph()
+            .dependency_by_id(dep)
+            .and_then(|dep| dep.as_module_dependency())
+            .map(|dep| dep.user_request().to_string())
+        })
+        .collect::<Vec<_>>();
 
       let chunk_ukey = Compilation::add_named_chunk(
         name.to_string(),
@@ -307,6 +319,9 @@ impl<'me> CodeSplitter<'me> {
       }
       entrypoint.set_entry_point_chunk(chunk.ukey);
       entrypoint.connect_chunk(chunk);
+      for request in requests {
+        entrypoint.add_origin(None, None, Some(request));
+      }
 
       self
         .compilation
@@ -1194,6 +1209,27 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
       cgi
     };
 
+    if let Some(origin_group) = c.or(entrypoint) {
+      let (module, loc, request) = {
+        let module_graph = self.compilation.get_module_graph();
+        let block = module_graph
+          .block_by_id(&block_id)
+          .expect("should have block");
+        let request = block
+          .get_dependencies()
+          .first()
+          .and_then(|dep| module_graph.dependency_by_id(dep))
+          .and_then(|dep| dep.as_module_dependency())
+          .map(|dep| dep.user_request().to_string());
+        (*block.parent(), block.loc().copied(), request)
+      };
+      self
+        .compilation
+        .chunk_group_by_ukey
+        .expect_get_mut(&origin_group)
+        .add_origin(Some(module), loc, request);
+    }
+
     if let Some(c) = c {
       let connect_list = self
         .queue_connect
@@ -14,7 +14,8 @@ mod snapshot;
 mod storage;
 pub use local::*;
 use occasion::{
-  BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion, ResolveModuleOccasion,
+  BuildModuleOccasion, ChunkHashOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion,
+  ResolveModuleOccasion,
 };
 use snapshot::SnapshotManager;
 use storage::new_storage;
@@ -27,6 +28,7 @@ pub struct Cache {
   pub build_module_occasion: BuildModuleOccasion,
   pub code_generate_occasion: CodeGenerateOccasion,
   pub create_chunk_assets_occasion: CreateChunkAssetsOccasion,
+  pub chunk_hash_occasion: ChunkHashOccasion,
 }
 
 impl Cache {
@@ -45,6 +47,7 @@ impl Cache {
       ),
       code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
       create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
+      chunk_hash_occasion: ChunkHashOccasion::new(new_storage(&options.cache)),
     }
   }
 
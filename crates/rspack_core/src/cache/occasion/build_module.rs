@@ -8,8 +8,8 @@ use rspack_identifier::Identifier;
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
   cache::storage,
-  BoxModule, BuildExtraDataType, BuildInfo, BuildMeta, BuildResult, DependencyTemplate, Module,
-  ModuleDependency, NormalModuleSource,
+  BoxModule, BuildExtraDataType, BuildInfo, BuildMeta, BuildResult, CompilerOptions,
+  DependencyTemplate, Module, ModuleDependency, NormalModuleSource,
 };
 
 #[derive(Debug, Clone)]
@@ -57,6 +57,7 @@ impl BuildModuleOccasion {
   pub async fn use_cache<'a, G, F>(
     &self,
     module: &'a mut BoxModule,
+    compiler_options: &CompilerOptions,
     generator: G,
   ) -> Result<(Result<TWithDiagnosticArray<BuildResult>>, bool)>
   where
@@ -85,7 +86,16 @@ impl BuildModuleOccasion {
         } else {
           false
         };
-        if valid {
+        // the files are unchanged, but a value the module is built with may be changed
+        let values_changed = module_data
+          .as_ref()
+          .and_then(|module_data| module_data.build_info.as_ref())
+          .is_some_and(|build_info| {
+            compiler_options
+              .builtins
+              .is_value_dependencies_changed(build_info)
+          });
+        if valid && !values_changed {
           if let Some(module) = module.as_normal_module_mut() {
             if let Some(module_data) = module_data {
               *module.source_mut() = module_data.source;
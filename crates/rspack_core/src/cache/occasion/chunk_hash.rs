@@ -0,0 +1,84 @@
+use std::hash::Hash;
+
+use futures::Future;
+use rspack_error::Result;
+use rspack_hash::{RspackHash, RspackHashDigest};
+use rspack_identifier::Identifier;
+
+use crate::{cache::storage, Chunk, ChunkContentHash, Compilation};
+
+type Storage = dyn storage::Storage<(RspackHashDigest, ChunkContentHash)>;
+
+#[derive(Debug)]
+pub struct ChunkHashOccasion {
+  storage: Option<Box<Storage>>,
+}
+
+impl ChunkHashOccasion {
+  pub fn new(storage: Option<Box<Storage>>) -> Self {
+    Self { storage }
+  }
+
+  /// Reuses the content hashes of the chunk with the same id from the last
+  /// compilation, unless the chunk hash or the module ids of the chunk have
+  /// changed.
+  ///
+  /// The chunk hash is always computed by the caller, so the inputs of every
+  /// `chunkHash` tap are part of the fingerprint. The chunks with a runtime or
+  /// entry modules never use this, as their content hashes also depend on the
+  /// startup code and the ids of the other chunks.
+  pub async fn use_cache<G, F>(
+    &self,
+    compilation: &Compilation,
+    chunk: &Chunk,
+    chunk_hash: &RspackHashDigest,
+    generator: G,
+  ) -> Result<(ChunkContentHash, bool)>
+  where
+    G: FnOnce() -> F,
+    F: Future<Output = Result<ChunkContentHash>>,
+  {
+    let storage = match &self.storage {
+      Some(s) => s,
+      // no cache return directly
+      None => return Ok((generator().await?, false)),
+    };
+    let Some(chunk_id) = &chunk.id else {
+      return Ok((generator().await?, false));
+    };
+    if chunk.has_runtime(&compilation.chunk_group_by_ukey)
+      || compilation
+        .chunk_graph
+        .get_number_of_entry_modules(&chunk.ukey)
+        > 0
+    {
+      return Ok((generator().await?, false));
+    }
+
+    // the module ids are part of the content hash but not the chunk hash
+    let mut hasher = RspackHash::from(&compilation.options.output);
+    chunk_hash.hash(&mut hasher);
+    for module in compilation
+      .chunk_graph
+      .get_ordered_chunk_modules(&chunk.ukey, &compilation.get_module_graph())
+    {
+      compilation
+        .chunk_graph
+        .get_module_id(module.identifier())
+        .hash(&mut hasher);
+    }
+    let fingerprint = hasher.digest(&compilation.options.output.hash_digest);
+
+    let id = Identifier::from(chunk_id.as_str());
+    if let Some((cached_fingerprint, result)) = storage.get(&id)
+      && cached_fingerprint == fingerprint
+    {
+      return Ok((result, true));
+    }
+
+    // run generator and save to cache
+    let result = generator().await?;
+    storage.set(id, (fingerprint, result.clone()));
+    Ok((result, false))
+  }
+}
@@ -338,6 +338,20 @@ impl ChunkGraph {
       })
   }
 
+  pub fn get_chunk_modules_sizes(
+    &self,
+    chunk: &ChunkUkey,
+    module_graph: &ModuleGraph,
+  ) -> HashMap<SourceType, f64> {
+    let mut sizes = HashMap::<SourceType, f64>::default();
+    for module in self.get_chunk_modules(chunk, module_graph) {
+      for source_type in module.source_types() {
+        *sizes.entry(*source_type).or_default() += module.size(source_type);
+      }
+    }
+    sizes
+  }
+
   pub fn get_number_of_chunk_modules(&self, chunk: &ChunkUkey) -> usize {
     let cgc = self.get_chunk_graph_chunk(chunk);
     cgc.modules.len()
@@ -10,9 +10,9 @@ use rustc_hash::FxHashSet as HashSet;
 use crate::update_hash::{UpdateHashContext, UpdateRspackHash};
 use crate::ChunkGraph;
 use crate::{
-  get_chunk_group_from_ukey, AsyncDependenciesBlockIdentifier, BoxModule, ChunkByUkey, ChunkGroup,
-  ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, Compilation, ExportsHash, ModuleIdentifier,
-  RuntimeGlobals, RuntimeSpec, RuntimeSpecMap, RuntimeSpecSet,
+  get_chunk_group_from_ukey, hashable_identifier, AsyncDependenciesBlockIdentifier, BoxModule,
+  ChunkByUkey, ChunkGroup, ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, Compilation, ExportsHash,
+  ModuleIdentifier, RuntimeGlobals, RuntimeSpec, RuntimeSpecMap, RuntimeSpecSet,
 };
 
 #[derive(Debug, Clone, Default)]
@@ -179,7 +179,7 @@ impl ChunkGraph {
 
     let process_module_graph_module = |module: &BoxModule, strict: Option<bool>| -> u64 {
       let mut hasher = DefaultHasher::new();
-      module.identifier().dyn_hash(&mut hasher);
+      hashable_identifier(&compilation.options, &module.identifier()).dyn_hash(&mut hasher);
       module.source_types().dyn_hash(&mut hasher);
       module_graph
         .is_async(&module.identifier())
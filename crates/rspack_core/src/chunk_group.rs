@@ -10,7 +10,7 @@ use crate::{
   get_chunk_from_ukey, Chunk, ChunkByUkey, ChunkGroupByUkey, ChunkGroupUkey, FilenameTemplate,
 };
 use crate::{ChunkLoading, ChunkUkey, Compilation};
-use crate::{LibraryOptions, ModuleIdentifier, PublicPath};
+use crate::{DependencyLocation, LibraryOptions, ModuleIdentifier, PublicPath};
 
 impl DatabaseItem for ChunkGroup {
   fn ukey(&self) -> rspack_database::Ukey<Self> {
@@ -18,6 +18,14 @@ impl DatabaseItem for ChunkGroup {
   }
 }
 
+/// Where a chunk group is loaded from, an entry or a block of a module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OriginRecord {
+  pub module: Option<ModuleIdentifier>,
+  pub loc: Option<DependencyLocation>,
+  pub request: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ChunkGroup {
   pub ukey: ChunkGroupUkey,
@@ -29,6 +37,7 @@ pub struct ChunkGroup {
   pub(crate) module_post_order_indices: IdentifierMap<usize>,
   pub(crate) children: HashSet<ChunkGroupUkey>,
   async_entrypoints: HashSet<ChunkGroupUkey>,
+  pub origins: Vec<OriginRecord>,
   // ChunkGroupInfo
   pub(crate) next_pre_order_index: usize,
   pub(crate) next_post_order_index: usize,
@@ -47,6 +56,7 @@ impl ChunkGroup {
       parents: Default::default(),
       children: Default::default(),
       async_entrypoints: Default::default(),
+      origins: Default::default(),
       kind,
       next_pre_order_index: 0,
       next_post_order_index: 0,
@@ -68,6 +78,19 @@ impl ChunkGroup {
       .copied()
   }
 
+  pub fn add_origin(
+    &mut self,
+    module: Option<ModuleIdentifier>,
+    loc: Option<DependencyLocation>,
+    request: Option<String>,
+  ) {
+    self.origins.push(OriginRecord {
+      module,
+      loc,
+      request,
+    });
+  }
+
   pub fn get_files(&self, chunk_by_ukey: &ChunkByUkey) -> Vec<String> {
     self
       .chunks
@@ -33,21 +33,30 @@ impl CodeGenerationDataUrl {
 #[derive(Clone, Debug)]
 pub struct CodeGenerationDataFilename {
   filename: String,
+  public_filename: String,
   public_path: PublicPath,
 }
 
 impl CodeGenerationDataFilename {
-  pub fn new(filename: String, public_path: PublicPath) -> Self {
+  pub fn new(filename: String, public_filename: String, public_path: PublicPath) -> Self {
     Self {
       filename,
+      public_filename,
       public_path,
     }
   }
 
+  /// The path where the asset is emitted, relative to `output.path`.
   pub fn filename(&self) -> &str {
     &self.filename
   }
 
+  /// The path of the asset relative to `public_path`, which may differ from
+  /// `filename` when `generator.outputPath` is used.
+  pub fn public_filename(&self) -> &str {
+    &self.public_filename
+  }
+
   pub fn public_path(&self) -> &PublicPath {
     &self.public_path
   }
@@ -95,6 +104,9 @@ pub struct CodeGenerationResult {
   pub chunk_init_fragments: ChunkInitFragments,
   pub runtime_requirements: RuntimeGlobals,
   pub hash: Option<RspackHashDigest>,
+  /// The hash of each generated source, used by the `[contenthash]` of the
+  /// chunk files so that a file only changes with the sources rendered into it
+  pub source_type_hashes: HashMap<SourceType, RspackHashDigest>,
   pub id: CodeGenResultId,
   pub concatenation_scope: Option<ConcatenationScope>,
 }
@@ -136,12 +148,26 @@ impl CodeGenerationResult {
   ) {
     let mut hasher = RspackHash::with_salt(hash_function, hash_salt);
     for (source_type, source) in &self.inner {
+      let mut source_type_hasher = RspackHash::with_salt(hash_function, hash_salt);
+      source.hash(&mut source_type_hasher);
+      // chunk init fragments are rendered into the javascript files
+      if matches!(source_type, SourceType::JavaScript) {
+        self.chunk_init_fragments.hash(&mut source_type_hasher);
+      }
+      let source_type_hash = source_type_hasher.digest(hash_digest);
       source_type.hash(&mut hasher);
-      source.hash(&mut hasher);
+      source_type_hash.hash(&mut hasher);
+      self
+        .source_type_hashes
+        .insert(*source_type, source_type_hash);
     }
     self.chunk_init_fragments.hash(&mut hasher);
     self.hash = Some(hasher.digest(hash_digest));
   }
+
+  pub fn source_type_hash(&self, source_type: &SourceType) -> Option<&RspackHashDigest> {
+    self.source_type_hashes.get(source_type)
+  }
 }
 
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize)]
@@ -271,4 +297,15 @@ impl CodeGenerationResults {
 
     code_generation_result.hash.as_ref()
   }
+
+  pub fn get_source_type_hash(
+    &self,
+    module_identifier: &ModuleIdentifier,
+    runtime: Option<&RuntimeSpec>,
+    source_type: &SourceType,
+  ) -> Option<&RspackHashDigest> {
+    self
+      .get(module_identifier, runtime)
+      .source_type_hash(source_type)
+  }
 }
@@ -59,6 +59,7 @@ define_hook!(CompilationOptimizeModules: AsyncSeriesBail(compilation: &mut Compi
 define_hook!(CompilationAfterOptimizeModules: AsyncSeries(compilation: &mut Compilation));
 define_hook!(CompilationOptimizeChunks: SyncSeriesBail(compilation: &mut Compilation) -> bool);
 define_hook!(CompilationOptimizeTree: AsyncSeries(compilation: &mut Compilation));
+define_hook!(CompilationAfterOptimizeTree: AsyncSeries(compilation: &mut Compilation));
 define_hook!(CompilationOptimizeChunkModules: AsyncSeriesBail(compilation: &mut Compilation) -> bool);
 define_hook!(CompilationModuleIds: SyncSeries(compilation: &mut Compilation));
 define_hook!(CompilationChunkIds: SyncSeries(compilation: &mut Compilation));
@@ -90,6 +91,7 @@ pub struct CompilationHooks {
   pub after_optimize_modules: CompilationAfterOptimizeModulesHook,
   pub optimize_chunks: CompilationOptimizeChunksHook,
   pub optimize_tree: CompilationOptimizeTreeHook,
+  pub after_optimize_tree: CompilationAfterOptimizeTreeHook,
   pub optimize_chunk_modules: CompilationOptimizeChunkModulesHook,
   pub module_ids: CompilationModuleIdsHook,
   pub chunk_ids: CompilationChunkIdsHook,
@@ -171,6 +173,7 @@ pub struct Compilation {
   // lazy compilation visit module
   pub lazy_visit_modules: std::collections::HashSet<String>,
   pub used_chunk_ids: HashSet<String>,
+  pub used_module_ids: HashSet<String>,
   pub include_module_ids: IdentifierSet,
 
   pub file_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
@@ -258,6 +261,7 @@ impl Compilation {
       hash: None,
       lazy_visit_modules: Default::default(),
       used_chunk_ids: Default::default(),
+      used_module_ids: Default::default(),
 
       file_dependencies: Default::default(),
       context_dependencies: Default::default(),
@@ -806,9 +810,12 @@ impl Compilation {
 
   #[instrument(skip_all)]
   async fn create_chunk_assets(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
+    // render the chunks in the order of their ids, so the diagnostics and the
+    // emitted assets don't depend on the order of the chunk ukeys
     let results = self
       .chunk_by_ukey
       .values()
+      .sorted_by(|a, b| a.id.cmp(&b.id))
       .map(|chunk| async {
         let mut manifest = Vec::new();
         let mut diagnostics = Vec::new();
@@ -978,6 +985,11 @@ impl Compilation {
       .optimize_tree
       .call(self)
       .await?;
+    plugin_driver
+      .compilation_hooks
+      .after_optimize_tree
+      .call(self)
+      .await?;
 
     plugin_driver
       .compilation_hooks
@@ -1316,27 +1328,68 @@ impl Compilation {
     }
 
     let start = logger.time("hashing: hash chunks");
-    let other_chunk_hash_results: Vec<Result<(ChunkUkey, (RspackHashDigest, ChunkContentHash))>> =
-      self
-        .chunk_by_ukey
-        .keys()
-        .filter(|key| !runtime_chunk_ukeys.contains(key))
-        .map(|chunk| async {
-          let hash_result = self.process_chunk_hash(*chunk, &plugin_driver).await?;
-          Ok((*chunk, hash_result))
-        })
-        .collect::<FuturesResults<_>>()
-        .into_inner();
+    let mut chunk_hash_cache_counter = match self.options.cache {
+      CacheOptions::Disabled => None,
+      _ => Some(logger.cache("chunk hash cache")),
+    };
+    #[allow(clippy::type_complexity)]
+    let other_chunk_hash_results: Vec<
+      Result<(ChunkUkey, (RspackHashDigest, ChunkContentHash), bool)>,
+    > = self
+      .chunk_by_ukey
+      .values()
+      .filter(|chunk| !runtime_chunk_ukeys.contains(&chunk.ukey))
+      .map(|chunk| async {
+        let chunk_hash = self.create_chunk_hash(chunk.ukey, &plugin_driver)?;
+        let (content_hash, from_cache) = self
+          .cache
+          .chunk_hash_occasion
+          .use_cache(self, chunk, &chunk_hash, || async {
+            self.create_chunk_content_hash(chunk.ukey, &plugin_driver)
+          })
+          .await?;
+        Ok((chunk.ukey, (chunk_hash, content_hash), from_cache))
+      })
+      .collect::<FuturesResults<_>>()
+      .into_inner();
 
+    let other_chunk_hash_results = other_chunk_hash_results
+      .into_iter()
+      .map(|result| {
+        result.map(|(chunk_ukey, hash_result, from_cache)| {
+          if let Some(counter) = &mut chunk_hash_cache_counter {
+            if from_cache {
+              counter.hit();
+            } else {
+              counter.miss();
+            }
+          }
+          (chunk_ukey, hash_result)
+        })
+      })
+      .collect();
     try_process_chunk_hash_results(self, other_chunk_hash_results)?;
+    if let Some(counter) = chunk_hash_cache_counter {
+      logger.cache_end(counter);
+    }
     logger.time_end(start);
 
     // runtime chunks should be hashed after all other chunks
     let start = logger.time("hashing: hash runtime chunks");
     self.create_runtime_module_hash()?;
 
-    let runtime_chunk_hash_results: Vec<Result<(ChunkUkey, (RspackHashDigest, ChunkContentHash))>> =
-      runtime_chunk_ukeys
+    for (level, runtime_chunks) in self
+      .get_runtime_chunk_levels(&runtime_chunk_ukeys)
+      .into_iter()
+      .enumerate()
+    {
+      // the runtime modules render the hashes of the runtime chunks in the previous levels
+      if level > 0 {
+        self.update_runtime_module_hash(&runtime_chunks)?;
+      }
+      let runtime_chunk_hash_results: Vec<
+        Result<(ChunkUkey, (RspackHashDigest, ChunkContentHash))>,
+      > = runtime_chunks
         .iter()
         .map(|chunk| async {
           let hash_result = self.process_chunk_hash(*chunk, &plugin_driver).await?;
@@ -1344,7 +1397,8 @@ impl Compilation {
         })
         .collect::<FuturesResults<_>>()
         .into_inner();
-    try_process_chunk_hash_results(self, runtime_chunk_hash_results)?;
+      try_process_chunk_hash_results(self, runtime_chunk_hash_results)?;
+    }
     logger.time_end(start);
 
     self
@@ -1358,10 +1412,26 @@ impl Compilation {
     self.hot_index.hash(&mut compilation_hasher);
     self.hash = Some(compilation_hasher.digest(&self.options.output.hash_digest));
 
-    // here omit re-create full hash runtime module hash, only update compilation hash to runtime chunk content hash
+    // here omit re-create full hash runtime module hash, only update compilation hash to the hashes
+    // of the runtime chunks which render it, so the other chunks keep their content hashes
     let start = logger.time("hashing: process full hash chunks");
+    let full_hash_chunk_ukeys = runtime_chunk_ukeys
+      .iter()
+      .filter(|chunk_ukey| {
+        self
+          .chunk_graph
+          .get_chunk_runtime_modules_iterable(chunk_ukey)
+          .any(|identifier| {
+            self
+              .runtime_modules
+              .get(identifier)
+              .is_some_and(|module| module.full_hash())
+          })
+      })
+      .copied()
+      .collect::<HashSet<_>>();
     self.chunk_by_ukey.values_mut().for_each(|chunk| {
-      if runtime_chunk_ukeys.contains(&chunk.ukey) {
+      if full_hash_chunk_ukeys.contains(&chunk.ukey) {
         if let Some(chunk_hash) = &mut chunk.hash {
           let mut hasher = RspackHash::from(&self.options.output);
           chunk_hash.hash(&mut hasher);
@@ -1390,6 +1460,16 @@ impl Compilation {
     chunk_ukey: ChunkUkey,
     plugin_driver: &SharedPluginDriver,
   ) -> Result<(RspackHashDigest, ChunkContentHash)> {
+    let chunk_hash = self.create_chunk_hash(chunk_ukey, plugin_driver)?;
+    let content_hashes = self.create_chunk_content_hash(chunk_ukey, plugin_driver)?;
+    Ok((chunk_hash, content_hashes))
+  }
+
+  fn create_chunk_hash(
+    &self,
+    chunk_ukey: ChunkUkey,
+    plugin_driver: &SharedPluginDriver,
+  ) -> Result<RspackHashDigest> {
     let mut hasher = RspackHash::from(&self.options.output);
     if let Some(chunk) = get_chunk_from_ukey(&chunk_ukey, &self.chunk_by_ukey) {
       chunk.update_hash(&mut hasher, self);
@@ -1398,8 +1478,14 @@ impl Compilation {
       .compilation_hooks
       .chunk_hash
       .call(self, &chunk_ukey, &mut hasher)?;
-    let chunk_hash = hasher.digest(&self.options.output.hash_digest);
+    Ok(hasher.digest(&self.options.output.hash_digest))
+  }
 
+  fn create_chunk_content_hash(
+    &self,
+    chunk_ukey: ChunkUkey,
+    plugin_driver: &SharedPluginDriver,
+  ) -> Result<ChunkContentHash> {
     let mut content_hashes = HashMap::default();
     plugin_driver
       .compilation_hooks
@@ -1409,8 +1495,7 @@ impl Compilation {
       .into_iter()
       .map(|(t, hasher)| (t, hasher.digest(&self.options.output.hash_digest)))
       .collect();
-
-    Ok((chunk_hash, content_hashes))
+    Ok(content_hashes)
   }
 
   // #[instrument(name = "compilation:create_module_hash", skip_all)]
@@ -1439,6 +1524,92 @@ impl Compilation {
   //   }
   // }
 
+  /// Sorts the runtime chunks into levels for hashing, the runtime chunks of a level only reference
+  /// the async entrypoints (e.g. workers) whose runtime chunks are in the previous levels.
+  fn get_runtime_chunk_levels(
+    &mut self,
+    runtime_chunk_ukeys: &HashSet<ChunkUkey>,
+  ) -> Vec<Vec<ChunkUkey>> {
+    // runtime chunk -> the runtime chunks referencing it
+    let mut referenced_by: HashMap<ChunkUkey, Vec<ChunkUkey>> = HashMap::default();
+    let mut remaining: HashMap<ChunkUkey, usize> = HashMap::default();
+    for chunk_ukey in runtime_chunk_ukeys.iter().sorted_unstable() {
+      let chunk = self.chunk_by_ukey.expect_get(chunk_ukey);
+      let references = chunk
+        .get_all_referenced_async_entrypoints(&self.chunk_group_by_ukey)
+        .iter()
+        .map(|entrypoint_ukey| {
+          self
+            .chunk_group_by_ukey
+            .expect_get(entrypoint_ukey)
+            .get_runtime_chunk(&self.chunk_group_by_ukey)
+        })
+        .filter(|runtime_chunk| {
+          runtime_chunk != chunk_ukey && runtime_chunk_ukeys.contains(runtime_chunk)
+        })
+        .collect::<IndexSet<_>>();
+      remaining.insert(*chunk_ukey, references.len());
+      for runtime_chunk in references {
+        referenced_by
+          .entry(runtime_chunk)
+          .or_default()
+          .push(*chunk_ukey);
+      }
+    }
+
+    let mut levels = vec![];
+    let mut current = remaining
+      .iter()
+      .filter(|(_, count)| **count == 0)
+      .map(|(chunk_ukey, _)| *chunk_ukey)
+      .sorted_unstable()
+      .collect::<Vec<_>>();
+    while !current.is_empty() {
+      let mut next = vec![];
+      for chunk_ukey in &current {
+        remaining.remove(chunk_ukey);
+        for referencing in referenced_by.get(chunk_ukey).into_iter().flatten() {
+          let count = remaining
+            .get_mut(referencing)
+            .expect("should have the referencing runtime chunk");
+          *count -= 1;
+          if *count == 0 {
+            next.push(*referencing);
+          }
+        }
+      }
+      next.sort_unstable();
+      levels.push(current);
+      current = next;
+    }
+
+    if !remaining.is_empty() {
+      let circular = remaining.into_keys().sorted_unstable().collect::<Vec<_>>();
+      let names = circular
+        .iter()
+        .map(|chunk_ukey| {
+          let chunk = self.chunk_by_ukey.expect_get(chunk_ukey);
+          chunk
+            .name
+            .clone()
+            .or_else(|| chunk.id.clone())
+            .unwrap_or_default()
+        })
+        .join(", ");
+      self.push_diagnostic(
+        Diagnostic::error(
+          "Circular dependency between chunks with runtime".into(),
+          format!(
+            "Circular dependency between chunks with runtime ({names})\nThis prevents using hashes of each other and should be avoided."
+          ),
+        )
+        .with_diagnostic_code("CIRCULAR_RUNTIME_CHUNKS"),
+      );
+      levels.push(circular);
+    }
+    levels
+  }
+
   #[instrument(name = "compilation:create_runtime_module_hash", skip_all)]
   pub fn create_runtime_module_hash(&mut self) -> Result<()> {
     self.runtime_module_code_generation_results = self
@@ -1446,20 +1617,50 @@ impl Compilation {
       .par_iter()
       .map(
         |(identifier, module)| -> Result<(Identifier, (RspackHashDigest, BoxSource))> {
-          let source = module.generate_with_custom(self)?;
-          let mut hasher = RspackHash::from(&self.options.output);
-          module.identifier().hash(&mut hasher);
-          source.source().hash(&mut hasher);
-          Ok((
-            *identifier,
-            (hasher.digest(&self.options.output.hash_digest), source),
-          ))
+          Ok((*identifier, self.generate_runtime_module(module.as_ref())?))
         },
       )
       .collect::<Result<IdentifierMap<(RspackHashDigest, BoxSource)>>>()?;
     Ok(())
   }
 
+  /// Generates the runtime modules of the chunks again after the chunks they reference are hashed.
+  fn update_runtime_module_hash(&mut self, chunks: &[ChunkUkey]) -> Result<()> {
+    let results = chunks
+      .iter()
+      .flat_map(|chunk_ukey| {
+        self
+          .chunk_graph
+          .get_chunk_runtime_modules_iterable(chunk_ukey)
+      })
+      .copied()
+      .collect::<IdentifierSet>()
+      .into_par_iter()
+      .map(
+        |identifier| -> Result<(Identifier, (RspackHashDigest, BoxSource))> {
+          let module = self
+            .runtime_modules
+            .get(&identifier)
+            .expect("should have the runtime module");
+          Ok((identifier, self.generate_runtime_module(module.as_ref())?))
+        },
+      )
+      .collect::<Result<IdentifierMap<(RspackHashDigest, BoxSource)>>>()?;
+    self.runtime_module_code_generation_results.extend(results);
+    Ok(())
+  }
+
+  fn generate_runtime_module(
+    &self,
+    module: &dyn RuntimeModule,
+  ) -> Result<(RspackHashDigest, BoxSource)> {
+    let source = module.generate_with_custom(self)?;
+    let mut hasher = RspackHash::from(&self.options.output);
+    module.identifier().hash(&mut hasher);
+    source.source().hash(&mut hasher);
+    Ok((hasher.digest(&self.options.output.hash_digest), source))
+  }
+
   pub fn add_runtime_module(
     &mut self,
     chunk_ukey: &ChunkUkey,
@@ -1656,6 +1857,8 @@ pub struct AssetInfo {
   /// An empty string means no version, it will always emit
   pub version: String,
   pub source_filename: Option<String>,
+  /// unstable field for plugins to attach additional information to the asset
+  pub extras: serde_json::Map<String, serde_json::Value>,
 }
 
 impl AssetInfo {
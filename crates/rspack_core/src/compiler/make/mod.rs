@@ -75,7 +75,11 @@ impl MakeArtifact {
       .build_dependencies
       .extend(self.build_dependencies.files().cloned());
 
-    compilation.push_batch_diagnostic(std::mem::take(&mut self.diagnostics));
+    // modules are built in parallel, sort the diagnostics by module so that
+    // they don't depend on the order in which the builds finish
+    let mut diagnostics = std::mem::take(&mut self.diagnostics);
+    diagnostics.sort_by_key(|diagnostic| diagnostic.module_identifier());
+    compilation.push_batch_diagnostic(diagnostics);
   }
 
   fn revoke_modules(&mut self, ids: HashSet<ModuleIdentifier>) -> Vec<BuildDependency> {
@@ -145,6 +149,24 @@ pub fn make_module_graph(
     let make_failed_dependencies = std::mem::take(&mut artifact.make_failed_dependencies);
     params.push(MakeParam::ForceBuildDeps(make_failed_dependencies));
   }
+  // the modules built with a changed value, e.g. a `DefinePlugin.runtimeValue`, are outdated
+  let value_changed_modules = artifact
+    .get_module_graph()
+    .modules()
+    .values()
+    .filter(|module| {
+      module.build_info().is_some_and(|build_info| {
+        compilation
+          .options
+          .builtins
+          .is_value_dependencies_changed(build_info)
+      })
+    })
+    .map(|module| module.identifier())
+    .collect::<HashSet<_>>();
+  if !value_changed_modules.is_empty() {
+    params.push(MakeParam::ForceBuildModules(value_changed_modules));
+  }
 
   // reset diagnostics
   artifact.diagnostics = Default::default();
@@ -1,10 +1,11 @@
 use std::{collections::VecDeque, sync::Arc};
 
-use rspack_error::{Diagnostic, IntoTWithDiagnosticArray};
+use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Severity};
 
 use super::{process_dependencies::ProcessDependenciesTask, MakeTaskContext};
 use crate::{
   cache::Cache,
+  diagnostics::ModuleBailError,
   utils::task_loop::{Task, TaskResult, TaskType},
   AsyncDependenciesBlock, BoxDependency, BuildContext, BuildResult, CompilerContext,
   CompilerOptions, DependencyParents, Module, ModuleProfile, ResolverFactory, SharedPluginDriver,
@@ -38,16 +39,16 @@ impl Task<MakeTaskContext> for BuildTask {
       current_profile.mark_building_start();
     }
 
-    let (build_result, is_cache_valid) = cache
+    let (mut build_result, is_cache_valid) = cache
       .build_module_occasion
-      .use_cache(&mut module, |module| async {
+      .use_cache(&mut module, &compiler_options, |module| async {
         plugin_driver
           .compilation_hooks
           .build_module
           .call(module)
           .await?;
 
-        let result = module
+        let mut result = module
           .build(
             BuildContext {
               compiler_context: CompilerContext {
@@ -66,11 +67,18 @@ impl Task<MakeTaskContext> for BuildTask {
           )
           .await;
 
+        // expose the build result to the taps, they may modify it
+        if let Ok(build_result) = &mut result {
+          lend_build_result(module.as_mut(), build_result);
+        }
         plugin_driver
           .compilation_hooks
           .succeed_module
           .call(module)
           .await?;
+        if let Ok(build_result) = &mut result {
+          return_build_result(module.as_mut(), build_result);
+        }
 
         result.map(|t| {
           let diagnostics = module
@@ -84,30 +92,63 @@ impl Task<MakeTaskContext> for BuildTask {
       .await?;
 
     if is_cache_valid {
+      if let Ok(build_result) = &mut build_result {
+        lend_build_result(module.as_mut(), &mut build_result.inner);
+      }
       plugin_driver
         .compilation_hooks
         .still_valid_module
         .call(&mut module)
         .await?;
+      if let Ok(build_result) = &mut build_result {
+        return_build_result(module.as_mut(), &mut build_result.inner);
+      }
     }
 
     if let Some(current_profile) = &current_profile {
       current_profile.mark_building_end();
     }
 
-    build_result.map::<Vec<Box<dyn Task<MakeTaskContext>>>, _>(|build_result| {
-      let (build_result, diagnostics) = build_result.split_into_parts();
-      vec![Box::new(BuildResultTask {
-        module,
-        build_result: Box::new(build_result),
-        diagnostics,
-        current_profile,
-        from_cache: is_cache_valid,
-      })]
-    })
+    let (build_result, diagnostics) = build_result?.split_into_parts();
+    // Bail out if `options.bail` set to `true`,
+    // which means 'Fail out on the first error instead of tolerating it.'
+    if compiler_options.bail
+      && let Some(error) = diagnostics
+        .iter()
+        .find(|diagnostic| matches!(diagnostic.severity(), Severity::Error))
+    {
+      return Err(
+        ModuleBailError::new(
+          module
+            .readable_identifier(&compiler_options.context)
+            .into_owned(),
+          error.clone(),
+        )
+        .into(),
+      );
+    }
+    Ok(vec![Box::new(BuildResultTask {
+      module,
+      build_result: Box::new(build_result),
+      diagnostics,
+      current_profile,
+      from_cache: is_cache_valid,
+    })])
   }
 }
 
+/// Moves the build info and meta into the module for the taps of the module
+/// hooks, they may be modified there.
+fn lend_build_result(module: &mut dyn Module, build_result: &mut BuildResult) {
+  module.set_build_info(std::mem::take(&mut build_result.build_info));
+  module.set_build_meta(std::mem::take(&mut build_result.build_meta));
+}
+
+fn return_build_result(module: &mut dyn Module, build_result: &mut BuildResult) {
+  build_result.build_info = module.take_build_info().unwrap_or_default();
+  build_result.build_meta = module.take_build_meta().unwrap_or_default();
+}
+
 #[derive(Debug)]
 struct BuildResultTask {
   pub module: Box<dyn Module>,
@@ -8,6 +8,8 @@ use rustc_hash::FxHashSet as HashSet;
 use super::{add::AddTask, MakeTaskContext};
 use crate::{
   cache::Cache,
+  contextify,
+  diagnostics::ModuleBailError,
   module_graph::ModuleGraphModule,
   utils::task_loop::{Task, TaskResult, TaskType},
   BoxDependency, CompilerOptions, Context, DependencyId, ExportInfo, ExportsInfo, ModuleFactory,
@@ -120,7 +122,14 @@ impl Task<MakeTaskContext> for FactorizeTask {
         // Bail out if `options.bail` set to `true`,
         // which means 'Fail out on the first error instead of tolerating it.'
         if self.options.bail {
-          return Err(e);
+          return Err(match &create_data.issuer {
+            Some(issuer) => ModuleBailError::new(
+              contextify(&self.options.context, issuer),
+              Diagnostic::from(e),
+            )
+            .into(),
+            None => e,
+          });
         }
         let mut diagnostics = Vec::with_capacity(create_data.diagnostics.len() + 1);
         diagnostics.push(e.into());
@@ -24,7 +24,10 @@ pub use self::module_executor::{ExecuteModuleId, ModuleExecutor};
 use crate::cache::Cache;
 use crate::tree_shaking::symbol::{IndirectType, StarSymbolKind, DEFAULT_JS_WORD};
 use crate::tree_shaking::visitor::SymbolRef;
-use crate::{fast_set, CompilerOptions, Logger, PluginDriver, ResolverFactory, SharedPluginDriver};
+use crate::{
+  fast_set, CompilerOptions, Logger, PluginDriver, ResolverFactory, SharedPluginDriver,
+  VirtualModules,
+};
 use crate::{BoxPlugin, ExportInfo, UsageState};
 use crate::{ContextModuleFactory, NormalModuleFactory};
 
@@ -65,6 +68,8 @@ where
   pub resolver_factory: Arc<ResolverFactory>,
   pub loader_resolver_factory: Arc<ResolverFactory>,
   pub cache: Arc<Cache>,
+  /// modules that only exist in memory, shared by all the resolvers
+  pub virtual_modules: VirtualModules,
   /// emitted asset versions
   /// the key of HashMap is filename, the value of HashMap is version
   pub emitted_asset_versions: HashMap<String, String>,
@@ -82,8 +87,15 @@ where
         debug_info.with_context(options.context.to_string());
       }
     }
-    let resolver_factory = Arc::new(ResolverFactory::new(options.resolve.clone()));
-    let loader_resolver_factory = Arc::new(ResolverFactory::new(options.resolve_loader.clone()));
+    let virtual_modules = VirtualModules::default();
+    let resolver_factory = Arc::new(ResolverFactory::new(
+      options.resolve.clone(),
+      virtual_modules.clone(),
+    ));
+    let loader_resolver_factory = Arc::new(ResolverFactory::new(
+      options.resolve_loader.clone(),
+      virtual_modules.clone(),
+    ));
     let (plugin_driver, options) = PluginDriver::new(options, plugins, resolver_factory.clone());
     let cache = Arc::new(Cache::new(options.clone()));
     assert!(!(options.is_new_tree_shaking() && options.builtins.tree_shaking.enable()), "Can't enable builtins.tree_shaking and `experiments.rspack_future.new_treeshaking` at the same time");
@@ -106,6 +118,7 @@ where
       resolver_factory,
       loader_resolver_factory,
       cache,
+      virtual_modules,
       emitted_asset_versions: Default::default(),
     }
   }
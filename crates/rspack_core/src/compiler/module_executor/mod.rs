@@ -130,10 +130,11 @@ impl ModuleExecutor {
     base_uri: Option<String>,
     original_module_context: Option<Context>,
   ) -> Result<ExecuteModuleResult> {
-    let sender = self
-      .event_sender
-      .as_ref()
-      .expect("should have event sender");
+    let Some(sender) = self.event_sender.as_ref() else {
+      return Err(rspack_error::error!(
+        "Modules can only be imported before the processAssets hook"
+      ));
+    };
     let (param, dep_id) = match self.request_dep_map.entry(request.clone()) {
       Entry::Vacant(v) => {
         let dep = EntryDependency::new(
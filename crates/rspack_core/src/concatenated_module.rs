@@ -23,7 +23,7 @@ use swc_core::{
   ecma::{
     ast::{EsVersion, Program},
     atoms::Atom,
-    parser::{parse_file_as_module, Syntax},
+    parser::{parse_file_as_module, parse_file_as_script, Syntax},
     transforms::base::resolver,
   },
 };
@@ -185,6 +185,19 @@ pub struct ExternalModuleInfo {
   pub interop_default_access_used: bool,
   pub interop_default_access_name: Option<Atom>,
   pub name: Option<Atom>,
+  /// The generated code of a CommonJS leaf module, which is wrapped in a function and inlined
+  /// instead of being required
+  pub inlined: Option<InlinedModuleCode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InlinedModuleCode {
+  pub source: Arc<dyn Source>,
+  pub runtime_requirements: RuntimeGlobals,
+  pub chunk_init_fragments: ChunkInitFragments,
+  /// The names the module refers to from the global scope, they are reserved so that the names of
+  /// the concatenated modules don't shadow them
+  pub global_names: Vec<Atom>,
 }
 
 pub struct ConnectionWithRuntimeCondition {
@@ -436,7 +449,7 @@ impl ConcatenatedModule {
     self.id
   }
 
-  fn get_modules(&self) -> Vec<ConcatenatedInnerModule> {
+  pub fn get_modules(&self) -> Vec<ConcatenatedInnerModule> {
     self.modules.clone()
   }
 }
@@ -502,6 +515,14 @@ impl Module for ConcatenatedModule {
     self.build_info = Some(v);
   }
 
+  fn take_build_info(&mut self) -> Option<BuildInfo> {
+    self.build_info.take()
+  }
+
+  fn take_build_meta(&mut self) -> Option<BuildMeta> {
+    self.root_module_ctxt.build_meta.take()
+  }
+
   fn source_types(&self) -> &[SourceType] {
     &[SourceType::JavaScript]
   }
@@ -545,12 +566,14 @@ impl Module for ConcatenatedModule {
       context_dependencies: Default::default(),
       missing_dependencies: Default::default(),
       build_dependencies: Default::default(),
+      value_dependencies: Default::default(),
       asset_filenames: Default::default(),
       harmony_named_exports: Default::default(),
       all_star_exports: Default::default(),
       need_create_require: Default::default(),
       json_data: Default::default(),
       module_concatenation_bailout: Default::default(),
+      extras: Default::default(),
     };
     self.clear_diagnostics();
 
@@ -698,6 +721,16 @@ impl Module for ConcatenatedModule {
       }
     }
 
+    for info in module_to_info_map.values() {
+      if let ModuleInfo::External(ExternalModuleInfo {
+        inlined: Some(inlined),
+        ..
+      }) = info
+      {
+        all_used_names.extend(inlined.global_names.iter().map(|name| name.to_string()));
+      }
+    }
+
     let module_graph = compilation.get_module_graph();
     for info in module_to_info_map.values_mut() {
       // Get used names in the scope
@@ -1175,13 +1208,16 @@ impl Module for ConcatenatedModule {
           name = info.namespace_object_name.clone();
         }
         ModuleInfo::External(info) => {
+          let label = if info.inlined.is_some() {
+            "INLINED MODULE"
+          } else {
+            runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+            "EXTERNAL MODULE"
+          };
           result.add(RawSource::from(format!(
-            "\n// EXTERNAL MODULE: {}\n",
-            module_readable_identifier
+            "\n// {label}: {module_readable_identifier}\n"
           )));
 
-          runtime_requirements.insert(RuntimeGlobals::REQUIRE);
-
           let runtime_condition = &info.runtime_condition;
 
           let condition = runtime_condition_expression(
@@ -1196,13 +1232,32 @@ impl Module for ConcatenatedModule {
             result.add(RawSource::from(format!("if ({}) {{\n", condition)));
           }
 
-          result.add(RawSource::from(format!(
-            "let {} = {}({});",
-            info.name.as_ref().expect("should have name"),
-            RuntimeGlobals::REQUIRE,
-            serde_json::to_string(compilation.chunk_graph.get_module_id(info.module))
-              .expect("should have module id")
-          )));
+          let external_name = info.name.as_ref().expect("should have name");
+          if let Some(inlined) = &info.inlined {
+            // the module and exports are the only parts of the module object a leaf can use
+            let mut inlined_requirements = inlined.runtime_requirements;
+            inlined_requirements.remove(RuntimeGlobals::MODULE | RuntimeGlobals::EXPORTS);
+            runtime_requirements = runtime_requirements.union(inlined_requirements);
+            chunk_init_fragments.extend(inlined.chunk_init_fragments.iter().cloned());
+            result.add(RawSource::from(format!(
+              "var {external_name} = {{ exports: {{}} }};\n(function ({}, {}) {{\n",
+              box_module.get_module_argument(),
+              box_module.get_exports_argument()
+            )));
+            result.add(inlined.source.clone());
+            result.add(RawSource::from(format!(
+              "\n}}).call({0}.exports, {0}, {0}.exports);\n{0} = {0}.exports;",
+              external_name
+            )));
+          } else {
+            result.add(RawSource::from(format!(
+              "var {} = {}({});",
+              external_name,
+              RuntimeGlobals::REQUIRE,
+              serde_json::to_string(compilation.chunk_graph.get_module_id(info.module))
+                .expect("should have module id")
+            )));
+          }
 
           name = info.name.as_ref().map(|atom| atom.to_string());
         }
@@ -1211,7 +1266,7 @@ impl Module for ConcatenatedModule {
       if info.get_interop_namespace_object_used() {
         runtime_requirements.insert(RuntimeGlobals::CREATE_FAKE_NAMESPACE_OBJECT);
         result.add(RawSource::from(format!(
-          "\nlet {} = /*#__PURE__*/{}({}, 2);",
+          "\nvar {} = /*#__PURE__*/{}({}, 2);",
           info
             .get_interop_namespace_object_name()
             .expect("should have interop_namespace_object_name"),
@@ -1223,7 +1278,7 @@ impl Module for ConcatenatedModule {
       if info.get_interop_namespace_object2_used() {
         runtime_requirements.insert(RuntimeGlobals::CREATE_FAKE_NAMESPACE_OBJECT);
         result.add(RawSource::from(format!(
-          "\nlet {} = /*#__PURE__*/{}({});",
+          "\nvar {} = /*#__PURE__*/{}({});",
           info
             .get_interop_namespace_object2_name()
             .expect("should have interop_namespace_object2_name"),
@@ -1235,7 +1290,7 @@ impl Module for ConcatenatedModule {
       if info.get_interop_default_access_used() {
         runtime_requirements.insert(RuntimeGlobals::COMPAT_GET_DEFAULT_EXPORT);
         result.add(RawSource::from(format!(
-          "\nlet {} = /*#__PURE__*/{}({});",
+          "\nvar {} = /*#__PURE__*/{}({});",
           info
             .get_interop_default_access_name()
             .expect("should have interop_default_access_name"),
@@ -1401,6 +1456,7 @@ impl ConcatenatedModule {
                 interop_default_access_used: false,
                 interop_default_access_name: None,
                 name: None,
+                inlined: None,
               };
               vac.insert(ModuleInfo::External(info.clone()));
               list.push(ModuleInfoOrReference::External(info));
@@ -1462,7 +1518,7 @@ impl ConcatenatedModule {
       None => None,
       Some(_condition) => Some(runtime_condition.clone()),
     };
-    if module_set.contains(module) {
+    if module_set.contains(module) && !Self::is_inlined_module(mg, module) {
       exists_entry.insert(*module, RuntimeCondition::Boolean(true));
       if !matches!(runtime_condition, RuntimeCondition::Boolean(true)) {
         panic!(
@@ -1519,6 +1575,14 @@ impl ConcatenatedModule {
     }
   }
 
+  /// The modules in the set which are not ECMAScript modules are the CommonJS leaves, they are
+  /// inlined into the concatenated module rather than concatenated
+  fn is_inlined_module(mg: &ModuleGraph, module: &ModuleIdentifier) -> bool {
+    mg.module_by_identifier(module)
+      .and_then(|module| module.build_meta())
+      .is_some_and(|meta| meta.exports_type != BuildMetaExportsType::Namespace)
+  }
+
   fn get_concatenated_imports(
     &self,
     module_id: &ModuleIdentifier,
@@ -1672,7 +1736,8 @@ impl ConcatenatedModule {
                 "JavaScript parsing error".to_string(),
                 err.kind().msg().to_string(),
               )
-              .with_kind(DiagnosticKind::JavaScript),
+              .with_kind(DiagnosticKind::JavaScript)
+              .with_code("JAVASCRIPT_PARSE_ERROR"),
             ]));
           return Ok(ModuleInfo::Concatenated(module_info));
         }
@@ -1702,10 +1767,100 @@ impl ConcatenatedModule {
       module_info.chunk_init_fragments = chunk_init_fragments;
       Ok(ModuleInfo::Concatenated(module_info))
     } else {
-      Ok(info)
+      match info {
+        ModuleInfo::External(info) if self.modules.iter().any(|m| m.id == info.module) => {
+          let info = self.analyze_inlined_module(compilation, info, runtime)?;
+          Ok(ModuleInfo::External(info))
+        }
+        info => Ok(info),
+      }
     }
   }
 
+  /// Generates the code of a CommonJS leaf module, which is inlined into the concatenated module
+  fn analyze_inlined_module(
+    &self,
+    compilation: &Compilation,
+    mut info: ExternalModuleInfo,
+    runtime: Option<&RuntimeSpec>,
+  ) -> Result<ExternalModuleInfo> {
+    let module_graph = compilation.get_module_graph();
+    let module = module_graph
+      .module_by_identifier(&info.module)
+      .unwrap_or_else(|| panic!("should have module {}", info.module));
+    let CodeGenerationResult {
+      mut inner,
+      chunk_init_fragments,
+      runtime_requirements,
+      ..
+    } = module.code_generation(compilation, runtime, None)?;
+    let source = inner
+      .remove(&SourceType::JavaScript)
+      .expect("should have javascript source");
+
+    let cm: Arc<swc_core::common::SourceMap> = Default::default();
+    let fm = cm.new_source_file(
+      FileName::Custom(
+        module
+          .readable_identifier(&compilation.options.context)
+          .to_string(),
+      ),
+      source.source().to_string(),
+    );
+    let mut errors = vec![];
+    let mut global_names = vec![];
+    match parse_file_as_script(&fm, Syntax::default(), EsVersion::EsNext, None, &mut errors) {
+      Ok(script) => {
+        let mut ast = Ast::new(Program::Script(script), cm, None);
+        let mut global_ctxt = SyntaxContext::empty();
+        ast.transform(|program, context| {
+          global_ctxt = global_ctxt.apply_mark(context.unresolved_mark);
+          program.visit_mut_with(&mut resolver(
+            context.unresolved_mark,
+            context.top_level_mark,
+            false,
+          ));
+        });
+        let mut collector = IdentCollector::default();
+        ast.visit(|program, _ctxt| {
+          program.visit_with(&mut collector);
+        });
+        global_names = collector
+          .ids
+          .into_iter()
+          .filter(|ident| ident.id.span.ctxt == global_ctxt)
+          .map(|ident| ident.id.sym)
+          .collect();
+      }
+      Err(err) => {
+        let span: ErrorSpan = err.span().into();
+        self
+          .diagnostics
+          .lock()
+          .expect("should have diagnostics")
+          .append(&mut map_box_diagnostics_to_module_parse_diagnostics(vec![
+            rspack_error::TraceableError::from_source_file(
+              &fm,
+              span.start as usize,
+              span.end as usize,
+              "JavaScript parsing error".to_string(),
+              err.kind().msg().to_string(),
+            )
+            .with_kind(DiagnosticKind::JavaScript)
+            .with_code("JAVASCRIPT_PARSE_ERROR"),
+          ]));
+      }
+    }
+
+    info.inlined = Some(InlinedModuleCode {
+      source,
+      runtime_requirements,
+      chunk_init_fragments,
+      global_names,
+    });
+    Ok(info)
+  }
+
   #[allow(clippy::too_many_arguments)]
   fn get_final_name(
     module_graph: &ModuleGraph,
@@ -22,14 +22,15 @@ use rustc_hash::FxHashMap as HashMap;
 use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
-  block_promise, contextify, get_exports_type_with_strict, impl_module_meta_info,
-  returning_function, to_path, AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier,
-  BoxDependency, BuildContext, BuildInfo, BuildMeta, BuildMetaDefaultObject, BuildMetaExportsType,
-  BuildResult, ChunkGraph, ChunkGroupOptions, CodeGenerationResult, Compilation,
-  ConcatenationScope, ContextElementDependency, DependenciesBlock, Dependency, DependencyCategory,
-  DependencyId, DynamicImportMode, ExportsType, FactoryMeta, FakeNamespaceObjectMode, GroupOptions,
-  LibIdentOptions, Module, ModuleType, Resolve, ResolveInnerOptions,
-  ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals, RuntimeSpec, SourceType,
+  block_promise, contextify, get_exports_type_with_strict, hashable_identifier,
+  impl_module_meta_info, returning_function, to_path, AsyncDependenciesBlock,
+  AsyncDependenciesBlockIdentifier, BoxDependency, BuildContext, BuildInfo, BuildMeta,
+  BuildMetaDefaultObject, BuildMetaExportsType, BuildResult, ChunkGraph, ChunkGroupOptions,
+  CodeGenerationResult, Compilation, ConcatenationScope, ContextElementDependency,
+  DependenciesBlock, Dependency, DependencyCategory, DependencyId, DynamicImportMode, ExportsType,
+  FactoryMeta, FakeNamespaceObjectMode, GroupOptions, LibIdentOptions, Module, ModuleType, Resolve,
+  ResolveInnerOptions, ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals,
+  RuntimeSpec, SourceType,
 };
 
 #[derive(Debug, Clone)]
@@ -887,7 +888,8 @@ impl Module for ContextModule {
     let (dependencies, blocks) = self.resolve_dependencies()?;
 
     let mut hasher = RspackHash::from(&build_context.compiler_options.output);
-    self.update_hash(&mut hasher);
+    "__rspack_internal__ContextModule".hash(&mut hasher);
+    hashable_identifier(build_context.compiler_options, &self.identifier).hash(&mut hasher);
 
     let mut context_dependencies: HashSet<PathBuf> = Default::default();
     context_dependencies.insert(PathBuf::from(&self.options.resource));
@@ -6,10 +6,10 @@ use rspack_regex::RspackRegex;
 use tracing::instrument;
 
 use crate::{
-  cache::Cache, resolve, ContextModule, ContextModuleOptions, DependencyCategory, ModuleExt,
-  ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, RawModule,
-  ResolveArgs, ResolveOptionsWithDependencyType, ResolveResult, Resolver, ResolverFactory,
-  SharedPluginDriver,
+  cache::Cache, resolve, ContextModule, ContextModuleOptions, ContextOptions, DependencyCategory,
+  ModuleExt, ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier,
+  RawModule, ResolveArgs, ResolveOptionsWithDependencyType, ResolveResult, Resolver,
+  ResolverFactory, SharedPluginDriver,
 };
 
 #[derive(Clone)]
@@ -24,6 +24,8 @@ pub struct BeforeResolveData {
   // resolve_options
   pub context: String,
   pub request: Option<String>,
+  pub recursive: bool,
+  pub reg_exp: Option<RspackRegex>,
   // assertions
   // dependencies
   // dependency_type
@@ -52,7 +54,7 @@ pub struct AfterResolveData {
   // context_dependencies: HashSet<String>,
   pub request: String,
   // mode
-  // recursive: bool,
+  pub recursive: bool,
   pub reg_exp: Option<RspackRegex>,
   // namespace_object
   // addon: String,
@@ -85,11 +87,13 @@ pub struct ContextModuleFactory {
 impl ModuleFactory for ContextModuleFactory {
   #[instrument(name = "context_module_factory:create", skip_all)]
   async fn create(&self, data: &mut ModuleFactoryCreateData) -> Result<ModuleFactoryResult> {
-    if let Some(before_resolve_result) = self.before_resolve(data).await? {
-      return Ok(before_resolve_result);
-    }
+    let context_options = match self.before_resolve(data).await? {
+      Some(context_options) => context_options,
+      None => return Ok(ModuleFactoryResult::default()),
+    };
 
-    let (factorize_result, mut context_module_options) = self.resolve(data).await?;
+    let (factorize_result, mut context_module_options) =
+      self.resolve(data, context_options).await?;
 
     if let Some(context_module_options) = context_module_options.as_mut() {
       if let Some(factorize_result) = self.after_resolve(context_module_options).await? {
@@ -114,13 +118,23 @@ impl ContextModuleFactory {
     }
   }
 
+  /// Returns the context options updated by the hook, or `None` if the
+  /// dependency is ignored.
   async fn before_resolve(
     &self,
     data: &mut ModuleFactoryCreateData,
-  ) -> Result<Option<ModuleFactoryResult>> {
+  ) -> Result<Option<ContextOptions>> {
+    let mut context_options = data
+      .dependency
+      .as_context_dependency()
+      .expect("should be context dependency")
+      .options()
+      .clone();
     let before_resolve_data = BeforeResolveData {
       context: data.context.to_string(),
-      request: data.request().map(|r| r.to_string()),
+      request: Some(context_options.request.clone()),
+      recursive: context_options.recursive,
+      reg_exp: context_options.reg_exp.clone(),
     };
 
     match self
@@ -130,10 +144,15 @@ impl ContextModuleFactory {
       .call(BeforeResolveResult::Data(Box::new(before_resolve_data)))
       .await?
     {
-      BeforeResolveResult::Ignored => Ok(Some(ModuleFactoryResult::default())),
+      BeforeResolveResult::Ignored => Ok(None),
       BeforeResolveResult::Data(d) => {
         data.context = d.context.into();
-        Ok(None)
+        if let Some(request) = d.request {
+          context_options.request = request;
+        }
+        context_options.recursive = d.recursive;
+        context_options.reg_exp = d.reg_exp;
+        Ok(Some(context_options))
       }
     }
   }
@@ -151,6 +170,7 @@ impl ContextModuleFactory {
   async fn resolve(
     &self,
     data: &mut ModuleFactoryCreateData,
+    context_options: ContextOptions,
   ) -> Result<(ModuleFactoryResult, Option<ContextModuleOptions>)> {
     let plugin_driver = &self.plugin_driver;
     let dependency = data
@@ -160,7 +180,7 @@ impl ContextModuleFactory {
     let mut file_dependencies = Default::default();
     let mut missing_dependencies = Default::default();
     // let context_dependencies = Default::default();
-    let request = dependency.request();
+    let request = context_options.request.as_str();
     let (loader_request, specifier) = match request.rfind('!') {
       Some(idx) => {
         let mut loaders_prefix = String::new();
@@ -247,7 +267,7 @@ impl ContextModuleFactory {
           resource_query: resource.query,
           resource_fragment: resource.fragment,
           resolve_options: data.resolve_options.clone(),
-          context_options: dependency.options().clone(),
+          context_options,
         };
         let module = Box::new(ContextModule::new(
           options.clone(),
@@ -292,6 +312,7 @@ impl ContextModuleFactory {
       resource: context_module_options.resource.to_owned(),
       context: context_options.context.to_owned(),
       request: context_options.request.to_owned(),
+      recursive: context_options.recursive,
       reg_exp: context_options.reg_exp.clone(),
     };
 
@@ -305,6 +326,7 @@ impl ContextModuleFactory {
       AfterResolveResult::Ignored => Ok(Some(ModuleFactoryResult::default())),
       AfterResolveResult::Data(d) => {
         context_module_options.resource = d.resource;
+        context_module_options.context_options.recursive = d.recursive;
         context_module_options.context_options.reg_exp = d.reg_exp;
 
         let module = ContextModule::new(
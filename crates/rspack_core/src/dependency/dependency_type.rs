@@ -93,6 +93,12 @@ pub enum DependencyType {
   /// Webpack is included
   WebpackIsIncluded,
   LoaderImport,
+  /// lazy compilation proxy to the proxied module
+  LazyImport,
+  /// dll entry
+  DllEntry,
+  /// delegated source
+  DelegatedSource,
   Custom(Box<str>), // TODO it will increase large layout size
 }
 
@@ -135,6 +141,9 @@ impl DependencyType {
       DependencyType::WasmExportImported => Cow::Borrowed("wasm export imported"),
       DependencyType::StaticExports => Cow::Borrowed("static exports"),
       DependencyType::LoaderImport => Cow::Borrowed("loader import"),
+      DependencyType::LazyImport => Cow::Borrowed("lazy import"),
+      DependencyType::DllEntry => Cow::Borrowed("dll entry"),
+      DependencyType::DelegatedSource => Cow::Borrowed("delegated source"),
       DependencyType::Custom(ty) => Cow::Owned(format!("custom {ty}")),
       DependencyType::ExportInfoApi => Cow::Borrowed("export info api"),
       // TODO: mode
@@ -25,6 +25,7 @@ impl EmptyDependency {
         "Empty dependency".to_string(),
         "Expected a non-empty request".to_string(),
       )
+      .with_code("EMPTY_DEPENDENCY")
       .boxed(),
     )
   }
@@ -76,6 +77,59 @@ impl miette::Diagnostic for ModuleBuildError {
   }
 }
 
+/// The first error of a module in bail mode, the module is prepended to the message,
+/// the source code and the labels of the error are kept to render its location.
+#[derive(Debug)]
+pub struct ModuleBailError {
+  module: String,
+  error: rspack_error::Diagnostic,
+}
+
+impl ModuleBailError {
+  pub fn new(module: String, error: rspack_error::Diagnostic) -> Self {
+    Self { module, error }
+  }
+}
+
+impl std::error::Error for ModuleBailError {
+  fn source(&self) -> ::core::option::Option<&(dyn std::error::Error + 'static)> {
+    std::error::Error::source(&**self.error)
+  }
+}
+
+impl std::fmt::Display for ModuleBailError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}\n{}", self.module, *self.error)
+  }
+}
+
+impl miette::Diagnostic for ModuleBailError {
+  fn code<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+    (*self.error).code()
+  }
+  fn severity(&self) -> Option<miette::Severity> {
+    (*self.error).severity()
+  }
+  fn help<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+    (*self.error).help()
+  }
+  fn url<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+    (*self.error).url()
+  }
+  fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+    (*self.error).source_code()
+  }
+  fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+    (*self.error).labels()
+  }
+  fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn miette::Diagnostic> + 'a>> {
+    (*self.error).related()
+  }
+  fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+    (*self.error).diagnostic_source()
+  }
+}
+
 /// Represent any errors or warnings during module parse
 /// This does NOT aligned with webpack as webpack does not have parse warning.
 /// However, rspack may create warning during parsing stage, taking CSS as an example.
@@ -139,11 +139,22 @@ impl ExternalModule {
     module_and_specifiers: &ExternalRequestValue,
     compilation: &Compilation,
   ) -> String {
-    format!(
-      "module.exports = {}({})",
+    let import = format!(
+      "{}({})",
       compilation.options.output.import_function_name,
       serde_json::to_string(module_and_specifiers.primary()).expect("invalid json to_string")
-    )
+    );
+    if module_and_specifiers
+      .rest()
+      .is_some_and(|rest| !rest.is_empty())
+    {
+      format!(
+        "module.exports = {import}.then(function(module) {{ return module{}; }})",
+        property_access(module_and_specifiers.iter(), 1)
+      )
+    } else {
+      format!("module.exports = {import}")
+    }
   }
 
   fn get_source_for_script_external(
@@ -166,6 +177,7 @@ module.exports = new Promise(function(resolve, reject) {{
     __webpack_error__.name = 'ScriptExternalLoadError';
     __webpack_error__.type = errorType;
     __webpack_error__.request = realSrc;
+    if (event && event.error) __webpack_error__.cause = event.error;
     reject(__webpack_error__);
   }}, {global_str});
 }}).then(function() {{ return {global}; }});
@@ -199,13 +211,14 @@ module.exports = new Promise(function(resolve, reject) {{
         "module.exports ={} ",
         get_source_for_global_variable_external(request, &compilation.options.output.global_object)
       ),
-      "commonjs" | "commonjs2" | "commonjs-module" | "commonjs-static"
+      "commonjs" | "commonjs2" | "commonjs-module" | "commonjs-static" | "node-commonjs"
         if let Some(request) = request =>
       {
-        self.get_source_for_commonjs(request)
-      }
-      "node-commonjs" if let Some(request) = request => {
-        if compilation.options.output.module {
+        if compilation.options.output.module
+          && (self.external_type == "node-commonjs" || compilation.options.target.node)
+        {
+          // there is no `require` in the node esm outputs, so it's created
+          // from the url of the output file
           chunk_init_fragments.push(
             NormalInitFragment::new(
               "import { createRequire as __WEBPACK_EXTERNAL_createRequire } from 'module';\n"
@@ -218,8 +231,9 @@ module.exports = new Promise(function(resolve, reject) {{
             .boxed(),
           );
           format!(
-            "module.exports = __WEBPACK_EXTERNAL_createRequire(import.meta.url)('{}')",
-            request.primary()
+            "module.exports = __WEBPACK_EXTERNAL_createRequire(import.meta.url)('{}'){}",
+            request.primary(),
+            property_access(request.iter(), 1)
           )
         } else {
           self.get_source_for_commonjs(request)
@@ -264,12 +278,9 @@ module.exports = new Promise(function(resolve, reject) {{
             )
             .boxed(),
           );
-          runtime_requirements.insert(RuntimeGlobals::DEFINE_PROPERTY_GETTERS);
           format!(
-            r#"var x = y => {{ var x = {{}}; {}(x, y); return x; }}
-            var y = x => () => x
-            module.exports = __WEBPACK_EXTERNAL_MODULE_{identifier}__"#,
-            RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
+            "module.exports = __WEBPACK_EXTERNAL_MODULE_{identifier}__{}",
+            property_access(request.iter(), 1)
           )
         } else {
           self.get_source_for_import(request, compilation)
@@ -400,16 +411,29 @@ impl Module for ExternalModule {
       analyze_result: Default::default(),
       optimization_bailouts: vec![],
     };
+    let request_has_specifiers = self
+      .get_request_and_external_type()
+      .0
+      .and_then(|request| request.rest())
+      .is_some_and(|rest| !rest.is_empty());
     // TODO add exports_type for request
     match self.external_type.as_str() {
       "this" => build_result.build_info.strict = false,
       "system" => build_result.build_meta.exports_type = BuildMetaExportsType::Namespace,
-      "module" => build_result.build_meta.exports_type = BuildMetaExportsType::Namespace,
-      "script" | "promise" => build_result.build_meta.has_top_level_await = true,
-      "import" => {
-        build_result.build_meta.has_top_level_await = true;
-        build_result.build_meta.exports_type = BuildMetaExportsType::Namespace;
+      "module" | "import" => {
+        // the module externals are loaded with `import()` when the output
+        // isn't a module
+        if self.external_type == "import" || !build_context.compiler_options.output.module {
+          build_result.build_meta.has_top_level_await = true;
+        }
+        // the specifiers access the properties of the namespace object
+        build_result.build_meta.exports_type = if request_has_specifiers {
+          BuildMetaExportsType::Dynamic
+        } else {
+          BuildMetaExportsType::Namespace
+        };
       }
+      "script" | "promise" => build_result.build_meta.has_top_level_await = true,
       _ => build_result.build_meta.exports_type = BuildMetaExportsType::Dynamic,
     }
     build_result
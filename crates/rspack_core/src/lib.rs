@@ -91,6 +91,8 @@ mod ukey;
 pub use ukey::*;
 pub mod resolver;
 pub use resolver::*;
+mod virtual_modules;
+pub use virtual_modules::*;
 pub mod concatenated_module;
 pub mod reserved_names;
 pub mod tree_shaking;
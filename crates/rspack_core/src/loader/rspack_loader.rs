@@ -29,6 +29,15 @@ impl LoaderRunnerPlugin for RspackLoaderRunnerPlugin {
   }
 
   async fn process_resource(&self, resource_data: &mut ResourceData) -> Result<Option<Content>> {
+    if let Some(content) = self
+      .plugin_driver
+      .resolver_factory
+      .virtual_modules()
+      .read(&resource_data.resource_path)
+    {
+      return Ok(Some(Content::from(content.to_vec())));
+    }
+
     let result = self
       .plugin_driver
       .normal_module_hooks
@@ -11,9 +11,12 @@ use rspack_identifier::{Identifiable, Identifier};
 use rspack_sources::Source;
 use rspack_util::ext::{AsAny, DynEq, DynHash};
 use rspack_util::source_map::ModuleSourceMapConfig;
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::de::{Error as _, Unexpected};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use swc_core::ecma::atoms::Atom;
 
+use crate::concatenated_module::ConcatenatedModule;
 use crate::tree_shaking::visitor::OptimizeAnalyzeResult;
 use crate::{
   AsyncDependenciesBlock, BoxDependency, ChunkGraph, ChunkUkey, CodeGenerationResult, Compilation,
@@ -33,6 +36,7 @@ pub struct BuildContext<'a> {
 pub enum BuildExtraDataType {
   CssParserAndGenerator,
   AssetParserAndGenerator,
+  AssetResponsiveImages,
   JavaScriptParserAndGenerator,
 }
 
@@ -46,12 +50,17 @@ pub struct BuildInfo {
   pub context_dependencies: HashSet<PathBuf>,
   pub missing_dependencies: HashSet<PathBuf>,
   pub build_dependencies: HashSet<PathBuf>,
+  /// The values the module is built with, e.g. the used `DefinePlugin.runtimeValue`s,
+  /// the module is built again when one of them is changed.
+  pub value_dependencies: HashMap<String, String>,
   pub asset_filenames: HashSet<String>,
   pub harmony_named_exports: HashSet<Atom>,
   pub all_star_exports: Vec<DependencyId>,
   pub need_create_require: bool,
   pub json_data: Option<JsonValue>,
   pub module_concatenation_bailout: Option<String>,
+  /// Custom fields set by plugins, e.g. `module.buildInfo.foo = 1` in JS.
+  pub extras: serde_json::Map<String, serde_json::Value>,
 }
 
 impl Default for BuildInfo {
@@ -64,17 +73,29 @@ impl Default for BuildInfo {
       context_dependencies: HashSet::default(),
       missing_dependencies: HashSet::default(),
       build_dependencies: HashSet::default(),
+      value_dependencies: HashMap::default(),
       asset_filenames: HashSet::default(),
       harmony_named_exports: HashSet::default(),
       all_star_exports: Vec::default(),
       need_create_require: false,
       json_data: None,
       module_concatenation_bailout: None,
+      extras: serde_json::Map::default(),
     }
   }
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+/// The custom `buildInfo` fields and the `buildMeta` set by loaders, e.g.
+/// `this._module.buildInfo.foo = 1` in a JS loader, carried by the additional data
+/// of the loader context.
+#[derive(Debug, Default, Clone)]
+pub struct LoaderModuleBuild {
+  pub build_info_extras: serde_json::Map<String, serde_json::Value>,
+  pub build_meta: BuildMeta,
+}
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum BuildMetaExportsType {
   #[default]
   Unset,
@@ -100,10 +121,50 @@ pub enum BuildMetaDefaultObject {
   RedirectWarn,
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash)]
+// Same as webpack, `false` is a boolean, the other values are strings
+impl Serialize for BuildMetaDefaultObject {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match self {
+      BuildMetaDefaultObject::False => serializer.serialize_bool(false),
+      BuildMetaDefaultObject::Redirect => serializer.serialize_str("redirect"),
+      BuildMetaDefaultObject::RedirectWarn => serializer.serialize_str("redirect-warn"),
+    }
+  }
+}
+
+impl<'de> Deserialize<'de> for BuildMetaDefaultObject {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawDefaultObject {
+      Bool(bool),
+      String(String),
+    }
+
+    match RawDefaultObject::deserialize(deserializer)? {
+      RawDefaultObject::Bool(false) => Ok(BuildMetaDefaultObject::False),
+      RawDefaultObject::String(s) if s == "redirect" => Ok(BuildMetaDefaultObject::Redirect),
+      RawDefaultObject::String(s) if s == "redirect-warn" => {
+        Ok(BuildMetaDefaultObject::RedirectWarn)
+      }
+      RawDefaultObject::Bool(true) => Err(D::Error::invalid_value(
+        Unexpected::Bool(true),
+        &"false, \"redirect\" or \"redirect-warn\"",
+      )),
+      RawDefaultObject::String(s) => Err(D::Error::invalid_value(
+        Unexpected::Str(&s),
+        &"false, \"redirect\" or \"redirect-warn\"",
+      )),
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum ModuleArgument {
   #[default]
+  #[serde(rename = "module")]
   Module,
+  #[serde(rename = "__webpack_module__")]
   WebpackModule,
 }
 
@@ -116,10 +177,12 @@ impl Display for ModuleArgument {
   }
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ExportsArgument {
   #[default]
+  #[serde(rename = "exports")]
   Exports,
+  #[serde(rename = "__webpack_exports__")]
   WebpackExports,
 }
 
@@ -132,7 +195,8 @@ impl Display for ExportsArgument {
   }
 }
 
-#[derive(Debug, Default, Clone, Hash)]
+#[derive(Debug, Default, Clone, Hash, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct BuildMeta {
   pub strict_harmony_module: bool,
   pub has_top_level_await: bool,
@@ -231,6 +295,12 @@ pub trait Module:
 
   fn set_build_meta(&mut self, build_meta: BuildMeta);
 
+  /// Moves the build info out of the module, the build result takes it back
+  /// after the `succeed_module` taps have seen it.
+  fn take_build_info(&mut self) -> Option<BuildInfo>;
+
+  fn take_build_meta(&mut self) -> Option<BuildMeta>;
+
   fn get_exports_argument(&self) -> ExportsArgument {
     self
       .build_meta()
@@ -533,6 +603,14 @@ macro_rules! impl_module_meta_info {
     fn set_build_meta(&mut self, v: $crate::BuildMeta) {
       self.build_meta = Some(v);
     }
+
+    fn take_build_info(&mut self) -> Option<$crate::BuildInfo> {
+      self.build_info.take()
+    }
+
+    fn take_build_meta(&mut self) -> Option<$crate::BuildMeta> {
+      self.build_meta.take()
+    }
   };
 }
 
@@ -575,6 +653,7 @@ impl_module_downcast_helpers!(RawModule, raw_module);
 impl_module_downcast_helpers!(ContextModule, context_module);
 impl_module_downcast_helpers!(ExternalModule, external_module);
 impl_module_downcast_helpers!(SelfModule, self_module);
+impl_module_downcast_helpers!(ConcatenatedModule, concatenated_module);
 
 pub struct LibIdentOptions<'me> {
   pub context: &'me str,
@@ -721,6 +800,14 @@ mod test {
         fn set_build_meta(&mut self, _: crate::BuildMeta) {
           unreachable!()
         }
+
+        fn take_build_info(&mut self) -> Option<crate::BuildInfo> {
+          unreachable!()
+        }
+
+        fn take_build_meta(&mut self) -> Option<crate::BuildMeta> {
+          unreachable!()
+        }
       }
 
       impl ModuleSourceMapConfig for $ident {
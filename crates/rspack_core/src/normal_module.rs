@@ -27,14 +27,14 @@ use rustc_hash::FxHasher;
 use serde_json::json;
 
 use crate::{
-  add_connection_states, contextify, diagnostics::ModuleBuildError, get_context,
-  impl_module_meta_info, AsyncDependenciesBlockIdentifier, BoxLoader, BoxModule, BuildContext,
-  BuildInfo, BuildMeta, BuildResult, ChunkGraph, CodeGenerationResult, Compilation,
-  CompilerContext, ConcatenationScope, ConnectionState, Context, DependenciesBlock, DependencyId,
-  DependencyTemplate, FactoryMeta, GenerateContext, GeneratorOptions, LibIdentOptions, Module,
-  ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType, ParseContext, ParseResult,
-  ParserAndGenerator, ParserOptions, Resolve, RspackLoaderRunnerPlugin, RuntimeGlobals,
-  RuntimeSpec, SourceType,
+  add_connection_states, contextify, contextify_source_map, diagnostics::ModuleBuildError,
+  get_context, impl_module_meta_info, source_name, AsyncDependenciesBlockIdentifier, BoxLoader,
+  BoxModule, BuildContext, BuildInfo, BuildMeta, BuildResult, ChunkGraph, CodeGenerationResult,
+  Compilation, CompilerContext, CompilerOptions, ConcatenationScope, ConnectionState, Context,
+  DependenciesBlock, DependencyId, DependencyTemplate, FactoryMeta, GenerateContext,
+  GeneratorOptions, LibIdentOptions, LoaderModuleBuild, Module, ModuleDependency, ModuleGraph,
+  ModuleIdentifier, ModuleType, ParseContext, ParseResult, ParserAndGenerator, ParserOptions,
+  Resolve, RspackLoaderRunnerPlugin, RuntimeGlobals, RuntimeSpec, SourceType,
 };
 
 bitflags! {
@@ -450,13 +450,23 @@ impl Module for NormalModule {
       .call(&mut loader_result.additional_data)
       .await?;
     self.add_diagnostics(ds);
+    if let Some(loader_module_build) = loader_result.additional_data.remove::<LoaderModuleBuild>() {
+      build_info
+        .extras
+        .extend(loader_module_build.build_info_extras);
+      build_meta = loader_module_build.build_meta;
+    }
 
     let content = if self.module_type().is_binary() {
       Content::Buffer(loader_result.content.into_bytes())
     } else {
       Content::String(loader_result.content.into_string_lossy())
     };
-    let original_source = self.create_source(content, loader_result.source_map)?;
+    let original_source = self.create_source(
+      content,
+      loader_result.source_map,
+      build_context.compiler_options,
+    )?;
 
     if no_parse {
       self.parsed = false;
@@ -540,8 +550,12 @@ impl Module for NormalModule {
     build_meta.hash(&mut hasher);
 
     build_info.hash = Some(hasher.digest(&build_context.compiler_options.output.hash_digest));
-    build_info.cacheable = loader_result.cacheable;
-    build_info.file_dependencies = loader_result.file_dependencies;
+    // the parser may also make the module uncacheable or add file dependencies,
+    // e.g. by a `DefinePlugin.runtimeValue`
+    build_info.cacheable &= loader_result.cacheable;
+    build_info
+      .file_dependencies
+      .extend(loader_result.file_dependencies);
     build_info.context_dependencies = loader_result.context_dependencies;
     build_info.missing_dependencies = loader_result.missing_dependencies;
     build_info.build_dependencies = loader_result.build_dependencies;
@@ -750,19 +764,25 @@ impl PartialEq for NormalModule {
 impl Eq for NormalModule {}
 
 impl NormalModule {
-  fn create_source(&self, content: Content, source_map: Option<SourceMap>) -> Result<BoxSource> {
+  fn create_source(
+    &self,
+    content: Content,
+    source_map: Option<SourceMap>,
+    options: &CompilerOptions,
+  ) -> Result<BoxSource> {
     if content.is_buffer() {
       return Ok(RawSource::Buffer(content.into_bytes()).boxed());
     }
     let source_map_kind = self.get_source_map_kind();
     if source_map_kind.enabled()
-      && let Some(source_map) = source_map
+      && let Some(mut source_map) = source_map
     {
       let content = content.into_string_lossy();
+      contextify_source_map(options, &mut source_map);
       return Ok(
         SourceMapSource::new(WithoutOriginalOptions {
           value: content,
-          name: self.request(),
+          name: source_name(options, self.request().to_string()),
           source_map,
         })
         .boxed(),
@@ -771,7 +791,9 @@ impl NormalModule {
     if source_map_kind.enabled()
       && let Content::String(content) = content
     {
-      return Ok(OriginalSource::new(content, self.request()).boxed());
+      return Ok(
+        OriginalSource::new(content, source_name(options, self.request().to_string())).boxed(),
+      );
     }
     Ok(RawSource::from(content.into_string_lossy()).boxed())
   }
@@ -692,7 +692,8 @@ impl NormalModuleFactory {
         | (ParserOptions::Css(_), ParserOptions::Css(_))
         | (ParserOptions::CssAuto(_), ParserOptions::CssAuto(_))
         | (ParserOptions::CssModule(_), ParserOptions::CssModule(_))
-        | (ParserOptions::Javascript(_), ParserOptions::Javascript(_)) => global.merge_from(local),
+        | (ParserOptions::Javascript(_), ParserOptions::Javascript(_))
+        | (ParserOptions::Json(_), ParserOptions::Json(_)) => global.merge_from(local),
         _ => global,
       },
     );
@@ -1,21 +1,83 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
 
+use derivative::Derivative;
+use futures::future::BoxFuture;
 use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
 pub use rspack_swc_visitors::{Define, Provide};
 
-use crate::{ApplyContext, CompilerOptions, Plugin, PluginContext};
+use crate::{
+  ApplyContext, BuildInfo, Compilation, CompilationParams, CompilerOptions,
+  CompilerThisCompilation, Plugin, PluginContext,
+};
 
+pub type DefineRuntimeValueFn = Box<dyn Fn() -> BoxFuture<'static, Result<String>> + Sync + Send>;
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct DefineRuntimeValue {
+  #[derivative(Debug = "ignore")]
+  pub get: DefineRuntimeValueFn,
+  pub file_dependencies: Vec<PathBuf>,
+  pub cacheable: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RuntimeDefineValue {
+  pub code: String,
+  pub file_dependencies: Vec<PathBuf>,
+  pub cacheable: bool,
+}
+
+/// The values of `DefinePlugin.runtimeValue`, they are evaluated again for each compilation
+pub type RuntimeDefine = Arc<RwLock<HashMap<String, RuntimeDefineValue>>>;
+
+#[derive(Debug, Default)]
+pub struct DefinePluginOptions {
+  pub define: Define,
+  pub runtime_values: HashMap<String, DefineRuntimeValue>,
+}
+
+#[plugin]
 #[derive(Debug)]
 pub struct DefinePlugin {
-  options: Define,
+  options: DefinePluginOptions,
+  runtime_define: RwLock<Option<RuntimeDefine>>,
 }
 
 impl DefinePlugin {
-  pub fn new(options: Define) -> Self {
-    Self { options }
+  pub fn new(options: DefinePluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
   }
 }
 
+#[plugin_hook(CompilerThisCompilation for DefinePlugin)]
+async fn this_compilation(
+  &self,
+  _compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  let mut values = HashMap::with_capacity(self.options.runtime_values.len());
+  for (key, runtime_value) in &self.options.runtime_values {
+    let code = (runtime_value.get)().await?;
+    values.insert(
+      key.clone(),
+      RuntimeDefineValue {
+        code,
+        file_dependencies: runtime_value.file_dependencies.clone(),
+        cacheable: runtime_value.cacheable,
+      },
+    );
+  }
+  if let Some(runtime_define) = &*self.runtime_define.read().expect("should lock") {
+    runtime_define.write().expect("should lock").extend(values);
+  }
+  Ok(())
+}
+
 impl Plugin for DefinePlugin {
   fn name(&self) -> &'static str {
     "rspack.DefinePlugin"
@@ -23,10 +85,19 @@ impl Plugin for DefinePlugin {
 
   fn apply(
     &self,
-    _ctx: PluginContext<&mut ApplyContext>,
+    ctx: PluginContext<&mut ApplyContext>,
     options: &mut CompilerOptions,
   ) -> Result<()> {
-    options.builtins.define.extend(self.options.clone());
+    options.builtins.define.extend(self.options.define.clone());
+    if !self.options.runtime_values.is_empty() {
+      *self.runtime_define.write().expect("should lock") =
+        Some(options.builtins.runtime_define.clone());
+      ctx
+        .context
+        .compiler_hooks
+        .this_compilation
+        .tap(this_compilation::new(self));
+    }
     Ok(())
   }
 }
@@ -118,12 +189,27 @@ impl From<String> for TreeShaking {
 pub struct Builtins {
   // TODO: refactor to string-replacement based
   pub define: Define,
+  pub runtime_define: RuntimeDefine,
   // TODO: refactor to string-replacement based
   pub provide: Provide,
   // TODO: refactoring
   pub tree_shaking: TreeShaking,
 }
 
+impl Builtins {
+  /// Whether a value the module is built with is changed, e.g. a `DefinePlugin.runtimeValue`
+  pub fn is_value_dependencies_changed(&self, build_info: &BuildInfo) -> bool {
+    if build_info.value_dependencies.is_empty() {
+      return false;
+    }
+    let runtime_define = self.runtime_define.read().expect("should lock");
+    build_info
+      .value_dependencies
+      .iter()
+      .any(|(key, code)| runtime_define.get(key).map(|value| &value.code) != Some(code))
+  }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct PresetEnv {
   pub targets: Vec<String>,
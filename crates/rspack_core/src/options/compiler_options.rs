@@ -42,4 +42,8 @@ impl CompilerOptions {
   pub fn is_new_tree_shaking(&self) -> bool {
     self.experiments.rspack_future.new_treeshaking
   }
+
+  pub fn is_path_independent(&self) -> bool {
+    self.experiments.path_independent
+  }
 }
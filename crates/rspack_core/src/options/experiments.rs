@@ -24,6 +24,9 @@ impl IncrementalRebuildMakeState {
 #[derive(Debug, Default)]
 pub struct RspackFuture {
   pub new_treeshaking: bool,
+  /// Renders the entry modules of the runtime chunks inline in the startup
+  /// code instead of requiring them from the module factories.
+  pub inline_entry_modules: bool,
 }
 
 #[derive(Debug, Default)]
@@ -31,5 +34,9 @@ pub struct Experiments {
   pub incremental_rebuild: IncrementalRebuild,
   pub new_split_chunks: bool,
   pub top_level_await: bool,
+  /// Makes the names and the source maps of the module sources, and the module
+  /// identifiers used in the hashes, relative to the context, so the output
+  /// doesn't depend on the directory of the project.
+  pub path_independent: bool,
   pub rspack_future: RspackFuture,
 }
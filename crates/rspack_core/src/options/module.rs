@@ -13,7 +13,7 @@ use rspack_regex::RspackRegex;
 use rspack_util::{try_all, try_any, MergeFrom};
 use rustc_hash::FxHashMap as HashMap;
 
-use crate::{Filename, ModuleType, PublicPath, Resolve};
+use crate::{Filename, Module, ModuleType, PublicPath, Resolve};
 
 #[derive(Debug)]
 pub struct ParserOptionsByModuleType(HashMap<ModuleType, ParserOptions>);
@@ -37,6 +37,7 @@ pub enum ParserOptions {
   CssAuto(CssAutoParserOptions),
   CssModule(CssModuleParserOptions),
   Javascript(JavascriptParserOptions),
+  Json(JsonParserOptions),
   Unknown,
 }
 
@@ -57,6 +58,7 @@ impl ParserOptions {
   get_variant!(get_css_auto, CssAuto, CssAutoParserOptions);
   get_variant!(get_css_module, CssModule, CssModuleParserOptions);
   get_variant!(get_javascript, Javascript, JavascriptParserOptions);
+  get_variant!(get_json, Json, JsonParserOptions);
 }
 
 #[derive(Debug, Clone, Copy, Default, MergeFrom)]
@@ -101,6 +103,41 @@ impl From<&str> for JavascriptParserUrl {
   }
 }
 
+#[derive(Debug, Clone, Copy, Default, MergeFrom)]
+pub enum JavascriptParserImportMetaUrl {
+  /// The file url of the module at build time
+  #[default]
+  Resolved,
+  /// The url of the output file at runtime
+  Runtime,
+}
+
+impl From<&str> for JavascriptParserImportMetaUrl {
+  fn from(value: &str) -> Self {
+    match value {
+      "runtime" => Self::Runtime,
+      _ => Self::Resolved,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, Default, MergeFrom)]
+pub enum JavascriptParserImportMetaEnv {
+  #[default]
+  Undefined,
+  /// An object of the defined `import.meta.env.*` values
+  Define,
+}
+
+impl From<&str> for JavascriptParserImportMetaEnv {
+  fn from(value: &str) -> Self {
+    match value {
+      "define" => Self::Define,
+      _ => Self::Undefined,
+    }
+  }
+}
+
 #[derive(Debug, Clone, Copy, Default, MergeFrom)]
 pub enum JavascriptParserOrder {
   #[default]
@@ -141,6 +178,8 @@ pub struct JavascriptParserOptions {
   pub url: JavascriptParserUrl,
   pub expr_context_critical: bool,
   pub wrapped_context_critical: bool,
+  pub import_meta_url: JavascriptParserImportMetaUrl,
+  pub import_meta_env: JavascriptParserImportMetaEnv,
 }
 
 #[derive(Debug, Clone, MergeFrom)]
@@ -174,6 +213,30 @@ pub struct CssModuleParserOptions {
   pub named_exports: Option<bool>,
 }
 
+/// Converts the source of a json module into a json value, e.g. to support JSON5, TOML or YAML.
+pub trait JsonParse: Debug + Sync + Send {
+  fn parse(&self, source: &str) -> Result<json::JsonValue>;
+}
+
+pub type JsonParseFn = Arc<dyn JsonParse>;
+
+#[derive(Debug, Clone)]
+pub struct JsonParserOptions {
+  /// How deep the exports of a json module are analyzed, `None` means no limit.
+  pub exports_depth: Option<u32>,
+  /// Custom parser of the source, `None` means the source is parsed as JSON.
+  pub parse: Option<JsonParseFn>,
+}
+
+impl MergeFrom for JsonParserOptions {
+  fn merge_from(self, other: &Self) -> Self {
+    Self {
+      exports_depth: self.exports_depth.merge_from(&other.exports_depth),
+      parse: other.parse.clone().or(self.parse),
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct GeneratorOptionsByModuleType(HashMap<ModuleType, GeneratorOptions>);
 
@@ -219,6 +282,17 @@ impl GeneratorOptions {
       .or_else(|| self.get_asset_resource().and_then(|x| x.filename.as_ref()))
   }
 
+  pub fn asset_output_path(&self) -> Option<&Filename> {
+    self
+      .get_asset()
+      .and_then(|x| x.output_path.as_ref())
+      .or_else(|| {
+        self
+          .get_asset_resource()
+          .and_then(|x| x.output_path.as_ref())
+      })
+  }
+
   pub fn asset_public_path(&self) -> Option<&PublicPath> {
     self
       .get_asset()
@@ -254,24 +328,48 @@ pub struct AssetInlineGeneratorOptions {
 pub struct AssetResourceGeneratorOptions {
   pub emit: Option<bool>,
   pub filename: Option<Filename>,
+  pub output_path: Option<Filename>,
   pub public_path: Option<PublicPath>,
+  pub responsive: Option<AssetGeneratorResponsiveOptions>,
 }
 
 #[derive(Debug, Clone, MergeFrom)]
 pub struct AssetGeneratorOptions {
   pub emit: Option<bool>,
   pub filename: Option<Filename>,
+  pub output_path: Option<Filename>,
   pub public_path: Option<PublicPath>,
   pub data_url: Option<AssetGeneratorDataUrl>,
+  pub responsive: Option<AssetGeneratorResponsiveOptions>,
+}
+
+/// Generate resized copies of an image asset, and export a descriptor containing
+/// the `srcSet` instead of a single url.
+#[derive(Debug, Clone)]
+pub struct AssetGeneratorResponsiveOptions {
+  /// Widths of the generated images, widths larger than the original image are
+  /// clamped to the original width.
+  pub sizes: Vec<u32>,
+  /// Formats of the generated images, the original format is used if empty.
+  pub formats: Vec<String>,
+  /// Quality used by lossy encoders, from 1 to 100.
+  pub quality: u8,
+}
+
+impl MergeFrom for AssetGeneratorResponsiveOptions {
+  fn merge_from(self, other: &Self) -> Self {
+    other.clone()
+  }
 }
 
-pub struct AssetGeneratorDataUrlFnArgs {
+pub struct AssetGeneratorDataUrlFnArgs<'a> {
   pub filename: String,
   pub content: String,
+  pub module: &'a dyn Module,
 }
 
 pub type AssetGeneratorDataUrlFn =
-  Arc<dyn Fn(AssetGeneratorDataUrlFnArgs) -> Result<String> + Sync + Send>;
+  Arc<dyn for<'a> Fn(AssetGeneratorDataUrlFnArgs<'a>) -> Result<String> + Sync + Send>;
 
 pub enum AssetGeneratorDataUrl {
   Options(AssetGeneratorDataUrlOptions),
@@ -8,7 +8,7 @@ use std::{
 
 use derivative::Derivative;
 use rspack_hash::RspackHash;
-pub use rspack_hash::{HashDigest, HashFunction, HashSalt};
+pub use rspack_hash::{CustomHashFunction, HashDigest, HashFunction, HashSalt};
 use rspack_macros::MergeFrom;
 use sugar_path::SugarPath;
 
@@ -38,6 +38,7 @@ pub struct OutputOptions {
   pub filename: Filename,
   pub chunk_filename: Filename,
   pub cross_origin_loading: CrossOriginLoading,
+  pub charset: bool,
   pub css_filename: Filename,
   pub css_chunk_filename: Filename,
   pub hot_update_main_filename: FilenameTemplate,
@@ -51,6 +52,8 @@ pub struct OutputOptions {
   pub iife: bool,
   pub module: bool,
   pub trusted_types: Option<TrustedTypes>,
+  pub chunk_load_timeout: u32,
+  pub chunk_load_retry: Option<ChunkLoadRetry>,
   pub source_map_filename: FilenameTemplate,
   pub hash_function: HashFunction,
   pub hash_digest: HashDigest,
@@ -61,6 +64,7 @@ pub struct OutputOptions {
   pub worker_wasm_loading: WasmLoading,
   pub worker_public_path: String,
   pub script_type: String,
+  pub environment: Environment,
 }
 
 impl From<&OutputOptions> for RspackHash {
@@ -69,11 +73,74 @@ impl From<&OutputOptions> for RspackHash {
   }
 }
 
+/// The ECMAScript features supported by the environment that runs the output,
+/// the runtime and wrapper code falls back to ES5 for the unsupported ones
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Environment {
+  pub arrow_function: bool,
+  pub big_int_literal: bool,
+  pub r#const: bool,
+  pub destructuring: bool,
+  pub dynamic_import: bool,
+  pub for_of: bool,
+  pub global_this: bool,
+  pub module: bool,
+  pub optional_chaining: bool,
+  pub template_literal: bool,
+}
+
+impl Environment {
+  pub fn supports_const(&self) -> bool {
+    self.r#const
+  }
+
+  pub fn supports_arrow_function(&self) -> bool {
+    self.arrow_function
+  }
+
+  /// The keyword to declare a variable that is never reassigned
+  pub fn declaration_keyword(&self) -> &'static str {
+    if self.supports_const() {
+      "const"
+    } else {
+      "var"
+    }
+  }
+
+  /// A function that returns `return_value`, e.g. `() => (x)` or `function() { return x; }`
+  pub fn returning_function(&self, return_value: &str, args: &str) -> String {
+    if self.supports_arrow_function() {
+      format!("({args}) => ({return_value})")
+    } else {
+      format!("function({args}) {{ return {return_value}; }}")
+    }
+  }
+
+  /// A function with the statements `body`, e.g. `() => { x }` or `function() { x }`
+  pub fn basic_function(&self, args: &str, body: &str) -> String {
+    if self.supports_arrow_function() {
+      format!("({args}) => {{\n{body}\n}}")
+    } else {
+      format!("function({args}) {{\n{body}\n}}")
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct TrustedTypes {
   pub policy_name: Option<String>,
 }
 
+#[derive(Debug)]
+pub struct ChunkLoadRetry {
+  /// The maximum number of retries of a failed script
+  pub times: u32,
+  /// The delay in milliseconds before the first retry, doubled for each retry
+  pub delay: u32,
+  /// The alternate public paths used by the retries in turn
+  pub public_paths: Vec<String>,
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ChunkLoading {
   Enable(ChunkLoadingType),
@@ -21,11 +21,14 @@ impl TargetEsVersion {
 #[derive(Debug, Clone)]
 pub struct Target {
   pub es_version: TargetEsVersion,
+  /// Whether the target is Node.js, e.g. `node`, `async-node14` or `electron-main`
+  pub node: bool,
 }
 
 impl Target {
   pub fn new(args: &Vec<String>) -> Result<Target> {
     let mut es_version = TargetEsVersion::None;
+    let mut node = false;
 
     for item in args {
       let item = item.as_str();
@@ -53,8 +56,18 @@ impl Target {
         es_version = version;
         continue;
       }
+      if is_node_target(item) {
+        node = true;
+      }
     }
 
-    Ok(Target { es_version })
+    Ok(Target { es_version, node })
   }
 }
+
+fn is_node_target(item: &str) -> bool {
+  item.starts_with("node")
+    || item.starts_with("async-node")
+    || (item.starts_with("electron") && (item.ends_with("-main") || item.ends_with("-preload")))
+    || item.starts_with("nwjs")
+}
@@ -4,7 +4,7 @@ use dashmap::DashMap;
 use rustc_hash::FxHasher;
 
 use super::resolver_impl::Resolver;
-use crate::{DependencyCategory, Resolve};
+use crate::{DependencyCategory, Resolve, VirtualModules};
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
 // Actually this should be ResolveOptionsWithDependencyCategory, it's a mistake from webpack, but keep the alignment for easily find the code in webpack
@@ -18,6 +18,7 @@ pub struct ResolveOptionsWithDependencyType {
 pub struct ResolverFactory {
   base_options: Resolve,
   resolver: Resolver,
+  virtual_modules: VirtualModules,
   /// Different resolvers are used for different resolution strategies such as ESM and CJS.
   /// All resolvers share the same underlying cache.
   resolvers: DashMap<ResolveOptionsWithDependencyType, Arc<Resolver>, BuildHasherDefault<FxHasher>>,
@@ -25,7 +26,7 @@ pub struct ResolverFactory {
 
 impl Default for ResolverFactory {
   fn default() -> Self {
-    Self::new(Resolve::default())
+    Self::new(Resolve::default(), VirtualModules::default())
   }
 }
 
@@ -34,14 +35,19 @@ impl ResolverFactory {
     self.resolver.clear_cache();
   }
 
-  pub fn new(options: Resolve) -> Self {
+  pub fn new(options: Resolve, virtual_modules: VirtualModules) -> Self {
     Self {
       base_options: options.clone(),
-      resolver: Resolver::new(options),
+      resolver: Resolver::new(options, virtual_modules.clone()),
+      virtual_modules,
       resolvers: Default::default(),
     }
   }
 
+  pub fn virtual_modules(&self) -> &VirtualModules {
+    &self.virtual_modules
+  }
+
   pub fn get(&self, options: ResolveOptionsWithDependencyType) -> Arc<Resolver> {
     if let Some(r) = self.resolvers.get(&options) {
       r.clone()
@@ -12,7 +12,10 @@ use rspack_loader_runner::DescriptionData;
 use rustc_hash::FxHashSet as HashSet;
 
 use super::{ResolveResult, Resource};
-use crate::{AliasMap, DependencyCategory, Resolve, ResolveArgs, ResolveOptionsWithDependencyType};
+use crate::{
+  AliasMap, DependencyCategory, Resolve, ResolveArgs, ResolveOptionsWithDependencyType,
+  VirtualFileSystem, VirtualModules,
+};
 
 #[derive(Debug, Default, Clone)]
 pub struct ResolveContext {
@@ -77,17 +80,20 @@ impl<'a> ResolveInnerOptions<'a> {
 /// Internal caches are shared.
 #[derive(Debug)]
 pub enum Resolver {
-  OxcResolver(oxc_resolver::Resolver),
+  OxcResolver(oxc_resolver::ResolverGeneric<VirtualFileSystem>),
 }
 
 impl Resolver {
-  pub fn new(options: Resolve) -> Self {
-    Self::new_oxc_resolver(options)
+  pub fn new(options: Resolve, virtual_modules: VirtualModules) -> Self {
+    Self::new_oxc_resolver(options, virtual_modules)
   }
 
-  fn new_oxc_resolver(options: Resolve) -> Self {
+  fn new_oxc_resolver(options: Resolve, virtual_modules: VirtualModules) -> Self {
     let options = to_oxc_resolver_options(options, false, DependencyCategory::Unknown);
-    let resolver = oxc_resolver::Resolver::new(options);
+    let resolver = oxc_resolver::ResolverGeneric::new_with_file_system(
+      VirtualFileSystem::new(virtual_modules),
+      options,
+    );
     Self::OxcResolver(resolver)
   }
 
@@ -315,9 +321,19 @@ fn map_resolver_error(
   let request = &args.specifier;
   let context = &args.context;
 
+  let code = if is_recursion {
+    "RESOLVE_RECURSION"
+  } else {
+    "MODULE_NOT_FOUND"
+  };
+
   let importer = args.importer;
   if importer.is_none() {
-    return diagnostic!("Resolve error: Can't resolve '{request}' in '{context}'").boxed();
+    return diagnostic!(
+      code = code,
+      "Resolve error: Can't resolve '{request}' in '{context}'"
+    )
+    .boxed();
   }
 
   let span = args.span.unwrap_or_default();
@@ -328,6 +344,7 @@ fn map_resolver_error(
     "Resolve error".to_string(),
     message,
   )
+  .with_code(code)
   .with_help(if is_recursion {
     Some("maybe it had cyclic aliases")
   } else {
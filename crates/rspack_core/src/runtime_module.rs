@@ -16,6 +16,10 @@ pub trait RuntimeModule: Module + CustomSourceRuntimeModule {
   fn cacheable(&self) -> bool {
     true
   }
+  // webpack fullHash, the compilation hash is rendered into the module
+  fn full_hash(&self) -> bool {
+    false
+  }
   // if wrap iife
   fn should_isolate(&self) -> bool {
     false
@@ -41,7 +45,7 @@ pub trait CustomSourceRuntimeModule {
 
 pub type BoxRuntimeModule = Box<dyn RuntimeModule>;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum RuntimeModuleStage {
   Normal,  // Runtime modules without any dependencies to other runtime modules
   Basic,   // Runtime modules with simple dependencies on other runtime modules
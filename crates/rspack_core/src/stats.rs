@@ -9,8 +9,10 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use crate::{
   get_chunk_from_ukey, get_chunk_group_from_ukey, ChunkGroupOrderKey, ProvidedExports, UsedExports,
 };
-use crate::{BoxModule, BoxRuntimeModule, Chunk};
-use crate::{ChunkGroupUkey, Compilation, LogType, ModuleIdentifier, ModuleType, SourceType};
+use crate::{BoxModule, BoxRuntimeModule, Chunk, Module};
+use crate::{
+  ChunkGroupUkey, Compilation, LogType, ModuleGraph, ModuleIdentifier, ModuleType, SourceType,
+};
 
 #[derive(Debug, Clone)]
 pub struct Stats<'compilation> {
@@ -144,6 +146,7 @@ impl Stats<'_> {
       .values()
       .map(|module| {
         self.get_module(
+          &module_graph,
           module,
           reasons,
           module_assets,
@@ -173,6 +176,7 @@ impl Stats<'_> {
     &self,
     chunk_modules: bool,
     chunk_relations: bool,
+    chunk_origins: bool,
     reasons: bool,
     module_assets: bool,
     nested_modules: bool,
@@ -202,6 +206,7 @@ impl Stats<'_> {
             .into_iter()
             .map(|m| {
               self.get_module(
+                &module_graph,
                 m,
                 reasons,
                 module_assets,
@@ -224,6 +229,7 @@ impl Stats<'_> {
         } else {
           (None, None, None)
         };
+        let origins = chunk_origins.then(|| self.get_chunk_origins(c, &module_graph));
 
         let orders = vec![ChunkGroupOrderKey::Prefetch, ChunkGroupOrderKey::Preload];
         let mut children_by_order = HashMap::<ChunkGroupOrderKey, Vec<String>>::default();
@@ -246,10 +252,12 @@ impl Stats<'_> {
             .compilation
             .chunk_graph
             .get_chunk_modules_size(&c.ukey, &self.compilation.get_module_graph()),
+          sizes: self.get_chunk_sizes(c, &module_graph),
           modules: chunk_modules,
           parents,
           children,
           siblings,
+          origins,
           children_by_order,
         })
       })
@@ -336,6 +344,7 @@ impl Stats<'_> {
           .unzip();
 
         StatsError {
+          code: d.diagnostic_code(),
           message: diagnostic_displayer
             .emit_diagnostic(d)
             .expect("should print diagnostics"),
@@ -366,6 +375,7 @@ impl Stats<'_> {
           .unzip();
 
         StatsWarning {
+          code: d.diagnostic_code(),
           message: diagnostic_displayer
             .emit_diagnostic(d)
             .expect("should print diagnostics"),
@@ -412,6 +422,7 @@ impl Stats<'_> {
   #[allow(clippy::too_many_arguments)]
   fn get_module<'a>(
     &'a self,
+    module_graph: &'a ModuleGraph,
     module: &'a BoxModule,
     reasons: bool,
     module_assets: bool,
@@ -421,7 +432,6 @@ impl Stats<'_> {
     provided_exports: bool,
   ) -> Result<StatsModule<'a>> {
     let identifier = module.identifier();
-    let module_graph = self.compilation.get_module_graph();
     let mgm = module_graph
       .module_graph_module_by_identifier(&identifier)
       .unwrap_or_else(|| panic!("Could not find ModuleGraphModule by identifier: {identifier:?}"));
@@ -443,6 +453,33 @@ impl Stats<'_> {
     }
     issuer_path.reverse();
 
+    // only the concatenated modules have nested modules
+    let modules = module
+      .as_concatenated_module()
+      .filter(|_| nested_modules)
+      .map(|concatenated_module| -> Result<_> {
+        let mut modules = concatenated_module
+          .get_modules()
+          .iter()
+          .filter_map(|inner| module_graph.module_by_identifier(&inner.id))
+          .map(|inner| {
+            self.get_module(
+              module_graph,
+              inner,
+              reasons,
+              module_assets,
+              false,
+              source,
+              used_exports,
+              provided_exports,
+            )
+          })
+          .collect::<Result<Vec<_>>>()?;
+        Self::sort_modules(&mut modules);
+        Ok(modules)
+      })
+      .transpose()?;
+
     let reasons = reasons
       .then(|| -> Result<_> {
         let mut reasons: Vec<StatsModuleReason> = mgm
@@ -505,8 +542,6 @@ impl Stats<'_> {
       assets
     });
 
-    // TODO: a placeholder for concatenation modules
-    let modules = nested_modules.then(Vec::new);
     let profile = if let Some(p) = mgm.get_profile()
       && let Some(factory) = p.factory.duration()
       && let Some(integration) = p.integration.duration()
@@ -573,6 +608,7 @@ impl Stats<'_> {
         .clone(),
       chunks,
       size: module.size(&SourceType::JavaScript),
+      sizes: get_module_sizes(module.as_ref()),
       issuer: issuer.map(|i| i.identifier().to_string()),
       issuer_name,
       issuer_id: issuer_id.and_then(|i| i),
@@ -582,6 +618,7 @@ impl Stats<'_> {
       modules,
       source: source.then(|| module.original_source()).flatten(),
       profile,
+      depth: module_graph.get_depth(&identifier),
       orphan: self
         .compilation
         .chunk_graph
@@ -619,6 +656,10 @@ impl Stats<'_> {
       id: Some(String::new()),
       chunks,
       size: module.size(&SourceType::JavaScript),
+      sizes: vec![StatsSourceTypeSize {
+        source_type: "runtime".to_string(),
+        size: module.size(&SourceType::JavaScript),
+      }],
       issuer: None,
       issuer_name: None,
       issuer_id: None,
@@ -628,6 +669,7 @@ impl Stats<'_> {
       modules: None,
       source: None,
       profile: None,
+      depth: None,
       orphan: self
         .compilation
         .chunk_graph
@@ -638,6 +680,34 @@ impl Stats<'_> {
       optimization_bailout: vec![],
     })
   }
+  fn get_chunk_sizes(&self, chunk: &Chunk, module_graph: &ModuleGraph) -> Vec<StatsSourceTypeSize> {
+    let mut sizes = self
+      .compilation
+      .chunk_graph
+      .get_chunk_modules_sizes(&chunk.ukey, module_graph)
+      .into_iter()
+      .map(|(source_type, size)| StatsSourceTypeSize {
+        source_type: source_type.to_string(),
+        size,
+      })
+      .collect_vec();
+    let runtime_size = self
+      .compilation
+      .chunk_graph
+      .get_chunk_runtime_modules_iterable(&chunk.ukey)
+      .filter_map(|identifier| self.compilation.runtime_modules.get(identifier))
+      .map(|module| module.size(&SourceType::JavaScript))
+      .sum::<f64>();
+    if runtime_size > 0.0 {
+      sizes.push(StatsSourceTypeSize {
+        source_type: "runtime".to_string(),
+        size: runtime_size,
+      });
+    }
+    sizes.sort_unstable_by(|a, b| a.source_type.cmp(&b.source_type));
+    sizes
+  }
+
   fn get_chunk_relations(&self, chunk: &Chunk) -> (Vec<String>, Vec<String>, Vec<String>) {
     let mut parents = HashSet::default();
     let mut children = HashSet::default();
@@ -688,6 +758,91 @@ impl Stats<'_> {
     siblings.sort();
     (parents, children, siblings)
   }
+
+  fn get_chunk_origins(&self, chunk: &Chunk, module_graph: &ModuleGraph) -> Vec<StatsOriginRecord> {
+    let mut origins: Vec<StatsOriginRecord> = vec![];
+    for cg in &chunk.groups {
+      let Some(cg) = get_chunk_group_from_ukey(cg, &self.compilation.chunk_group_by_ukey) else {
+        continue;
+      };
+      for origin in &cg.origins {
+        let module = origin
+          .module
+          .and_then(|m| module_graph.module_by_identifier(&m));
+        let (module_identifier, module_name, module_id, loc) = if let Some(module) = module {
+          let (name, id) = get_stats_module_name_and_id(module, self.compilation);
+          let loc = origin
+            .loc
+            .and_then(|loc| {
+              module
+                .original_source()
+                .map(|source| format_location(&source.source(), loc.start(), loc.end()))
+            })
+            .unwrap_or_default();
+          (
+            module.identifier().to_string(),
+            name,
+            id.unwrap_or_default(),
+            loc,
+          )
+        } else {
+          // the origin of an entrypoint is located by the name of the entry
+          let loc = cg.name().unwrap_or_default().to_string();
+          (String::new(), String::new(), String::new(), loc)
+        };
+        let record = StatsOriginRecord {
+          module: module_identifier.clone(),
+          module_id,
+          module_identifier,
+          module_name,
+          loc,
+          request: origin.request.clone().unwrap_or_default(),
+        };
+        if !origins.contains(&record) {
+          origins.push(record);
+        }
+      }
+    }
+    origins
+      .sort_by(|a, b| (&a.module_id, &a.loc, &a.request).cmp(&(&b.module_id, &b.loc, &b.request)));
+    origins
+  }
+}
+
+/// Formats the byte offsets the same way as webpack, `line:column-column` or
+/// `line:column-line:column`, lines are 1-based and columns are 0-based.
+fn format_location(source: &str, start: u32, end: u32) -> String {
+  let position = |offset: u32| {
+    let offset = (offset as usize).min(source.len());
+    let before = source.get(..offset).unwrap_or_default();
+    let line = before.matches('\n').count() + 1;
+    let column = before
+      .rfind('\n')
+      .map_or(before, |i| &before[i + 1..])
+      .chars()
+      .count();
+    (line, column)
+  };
+  let (start_line, start_column) = position(start);
+  let (end_line, end_column) = position(end);
+  if start_line == end_line {
+    format!("{start_line}:{start_column}-{end_column}")
+  } else {
+    format!("{start_line}:{start_column}-{end_line}:{end_column}")
+  }
+}
+
+fn get_module_sizes(module: &dyn Module) -> Vec<StatsSourceTypeSize> {
+  let mut sizes = module
+    .source_types()
+    .iter()
+    .map(|source_type| StatsSourceTypeSize {
+      source_type: source_type.to_string(),
+      size: module.size(source_type),
+    })
+    .collect_vec();
+  sizes.sort_unstable_by(|a, b| a.source_type.cmp(&b.source_type));
+  sizes
 }
 
 fn get_stats_module_name_and_id(
@@ -702,6 +857,7 @@ fn get_stats_module_name_and_id(
 
 #[derive(Debug)]
 pub struct StatsError {
+  pub code: String,
   pub message: String,
   pub formatted: String,
   pub module_identifier: Option<String>,
@@ -711,6 +867,7 @@ pub struct StatsError {
 
 #[derive(Debug)]
 pub struct StatsWarning {
+  pub code: String,
   pub message: String,
   pub formatted: String,
   pub module_identifier: Option<String>,
@@ -752,6 +909,7 @@ pub struct StatsModule<'a> {
   pub id: Option<String>,
   pub chunks: Vec<Option<String>>, // has id after the call of chunkIds hook
   pub size: f64,
+  pub sizes: Vec<StatsSourceTypeSize>,
   pub issuer: Option<String>,
   pub issuer_name: Option<String>,
   pub issuer_id: Option<String>,
@@ -761,12 +919,19 @@ pub struct StatsModule<'a> {
   pub modules: Option<Vec<StatsModule<'a>>>,
   pub source: Option<&'a dyn Source>,
   pub profile: Option<StatsModuleProfile>,
+  pub depth: Option<usize>,
   pub orphan: bool,
   pub provided_exports: Option<Vec<String>>,
   pub used_exports: Option<StatsUsedExports>,
   pub optimization_bailout: Vec<String>,
 }
 
+#[derive(Debug)]
+pub struct StatsSourceTypeSize {
+  pub source_type: String,
+  pub size: f64,
+}
+
 #[derive(Debug)]
 pub enum StatsUsedExports {
   Vec(Vec<String>),
@@ -791,13 +956,25 @@ pub struct StatsChunk<'a> {
   pub initial: bool,
   pub names: Vec<String>,
   pub size: f64,
+  pub sizes: Vec<StatsSourceTypeSize>,
   pub modules: Option<Vec<StatsModule<'a>>>,
   pub parents: Option<Vec<String>>,
   pub children: Option<Vec<String>>,
   pub siblings: Option<Vec<String>>,
+  pub origins: Option<Vec<StatsOriginRecord>>,
   pub children_by_order: HashMap<ChunkGroupOrderKey, Vec<String>>,
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub struct StatsOriginRecord {
+  pub module: String,
+  pub module_id: String,
+  pub module_identifier: String,
+  pub module_name: String,
+  pub loc: String,
+  pub request: String,
+}
+
 #[derive(Debug)]
 pub struct StatsChunkGroupAsset {
   pub name: String,
@@ -7,7 +7,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_util::identifier::absolute_to_request;
 
-use crate::ModuleRuleUseLoader;
+use crate::{CompilerOptions, ModuleRuleUseLoader};
 
 pub fn contextify(context: impl AsRef<Path>, request: &str) -> String {
   let context = context.as_ref();
@@ -18,6 +18,16 @@ pub fn contextify(context: impl AsRef<Path>, request: &str) -> String {
     .join("!")
 }
 
+/// The module identifier to hash, which is relative to the context if
+/// `experiments.pathIndependent` is enabled.
+pub fn hashable_identifier<'a>(options: &CompilerOptions, identifier: &'a str) -> Cow<'a, str> {
+  if options.is_path_independent() {
+    Cow::Owned(contextify(&options.context, identifier))
+  } else {
+    Cow::Borrowed(identifier)
+  }
+}
+
 static IDENTIFIER_NAME_REPLACE_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"^([^a-zA-Z$_])").expect("should init regex"));
 static IDENTIFIER_REGEXP: Lazy<Regex> =
@@ -1,4 +1,8 @@
-use rspack_sources::Source;
+use std::borrow::Cow;
+
+use rspack_sources::{Source, SourceMap};
+
+use crate::CompilerOptions;
 
 pub fn is_source_equal(a: &dyn Source, b: &dyn Source) -> bool {
   let a_source = a.buffer();
@@ -6,3 +10,25 @@ pub fn is_source_equal(a: &dyn Source, b: &dyn Source) -> bool {
 
   a_source == b_source
 }
+
+/// The name of a module source, which is relative to the context if
+/// `experiments.pathIndependent` is enabled.
+pub fn source_name(options: &CompilerOptions, name: String) -> String {
+  if options.is_path_independent() {
+    options.context.shorten(&name)
+  } else {
+    name
+  }
+}
+
+/// Makes the sources of the source map of a module relative to the context if
+/// `experiments.pathIndependent` is enabled, the hashes of the module sources
+/// are computed with their source maps.
+pub fn contextify_source_map(options: &CompilerOptions, source_map: &mut SourceMap) {
+  if !options.is_path_independent() {
+    return;
+  }
+  for source in source_map.sources_mut() {
+    *source = Cow::Owned(options.context.shorten(source));
+  }
+}
@@ -0,0 +1,136 @@
+use std::{
+  io,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use dashmap::DashMap;
+use oxc_resolver::{FileMetadata, FileSystem};
+use sugar_path::SugarPath;
+
+/// Modules that only exist in memory.
+///
+/// They are resolved and read like files on the disk, a virtual module shadows
+/// the file with the same path.
+#[derive(Debug, Default, Clone)]
+pub struct VirtualModules(Arc<DashMap<PathBuf, Arc<[u8]>>>);
+
+impl VirtualModules {
+  pub fn write(&self, path: impl AsRef<Path>, content: impl Into<Vec<u8>>) {
+    self
+      .0
+      .insert(path.as_ref().normalize(), Arc::from(content.into()));
+  }
+
+  pub fn remove(&self, path: impl AsRef<Path>) -> bool {
+    self.0.remove(&path.as_ref().normalize()).is_some()
+  }
+
+  pub fn read(&self, path: &Path) -> Option<Arc<[u8]>> {
+    if self.0.is_empty() {
+      return None;
+    }
+    self.0.get(&path.normalize()).map(|content| content.clone())
+  }
+
+  pub fn is_file(&self, path: &Path) -> bool {
+    !self.0.is_empty() && self.0.contains_key(&path.normalize())
+  }
+
+  /// A directory exists if it contains any virtual module.
+  pub fn is_dir(&self, path: &Path) -> bool {
+    if self.0.is_empty() {
+      return false;
+    }
+    let path = path.normalize();
+    self
+      .0
+      .iter()
+      .any(|entry| entry.key() != &path && entry.key().starts_with(&path))
+  }
+}
+
+/// The file system of the resolver, virtual modules take precedence over the
+/// files on the disk.
+#[derive(Debug, Default)]
+pub struct VirtualFileSystem {
+  virtual_modules: VirtualModules,
+}
+
+impl VirtualFileSystem {
+  pub fn new(virtual_modules: VirtualModules) -> Self {
+    Self { virtual_modules }
+  }
+
+  /// Virtual modules shadow the files on the disk, and directories without
+  /// any file on the disk are only checked at last.
+  fn metadata_with(
+    &self,
+    path: &Path,
+    metadata: impl FnOnce(&Path) -> io::Result<std::fs::Metadata>,
+  ) -> io::Result<FileMetadata> {
+    if self.virtual_modules.is_file(path) {
+      return Ok(FileMetadata::new(true, false, false));
+    }
+    match metadata(path) {
+      Err(_) if self.virtual_modules.is_dir(path) => Ok(FileMetadata::new(false, true, false)),
+      result => result.map(FileMetadata::from),
+    }
+  }
+}
+
+impl FileSystem for VirtualFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    match self.virtual_modules.read(path) {
+      Some(content) => String::from_utf8(content.to_vec())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+      None => std::fs::read_to_string(path),
+    }
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    self.metadata_with(path, |path| std::fs::metadata(path))
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    self.metadata_with(path, |path| std::fs::symlink_metadata(path))
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    if self.virtual_modules.is_file(path) {
+      return Ok(path.to_path_buf());
+    }
+    match dunce::canonicalize(path) {
+      Err(_) if self.virtual_modules.is_dir(path) => Ok(path.to_path_buf()),
+      result => result,
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn should_shadow_the_disk() {
+    let virtual_modules = VirtualModules::default();
+    let fs = VirtualFileSystem::new(virtual_modules.clone());
+    let dir = std::env::temp_dir().join("rspack-virtual-modules");
+    let file = dir.join("nested/index.js");
+    virtual_modules.write(&file, "export default 1");
+
+    assert_eq!(
+      fs.read_to_string(&file).expect("should read"),
+      "export default 1"
+    );
+    assert!(fs.metadata(&file).is_ok());
+    assert!(virtual_modules.is_file(&file));
+    assert!(virtual_modules.is_dir(&dir));
+    assert!(fs.metadata(&dir.join("nested")).is_ok());
+    assert!(fs.metadata(&dir.join("index.js")).is_err());
+
+    assert!(virtual_modules.remove(&file));
+    assert!(fs.metadata(&file).is_err());
+    assert!(fs.read_to_string(&file).is_err());
+  }
+}
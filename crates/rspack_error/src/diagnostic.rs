@@ -125,17 +125,73 @@ impl Diagnostic {
     self.1.set_module_identifier(module_identifier);
     self
   }
+
+  /// The stable code of the diagnostic for programmatic consumers, e.g. `MODULE_NOT_FOUND`.
+  ///
+  /// Uses the innermost explicit code in the chain of the inner errors, or
+  /// `UNKNOWN_ERROR` / `UNKNOWN_WARNING` if none of them has one.
+  pub fn diagnostic_code(&self) -> String {
+    if let Some(code) = self.1.code {
+      return code.to_string();
+    }
+    let mut code = None;
+    let mut current: Option<&dyn miette::Diagnostic> = Some(&**self.0);
+    while let Some(diagnostic) = current {
+      if let Some(c) = diagnostic
+        .code()
+        .and_then(|c| stable_diagnostic_code(&c.to_string()))
+      {
+        code = Some(c);
+      }
+      current = diagnostic.diagnostic_source();
+    }
+    code.unwrap_or_else(|| match self.severity() {
+      Severity::Error => "UNKNOWN_ERROR".to_string(),
+      Severity::Warn => "UNKNOWN_WARNING".to_string(),
+    })
+  }
+
+  pub fn with_diagnostic_code(mut self, code: &'static str) -> Self {
+    self.1.set_code(code);
+    self
+  }
 }
 
 #[derive(Debug, Default, Clone)]
 struct DiagnosticMeta {
   module_identifier: Option<Identifier>,
+  code: Option<&'static str>,
 }
 
 impl DiagnosticMeta {
   fn set_module_identifier(&mut self, module_identifier: Option<Identifier>) {
     self.module_identifier = module_identifier;
   }
+
+  fn set_code(&mut self, code: &'static str) {
+    self.code = Some(code);
+  }
+}
+
+/// The codes set by `TraceableError::with_code` are already stable, the codes
+/// of the wrapping errors are mapped explicitly, anything else is not stable.
+fn stable_diagnostic_code(code: &str) -> Option<String> {
+  let code = match code {
+    "ModuleBuildError" => "MODULE_BUILD_FAILED",
+    "ModuleParseError" => "MODULE_PARSE_FAILED",
+    "ModuleParseWarning" => "MODULE_PARSE_WARNING",
+    "AsyncDependencyToInitialChunkError" => "ASYNC_DEPENDENCY_TO_INITIAL_CHUNK",
+    code
+      if !code.is_empty()
+        && code
+          .chars()
+          .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_') =>
+    {
+      code
+    }
+    _ => return None,
+  };
+  Some(code.to_string())
 }
 
 pub trait Diagnosable {
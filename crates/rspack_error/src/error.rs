@@ -54,9 +54,14 @@ pub struct TraceableError {
   label: SourceSpan,
   help: Option<String>,
   url: Option<String>,
+  code: Option<&'static str>,
 }
 
 impl Diagnostic for TraceableError {
+  fn code(&self) -> Option<Box<dyn Display + '_>> {
+    self.code.map(|c| Box::new(c) as Box<dyn Display>)
+  }
+
   fn severity(&self) -> Option<Severity> {
     Some(self.severity)
   }
@@ -115,6 +120,11 @@ impl TraceableError {
     self
   }
 
+  pub fn with_code(mut self, code: &'static str) -> Self {
+    self.code = Some(code);
+    self
+  }
+
   pub fn from_source_file(
     source_file: &SourceFile,
     start: usize,
@@ -134,6 +144,7 @@ impl TraceableError {
       label: SourceSpan::new(start.into(), end.saturating_sub(start).into()),
       help: None,
       url: None,
+      code: None,
     }
   }
 
@@ -153,6 +164,7 @@ impl TraceableError {
       label: SourceSpan::new(start.into(), end.saturating_sub(start).into()),
       help: None,
       url: None,
+      code: None,
     }
   }
 
@@ -166,6 +178,7 @@ impl TraceableError {
       label: SourceSpan::new(start.into(), end.saturating_sub(start).into()),
       help: None,
       url: None,
+      code: None,
     }
   }
 
@@ -10,6 +10,9 @@ pub use sync::{FileSystem, ReadableFileSystem, WritableFileSystem};
 mod error;
 pub use error::{Error, Result};
 
+mod memory;
+pub use memory::MemoryFileSystem;
+
 cfg_native! {
   mod native;
   pub use native::{NativeFileSystem};
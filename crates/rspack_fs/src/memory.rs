@@ -0,0 +1,234 @@
+use std::{
+  collections::{HashMap, HashSet},
+  io,
+  path::{Component, Path, PathBuf},
+  sync::{Arc, Mutex},
+};
+
+use super::{
+  cfg_async,
+  sync::{ReadableFileSystem, WritableFileSystem},
+  Error, Result,
+};
+
+#[derive(Debug, Default)]
+struct MemoryFileSystemInner {
+  files: HashMap<PathBuf, Vec<u8>>,
+  dirs: HashSet<PathBuf>,
+}
+
+/// A file system that keeps everything in memory, the clones share the same
+/// files, so it can be used to inspect the output in tests.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryFileSystem(Arc<Mutex<MemoryFileSystemInner>>);
+
+fn normalize(path: &Path) -> PathBuf {
+  let mut normalized = PathBuf::new();
+  for component in path.components() {
+    match component {
+      Component::CurDir => {}
+      Component::ParentDir => {
+        normalized.pop();
+      }
+      component => normalized.push(component),
+    }
+  }
+  normalized
+}
+
+fn is_root(path: &Path) -> bool {
+  path.parent().is_none()
+}
+
+fn not_found(path: &Path) -> Error {
+  Error::from(io::Error::new(
+    io::ErrorKind::NotFound,
+    format!("no such file or directory: {}", path.display()),
+  ))
+}
+
+impl MemoryFileSystem {
+  fn inner(&self) -> std::sync::MutexGuard<'_, MemoryFileSystemInner> {
+    self
+      .0
+      .lock()
+      .expect("failed to lock the memory file system")
+  }
+
+  /// Returns the paths of all the files, sorted.
+  pub fn files(&self) -> Vec<PathBuf> {
+    let mut files = self.inner().files.keys().cloned().collect::<Vec<_>>();
+    files.sort_unstable();
+    files
+  }
+
+  pub fn exists<P: AsRef<Path>>(&self, path: P) -> bool {
+    let path = normalize(path.as_ref());
+    let inner = self.inner();
+    inner.files.contains_key(&path) || inner.dirs.contains(&path)
+  }
+
+  fn create_dir_sync(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir);
+    let mut inner = self.inner();
+    if inner.dirs.contains(&dir) || inner.files.contains_key(&dir) {
+      return Err(Error::from(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        format!("file exists: {}", dir.display()),
+      )));
+    }
+    if let Some(parent) = dir.parent() {
+      if !is_root(parent) && !inner.dirs.contains(parent) {
+        return Err(not_found(parent));
+      }
+    }
+    inner.dirs.insert(dir);
+    Ok(())
+  }
+
+  fn create_dir_all_sync(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir);
+    let mut inner = self.inner();
+    for ancestor in dir.ancestors() {
+      if is_root(ancestor) {
+        break;
+      }
+      if inner.files.contains_key(ancestor) {
+        return Err(Error::from(io::Error::new(
+          io::ErrorKind::AlreadyExists,
+          format!("not a directory: {}", ancestor.display()),
+        )));
+      }
+      inner.dirs.insert(ancestor.to_path_buf());
+    }
+    Ok(())
+  }
+
+  fn write_sync(&self, file: &Path, data: &[u8]) -> Result<()> {
+    let file = normalize(file);
+    let mut inner = self.inner();
+    if let Some(parent) = file.parent() {
+      if !is_root(parent) && !inner.dirs.contains(parent) {
+        return Err(not_found(parent));
+      }
+    }
+    inner.files.insert(file, data.to_vec());
+    Ok(())
+  }
+
+  fn read_sync(&self, file: &Path) -> Result<Vec<u8>> {
+    let file = normalize(file);
+    self
+      .inner()
+      .files
+      .get(&file)
+      .cloned()
+      .ok_or_else(|| not_found(&file))
+  }
+}
+
+impl WritableFileSystem for MemoryFileSystem {
+  fn create_dir<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.create_dir_sync(dir.as_ref())
+  }
+
+  fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.create_dir_all_sync(dir.as_ref())
+  }
+
+  fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> Result<()> {
+    self.write_sync(file.as_ref(), data.as_ref())
+  }
+}
+
+impl ReadableFileSystem for MemoryFileSystem {
+  fn read<P: AsRef<Path>>(&self, file: P) -> Result<Vec<u8>> {
+    self.read_sync(file.as_ref())
+  }
+}
+
+cfg_async! {
+  use futures::future::BoxFuture;
+
+  use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+
+  impl AsyncWritableFileSystem for MemoryFileSystem {
+    fn create_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let result = self.create_dir_sync(dir.as_ref());
+      Box::pin(async move { result })
+    }
+
+    fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let result = self.create_dir_all_sync(dir.as_ref());
+      Box::pin(async move { result })
+    }
+
+    fn write<P: AsRef<Path>, D: AsRef<[u8]>>(
+      &self,
+      file: P,
+      data: D,
+    ) -> BoxFuture<'_, Result<()>> {
+      let result = self.write_sync(file.as_ref(), data.as_ref());
+      Box::pin(async move { result })
+    }
+
+    fn remove_file<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<()>> {
+      let file = normalize(file.as_ref());
+      let result = match self.inner().files.remove(&file) {
+        Some(_) => Ok(()),
+        None => Err(not_found(&file)),
+      };
+      Box::pin(async move { result })
+    }
+
+    fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let dir = normalize(dir.as_ref());
+      let mut inner = self.inner();
+      let result = if inner.dirs.contains(&dir) {
+        inner.files.retain(|file, _| !file.starts_with(&dir));
+        inner.dirs.retain(|d| !d.starts_with(&dir));
+        Ok(())
+      } else {
+        Err(not_found(&dir))
+      };
+      Box::pin(async move { result })
+    }
+  }
+
+  impl AsyncReadableFileSystem for MemoryFileSystem {
+    fn read<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<Vec<u8>>> {
+      let result = self.read_sync(file.as_ref());
+      Box::pin(async move { result })
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn should_write_and_read_files() {
+    let fs = MemoryFileSystem::default();
+    assert!(WritableFileSystem::write(&fs, "/dist/main.js", "").is_err());
+
+    WritableFileSystem::create_dir_all(&fs, "/dist/assets").expect("should create dirs");
+    WritableFileSystem::write(&fs, "/dist/main.js", "console.log(1)").expect("should write");
+    WritableFileSystem::write(&fs, "/dist/assets/../index.html", "<html>").expect("should write");
+
+    assert_eq!(
+      ReadableFileSystem::read(&fs, "/dist/main.js").expect("should read"),
+      b"console.log(1)"
+    );
+    assert!(fs.exists("/dist/assets"));
+    assert_eq!(
+      fs.files(),
+      vec![
+        PathBuf::from("/dist/index.html"),
+        PathBuf::from("/dist/main.js")
+      ]
+    );
+    assert!(WritableFileSystem::create_dir(&fs, "/dist").is_err());
+    assert!(WritableFileSystem::create_dir(&fs, "/other/nested").is_err());
+  }
+}
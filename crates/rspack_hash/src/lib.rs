@@ -1,25 +1,40 @@
 use std::{
   fmt,
   hash::{Hash, Hasher},
+  sync::Arc,
 };
 
-use data_encoding::HEXLOWER_PERMISSIVE;
+use data_encoding::{BASE64, BASE64URL_NOPAD, HEXLOWER_PERMISSIVE};
 use md4::Digest;
+use rspack_error::{error, Result};
 use smol_str::SmolStr;
 use xxhash_rust::xxh3;
 
-#[derive(Debug, Clone, Copy)]
+/// A hash function implemented outside of rspack, e.g. by a JS callback, it's called
+/// with all the data written to the hash and returns the digest.
+pub trait CustomHashFunction: fmt::Debug + Send + Sync {
+  fn digest(&self, data: &[u8]) -> Vec<u8>;
+}
+
+#[derive(Debug, Clone)]
 pub enum HashFunction {
   Xxhash64,
   MD4,
+  SHA256,
+  Custom(Arc<dyn CustomHashFunction>),
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "md4" => Ok(HashFunction::MD4),
+      "sha256" => Ok(HashFunction::SHA256),
+      _ => Err(error!(
+        "Unsupported hash function \"{value}\", expected md4, xxhash64 or sha256"
+      )),
     }
   }
 }
@@ -27,13 +42,21 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  Base64,
+  Base64Url,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashDigest {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      "base64url" => Ok(HashDigest::Base64Url),
+      _ => Err(error!(
+        "Unsupported hash digest \"{value}\", expected hex, base64 or base64url"
+      )),
     }
   }
 }
@@ -57,6 +80,9 @@ impl From<Option<String>> for HashSalt {
 pub enum RspackHash {
   Xxhash64(Box<xxh3::Xxh3>),
   MD4(md4::Md4),
+  SHA256(sha2::Sha256),
+  /// The data is buffered until the digest, as the custom function hashes it at once
+  Custom(Arc<dyn CustomHashFunction>, Vec<u8>),
 }
 
 impl fmt::Debug for RspackHash {
@@ -64,6 +90,8 @@ impl fmt::Debug for RspackHash {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
+      Self::SHA256(_) => write!(f, "RspackHash(SHA256)"),
+      Self::Custom(function, _) => write!(f, "RspackHash({function:?})"),
     }
   }
 }
@@ -73,6 +101,8 @@ impl RspackHash {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(xxh3::Xxh3::new())),
       HashFunction::MD4 => Self::MD4(md4::Md4::new()),
+      HashFunction::SHA256 => Self::SHA256(sha2::Sha256::new()),
+      HashFunction::Custom(function) => Self::Custom(function.clone(), Vec::new()),
     }
   }
 
@@ -88,6 +118,8 @@ impl RspackHash {
     let inner = match self {
       RspackHash::Xxhash64(hasher) => hasher.finish().to_le_bytes().to_vec(),
       RspackHash::MD4(hash) => hash.finalize().to_vec(),
+      RspackHash::SHA256(hash) => hash.finalize().to_vec(),
+      RspackHash::Custom(function, data) => function.digest(&data),
     };
     RspackHashDigest::new(inner, digest)
   }
@@ -97,19 +129,10 @@ impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.finish(),
-      RspackHash::MD4(hasher) => {
-        // finalize take ownership, so we need to clone it
-        let hash = hasher.clone().finalize();
-        let msb_u64: u64 = ((hash[0] as u64) << 56)
-          | ((hash[1] as u64) << 48)
-          | ((hash[2] as u64) << 40)
-          | ((hash[3] as u64) << 32)
-          | ((hash[4] as u64) << 24)
-          | ((hash[5] as u64) << 16)
-          | ((hash[6] as u64) << 8)
-          | (hash[7] as u64);
-        msb_u64
-      }
+      // finalize take ownership, so we need to clone it
+      RspackHash::MD4(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::SHA256(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Custom(function, data) => msb_u64(&function.digest(data)),
     }
   }
 
@@ -117,10 +140,20 @@ impl Hasher for RspackHash {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
+      RspackHash::SHA256(hasher) => hasher.update(bytes),
+      RspackHash::Custom(_, data) => data.extend_from_slice(bytes),
     }
   }
 }
 
+// the most significant 8 bytes of the hash, a custom hash function may return less bytes
+fn msb_u64(hash: &[u8]) -> u64 {
+  hash
+    .iter()
+    .take(8)
+    .fold(0, |msb, byte| (msb << 8) | (*byte as u64))
+}
+
 #[derive(Debug, Clone, Eq)]
 pub struct RspackHashDigest {
   encoded: SmolStr,
@@ -130,6 +163,8 @@ impl RspackHashDigest {
   pub fn new(inner: Vec<u8>, digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => HEXLOWER_PERMISSIVE.encode(&inner).into(),
+      HashDigest::Base64 => BASE64.encode(&inner).into(),
+      HashDigest::Base64Url => BASE64URL_NOPAD.encode(&inner).into(),
     };
     Self { encoded }
   }
@@ -2,6 +2,11 @@ use async_trait::async_trait;
 use rspack_error::Result;
 use rustc_hash::FxHashSet;
 
+mod timing;
+pub use timing::{
+  enable_hook_timing, hook_timings, is_hook_timing_enabled, take_hook_timings, HookTiming,
+};
+
 #[async_trait]
 pub trait Interceptor<H: Hook> {
   async fn call(&self, _hook: &H) -> Result<Vec<<H as Hook>::Tap>> {
@@ -33,6 +38,8 @@ pub mod __macro_helper {
   pub use futures_concurrency;
   pub use rspack_error::Result;
   pub use rustc_hash::FxHashSet;
+
+  pub use crate::timing::{hook_timing_end, hook_timing_start};
 }
 
 pub use rspack_macros::{define_hook, plugin, plugin_hook};
@@ -0,0 +1,85 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use rustc_hash::FxHashMap;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+type Timings = FxHashMap<(&'static str, &'static str), HookTiming>;
+
+static TIMINGS: Mutex<Option<Timings>> = Mutex::new(None);
+
+/// The wall time spent in a tap of a hook.
+#[derive(Debug, Clone)]
+pub struct HookTiming {
+  pub hook: &'static str,
+  pub tap: &'static str,
+  pub calls: u32,
+  pub duration: Duration,
+}
+
+/// Starts or stops recording the time spent in the taps of all the hooks,
+/// the recorded timings are kept until they are taken.
+pub fn enable_hook_timing(enabled: bool) {
+  ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_hook_timing_enabled() -> bool {
+  ENABLED.load(Ordering::Relaxed)
+}
+
+/// Takes the recorded timings, sorted by the time spent in the taps.
+pub fn take_hook_timings() -> Vec<HookTiming> {
+  let timings = TIMINGS
+    .lock()
+    .expect("should lock the hook timings")
+    .take()
+    .unwrap_or_default();
+  sort_hook_timings(timings.into_values().collect())
+}
+
+/// Copies the recorded timings without taking them, sorted by the time spent in the taps.
+pub fn hook_timings() -> Vec<HookTiming> {
+  let timings = TIMINGS
+    .lock()
+    .expect("should lock the hook timings")
+    .as_ref()
+    .map(|timings| timings.values().cloned().collect())
+    .unwrap_or_default();
+  sort_hook_timings(timings)
+}
+
+fn sort_hook_timings(mut timings: Vec<HookTiming>) -> Vec<HookTiming> {
+  timings.sort_by(|a, b| {
+    b.duration
+      .cmp(&a.duration)
+      .then_with(|| (a.hook, a.tap).cmp(&(b.hook, b.tap)))
+  });
+  timings
+}
+
+#[doc(hidden)]
+pub fn hook_timing_start() -> Option<Instant> {
+  is_hook_timing_enabled().then(Instant::now)
+}
+
+#[doc(hidden)]
+pub fn hook_timing_end(hook: &'static str, tap: &'static str, start: Option<Instant>) {
+  let Some(start) = start else {
+    return;
+  };
+  let elapsed = start.elapsed();
+  let mut timings = TIMINGS.lock().expect("should lock the hook timings");
+  let timing = timings
+    .get_or_insert_with(Default::default)
+    .entry((hook, tap))
+    .or_insert_with(|| HookTiming {
+      hook,
+      tap,
+      calls: 0,
+      duration: Duration::ZERO,
+    });
+  timing.calls += 1;
+  timing.duration += elapsed;
+}
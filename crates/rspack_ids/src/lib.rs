@@ -1,4 +1,5 @@
 #![feature(iter_intersperse)]
+#![feature(let_chains)]
 mod deterministic_module_ids_plugin;
 pub use deterministic_module_ids_plugin::*;
 mod named_module_ids_plugin;
@@ -8,3 +9,5 @@ mod named_chunk_ids_plugin;
 pub use named_chunk_ids_plugin::*;
 mod deterministic_chunk_ids_plugin;
 pub use deterministic_chunk_ids_plugin::DeterministicChunkIdsPlugin;
+mod record_ids_plugin;
+pub use record_ids_plugin::*;
@@ -0,0 +1,252 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use rspack_core::{
+  ApplyContext, Chunk, Compilation, CompilationChunkIds, CompilationModuleIds, CompilationParams,
+  CompilerAfterEmit, CompilerOptions, CompilerThisCompilation, Plugin, PluginContext,
+};
+use rspack_error::{error, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::identifier::make_paths_relative;
+use serde::{Deserialize, Serialize};
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/RecordIdsPlugin.js
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct Records {
+  modules: ModuleRecords,
+  chunks: ChunkRecords,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct ModuleRecords {
+  /// The relative module identifier -> module id
+  by_identifier: BTreeMap<String, String>,
+  used_ids: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct ChunkRecords {
+  /// The chunk name -> chunk id
+  by_name: BTreeMap<String, String>,
+  /// The chunk source -> chunk id, the sources are the chunk groups which
+  /// the chunk belongs to, used by the chunks without names
+  by_source: BTreeMap<String, String>,
+  used_ids: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct RecordIdsPluginOptions {
+  /// The records file to read the ids from
+  pub input_path: Option<PathBuf>,
+  /// The records file to write the ids to
+  pub output_path: Option<PathBuf>,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct RecordIdsPlugin {
+  options: RecordIdsPluginOptions,
+  /// The records read at the start of the compilation, the module records and
+  /// the chunk records are taken by the hooks assigning the ids
+  records: Mutex<Records>,
+}
+
+impl RecordIdsPlugin {
+  pub fn new(options: RecordIdsPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+
+  fn read_records(&self) -> Result<Records> {
+    let Some(path) = &self.options.input_path else {
+      return Ok(Records::default());
+    };
+    match std::fs::read_to_string(path) {
+      Ok(content) => serde_json::from_str(&content)
+        .map_err(|e| error!("Cannot parse records file {}: {e}", path.display())),
+      // the records file doesn't exist before the first build
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Records::default()),
+      Err(e) => Err(error!("Cannot read records file {}: {e}", path.display())),
+    }
+  }
+}
+
+fn get_chunk_sources(chunk: &Chunk, compilation: &Compilation) -> Vec<String> {
+  let context = compilation.options.context.as_ref();
+  let mut sources = vec![];
+  for group in chunk.get_sorted_groups_iter(&compilation.chunk_group_by_ukey) {
+    let group = compilation.chunk_group_by_ukey.expect_get(group);
+    let Some(index) = group.chunks.iter().position(|c| c == &chunk.ukey) else {
+      continue;
+    };
+    if let Some(name) = group.name() {
+      sources.push(format!("{index} {name}"));
+    } else {
+      for origin in &group.origins {
+        if let Some(module) = origin.module {
+          sources.push(format!(
+            "{index} {} {}",
+            make_paths_relative(context, &module),
+            origin.request.as_deref().unwrap_or_default()
+          ));
+        }
+      }
+    }
+  }
+  sources
+}
+
+#[plugin_hook(CompilerThisCompilation for RecordIdsPlugin)]
+async fn this_compilation(
+  &self,
+  _compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  *self.records.lock().expect("should lock") = self.read_records()?;
+  Ok(())
+}
+
+#[plugin_hook(CompilationModuleIds for RecordIdsPlugin)]
+fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let records = std::mem::take(&mut self.records.lock().expect("should lock").modules);
+  compilation.used_module_ids.extend(records.used_ids);
+
+  let context = compilation.options.context.as_ref();
+  let mut chunk_graph = std::mem::take(&mut compilation.chunk_graph);
+  let module_graph = compilation.get_module_graph();
+  let mut used_ids = HashSet::new();
+  for module_identifier in module_graph.modules().keys() {
+    if chunk_graph.get_number_of_module_chunks(*module_identifier) == 0
+      || chunk_graph.get_module_id(*module_identifier).is_some()
+    {
+      continue;
+    }
+    let identifier = make_paths_relative(context, module_identifier);
+    if let Some(id) = records.by_identifier.get(&identifier)
+      && used_ids.insert(id)
+    {
+      chunk_graph.set_module_id(*module_identifier, id.clone());
+    }
+  }
+  compilation.chunk_graph = chunk_graph;
+  Ok(())
+}
+
+#[plugin_hook(CompilationChunkIds for RecordIdsPlugin)]
+fn chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let records = std::mem::take(&mut self.records.lock().expect("should lock").chunks);
+  compilation.used_chunk_ids.extend(records.used_ids);
+
+  let mut used_ids = HashSet::new();
+  let mut chunk_ids = vec![];
+  for chunk in compilation.chunk_by_ukey.values() {
+    if chunk.id.is_some() {
+      continue;
+    }
+    let id = chunk
+      .name
+      .as_ref()
+      .and_then(|name| records.by_name.get(name))
+      .or_else(|| {
+        get_chunk_sources(chunk, compilation)
+          .iter()
+          .find_map(|source| records.by_source.get(source))
+      });
+    if let Some(id) = id
+      && used_ids.insert(id)
+    {
+      chunk_ids.push((chunk.ukey, id.clone()));
+    }
+  }
+  for (chunk_ukey, id) in chunk_ids {
+    let chunk = compilation.chunk_by_ukey.expect_get_mut(&chunk_ukey);
+    chunk.id = Some(id.clone());
+    chunk.ids = vec![id];
+  }
+  Ok(())
+}
+
+#[plugin_hook(CompilerAfterEmit for RecordIdsPlugin)]
+async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(path) = &self.options.output_path else {
+    return Ok(());
+  };
+  let context = compilation.options.context.as_ref();
+  let mut records = Records::default();
+
+  let module_graph = compilation.get_module_graph();
+  for module_identifier in module_graph.modules().keys() {
+    let Some(id) = compilation.chunk_graph.get_module_id(*module_identifier) else {
+      continue;
+    };
+    records
+      .modules
+      .by_identifier
+      .insert(make_paths_relative(context, module_identifier), id.clone());
+    records.modules.used_ids.push(id.clone());
+  }
+
+  for chunk in compilation.chunk_by_ukey.values() {
+    let Some(id) = &chunk.id else {
+      continue;
+    };
+    if let Some(name) = &chunk.name {
+      records.chunks.by_name.insert(name.clone(), id.clone());
+    }
+    for source in get_chunk_sources(chunk, compilation) {
+      records.chunks.by_source.insert(source, id.clone());
+    }
+    records.chunks.used_ids.push(id.clone());
+  }
+  records.modules.used_ids.sort();
+  records.modules.used_ids.dedup();
+  records.chunks.used_ids.sort();
+  records.chunks.used_ids.dedup();
+
+  let content = serde_json::to_string_pretty(&records).expect("should serialize the records");
+  if let Some(dir) = path.parent() {
+    std::fs::create_dir_all(dir)
+      .map_err(|e| error!("Cannot create directory {}: {e}", dir.display()))?;
+  }
+  std::fs::write(path, content)
+    .map_err(|e| error!("Cannot write records file {}: {e}", path.display()))?;
+  Ok(())
+}
+
+impl Plugin for RecordIdsPlugin {
+  fn name(&self) -> &'static str {
+    "RecordIdsPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .this_compilation
+      .tap(this_compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(module_ids::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .chunk_ids
+      .tap(chunk_ids::new(self));
+    ctx
+      .context
+      .compiler_hooks
+      .after_emit
+      .tap(after_emit::new(self));
+    Ok(())
+  }
+}
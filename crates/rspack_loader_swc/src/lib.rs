@@ -72,10 +72,13 @@ impl Loader<LoaderRunnerContext> for SwcLoader {
       }
 
       if swc_options.config.jsc.target.is_some() && swc_options.config.env.is_some() {
-        loader_context.emit_diagnostic(Diagnostic::warn(
-          SWC_LOADER_IDENTIFIER.to_string(),
-          "`env` and `jsc.target` cannot be used together".to_string(),
-        ));
+        loader_context.emit_diagnostic(
+          Diagnostic::warn(
+            SWC_LOADER_IDENTIFIER.to_string(),
+            "`env` and `jsc.target` cannot be used together".to_string(),
+          )
+          .with_diagnostic_code("SWC_LOADER_CONFLICTING_TARGET"),
+        );
       }
       swc_options
     };
@@ -86,7 +86,8 @@ impl DefineHookInput {
         _ => Err(Error::new_spanned(arg, "unexpected arg")),
       })
       .collect::<Result<Punctuated<&Ident, Comma>>>()?;
-    let call_body = exec_kind.body(arg_names);
+    let trait_name_lit_str = LitStr::new(&trait_name.to_string(), trait_name.span());
+    let call_body = exec_kind.body(arg_names, &trait_name_lit_str);
     let call_fn = quote! {
       fn call(&self, #args) -> #ret {
         #call_body
@@ -106,6 +107,10 @@ impl DefineHookInput {
         fn stage(&self) -> i32 {
           0
         }
+        /// The name of the tap shown in the hook timings.
+        fn tap_name(&self) -> &'static str {
+          std::any::type_name::<Self>()
+        }
       }
 
       pub struct #hook_name {
@@ -215,23 +220,40 @@ impl ExecKind {
     }
   }
 
-  pub fn body(&self, args: Punctuated<&Ident, Comma>) -> TokenStream {
+  pub fn body(&self, args: Punctuated<&Ident, Comma>, hook: &LitStr) -> TokenStream {
     let additional_taps = self.additional_taps();
+    let run = |tap: TokenStream, args: TokenStream| {
+      let call = if self.is_async() {
+        quote! { #tap.run(#args).await }
+      } else {
+        quote! { #tap.run(#args) }
+      };
+      quote! {
+        {
+          let start = rspack_hook::__macro_helper::hook_timing_start();
+          let res = #call;
+          rspack_hook::__macro_helper::hook_timing_end(#hook, #tap.tap_name(), start);
+          res
+        }
+      }
+    };
     match self {
-      Self::AsyncSeries => {
+      Self::AsyncSeries | Self::SyncSeries => {
+        let run = run(quote! { tap }, quote! { #args });
         quote! {
           #additional_taps
           for tap in all_taps {
-            tap.run(#args).await?;
+            #run?;
           }
           Ok(())
         }
       }
-      Self::AsyncSeriesBail { .. } => {
+      Self::AsyncSeriesBail { .. } | Self::SyncSeriesBail { .. } => {
+        let run = run(quote! { tap }, quote! { #args });
         quote! {
           #additional_taps
           for tap in all_taps {
-            if let Some(res) = tap.run(#args).await? {
+            if let Some(res) = #run? {
               return Ok(Some(res));
             }
           }
@@ -239,11 +261,12 @@ impl ExecKind {
         }
       }
       Self::AsyncSeriesWaterfall { .. } => {
+        let run = run(quote! { tap }, quote! { data });
         quote! {
           #additional_taps
           let mut data = #args;
           for tap in all_taps {
-            data = tap.run(data).await?
+            data = #run?
           }
           Ok(data)
         }
@@ -251,31 +274,23 @@ impl ExecKind {
       Self::AsyncParallel => {
         quote! {
           #additional_taps
-          let futs: std::vec::Vec<_> = all_taps.iter().map(|t| t.run(#args)).collect();
+          let futs: std::vec::Vec<_> = all_taps
+            .iter()
+            .map(|t| {
+              let tap_name = t.tap_name();
+              let fut = t.run(#args);
+              async move {
+                let start = rspack_hook::__macro_helper::hook_timing_start();
+                let res = fut.await;
+                rspack_hook::__macro_helper::hook_timing_end(#hook, tap_name, start);
+                res
+              }
+            })
+            .collect();
           futures_concurrency::vec::TryJoin(futs).await?;
           Ok(())
         }
       }
-      Self::SyncSeries => {
-        quote! {
-          #additional_taps
-          for tap in all_taps {
-            tap.run(#args)?;
-          }
-          Ok(())
-        }
-      }
-      Self::SyncSeriesBail { .. } => {
-        quote! {
-          #additional_taps
-          for tap in all_taps {
-            if let Some(res) = tap.run(#args)? {
-              return Ok(Some(res));
-            }
-          }
-          Ok(None)
-        }
-      }
     }
   }
 }
@@ -203,6 +203,10 @@ pub fn expand_fn(args: HookArgs, input: syn::ItemFn) -> proc_macro::TokenStream
       }
 
       #stage_fn
+
+      fn tap_name(&self) -> &'static str {
+        concat!(stringify!(#name), "::", stringify!(#fn_ident))
+      }
     }
   };
   expanded.into()
@@ -114,6 +114,14 @@ pub fn impl_runtime_module(
 
       fn set_build_meta(&mut self, v: ::rspack_core::BuildMeta) {}
 
+      fn take_build_info(&mut self) -> Option<::rspack_core::BuildInfo> {
+        None
+      }
+
+      fn take_build_meta(&mut self) -> Option<::rspack_core::BuildMeta> {
+        None
+      }
+
       fn get_diagnostics(&self) -> Vec<::rspack_error::Diagnostic> {
         vec![]
       }
@@ -46,3 +46,45 @@ mod simple {
     Ok(())
   }
 }
+
+mod timing {
+  use super::*;
+
+  define_hook!(Emit: AsyncSeries(count: &mut u32));
+
+  #[plugin]
+  #[derive(Default)]
+  struct MyEmitPlugin;
+
+  #[plugin_hook(Emit for MyEmitPlugin)]
+  async fn emit(&self, count: &mut u32) -> Result<()> {
+    *count += 1;
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test() -> Result<()> {
+    let mut hook = EmitHook::default();
+    let plugin = MyEmitPlugin::default();
+    hook.tap(emit::new(&plugin));
+    let mut count = 0;
+    hook.call(&mut count).await?;
+    rspack_hook::enable_hook_timing(true);
+    hook.call(&mut count).await?;
+    hook.call(&mut count).await?;
+    rspack_hook::enable_hook_timing(false);
+    assert_eq!(count, 3);
+    // copying the timings keeps them for the next take
+    assert!(rspack_hook::hook_timings()
+      .iter()
+      .any(|timing| timing.tap == "MyEmitPlugin::emit" && timing.calls == 2));
+    let timings = rspack_hook::take_hook_timings()
+      .into_iter()
+      .filter(|timing| timing.hook == "Emit")
+      .collect::<Vec<_>>();
+    assert_eq!(timings.len(), 1);
+    assert_eq!(timings[0].tap, "MyEmitPlugin::emit");
+    assert_eq!(timings[0].calls, 2);
+    Ok(())
+  }
+}
@@ -6,6 +6,9 @@ mod mock_hook {
     fn stage(&self) -> i32 {
       0
     }
+    fn tap_name(&self) -> &'static str {
+      std::any::type_name::<Self>()
+    }
   }
 }
 
@@ -1,5 +1,7 @@
 #![feature(let_chains)]
 
+mod responsive;
+
 use std::{collections::HashMap, hash::Hash};
 
 use async_trait::async_trait;
@@ -10,18 +12,20 @@ use rspack_core::{
   tree_shaking::{
     analyzer::OptimizeAnalyzer, asset_module::AssetModule, visitor::OptimizeAnalyzeResult,
   },
-  AssetGeneratorDataUrl, AssetGeneratorDataUrlFnArgs, AssetParserDataUrl, BuildExtraDataType,
-  BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, ChunkUkey, CodeGenerationDataAssetInfo,
-  CodeGenerationDataFilename, CodeGenerationDataUrl, Compilation, CompilationRenderManifest,
-  CompilerOptions, GenerateContext, Module, ModuleGraph, NormalModule, ParseContext,
-  ParserAndGenerator, PathData, Plugin, RenderManifestEntry, ResourceData, RuntimeGlobals,
-  SourceType, NAMESPACE_OBJECT_EXPORT,
+  AssetGeneratorDataUrl, AssetGeneratorDataUrlFnArgs, AssetGeneratorResponsiveOptions, AssetInfo,
+  AssetParserDataUrl, BuildExtraDataType, BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph,
+  ChunkUkey, CodeGenerationDataAssetInfo, CodeGenerationDataFilename, CodeGenerationDataUrl,
+  Compilation, CompilationRenderManifest, CompilerOptions, GenerateContext, Module, ModuleGraph,
+  NormalModule, ParseContext, ParserAndGenerator, PathData, Plugin, RenderManifestEntry,
+  ResourceData, RuntimeGlobals, SourceType, NAMESPACE_OBJECT_EXPORT,
 };
 use rspack_error::{error, Diagnostic, IntoTWithDiagnosticArray, Result};
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::identifier::make_paths_relative;
 
+use self::responsive::{responsive_image_filename, ResponsiveImage, ResponsiveImages};
+
 #[plugin]
 #[derive(Debug, Default)]
 pub struct AssetPlugin;
@@ -70,7 +74,20 @@ impl CanonicalizedDataUrlOption {
 pub struct AssetParserAndGenerator {
   emit: bool,
   data_url: DataUrlOptions,
+  responsive: Option<AssetGeneratorResponsiveOptions>,
   parsed_asset_config: Option<CanonicalizedDataUrlOption>,
+  responsive_images: Option<ResponsiveImages>,
+}
+
+/// Resized images of a responsive asset module, emitted along with the original asset.
+#[derive(Clone, Debug)]
+struct CodeGenerationDataResponsiveImages(Vec<ResponsiveImageAsset>);
+
+#[derive(Clone, Debug)]
+struct ResponsiveImageAsset {
+  filename: String,
+  source: BoxSource,
+  info: AssetInfo,
 }
 
 impl AssetParserAndGenerator {
@@ -78,7 +95,9 @@ impl AssetParserAndGenerator {
     Self {
       emit,
       data_url: DataUrlOptions::Auto(option),
+      responsive: None,
       parsed_asset_config: None,
+      responsive_images: None,
     }
   }
 
@@ -86,7 +105,9 @@ impl AssetParserAndGenerator {
     Self {
       emit: false,
       data_url: DataUrlOptions::Inline(true),
+      responsive: None,
       parsed_asset_config: None,
+      responsive_images: None,
     }
   }
 
@@ -94,7 +115,9 @@ impl AssetParserAndGenerator {
     Self {
       emit,
       data_url: DataUrlOptions::Inline(false),
+      responsive: None,
       parsed_asset_config: None,
+      responsive_images: None,
     }
   }
 
@@ -102,10 +125,17 @@ impl AssetParserAndGenerator {
     Self {
       emit: false,
       data_url: DataUrlOptions::Source,
+      responsive: None,
       parsed_asset_config: None,
+      responsive_images: None,
     }
   }
 
+  pub fn responsive(mut self, responsive: Option<AssetGeneratorResponsiveOptions>) -> Self {
+    self.responsive = responsive;
+    self
+  }
+
   fn hash_for_source(
     &self,
     source: &BoxSource,
@@ -121,16 +151,17 @@ impl AssetParserAndGenerator {
     resource_data: &ResourceData,
     data_url: Option<&AssetGeneratorDataUrl>,
     source: &BoxSource,
-  ) -> Option<String> {
-    let func_args = AssetGeneratorDataUrlFnArgs {
-      filename: resource_data.resource_path.to_string_lossy().to_string(),
-      content: source.source().into_owned().to_string(),
-    };
-
+    module: &dyn Module,
+  ) -> Result<Option<String>> {
     if let Some(AssetGeneratorDataUrl::Func(data_url)) = data_url {
-      return Some(data_url(func_args).expect("call data_url function failed"));
+      let func_args = AssetGeneratorDataUrlFnArgs {
+        filename: resource_data.resource_path.to_string_lossy().to_string(),
+        content: source.source().into_owned().to_string(),
+        module,
+      };
+      return data_url(func_args).map(Some);
     }
-    None
+    Ok(None)
   }
 
   fn get_mimetype(
@@ -209,6 +240,72 @@ impl AssetParserAndGenerator {
   }
 }
 
+/// Render the descriptor of a responsive asset module, the `srcSet` is rendered
+/// with the images of the first format, and `sources` contains the `srcSet` of each format.
+fn render_responsive_images(
+  src: &str,
+  responsive_images: &ResponsiveImages,
+  images: &[(String, &ResponsiveImage)],
+) -> String {
+  let render_src_set = |format: &str| {
+    images
+      .iter()
+      .filter(|(_, image)| image.format == format)
+      .map(|(url, image)| format!(r#"{url} + " {}w""#, image.width))
+      .collect::<Vec<_>>()
+      .join(r#" + "," + "#)
+  };
+  let mut formats: Vec<&str> = vec![];
+  for (_, image) in images {
+    if !formats.contains(&image.format.as_str()) {
+      formats.push(&image.format);
+    }
+  }
+  let src_set = formats
+    .first()
+    .map(|format| render_src_set(format))
+    .unwrap_or_else(|| r#""""#.to_string());
+  let sources = formats
+    .iter()
+    .map(|format| {
+      format!(
+        "{}: {}",
+        serde_json::to_string(format).expect("should serialize the format"),
+        render_src_set(format)
+      )
+    })
+    .collect::<Vec<_>>()
+    .join(", ");
+  let images = images
+    .iter()
+    .map(|(url, image)| {
+      format!(
+        "{{ path: {url}, width: {}, height: {}, format: {} }}",
+        image.width,
+        image.height,
+        serde_json::to_string(&image.format).expect("should serialize the format")
+      )
+    })
+    .collect::<Vec<_>>()
+    .join(", ");
+  format!(
+    "{{ src: {src}, width: {}, height: {}, srcSet: {src_set}, sources: {{ {sources} }}, images: [{images}] }}",
+    responsive_images.width, responsive_images.height
+  )
+}
+
+/// Join `generator.outputPath` and the asset filename like `path.posix.join`.
+fn join_output_path(output_path: &str, filename: &str) -> String {
+  let output_path = output_path
+    .strip_prefix("./")
+    .unwrap_or(output_path)
+    .trim_end_matches('/');
+  if output_path.is_empty() || output_path == "." {
+    return filename.to_owned();
+  }
+  format!("{output_path}/{filename}")
+}
+
 // Webpack's default parser.dataUrlCondition.maxSize
 const DEFAULT_MAX_SIZE: u32 = 8096;
 
@@ -276,6 +373,7 @@ impl ParserAndGenerator for AssetParserAndGenerator {
       build_info,
       compiler_options,
       module_identifier,
+      resource_data,
       ..
     } = parse_context;
     build_info.strict = true;
@@ -307,6 +405,30 @@ impl ParserAndGenerator for AssetParserAndGenerator {
         ))
       }
     };
+    let mut diagnostics = vec![];
+    self.responsive_images = match &self.responsive {
+      Some(responsive)
+        if self
+          .parsed_asset_config
+          .as_ref()
+          .is_some_and(|config| config.is_resource()) =>
+      {
+        let responsive_images =
+          ResponsiveImages::new(&resource_data.resource, &source.buffer(), responsive)?;
+        if responsive_images.is_none() {
+          diagnostics.push(Diagnostic::warn(
+            "Responsive images".into(),
+            format!(
+              "Skipped generating responsive images for {} as the image is empty",
+              resource_data.resource
+            ),
+          ));
+        }
+        responsive_images
+      }
+      _ => None,
+    };
+
     let analyze_result = if compiler_options.builtins.tree_shaking.enable() {
       AssetModule::new(module_identifier).analyze()
     } else {
@@ -323,7 +445,7 @@ impl ParserAndGenerator for AssetParserAndGenerator {
         analyze_result,
         side_effects_bailout: None,
       }
-      .with_empty_diagnostic(),
+      .with_diagnostic(diagnostics),
     )
   }
 
@@ -354,7 +476,9 @@ impl ParserAndGenerator for AssetParserAndGenerator {
 
           let encoded_source: String;
 
-          if let Some(custom_data_url) = self.get_data_url(resource_data, data_url, source) {
+          if let Some(custom_data_url) =
+            self.get_data_url(resource_data, data_url, source, module)?
+          {
             encoded_source = custom_data_url;
           } else {
             let mimetype = self.get_mimetype(resource_data, data_url)?;
@@ -396,28 +520,76 @@ impl ParserAndGenerator for AssetParserAndGenerator {
               .filename(&source_file_name),
           )?;
 
-          let asset_path = if let Some(public_path) = generate_context
+          let public_path = generate_context
             .module_generator_options
-            .and_then(|x| x.asset_public_path())
-          {
-            let public_path = public_path.render(compilation, &filename);
-            serde_json::to_string(&format!("{public_path}{filename}"))
-              .map_err(|e| error!(e.to_string()))?
-          } else {
+            .and_then(|x| x.asset_public_path());
+          if public_path.is_none() {
             generate_context
               .runtime_requirements
               .insert(RuntimeGlobals::PUBLIC_PATH);
-            format!(r#"{} + "{}""#, RuntimeGlobals::PUBLIC_PATH, filename)
+          }
+          let asset_url = |filename: &str| -> Result<String> {
+            if let Some(public_path) = public_path {
+              let public_path = public_path.render(compilation, filename);
+              serde_json::to_string(&format!("{public_path}{filename}"))
+                .map_err(|e| error!(e.to_string()))
+            } else {
+              Ok(format!(
+                r#"{} + "{}""#,
+                RuntimeGlobals::PUBLIC_PATH,
+                filename
+              ))
+            }
+          };
+          let mut asset_path = asset_url(&filename)?;
+
+          // Use [Rule.generator.outputPath] to emit the asset into a different directory,
+          // while keeping the url relative to the public path.
+          let output_path = generate_context
+            .module_generator_options
+            .and_then(|x| x.asset_output_path())
+            .map(|output_path| {
+              compilation.get_asset_path(
+                output_path,
+                PathData::default()
+                  .module(module)
+                  .chunk_graph(&generate_context.compilation.chunk_graph)
+                  .content_hash(contenthash)
+                  .hash(contenthash)
+                  .filename(&source_file_name),
+              )
+            })
+            .transpose()?;
+          let output_filename = |filename: &str| match &output_path {
+            Some(output_path) => join_output_path(output_path, filename),
+            None => filename.to_owned(),
           };
           asset_info.set_source_filename(source_file_name);
 
+          if let Some(responsive_images) = &self.responsive_images {
+            let mut assets = Vec::with_capacity(responsive_images.images.len());
+            let mut images = Vec::with_capacity(responsive_images.images.len());
+            for image in &responsive_images.images {
+              let image_filename = responsive_image_filename(&filename, image);
+              images.push((asset_url(&image_filename)?, image));
+              assets.push(ResponsiveImageAsset {
+                filename: output_filename(&image_filename),
+                source: RawSource::from(image.content.clone()).boxed(),
+                info: asset_info.clone(),
+              });
+            }
+            asset_path = render_responsive_images(&asset_path, responsive_images, &images);
+            generate_context
+              .data
+              .insert(CodeGenerationDataResponsiveImages(assets));
+          }
+
           generate_context
             .data
             .insert(CodeGenerationDataFilename::new(
+              output_filename(&filename),
               filename,
-              generate_context
-                .module_generator_options
-                .and_then(|x| x.asset_public_path())
+              public_path
                 .unwrap_or_else(|| &compilation.options.output.public_path)
                 .clone(),
             ));
@@ -433,10 +605,11 @@ impl ParserAndGenerator for AssetParserAndGenerator {
         };
         if let Some(ref mut scope) = generate_context.concatenation_scope {
           scope.register_namespace_export(NAMESPACE_OBJECT_EXPORT);
-          // TODO: inspect supportConst https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/asset/AssetGenerator.js#L382-L386
+          // https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/asset/AssetGenerator.js#L382-L386
+          let declaration = compilation.options.output.environment.declaration_keyword();
           Ok(
             RawSource::from(format!(
-              r#"const {NAMESPACE_OBJECT_EXPORT} = {exported_content};"#
+              r#"{declaration} {NAMESPACE_OBJECT_EXPORT} = {exported_content};"#
             ))
             .boxed(),
           )
@@ -470,6 +643,12 @@ impl ParserAndGenerator for AssetParserAndGenerator {
       BuildExtraDataType::AssetParserAndGenerator,
       to_bytes::<_, 256>(&self.parsed_asset_config).expect("Failed to store extra data"),
     );
+    if let Some(responsive_images) = &self.responsive_images {
+      extra_data.insert(
+        BuildExtraDataType::AssetResponsiveImages,
+        to_bytes::<_, 256>(responsive_images).expect("Failed to store extra data"),
+      );
+    }
   }
 
   fn resume(&mut self, extra_data: &HashMap<BuildExtraDataType, AlignedVec>) {
@@ -477,6 +656,10 @@ impl ParserAndGenerator for AssetParserAndGenerator {
       self.parsed_asset_config = from_bytes::<Option<CanonicalizedDataUrlOption>>(data)
         .expect("Failed to resume extra data");
     }
+    if let Some(data) = extra_data.get(&BuildExtraDataType::AssetResponsiveImages) {
+      self.responsive_images =
+        Some(from_bytes::<ResponsiveImages>(data).expect("Failed to resume extra data"));
+    }
   }
 
   fn get_concatenation_bailout_reason(
@@ -553,20 +736,36 @@ async fn render_manifest(
           .get::<CodeGenerationDataAssetInfo>()
           .expect("should have asset_info")
           .inner();
-        RenderManifestEntry::new(
+        let mut entries = vec![RenderManifestEntry::new(
           source.clone(),
           asset_filename.to_owned(),
           asset_info.to_owned(),
           true,
           true,
-        )
+        )];
+        if let Some(responsive_images) = code_gen_result
+          .data
+          .get::<CodeGenerationDataResponsiveImages>()
+        {
+          entries.extend(responsive_images.0.iter().map(|asset| {
+            RenderManifestEntry::new(
+              asset.source.clone(),
+              asset.filename.clone(),
+              asset.info.clone(),
+              true,
+              true,
+            )
+          }));
+        }
+        entries
       });
 
       Ok(result)
     })
-    .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
+    .collect::<Result<Vec<Option<Vec<RenderManifestEntry>>>>>()?
     .into_par_iter()
     .flatten()
+    .flatten()
     .collect::<Vec<RenderManifestEntry>>();
 
   manifest.extend(assets);
@@ -600,11 +799,14 @@ impl Plugin for AssetPlugin {
         let emit: Option<bool> = generator_options
           .and_then(|x| x.get_asset())
           .and_then(|x| x.emit);
+        let responsive = generator_options
+          .and_then(|x| x.get_asset())
+          .and_then(|x| x.responsive.clone());
 
-        Box::new(AssetParserAndGenerator::with_auto(
-          data_url_condition.clone(),
-          emit.unwrap_or(true),
-        ))
+        Box::new(
+          AssetParserAndGenerator::with_auto(data_url_condition.clone(), emit.unwrap_or(true))
+            .responsive(responsive),
+        )
       }),
     );
 
@@ -619,8 +821,13 @@ impl Plugin for AssetPlugin {
         let emit = generator_options
           .and_then(|x| x.get_asset_resource())
           .and_then(|x| x.emit);
+        let responsive = generator_options
+          .and_then(|x| x.get_asset_resource())
+          .and_then(|x| x.responsive.clone());
 
-        Box::new(AssetParserAndGenerator::with_resource(emit.unwrap_or(true)))
+        Box::new(
+          AssetParserAndGenerator::with_resource(emit.unwrap_or(true)).responsive(responsive),
+        )
       }),
     );
 
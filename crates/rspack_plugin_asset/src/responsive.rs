@@ -0,0 +1,158 @@
+use std::io::Cursor;
+
+use image::{
+  codecs::{
+    jpeg::JpegEncoder,
+    png::{CompressionType, FilterType as PngFilterType, PngEncoder},
+    webp::WebPEncoder,
+  },
+  imageops::FilterType,
+  DynamicImage, ImageEncoder, ImageError,
+};
+use rayon::prelude::*;
+use rkyv::{Archive, Deserialize, Serialize};
+use rspack_core::AssetGeneratorResponsiveOptions;
+use rspack_error::{error, Result};
+
+#[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+pub struct ResponsiveImage {
+  pub width: u32,
+  pub height: u32,
+  pub format: String,
+  pub content: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+pub struct ResponsiveImages {
+  /// Width of the original image.
+  pub width: u32,
+  /// Height of the original image.
+  pub height: u32,
+  pub images: Vec<ResponsiveImage>,
+}
+
+impl ResponsiveImages {
+  /// Returns `None` if the image is empty, as there is nothing to resize.
+  pub fn new(
+    resource: &str,
+    input: &[u8],
+    options: &AssetGeneratorResponsiveOptions,
+  ) -> Result<Option<Self>> {
+    let into_error =
+      |e: ImageError| error!("Failed to generate responsive images for {resource}: {e}");
+
+    let format = image::guess_format(input).map_err(into_error)?;
+    let original = image::load_from_memory_with_format(input, format).map_err(into_error)?;
+    let (width, height) = (original.width(), original.height());
+    if width == 0 || height == 0 {
+      return Ok(None);
+    }
+
+    let formats = if options.formats.is_empty() {
+      let format = format
+        .extensions_str()
+        .first()
+        .ok_or_else(|| error!("Unsupported image format of {resource}"))?;
+      vec![format.to_string()]
+    } else {
+      options.formats.clone()
+    };
+
+    let mut sizes = options
+      .sizes
+      .iter()
+      .map(|size| (*size).clamp(1, width))
+      .collect::<Vec<_>>();
+    sizes.sort_unstable();
+    sizes.dedup();
+
+    let images = formats
+      .iter()
+      .flat_map(|format| sizes.iter().map(move |size| (format, *size)))
+      .collect::<Vec<_>>()
+      .into_par_iter()
+      .map(|(format, size)| {
+        let image = if size == width {
+          original.clone()
+        } else {
+          let height = ((height as f64) * (size as f64) / (width as f64)).round() as u32;
+          original.resize_exact(size, height.max(1), FilterType::Lanczos3)
+        };
+        let content = encode(&image, format, options.quality)
+          .map_err(into_error)?
+          .ok_or_else(|| error!("Unsupported responsive image format {format} of {resource}"))?;
+        Ok(ResponsiveImage {
+          width: image.width(),
+          height: image.height(),
+          format: format.to_owned(),
+          content,
+        })
+      })
+      .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(Self {
+      width,
+      height,
+      images,
+    }))
+  }
+}
+
+fn encode(
+  image: &DynamicImage,
+  format: &str,
+  quality: u8,
+) -> std::result::Result<Option<Vec<u8>>, ImageError> {
+  let mut output = Cursor::new(Vec::new());
+  match format {
+    "png" => {
+      PngEncoder::new_with_quality(&mut output, CompressionType::Best, PngFilterType::Adaptive)
+        .write_image(
+          image.as_bytes(),
+          image.width(),
+          image.height(),
+          image.color(),
+        )?;
+    }
+    "jpg" | "jpeg" => {
+      let image = DynamicImage::ImageRgb8(image.to_rgb8());
+      JpegEncoder::new_with_quality(&mut output, quality.clamp(1, 100)).write_image(
+        image.as_bytes(),
+        image.width(),
+        image.height(),
+        image.color(),
+      )?;
+    }
+    "webp" => {
+      let image = if image.color().has_alpha() {
+        DynamicImage::ImageRgba8(image.to_rgba8())
+      } else {
+        DynamicImage::ImageRgb8(image.to_rgb8())
+      };
+      WebPEncoder::new_lossless(&mut output).write_image(
+        image.as_bytes(),
+        image.width(),
+        image.height(),
+        image.color(),
+      )?;
+    }
+    _ => return Ok(None),
+  }
+  Ok(Some(output.into_inner()))
+}
+
+/// Insert the width of the image before the extension, and replace the
+/// extension with the format, e.g. `static/logo.png` -> `static/logo-320w.webp`.
+pub fn responsive_image_filename(filename: &str, image: &ResponsiveImage) -> String {
+  let (dir, base) = match filename.rfind('/') {
+    Some(index) => filename.split_at(index + 1),
+    None => ("", filename),
+  };
+  let stem = match base.rfind('.') {
+    Some(index) if index > 0 => &base[..index],
+    _ => base,
+  };
+  format!("{dir}{stem}-{}w.{}", image.width, image.format)
+}
@@ -0,0 +1,319 @@
+use async_trait::async_trait;
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, Chunk, Compilation, CompilationAsset, CompilationProcessAssets, CompilerOptions,
+  Plugin, PluginContext, SourceType,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use serde::Serialize;
+
+#[derive(Debug)]
+pub struct BundleAnalysisRspackPluginOptions {
+  pub filename: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AnalysisAssetModule {
+  identifier: String,
+  name: String,
+  /// The size of the generated code of the module.
+  size: f64,
+  /// The share of the emitted asset attributed to the module.
+  parsed_size: f64,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  modules: Option<Vec<AnalysisAssetModule>>,
+}
+
+#[derive(Debug, Serialize)]
+struct AnalysisAsset {
+  name: String,
+  size: f64,
+  chunks: Vec<String>,
+  modules: Vec<AnalysisAssetModule>,
+}
+
+#[derive(Debug, Serialize)]
+struct AnalysisChunk {
+  id: Option<String>,
+  names: Vec<String>,
+  files: Vec<String>,
+  initial: bool,
+  entry: bool,
+  modules: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct AnalysisModule {
+  identifier: String,
+  name: String,
+  size: f64,
+  chunks: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AnalysisDuplicatedModule {
+  identifier: String,
+  name: String,
+  chunks: Vec<String>,
+  /// The size of the copies of the module beyond the first one.
+  duplicated_size: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct BundleAnalysis {
+  assets: Vec<AnalysisAsset>,
+  chunks: Vec<AnalysisChunk>,
+  modules: Vec<AnalysisModule>,
+  duplicates: Vec<AnalysisDuplicatedModule>,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct BundleAnalysisRspackPlugin {
+  options: BundleAnalysisRspackPluginOptions,
+}
+
+impl BundleAnalysisRspackPlugin {
+  pub fn new(options: BundleAnalysisRspackPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+fn source_type_of_file(file: &str) -> Option<SourceType> {
+  let file = file.split(['?', '#']).next().unwrap_or(file);
+  if file.ends_with(".js") || file.ends_with(".mjs") || file.ends_with(".cjs") {
+    Some(SourceType::JavaScript)
+  } else if file.ends_with(".css") {
+    Some(SourceType::Css)
+  } else {
+    None
+  }
+}
+
+fn chunk_ids<'a>(chunks: impl Iterator<Item = &'a Chunk>) -> Vec<String> {
+  let mut ids: Vec<String> = chunks.filter_map(|chunk| chunk.id.clone()).collect();
+  ids.sort_unstable();
+  ids.dedup();
+  ids
+}
+
+/// Splits the emitted asset of a chunk between the modules rendered into it,
+/// e.g. the size after minification, in proportion to their generated code.
+fn analyze_asset(
+  compilation: &Compilation,
+  chunk: &Chunk,
+  source_type: SourceType,
+  asset_size: f64,
+) -> Vec<AnalysisAssetModule> {
+  let module_graph = compilation.get_module_graph();
+  let context = &compilation.options.context;
+  let mut modules: Vec<AnalysisAssetModule> = compilation
+    .chunk_graph
+    .get_chunk_modules_by_source_type(&chunk.ukey, source_type, &module_graph)
+    .into_iter()
+    .map(|module| {
+      let size = compilation
+        .code_generation_results
+        .get(&module.identifier(), Some(&chunk.runtime))
+        .get(&source_type)
+        .map_or(0.0, |source| source.size() as f64);
+      let inner_modules = module.as_concatenated_module().map(|concatenated_module| {
+        concatenated_module
+          .get_modules()
+          .iter()
+          .filter_map(|inner| module_graph.module_by_identifier(&inner.id))
+          .map(|inner| AnalysisAssetModule {
+            identifier: inner.identifier().to_string(),
+            name: inner.readable_identifier(context).to_string(),
+            size: inner.size(&source_type),
+            parsed_size: 0.0,
+            modules: None,
+          })
+          .collect::<Vec<_>>()
+      });
+      AnalysisAssetModule {
+        identifier: module.identifier().to_string(),
+        name: module.readable_identifier(context).to_string(),
+        size,
+        parsed_size: 0.0,
+        modules: inner_modules,
+      }
+    })
+    .collect();
+  if matches!(source_type, SourceType::JavaScript) {
+    for identifier in compilation
+      .chunk_graph
+      .get_chunk_runtime_modules_iterable(&chunk.ukey)
+    {
+      let Some((_, source)) = compilation
+        .runtime_module_code_generation_results
+        .get(identifier)
+      else {
+        continue;
+      };
+      modules.push(AnalysisAssetModule {
+        identifier: identifier.to_string(),
+        name: identifier.to_string(),
+        size: source.size() as f64,
+        parsed_size: 0.0,
+        modules: None,
+      });
+    }
+  }
+
+  let total: f64 = modules.iter().map(|module| module.size).sum();
+  for module in &mut modules {
+    module.parsed_size = if total > 0.0 {
+      (asset_size * module.size / total).round()
+    } else {
+      0.0
+    };
+    if let Some(inner_modules) = &mut module.modules {
+      let inner_total: f64 = inner_modules.iter().map(|inner| inner.size).sum();
+      for inner in inner_modules {
+        inner.parsed_size = if inner_total > 0.0 {
+          (module.parsed_size * inner.size / inner_total).round()
+        } else {
+          0.0
+        };
+      }
+    }
+  }
+  modules.sort_unstable_by(|a, b| a.identifier.cmp(&b.identifier));
+  modules
+}
+
+#[plugin_hook(CompilationProcessAssets for BundleAnalysisRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_REPORT)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  let context = &compilation.options.context;
+
+  let mut chunks: Vec<&Chunk> = compilation.chunk_by_ukey.values().collect();
+  chunks.sort_unstable_by(|a, b| a.id.cmp(&b.id));
+
+  let mut assets = vec![];
+  for (name, asset) in compilation.assets() {
+    let Some(source) = asset.get_source() else {
+      continue;
+    };
+    let size = source.size() as f64;
+    let asset_chunks: Vec<&Chunk> = chunks
+      .iter()
+      .copied()
+      .filter(|chunk| chunk.files.contains(name))
+      .collect();
+    let modules = match (source_type_of_file(name), asset_chunks.as_slice()) {
+      (Some(source_type), [chunk]) => analyze_asset(compilation, chunk, source_type, size),
+      _ => vec![],
+    };
+    assets.push(AnalysisAsset {
+      name: name.clone(),
+      size,
+      chunks: chunk_ids(asset_chunks.into_iter()),
+      modules,
+    });
+  }
+  assets.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+  let analysis_chunks = chunks
+    .iter()
+    .map(|chunk| {
+      let mut files: Vec<String> = chunk.files.iter().cloned().collect();
+      files.sort_unstable();
+      let mut modules: Vec<String> = compilation
+        .chunk_graph
+        .get_chunk_module_identifiers(&chunk.ukey)
+        .iter()
+        .map(|identifier| identifier.to_string())
+        .collect();
+      modules.sort_unstable();
+      AnalysisChunk {
+        id: chunk.id.clone(),
+        names: chunk.name.clone().into_iter().collect(),
+        files,
+        initial: chunk.can_be_initial(&compilation.chunk_group_by_ukey),
+        entry: chunk.has_runtime(&compilation.chunk_group_by_ukey),
+        modules,
+      }
+    })
+    .collect();
+
+  let mut modules = vec![];
+  let mut duplicates = vec![];
+  for module in module_graph.modules().values() {
+    let identifier = module.identifier();
+    if compilation
+      .chunk_graph
+      .get_number_of_module_chunks(identifier)
+      == 0
+    {
+      continue;
+    }
+    let name = module.readable_identifier(context).to_string();
+    let size = module
+      .source_types()
+      .iter()
+      .map(|source_type| module.size(source_type))
+      .sum::<f64>();
+    let module_chunks = chunk_ids(
+      compilation
+        .chunk_graph
+        .get_module_chunks(identifier)
+        .iter()
+        .map(|chunk| compilation.chunk_by_ukey.expect_get(chunk)),
+    );
+    if module_chunks.len() > 1 {
+      duplicates.push(AnalysisDuplicatedModule {
+        identifier: identifier.to_string(),
+        name: name.clone(),
+        chunks: module_chunks.clone(),
+        duplicated_size: size * (module_chunks.len() - 1) as f64,
+      });
+    }
+    modules.push(AnalysisModule {
+      identifier: identifier.to_string(),
+      name,
+      size,
+      chunks: module_chunks,
+    });
+  }
+  modules.sort_unstable_by(|a, b| a.identifier.cmp(&b.identifier));
+  duplicates.sort_unstable_by(|a, b| a.identifier.cmp(&b.identifier));
+
+  let analysis = BundleAnalysis {
+    assets,
+    chunks: analysis_chunks,
+    modules,
+    duplicates,
+  };
+  let source =
+    serde_json::to_string_pretty(&analysis).expect("should serialize the bundle analysis");
+  compilation.emit_asset(
+    self.options.filename.clone(),
+    CompilationAsset::from(RawSource::from(source).boxed()),
+  );
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for BundleAnalysisRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.BundleAnalysisRspackPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
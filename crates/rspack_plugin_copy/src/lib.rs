@@ -269,10 +269,10 @@ impl CopyRspackPlugin {
                 diagnostics
                   .lock()
                   .expect("failed to obtain lock of `diagnostics`")
-                  .push(Diagnostic::error(
-                    "Run copy transform fn error".into(),
-                    e.to_string(),
-                  ));
+                  .push(
+                    Diagnostic::error("Run copy transform fn error".into(), e.to_string())
+                      .with_diagnostic_code("COPY_TRANSFORM_FAILED"),
+                  );
               }
             };
           }
@@ -486,10 +486,13 @@ impl CopyRspackPlugin {
           diagnostics
             .lock()
             .expect("failed to obtain lock of `diagnostics`")
-            .push(Diagnostic::error(
-              "CopyRspackPlugin Error".into(),
-              format!("unable to locate '{glob_query}' glob"),
-            ));
+            .push(
+              Diagnostic::error(
+                "CopyRspackPlugin Error".into(),
+                format!("unable to locate '{glob_query}' glob"),
+              )
+              .with_diagnostic_code("COPY_PATTERN_NOT_FOUND"),
+            );
         }
 
         let output_path = &compilation.options.output.path;
@@ -521,10 +524,13 @@ impl CopyRspackPlugin {
           diagnostics
             .lock()
             .expect("failed to obtain lock of `diagnostics`")
-            .push(Diagnostic::error(
-              "CopyRspackPlugin Error".into(),
-              format!("unable to locate '{glob_query}' glob"),
-            ));
+            .push(
+              Diagnostic::error(
+                "CopyRspackPlugin Error".into(),
+                format!("unable to locate '{glob_query}' glob"),
+              )
+              .with_diagnostic_code("COPY_PATTERN_NOT_FOUND"),
+            );
           return None;
         }
 
@@ -545,7 +551,10 @@ impl CopyRspackPlugin {
         diagnostics
           .lock()
           .expect("failed to obtain lock of `diagnostics`")
-          .push(Diagnostic::error("Glob Error".into(), e.msg.to_string()));
+          .push(
+            Diagnostic::error("Glob Error".into(), e.msg.to_string())
+              .with_diagnostic_code("COPY_GLOB_ERROR"),
+          );
 
         None
       }
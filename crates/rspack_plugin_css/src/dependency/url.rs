@@ -8,19 +8,39 @@ use rspack_core::{
 
 use crate::utils::AUTO_PUBLIC_PATH_PLACEHOLDER;
 
+/// How the referenced url is written in the stylesheet, which decides the
+/// shape of the replacement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CssUrlKind {
+  /// `url(./a.png)` or `url("./a.png")`
+  Url,
+  /// `src("./a.png")`
+  Src,
+  /// A bare string inside `image-set("./a.png" 1x)`
+  String,
+}
+
 #[derive(Debug, Clone)]
 pub struct CssUrlDependency {
   id: DependencyId,
   request: String,
+  kind: CssUrlKind,
   span: Option<ErrorSpan>,
   start: u32,
   end: u32,
 }
 
 impl CssUrlDependency {
-  pub fn new(request: String, span: Option<ErrorSpan>, start: u32, end: u32) -> Self {
+  pub fn new(
+    request: String,
+    kind: CssUrlKind,
+    span: Option<ErrorSpan>,
+    start: u32,
+    end: u32,
+  ) -> Self {
     Self {
       request,
+      kind,
       span,
       start,
       end,
@@ -28,6 +48,12 @@ impl CssUrlDependency {
     }
   }
 
+  /// Fragments are not part of the emitted asset filename, but things like
+  /// `url(./font.svg#iefix)` rely on them, so they are kept on the final url.
+  fn fragment(&self) -> Option<&str> {
+    self.request.find('#').map(|i| &self.request[i..])
+  }
+
   fn get_target_url(
     &self,
     identifier: &ModuleIdentifier,
@@ -39,7 +65,7 @@ impl CssUrlDependency {
       if let Some(url) = code_gen_result.data.get::<CodeGenerationDataUrl>() {
         Some(url.inner().to_string())
       } else if let Some(data) = code_gen_result.data.get::<CodeGenerationDataFilename>() {
-        let filename = data.filename();
+        let filename = data.public_filename();
         let public_path = match data.public_path() {
           PublicPath::String(p) => p,
           PublicPath::Auto => AUTO_PUBLIC_PATH_PLACEHOLDER,
@@ -102,7 +128,17 @@ impl DependencyTemplate for CssUrlDependency {
       .module_graph_module_by_dependency_id(self.id())
       && let Some(target_url) = self.get_target_url(&mgm.module_identifier, compilation)
     {
-      let content = format!("url({})", css_escape_string(&target_url));
+      let target_url = match self.fragment() {
+        Some(fragment) if !target_url.starts_with("data:") && !target_url.contains('#') => {
+          format!("{target_url}{fragment}")
+        }
+        _ => target_url,
+      };
+      let content = match self.kind {
+        CssUrlKind::Url => format!("url({})", css_escape_string(&target_url)),
+        CssUrlKind::Src => format!("src({})", css_quote_string(&target_url)),
+        CssUrlKind::String => css_quote_string(&target_url),
+      };
       source.replace(self.start, self.end, &content, None);
     }
   }
@@ -121,6 +157,13 @@ static QUOTATION_REGEX: Lazy<Regex> =
 static APOSTROPHE_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r#"[\n'\\]"#).expect("Invalid Regexp"));
 
+fn css_quote_string(s: &str) -> String {
+  format!(
+    "\"{}\"",
+    QUOTATION_REGEX.replace_all(s, |caps: &Captures| format!("\\{}", &caps[0]))
+  )
+}
+
 fn css_escape_string(s: &str) -> String {
   let mut count_white_or_bracket = 0;
   let mut count_quotation = 0;
@@ -7,17 +7,20 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use rkyv::{from_bytes, to_bytes, AlignedVec};
 use rspack_core::{
+  contextify_source_map,
   diagnostics::map_box_diagnostics_to_module_parse_diagnostics,
   rspack_sources::{
     BoxSource, ConcatSource, MapOptions, RawSource, ReplaceSource, Source, SourceExt, SourceMap,
     SourceMapSource, SourceMapSourceOptions,
   },
-  BoxDependency, BuildExtraDataType, BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph,
-  CssExportsConvention, ErrorSpan, GenerateContext, LocalIdentName, Module, ModuleGraph,
-  ModuleType, ParseContext, ParseResult, ParserAndGenerator, SourceType, TemplateContext,
+  source_name, BoxDependency, BuildExtraDataType, BuildMetaDefaultObject, BuildMetaExportsType,
+  ChunkGraph, CssExportsConvention, ErrorSpan, GenerateContext, LocalIdentName, Module,
+  ModuleGraph, ModuleType, ParseContext, ParseResult, ParserAndGenerator, SourceType,
+  TemplateContext,
 };
 use rspack_core::{ModuleInitFragments, RuntimeGlobals};
 use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_util::source_map::SourceMapKind;
 use rustc_hash::FxHashSet;
 use swc_core::{
   css::{parser::parser::ParserConfig, visit::VisitWith},
@@ -121,6 +124,12 @@ impl ParserAndGenerator for CssParserAndGenerator {
       _ => false,
     };
 
+    // The css of `exportsOnly` modules is never emitted, there is nothing to be mapped.
+    let module_source_map_kind = if self.exports_only {
+      SourceMapKind::empty()
+    } else {
+      module_source_map_kind
+    };
     let mut source_map = None;
     let mut diagnostic_vec = vec![];
 
@@ -208,6 +217,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
       &mut diagnostic_vec,
       &source_code,
       module_user_request,
+      !self.exports_only,
     );
     for (k, v) in exports_pairs {
       dependencies.push(Box::new(CssModuleExportDependency::new(
@@ -250,11 +260,13 @@ impl ParserAndGenerator for CssParserAndGenerator {
 
     let new_source = if module_source_map_kind.enabled() {
       if let Some(source_map) = source_map {
+        let mut source_map =
+          SourceMap::from_slice(&source_map).expect("should be able to generate source-map");
+        contextify_source_map(compiler_options, &mut source_map);
         SourceMapSource::new(SourceMapSourceOptions {
           value: source_code,
-          name: module_user_request,
-          source_map: SourceMap::from_slice(&source_map)
-            .expect("should be able to generate source-map"),
+          name: source_name(compiler_options, module_user_request.to_string()),
+          source_map,
           // Safety: original source exists in code generation
           original_source: Some(source.source().to_string()),
           // Safety: original source exists in code generation
@@ -182,9 +182,11 @@ fn content_hash(
     .iter()
     .map(|m| {
       (
-        compilation
-          .code_generation_results
-          .get_hash(&m.identifier(), Some(&chunk.runtime)),
+        compilation.code_generation_results.get_source_type_hash(
+          &m.identifier(),
+          Some(&chunk.runtime),
+          &SourceType::Css,
+        ),
         compilation.chunk_graph.get_module_id(m.identifier()),
       )
     })
@@ -282,6 +284,7 @@ async fn render_manifest(
           selected_module.readable_identifier(&compilation.options.context)
         ),
       )
+      .with_diagnostic_code("CSS_ORDER_CONFLICT")
     }));
   }
   manifest.push(RenderManifestEntry::new(
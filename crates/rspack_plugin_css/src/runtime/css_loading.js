@@ -30,6 +30,9 @@ var loadStylesheet = function (chunkId, url, done, hmr) {
 		link = document.createElement("link");
 		link.setAttribute("data-webpack", uniqueName + ":" + key);
 		link.setAttribute(loadingAttribute, 1);
+		if (__webpack_require__.nc) {
+			link.setAttribute("nonce", __webpack_require__.nc);
+		}
 		link.rel = "stylesheet";
 		link.href = url;
 
@@ -46,7 +49,7 @@ var loadStylesheet = function (chunkId, url, done, hmr) {
 	if (link.getAttribute(loadingAttribute)) {
 		var timeout = setTimeout(
 			onLinkComplete.bind(null, undefined, { type: "timeout", target: link }),
-			120000
+			__CHUNK_LOAD_TIMEOUT_PLACEHOLDER__
 		);
 		link.onerror = onLinkComplete.bind(null, link.onerror);
 		link.onload = onLinkComplete.bind(null, link.onload);
@@ -0,0 +1,18 @@
+__webpack_require__.F.s = function (chunkId) {
+	if (
+		(!__webpack_require__.o(installedChunks, chunkId) ||
+			installedChunks[chunkId] === undefined) &&
+		CSS_MATCHER
+	) {
+		installedChunks[chunkId] = null;
+		var link = document.createElement("link");
+		if (__webpack_require__.nc) {
+			link.setAttribute("nonce", __webpack_require__.nc);
+		}
+		link.rel = "prefetch";
+		link.as = "style";
+		link.href = __webpack_require__.p + __webpack_require__.k(chunkId);
+		__CROSS_ORIGIN_LOADING_PLACEHOLDER__
+		document.head.appendChild(link);
+	}
+};
@@ -48,6 +48,10 @@ impl RuntimeModule for CssLoadingRuntimeModule {
 
       let with_loading = runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
         && !matches!(has_css_matcher, BooleanMatcher::Condition(false));
+      let with_prefetch = runtime_requirements.contains(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS)
+        && !matches!(has_css_matcher, BooleanMatcher::Condition(false));
+      let with_preload = runtime_requirements.contains(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS)
+        && !matches!(has_css_matcher, BooleanMatcher::Condition(false));
 
       let initial_chunks = chunk.get_all_initial_chunks(&compilation.chunk_group_by_ukey);
       let mut initial_chunk_ids_with_css = HashSet::default();
@@ -105,7 +109,11 @@ impl RuntimeModule for CssLoadingRuntimeModule {
             "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
             &cross_origin_content,
           )
-          .replace("__UNIQUE_NAME__", unique_name),
+          .replace("__UNIQUE_NAME__", unique_name)
+          .replace(
+            "__CHUNK_LOAD_TIMEOUT_PLACEHOLDER__",
+            &compilation.options.output.chunk_load_timeout.to_string(),
+          ),
       ));
 
       if with_loading {
@@ -121,6 +129,36 @@ impl RuntimeModule for CssLoadingRuntimeModule {
         ));
       }
 
+      if with_prefetch {
+        source.add(RawSource::from(
+          include_str!("./css_loading_with_prefetch.js")
+            .replace(
+              "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
+              &cross_origin_content,
+            )
+            .replace("CSS_MATCHER", &has_css_matcher.render("chunkId")),
+        ));
+      }
+
+      if with_preload {
+        source.add(RawSource::from(
+          include_str!("./css_loading_with_preload.js")
+            .replace(
+              "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
+              &cross_origin_content,
+            )
+            .replace(
+              "$CHARSET$",
+              if compilation.options.output.charset {
+                "link.charset = \"utf-8\";"
+              } else {
+                ""
+              },
+            )
+            .replace("CSS_MATCHER", &has_css_matcher.render("chunkId")),
+        ));
+      }
+
       if with_hmr {
         source.add(RawSource::from(include_str!("./css_loading_with_hmr.js")));
       }
@@ -2,16 +2,26 @@ use std::sync::Arc;
 
 use rspack_core::rspack_sources::{self, SourceExt};
 use rspack_error::{error, Result};
-use swc_core::common::{input::SourceFileInput, source_map::SourceMapGenConfig, FileName};
-use swc_core::common::{Globals, GLOBALS};
+use rustc_hash::FxHashSet as HashSet;
+use swc_core::common::comments::{Comments, SingleThreadedComments};
+use swc_core::common::{input::SourceFileInput, source_map::SourceMapGenConfig, FileName, Span};
+use swc_core::common::{Globals, Spanned, GLOBALS};
+use swc_core::css::ast::{
+  AtRule, AtRulePrelude, ComponentValue, Declaration, DeclarationName, Function, Ident,
+  KeyframesName, Number, Rule, Token,
+};
 use swc_core::css::codegen::{
   writer::basic::{BasicCssWriter, BasicCssWriterConfig},
   CodeGenerator, CodegenConfig, Emit,
 };
 use swc_core::css::minifier;
 use swc_core::css::parser::{lexer::Lexer, parser::ParserConfig};
+use swc_core::css::visit::{Visit, VisitMut, VisitMutWith, VisitWith};
 use swc_core::css::{ast::Stylesheet, parser::parser::Parser};
 
+/// Tests the text of a comment, the delimiters are not included.
+pub type SwcCssCommentFilter<'a> = dyn Fn(&str) -> Result<bool> + 'a;
+
 #[derive(Default)]
 pub struct SwcCssCompiler {
   cm: Arc<swc_core::common::SourceMap>,
@@ -19,11 +29,21 @@ pub struct SwcCssCompiler {
 
 impl SwcCssCompiler {
   pub fn parse_file(&self, path: &str, source: String, config: ParserConfig) -> Result<Stylesheet> {
+    self.parse_file_with_comments(path, source, config, None)
+  }
+
+  fn parse_file_with_comments(
+    &self,
+    path: &str,
+    source: String,
+    config: ParserConfig,
+    comments: Option<&dyn Comments>,
+  ) -> Result<Stylesheet> {
     let fm = self
       .cm
       .new_source_file(FileName::Custom(path.to_string()), source);
 
-    let lexer = Lexer::new(SourceFileInput::from(&*fm), None, config);
+    let lexer = Lexer::new(SourceFileInput::from(&*fm), comments, config);
     let mut parser = Parser::new(lexer, config);
     let stylesheet = parser.parse_all();
     stylesheet.map_err(|e| error!("Css parsing failed {}", e.message()))
@@ -68,21 +88,121 @@ impl SwcCssCompiler {
     }
   }
 
+  /// Minifies the stylesheet, the comments for which `preserve_comment` returns
+  /// true are kept in place, a comment inside a rule is kept before the rule.
   pub fn minify(
     &self,
     filename: &str,
     input_source: String,
     input_source_map: Option<rspack_sources::SourceMap>,
     gen_source_map: SwcCssSourceMapGenConfig,
+    options: &SwcCssMinifyOptions,
+    preserve_comment: Option<&SwcCssCommentFilter>,
   ) -> Result<rspack_sources::BoxSource> {
-    let mut ast = self.parse_file(filename, input_source.clone(), Default::default())?;
-    // ignore errors since css in webpack is tolerant, and diagnostics already reported in parse.
-    GLOBALS.set(&Globals::default(), || {
-      minifier::minify(&mut ast, minifier::options::MinifyOptions::default());
+    let comments = preserve_comment.map(|_| SingleThreadedComments::default());
+    let mut ast = self.parse_file_with_comments(
+      filename,
+      input_source.clone(),
+      Default::default(),
+      comments.as_ref().map(|c| c as &dyn Comments),
+    )?;
+    if options.discard_unused_keyframes {
+      discard_unused_keyframes(&mut ast);
+    }
+    let math_function_spans = options.calc_precision.map(|_| {
+      let mut collector = MathFunctionSpansCollector::default();
+      ast.visit_with(&mut collector);
+      collector.spans
     });
-    let (code, source_map) = self.codegen_impl(&ast, gen_source_map, true)?;
+    if options.compress {
+      // ignore errors since css in webpack is tolerant, and diagnostics already reported in parse.
+      GLOBALS.set(&Globals::default(), || {
+        minifier::minify(&mut ast, minifier::options::MinifyOptions::default());
+      });
+    }
+    if let (Some(precision), Some(spans)) = (options.calc_precision, math_function_spans) {
+      ast.visit_mut_with(&mut CalcPrecisionRounder { precision, spans });
+    }
+
+    let mut preserved_comments = vec![];
+    if let (Some(comments), Some(preserve_comment)) = (comments, preserve_comment) {
+      let (leading, trailing) = comments.take_all();
+      let mut all = leading
+        .take()
+        .into_values()
+        .chain(trailing.take().into_values())
+        .flatten()
+        .collect::<Vec<_>>();
+      all.sort_by_key(|comment| comment.span.lo);
+      all.dedup_by_key(|comment| comment.span.lo);
+      for comment in all {
+        // the text of the css comments includes the delimiters
+        let text = comment
+          .text
+          .strip_prefix("/*")
+          .and_then(|text| text.strip_suffix("*/"))
+          .unwrap_or(&comment.text);
+        if preserve_comment(text)? {
+          preserved_comments.push(comment);
+        }
+      }
+    }
+    if preserved_comments.is_empty() {
+      let (code, source_map) = self.codegen_impl(&ast, gen_source_map, true)?;
+      return Ok(self.create_minified_source(
+        filename,
+        code,
+        source_map,
+        input_source,
+        input_source_map,
+      ));
+    }
+
+    // the rules between the preserved comments are generated separately, so that the comments
+    // can be put in place
+    let mut sources = rspack_sources::ConcatSource::default();
+    let mut rules = std::mem::take(&mut ast.rules).into_iter().peekable();
+    let mut segments = vec![];
+    for comment in preserved_comments {
+      let mut segment = vec![];
+      while let Some(rule) = rules.next_if(|rule| rule.span_hi() <= comment.span.lo) {
+        segment.push(rule);
+      }
+      segments.push((segment, Some(comment.text)));
+    }
+    segments.push((rules.collect(), None));
+    for (rules, comment) in segments {
+      if !rules.is_empty() {
+        let segment = Stylesheet {
+          span: ast.span,
+          rules,
+        };
+        let (code, source_map) = self.codegen_impl(&segment, gen_source_map.clone(), true)?;
+        sources.add(self.create_minified_source(
+          filename,
+          code,
+          source_map,
+          input_source.clone(),
+          input_source_map.clone(),
+        ));
+      }
+      if let Some(comment) = comment {
+        sources.add(rspack_sources::RawSource::from(comment.to_string()));
+      }
+    }
+    Ok(sources.boxed())
+  }
+
+  fn create_minified_source(
+    &self,
+    filename: &str,
+    code: String,
+    source_map: Option<Vec<u8>>,
+    input_source: String,
+    input_source_map: Option<rspack_sources::SourceMap>,
+  ) -> rspack_sources::BoxSource {
     if let Some(source_map) = source_map {
-      let source = rspack_sources::SourceMapSource::new(rspack_sources::SourceMapSourceOptions {
+      rspack_sources::SourceMapSource::new(rspack_sources::SourceMapSourceOptions {
         value: code,
         name: filename,
         source_map: rspack_sources::SourceMap::from_slice(&source_map)
@@ -91,14 +211,190 @@ impl SwcCssCompiler {
         inner_source_map: input_source_map,
         remove_original_source: true,
       })
-      .boxed();
-      Ok(source)
+      .boxed()
     } else {
-      Ok(rspack_sources::RawSource::from(code).boxed())
+      rspack_sources::RawSource::from(code).boxed()
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct SwcCssMinifyOptions {
+  /// Whether to compress the stylesheet, otherwise only the whitespaces are removed.
+  pub compress: bool,
+  /// Whether to remove the `@keyframes` which are not referenced by the animations
+  /// of the stylesheet.
+  pub discard_unused_keyframes: bool,
+  /// The number of decimal places the numbers computed from the math functions
+  /// (`calc()`, `min()`, `max()` and `clamp()`) are rounded to.
+  pub calc_precision: Option<u32>,
+}
+
+impl Default for SwcCssMinifyOptions {
+  fn default() -> Self {
+    Self {
+      compress: true,
+      discard_unused_keyframes: false,
+      calc_precision: None,
+    }
+  }
+}
+
+#[derive(Default)]
+struct MathFunctionSpansCollector {
+  spans: Vec<Span>,
+}
+
+impl Visit for MathFunctionSpansCollector {
+  fn visit_function(&mut self, n: &Function) {
+    if ["calc", "min", "max", "clamp"]
+      .iter()
+      .any(|name| n.name == **name)
+    {
+      self.spans.push(n.span);
+    }
+    n.visit_children_with(self);
+  }
+}
+
+/// Rounds the numbers inside the spans of the math functions collected before the
+/// compression, so the numbers computed by reducing the functions are rounded as well.
+struct CalcPrecisionRounder {
+  precision: u32,
+  spans: Vec<Span>,
+}
+
+impl VisitMut for CalcPrecisionRounder {
+  fn visit_mut_number(&mut self, n: &mut Number) {
+    if !self.spans.iter().any(|span| span.contains(n.span)) {
+      return;
+    }
+    let factor = 10f64.powi(self.precision as i32);
+    let value = (n.value * factor).round() / factor;
+    if value != n.value {
+      n.value = value;
+      n.raw = None;
     }
   }
 }
 
+fn is_keyframes_at_rule(at_rule: &AtRule) -> bool {
+  let name = match &at_rule.name {
+    swc_core::css::ast::AtRuleName::Ident(ident) => &ident.value,
+    swc_core::css::ast::AtRuleName::DashedIdent(ident) => &ident.value,
+  };
+  name.eq_ignore_ascii_case("keyframes")
+    || (name.starts_with('-') && name.to_ascii_lowercase().ends_with("-keyframes"))
+}
+
+fn get_keyframes_name(at_rule: &AtRule) -> Option<&str> {
+  if !is_keyframes_at_rule(at_rule) {
+    return None;
+  }
+  match at_rule.prelude.as_deref() {
+    Some(AtRulePrelude::KeyframesPrelude(KeyframesName::CustomIdent(ident))) => Some(&ident.value),
+    Some(AtRulePrelude::KeyframesPrelude(KeyframesName::Str(str))) => Some(&str.value),
+    _ => None,
+  }
+}
+
+#[derive(Default)]
+struct AnimationNamesCollector {
+  names: HashSet<String>,
+  // the animations which use `var()` can reference any keyframes
+  has_dynamic_names: bool,
+}
+
+impl Visit for AnimationNamesCollector {
+  fn visit_declaration(&mut self, n: &Declaration) {
+    let is_animation = match &n.name {
+      DeclarationName::Ident(name) => {
+        let name = name.value.to_ascii_lowercase();
+        name.ends_with("animation") || name.ends_with("animation-name")
+      }
+      // the custom properties may be used as the animation names
+      DeclarationName::DashedIdent(_) => true,
+    };
+    if is_animation {
+      let mut collector = IdentCollector::default();
+      n.value.visit_with(&mut collector);
+      self.names.extend(collector.names);
+      if collector.has_var_function && matches!(n.name, DeclarationName::Ident(_)) {
+        self.has_dynamic_names = true;
+      }
+    }
+    n.visit_children_with(self);
+  }
+}
+
+#[derive(Default)]
+struct IdentCollector {
+  names: Vec<String>,
+  has_var_function: bool,
+}
+
+impl Visit for IdentCollector {
+  fn visit_ident(&mut self, n: &Ident) {
+    self.names.push(n.value.to_string());
+  }
+
+  fn visit_str(&mut self, n: &swc_core::css::ast::Str) {
+    self.names.push(n.value.to_string());
+  }
+
+  fn visit_token(&mut self, n: &Token) {
+    match n {
+      Token::Ident { value, .. } | Token::String { value, .. } => {
+        self.names.push(value.to_string())
+      }
+      Token::Function { value, .. } if value.eq_ignore_ascii_case("var") => {
+        self.has_var_function = true
+      }
+      _ => {}
+    }
+  }
+
+  fn visit_function(&mut self, n: &Function) {
+    if n.name == *"var" {
+      self.has_var_function = true;
+    }
+    n.visit_children_with(self);
+  }
+}
+
+struct UnusedKeyframesRemover {
+  used: HashSet<String>,
+}
+
+impl UnusedKeyframesRemover {
+  fn is_unused(&self, at_rule: &AtRule) -> bool {
+    get_keyframes_name(at_rule).is_some_and(|name| !self.used.contains(name))
+  }
+}
+
+impl VisitMut for UnusedKeyframesRemover {
+  fn visit_mut_rules(&mut self, n: &mut Vec<Rule>) {
+    n.retain(|rule| !matches!(rule, Rule::AtRule(at_rule) if self.is_unused(at_rule)));
+    n.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_component_values(&mut self, n: &mut Vec<ComponentValue>) {
+    n.retain(|value| !matches!(value, ComponentValue::AtRule(at_rule) if self.is_unused(at_rule)));
+    n.visit_mut_children_with(self);
+  }
+}
+
+fn discard_unused_keyframes(ast: &mut Stylesheet) {
+  let mut collector = AnimationNamesCollector::default();
+  ast.visit_with(&mut collector);
+  if collector.has_dynamic_names {
+    return;
+  }
+  ast.visit_mut_with(&mut UnusedKeyframesRemover {
+    used: collector.names,
+  });
+}
+
 #[derive(Debug, Clone)]
 pub struct SwcCssSourceMapGenConfig {
   pub enable: bool,
@@ -9,14 +9,14 @@ use swc_core::atoms::Atom;
 use swc_core::common::{BytePos, FileName, Span, Spanned, SyntaxContext};
 use swc_core::css::ast::{
   AtRule, AtRuleName, ComplexSelectorChildren, ComponentValue, DeclarationName, Function, Ident,
-  ImportHref, ImportPrelude, PseudoClassSelector, QualifiedRulePrelude, Stylesheet,
+  ImportHref, ImportPrelude, PseudoClassSelector, QualifiedRulePrelude, Str, Stylesheet,
   SubclassSelector, Token, TokenAndSpan, Url, UrlValue,
 };
 use swc_core::css::modules::CssClassName;
 use swc_core::css::visit::{Visit, VisitWith};
 
 use crate::{
-  dependency::{CssImportDependency, CssUrlDependency},
+  dependency::{CssImportDependency, CssUrlDependency, CssUrlKind},
   utils::normalize_url,
 };
 
@@ -28,13 +28,15 @@ pub fn analyze_dependencies(
   diagnostics: &mut Vec<Box<dyn Diagnostic + Send + Sync>>,
   source_code: &str,
   filename: &str,
+  handle_url: bool,
 ) -> Vec<BoxDependency> {
   let mut v = Analyzer {
     deps: Vec::new(),
+    handle_url,
     code_generation_dependencies,
     diagnostics,
     nearest_at_import_span: None,
-    url_function_span: None,
+    url_function: None,
     source_code,
     filename,
     // in_support_contdition: false,
@@ -47,10 +49,13 @@ pub fn analyze_dependencies(
 #[derive(Debug)]
 struct Analyzer<'a> {
   deps: Vec<BoxDependency>,
+  /// Whether `url()` and friends should be turned into dependencies, the css of
+  /// `exportsOnly` modules is never rendered so their urls are meaningless.
+  handle_url: bool,
   code_generation_dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
   diagnostics: &'a mut Vec<Box<dyn Diagnostic + Send + Sync>>,
   nearest_at_import_span: Option<Span>,
-  url_function_span: Option<Span>,
+  url_function: Option<(Span, CssUrlKind)>,
   source_code: &'a str,
   filename: &'a str,
   // in_support_contdition: bool,
@@ -86,6 +91,7 @@ fn replace_module_request_prefix(
       )
       .with_help(Some("Remove '~' from the request."))
       .with_severity(Severity::Warning)
+      .with_code("CSS_DEPRECATED_TILDE_REQUEST")
       .boxed(),
     );
     IS_MODULE_REQUEST.replace(&specifier, "").to_string()
@@ -95,12 +101,23 @@ fn replace_module_request_prefix(
 }
 
 static URL_KEYWORD: &str = "url";
+static SRC_KEYWORD: &str = "src";
+static IMAGE_SET_KEYWORDS: &[&str] = &["image-set", "-webkit-image-set"];
 static IMPORT_KEYWORD: &str = "import";
 
 impl Analyzer<'_> {
-  fn analyze_url(&mut self, value: impl Into<String>, span: Span) {
+  fn analyze_url(&mut self, value: impl Into<String>, span: Span, kind: CssUrlKind) {
+    if !self.handle_url {
+      return;
+    }
+    let value = value.into();
+    // `url()` is valid by spec, and `url(#id)` is a reference into the current
+    // document (e.g. svg filters), neither of them is a module request.
+    if value.trim().is_empty() || value.trim_start().starts_with('#') {
+      return;
+    }
     let mut specifier = replace_module_request_prefix(
-      value.into(),
+      value,
       self.diagnostics,
       self.source_code,
       self.filename,
@@ -109,6 +126,7 @@ impl Analyzer<'_> {
     specifier = normalize_url(&specifier);
     let dep = Box::new(CssUrlDependency::new(
       specifier,
+      kind,
       Some(span.into()),
       span.real_lo(),
       span.real_hi(),
@@ -194,22 +212,49 @@ impl Visit for Analyzer<'_> {
   ///      ],
   ///  }
   fn visit_function(&mut self, f: &Function) {
-    if let Some(i) = f.name.as_ident()
-      && i.value == URL_KEYWORD
-    {
-      self.url_function_span = Some(f.span);
+    let Some(name) = f.name.as_ident() else {
+      f.visit_children_with(self);
+      return;
+    };
+    if name.value.eq_ignore_ascii_case(URL_KEYWORD) {
+      self.url_function = Some((f.span, CssUrlKind::Url));
       f.visit_children_with(self);
-      self.url_function_span = None;
+      self.url_function = None;
+    } else if name.value.eq_ignore_ascii_case(SRC_KEYWORD) {
+      self.url_function = Some((f.span, CssUrlKind::Src));
+      f.visit_children_with(self);
+      self.url_function = None;
+    } else if IMAGE_SET_KEYWORDS
+      .iter()
+      .any(|keyword| name.value.eq_ignore_ascii_case(keyword))
+    {
+      // Only the direct string arguments of `image-set()` are urls, strings of
+      // nested functions like `type("image/avif")` must be kept as is.
+      for value in &f.value {
+        match value {
+          ComponentValue::Str(s) => self.analyze_url(s.value.as_ref(), s.span, CssUrlKind::String),
+          ComponentValue::PreservedToken(t) if let Token::String { value, .. } = &t.token => {
+            self.analyze_url(value.as_ref(), t.span, CssUrlKind::String)
+          }
+          _ => value.visit_with(self),
+        }
+      }
     } else {
       f.visit_children_with(self);
     }
   }
 
+  fn visit_str(&mut self, s: &Str) {
+    if let Some((span, kind)) = self.url_function {
+      self.analyze_url(s.value.as_ref(), span, kind)
+    }
+  }
+
   fn visit_token_and_span(&mut self, t: &TokenAndSpan) {
     match &t.token {
-      Token::Url { value, .. } => self.analyze_url(value.as_ref(), t.span),
-      Token::String { value, .. } if let Some(span) = self.url_function_span => {
-        self.analyze_url(value.as_ref(), span)
+      Token::Url { value, .. } => self.analyze_url(value.as_ref(), t.span, CssUrlKind::Url),
+      Token::String { value, .. } if let Some((span, kind)) = self.url_function => {
+        self.analyze_url(value.as_ref(), span, kind)
       }
       _ => t.visit_children_with(self),
     }
@@ -226,7 +271,7 @@ impl Visit for Analyzer<'_> {
     if let Some(specifier) = specifier
       && !specifier.is_empty()
     {
-      self.analyze_url(specifier, u.span);
+      self.analyze_url(specifier, u.span, CssUrlKind::Url);
     }
   }
 }
@@ -379,19 +379,10 @@ impl SourceMapDevToolPlugin {
             used_names_set.insert(source_name.clone());
             *source = Cow::from(source_name);
           }
-          if self.no_sources {
-            for content in source_map.sources_content_mut() {
-              *content = Cow::from(String::default());
-            }
-          }
           if let Some(source_root) = &self.source_root {
             source_map.set_source_root(Some(source_root.clone()));
           }
-          let mut source_map_buffer = Vec::new();
-          source_map
-            .to_writer(&mut source_map_buffer)
-            .unwrap_or_else(|e| panic!("{}", e.to_string()));
-          Some(source_map_buffer)
+          Some(source_map_to_buffer(source_map, self.no_sources)?)
         }
         None => None,
       };
@@ -444,13 +435,15 @@ impl SourceMapDevToolPlugin {
         } else {
           &SourceType::JavaScript
         };
-        let filename = match &self.file_context {
+        // `fileContext` only affects the `[file]` placeholder of the source map filename,
+        // the asset itself keeps its original name
+        let source_map_file = match &self.file_context {
           Some(file_context) => relative(Path::new(file_context), Path::new(&filename))
             .to_string_lossy()
             .to_string(),
           None => filename.clone(),
         };
-        let data = PathData::default().filename(&filename);
+        let data = PathData::default().filename(&source_map_file);
         let data = match chunk {
           Some(chunk) => data
             .chunk(chunk)
@@ -509,11 +502,14 @@ impl SourceMapDevToolPlugin {
           source_map: Some((source_map_filename, source_map_asset)),
         });
       } else {
-        let current_source_mapping_url_comment = current_source_mapping_url_comment
-          .expect("SourceMapDevToolPlugin: append can't be false when no filename is provided.");
         let current_source_mapping_url_comment = match &current_source_mapping_url_comment {
-          SourceMappingUrlCommentRef::String(s) => s,
-          SourceMappingUrlCommentRef::Fn(_) => {
+          Some(SourceMappingUrlCommentRef::String(s)) => s,
+          None => {
+            return Err(error!(
+              "SourceMapDevToolPlugin: append can't be false when no filename is provided"
+            ))
+          }
+          Some(SourceMappingUrlCommentRef::Fn(_)) => {
             return Err(error!(
               "SourceMapDevToolPlugin: append can't be a function when no filename is provided"
             ))
@@ -628,6 +624,24 @@ fn get_after(s: &str, token: &str) -> String {
     .unwrap_or("".to_string())
 }
 
+fn source_map_to_buffer(mut source_map: SourceMap, no_sources: bool) -> Result<Vec<u8>> {
+  if no_sources {
+    // `noSources` omits `sourcesContent` entirely, so the original sources never leave the build
+    let source_root = source_map.source_root().map(ToString::to_string);
+    source_map = SourceMap::new(
+      source_map.file().map(ToString::to_string),
+      source_map.mappings().to_string(),
+      source_map.sources().to_vec(),
+      Vec::<Cow<'static, str>>::new(),
+      source_map.names().to_vec(),
+    );
+    source_map.set_source_root(source_root);
+  }
+  let mut buffer = Vec::new();
+  source_map.to_writer(&mut buffer).into_diagnostic()?;
+  Ok(buffer)
+}
+
 fn get_hash(text: &str, output_options: &OutputOptions) -> String {
   let OutputOptions {
     hash_function,
@@ -829,12 +843,64 @@ impl ModuleFilenameHelpers {
   }
 }
 
-static MODULE_RENDER_CACHE: Lazy<DashMap<BoxSource, BoxSource>> = Lazy::new(DashMap::default);
+#[derive(Debug)]
+struct ModuleRenderCacheEntry {
+  module_id: String,
+  source: BoxSource,
+  result: BoxSource,
+}
+
+// Module sources are immutable once rendered, so the eval wrapped result (including the
+// inlined per-module source map) can be reused across rebuilds as long as the module, its id
+// and its source are unchanged, the result embeds the module id and the module name
+#[derive(Debug, Default)]
+struct ModuleRenderCache(DashMap<ModuleIdentifier, ModuleRenderCacheEntry>);
+
+impl ModuleRenderCache {
+  fn get(
+    &self,
+    module: &ModuleIdentifier,
+    module_id: &str,
+    source: &BoxSource,
+  ) -> Option<BoxSource> {
+    self
+      .0
+      .get(module)
+      .filter(|entry| entry.module_id == module_id && &entry.source == source)
+      .map(|entry| entry.result.clone())
+  }
+
+  fn insert(
+    &self,
+    module: ModuleIdentifier,
+    module_id: String,
+    source: BoxSource,
+    result: BoxSource,
+  ) {
+    self.0.insert(
+      module,
+      ModuleRenderCacheEntry {
+        module_id,
+        source,
+        result,
+      },
+    );
+  }
+
+  /// Removes the entries of the modules that no longer exist
+  fn prune(&self, compilation: &Compilation) {
+    let module_graph = compilation.get_module_graph();
+    self
+      .0
+      .retain(|module, _| module_graph.module_by_identifier(module).is_some());
+  }
+}
 
 #[derive(Debug)]
 struct EvalSourceMapDevToolJavascriptModulesPluginPlugin {
   columns: bool,
   no_sources: bool,
+  cache: Arc<ModuleRenderCache>,
 }
 
 impl EvalSourceMapDevToolJavascriptModulesPluginPlugin {
@@ -842,25 +908,20 @@ impl EvalSourceMapDevToolJavascriptModulesPluginPlugin {
     &self,
     source: &str,
     mut map: SourceMap,
+    module_id: &str,
     compilation: &Compilation,
   ) -> Result<BoxSource> {
+    map.set_file(Some(module_id.to_string()));
     for source in map.sources_mut() {
       let resource_path = normalize_custom_filename(source);
       let resource_path = contextify(&compilation.options.context, resource_path);
       *source = Cow::from(resource_path);
     }
-    if self.no_sources {
-      for content in map.sources_content_mut() {
-        *content = Cow::from(String::default());
-      }
-    }
-    let mut map_buffer = Vec::new();
-    map
-      .to_writer(&mut map_buffer)
-      .unwrap_or_else(|e| panic!("{}", e.to_string()));
-    let base64 = rspack_base64::encode_to_string(&map_buffer);
-    let footer =
-      format!("\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,{base64}");
+    let map_buffer = source_map_to_buffer(map, self.no_sources)?;
+    let base64 = rspack_base64::encode_to_string(map_buffer);
+    let footer = format!(
+      "\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,{base64}\n//# sourceURL=webpack-internal:///{module_id}\n"
+    );
     let result = RawSource::from(format!("eval({});", json!(format!("{source}{footer}")))).boxed();
     Ok(result)
   }
@@ -872,12 +933,30 @@ impl JavascriptModulesPluginPlugin for EvalSourceMapDevToolJavascriptModulesPlug
     mut args: RenderJsModuleContentArgs<'a>,
   ) -> PluginRenderJsModuleContentOutput<'a> {
     let origin_source = args.module_source.clone();
-    if let Some(cached) = MODULE_RENDER_CACHE.get(&origin_source) {
-      args.module_source = cached.value().clone();
+    let module_identifier = args.module.identifier();
+    let module_id = args
+      .compilation
+      .chunk_graph
+      .get_module_id(module_identifier)
+      .as_deref()
+      .unwrap_or_default();
+    if let Some(cached) = self
+      .cache
+      .get(&module_identifier, module_id, &origin_source)
+    {
+      args.module_source = cached;
+      return Ok(args);
+    } else if args.module.as_external_module().is_some() {
       return Ok(args);
     } else if let Some(map) = origin_source.map(&MapOptions::new(self.columns)) {
-      let source = self.wrap_eval_source_map(&origin_source.source(), map, args.compilation)?;
-      MODULE_RENDER_CACHE.insert(origin_source, source.clone());
+      let source =
+        self.wrap_eval_source_map(&origin_source.source(), map, module_id, args.compilation)?;
+      self.cache.insert(
+        module_identifier,
+        module_id.to_string(),
+        origin_source,
+        source.clone(),
+      );
       args.module_source = source;
       return Ok(args);
     }
@@ -897,11 +976,12 @@ const EVAL_SOURCE_MAP_DEV_TOOL_PLUGIN_NAME: &str = "rspack.EvalSourceMapDevToolP
 pub struct EvalSourceMapDevToolPlugin {
   columns: bool,
   no_sources: bool,
+  cache: Arc<ModuleRenderCache>,
 }
 
 impl EvalSourceMapDevToolPlugin {
   pub fn new(options: SourceMapDevToolPluginOptions) -> Self {
-    Self::new_inner(options.columns, options.no_sources)
+    Self::new_inner(options.columns, options.no_sources, Default::default())
   }
 }
 
@@ -915,10 +995,20 @@ async fn eval_source_map_devtool_plugin_compilation(
   drive.add_plugin(EvalSourceMapDevToolJavascriptModulesPluginPlugin {
     columns: self.columns,
     no_sources: self.no_sources,
+    cache: self.cache.clone(),
   });
   Ok(())
 }
 
+#[plugin_hook(CompilationProcessAssets for EvalSourceMapDevToolPlugin)]
+async fn eval_source_map_devtool_plugin_process_assets(
+  &self,
+  compilation: &mut Compilation,
+) -> Result<()> {
+  self.cache.prune(compilation);
+  Ok(())
+}
+
 #[async_trait::async_trait]
 impl Plugin for EvalSourceMapDevToolPlugin {
   fn name(&self) -> &'static str {
@@ -935,6 +1025,11 @@ impl Plugin for EvalSourceMapDevToolPlugin {
       .compiler_hooks
       .compilation
       .tap(eval_source_map_devtool_plugin_compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(eval_source_map_devtool_plugin_process_assets::new(self));
     Ok(())
   }
 }
@@ -1024,16 +1119,15 @@ pub struct EvalDevToolModulePluginOptions {
   pub source_url_comment: Option<String>,
 }
 
-static EVAL_MODULE_RENDER_CACHE: Lazy<DashMap<BoxSource, BoxSource>> = Lazy::new(DashMap::default);
-
 struct EvalDevToolModuleJavascriptModulesPluginPlugin {
   namespace: String,
   module_filename_template: ModuleFilenameTemplate,
   source_url_comment: String,
+  cache: Arc<ModuleRenderCache>,
 }
 
 impl EvalDevToolModuleJavascriptModulesPluginPlugin {
-  pub fn new(options: EvalDevToolModulePluginOptions) -> Self {
+  pub fn new(options: EvalDevToolModulePluginOptions, cache: Arc<ModuleRenderCache>) -> Self {
     let source_url_comment = options
       .source_url_comment
       .unwrap_or("\n//# sourceURL=[url]".to_string());
@@ -1049,6 +1143,7 @@ impl EvalDevToolModuleJavascriptModulesPluginPlugin {
       namespace: options.namespace.unwrap_or("".to_string()),
       module_filename_template,
       source_url_comment,
+      cache,
     }
   }
 
@@ -1066,8 +1161,18 @@ impl JavascriptModulesPluginPlugin for EvalDevToolModuleJavascriptModulesPluginP
     mut args: RenderJsModuleContentArgs<'a>,
   ) -> PluginRenderJsModuleContentOutput<'a> {
     let origin_source = args.module_source.clone();
-    if let Some(cached) = EVAL_MODULE_RENDER_CACHE.get(&origin_source) {
-      args.module_source = cached.value().clone();
+    let module_identifier = args.module.identifier();
+    let module_id = args
+      .compilation
+      .chunk_graph
+      .get_module_id(module_identifier)
+      .as_deref()
+      .unwrap_or_default();
+    if let Some(cached) = self
+      .cache
+      .get(&module_identifier, module_id, &origin_source)
+    {
+      args.module_source = cached;
       return Ok(args);
     } else if args.module.as_external_module().is_some() {
       return Ok(args);
@@ -1077,7 +1182,7 @@ impl JavascriptModulesPluginPlugin for EvalDevToolModuleJavascriptModulesPluginP
     let source_name = match &self.module_filename_template {
       ModuleFilenameTemplate::String(s) => {
         ModuleFilenameHelpers::create_filename_of_string_template(
-          &ModuleOrSource::Module(args.module.identifier()),
+          &ModuleOrSource::Module(module_identifier),
           args.compilation,
           s,
           output_options,
@@ -1086,18 +1191,22 @@ impl JavascriptModulesPluginPlugin for EvalDevToolModuleJavascriptModulesPluginP
       }
       ModuleFilenameTemplate::Fn(f) => {
         futures::executor::block_on(ModuleFilenameHelpers::create_filename_of_fn_template(
-          &ModuleOrSource::Module(args.module.identifier()),
+          &ModuleOrSource::Module(module_identifier),
           compilation,
           f,
           output_options,
           self.namespace.as_str(),
-        ))
-        .expect("todo!")
+        ))?
       }
     };
     let source = self.wrap_eval(&origin_source.source(), source_name.as_str())?;
 
-    EVAL_MODULE_RENDER_CACHE.insert(origin_source, source.clone());
+    self.cache.insert(
+      module_identifier,
+      module_id.to_string(),
+      origin_source,
+      source.clone(),
+    );
     args.module_source = source;
     Ok(args)
   }
@@ -1114,11 +1223,12 @@ const EVAL_DEV_TOOL_MODULE_PLUGIN_NAME: &str = "rspack.EvalDevToolModulePlugin";
 #[derive(Debug)]
 pub struct EvalDevToolModulePlugin {
   options: EvalDevToolModulePluginOptions,
+  cache: Arc<ModuleRenderCache>,
 }
 
 impl EvalDevToolModulePlugin {
   pub fn new(options: EvalDevToolModulePluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, Default::default())
   }
 }
 
@@ -1131,10 +1241,17 @@ async fn eval_devtool_plugin_compilation(
   let mut drive = JsPlugin::get_compilation_drives_mut(compilation);
   drive.add_plugin(EvalDevToolModuleJavascriptModulesPluginPlugin::new(
     self.options.clone(),
+    self.cache.clone(),
   ));
   Ok(())
 }
 
+#[plugin_hook(CompilationProcessAssets for EvalDevToolModulePlugin)]
+async fn eval_devtool_plugin_process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  self.cache.prune(compilation);
+  Ok(())
+}
+
 impl Plugin for EvalDevToolModulePlugin {
   fn name(&self) -> &'static str {
     EVAL_DEV_TOOL_MODULE_PLUGIN_NAME
@@ -1150,6 +1267,11 @@ impl Plugin for EvalDevToolModulePlugin {
       .compiler_hooks
       .compilation
       .tap(eval_devtool_plugin_compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(eval_devtool_plugin_process_assets::new(self));
     Ok(())
   }
 }
@@ -0,0 +1,57 @@
+use rspack_core::{
+  AsContextDependency, AsDependencyTemplate, Context, Dependency, DependencyCategory, DependencyId,
+  DependencyType, ModuleDependency,
+};
+
+#[derive(Debug, Clone)]
+pub struct DllEntryDependency {
+  id: DependencyId,
+  pub name: String,
+  pub context: Context,
+  pub entries: Vec<String>,
+  resource_identifier: String,
+}
+
+impl DllEntryDependency {
+  pub fn new(name: String, context: Context, entries: Vec<String>) -> Self {
+    let resource_identifier = format!("dll-entry-{}", &name);
+    Self {
+      id: DependencyId::new(),
+      name,
+      context,
+      entries,
+      resource_identifier,
+    }
+  }
+}
+
+impl Dependency for DllEntryDependency {
+  fn dependency_debug_name(&self) -> &'static str {
+    "DllEntryDependency"
+  }
+
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::DllEntry
+  }
+
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+}
+
+impl ModuleDependency for DllEntryDependency {
+  fn request(&self) -> &str {
+    &self.resource_identifier
+  }
+}
+
+impl AsContextDependency for DllEntryDependency {}
+impl AsDependencyTemplate for DllEntryDependency {}
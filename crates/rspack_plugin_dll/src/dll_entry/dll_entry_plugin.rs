@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rspack_core::{
+  ApplyContext, Compilation, CompilationParams, CompilerCompilation, CompilerMake, CompilerOptions,
+  Context, DependencyType, EntryOptions, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+use super::{dll_entry_dependency::DllEntryDependency, dll_module_factory::DllModuleFactory};
+
+#[derive(Debug, Clone)]
+pub struct DllEntryPluginOptions {
+  pub name: String,
+  pub context: Context,
+  pub entries: Vec<String>,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct DllEntryPlugin {
+  options: DllEntryPluginOptions,
+}
+
+impl DllEntryPlugin {
+  pub fn new(options: DllEntryPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilerCompilation for DllEntryPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(DependencyType::DllEntry, Arc::new(DllModuleFactory));
+  compilation.set_dependency_factory(DependencyType::Entry, params.normal_module_factory.clone());
+  Ok(())
+}
+
+#[plugin_hook(CompilerMake for DllEntryPlugin)]
+async fn make(&self, compilation: &mut Compilation) -> Result<()> {
+  let dep = DllEntryDependency::new(
+    self.options.name.clone(),
+    self.options.context.clone(),
+    self.options.entries.clone(),
+  );
+  compilation
+    .add_entry(
+      Box::new(dep),
+      EntryOptions {
+        name: Some(self.options.name.clone()),
+        ..Default::default()
+      },
+    )
+    .await?;
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for DllEntryPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DllEntryPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx.context.compiler_hooks.make.tap(make::new(self));
+    Ok(())
+  }
+}
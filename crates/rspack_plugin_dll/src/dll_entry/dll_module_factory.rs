@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use rspack_core::{ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult};
+use rspack_error::Result;
+
+use super::{dll_entry_dependency::DllEntryDependency, dll_module::DllModule};
+
+#[derive(Debug)]
+pub struct DllModuleFactory;
+
+#[async_trait]
+impl ModuleFactory for DllModuleFactory {
+  async fn create(&self, data: &mut ModuleFactoryCreateData) -> Result<ModuleFactoryResult> {
+    let dep = data
+      .dependency
+      .downcast_ref::<DllEntryDependency>()
+      .expect("dependency of DllModuleFactory should be DllEntryDependency");
+    Ok(ModuleFactoryResult::new_with_module(Box::new(
+      DllModule::new(dep),
+    )))
+  }
+}
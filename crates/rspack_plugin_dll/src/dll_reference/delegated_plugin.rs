@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use rspack_core::{
+  ApplyContext, BoxModule, Compilation, CompilationParams, CompilerCompilation, CompilerOptions,
+  Context, DependencyType, LibIdentOptions, ModuleFactoryCreateData, NormalModuleCreateData,
+  NormalModuleFactoryFactorize, NormalModuleFactoryModule, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+use super::delegated_module::DelegatedModule;
+use crate::DllManifestContent;
+
+#[derive(Debug, Clone)]
+pub struct DelegatedPluginOptions {
+  pub source: String,
+  pub context: Context,
+  pub content: DllManifestContent,
+  pub r#type: String,
+  pub extensions: Vec<String>,
+  pub scope: Option<String>,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct DelegatedPlugin {
+  options: DelegatedPluginOptions,
+}
+
+impl DelegatedPlugin {
+  pub fn new(options: DelegatedPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilerCompilation for DelegatedPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::DelegatedSource,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+#[plugin_hook(NormalModuleFactoryFactorize for DelegatedPlugin)]
+async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<BoxModule>> {
+  let Some(scope) = &self.options.scope else {
+    return Ok(None);
+  };
+  let Some(dependency) = data.dependency.as_module_dependency() else {
+    return Ok(None);
+  };
+  let request = dependency.request();
+  let Some(inner_request) = request
+    .strip_prefix(scope.as_str())
+    .filter(|rest| rest.starts_with('/'))
+    .map(|rest| format!(".{rest}"))
+  else {
+    return Ok(None);
+  };
+
+  if let Some(resolved) = self.options.content.get(&inner_request) {
+    return Ok(Some(Box::new(DelegatedModule::new(
+      self.options.source.clone(),
+      resolved.clone(),
+      self.options.r#type.clone(),
+      inner_request,
+      request.to_string(),
+    ))));
+  }
+
+  for extension in &self.options.extensions {
+    let request_plus_ext = format!("{inner_request}{extension}");
+    if let Some(resolved) = self.options.content.get(&request_plus_ext) {
+      return Ok(Some(Box::new(DelegatedModule::new(
+        self.options.source.clone(),
+        resolved.clone(),
+        self.options.r#type.clone(),
+        request_plus_ext,
+        format!("{request}{extension}"),
+      ))));
+    }
+  }
+
+  Ok(None)
+}
+
+#[plugin_hook(NormalModuleFactoryModule for DelegatedPlugin)]
+async fn nmf_module(
+  &self,
+  _data: &mut ModuleFactoryCreateData,
+  _create_data: &mut NormalModuleCreateData,
+  module: &mut BoxModule,
+) -> Result<()> {
+  if self.options.scope.is_some() {
+    return Ok(());
+  }
+  let Some(request) = module
+    .lib_ident(LibIdentOptions {
+      context: self.options.context.as_str(),
+    })
+    .map(|request| request.into_owned())
+  else {
+    return Ok(());
+  };
+  if let Some(resolved) = self.options.content.get(&request) {
+    *module = Box::new(DelegatedModule::new(
+      self.options.source.clone(),
+      resolved.clone(),
+      self.options.r#type.clone(),
+      request.clone(),
+      request,
+    ));
+  }
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for DelegatedPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DelegatedPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .factorize
+      .tap(factorize::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .module
+      .tap(nmf_module::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use rspack_core::{ApplyContext, CompilerOptions, Context, Plugin, PluginContext};
+use rspack_error::Result;
+
+use super::delegated_plugin::{DelegatedPlugin, DelegatedPluginOptions};
+use crate::DllManifestContent;
+
+#[derive(Debug, Clone)]
+pub struct DllReferenceAgencyPluginOptions {
+  pub context: Option<Context>,
+  pub name: String,
+  pub content: DllManifestContent,
+  pub extensions: Vec<String>,
+  pub scope: Option<String>,
+  pub r#type: String,
+}
+
+/// Delegates the modules found in a dll manifest to the `dll-reference {name}` external,
+/// the external itself is added by the js side `DllReferencePlugin`.
+#[derive(Debug)]
+pub struct DllReferenceAgencyPlugin {
+  options: DllReferenceAgencyPluginOptions,
+}
+
+impl DllReferenceAgencyPlugin {
+  pub fn new(options: DllReferenceAgencyPluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+#[async_trait]
+impl Plugin for DllReferenceAgencyPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DllReferenceAgencyPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let delegated_plugin = DelegatedPlugin::new(DelegatedPluginOptions {
+      source: format!("dll-reference {}", self.options.name),
+      context: self
+        .options
+        .context
+        .clone()
+        .unwrap_or_else(|| options.context.clone()),
+      content: self.options.content.clone(),
+      r#type: self.options.r#type.clone(),
+      extensions: self.options.extensions.clone(),
+      scope: self.options.scope.clone(),
+    });
+    delegated_plugin.apply(PluginContext::with_context(ctx.context), options)
+  }
+}
@@ -0,0 +1,4 @@
+pub mod delegated_module;
+pub mod delegated_plugin;
+pub mod delegated_source_dependency;
+pub mod dll_reference_agency_plugin;
@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use rspack_core::{
+  ApplyContext, BoxModule, Compilation, CompilationOptimizeDependencies, CompilationSucceedModule,
+  CompilerOptions, FactoryMeta, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+#[plugin]
+#[derive(Debug)]
+pub struct FlagAllModulesAsUsedPlugin {
+  explanation: String,
+}
+
+impl FlagAllModulesAsUsedPlugin {
+  pub fn new(explanation: String) -> Self {
+    Self::new_inner(explanation)
+  }
+}
+
+#[plugin_hook(CompilationSucceedModule for FlagAllModulesAsUsedPlugin)]
+async fn succeed_module(&self, module: &mut BoxModule) -> Result<()> {
+  // modules of a dll can be consumed by anyone, so none of them is side effect free
+  let factory_meta = module.factory_meta().cloned().unwrap_or_default();
+  module.set_factory_meta(FactoryMeta {
+    side_effect_free: Some(false),
+    ..factory_meta
+  });
+  Ok(())
+}
+
+#[plugin_hook(CompilationOptimizeDependencies for FlagAllModulesAsUsedPlugin)]
+fn optimize_dependencies(&self, compilation: &mut Compilation) -> Result<Option<bool>> {
+  let mut module_graph = compilation.get_module_graph_mut();
+  let module_identifiers = module_graph.modules().into_keys().collect::<Vec<_>>();
+  for module_identifier in module_identifiers {
+    let exports_info_id = module_graph.get_exports_info(&module_identifier).id;
+    exports_info_id.set_used_in_unknown_way(&mut module_graph, None);
+    module_graph
+      .get_optimization_bailout_mut(&module_identifier)
+      .push(self.explanation.clone());
+  }
+  Ok(None)
+}
+
+#[async_trait]
+impl Plugin for FlagAllModulesAsUsedPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.FlagAllModulesAsUsedPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .succeed_module
+      .tap(succeed_module::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .optimize_dependencies
+      .tap(optimize_dependencies::new(self));
+    Ok(())
+  }
+}
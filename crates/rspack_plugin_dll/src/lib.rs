@@ -0,0 +1,53 @@
+use std::collections::BTreeMap;
+
+use rspack_core::BuildMeta;
+use serde::{Deserialize, Deserializer, Serialize};
+
+mod dll_entry;
+mod dll_reference;
+mod flag_all_modules_as_used_plugin;
+mod lib_manifest_plugin;
+
+pub use dll_entry::dll_entry_plugin::{DllEntryPlugin, DllEntryPluginOptions};
+pub use dll_reference::dll_reference_agency_plugin::{
+  DllReferenceAgencyPlugin, DllReferenceAgencyPluginOptions,
+};
+pub use flag_all_modules_as_used_plugin::FlagAllModulesAsUsedPlugin;
+pub use lib_manifest_plugin::{LibManifestPlugin, LibManifestPluginOptions};
+
+pub type DllManifestContent = BTreeMap<String, DllManifestContentItem>;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DllManifestContentItem {
+  #[serde(deserialize_with = "deserialize_module_id")]
+  pub id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub build_meta: Option<BuildMeta>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub exports: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DllManifest {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+  pub r#type: Option<String>,
+  pub content: DllManifestContent,
+}
+
+/// Manifests emitted by webpack may use numeric module ids
+fn deserialize_module_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum ModuleId {
+    String(String),
+    Number(u64),
+  }
+
+  Ok(match ModuleId::deserialize(deserializer)? {
+    ModuleId::String(id) => id,
+    ModuleId::Number(id) => id.to_string(),
+  })
+}
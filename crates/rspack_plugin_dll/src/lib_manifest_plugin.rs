@@ -0,0 +1,170 @@
+use std::hash::Hasher;
+
+use async_trait::async_trait;
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, Compilation, CompilationAsset, CompilerEmit, CompilerOptions, Context,
+  DependencyType, FilenameTemplate, LibIdentOptions, PathData, Plugin, PluginContext,
+  ProvidedExports, SourceType,
+};
+use rspack_error::{error, miette::IntoDiagnostic, Result};
+use rspack_hash::RspackHash;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::infallible::ResultInfallibleExt as _;
+use rustc_hash::FxHashSet as HashSet;
+
+use crate::{DllManifest, DllManifestContent, DllManifestContentItem};
+
+#[derive(Debug, Clone)]
+pub struct LibManifestPluginOptions {
+  pub context: Option<Context>,
+  pub entry_only: Option<bool>,
+  pub name: Option<FilenameTemplate>,
+  pub path: FilenameTemplate,
+  pub format: Option<bool>,
+  pub r#type: Option<String>,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct LibManifestPlugin {
+  options: LibManifestPluginOptions,
+}
+
+impl LibManifestPlugin {
+  pub fn new(options: LibManifestPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilerEmit for LibManifestPlugin)]
+async fn emit(&self, compilation: &mut Compilation) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  let chunk_graph = &compilation.chunk_graph;
+  let context = self
+    .options
+    .context
+    .as_ref()
+    .unwrap_or(&compilation.options.context)
+    .as_str();
+  let entry_only = self.options.entry_only.unwrap_or_default();
+
+  let mut chunks = compilation
+    .chunk_by_ukey
+    .values()
+    .filter(|chunk| chunk.can_be_initial(&compilation.chunk_group_by_ukey))
+    .collect::<Vec<_>>();
+  chunks.sort_unstable_by_key(|chunk| chunk.ukey.as_usize());
+
+  let mut used_paths = HashSet::default();
+  let mut manifests = vec![];
+  for chunk in chunks {
+    let name = self.options.name.as_ref().map(|name| {
+      compilation
+        .get_path(
+          name,
+          PathData::default().chunk(chunk).content_hash_optional(
+            chunk
+              .content_hash
+              .get(&SourceType::JavaScript)
+              .map(|hash| hash.encoded()),
+          ),
+        )
+        .always_ok()
+    });
+
+    let mut content = DllManifestContent::default();
+    for module in chunk_graph.get_ordered_chunk_modules(&chunk.ukey, &module_graph) {
+      if entry_only
+        && !module_graph
+          .get_incoming_connections(&module.identifier())
+          .iter()
+          .any(|connection| {
+            module_graph
+              .dependency_by_id(&connection.dependency_id)
+              .is_some_and(|dep| dep.dependency_type() == &DependencyType::Entry)
+          })
+      {
+        continue;
+      }
+      let Some(ident) = module.lib_ident(LibIdentOptions { context }) else {
+        continue;
+      };
+      let Some(id) = chunk_graph.get_module_id(module.identifier()) else {
+        continue;
+      };
+      let exports = match module_graph
+        .get_exports_info(&module.identifier())
+        .get_provided_exports(&module_graph)
+      {
+        ProvidedExports::Vec(exports) => Some(exports.iter().map(|e| e.to_string()).collect()),
+        _ => None,
+      };
+      content.insert(
+        ident.into_owned(),
+        DllManifestContentItem {
+          id: id.clone(),
+          build_meta: module.build_meta().cloned(),
+          exports,
+        },
+      );
+    }
+
+    let manifest = DllManifest {
+      name,
+      r#type: self.options.r#type.clone(),
+      content,
+    };
+    let manifest_json = if self.options.format.unwrap_or_default() {
+      serde_json::to_string_pretty(&manifest)
+    } else {
+      serde_json::to_string(&manifest)
+    }
+    .into_diagnostic()?;
+
+    let mut hasher = RspackHash::from(&compilation.options.output);
+    hasher.write(manifest_json.as_bytes());
+    let content_hash = hasher.digest(&compilation.options.output.hash_digest);
+    let target_path = compilation
+      .get_path(
+        &self.options.path,
+        PathData::default()
+          .chunk(chunk)
+          .content_hash(content_hash.encoded()),
+      )
+      .always_ok();
+    if !used_paths.insert(target_path.clone()) {
+      return Err(error!(
+        "each chunk must have a unique path, but \"{target_path}\" is used more than once"
+      ));
+    }
+    manifests.push((target_path, manifest_json));
+  }
+
+  for (filename, manifest_json) in manifests {
+    compilation.emit_asset(
+      filename,
+      CompilationAsset::new(
+        Some(RawSource::from(manifest_json).boxed()),
+        Default::default(),
+      ),
+    );
+  }
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for LibManifestPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.LibManifestPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx.context.compiler_hooks.emit.tap(emit::new(self));
+    Ok(())
+  }
+}
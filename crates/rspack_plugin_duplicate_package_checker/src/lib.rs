@@ -0,0 +1,191 @@
+// https://github.com/darrenscerri/duplicate-package-checker-webpack-plugin
+
+use std::collections::BTreeMap;
+
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, Compilation, CompilationAsset, CompilationSeal, CompilerOptions, Logger, Plugin,
+  PluginContext,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use serde::Serialize;
+
+#[derive(Debug)]
+pub struct DuplicatePackageCheckerRspackPluginOptions {
+  /// Reports the duplicated packages as errors instead of warnings.
+  pub emit_error: bool,
+  /// The filename of the emitted report, relative to the output path.
+  pub filename: Option<String>,
+  /// The names of the packages which are allowed to have multiple versions.
+  pub exclude: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PackageInstance {
+  version: String,
+  /// The directory of the package, relative to the context.
+  path: String,
+  /// The size of the modules of the package.
+  size: f64,
+  modules: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DuplicatePackage {
+  name: String,
+  /// The size of all the instances except the largest one.
+  duplicated_size: f64,
+  instances: Vec<PackageInstance>,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct DuplicatePackageCheckerRspackPlugin {
+  options: DuplicatePackageCheckerRspackPluginOptions,
+}
+
+impl DuplicatePackageCheckerRspackPlugin {
+  pub fn new(options: DuplicatePackageCheckerRspackPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+
+  fn find_duplicates(&self, compilation: &Compilation) -> Vec<DuplicatePackage> {
+    let module_graph = compilation.get_module_graph();
+    let context = &compilation.options.context;
+    // package name -> package directory -> instance
+    let mut packages: BTreeMap<String, BTreeMap<String, PackageInstance>> = BTreeMap::new();
+
+    for module in module_graph.modules().values() {
+      let Some(normal_module) = module.as_normal_module() else {
+        continue;
+      };
+      let Some(description) = &normal_module.resource_resolved_data().resource_description else {
+        continue;
+      };
+      let Some(dir) = description.path().parent() else {
+        continue;
+      };
+      // only the installed packages are checked, the packages of a monorepo
+      // are linked into `node_modules` at most once
+      if !dir.components().any(|c| c.as_os_str() == "node_modules") {
+        continue;
+      }
+      let json = description.json();
+      let (Some(name), Some(version)) = (
+        json.get("name").and_then(|name| name.as_str()),
+        json.get("version").and_then(|version| version.as_str()),
+      ) else {
+        continue;
+      };
+      if self.options.exclude.iter().any(|exclude| exclude == name) {
+        continue;
+      }
+      let size = module
+        .source_types()
+        .iter()
+        .map(|source_type| module.size(source_type))
+        .sum::<f64>();
+      let instance = packages
+        .entry(name.to_string())
+        .or_default()
+        .entry(dir.to_string_lossy().to_string())
+        .or_insert_with(|| PackageInstance {
+          version: version.to_string(),
+          path: context.shorten(&dir.to_string_lossy()),
+          size: 0.0,
+          modules: 0,
+        });
+      instance.size += size;
+      instance.modules += 1;
+    }
+
+    packages
+      .into_iter()
+      .filter(|(_, instances)| {
+        let mut versions = instances.values().map(|instance| &instance.version);
+        let first = versions.next();
+        versions.any(|version| Some(version) != first)
+      })
+      .map(|(name, instances)| {
+        let mut instances: Vec<PackageInstance> = instances.into_values().collect();
+        instances.sort_by(|a, b| a.version.cmp(&b.version).then(a.path.cmp(&b.path)));
+        let total = instances.iter().map(|instance| instance.size).sum::<f64>();
+        let largest = instances
+          .iter()
+          .map(|instance| instance.size)
+          .fold(0.0, f64::max);
+        DuplicatePackage {
+          name,
+          duplicated_size: total - largest,
+          instances,
+        }
+      })
+      .collect()
+  }
+}
+
+fn create_message(package: &DuplicatePackage) -> String {
+  let mut message = format!(
+    "Multiple versions of \"{}\" found, {} bytes are duplicated:\n",
+    package.name, package.duplicated_size
+  );
+  for instance in &package.instances {
+    message.push_str(&format!(
+      "  {} {} ({} bytes)\n",
+      instance.version, instance.path, instance.size
+    ));
+  }
+  message
+}
+
+#[plugin_hook(CompilationSeal for DuplicatePackageCheckerRspackPlugin)]
+fn seal(&self, compilation: &mut Compilation) -> Result<()> {
+  let logger = compilation.get_logger(self.name());
+  let start = logger.time("check duplicate packages");
+  let duplicates = self.find_duplicates(compilation);
+
+  let diagnostics = duplicates
+    .iter()
+    .map(|package| {
+      let title = "Duplicate Package".to_string();
+      let message = create_message(package);
+      let diagnostic = if self.options.emit_error {
+        Diagnostic::error(title, message)
+      } else {
+        Diagnostic::warn(title, message)
+      };
+      diagnostic.with_diagnostic_code("DUPLICATE_PACKAGE")
+    })
+    .collect::<Vec<_>>();
+  compilation.push_batch_diagnostic(diagnostics);
+
+  if let Some(filename) = &self.options.filename {
+    let report = serde_json::to_string_pretty(&duplicates).expect("should serialize the report");
+    compilation.emit_asset(
+      filename.clone(),
+      CompilationAsset::from(RawSource::from(report).boxed()),
+    );
+  }
+
+  logger.time_end(start);
+  Ok(())
+}
+
+// This plugin reports the packages which are bundled in multiple versions,
+// it is executed in hook `compilation.seal`
+impl Plugin for DuplicatePackageCheckerRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DuplicatePackageCheckerRspackPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx.context.compilation_hooks.seal.tap(seal::new(self));
+    Ok(())
+  }
+}
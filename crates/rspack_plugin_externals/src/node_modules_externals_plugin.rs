@@ -0,0 +1,134 @@
+use std::path::{Component, Path};
+
+use rspack_core::{
+  ApplyContext, BoxModule, CompilerOptions, ExternalModule, ExternalRequest, ExternalRequestValue,
+  ExternalType, ModuleExt, ModuleFactoryCreateData, NormalModuleCreateData,
+  NormalModuleFactoryCreateModule, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_regex::RspackRegex;
+
+#[derive(Debug)]
+pub enum NodeModulesExternalsAllowlistItem {
+  String(String),
+  RegExp(RspackRegex),
+}
+
+impl NodeModulesExternalsAllowlistItem {
+  fn test(&self, request: &str) -> bool {
+    match self {
+      Self::String(s) => s == request,
+      Self::RegExp(r) => r.test(request),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct NodeModulesExternalsOptions {
+  /// The requests still bundled even if they are resolved into node_modules
+  pub allowlist: Vec<NodeModulesExternalsAllowlistItem>,
+  pub import_type: ExternalType,
+}
+
+/// Externalizes the bare requests which are resolved into the directories of
+/// their packages in node_modules, the requests aliased to other paths are
+/// still bundled.
+#[plugin]
+#[derive(Debug)]
+pub struct NodeModulesExternalsPlugin {
+  options: NodeModulesExternalsOptions,
+}
+
+impl NodeModulesExternalsPlugin {
+  pub fn new(options: NodeModulesExternalsOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(NormalModuleFactoryCreateModule for NodeModulesExternalsPlugin)]
+async fn create_module(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  create_data: &mut NormalModuleCreateData,
+) -> Result<Option<BoxModule>> {
+  let Some(dependency) = data.dependency.as_module_dependency() else {
+    return Ok(None);
+  };
+  let request = dependency.request();
+  let Some(package_name) = get_package_name(request) else {
+    return Ok(None);
+  };
+  if self.options.allowlist.iter().any(|item| item.test(request))
+    || !is_in_package(
+      &create_data.resource_resolve_data.resource_path,
+      package_name,
+    )
+  {
+    return Ok(None);
+  }
+  Ok(Some(
+    ExternalModule::new(
+      ExternalRequest::Single(ExternalRequestValue::new(request.to_string(), None)),
+      self.options.import_type.clone(),
+      request.to_string(),
+    )
+    .boxed(),
+  ))
+}
+
+impl Plugin for NodeModulesExternalsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.NodeModulesExternalsPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .create_module
+      .tap(create_module::new(self));
+    Ok(())
+  }
+}
+
+/// Get the package name of a bare request, e.g. `@scope/pkg` of `@scope/pkg/lib/index.js`
+fn get_package_name(request: &str) -> Option<&str> {
+  if request.is_empty()
+    || request.starts_with(['.', '/', '#', '\\'])
+    || request.contains([':', '!', '?'])
+  {
+    return None;
+  }
+  let mut segments = request.splitn(3, '/');
+  let first = segments.next()?;
+  if first.starts_with('@') {
+    let second = segments.next().filter(|s| !s.is_empty())?;
+    Some(&request[..first.len() + 1 + second.len()])
+  } else {
+    Some(first)
+  }
+}
+
+/// Whether the resolved path is inside the directory of the package in the
+/// innermost node_modules, e.g. `node_modules/.pnpm/pkg@1.0.0/node_modules/pkg/index.js`
+fn is_in_package(resource_path: &Path, package_name: &str) -> bool {
+  let components = resource_path
+    .components()
+    .filter_map(|component| match component {
+      Component::Normal(name) => name.to_str(),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+  let Some(index) = components.iter().rposition(|name| *name == "node_modules") else {
+    return false;
+  };
+  let package_segments = package_name.split('/').collect::<Vec<_>>();
+  components
+    .get(index + 1..index + 1 + package_segments.len())
+    .is_some_and(|names| names == package_segments.as_slice())
+}
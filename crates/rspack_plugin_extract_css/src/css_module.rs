@@ -2,12 +2,13 @@ use std::hash::Hash;
 use std::path::PathBuf;
 
 use once_cell::sync::Lazy;
-use rspack_core::rspack_sources::Source;
+use rspack_core::rspack_sources::{Source, SourceMap};
 use rspack_core::{
-  impl_module_meta_info, impl_source_map_config, AsyncDependenciesBlockIdentifier, BuildContext,
-  BuildInfo, BuildMeta, BuildResult, CodeGenerationResult, Compilation, CompilerOptions,
-  ConcatenationScope, DependenciesBlock, DependencyId, DependencyType, FactoryMeta, Module,
-  ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult, RuntimeSpec, SourceType,
+  contextify_source_map, impl_module_meta_info, impl_source_map_config,
+  AsyncDependenciesBlockIdentifier, BuildContext, BuildInfo, BuildMeta, BuildResult,
+  CodeGenerationResult, Compilation, CompilerOptions, ConcatenationScope, DependenciesBlock,
+  DependencyId, DependencyType, FactoryMeta, Module, ModuleFactory, ModuleFactoryCreateData,
+  ModuleFactoryResult, RuntimeSpec, SourceType,
 };
 use rspack_error::Result;
 use rspack_error::{impl_empty_diagnosable_trait, Diagnostic};
@@ -90,6 +91,14 @@ impl CssModule {
     self.content.hash(&mut hasher);
     self.supports.hash(&mut hasher);
     self.media.hash(&mut hasher);
+    if options.is_path_independent()
+      && let Ok(mut source_map) = SourceMap::from_json(&self.source_map)
+    {
+      contextify_source_map(options, &mut source_map);
+      source_map.hash(&mut hasher);
+    } else {
+      self.source_map.hash(&mut hasher);
+    }
 
     hasher.digest(&options.output.hash_digest)
   }
@@ -1,9 +1,11 @@
 use std::{borrow::Cow, cmp::max, hash::Hash, sync::Arc};
 
 use once_cell::sync::Lazy;
-use regex::Regex;
+use regex::{Captures, Regex};
 use rspack_core::{
-  rspack_sources::{ConcatSource, RawSource, SourceMap, SourceMapSource, WithoutOriginalOptions},
+  rspack_sources::{
+    ConcatSource, RawSource, ReplaceSource, SourceMap, SourceMapSource, WithoutOriginalOptions,
+  },
   ApplyContext, AssetInfo, Chunk, ChunkGroupUkey, ChunkKind, ChunkUkey, Compilation,
   CompilationContentHash, CompilationParams, CompilationRenderManifest,
   CompilationRuntimeRequirementInTree, CompilerCompilation, CompilerOptions, Filename, Module,
@@ -14,6 +16,7 @@ use rspack_error::{Diagnostic, Result};
 use rspack_hash::RspackHash;
 use rspack_hook::{plugin, plugin_hook};
 use rspack_plugin_runtime::GetChunkFilenameRuntimeModule;
+use rspack_util::source_map::ModuleSourceMapConfig;
 use rustc_hash::{FxHashMap, FxHashSet};
 use ustr::Ustr;
 
@@ -30,19 +33,36 @@ pub static SOURCE_TYPE: Lazy<[SourceType; 1]> =
   Lazy::new(|| [SourceType::Custom(*MODULE_TYPE_STR)]);
 
 pub static AUTO_PUBLIC_PATH: &str = "__mini_css_extract_plugin_public_path_auto__";
-pub static AUTO_PUBLIC_PATH_RE: Lazy<Regex> =
-  Lazy::new(|| Regex::new(AUTO_PUBLIC_PATH).expect("should compile"));
 
 pub static ABSOLUTE_PUBLIC_PATH: &str = "webpack:///mini-css-extract-plugin/";
-pub static ABSOLUTE_PUBLIC_PATH_RE: Lazy<Regex> =
-  Lazy::new(|| Regex::new(ABSOLUTE_PUBLIC_PATH).expect("should compile"));
 
 pub static BASE_URI: &str = "webpack://";
-pub static BASE_URI_RE: Lazy<Regex> = Lazy::new(|| Regex::new(BASE_URI).expect("should compile"));
 
 pub static SINGLE_DOT_PATH_SEGMENT: &str = "__mini_css_extract_plugin_single_dot_path_segment__";
-pub static SINGLE_DOT_PATH_SEGMENT_RE: Lazy<Regex> =
-  Lazy::new(|| Regex::new(SINGLE_DOT_PATH_SEGMENT).expect("should compile"));
+
+// `ABSOLUTE_PUBLIC_PATH` must come before `BASE_URI` since the latter is a prefix of it
+static PLACEHOLDERS_RE: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(&format!(
+    "{}|{}|{}|{}",
+    regex::escape(ABSOLUTE_PUBLIC_PATH),
+    regex::escape(SINGLE_DOT_PATH_SEGMENT),
+    regex::escape(AUTO_PUBLIC_PATH),
+    regex::escape(BASE_URI),
+  ))
+  .expect("should compile")
+});
+
+fn replace_placeholder<'a>(placeholder: &str, undo_path: &'a str, base_uri: &'a str) -> &'a str {
+  if placeholder == ABSOLUTE_PUBLIC_PATH {
+    ""
+  } else if placeholder == SINGLE_DOT_PATH_SEGMENT {
+    "."
+  } else if placeholder == AUTO_PUBLIC_PATH {
+    undo_path
+  } else {
+    base_uri
+  }
+}
 
 static STARTS_WITH_AT_IMPORT_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new("^@import url").expect("should compile"));
@@ -376,25 +396,34 @@ impl PluginCssExtract {
             .expect("should have output.path"),
           false,
         );
-
-        let content = ABSOLUTE_PUBLIC_PATH_RE.replace_all(&content, "");
-        let content = SINGLE_DOT_PATH_SEGMENT_RE.replace_all(&content, ".");
-        let content = AUTO_PUBLIC_PATH_RE.replace_all(&content, &undo_path);
-        let content = BASE_URI_RE.replace_all(
-          &content,
-          chunk
-            .get_entry_options(&compilation.chunk_group_by_ukey)
-            .and_then(|entry_options| entry_options.base_uri.as_ref())
-            .unwrap_or(&undo_path),
-        );
-
-        if !module.source_map.is_empty() {
-          source.add(SourceMapSource::new(WithoutOriginalOptions {
-            value: content.to_string(),
-            name: readable_identifier,
-            source_map: SourceMap::from_json(&module.source_map).expect("invalid sourcemap"),
-          }))
+        let base_uri = chunk
+          .get_entry_options(&compilation.chunk_group_by_ukey)
+          .and_then(|entry_options| entry_options.base_uri.as_ref())
+          .unwrap_or(&undo_path);
+
+        // Placeholders are replaced through a `ReplaceSource` when the loader
+        // provides a source map, so the mappings still point at the right columns
+        // of the original `.scss`/`.less` sources after the rewrite.
+        if !module.source_map.is_empty() && module.get_source_map_kind().enabled() {
+          let mut replace_source =
+            ReplaceSource::new(SourceMapSource::new(WithoutOriginalOptions {
+              value: content.to_string(),
+              name: readable_identifier,
+              source_map: SourceMap::from_json(&module.source_map).expect("invalid sourcemap"),
+            }));
+          for placeholder in PLACEHOLDERS_RE.find_iter(&content) {
+            replace_source.replace(
+              placeholder.start() as u32,
+              placeholder.end() as u32,
+              replace_placeholder(placeholder.as_str(), &undo_path, base_uri),
+              None,
+            );
+          }
+          source.add(replace_source);
         } else {
+          let content = PLACEHOLDERS_RE.replace_all(&content, |caps: &Captures| {
+            replace_placeholder(&caps[0], &undo_path, base_uri).to_string()
+          });
           source.add(RawSource::from(content.to_string()));
         }
 
@@ -659,6 +688,7 @@ despite it was not able to fulfill desired ordering with these modules:\n{}",
             .join("\n")
         ),
       )
+      .with_diagnostic_code("CSS_ORDER_CONFLICT")
     }));
   }
   manifest.push(render_result);
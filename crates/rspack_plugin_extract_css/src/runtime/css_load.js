@@ -5,6 +5,9 @@ var createStylesheet = function (
 	var linkTag = document.createElement("link");
 	__SET_ATTRIBUTES__
 	linkTag.rel = "stylesheet";
+	if (__webpack_require__.nc) {
+		linkTag.nonce = __webpack_require__.nc;
+	}
 	__SET_LINKTYPE__
 	var onLinkComplete = function (event) {
 		// avoid mem leaks.
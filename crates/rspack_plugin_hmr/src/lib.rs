@@ -245,11 +245,14 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           compilation
             .get_path(
               &compilation.options.output.hot_update_chunk_filename,
-              PathData::default().chunk(chunk).hash_optional(
-                old_hash
-                  .as_ref()
-                  .map(|hash| hash.rendered(compilation.options.output.hash_digest_length)),
-              ),
+              PathData::default()
+                .chunk(chunk)
+                .runtime(&chunk.runtime)
+                .hash_optional(
+                  old_hash
+                    .as_ref()
+                    .map(|hash| hash.rendered(compilation.options.output.hash_digest_length)),
+                ),
             )
             .always_ok()
         };
@@ -183,7 +183,7 @@ function setStatus(newStatus) {
 	for (var i = 0; i < registeredStatusHandlers.length; i++)
 		results[i] = registeredStatusHandlers[i].call(null, newStatus);
 
-	return Promise.all(results);
+	return Promise.all(results).then(function () {});
 }
 
 function unblock() {
@@ -71,7 +71,8 @@ pub fn html_parse_error_to_traceable_error(error: Error, fm: &SourceFile) -> rsp
     "HTML parsing error".to_string(),
     message.to_string(),
   )
-  .with_kind(DiagnosticKind::Html);
+  .with_kind(DiagnosticKind::Html)
+  .with_code("HTML_PARSE_ERROR");
   //Use this `Error` conversion could avoid eagerly clone source file.
   traceable_error.into()
 }
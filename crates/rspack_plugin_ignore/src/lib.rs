@@ -37,41 +37,40 @@ impl IgnorePlugin {
     Self::new_inner(options)
   }
 
-  async fn check_ignore(&self, request: Option<&str>, context: &str) -> Option<bool> {
+  async fn check_ignore(&self, request: Option<&str>, context: &str) -> Result<Option<bool>> {
+    let Some(request) = request else {
+      return Ok(None);
+    };
+
     if let Some(check_resource) = &self.options.check_resource {
-      if let Some(request) = request {
-        match check_resource {
-          CheckResourceContent::Fn(check) => {
-            if check(request, context)
-              .await
-              .expect("run IgnorePlugin check resource error")
-            {
-              return Some(false);
-            }
+      match check_resource {
+        CheckResourceContent::Fn(check) => {
+          if check(request, context).await? {
+            return Ok(Some(false));
           }
         }
       }
     }
 
     if let Some(resource_reg_exp) = &self.options.resource_reg_exp {
-      if resource_reg_exp.test(request?) {
+      if resource_reg_exp.test(request) {
         if let Some(context_reg_exp) = &self.options.context_reg_exp {
           if context_reg_exp.test(context) {
-            return Some(false);
+            return Ok(Some(false));
           }
         } else {
-          return Some(false);
+          return Ok(Some(false));
         }
       }
     }
 
-    None
+    Ok(None)
   }
 }
 
 #[plugin_hook(NormalModuleFactoryBeforeResolve for IgnorePlugin)]
 async fn nmf_before_resolve(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<bool>> {
-  Ok(self.check_ignore(data.request(), &data.context).await)
+  self.check_ignore(data.request(), &data.context).await
 }
 
 #[plugin_hook(ContextModuleFactoryBeforeResolve for IgnorePlugin)]
@@ -79,7 +78,7 @@ async fn cmf_before_resolve(&self, data: BeforeResolveResult) -> Result<BeforeRe
   match data {
     BeforeResolveResult::Ignored => Ok(BeforeResolveResult::Ignored),
     BeforeResolveResult::Data(d) => {
-      if let Some(false) = self.check_ignore(d.request.as_deref(), &d.context).await {
+      if let Some(false) = self.check_ignore(d.request.as_deref(), &d.context).await? {
         Ok(BeforeResolveResult::Ignored)
       } else {
         Ok(BeforeResolveResult::Data(d))
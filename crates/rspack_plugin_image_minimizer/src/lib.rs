@@ -0,0 +1,220 @@
+#![feature(let_chains)]
+
+mod minify;
+
+use once_cell::sync::Lazy;
+use rayon::prelude::*;
+use regex::Regex;
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  Compilation, CompilationAsset, CompilationProcessAssets, Plugin,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_regex::RspackRegex;
+use rspack_util::try_any_sync;
+
+use self::minify::minify;
+
+static IMAGE_ASSET_REGEXP: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"\.(png|jpe?g|webp)(\?.*)?$").expect("Invalid RegExp"));
+
+#[derive(Debug)]
+pub enum ImageMinimizerRule {
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl ImageMinimizerRule {
+  pub fn try_match(&self, data: &str) -> Result<bool> {
+    match self {
+      Self::String(s) => Ok(data.starts_with(s)),
+      Self::Regexp(r) => Ok(r.test(data)),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub enum ImageMinimizerRules {
+  Single(ImageMinimizerRule),
+  Array(Vec<ImageMinimizerRule>),
+}
+
+impl FromIterator<ImageMinimizerRule> for ImageMinimizerRules {
+  fn from_iter<T: IntoIterator<Item = ImageMinimizerRule>>(iter: T) -> Self {
+    Self::Array(iter.into_iter().collect())
+  }
+}
+
+impl ImageMinimizerRules {
+  pub fn try_match(&self, data: &str) -> Result<bool> {
+    match self {
+      Self::Single(s) => s.try_match(data),
+      Self::Array(l) => try_any_sync(l, |i| i.try_match(data)),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageMinimizerPreset {
+  /// Only apply optimizations that keep every pixel untouched.
+  Lossless,
+  /// Allow re-encoding with `quality` if the encoder of the format supports it.
+  Lossy,
+}
+
+impl From<String> for ImageMinimizerPreset {
+  fn from(value: String) -> Self {
+    match value.as_str() {
+      "lossy" => Self::Lossy,
+      _ => Self::Lossless,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ImageMinimizerFormatOptions {
+  pub preset: ImageMinimizerPreset,
+  // Quality for lossy encoding, from 1 to 100.
+  pub quality: u8,
+}
+
+#[derive(Debug)]
+pub struct ImageMinimizerRspackPluginOptions {
+  // Include all assets that pass test assertion.
+  pub test: Option<ImageMinimizerRules>,
+  // Include all assets matching any of these conditions.
+  pub include: Option<ImageMinimizerRules>,
+  // Exclude all assets matching any of these conditions.
+  pub exclude: Option<ImageMinimizerRules>,
+  // Options for each image format, `None` means the format will not be minimized.
+  pub png: Option<ImageMinimizerFormatOptions>,
+  pub jpeg: Option<ImageMinimizerFormatOptions>,
+  pub webp: Option<ImageMinimizerFormatOptions>,
+}
+
+impl ImageMinimizerRspackPluginOptions {
+  fn match_object(&self, filename: &str) -> Result<bool> {
+    if let Some(condition) = &self.test {
+      if !condition.try_match(filename)? {
+        return Ok(false);
+      }
+    }
+    if let Some(condition) = &self.include {
+      if !condition.try_match(filename)? {
+        return Ok(false);
+      }
+    }
+    if let Some(condition) = &self.exclude {
+      if condition.try_match(filename)? {
+        return Ok(false);
+      }
+    }
+    Ok(true)
+  }
+
+  fn format_options(&self, format: ImageFormat) -> Option<&ImageMinimizerFormatOptions> {
+    match format {
+      ImageFormat::Png => self.png.as_ref(),
+      ImageFormat::Jpeg => self.jpeg.as_ref(),
+      ImageFormat::WebP => self.webp.as_ref(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ImageFormat {
+  Png,
+  Jpeg,
+  WebP,
+}
+
+impl ImageFormat {
+  fn from_filename(filename: &str) -> Option<Self> {
+    let captures = IMAGE_ASSET_REGEXP.captures(filename)?;
+    match captures.get(1)?.as_str() {
+      "png" => Some(Self::Png),
+      "jpg" | "jpeg" => Some(Self::Jpeg),
+      "webp" => Some(Self::WebP),
+      _ => None,
+    }
+  }
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ImageMinimizerRspackPlugin {
+  options: ImageMinimizerRspackPluginOptions,
+}
+
+impl ImageMinimizerRspackPlugin {
+  pub fn new(options: ImageMinimizerRspackPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationProcessAssets for ImageMinimizerRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_SIZE)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let options = &self.options;
+
+  // An image that fails to be minimized is kept as is, the other images are still minimized.
+  let diagnostics = compilation
+    .assets_mut()
+    .par_iter_mut()
+    .filter_map(|(filename, original)| {
+      minify_asset(options, filename, original)
+        .err()
+        .map(|e| Diagnostic::warn("ImageMinimizerRspackPlugin".into(), e.to_string()))
+    })
+    .collect::<Vec<_>>();
+  compilation.push_batch_diagnostic(diagnostics);
+
+  Ok(())
+}
+
+fn minify_asset(
+  options: &ImageMinimizerRspackPluginOptions,
+  filename: &str,
+  original: &mut CompilationAsset,
+) -> Result<()> {
+  let Some(format) = ImageFormat::from_filename(filename) else {
+    return Ok(());
+  };
+  let Some(format_options) = options.format_options(format) else {
+    return Ok(());
+  };
+  if original.get_info().minimized || !options.match_object(filename)? {
+    return Ok(());
+  }
+
+  if let Some(original_source) = original.get_source() {
+    let input = original_source.buffer();
+    // Keep the original asset if it can't be compressed any further.
+    if let Some(output) = minify(filename, format, format_options, &input)?
+      && output.len() < input.len()
+    {
+      original.set_source(Some(RawSource::from(output).boxed()));
+    }
+  }
+  original.get_info_mut().minimized = true;
+  Ok(())
+}
+
+impl Plugin for ImageMinimizerRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ImageMinimizerRspackPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: rspack_core::PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &mut rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
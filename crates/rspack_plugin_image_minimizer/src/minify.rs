@@ -0,0 +1,161 @@
+use std::io::Cursor;
+
+use color_quant::NeuQuant;
+use image::{
+  codecs::{
+    jpeg::JpegEncoder,
+    png::{CompressionType, FilterType, PngEncoder},
+    webp::WebPEncoder,
+  },
+  DynamicImage, ImageEncoder, ImageError,
+};
+use rspack_error::{error, Result};
+
+use crate::{ImageFormat, ImageMinimizerFormatOptions, ImageMinimizerPreset};
+
+/// Re-encode the image, returns `None` if the image should be kept as is.
+pub(crate) fn minify(
+  filename: &str,
+  format: ImageFormat,
+  options: &ImageMinimizerFormatOptions,
+  input: &[u8],
+) -> Result<Option<Vec<u8>>> {
+  let into_error = |e: ImageError| error!("Failed to minimize image {filename}: {e}");
+
+  match format {
+    ImageFormat::Png if options.preset == ImageMinimizerPreset::Lossy => {
+      let image =
+        image::load_from_memory_with_format(input, image::ImageFormat::Png).map_err(into_error)?;
+      quantize_png(&image, options.quality)
+        .map(Some)
+        .map_err(|e| error!("Failed to minimize image {filename}: {e}"))
+    }
+    ImageFormat::Png => {
+      let image =
+        image::load_from_memory_with_format(input, image::ImageFormat::Png).map_err(into_error)?;
+      let image = reduce_color_type(image);
+      let mut output = Vec::new();
+      PngEncoder::new_with_quality(&mut output, CompressionType::Best, FilterType::Adaptive)
+        .write_image(
+          image.as_bytes(),
+          image.width(),
+          image.height(),
+          image.color(),
+        )
+        .map_err(into_error)?;
+      Ok(Some(output))
+    }
+    // The WebP encoder only supports lossless encoding, so the preset is always lossless.
+    ImageFormat::WebP => {
+      let image =
+        image::load_from_memory_with_format(input, image::ImageFormat::WebP).map_err(into_error)?;
+      let image = if image.color().has_alpha() {
+        DynamicImage::ImageRgba8(image.into_rgba8())
+      } else {
+        DynamicImage::ImageRgb8(image.into_rgb8())
+      };
+      let mut output = Vec::new();
+      WebPEncoder::new_lossless(&mut output)
+        .write_image(
+          image.as_bytes(),
+          image.width(),
+          image.height(),
+          image.color(),
+        )
+        .map_err(into_error)?;
+      Ok(Some(output))
+    }
+    // Re-encoding a jpeg is always lossy.
+    ImageFormat::Jpeg if options.preset == ImageMinimizerPreset::Lossy => {
+      let image =
+        image::load_from_memory_with_format(input, image::ImageFormat::Jpeg).map_err(into_error)?;
+      let image = if image.color().has_alpha() {
+        DynamicImage::ImageRgb8(image.into_rgb8())
+      } else {
+        image
+      };
+      let mut output = Cursor::new(Vec::new());
+      JpegEncoder::new_with_quality(&mut output, options.quality.clamp(1, 100))
+        .write_image(
+          image.as_bytes(),
+          image.width(),
+          image.height(),
+          image.color(),
+        )
+        .map_err(into_error)?;
+      Ok(Some(output.into_inner()))
+    }
+    ImageFormat::Jpeg => Ok(None),
+  }
+}
+
+/// Reduce the image to a palette of at most 256 colors, the number of colors scales with
+/// `quality`, and encode it as an indexed png.
+fn quantize_png(
+  image: &DynamicImage,
+  quality: u8,
+) -> std::result::Result<Vec<u8>, png::EncodingError> {
+  let image = image.to_rgba8();
+  let colors = (usize::from(quality.clamp(1, 100)) * 256 / 100).clamp(2, 256);
+  let quantizer = NeuQuant::new(10, colors, image.as_raw());
+  let indices = image
+    .pixels()
+    .map(|pixel| quantizer.index_of(&pixel.0) as u8)
+    .collect::<Vec<_>>();
+
+  let color_map = quantizer.color_map_rgba();
+  let palette = color_map
+    .chunks_exact(4)
+    .flat_map(|rgba| [rgba[0], rgba[1], rgba[2]])
+    .collect::<Vec<_>>();
+  let transparency = color_map
+    .chunks_exact(4)
+    .map(|rgba| rgba[3])
+    .collect::<Vec<_>>();
+
+  let mut output = Vec::new();
+  let mut encoder = png::Encoder::new(&mut output, image.width(), image.height());
+  encoder.set_color(png::ColorType::Indexed);
+  encoder.set_depth(png::BitDepth::Eight);
+  encoder.set_compression(png::Compression::Best);
+  encoder.set_palette(palette);
+  if transparency.iter().any(|alpha| *alpha != u8::MAX) {
+    encoder.set_trns(transparency);
+  }
+  let mut writer = encoder.write_header()?;
+  writer.write_image_data(&indices)?;
+  writer.finish()?;
+  Ok(output)
+}
+
+/// Drop the alpha channel of fully opaque images and convert gray images to luma,
+/// which keeps the pixels untouched.
+fn reduce_color_type(image: DynamicImage) -> DynamicImage {
+  match image {
+    DynamicImage::ImageRgba8(ref buffer) => {
+      let opaque = buffer.pixels().all(|p| p[3] == u8::MAX);
+      let gray = buffer.pixels().all(|p| p[0] == p[1] && p[1] == p[2]);
+      match (opaque, gray) {
+        (true, true) => DynamicImage::ImageLuma8(image.into_luma8()),
+        (true, false) => DynamicImage::ImageRgb8(image.into_rgb8()),
+        (false, true) => DynamicImage::ImageLumaA8(image.into_luma_alpha8()),
+        (false, false) => image,
+      }
+    }
+    DynamicImage::ImageRgb8(ref buffer) => {
+      if buffer.pixels().all(|p| p[0] == p[1] && p[1] == p[2]) {
+        DynamicImage::ImageLuma8(image.into_luma8())
+      } else {
+        image
+      }
+    }
+    DynamicImage::ImageLumaA8(ref buffer) => {
+      if buffer.pixels().all(|p| p[1] == u8::MAX) {
+        DynamicImage::ImageLuma8(image.into_luma8())
+      } else {
+        image
+      }
+    }
+    image => image,
+  }
+}
@@ -1,5 +1,8 @@
 use itertools::Itertools;
-use rspack_core::{module_raw, NormalInitFragment, UsedName};
+use rspack_core::{
+  create_exports_object_referenced, module_raw, ExtendedReferencedExport, ModuleGraph,
+  NormalInitFragment, RuntimeSpec, UsedName,
+};
 use rspack_core::{AsContextDependency, Dependency, InitFragmentKey, InitFragmentStage};
 use rspack_core::{DependencyCategory, DependencyId, DependencyTemplate};
 use rspack_core::{DependencyType, ErrorSpan};
@@ -63,6 +66,18 @@ impl ModuleDependency for ProvideDependency {
   fn set_request(&mut self, request: String) {
     self.request = request.into();
   }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    if self.ids.is_empty() {
+      create_exports_object_referenced()
+    } else {
+      vec![ExtendedReferencedExport::Array(self.ids.clone())]
+    }
+  }
 }
 
 impl DependencyTemplate for ProvideDependency {
@@ -114,13 +129,16 @@ impl DependencyTemplate for ProvideDependency {
 }
 
 fn path_to_string(path: Option<&UsedName>) -> String {
+  let part_to_string = |part: &Atom| {
+    format!(
+      "[{}]",
+      serde_json::to_string(part.as_str()).expect("should serialize the property")
+    )
+  };
   match path {
     Some(p) => match p {
-      UsedName::Str(str) => format!("[\"{}\"]", str.as_str()),
-      UsedName::Vec(vec) if !vec.is_empty() => vec
-        .iter()
-        .map(|part| format!("[\"{}\"]", part.as_str()))
-        .join(""),
+      UsedName::Str(str) => part_to_string(str),
+      UsedName::Vec(vec) if !vec.is_empty() => vec.iter().map(part_to_string).join(""),
       _ => String::new(),
     },
     None => String::new(),
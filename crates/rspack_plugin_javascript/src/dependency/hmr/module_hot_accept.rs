@@ -63,6 +63,10 @@ impl ModuleDependency for ModuleHotAcceptDependency {
   fn weak(&self) -> bool {
     true
   }
+
+  fn get_optional(&self) -> bool {
+    true
+  }
 }
 
 impl DependencyTemplate for ModuleHotAcceptDependency {
@@ -63,6 +63,10 @@ impl ModuleDependency for ModuleHotDeclineDependency {
   fn weak(&self) -> bool {
     true
   }
+
+  fn get_optional(&self) -> bool {
+    true
+  }
 }
 
 impl DependencyTemplate for ModuleHotDeclineDependency {
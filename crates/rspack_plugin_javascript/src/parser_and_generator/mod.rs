@@ -9,10 +9,10 @@ use rspack_core::tree_shaking::analyzer::OptimizeAnalyzer;
 use rspack_core::tree_shaking::js_module::JsModule;
 use rspack_core::tree_shaking::visitor::OptimizeAnalyzeResult;
 use rspack_core::{
-  render_init_fragments, AsyncDependenciesBlockIdentifier, BuildMetaExportsType, ChunkGraph,
-  Compilation, DependenciesBlock, DependencyId, GenerateContext, Module, ModuleGraph, ParseContext,
-  ParseResult, ParserAndGenerator, SideEffectsBailoutItem, SourceType, SpanExt, TemplateContext,
-  TemplateReplaceSource,
+  contextify_source_map, render_init_fragments, source_name, AsyncDependenciesBlockIdentifier,
+  BuildMetaExportsType, ChunkGraph, Compilation, DependenciesBlock, DependencyId, GenerateContext,
+  Module, ModuleGraph, ParseContext, ParseResult, ParserAndGenerator, SideEffectsBailoutItem,
+  SourceType, SpanExt, TemplateContext, TemplateReplaceSource,
 };
 use rspack_error::miette::Diagnostic;
 use rspack_error::{DiagnosticExt, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
@@ -72,6 +72,21 @@ impl JavaScriptParserAndGenerator {
 
 static SOURCE_TYPES: &[SourceType; 1] = &[SourceType::JavaScript];
 
+/// A conservative text search, a module is built again more often than necessary at most
+fn is_runtime_define_key_used(source: &str, key: &str) -> bool {
+  let is_ident_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+  source.match_indices(key).any(|(index, _)| {
+    !source[..index]
+      .chars()
+      .next_back()
+      .is_some_and(|c| is_ident_char(c) || c == '.')
+      && !source[index + key.len()..]
+        .chars()
+        .next()
+        .is_some_and(is_ident_char)
+  })
+}
+
 impl ParserAndGenerator for JavaScriptParserAndGenerator {
   fn source_types(&self) -> &[SourceType] {
     SOURCE_TYPES
@@ -109,16 +124,16 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
     let enable_source_map = module_source_map_kind.enabled();
     let original_map = source.map(&MapOptions::new(use_source_map));
     let source = source.source();
+    let name = source_name(
+      compiler_options,
+      resource_data.resource_path.to_string_lossy().to_string(),
+    );
 
     let result_with_diagnostics =
       |source: String, diagnostics: Vec<Box<dyn Diagnostic + Send + Sync>>| {
         Ok(
           ParseResult {
-            source: create_source(
-              source,
-              resource_data.resource_path.to_string_lossy().to_string(),
-              enable_source_map,
-            ),
+            source: create_source(source, name.clone(), enable_source_map),
             dependencies: vec![],
             blocks: vec![],
             presentational_dependencies: vec![],
@@ -151,6 +166,26 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         .0
       };
 
+    // modules using a runtime value are built again when the value or its file dependencies
+    // are changed
+    for (key, value) in compiler_options
+      .builtins
+      .runtime_define
+      .read()
+      .expect("should lock")
+      .iter()
+    {
+      if is_runtime_define_key_used(&source, key) {
+        build_info
+          .file_dependencies
+          .extend(value.file_dependencies.iter().cloned());
+        build_info.cacheable &= value.cacheable;
+        build_info
+          .value_dependencies
+          .insert(key.clone(), value.code.clone());
+      }
+    }
+
     run_before_pass(&mut ast, compiler_options)?;
 
     let output: crate::TransformOutput = crate::ast::stringify(
@@ -277,17 +312,20 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       };
 
     let source = if let Some(map) = output.map {
+      let mut source_map =
+        SourceMap::from_json(&map).expect("should be able to generate source-map");
+      contextify_source_map(compiler_options, &mut source_map);
       SourceMapSource::new(SourceMapSourceOptions {
         value: output.code,
-        name: resource_data.resource_path.to_string_lossy().to_string(),
-        source_map: SourceMap::from_json(&map).expect("should be able to generate source-map"),
+        name,
+        source_map,
         inner_source_map: use_source_map.then_some(original_map).flatten(),
         remove_original_source: true,
         ..Default::default()
       })
       .boxed()
     } else if enable_source_map {
-      OriginalSource::new(output.code, resource_data.resource_path.to_string_lossy()).boxed()
+      OriginalSource::new(output.code, name).boxed()
     } else {
       RawSource::from(output.code).boxed()
     };
@@ -229,7 +229,8 @@ impl CommonJsImportsParserPlugin {
         parser.source_file,
         ident.span().into(),
       )
-      .with_severity(Severity::Warn),
+      .with_severity(Severity::Warn)
+      .with_code("CRITICAL_DEPENDENCY"),
     ));
     parser.dependencies.push(Box::new(dep));
     Some(true)
@@ -11,12 +11,15 @@ use crate::visitors::{create_traceable_error, JavascriptParser};
 
 impl<'parser> JavascriptParser<'parser> {
   fn throw_top_level_await_error(&mut self, msg: String, span: Span) {
-    self.errors.push(Box::new(create_traceable_error(
-      "JavaScript parsing error".into(),
-      msg,
-      self.source_file,
-      span.into(),
-    )));
+    self.errors.push(Box::new(
+      create_traceable_error(
+        "JavaScript parsing error".into(),
+        msg,
+        self.source_file,
+        span.into(),
+      )
+      .with_code("JAVASCRIPT_PARSE_ERROR"),
+    ));
   }
 
   fn handle_top_level_await(&mut self, allow_top_level: bool, span: Span) {
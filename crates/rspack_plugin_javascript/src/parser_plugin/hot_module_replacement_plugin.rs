@@ -5,7 +5,7 @@ pub mod hot_module_replacement {
 
 use rspack_core::{BoxDependency, ErrorSpan, SpanExt};
 use swc_core::common::{Span, Spanned};
-use swc_core::ecma::ast::{CallExpr, Expr, Lit};
+use swc_core::ecma::ast::CallExpr;
 use swc_core::ecma::atoms::Atom;
 
 use crate::dependency::{
@@ -18,41 +18,43 @@ use crate::visitors::{expr_name, JavascriptParser};
 
 type CreateDependency = fn(u32, u32, Atom, Option<ErrorSpan>) -> BoxDependency;
 
-fn extract_deps(call_expr: &CallExpr, create_dependency: CreateDependency) -> Vec<BoxDependency> {
-  let mut dependencies: Vec<BoxDependency> = vec![];
-
-  if let Some(first_arg) = call_expr.args.first() {
-    match &*first_arg.expr {
-      Expr::Lit(Lit::Str(s)) => {
-        dependencies.push(create_dependency(
-          s.span.real_lo(),
-          s.span.real_hi(),
-          s.value.clone(),
-          Some(s.span.into()),
-        ));
-      }
-      Expr::Array(array_lit) => {
-        array_lit.elems.iter().for_each(|e| {
-          if let Some(expr) = e {
-            if let Expr::Lit(Lit::Str(s)) = &*expr.expr {
-              dependencies.push(create_dependency(
-                s.span.real_lo(),
-                s.span.real_hi(),
-                s.value.clone(),
-                Some(s.span.into()),
-              ));
-            }
-          }
-        });
-      }
-      _ => {}
-    }
+impl JavascriptParser<'_> {
+  /// Extracts the requests of `accept` and `decline`, the first argument
+  /// should be evaluated to a string or an array of strings.
+  fn extract_hmr_deps(
+    &mut self,
+    call_expr: &CallExpr,
+    create_dependency: CreateDependency,
+  ) -> Vec<BoxDependency> {
+    let Some(first_arg) = call_expr.args.first() else {
+      return vec![];
+    };
+    let arg = self.evaluate_expression(&first_arg.expr);
+    let params = if arg.is_string() {
+      vec![&arg]
+    } else if arg.is_array() {
+      arg
+        .items()
+        .iter()
+        .filter(|param| param.is_string())
+        .collect()
+    } else {
+      vec![]
+    };
+    params
+      .into_iter()
+      .map(|param| {
+        let (start, end) = param.range();
+        create_dependency(
+          start,
+          end - 1,
+          param.string().as_str().into(),
+          Some(ErrorSpan::new(start, end - 1)),
+        )
+      })
+      .collect()
   }
 
-  dependencies
-}
-
-impl JavascriptParser<'_> {
   fn create_hmr_expression_handler(&mut self, span: Span) {
     self.build_info.module_concatenation_bailout = Some(String::from("Hot Module Replacement"));
     self
@@ -77,7 +79,7 @@ impl JavascriptParser<'_> {
         call_expr.callee.span().real_hi(),
         Some("hot.accept"),
       )));
-    let dependencies = extract_deps(call_expr, create_dependency);
+    let dependencies = self.extract_hmr_deps(call_expr, create_dependency);
     if self.build_meta.esm && !call_expr.args.is_empty() {
       let dependency_ids = dependencies.iter().map(|dep| *dep.id()).collect::<Vec<_>>();
       if let Some(callback_arg) = call_expr.args.get(1) {
@@ -118,8 +120,10 @@ impl JavascriptParser<'_> {
         call_expr.callee.span().real_hi(),
         Some("hot.decline"),
       )));
-    let dependencies = extract_deps(call_expr, create_dependency);
-    self.dependencies.extend(dependencies);
+    if call_expr.args.len() == 1 {
+      let dependencies = self.extract_hmr_deps(call_expr, create_dependency);
+      self.dependencies.extend(dependencies);
+    }
     Some(true)
   }
 }
@@ -1,4 +1,8 @@
-use rspack_core::{ConstDependency, SpanExt};
+use itertools::Itertools;
+use rspack_core::{
+  ChunkLoading, ChunkLoadingType, ConstDependency, JavascriptParserImportMetaEnv,
+  JavascriptParserImportMetaUrl, SpanExt,
+};
 use rspack_error::miette::Severity;
 use swc_core::common::{Span, Spanned};
 use url::Url;
@@ -43,6 +47,21 @@ impl JavascriptParserPlugin for ImportMetaPlugin {
           None,
         )));
       Some(true)
+    } else if for_name == expr_name::IMPORT_META_ENV
+      && matches!(
+        parser.javascript_options.import_meta_env,
+        JavascriptParserImportMetaEnv::Define
+      )
+    {
+      parser
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          unary_expr.span().real_lo(),
+          unary_expr.span().real_hi(),
+          "'object'".into(),
+          None,
+        )));
+      Some(true)
     } else {
       None
     }
@@ -62,7 +81,7 @@ impl JavascriptParserPlugin for ImportMetaPlugin {
       "Accessing import.meta directly is unsupported (only property access or destructuring is supported)".into(),
       parser.source_file,
       span.into()
-    ).with_severity(Severity::Warning)));
+    ).with_severity(Severity::Warning).with_code("CRITICAL_DEPENDENCY")));
       parser
         .presentational_dependencies
         .push(Box::new(ConstDependency::new(
@@ -85,13 +104,66 @@ impl JavascriptParserPlugin for ImportMetaPlugin {
   ) -> Option<bool> {
     if for_name == expr_name::IMPORT_META_URL {
       // import.meta.url
-      let url = Url::from_file_path(&parser.resource_data.resource).expect("should be a path");
+      let content = match parser.javascript_options.import_meta_url {
+        JavascriptParserImportMetaUrl::Resolved => {
+          let url = Url::from_file_path(&parser.resource_data.resource).expect("should be a path");
+          format!("'{url}'")
+        }
+        JavascriptParserImportMetaUrl::Runtime => {
+          if parser.compiler_options.output.module {
+            expr_name::IMPORT_META_URL.to_string()
+          } else if matches!(
+            parser.compiler_options.output.chunk_loading,
+            ChunkLoading::Enable(ChunkLoadingType::Require | ChunkLoadingType::AsyncNode)
+          ) {
+            "require('url').pathToFileURL(__filename).href".to_string()
+          } else {
+            // the current script is only available while the script is evaluated synchronously,
+            // fall back to the url of the page or the worker
+            "(typeof document !== 'undefined' && document.currentScript && document.currentScript.src || self.location.href)".to_string()
+          }
+        }
+      };
+      parser
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          member_expr.span().real_lo(),
+          member_expr.span().real_hi(),
+          content.into(),
+          None,
+        )));
+      Some(true)
+    } else if for_name == expr_name::IMPORT_META_ENV
+      && matches!(
+        parser.javascript_options.import_meta_env,
+        JavascriptParserImportMetaEnv::Define
+      )
+    {
+      // import.meta.env, the defined `import.meta.env.*` are already replaced
+      // by the define transform, so only the env object itself is left here
+      let prefix = format!("{}.", expr_name::IMPORT_META_ENV);
+      let properties = parser
+        .compiler_options
+        .builtins
+        .define
+        .iter()
+        .filter_map(|(key, value)| {
+          let name = key.strip_prefix(&prefix)?;
+          (!name.contains('.')).then(|| {
+            format!(
+              "{}: {value}",
+              serde_json::to_string(name).expect("should serialize the key")
+            )
+          })
+        })
+        .sorted()
+        .join(", ");
       parser
         .presentational_dependencies
         .push(Box::new(ConstDependency::new(
           member_expr.span().real_lo(),
           member_expr.span().real_hi(),
-          format!("'{url}'").into(),
+          format!("({{{properties}}})").into(),
           None,
         )));
       Some(true)
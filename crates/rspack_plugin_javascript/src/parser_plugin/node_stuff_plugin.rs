@@ -1,19 +1,64 @@
+use std::path::Path;
+
 use rspack_core::{get_context, ConstDependency, RuntimeGlobals, SpanExt};
+use rspack_error::miette::Severity;
 use sugar_path::SugarPath;
+use swc_core::common::Span;
 
 use super::JavascriptParserPlugin;
 use crate::utils::eval;
+use crate::visitors::{create_traceable_error, JavascriptParser};
 
 const DIR_NAME: &str = "__dirname";
 const FILE_NAME: &str = "__filename";
 const GLOBAL: &str = "global";
 
+// Port from https://github.com/webpack/webpack/blob/main/lib/NodeStuffPlugin.js
 pub struct NodeStuffPlugin;
 
+impl NodeStuffPlugin {
+  fn set_constant(
+    parser: &mut JavascriptParser,
+    span: Span,
+    content: String,
+    runtime_requirement: Option<RuntimeGlobals>,
+  ) {
+    parser
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        span.real_lo(),
+        span.real_hi(),
+        content.into(),
+        runtime_requirement,
+      )));
+  }
+
+  fn add_node_stuff_in_web_warning(
+    parser: &mut JavascriptParser,
+    span: Span,
+    expression: &str,
+    description: &str,
+  ) {
+    parser.warning_diagnostics.push(Box::new(
+      create_traceable_error(
+        "NodeStuffInWebError".into(),
+        format!(
+          "{} has been used, it will be undefined in next major version.\n{description}",
+          serde_json::to_string(expression).expect("should stringify expression")
+        ),
+        parser.source_file,
+        span.into(),
+      )
+      .with_severity(Severity::Warning)
+      .with_code("NODE_STUFF_IN_WEB"),
+    ));
+  }
+}
+
 impl JavascriptParserPlugin for NodeStuffPlugin {
   fn identifier(
     &self,
-    parser: &mut crate::visitors::JavascriptParser,
+    parser: &mut JavascriptParser,
     ident: &swc_core::ecma::ast::Ident,
     _for_name: &str,
   ) -> Option<bool> {
@@ -26,67 +71,69 @@ impl JavascriptParserPlugin for NodeStuffPlugin {
     }
     if str == DIR_NAME {
       let dirname = match node_option.dirname.as_str() {
-        "mock" => Some("/".to_string()),
-        "warn-mock" => Some("/".to_string()),
-        "true" => Some(
-          parser
-            .resource_data
-            .resource_path
-            .parent()
-            .expect("TODO:")
-            .relative(&parser.compiler_options.context)
-            .to_string_lossy()
-            .to_string(),
-        ),
-        _ => None,
+        "mock" | "warn-mock" => "/".to_string(),
+        "true" => Path::new(get_context(parser.resource_data).as_str())
+          .relative(&parser.compiler_options.context)
+          .to_string_lossy()
+          .to_string(),
+        _ => return None,
       };
-      if let Some(dirname) = dirname {
-        parser
-          .presentational_dependencies
-          .push(Box::new(ConstDependency::new(
-            ident.span.real_lo(),
-            ident.span.real_hi(),
-            format!("'{dirname}'").into(),
-            None,
-          )));
-        return Some(true);
+      Self::set_constant(
+        parser,
+        ident.span,
+        serde_json::to_string(&dirname).expect("should stringify dirname"),
+        None,
+      );
+      if node_option.dirname == "warn-mock" {
+        Self::add_node_stuff_in_web_warning(
+          parser,
+          ident.span,
+          DIR_NAME,
+          "__dirname is a Node.js feature and isn't available in browsers.",
+        );
       }
+      return Some(true);
     } else if str == FILE_NAME {
       let filename = match node_option.filename.as_str() {
-        "mock" => Some("/index.js".to_string()),
-        "warn-mock" => Some("/index.js".to_string()),
-        "true" => Some(
-          parser
-            .resource_data
-            .resource_path
-            .relative(&parser.compiler_options.context)
-            .to_string_lossy()
-            .to_string(),
-        ),
-        _ => None,
+        "mock" | "warn-mock" => "/index.js".to_string(),
+        "true" => parser
+          .resource_data
+          .resource_path
+          .relative(&parser.compiler_options.context)
+          .to_string_lossy()
+          .to_string(),
+        _ => return None,
       };
-      if let Some(filename) = filename {
-        parser
-          .presentational_dependencies
-          .push(Box::new(ConstDependency::new(
-            ident.span.real_lo(),
-            ident.span.real_hi(),
-            serde_json::to_string(&filename)
-              .expect("should render filename")
-              .into(),
-            None,
-          )));
-        return Some(true);
+      Self::set_constant(
+        parser,
+        ident.span,
+        serde_json::to_string(&filename).expect("should stringify filename"),
+        None,
+      );
+      if node_option.filename == "warn-mock" {
+        Self::add_node_stuff_in_web_warning(
+          parser,
+          ident.span,
+          FILE_NAME,
+          "__filename is a Node.js feature and isn't available in browsers.",
+        );
       }
+      return Some(true);
     } else if str == GLOBAL && matches!(node_option.global.as_str(), "true" | "warn") {
-      parser
-        .presentational_dependencies
-        .push(Box::new(ConstDependency::new(
-          ident.span.real_lo(),
-          ident.span.real_hi(),
-          RuntimeGlobals::GLOBAL.name().into(),
-          Some(RuntimeGlobals::GLOBAL),
-        )));
+      Self::set_constant(
+        parser,
+        ident.span,
+        RuntimeGlobals::GLOBAL.name().into(),
+        Some(RuntimeGlobals::GLOBAL),
+      );
+      if node_option.global == "warn" {
+        Self::add_node_stuff_in_web_warning(
+          parser,
+          ident.span,
+          GLOBAL,
+          "The global namespace object is a Node.js feature and isn't available in browsers.",
+        );
+      }
       return Some(true);
     }
     None
@@ -94,17 +141,34 @@ impl JavascriptParserPlugin for NodeStuffPlugin {
 
   fn evaluate_identifier(
     &self,
-    parser: &mut crate::visitors::JavascriptParser,
+    parser: &mut JavascriptParser,
     ident: &str,
     start: u32,
     end: u32,
-  ) -> Option<crate::utils::eval::BasicEvaluatedExpression> {
-    if ident == DIR_NAME {
+  ) -> Option<eval::BasicEvaluatedExpression> {
+    let Some(node_option) = parser.compiler_options.node.as_ref() else {
+      unreachable!("ensure only invoke `NodeStuffPlugin` when node options is enabled");
+    };
+    if !parser.is_unresolved_ident(ident) {
+      return None;
+    }
+    // evaluated to the real paths of the module even in the mock modes, same as webpack
+    if ident == DIR_NAME && node_option.dirname != "false" {
       Some(eval::evaluate_to_string(
         get_context(parser.resource_data).as_str().to_string(),
         start,
         end,
       ))
+    } else if ident == FILE_NAME && node_option.filename != "false" {
+      Some(eval::evaluate_to_string(
+        parser
+          .resource_data
+          .resource_path
+          .to_string_lossy()
+          .to_string(),
+        start,
+        end,
+      ))
     } else {
       None
     }
@@ -11,7 +11,10 @@ const MODULE_DOT: &str = r#"_dot_"#;
 fn dep(parser: &JavascriptParser, name: &str, start: u32, end: u32) -> Option<ProvideDependency> {
   if let Some(requests) = parser.compiler_options.builtins.provide.get(name) {
     let name_identifier = if name.contains(SOURCE_DOT) {
-      format!("__webpack_provide_{}", name.replace(SOURCE_DOT, MODULE_DOT))
+      format!(
+        "__webpack_provided_{}",
+        name.replace(SOURCE_DOT, MODULE_DOT)
+      )
     } else {
       name.to_string()
     };
@@ -1,8 +1,8 @@
 use std::hash::Hash;
 
 use rspack_core::{
-  AsyncDependenciesBlock, ConstDependency, DependencyLocation, EntryOptions, ErrorSpan,
-  GroupOptions, SpanExt,
+  hashable_identifier, AsyncDependenciesBlock, ConstDependency, DependencyLocation, EntryOptions,
+  ErrorSpan, GroupOptions, SpanExt,
 };
 use rspack_hash::RspackHash;
 use swc_core::common::{Span, Spanned};
@@ -67,7 +67,7 @@ impl JavascriptParser<'_> {
   ) {
     let output_options = &self.compiler_options.output;
     let mut hasher = RspackHash::from(output_options);
-    self.module_identifier.hash(&mut hasher);
+    hashable_identifier(self.compiler_options, self.module_identifier).hash(&mut hasher);
     self.worker_index.hash(&mut hasher);
     self.worker_index += 1;
     let digest = hasher.digest(&output_options.hash_digest);
@@ -189,9 +189,11 @@ fn content_hash(
     .iter()
     .map(|mgm| {
       (
-        compilation
-          .code_generation_results
-          .get_hash(&mgm.identifier(), Some(&chunk.runtime)),
+        compilation.code_generation_results.get_source_type_hash(
+          &mgm.identifier(),
+          Some(&chunk.runtime),
+          &SourceType::JavaScript,
+        ),
         compilation.chunk_graph.get_module_id(mgm.identifier()),
       )
     })
@@ -20,15 +20,20 @@ use once_cell::sync::Lazy;
 use rspack_core::rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rspack_core::{
   render_init_fragments, ChunkRenderContext, ChunkUkey, Compilation, CompilationId, RuntimeGlobals,
+  SourceType,
 };
 use rspack_error::Result;
 use rspack_hash::RspackHash;
 use rspack_hook::plugin;
+use rspack_identifier::IdentifierLinkedSet;
 use rspack_util::diff_mode::is_diff_mode;
 use rspack_util::fx_hash::{BuildFxHasher, FxDashMap};
 pub use side_effects_flag_plugin::*;
 
-use crate::runtime::{render_chunk_modules, render_iife, render_runtime_modules, stringify_array};
+use crate::runtime::{
+  render_chunk_modules, render_iife, render_inlined_modules, render_runtime_modules,
+  stringify_array,
+};
 
 static COMPILATION_DRIVES_MAP: Lazy<FxDashMap<CompilationId, JavascriptModulesPluginPluginDrive>> =
   Lazy::new(Default::default);
@@ -159,21 +164,92 @@ impl JsPlugin {
     sources.boxed()
   }
 
+  /// Returns the entry modules of the runtime chunk that can be executed inline
+  /// in the startup code, it's empty when any of them can't be inlined.
+  pub fn get_inlined_entry_modules(
+    &self,
+    chunk_ukey: &ChunkUkey,
+    compilation: &Compilation,
+  ) -> IdentifierLinkedSet {
+    if !compilation
+      .options
+      .experiments
+      .rspack_future
+      .inline_entry_modules
+    {
+      return IdentifierLinkedSet::default();
+    }
+    let runtime_requirements = compilation
+      .chunk_graph
+      .get_chunk_runtime_requirements(chunk_ukey);
+    // the entry modules are executed by the startup function or other runtime
+    // modules, or their execution is intercepted
+    if runtime_requirements.intersects(
+      RuntimeGlobals::STARTUP
+        | RuntimeGlobals::STARTUP_NO_DEFAULT
+        | RuntimeGlobals::INTERCEPT_MODULE_EXECUTION
+        | RuntimeGlobals::ENTRY_MODULE_ID,
+    ) {
+      return IdentifierLinkedSet::default();
+    }
+    let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+    let module_graph = compilation.get_module_graph();
+    let mut inlined_modules = IdentifierLinkedSet::default();
+    for (module, entry) in compilation
+      .chunk_graph
+      .get_chunk_entry_modules_with_chunk_group_iterable(chunk_ukey)
+    {
+      let chunk_group = compilation.chunk_group_by_ukey.expect_get(entry);
+      // the execution is delayed until the other chunks are loaded
+      if chunk_group.chunks.iter().any(|c| c != chunk_ukey) {
+        return IdentifierLinkedSet::default();
+      }
+      // the entry module is required by other modules
+      if module_graph
+        .get_incoming_connections(module)
+        .iter()
+        .any(|connection| connection.original_module_identifier.is_some())
+      {
+        return IdentifierLinkedSet::default();
+      }
+      let has_javascript_source = compilation
+        .code_generation_results
+        .get(module, Some(&chunk.runtime))
+        .get(&SourceType::JavaScript)
+        .is_some();
+      let uses_module = compilation
+        .chunk_graph
+        .get_module_runtime_requirements(*module, &chunk.runtime)
+        .is_some_and(|r| r.intersects(RuntimeGlobals::MODULE | RuntimeGlobals::THIS_AS_EXPORTS));
+      if !has_javascript_source
+        || uses_module
+        || !(compilation.options.is_new_tree_shaking()
+          || compilation.include_module_ids.contains(module))
+      {
+        return IdentifierLinkedSet::default();
+      }
+      inlined_modules.insert(*module);
+    }
+    inlined_modules
+  }
+
   pub fn render_bootstrap(
     &self,
     chunk_ukey: &ChunkUkey,
     compilation: &Compilation,
+    inlined_source: Option<BoxSource>,
   ) -> (BoxSource, BoxSource) {
     let runtime_requirements = compilation
       .chunk_graph
       .get_chunk_runtime_requirements(chunk_ukey);
     let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
     let module_factories = runtime_requirements.contains(RuntimeGlobals::MODULE_FACTORIES);
-    // let require_function = runtime_requirements.contains(RuntimeGlobals::REQUIRE);
+    let require_function = runtime_requirements.contains(RuntimeGlobals::REQUIRE);
     let intercept_module_execution =
       runtime_requirements.contains(RuntimeGlobals::INTERCEPT_MODULE_EXECUTION);
-    // let module_used = runtime_requirements.contains(RuntimeGlobals::MODULE);
-    // let use_require = require_function || intercept_module_execution || module_used;
+    let module_used = runtime_requirements.contains(RuntimeGlobals::MODULE);
+    let require_scope_used = runtime_requirements.contains(RuntimeGlobals::REQUIRE_SCOPE);
+    let use_require = require_function || intercept_module_execution || module_used;
     let mut header = ConcatSource::default();
 
     if is_diff_mode() {
@@ -182,14 +258,24 @@ impl JsPlugin {
       ));
     }
 
-    header.add(RawSource::from(
-      "// The module cache\n var __webpack_module_cache__ = {};\n",
-    ));
-    header.add(RawSource::from(
-      "function __webpack_require__(moduleId) {\n",
-    ));
-    header.add(self.render_require(chunk_ukey, compilation));
-    header.add(RawSource::from("\n}\n"));
+    if use_require || runtime_requirements.contains(RuntimeGlobals::MODULE_CACHE) {
+      header.add(RawSource::from(
+        "// The module cache\n var __webpack_module_cache__ = {};\n",
+      ));
+    }
+
+    if use_require {
+      header.add(RawSource::from(
+        "function __webpack_require__(moduleId) {\n",
+      ));
+      header.add(self.render_require(chunk_ukey, compilation));
+      header.add(RawSource::from("\n}\n"));
+    } else if require_scope_used {
+      // the runtime modules are attached to the require scope
+      header.add(RawSource::from(
+        "// The require scope\n var __webpack_require__ = {};\n",
+      ));
+    }
 
     if module_factories || runtime_requirements.contains(RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY)
     {
@@ -210,6 +296,24 @@ impl JsPlugin {
       ));
     }
 
+    if let Some(inlined_source) = inlined_source {
+      let mut startup = ConcatSource::default();
+      startup.add(RawSource::from("var __webpack_exports__ = {};\n"));
+      startup.add(inlined_source);
+      if runtime_requirements.contains(RuntimeGlobals::ON_CHUNKS_LOADED) {
+        startup.add(RawSource::from(format!(
+          "__webpack_exports__ = {}(__webpack_exports__);\n",
+          RuntimeGlobals::ON_CHUNKS_LOADED
+        )));
+      }
+      if is_diff_mode() {
+        header.add(RawSource::from(
+          "\n/************************************************************************/\n",
+        ));
+      }
+      return (header.boxed(), startup.boxed());
+    }
+
     let mut startup = vec![];
 
     if !runtime_requirements.contains(RuntimeGlobals::STARTUP_NO_DEFAULT) {
@@ -252,9 +356,7 @@ impl JsPlugin {
               RuntimeGlobals::ON_CHUNKS_LOADED,
               stringify_array(&chunk_ids)
             ));
-          }
-          /* if use_require */
-          else {
+          } else if use_require {
             startup.push(format!(
               "{}__webpack_require__({module_id_expr});",
               if i + 1 == entries.len() {
@@ -263,10 +365,31 @@ impl JsPlugin {
                 ""
               },
             ))
+          } else {
+            // no module is required, so call the entry module directly
+            let is_last = i + 1 == entries.len();
+            if is_last {
+              startup.push("var __webpack_exports__ = {};".to_string());
+            }
+            let exports = if is_last { "__webpack_exports__" } else { "{}" };
+            let entry_runtime_requirements = compilation
+              .chunk_graph
+              .get_module_runtime_requirements(*module, &chunk.runtime);
+            if require_scope_used {
+              startup.push(format!(
+                "__webpack_modules__[{module_id_expr}](0, {exports}, {});",
+                RuntimeGlobals::REQUIRE
+              ));
+            } else if entry_runtime_requirements
+              .is_some_and(|requirements| requirements.contains(RuntimeGlobals::EXPORTS))
+            {
+              startup.push(format!(
+                "__webpack_modules__[{module_id_expr}](0, {exports});"
+              ));
+            } else {
+              startup.push(format!("__webpack_modules__[{module_id_expr}]();"));
+            }
           }
-          // else {
-          //   startup.push(format!("__webpack_modules__[{module_id_expr}]();"))
-          // }
         }
         if runtime_requirements.contains(RuntimeGlobals::ON_CHUNKS_LOADED) {
           startup.push(format!(
@@ -332,8 +455,18 @@ impl JsPlugin {
     let runtime_requirements = compilation
       .chunk_graph
       .get_tree_runtime_requirements(chunk_ukey);
-    let (module_source, chunk_init_fragments) = render_chunk_modules(compilation, chunk_ukey)?;
-    let (header, startup) = self.render_bootstrap(chunk_ukey, compilation);
+    let inlined_modules = self.get_inlined_entry_modules(chunk_ukey, compilation);
+    let (module_source, mut chunk_init_fragments) =
+      render_chunk_modules(compilation, chunk_ukey, &inlined_modules)?;
+    let inlined_source = if inlined_modules.is_empty() {
+      None
+    } else {
+      let (inlined_source, inlined_chunk_init_fragments) =
+        render_inlined_modules(compilation, chunk_ukey, &inlined_modules)?;
+      chunk_init_fragments.extend(inlined_chunk_init_fragments);
+      Some(inlined_source)
+    };
+    let (header, startup) = self.render_bootstrap(chunk_ukey, compilation, inlined_source);
     let mut sources = ConcatSource::default();
     sources.add(RawSource::from("var __webpack_modules__ = "));
     sources.add(module_source);
@@ -386,7 +519,8 @@ impl JsPlugin {
     chunk_ukey: &ChunkUkey,
   ) -> Result<BoxSource> {
     let drive = Self::get_compilation_drives(compilation);
-    let (module_source, chunk_init_fragments) = render_chunk_modules(compilation, chunk_ukey)?;
+    let (module_source, chunk_init_fragments) =
+      render_chunk_modules(compilation, chunk_ukey, &IdentifierLinkedSet::default())?;
     let source = drive
       .render_chunk(RenderJsChunkArgs {
         compilation,
@@ -437,9 +571,13 @@ impl JsPlugin {
     hasher: &mut RspackHash,
   ) {
     // sample hash use content
-    let (header, startup) = self.render_bootstrap(chunk_ukey, compilation);
+    let (header, startup) = self.render_bootstrap(chunk_ukey, compilation, None);
     header.hash(hasher);
     startup.hash(hasher);
+    // the sources of the inlined modules are hashed with the chunk modules
+    for module in self.get_inlined_entry_modules(chunk_ukey, compilation) {
+      module.hash(hasher);
+    }
   }
 }
 
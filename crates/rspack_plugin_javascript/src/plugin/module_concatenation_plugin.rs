@@ -9,11 +9,11 @@ use rspack_core::concatenated_module::{
   is_harmony_dep_like, ConcatenatedInnerModule, ConcatenatedModule, RootModuleContext,
 };
 use rspack_core::{
-  filter_runtime, merge_runtime, runtime_to_string, ApplyContext, Compilation,
-  CompilationOptimizeChunkModules, CompilerContext, CompilerOptions, ExportInfoProvided,
-  ExtendedReferencedExport, LibIdentOptions, Logger, Module, ModuleExt, ModuleGraph,
-  ModuleGraphModule, ModuleIdentifier, MutableModuleGraph, Plugin, PluginContext, ProvidedExports,
-  RuntimeCondition, RuntimeSpec, SourceType,
+  filter_runtime, merge_runtime, runtime_to_string, ApplyContext, BoxModule, BuildMetaExportsType,
+  Compilation, CompilationOptimizeChunkModules, CompilerContext, CompilerOptions,
+  ExportInfoProvided, ExtendedReferencedExport, LibIdentOptions, Logger, Module, ModuleExt,
+  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleType, MutableModuleGraph, Plugin,
+  PluginContext, ProvidedExports, RuntimeCondition, RuntimeSpec, SourceType,
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
@@ -28,6 +28,15 @@ fn format_bailout_reason(msg: &str) -> String {
 enum Warning {
   Id(ModuleIdentifier),
   Problem(String),
+  /// The module would make the concatenated module exceed the max size, it depends on the modules
+  /// added before, so it's not cached as a failure
+  ExceedingMaxSize(String),
+}
+
+impl Warning {
+  fn is_cacheable(&self) -> bool {
+    !matches!(self, Warning::ExceedingMaxSize(_))
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -36,10 +45,13 @@ struct ConcatConfiguration {
   runtime: Option<RuntimeSpec>,
   modules: LinkedHashSet<ModuleIdentifier>,
   warnings: HashMap<ModuleIdentifier, Warning>,
+  /// the javascript size of each module, in the order of `modules`
+  sizes: Vec<f64>,
+  size: f64,
 }
 
 impl ConcatConfiguration {
-  fn new(root_module: ModuleIdentifier, runtime: Option<RuntimeSpec>) -> Self {
+  fn new(root_module: ModuleIdentifier, root_size: f64, runtime: Option<RuntimeSpec>) -> Self {
     let mut modules = LinkedHashSet::default();
     modules.insert(root_module);
 
@@ -48,11 +60,16 @@ impl ConcatConfiguration {
       runtime,
       modules,
       warnings: HashMap::default(),
+      sizes: vec![root_size],
+      size: root_size,
     }
   }
 
-  fn add(&mut self, module: ModuleIdentifier) {
-    self.modules.insert(module);
+  fn add(&mut self, module: ModuleIdentifier, size: f64) {
+    if self.modules.insert(module) {
+      self.sizes.push(size);
+      self.size += size;
+    }
   }
 
   fn has(&self, module: &ModuleIdentifier) -> bool {
@@ -88,22 +105,40 @@ impl ConcatConfiguration {
     while i < len {
       if i >= snapshot {
         modules.pop_back();
+        if let Some(size) = self.sizes.pop() {
+          self.size -= size;
+        }
       }
       i += 1;
     }
   }
 }
 
+#[derive(Debug, Default)]
+pub struct ModuleConcatenationPluginOptions {
+  /// The max size of the javascript of a concatenated module, the modules
+  /// which would exceed it are not concatenated into it.
+  pub max_size: Option<f64>,
+  /// Allows the CommonJS modules without dependencies to be wrapped in a function and inlined into
+  /// the concatenated modules importing them.
+  pub allow_cjs_leaves: bool,
+}
+
 #[plugin]
 #[derive(Debug, Default)]
 pub struct ModuleConcatenationPlugin {
+  options: ModuleConcatenationPluginOptions,
   bailout_reason_map: FxDashMap<ModuleIdentifier, String>,
 }
 
 impl ModuleConcatenationPlugin {
+  pub fn new(options: ModuleConcatenationPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+
   fn format_bailout_warning(&self, module: ModuleIdentifier, warning: &Warning) -> String {
     match warning {
-      Warning::Problem(id) => {
+      Warning::Problem(id) | Warning::ExceedingMaxSize(id) => {
         format_bailout_reason(&format!("Cannot concat with {}: {}", module, id))
       }
       Warning::Id(id) => {
@@ -151,6 +186,24 @@ impl ModuleConcatenationPlugin {
     self.bailout_reason_map.get(module_id)
   }
 
+  /// A CommonJS module which doesn't depend on other modules, it can't be concatenated, but it can
+  /// be wrapped in a function and inlined into the concatenated module importing it
+  fn is_commonjs_leaf(mg: &ModuleGraph, module: &BoxModule) -> bool {
+    let module_id = module.identifier();
+    matches!(module.module_type(), ModuleType::Js | ModuleType::JsDynamic)
+      && module
+        .build_meta()
+        .is_some_and(|meta| matches!(meta.exports_type, BuildMetaExportsType::Dynamic))
+      && module
+        .build_info()
+        .is_some_and(|info| info.module_concatenation_bailout.is_none())
+      && module.get_blocks().is_empty()
+      && mg
+        .get_outgoing_connections(&module_id)
+        .iter()
+        .all(|connection| connection.module_identifier() == &module_id)
+  }
+
   pub fn get_imports(
     mg: &ModuleGraph,
     mi: ModuleIdentifier,
@@ -187,6 +240,7 @@ impl ModuleConcatenationPlugin {
 
   #[allow(clippy::too_many_arguments)]
   fn try_to_add(
+    &self,
     compilation: &Compilation,
     config: &mut ConcatConfiguration,
     module_id: &ModuleIdentifier,
@@ -497,18 +551,30 @@ impl ModuleConcatenationPlugin {
         return Some(problem);
       }
     }
+    let module_size = module.size(&SourceType::JavaScript);
+    if let Some(max_size) = self.options.max_size {
+      let size = config.size + module_size;
+      if size > max_size {
+        statistics.exceeding_max_size += 1;
+        return Some(Warning::ExceedingMaxSize(format!(
+          "Module {} would make the concatenated module exceed the max size ({} > {} bytes)",
+          module_readable_identifier, size, max_size
+        )));
+      }
+    }
+
     let backup = if avoid_mutate_on_failure {
       Some(config.snapshot())
     } else {
       None
     };
 
-    config.add(*module_id);
+    config.add(*module_id, module_size);
 
     incoming_modules.sort();
 
     for origin_module in &incoming_modules {
-      if let Some(problem) = Self::try_to_add(
+      if let Some(problem) = self.try_to_add(
         compilation,
         config,
         origin_module,
@@ -524,7 +590,9 @@ impl ModuleConcatenationPlugin {
           config.rollback(*backup);
         }
         statistics.importer_failed += 1;
-        failure_cache.insert(*module_id, problem.clone());
+        if problem.is_cacheable() {
+          failure_cache.insert(*module_id, problem.clone());
+        }
         return Some(problem);
       }
     }
@@ -561,12 +629,16 @@ impl ModuleConcatenationPlugin {
       let module_graph = compilation.get_module_graph();
       let m = module_graph.module_by_identifier(&module_id);
 
-      if let Some(reason) = m
-        .expect("should have module")
-        .get_concatenation_bailout_reason(&module_graph, &compilation.chunk_graph)
+      let m = m.expect("should have module");
+      if let Some(reason) =
+        m.get_concatenation_bailout_reason(&module_graph, &compilation.chunk_graph)
       {
-        self.set_bailout_reason(&module_id, reason, &mut compilation.get_module_graph_mut());
-        continue;
+        if !(self.options.allow_cjs_leaves && Self::is_commonjs_leaf(&module_graph, m)) {
+          self.set_bailout_reason(&module_id, reason, &mut compilation.get_module_graph_mut());
+          continue;
+        }
+        // a CommonJS leaf can only be inlined into a concatenated module
+        can_be_root = false;
       }
 
       // shadowing previous immutable binding, reduce borrow lifetime
@@ -736,8 +808,12 @@ impl ModuleConcatenationPlugin {
         RuntimeCondition::Spec(spec) => Some(spec),
       };
 
+      let root_size = module_graph
+        .module_by_identifier(current_root)
+        .expect("should have module")
+        .size(&SourceType::JavaScript);
       let mut current_configuration =
-        ConcatConfiguration::new(*current_root, active_runtime.clone());
+        ConcatConfiguration::new(*current_root, root_size, active_runtime.clone());
 
       let mut failure_cache = HashMap::default();
       let mut candidates_visited = HashSet::default();
@@ -755,7 +831,7 @@ impl ModuleConcatenationPlugin {
           candidates_visited.insert(imp);
         }
         let mut import_candidates = HashSet::default();
-        match Self::try_to_add(
+        match self.try_to_add(
           compilation,
           &mut current_configuration,
           &imp,
@@ -768,7 +844,9 @@ impl ModuleConcatenationPlugin {
           &mut statistics,
         ) {
           Some(problem) => {
-            failure_cache.insert(imp, problem.clone());
+            if problem.is_cacheable() {
+              failure_cache.insert(imp, problem.clone());
+            }
             current_configuration.add_warning(imp, problem);
           }
           _ => {
@@ -811,7 +889,7 @@ impl ModuleConcatenationPlugin {
     }
 
     logger.debug(format!(
-        "{} candidates were considered for adding ({} cached failure, {} already in config, {} invalid module, {} incorrect chunks, {} incorrect dependency, {} incorrect chunks of importer, {} incorrect module dependency, {} incorrect runtime condition, {} importer failed, {} added)",
+        "{} candidates were considered for adding ({} cached failure, {} already in config, {} invalid module, {} incorrect chunks, {} incorrect dependency, {} incorrect chunks of importer, {} incorrect module dependency, {} incorrect runtime condition, {} exceeding max size, {} importer failed, {} added)",
         stats_candidates,
         statistics.cached,
         statistics.already_in_config,
@@ -821,6 +899,7 @@ impl ModuleConcatenationPlugin {
         statistics.incorrect_chunks_of_importer,
         statistics.incorrect_module_dependency,
         statistics.incorrect_runtime_condition,
+        statistics.exceeding_max_size,
         statistics.importer_failed,
         statistics.added
     ));
@@ -922,6 +1001,11 @@ impl ModuleConcatenationPlugin {
           Some(compilation),
         )
         .await?;
+      let bailout_warnings = config
+        .get_warnings_sorted()
+        .into_iter()
+        .map(|(module, warning)| self.format_bailout_warning(module, &warning))
+        .collect::<Vec<_>>();
       let mut chunk_graph = std::mem::take(&mut compilation.chunk_graph);
       let mut module_graph = compilation.get_module_graph_mut();
       let root_mgm_exports = module_graph
@@ -932,6 +1016,10 @@ impl ModuleConcatenationPlugin {
         ModuleGraphModule::new(new_module.id(), *new_module.module_type(), root_mgm_exports);
       module_graph.add_module_graph_module(module_graph_module);
       module_graph.clone_module_attributes(&root_module_id, &new_module.id());
+      // the modules which failed to be added are reported on the concatenated module
+      module_graph
+        .get_optimization_bailout_mut(&new_module.id())
+        .extend(bailout_warnings);
       // integrate
 
       for m in modules_set {
@@ -1024,6 +1112,7 @@ struct Statistics {
   incorrect_module_dependency: u32,
   incorrect_chunks_of_importer: u32,
   incorrect_runtime_condition: u32,
+  exceeding_max_size: u32,
   importer_failed: u32,
   added: u32,
 }
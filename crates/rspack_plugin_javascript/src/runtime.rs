@@ -1,10 +1,11 @@
 use rayon::prelude::*;
 use rspack_core::rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rspack_core::{
-  to_normal_comment, BoxModule, ChunkInitFragments, ChunkUkey, Compilation, RuntimeGlobals,
-  SourceType,
+  to_normal_comment, BoxModule, ChunkInitFragments, ChunkUkey, Compilation, ExportsArgument,
+  RuntimeGlobals, SourceType,
 };
 use rspack_error::{error, Result};
+use rspack_identifier::IdentifierLinkedSet;
 use rspack_util::diff_mode::is_diff_mode;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -13,6 +14,7 @@ use crate::{JsPlugin, RenderJsModuleContentArgs};
 pub fn render_chunk_modules(
   compilation: &Compilation,
   chunk_ukey: &ChunkUkey,
+  inlined_modules: &IdentifierLinkedSet,
 ) -> Result<(BoxSource, ChunkInitFragments)> {
   let drive = JsPlugin::get_compilation_drives(compilation);
   let module_graph = &compilation.get_module_graph();
@@ -30,6 +32,7 @@ pub fn render_chunk_modules(
     .filter(|module| {
       compilation.options.is_new_tree_shaking() || include_module_ids.contains(&module.identifier())
     })
+    .filter(|module| !inlined_modules.contains(&module.identifier()))
     .filter_map(|module| {
       let code_gen_result = compilation
         .code_generation_results
@@ -100,6 +103,66 @@ pub fn render_chunk_modules(
   Ok((sources.boxed(), chunk_init_fragments))
 }
 
+/// Renders the inlined entry modules without the module factories, each one is
+/// wrapped in a function to isolate its top-level declarations.
+pub fn render_inlined_modules(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  inlined_modules: &IdentifierLinkedSet,
+) -> Result<(BoxSource, ChunkInitFragments)> {
+  let drive = JsPlugin::get_compilation_drives(compilation);
+  let module_graph = compilation.get_module_graph();
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  let mut sources = ConcatSource::default();
+  let mut chunk_init_fragments = ChunkInitFragments::default();
+  let last_module = inlined_modules.back();
+  for module_identifier in inlined_modules {
+    let module = module_graph
+      .module_by_identifier(module_identifier)
+      .expect("should have module");
+    let code_gen_result = compilation
+      .code_generation_results
+      .get(module_identifier, Some(&chunk.runtime));
+    let origin_source = code_gen_result
+      .get(&SourceType::JavaScript)
+      .expect("should have javascript source of the inlined module");
+    let render_module_result = drive.render_module_content(RenderJsModuleContentArgs {
+      compilation,
+      module,
+      module_source: origin_source.clone(),
+      chunk_init_fragments: ChunkInitFragments::default(),
+    })?;
+    chunk_init_fragments.extend(code_gen_result.chunk_init_fragments.clone());
+    chunk_init_fragments.extend(render_module_result.chunk_init_fragments);
+
+    let need_exports = compilation
+      .chunk_graph
+      .get_module_runtime_requirements(*module_identifier, &chunk.runtime)
+      .is_some_and(|r| r.contains(RuntimeGlobals::EXPORTS));
+    let exports_argument = module.get_exports_argument();
+
+    sources.add(RawSource::from("!function() {\n"));
+    if let Some(build_info) = &module.build_info()
+      && build_info.strict
+    {
+      sources.add(RawSource::from("\"use strict\";\n"));
+    }
+    if need_exports {
+      // only the exports of the last entry module are returned from the startup
+      if Some(module_identifier) != last_module {
+        sources.add(RawSource::from(format!("var {exports_argument} = {{}};\n")));
+      } else if !matches!(exports_argument, ExportsArgument::WebpackExports) {
+        sources.add(RawSource::from(format!(
+          "var {exports_argument} = __webpack_exports__;\n"
+        )));
+      }
+    }
+    sources.add(render_module_result.module_source);
+    sources.add(RawSource::from("\n}();\n"));
+  }
+  Ok((sources.boxed(), chunk_init_fragments))
+}
+
 fn render_module(
   source: BoxSource,
   module: &BoxModule,
@@ -67,4 +67,5 @@ pub fn ecma_parse_error_deduped_to_rspack_error(
     message,
   )
   .with_kind(diagnostic_kind)
+  .with_code("JAVASCRIPT_PARSE_ERROR")
 }
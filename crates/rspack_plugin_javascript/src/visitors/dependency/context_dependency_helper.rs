@@ -88,7 +88,8 @@ pub fn create_context_dependency(
           parser.source_file,
           rspack_core::ErrorSpan::new(range.0, range.1),
         )
-        .with_severity(Severity::Warn),
+        .with_severity(Severity::Warn)
+        .with_code("CRITICAL_DEPENDENCY"),
       ));
     }
 
@@ -156,7 +157,8 @@ pub fn create_context_dependency(
           parser.source_file,
           rspack_core::ErrorSpan::new(range.0, range.1),
         )
-        .with_severity(Severity::Warn),
+        .with_severity(Severity::Warn)
+        .with_code("CRITICAL_DEPENDENCY"),
       ));
     }
 
@@ -178,7 +180,8 @@ pub fn create_context_dependency(
           parser.source_file,
           rspack_core::ErrorSpan::new(range.0, range.1),
         )
-        .with_severity(Severity::Warn),
+        .with_severity(Severity::Warn)
+        .with_code("CRITICAL_DEPENDENCY"),
       ));
     }
     ContextModuleScanResult {
@@ -10,7 +10,7 @@ use bitflags::bitflags;
 pub use call_hooks_name::CallHooksName;
 use rspack_core::needs_refactor::WorkerSyntaxList;
 use rspack_core::{
-  AsyncDependenciesBlock, BoxDependency, BuildInfo, BuildMeta, DependencyTemplate,
+  AsyncDependenciesBlock, BoxDependency, BuildInfo, BuildMeta, ConstDependency, DependencyTemplate,
   JavascriptParserOptions, ModuleIdentifier, ResourceData,
 };
 use rspack_core::{CompilerOptions, JavascriptParserUrl, ModuleType, SpanExt};
@@ -636,6 +636,19 @@ impl<'parser> JavascriptParser<'parser> {
         }
         Program::Script(s) => {
           self.detect_mode(&s.body);
+          if self.is_strict() {
+            // the directive is moved to the module wrapper, like the UseStrictPlugin of webpack
+            let span = s.body[0].span();
+            self
+              .presentational_dependencies
+              .push(Box::new(ConstDependency::new(
+                span.real_lo(),
+                span.real_hi(),
+                "".into(),
+                None,
+              )));
+            self.build_info.strict = true;
+          }
           self.pre_walk_statements(&s.body);
           self.block_pre_walk_statements(&s.body);
           self.walk_statements(&s.body);
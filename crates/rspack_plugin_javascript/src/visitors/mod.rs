@@ -2,8 +2,10 @@ mod dependency;
 pub mod scope_info;
 pub mod swc_visitor;
 
+use std::borrow::Cow;
+
 use rspack_ast::javascript::Ast;
-use rspack_core::CompilerOptions;
+use rspack_core::{CompilerOptions, Define};
 use rspack_error::{AnyhowError, Result};
 use swc_core::common::comments::Comments;
 use swc_core::common::{chain, Mark};
@@ -16,15 +18,12 @@ pub use self::JavascriptParser;
 
 /// Webpack builtin plugins
 /// - `define`: a port of `DefinePlugin`
-fn builtins_webpack_plugin(options: &CompilerOptions, unresolved_mark: Mark) -> impl Fold + '_ {
+fn builtins_webpack_plugin(define: &Define, unresolved_mark: Mark) -> impl Fold + '_ {
   chain!(
-    Optional::new(
-      rspack_swc_visitors::define(&options.builtins.define),
-      !options.builtins.define.is_empty()
-    ),
+    Optional::new(rspack_swc_visitors::define(define), !define.is_empty()),
     Optional::new(
       builtins_webpack_plugin_define_optimizer(unresolved_mark),
-      !options.builtins.define.is_empty()
+      !define.is_empty()
     ),
   )
 }
@@ -55,6 +54,20 @@ fn builtins_webpack_plugin_define_optimizer(unresolved_mark: Mark) -> impl Fold
 #[allow(clippy::too_many_arguments)]
 pub fn run_before_pass(ast: &mut Ast, options: &CompilerOptions) -> Result<()> {
   let cm = ast.get_context().source_map.clone();
+  let define = {
+    let runtime_define = options.builtins.runtime_define.read().expect("should lock");
+    if runtime_define.is_empty() {
+      Cow::Borrowed(&options.builtins.define)
+    } else {
+      let mut define = options.builtins.define.clone();
+      define.extend(
+        runtime_define
+          .iter()
+          .map(|(key, value)| (key.clone(), value.code.clone())),
+      );
+      Cow::Owned(define)
+    }
+  };
   ast
     .transform_with_handler(cm.clone(), |_handler, program, context| {
       let top_level_mark = context.top_level_mark;
@@ -64,7 +77,7 @@ pub fn run_before_pass(ast: &mut Ast, options: &CompilerOptions) -> Result<()> {
         let mut pass = chain!(
           dropped_comments_preserver(comments.clone()),
           swc_visitor::resolver(unresolved_mark, top_level_mark, false),
-          builtins_webpack_plugin(options, unresolved_mark),
+          builtins_webpack_plugin(&define, unresolved_mark),
           swc_visitor::hygiene(false, top_level_mark),
           swc_visitor::fixer(Some(&comments as &dyn Comments)),
         );
@@ -75,7 +75,8 @@ fn add_magic_comment_warning(
       source_file,
       span.into(),
     )
-    .with_severity(Severity::Warning),
+    .with_severity(Severity::Warning)
+    .with_code("MAGIC_COMMENT_PARSE_FAILED"),
   ))
 }
 
@@ -8,13 +8,15 @@ use rspack_core::{
 pub struct JsonExportsDependency {
   id: DependencyId,
   data: JsonValue,
+  exports_depth: u32,
 }
 
 impl JsonExportsDependency {
-  pub fn new(data: JsonValue) -> Self {
+  pub fn new(data: JsonValue, exports_depth: u32) -> Self {
     Self {
       data,
       id: DependencyId::new(),
+      exports_depth,
     }
   }
 }
@@ -30,7 +32,8 @@ impl Dependency for JsonExportsDependency {
 
   fn get_exports(&self, _mg: &ModuleGraph) -> Option<ExportsSpec> {
     Some(ExportsSpec {
-      exports: get_exports_from_data(&self.data).unwrap_or(ExportsOfExportsSpec::Null),
+      exports: get_exports_from_data(&self.data, self.exports_depth, 1)
+        .unwrap_or(ExportsOfExportsSpec::Null),
       ..Default::default()
     })
   }
@@ -52,7 +55,15 @@ impl DependencyTemplate for JsonExportsDependency {
   }
 }
 
-fn get_exports_from_data(data: &JsonValue) -> Option<ExportsOfExportsSpec> {
+fn get_exports_from_data(
+  data: &JsonValue,
+  exports_depth: u32,
+  cur_depth: u32,
+) -> Option<ExportsOfExportsSpec> {
+  // The exports deeper than `exports_depth` are unknown, so they will not be tree shaken
+  if cur_depth > exports_depth {
+    return None;
+  }
   let ret = match data {
     JsonValue::Null
     | JsonValue::Short(_)
@@ -68,11 +79,13 @@ fn get_exports_from_data(data: &JsonValue) -> Option<ExportsOfExportsSpec> {
           ExportNameOrSpec::ExportSpec(ExportSpec {
             name: k.into(),
             can_mangle: Some(true),
-            exports: get_exports_from_data(v).map(|item| match item {
-              ExportsOfExportsSpec::True => unreachable!(),
-              ExportsOfExportsSpec::Null => unreachable!(),
-              ExportsOfExportsSpec::Array(arr) => arr,
-            }),
+            exports: get_exports_from_data(v, exports_depth, cur_depth + 1).map(
+              |item| match item {
+                ExportsOfExportsSpec::True => unreachable!(),
+                ExportsOfExportsSpec::Null => unreachable!(),
+                ExportsOfExportsSpec::Array(arr) => arr,
+              },
+            ),
             ..Default::default()
           })
         })
@@ -90,9 +103,11 @@ fn get_exports_from_data(data: &JsonValue) -> Option<ExportsOfExportsSpec> {
             ExportNameOrSpec::ExportSpec(ExportSpec {
               name: format!("{i}").into(),
               can_mangle: Some(true),
-              exports: get_exports_from_data(item).map(|item| match item {
-                ExportsOfExportsSpec::True | ExportsOfExportsSpec::Null => unreachable!(),
-                ExportsOfExportsSpec::Array(arr) => arr,
+              exports: get_exports_from_data(item, exports_depth, cur_depth + 1).map(|item| {
+                match item {
+                  ExportsOfExportsSpec::True | ExportsOfExportsSpec::Null => unreachable!(),
+                  ExportsOfExportsSpec::Array(arr) => arr,
+                }
               }),
               ..Default::default()
             })
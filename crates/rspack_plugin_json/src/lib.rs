@@ -14,12 +14,13 @@ use rspack_core::{
   diagnostics::ModuleParseError,
   rspack_sources::{BoxSource, RawSource, Source, SourceExt},
   BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, CompilerOptions, ExportsInfo,
-  GenerateContext, Module, ModuleGraph, ParserAndGenerator, Plugin, RuntimeGlobals, RuntimeSpec,
-  SourceType, UsageState, NAMESPACE_OBJECT_EXPORT,
+  GenerateContext, JsonParseFn, Module, ModuleGraph, ParserAndGenerator, Plugin, RuntimeGlobals,
+  RuntimeSpec, SourceType, UsageState, NAMESPACE_OBJECT_EXPORT,
 };
 use rspack_error::{
-  miette::diagnostic, DiagnosticExt, DiagnosticKind, IntoTWithDiagnosticArray, Result,
-  TWithDiagnosticArray, TraceableError,
+  miette::{diagnostic, Diagnostic},
+  DiagnosticExt, DiagnosticKind, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray,
+  TraceableError,
 };
 
 use crate::json_exports_dependency::JsonExportsDependency;
@@ -28,7 +29,10 @@ mod json_exports_dependency;
 mod utils;
 
 #[derive(Debug)]
-struct JsonParserAndGenerator;
+struct JsonParserAndGenerator {
+  exports_depth: u32,
+  parse: Option<JsonParseFn>,
+}
 
 impl ParserAndGenerator for JsonParserAndGenerator {
   fn source_types(&self) -> &[SourceType] {
@@ -58,47 +62,13 @@ impl ParserAndGenerator for JsonParserAndGenerator {
     let strip_bom_source = source.strip_prefix('\u{feff}');
     let need_strip_bom = strip_bom_source.is_some();
 
-    let parse_result = json::parse(strip_bom_source.unwrap_or(&source)).map_err(|e| {
-      match e {
-        UnexpectedCharacter { ch, line, column } => {
-          let rope = ropey::Rope::from_str(&source);
-          let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
-          let start_offset = source[line_offset..]
-            .chars()
-            .take(column)
-            .fold(line_offset, |acc, cur| acc + cur.len_utf8());
-          let start_offset = if need_strip_bom {
-            start_offset + 1
-          } else {
-            start_offset
-          };
-          TraceableError::from_file(
-            source.into_owned(),
-            // one character offset
-            start_offset,
-            start_offset + 1,
-            "Json parsing error".to_string(),
-            format!("Unexpected character {ch}"),
-          )
-          .with_kind(DiagnosticKind::Json)
-          .boxed()
-        }
-        ExceededDepthLimit | WrongType(_) | FailedUtf8Parsing => diagnostic!("{e}").boxed(),
-        UnexpectedEndOfJson => {
-          // End offset of json file
-          let offset = source.len() - 1;
-          TraceableError::from_file(
-            source.into_owned(),
-            offset,
-            offset,
-            "Json parsing error".to_string(),
-            format!("{e}"),
-          )
-          .with_kind(DiagnosticKind::Json)
-          .boxed()
-        }
-      }
-    });
+    let parse_result = if let Some(parse) = &self.parse {
+      parse
+        .parse(strip_bom_source.unwrap_or(&source))
+        .map_err(|e| e.into())
+    } else {
+      parse_json(&source, strip_bom_source, need_strip_bom)
+    };
 
     let (diagnostics, data) = match parse_result {
       Ok(data) => (vec![], Some(data)),
@@ -113,7 +83,10 @@ impl ParserAndGenerator for JsonParserAndGenerator {
       rspack_core::ParseResult {
         presentational_dependencies: vec![],
         dependencies: if let Some(data) = data {
-          vec![Box::new(JsonExportsDependency::new(data))]
+          vec![Box::new(JsonExportsDependency::new(
+            data,
+            self.exports_depth,
+          ))]
         } else {
           vec![]
         },
@@ -204,6 +177,56 @@ impl ParserAndGenerator for JsonParserAndGenerator {
   }
 }
 
+fn parse_json(
+  source: &str,
+  strip_bom_source: Option<&str>,
+  need_strip_bom: bool,
+) -> std::result::Result<JsonValue, Box<dyn Diagnostic + Send + Sync>> {
+  json::parse(strip_bom_source.unwrap_or(source)).map_err(|e| {
+    match e {
+      UnexpectedCharacter { ch, line, column } => {
+        let rope = ropey::Rope::from_str(source);
+        let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
+        let start_offset = source[line_offset..]
+          .chars()
+          .take(column)
+          .fold(line_offset, |acc, cur| acc + cur.len_utf8());
+        let start_offset = if need_strip_bom {
+          start_offset + 1
+        } else {
+          start_offset
+        };
+        TraceableError::from_file(
+          source.to_owned(),
+          // one character offset
+          start_offset,
+          start_offset + 1,
+          "Json parsing error".to_string(),
+          format!("Unexpected character {ch}"),
+        )
+        .with_kind(DiagnosticKind::Json)
+        .with_code("JSON_PARSE_ERROR")
+        .boxed()
+      }
+      ExceededDepthLimit | WrongType(_) | FailedUtf8Parsing => diagnostic!("{e}").boxed(),
+      UnexpectedEndOfJson => {
+        // End offset of json file
+        let offset = source.len() - 1;
+        TraceableError::from_file(
+          source.to_owned(),
+          offset,
+          offset,
+          "Json parsing error".to_string(),
+          format!("{e}"),
+        )
+        .with_kind(DiagnosticKind::Json)
+        .with_code("JSON_PARSE_ERROR")
+        .boxed()
+      }
+    }
+  })
+}
+
 #[derive(Debug)]
 pub struct JsonPlugin;
 
@@ -219,7 +242,13 @@ impl Plugin for JsonPlugin {
   ) -> Result<()> {
     ctx.context.register_parser_and_generator_builder(
       rspack_core::ModuleType::Json,
-      Box::new(|_, _| Box::new(JsonParserAndGenerator {})),
+      Box::new(|p, _| {
+        let p = p.and_then(|p| p.get_json());
+        Box::new(JsonParserAndGenerator {
+          exports_depth: p.and_then(|p| p.exports_depth).unwrap_or(u32::MAX),
+          parse: p.and_then(|p| p.parse.clone()),
+        })
+      }),
     );
 
     Ok(())
@@ -63,6 +63,10 @@ impl ContainerEntryModule {
       enhanced,
     }
   }
+
+  pub fn exposes(&self) -> &[(String, ExposeOptions)] {
+    &self.exposes
+  }
 }
 
 impl Identifiable for ContainerEntryModule {
@@ -198,14 +202,17 @@ impl Module for ContainerEntryModule {
       &mut code_generation_result.runtime_requirements,
     );
     let module_map_str = module_map.render();
+    let environment = &compilation.options.output.environment;
     let source = if self.enhanced {
       format!(
         r#"
 {}(exports, {{
-	get: () => (__webpack_require__.getContainer),
-	init: () => (__webpack_require__.initContainer)
+	get: {},
+	init: {}
 }});"#,
         RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
+        environment.returning_function("__webpack_require__.getContainer", ""),
+        environment.returning_function("__webpack_require__.initContainer", ""),
       )
     } else {
       format!(
@@ -216,9 +223,7 @@ var get = function(module, getScope) {{
   getScope = (
     {has_own_property}(moduleMap, module)
       ? moduleMap[module]()
-      : Promise.resolve().then(() => {{
-        throw new Error('Module "' + module + '" does not exist in container.');
-      }})
+      : Promise.resolve().then({module_not_found})
   );
   {current_remote_get_scope} = undefined;
   return getScope;
@@ -232,9 +237,15 @@ var init = function(shareScope, initScope) {{
   return {initialize_sharing}(name, initScope);
 }}
 {define_property_getters}(exports, {{
-	get: () => (get),
-	init: () => (init)
+	get: {get_getter},
+	init: {init_getter}
 }});"#,
+        module_not_found = environment.basic_function(
+          "",
+          "throw new Error('Module \"' + module + '\" does not exist in container.');"
+        ),
+        get_getter = environment.returning_function("get", ""),
+        init_getter = environment.returning_function("init", ""),
         current_remote_get_scope = RuntimeGlobals::CURRENT_REMOTE_GET_SCOPE,
         has_own_property = RuntimeGlobals::HAS_OWN_PROPERTY,
         share_scope_map = RuntimeGlobals::SHARE_SCOPE_MAP,
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use rspack_core::{
@@ -27,6 +27,24 @@ pub struct ContainerReferencePluginOptions {
 pub struct RemoteOptions {
   pub external: Vec<String>,
   pub share_scope: String,
+  pub fallback: Option<RemoteFallback>,
+}
+
+#[derive(Debug)]
+pub enum RemoteFallback {
+  /// Used in place of every module of the remote.
+  Remote(String),
+  /// Keyed by the exposed module request, e.g. `./Button`.
+  Exposes(HashMap<String, String>),
+}
+
+impl RemoteFallback {
+  pub fn get(&self, exposed: &str) -> Option<&String> {
+    match self {
+      Self::Remote(fallback) => Some(fallback),
+      Self::Exposes(fallbacks) => fallbacks.get(exposed),
+    }
+  }
 }
 
 #[plugin]
@@ -75,7 +93,12 @@ async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<B
       if request.starts_with(key)
         && (request.len() == key_len || request[key_len..].starts_with('/'))
       {
-        let internal_request = &request[key_len..];
+        let internal_request = format!(".{}", &request[key_len..]);
+        let fallback = config
+          .fallback
+          .as_ref()
+          .and_then(|f| f.get(&internal_request))
+          .cloned();
         let remote = RemoteModule::new(
           request.to_owned(),
           config
@@ -96,9 +119,10 @@ async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<B
               }
             })
             .collect(),
-          format!(".{}", internal_request),
+          internal_request,
           config.share_scope.clone(),
           key.to_string(),
+          fallback,
         )
         .boxed();
         return Ok(Some(remote));
@@ -9,6 +9,7 @@ pub mod fallback_dependency;
 pub mod fallback_item_dependency;
 pub mod fallback_module;
 pub mod fallback_module_factory;
+pub mod module_federation_manifest_plugin;
 pub mod module_federation_runtime_plugin;
 pub mod remote_module;
 pub mod remote_runtime_module;
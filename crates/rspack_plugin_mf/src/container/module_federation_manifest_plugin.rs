@@ -0,0 +1,367 @@
+use async_trait::async_trait;
+use hashlink::LinkedHashMap;
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, ChunkGroup, ChunkUkey, Compilation, CompilationAsset, CompilationProcessAssets,
+  CompilerOptions, DependenciesBlock, Module, Plugin, PluginContext, PublicPath,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+
+use super::{
+  container_entry_module::ContainerEntryModule,
+  container_exposed_dependency::ContainerExposedDependency, remote_module::RemoteModule,
+};
+use crate::sharing::{
+  consume_shared_module::ConsumeSharedModule, provide_shared_module::ProvideSharedModule,
+};
+
+#[derive(Debug)]
+pub struct ModuleFederationManifestPluginOptions {
+  pub name: String,
+  pub global_name: String,
+  pub file_name: String,
+  pub remote_entry: Option<ManifestRemoteEntry>,
+  pub remotes: Vec<ManifestRemoteInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ManifestRemoteEntry {
+  pub name: String,
+  pub path: String,
+  pub r#type: String,
+}
+
+#[derive(Debug)]
+pub struct ManifestRemoteInfo {
+  pub alias: String,
+  pub name: Option<String>,
+  pub entry: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct ManifestAssetGroup {
+  sync: Vec<String>,
+  r#async: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct ManifestAssets {
+  js: ManifestAssetGroup,
+  css: ManifestAssetGroup,
+}
+
+impl ManifestAssets {
+  fn merge(&mut self, other: ManifestAssets) {
+    for (group, other) in [(&mut self.js, other.js), (&mut self.css, other.css)] {
+      group.sync.extend(other.sync);
+      group.sync.sort_unstable();
+      group.sync.dedup();
+      group.r#async.extend(other.r#async);
+      group.r#async.sort_unstable();
+      group.r#async.dedup();
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ManifestMetaData {
+  name: String,
+  r#type: &'static str,
+  global_name: String,
+  public_path: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  remote_entry: Option<ManifestRemoteEntry>,
+}
+
+#[derive(Debug, Serialize)]
+struct ManifestExpose {
+  id: String,
+  name: String,
+  path: String,
+  assets: ManifestAssets,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ManifestShared {
+  id: String,
+  name: String,
+  version: String,
+  singleton: bool,
+  required_version: String,
+  assets: ManifestAssets,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ManifestRemote {
+  federation_container_name: String,
+  module_name: String,
+  alias: String,
+  entry: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Manifest {
+  id: String,
+  name: String,
+  meta_data: ManifestMetaData,
+  shared: Vec<ManifestShared>,
+  remotes: Vec<ManifestRemote>,
+  exposes: Vec<ManifestExpose>,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ModuleFederationManifestPlugin {
+  options: ModuleFederationManifestPluginOptions,
+}
+
+impl ModuleFederationManifestPlugin {
+  pub fn new(options: ModuleFederationManifestPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+fn collect_assets(
+  compilation: &Compilation,
+  chunks: impl IntoIterator<Item = ChunkUkey>,
+) -> ManifestAssets {
+  let chunks: FxHashSet<ChunkUkey> = chunks.into_iter().collect();
+  let mut async_chunks = FxHashSet::default();
+  for chunk in &chunks {
+    let chunk = compilation.chunk_by_ukey.expect_get(chunk);
+    async_chunks.extend(chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey));
+  }
+
+  let mut assets = ManifestAssets::default();
+  let mut add_files = |chunk: &ChunkUkey, is_async: bool| {
+    let chunk = compilation.chunk_by_ukey.expect_get(chunk);
+    for file in &chunk.files {
+      let group = if file.ends_with(".css") {
+        &mut assets.css
+      } else if file.ends_with(".js") || file.ends_with(".mjs") || file.ends_with(".cjs") {
+        &mut assets.js
+      } else {
+        continue;
+      };
+      if is_async {
+        group.r#async.push(file.clone());
+      } else {
+        group.sync.push(file.clone());
+      }
+    }
+  };
+  for chunk in &chunks {
+    add_files(chunk, false);
+  }
+  for chunk in async_chunks.difference(&chunks) {
+    add_files(chunk, true);
+  }
+  for group in [&mut assets.js, &mut assets.css] {
+    group.sync.sort_unstable();
+    group.sync.dedup();
+    group.r#async.sort_unstable();
+    group.r#async.dedup();
+  }
+  assets
+}
+
+/// Whether `a` is higher than `b` by their numeric `major.minor.patch` parts,
+/// an empty version is lower than any other.
+fn is_higher_version(a: &str, b: &str) -> bool {
+  let parse = |version: &str| -> Vec<u64> {
+    version
+      .split(['-', '+'])
+      .next()
+      .unwrap_or_default()
+      .split('.')
+      .map(|part| part.parse().unwrap_or_default())
+      .collect()
+  };
+  if b.is_empty() {
+    return !a.is_empty();
+  }
+  parse(a) > parse(b)
+}
+
+fn collect_module_assets(compilation: &Compilation, module: &dyn Module) -> ManifestAssets {
+  // lazy shared modules are split into their own chunk groups, eager ones are
+  // bundled into the chunks of the module itself
+  let chunk_groups: Vec<&ChunkGroup> = module
+    .get_blocks()
+    .iter()
+    .filter_map(|block| {
+      compilation
+        .chunk_graph
+        .get_block_chunk_group(block, &compilation.chunk_group_by_ukey)
+    })
+    .collect();
+  if chunk_groups.is_empty() {
+    let chunks = compilation
+      .chunk_graph
+      .get_module_chunks(module.identifier())
+      .clone();
+    collect_assets(compilation, chunks)
+  } else {
+    collect_assets(
+      compilation,
+      chunk_groups
+        .into_iter()
+        .flat_map(|group| group.chunks.iter().copied()),
+    )
+  }
+}
+
+#[plugin_hook(CompilationProcessAssets for ModuleFederationManifestPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_REPORT)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let container_name = &self.options.name;
+  let module_graph = compilation.get_module_graph();
+  let mut modules = module_graph.modules().into_values().collect::<Vec<_>>();
+  modules.sort_unstable_by_key(|module| module.identifier());
+
+  let mut exposes = vec![];
+  let mut shared: LinkedHashMap<String, ManifestShared> = LinkedHashMap::default();
+  let mut remotes = vec![];
+  for module in modules {
+    if let Some(module) = module.downcast_ref::<ContainerEntryModule>() {
+      let blocks_by_name: FxHashMap<&str, _> = module
+        .get_blocks()
+        .iter()
+        .filter_map(|block_id| {
+          let block = module_graph.block_by_id(block_id)?;
+          let dependency = module_graph.dependency_by_id(block.get_dependencies().first()?)?;
+          let dependency = dependency.downcast_ref::<ContainerExposedDependency>()?;
+          Some((dependency.exposed_name.as_str(), block_id))
+        })
+        .collect();
+      for (path, _) in module.exposes() {
+        let name = path.trim_start_matches("./").to_string();
+        let assets = blocks_by_name
+          .get(path.as_str())
+          .and_then(|block| {
+            compilation
+              .chunk_graph
+              .get_block_chunk_group(block, &compilation.chunk_group_by_ukey)
+          })
+          .map(|group| collect_assets(compilation, group.chunks.iter().copied()))
+          .unwrap_or_default();
+        exposes.push(ManifestExpose {
+          id: format!("{container_name}:{name}"),
+          name,
+          path: path.clone(),
+          assets,
+        });
+      }
+    } else if let Some(module) = module.downcast_ref::<ProvideSharedModule>() {
+      let item = shared
+        .entry(module.name().to_string())
+        .or_insert_with(|| ManifestShared {
+          id: format!("{container_name}:{}", module.name()),
+          name: module.name().to_string(),
+          version: String::new(),
+          singleton: false,
+          required_version: "*".to_string(),
+          assets: ManifestAssets::default(),
+        });
+      // the same package may be provided in several versions, advertise the
+      // highest one and the assets of all of them
+      let version = module.version().to_string();
+      if is_higher_version(&version, &item.version) {
+        item.version = version;
+      }
+      item
+        .assets
+        .merge(collect_module_assets(compilation, module));
+    } else if let Some(module) = module.downcast_ref::<ConsumeSharedModule>() {
+      let options = module.options();
+      let item = shared
+        .entry(options.share_key.clone())
+        .or_insert_with(|| ManifestShared {
+          id: format!("{container_name}:{}", options.share_key),
+          name: options.share_key.clone(),
+          version: String::new(),
+          singleton: false,
+          required_version: "*".to_string(),
+          assets: ManifestAssets::default(),
+        });
+      item
+        .assets
+        .merge(collect_module_assets(compilation, module));
+      item.singleton |= options.singleton;
+      if let Some(required_version) = &options.required_version {
+        item.required_version = required_version.to_string();
+      }
+    } else if let Some(module) = module.downcast_ref::<RemoteModule>() {
+      let alias = module.remote_key();
+      let info = self
+        .options
+        .remotes
+        .iter()
+        .find(|remote| remote.alias == alias);
+      remotes.push(ManifestRemote {
+        federation_container_name: info
+          .and_then(|info| info.name.clone())
+          .unwrap_or_else(|| alias.to_string()),
+        module_name: module
+          .internal_request()
+          .trim_start_matches("./")
+          .to_string(),
+        alias: alias.to_string(),
+        entry: info
+          .and_then(|info| info.entry.clone())
+          .unwrap_or_else(|| "*".to_string()),
+      });
+    }
+  }
+
+  let public_path = match &compilation.options.output.public_path {
+    PublicPath::String(public_path) => public_path.clone(),
+    PublicPath::Auto => "auto".to_string(),
+  };
+  let manifest = Manifest {
+    id: container_name.clone(),
+    name: container_name.clone(),
+    meta_data: ManifestMetaData {
+      name: container_name.clone(),
+      r#type: "app",
+      global_name: self.options.global_name.clone(),
+      public_path,
+      remote_entry: self.options.remote_entry.clone(),
+    },
+    shared: shared.into_iter().map(|(_, shared)| shared).collect(),
+    remotes,
+    exposes,
+  };
+  let source = serde_json::to_string_pretty(&manifest).expect("should serialize the manifest");
+  compilation.emit_asset(
+    self.options.file_name.clone(),
+    CompilationAsset::from(RawSource::from(source).boxed()),
+  );
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for ModuleFederationManifestPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.container.ModuleFederationManifestPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
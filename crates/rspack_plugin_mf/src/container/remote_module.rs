@@ -15,7 +15,7 @@ use rspack_identifier::{Identifiable, Identifier};
 use rspack_util::source_map::SourceMapKind;
 
 use super::{
-  fallback_dependency::FallbackDependency,
+  fallback_dependency::FallbackDependency, fallback_item_dependency::FallbackItemDependency,
   remote_to_external_dependency::RemoteToExternalDependency,
 };
 use crate::{
@@ -35,6 +35,7 @@ pub struct RemoteModule {
   pub internal_request: String,
   pub share_scope: String,
   pub remote_key: String,
+  fallback: Option<String>,
   factory_meta: Option<FactoryMeta>,
   build_info: Option<BuildInfo>,
   build_meta: Option<BuildMeta>,
@@ -47,6 +48,7 @@ impl RemoteModule {
     internal_request: String,
     share_scope: String,
     remote_key: String,
+    fallback: Option<String>,
   ) -> Self {
     let readable_identifier = format!("remote {}", &request);
     let lib_ident = format!("webpack/container/remote/{}", &request);
@@ -54,10 +56,14 @@ impl RemoteModule {
       blocks: Default::default(),
       dependencies: Default::default(),
       identifier: ModuleIdentifier::from(format!(
-        "remote ({}) {} {}",
+        "remote ({}) {} {}{}",
         share_scope,
         external_requests.join(" "),
-        internal_request
+        internal_request,
+        fallback
+          .as_ref()
+          .map(|f| format!(" fallback {f}"))
+          .unwrap_or_default()
       )),
       readable_identifier,
       lib_ident,
@@ -66,12 +72,21 @@ impl RemoteModule {
       internal_request,
       share_scope,
       remote_key,
+      fallback,
       factory_meta: None,
       build_info: None,
       build_meta: None,
       source_map_kind: SourceMapKind::empty(),
     }
   }
+
+  pub fn remote_key(&self) -> &str {
+    &self.remote_key
+  }
+
+  pub fn internal_request(&self) -> &str {
+    &self.internal_request
+  }
 }
 
 impl Identifiable for RemoteModule {
@@ -156,6 +171,12 @@ impl Module for RemoteModule {
       let dep = FallbackDependency::new(self.external_requests.clone());
       dependencies.push(Box::new(dep));
     }
+    // the fallback is bundled with the remote module, so it can be used synchronously
+    // when loading the remote container fails
+    if let Some(fallback) = &self.fallback {
+      let dep = FallbackItemDependency::new(fallback.clone());
+      dependencies.push(Box::new(dep));
+    }
 
     Ok(BuildResult {
       build_info,
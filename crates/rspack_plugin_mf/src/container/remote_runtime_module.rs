@@ -8,7 +8,7 @@ use rspack_util::source_map::SourceMapKind;
 use rustc_hash::FxHashMap;
 use serde::Serialize;
 
-use super::remote_module::RemoteModule;
+use super::{fallback_item_dependency::FallbackItemDependency, remote_module::RemoteModule};
 use crate::utils::json_stringify;
 
 #[impl_runtime_module]
@@ -73,6 +73,25 @@ impl RuntimeModule for RemoteRuntimeModule {
           .get_module_id(external_module.identifier())
           .as_deref()
           .expect("should have module_id at <RemoteRuntimeModule as RuntimeModule>::generate");
+        let fallback_module_id = m
+          .get_dependencies()
+          .iter()
+          .find(|dep| {
+            module_graph
+              .dependency_by_id(dep)
+              .and_then(|dep| dep.downcast_ref::<FallbackItemDependency>())
+              .is_some()
+          })
+          .map(|dep| {
+            let fallback_module = module_graph
+              .get_module_by_dependency_id(dep)
+              .expect("should have fallback module");
+            compilation
+              .chunk_graph
+              .get_module_id(fallback_module.identifier())
+              .as_deref()
+              .expect("should have module_id at <RemoteRuntimeModule as RuntimeModule>::generate")
+          });
         remotes.push(id.to_string());
         id_to_remote_data_mapping.insert(
           id,
@@ -81,6 +100,7 @@ impl RuntimeModule for RemoteRuntimeModule {
             name,
             external_module_id,
             remote_name: &m.remote_key,
+            fallback: fallback_module_id,
           },
         );
       }
@@ -125,4 +145,6 @@ struct RemoteData<'a> {
   name: &'a str,
   external_module_id: &'a str,
   remote_name: &'a str,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  fallback: Option<&'a str>,
 }
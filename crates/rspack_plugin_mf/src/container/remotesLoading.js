@@ -10,6 +10,13 @@ __webpack_require__.f.remotes = function (chunkId, promises) {
 			getScope.push(data);
 			if (data.p) return promises.push(data.p);
 			var onError = function (error) {
+				if (data.fallback !== undefined) {
+					__webpack_require__.m[id] = function (module) {
+						module.exports = __webpack_require__(data.fallback);
+					};
+					data.p = 1;
+					return;
+				}
 				if (!error) error = new Error("Container missing");
 				if (typeof error.message === "string")
 					error.message +=
@@ -6,7 +6,11 @@ mod sharing;
 
 pub use container::container_plugin::{ContainerPlugin, ContainerPluginOptions, ExposeOptions};
 pub use container::container_reference_plugin::{
-  ContainerReferencePlugin, ContainerReferencePluginOptions, RemoteOptions,
+  ContainerReferencePlugin, ContainerReferencePluginOptions, RemoteFallback, RemoteOptions,
+};
+pub use container::module_federation_manifest_plugin::{
+  ManifestRemoteEntry, ManifestRemoteInfo, ModuleFederationManifestPlugin,
+  ModuleFederationManifestPluginOptions,
 };
 pub use container::module_federation_runtime_plugin::ModuleFederationRuntimePlugin;
 pub use sharing::consume_shared_plugin::{
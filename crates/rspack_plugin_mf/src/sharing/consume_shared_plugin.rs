@@ -209,7 +209,7 @@ impl ConsumeSharedPlugin {
     mut add_diagnostic: impl FnMut(Diagnostic),
   ) -> Option<ConsumeVersion> {
     let mut required_version_warning = |details: &str| {
-      add_diagnostic(Diagnostic::warn(self.name().into(), format!("No required version specified and unable to automatically determine one. {details} file: shared module {request}")))
+      add_diagnostic(Diagnostic::warn(self.name().into(), format!("No required version specified and unable to automatically determine one. {details} file: shared module {request}")).with_diagnostic_code("SHARED_MODULE_NO_REQUIRED_VERSION"))
     };
     if let Some(version) = config.required_version.as_ref() {
       Some(version.clone())
@@ -276,10 +276,13 @@ impl ConsumeSharedPlugin {
             import,
           )
           .map_err(|_e| {
-            add_diagnostic(Diagnostic::error(
-              "ModuleNotFoundError".into(),
-              format!("resolving fallback for shared module {request}"),
-            ))
+            add_diagnostic(
+              Diagnostic::error(
+                "ModuleNotFoundError".into(),
+                format!("resolving fallback for shared module {request}"),
+              )
+              .with_diagnostic_code("MODULE_NOT_FOUND"),
+            )
           })
           .ok()
       })
@@ -14,7 +14,7 @@ var parseRange = function(str) {
     }
 
     // remove trailing any matchers
-    let last = ver[ver.length - 1];
+    var last = ver[ver.length - 1];
     while (
       ver.length &&
       (last === undefined || /^[*xX]$/.test(/** @type {string} */ (last)))
@@ -47,9 +47,9 @@ var parseRange = function(str) {
     // primitive    ::= ( '<' | '>' | '>=' | '<=' | '=' | '!' ) ( ' ' ) * partial
     // tilde        ::= '~' ( ' ' ) * partial
     // caret        ::= '^' ( ' ' ) * partial
-    const match = /^(\^|~|<=|<|>=|>|=|v|!)/.exec(str);
-    const start = match ? match[0] : "";
-    const remainder = parsePartial(
+    var match = /^(\^|~|<=|<|>=|>|=|v|!)/.exec(str);
+    var start = match ? match[0] : "";
+    var remainder = parsePartial(
       start.length ? str.slice(start.length).trim() : str.trim()
     );
     switch (start) {
@@ -71,55 +71,57 @@ var parseRange = function(str) {
         return toFixed(remainder);
       case "<":
         return negate(remainder);
-      case ">": {
+      case ">":
         // and( >=, not( = ) ) => >=, =, not, and
-        const fixed = toFixed(remainder);
-        return [, fixed, 0, remainder, 2];
-      }
+        return [, toFixed(remainder), 0, remainder, 2];
       case "<=":
         // or( <, = ) => <, =, or
         return [, toFixed(remainder), negate(remainder), 1];
-      case "!": {
+      case "!":
         // not =
-        const fixed = toFixed(remainder);
-        return [, fixed, 0];
-      }
+        return [, toFixed(remainder), 0];
       default:
         throw new Error("Unexpected start value");
     }
   };
   var combine = function(items, fn) {
     if (items.length === 1) return items[0];
-    const arr = [];
-    for (const item of items.slice().reverse()) {
+    var arr = [];
+    for (var i = items.length - 1; i >= 0; i--) {
+      var item = items[i];
       if (0 in item) {
         arr.push(item);
       } else {
         arr.push.apply(arr, item.slice(1));
       }
     }
-    return [,].concat(arr, items.slice(1).map(() => fn));
+    return [,].concat(arr, items.slice(1).map(function() {
+      return fn;
+    }));
   };
   var parseRange = function(str) {
     // range      ::= hyphen | simple ( ' ' ( ' ' ) * simple ) * | ''
     // hyphen     ::= partial ( ' ' ) * ' - ' ( ' ' ) * partial
-    const items = str.split(/\s+-\s+/);
+    var items = str.split(/\s+-\s+/);
     if (items.length === 1) {
-      const items = str
+      // split at the whitespace following a version, but not at the
+      // whitespace following an operator, without a lookbehind assertion
+      var simples = str
         .trim()
-        .split(/(?<=[-0-9A-Za-z])\s+/g)
+        .replace(/([-0-9A-Za-z])\s+/g, "$1\0")
+        .split("\0")
         .map(parseSimple);
-      return combine(items, 2);
+      return combine(simples, 2);
     }
-    const a = parsePartial(items[0]);
-    const b = parsePartial(items[1]);
+    var a = parsePartial(items[0]);
+    var b = parsePartial(items[1]);
     // >=a <=b => and( >=a, or( <b, =b ) ) => >=a, <b, =b, or, and
     return [, toFixed(b), negate(b), 1, a, 2];
   };
   var parseLogicalOr = function(str) {
     // range-set  ::= range ( logical-or range ) *
     // logical-or ::= ( ' ' ) * '||' ( ' ' ) *
-    const items = str.split(/\s*\|\|\s*/).map(parseRange);
+    var items = str.split(/\s*\|\|\s*/).map(parseRange);
     return combine(items, 1);
   };
   return parseLogicalOr(str);
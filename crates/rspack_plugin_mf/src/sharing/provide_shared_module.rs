@@ -68,6 +68,14 @@ impl ProvideSharedModule {
       source_map_kind: SourceMapKind::empty(),
     }
   }
+
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  pub fn version(&self) -> &ProvideVersion {
+    &self.version
+  }
 }
 
 impl Identifiable for ProvideSharedModule {
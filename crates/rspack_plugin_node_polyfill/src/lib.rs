@@ -0,0 +1,179 @@
+// https://github.com/Richienb/node-polyfill-webpack-plugin
+
+use rspack_core::{
+  AliasMap, ApplyContext, CompilerOptions, ModuleFactoryCreateData,
+  NormalModuleFactoryBeforeResolve, Plugin, PluginContext, Resolve,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+/// The node core modules and their browser polyfill packages, a trailing
+/// slash resolves the package instead of the core module of the same name
+const NODE_POLYFILLS: &[(&str, &str)] = &[
+  ("assert", "assert/"),
+  ("buffer", "buffer/"),
+  ("console", "console-browserify"),
+  ("constants", "constants-browserify"),
+  ("crypto", "crypto-browserify"),
+  ("domain", "domain-browser"),
+  ("events", "events/"),
+  ("http", "stream-http"),
+  ("https", "https-browserify"),
+  ("os", "os-browserify/browser.js"),
+  ("path", "path-browserify"),
+  ("punycode", "punycode/"),
+  ("process", "process/browser.js"),
+  ("querystring", "querystring-es3"),
+  ("stream", "stream-browserify"),
+  ("_stream_duplex", "readable-stream/lib/_stream_duplex.js"),
+  (
+    "_stream_passthrough",
+    "readable-stream/lib/_stream_passthrough.js",
+  ),
+  (
+    "_stream_readable",
+    "readable-stream/lib/_stream_readable.js",
+  ),
+  (
+    "_stream_transform",
+    "readable-stream/lib/_stream_transform.js",
+  ),
+  (
+    "_stream_writable",
+    "readable-stream/lib/_stream_writable.js",
+  ),
+  ("string_decoder", "string_decoder/"),
+  ("sys", "util/"),
+  ("timers", "timers-browserify"),
+  ("tty", "tty-browserify"),
+  ("url", "url/"),
+  ("util", "util/"),
+  ("vm", "vm-browserify"),
+  ("zlib", "browserify-zlib"),
+];
+
+#[derive(Debug)]
+pub struct NodePolyfillRspackPluginOptions {
+  /// Only polyfill these modules, all the modules are polyfilled if it's not set.
+  pub include_aliases: Option<Vec<String>>,
+  /// The modules which are not polyfilled.
+  pub exclude_aliases: Vec<String>,
+  /// Which of the node globals are provided.
+  pub globals: NodePolyfillGlobals,
+}
+
+#[derive(Debug)]
+pub struct NodePolyfillGlobals {
+  /// Provide `process` with the `process` polyfill.
+  pub process: bool,
+  /// Provide `Buffer` with the `buffer` polyfill.
+  pub buffer: bool,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct NodePolyfillRspackPlugin {
+  /// module name -> polyfill request
+  polyfills: Vec<(&'static str, &'static str)>,
+  globals: NodePolyfillGlobals,
+}
+
+impl NodePolyfillRspackPlugin {
+  pub fn new(options: NodePolyfillRspackPluginOptions) -> Self {
+    let polyfills = NODE_POLYFILLS
+      .iter()
+      .filter(|(name, _)| {
+        options
+          .include_aliases
+          .as_ref()
+          .map_or(true, |include| include.iter().any(|i| i == name))
+          && !options.exclude_aliases.iter().any(|e| e == name)
+      })
+      .copied()
+      .collect();
+    Self::new_inner(polyfills, options.globals)
+  }
+
+  fn get_polyfill(&self, module_name: &str) -> Option<&'static str> {
+    self
+      .polyfills
+      .iter()
+      .find(|(name, _)| *name == module_name)
+      .map(|(_, polyfill)| *polyfill)
+  }
+}
+
+/// The globals are provided even if their modules are excluded from the aliases
+fn get_global_polyfill(module_name: &str) -> &'static str {
+  NODE_POLYFILLS
+    .iter()
+    .find(|(name, _)| *name == module_name)
+    .map(|(_, polyfill)| *polyfill)
+    .expect("should have the polyfill of the global")
+}
+
+#[plugin_hook(NormalModuleFactoryBeforeResolve for NodePolyfillRspackPlugin)]
+async fn nmf_before_resolve(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<bool>> {
+  let Some(request) = data.request() else {
+    return Ok(None);
+  };
+  let module_name = request.split('/').next().unwrap_or(request);
+  let Some(polyfill) = self.get_polyfill(module_name.trim_start_matches("node:")) else {
+    return Ok(None);
+  };
+  // the polyfill is a resolve fallback, so the installed packages of the same
+  // name and the `resolve.alias` still take precedence
+  let fallback = Resolve {
+    fallback: Some(vec![(
+      module_name.to_string(),
+      vec![AliasMap::Path(polyfill.to_string())],
+    )]),
+    ..Default::default()
+  };
+  data.resolve_options = Some(Box::new(match data.resolve_options.take() {
+    Some(resolve) => resolve.merge(fallback),
+    None => fallback,
+  }));
+  Ok(None)
+}
+
+impl Plugin for NodePolyfillRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.NodePolyfillRspackPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    options: &mut CompilerOptions,
+  ) -> Result<()> {
+    // inject the `Buffer` and `process` globals used by the polyfills, the
+    // provided modules are only added to the modules referencing the globals
+    if self.globals.buffer {
+      options
+        .builtins
+        .provide
+        .entry("Buffer".to_string())
+        .or_insert_with(|| {
+          vec![
+            get_global_polyfill("buffer").to_string(),
+            "Buffer".to_string(),
+          ]
+        });
+    }
+    if self.globals.process {
+      options
+        .builtins
+        .provide
+        .entry("process".to_string())
+        .or_insert_with(|| vec![get_global_polyfill("process").to_string()]);
+    }
+
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .before_resolve
+      .tap(nmf_before_resolve::new(self));
+    Ok(())
+  }
+}
@@ -1,10 +1,11 @@
 use std::cmp::Ordering;
-use std::sync::atomic::Ordering::Relaxed;
-use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering::Relaxed};
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use std::{cmp, sync::atomic::AtomicU32, time::Instant};
 
 use async_trait::async_trait;
+use derivative::Derivative;
 use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
 use linked_hash_map::LinkedHashMap as HashMap;
 use rspack_core::{
@@ -20,11 +21,20 @@ use rspack_core::{
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
 
-#[derive(Debug, Clone, Default)]
+/// The time spent in and the calls of each hook tap, keyed by the hook and the tap name
+pub type HookTimingsBaseline = HashMap<(&'static str, &'static str), (Duration, u32)>;
+
+pub type HandlerFn = Arc<dyn Fn(f64, String, Vec<String>) -> Result<()> + Send + Sync>;
+
+#[derive(Derivative, Clone, Default)]
+#[derivative(Debug)]
 pub struct ProgressPluginOptions {
   // the prefix name of progress bar
   pub prefix: String,
   pub profile: bool,
+  // replaces the progress bar and the profile output when provided
+  #[derivative(Debug = "ignore")]
+  pub handler: Option<HandlerFn>,
 }
 
 #[derive(Debug)]
@@ -45,6 +55,9 @@ pub struct ProgressPlugin {
   pub last_modules_count: RwLock<Option<u32>>,
   pub last_active_module: RwLock<Option<ModuleIdentifier>>,
   pub last_state_info: RwLock<Vec<ProgressPluginStateInfo>>,
+  /// Whether the hook timing is enabled by this plugin for the profile output
+  pub hook_timing_enabled: AtomicBool,
+  pub hook_timings_baseline: RwLock<HookTimingsBaseline>,
 }
 
 impl ProgressPlugin {
@@ -69,35 +82,43 @@ impl ProgressPlugin {
       Default::default(),
       Default::default(),
       Default::default(),
+      AtomicBool::new(false),
+      Default::default(),
     )
   }
 
-  fn update(&self) {
+  fn update(&self) -> Result<()> {
     let modules_done = self.modules_done.load(Relaxed);
+    let modules_count = self.modules_count.load(Relaxed);
     let percent_by_module = (modules_done as f32)
       / (cmp::max(
         self.last_modules_count.read().expect("TODO:").unwrap_or(1),
-        self.modules_count.load(Relaxed),
+        modules_count,
       ) as f32);
 
     let mut items = vec![];
-    let last_active_module = self.last_active_module.read().expect("TODO:");
+    let last_active_module = *self.last_active_module.read().expect("TODO:");
+    let active_modules = self.active_modules.read().expect("TODO:");
 
-    if let Some(last_active_module) = *last_active_module {
+    items.push(format!("{modules_done}/{modules_count} modules"));
+    items.push(format!("{} active", active_modules.len()));
+
+    // the module is the last item, so its duration is the time spent in building it
+    let mut duration = None;
+    if let Some(last_active_module) = last_active_module {
       items.push(last_active_module.to_string());
-      let duration = self
-        .active_modules
-        .read()
-        .expect("TODO:")
+      duration = active_modules
         .get(&last_active_module)
         .map(|time| Instant::now() - *time);
-      self.handler(
-        0.1 + percent_by_module * 0.55,
-        String::from("building"),
-        items,
-        duration,
-      );
     }
+    drop(active_modules);
+
+    self.handler(
+      0.1 + percent_by_module * 0.55,
+      String::from("building"),
+      items,
+      duration,
+    )
   }
 
   pub fn handler(
@@ -106,12 +127,16 @@ impl ProgressPlugin {
     msg: String,
     state_items: Vec<String>,
     time: Option<Duration>,
-  ) {
+  ) -> Result<()> {
+    if let Some(handler) = &self.options.handler {
+      return handler(percent as f64, msg, state_items);
+    }
     if self.options.profile {
       self.default_handler(percent, msg, state_items, time);
     } else {
       self.progress_bar_handler(percent, msg, state_items);
     }
+    Ok(())
   }
 
   fn default_handler(&self, _: f32, msg: String, items: Vec<String>, duration: Option<Duration>) {
@@ -144,16 +169,9 @@ impl ProgressPlugin {
           };
 
           if diff > 5 {
-            // TODO: color map
-            let mut color = "\x1b[32m";
-            if diff > 10000 {
-              color = "\x1b[31m"
-            } else if diff > 1000 {
-              color = "\x1b[33m"
-            }
             println!(
               "{}{} {} ms {}\x1B[0m",
-              color,
+              profile_color(diff),
               " | ".repeat(i),
               diff,
               report_state
@@ -181,6 +199,59 @@ impl ProgressPlugin {
     }
   }
 
+  fn start_hook_timing(&self) {
+    if !rspack_hook::is_hook_timing_enabled() {
+      rspack_hook::enable_hook_timing(true);
+      self.hook_timing_enabled.store(true, Relaxed);
+    }
+    *self.hook_timings_baseline.write().expect("TODO:") = rspack_hook::hook_timings()
+      .into_iter()
+      .map(|timing| ((timing.hook, timing.tap), (timing.duration, timing.calls)))
+      .collect();
+  }
+
+  /// Prints the time spent in the hooks of each plugin since `start_hook_timing`
+  fn print_hook_timings(&self) {
+    let baseline = std::mem::take(&mut *self.hook_timings_baseline.write().expect("TODO:"));
+    // the timings are only taken when they are not recorded for someone else
+    let timings = if self.hook_timing_enabled.swap(false, Relaxed) {
+      rspack_hook::enable_hook_timing(false);
+      rspack_hook::take_hook_timings()
+    } else {
+      rspack_hook::hook_timings()
+    };
+
+    let mut plugins: HashMap<&'static str, (Duration, u32)> = HashMap::new();
+    for timing in timings {
+      let (duration, calls) = baseline
+        .get(&(timing.hook, timing.tap))
+        .copied()
+        .unwrap_or_default();
+      // the tap name is `Plugin::hook_fn`
+      let plugin = timing.tap.split("::").next().unwrap_or(timing.tap);
+      let entry = plugins.entry(plugin).or_insert((Duration::ZERO, 0));
+      entry.0 += timing.duration.saturating_sub(duration);
+      entry.1 += timing.calls.saturating_sub(calls);
+    }
+
+    let mut plugins = plugins.into_iter().collect::<Vec<_>>();
+    plugins.sort_by(|(a_plugin, (a, _)), (b_plugin, (b, _))| {
+      b.cmp(a).then_with(|| a_plugin.cmp(b_plugin))
+    });
+    for (plugin, (duration, calls)) in plugins {
+      let diff = duration.as_millis();
+      if diff > 5 {
+        println!(
+          "{} {} ms plugins > {} ({} calls)\x1B[0m",
+          profile_color(diff),
+          diff,
+          plugin,
+          calls
+        );
+      }
+    }
+  }
+
   fn progress_bar_handler(&self, percent: f32, msg: String, state_items: Vec<String>) {
     self
       .progress_bar
@@ -188,14 +259,24 @@ impl ProgressPlugin {
     self.progress_bar.set_position((percent * 100.0) as u64);
   }
 
-  fn sealing_hooks_report(&self, name: &str, index: i32) {
+  fn sealing_hooks_report(&self, name: &str, index: i32) -> Result<()> {
     let number_of_sealing_hooks = 38;
     self.handler(
-      0.7 + 0.25 * (index / number_of_sealing_hooks) as f32,
+      0.7 + 0.25 * (index as f32 / number_of_sealing_hooks as f32),
       "sealing".to_string(),
       vec![name.to_string()],
       None,
-    );
+    )
+  }
+}
+
+fn profile_color(diff: u128) -> &'static str {
+  if diff > 10000 {
+    "\x1b[31m"
+  } else if diff > 1000 {
+    "\x1b[33m"
+  } else {
+    "\x1b[32m"
   }
 }
 
@@ -205,12 +286,15 @@ async fn this_compilation(
   _compilation: &mut Compilation,
   _params: &mut CompilationParams,
 ) -> Result<()> {
+  if self.options.profile && self.options.handler.is_none() {
+    self.start_hook_timing();
+  }
   self.handler(
     0.08,
     "setup".to_string(),
     vec!["compilation".to_string()],
     None,
-  );
+  )?;
   Ok(())
 }
 
@@ -225,19 +309,21 @@ async fn compilation(
     "setup".to_string(),
     vec!["compilation".to_string()],
     None,
-  );
+  )?;
   Ok(())
 }
 
 #[plugin_hook(CompilerMake for ProgressPlugin)]
 async fn make(&self, _compilation: &mut Compilation) -> Result<()> {
-  if !self.options.profile {
+  if !self.options.profile && self.options.handler.is_none() {
     self.progress_bar.reset();
     self.progress_bar.set_prefix(self.options.prefix.clone());
   }
-  self.handler(0.01, String::from("make"), vec![], None);
+  self.handler(0.01, String::from("make"), vec![], None)?;
   self.modules_count.store(0, Relaxed);
   self.modules_done.store(0, Relaxed);
+  self.active_modules.write().expect("TODO:").clear();
+  self.last_active_module.write().expect("TODO:").take();
   Ok(())
 }
 
@@ -255,7 +341,7 @@ async fn build_module(&self, module: &mut BoxModule) -> Result<()> {
     .expect("TODO:")
     .replace(module.identifier());
   if !self.options.profile {
-    self.update();
+    self.update()?;
   }
   Ok(())
 }
@@ -271,9 +357,9 @@ async fn succeed_module(&self, module: &mut BoxModule) -> Result<()> {
 
   // only profile mode should update at succeed module
   if self.options.profile {
-    self.update();
+    self.update()?;
   }
-  let mut last_active_module = Default::default();
+  let mut last_active_module = None;
   {
     let mut active_modules = self.active_modules.write().expect("TODO:");
     active_modules.remove(&module.identifier());
@@ -281,109 +367,109 @@ async fn succeed_module(&self, module: &mut BoxModule) -> Result<()> {
     // get the last active module
     if !self.options.profile {
       active_modules.iter().for_each(|(module, _)| {
-        last_active_module = *module;
+        last_active_module = Some(*module);
       });
     }
   }
   if !self.options.profile {
-    self
-      .last_active_module
-      .write()
-      .expect("TODO:")
-      .replace(last_active_module);
-    if !last_active_module.is_empty() {
-      self.update();
-    }
+    *self.last_active_module.write().expect("TODO:") = last_active_module;
+    self.update()?;
   }
   Ok(())
 }
 
 #[plugin_hook(CompilerFinishMake for ProgressPlugin)]
 async fn finish_make(&self, _compilation: &mut Compilation) -> Result<()> {
+  // used to estimate the percentage of the next rebuild
+  self
+    .last_modules_count
+    .write()
+    .expect("TODO:")
+    .replace(self.modules_count.load(Relaxed));
   self.handler(
     0.69,
     "building".to_string(),
     vec!["finish make".to_string()],
     None,
-  );
+  )?;
   Ok(())
 }
 
 #[plugin_hook(CompilationSeal for ProgressPlugin)]
 fn seal(&self, _compilation: &mut Compilation) -> Result<()> {
-  self.sealing_hooks_report("plugins", 1);
+  self.sealing_hooks_report("plugins", 1)?;
   Ok(())
 }
 
 #[plugin_hook(CompilationOptimizeDependencies for ProgressPlugin)]
 fn optimize_dependencies(&self, _compilation: &mut Compilation) -> Result<Option<bool>> {
-  self.sealing_hooks_report("dependencies", 2);
+  self.sealing_hooks_report("dependencies", 2)?;
   Ok(None)
 }
 
 #[plugin_hook(CompilationFinishModules for ProgressPlugin)]
 async fn finish_modules(&self, _compilation: &mut Compilation) -> Result<()> {
-  self.sealing_hooks_report("finish modules", 0);
+  self.sealing_hooks_report("finish modules", 0)?;
   Ok(())
 }
 
 #[plugin_hook(CompilationOptimizeModules for ProgressPlugin)]
 async fn optimize_modules(&self, _compilation: &mut Compilation) -> Result<Option<bool>> {
-  self.sealing_hooks_report("module optimization", 7);
+  self.sealing_hooks_report("module optimization", 7)?;
   Ok(None)
 }
 
 #[plugin_hook(CompilationAfterOptimizeModules for ProgressPlugin)]
 async fn after_optimize_modules(&self, _compilation: &mut Compilation) -> Result<()> {
-  self.sealing_hooks_report("after module optimization", 8);
+  self.sealing_hooks_report("after module optimization", 8)?;
   Ok(())
 }
 
 #[plugin_hook(CompilationOptimizeChunks for ProgressPlugin)]
 fn optimize_chunks(&self, _compilation: &mut Compilation) -> Result<Option<bool>> {
-  self.sealing_hooks_report("chunk optimization", 9);
+  self.sealing_hooks_report("chunk optimization", 9)?;
   Ok(None)
 }
 
 #[plugin_hook(CompilationOptimizeTree for ProgressPlugin)]
 async fn optimize_tree(&self, _compilation: &mut Compilation) -> Result<()> {
-  self.sealing_hooks_report("module and chunk tree optimization", 11);
+  self.sealing_hooks_report("module and chunk tree optimization", 11)?;
   Ok(())
 }
 
 #[plugin_hook(CompilationOptimizeChunkModules for ProgressPlugin)]
 async fn optimize_chunk_modules(&self, _compilation: &mut Compilation) -> Result<Option<bool>> {
-  self.sealing_hooks_report("chunk modules optimization", 13);
+  self.sealing_hooks_report("chunk modules optimization", 13)?;
   Ok(None)
 }
 
 #[plugin_hook(CompilationModuleIds for ProgressPlugin)]
 fn module_ids(&self, _modules: &mut Compilation) -> Result<()> {
-  self.sealing_hooks_report("module ids", 16);
+  self.sealing_hooks_report("module ids", 16)?;
   Ok(())
 }
 
 #[plugin_hook(CompilationChunkIds for ProgressPlugin)]
 fn chunk_ids(&self, _compilation: &mut Compilation) -> Result<()> {
-  self.sealing_hooks_report("chunk ids", 21);
+  self.sealing_hooks_report("chunk ids", 21)?;
   Ok(())
 }
 
 #[plugin_hook(CompilationProcessAssets for ProgressPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
 async fn process_assets(&self, _compilation: &mut Compilation) -> Result<()> {
-  self.sealing_hooks_report("asset processing", 35);
+  self.sealing_hooks_report("asset processing", 35)?;
   Ok(())
 }
 
 #[plugin_hook(CompilationAfterProcessAssets for ProgressPlugin)]
 async fn after_process_assets(&self, _compilation: &mut Compilation) -> Result<()> {
-  self.sealing_hooks_report("after asset optimization", 36);
+  self.sealing_hooks_report("after asset optimization", 36)?;
   Ok(())
 }
 
 #[plugin_hook(CompilerEmit for ProgressPlugin)]
 async fn emit(&self, _compilation: &mut Compilation) -> Result<()> {
-  self.handler(0.98, "emitting".to_string(), vec!["emit".to_string()], None);
+  self.handler(0.98, "emitting".to_string(), vec!["emit".to_string()], None)?;
   Ok(())
 }
 
@@ -394,7 +480,10 @@ async fn after_emit(&self, _compilation: &mut Compilation) -> Result<()> {
     "emitting".to_string(),
     vec!["after emit".to_string()],
     None,
-  );
+  )?;
+  if self.options.profile && self.options.handler.is_none() {
+    self.print_hook_timings();
+  }
   Ok(())
 }
 
@@ -0,0 +1,62 @@
+use rspack_core::{
+  AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory, DependencyId,
+  DependencyType, ModuleDependency,
+};
+
+/// The dependencies of a lazy compilation proxy module, on the client which
+/// keeps the proxied module active, or on the proxied module itself.
+#[derive(Debug, Clone)]
+pub struct LazyCompilationDependency {
+  id: DependencyId,
+  request: String,
+  dependency_type: DependencyType,
+  category: DependencyCategory,
+}
+
+impl LazyCompilationDependency {
+  pub fn client(request: String) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      dependency_type: DependencyType::CjsRequire,
+      category: DependencyCategory::CommonJS,
+    }
+  }
+
+  /// Depends on the proxied module, the dependency is never proxied again.
+  pub fn original(request: String, category: DependencyCategory) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      dependency_type: DependencyType::LazyImport,
+      category,
+    }
+  }
+}
+
+impl Dependency for LazyCompilationDependency {
+  fn dependency_debug_name(&self) -> &'static str {
+    "LazyCompilationDependency"
+  }
+
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &self.dependency_type
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &self.category
+  }
+}
+
+impl ModuleDependency for LazyCompilationDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+}
+
+impl AsContextDependency for LazyCompilationDependency {}
+impl AsDependencyTemplate for LazyCompilationDependency {}
@@ -0,0 +1,193 @@
+mod dependency;
+mod module;
+
+use std::fmt;
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_core::{
+  ApplyContext, BoxModule, Compilation, CompilationParams, CompilerCompilation, CompilerOptions,
+  DependencyType, Module, ModuleFactoryCreateData, NormalModuleCreateData,
+  NormalModuleFactoryModule, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_regex::RspackRegex;
+
+pub use self::dependency::LazyCompilationDependency;
+pub use self::module::LazyCompilationProxyModule;
+
+/// The hot clients of the dev server should never be proxied.
+static HOT_CLIENT: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(
+    r"[/\\](webpack|@rspack[/\\]core)[/\\]hot[/\\]|webpack-dev-server[/\\]client|webpack-hot-middleware[/\\]client",
+  )
+  .expect("should be a valid regex")
+});
+
+pub struct LazyCompilationModuleArgs {
+  pub module: String,
+  pub path: String,
+}
+
+/// How a proxy module communicates with the backend.
+pub struct LazyCompilationModuleInfo {
+  /// Whether the proxied module has been requested.
+  pub active: bool,
+  /// The key of the module passed to the client.
+  pub data: String,
+  /// The request of the client module.
+  pub client: String,
+}
+
+pub type LazyCompilationBackend = Box<
+  dyn Fn(LazyCompilationModuleArgs) -> BoxFuture<'static, Result<LazyCompilationModuleInfo>>
+    + Sync
+    + Send,
+>;
+
+pub type LazyCompilationTestFn =
+  Box<dyn for<'a> Fn(&'a dyn Module) -> BoxFuture<'a, Result<bool>> + Sync + Send>;
+
+pub enum LazyCompilationTest {
+  Regex(RspackRegex),
+  Fn(LazyCompilationTestFn),
+}
+
+impl fmt::Debug for LazyCompilationTest {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Regex(regex) => write!(f, "LazyCompilationTest::Regex({regex:?})"),
+      Self::Fn(_) => write!(f, "LazyCompilationTest::Fn(...)"),
+    }
+  }
+}
+
+pub struct LazyCompilationOptions {
+  pub backend: LazyCompilationBackend,
+  /// Compiles the entries lazily.
+  pub entries: bool,
+  /// Compiles the dynamic imports lazily.
+  pub imports: bool,
+  /// Only the modules matching the test are compiled lazily.
+  pub test: Option<LazyCompilationTest>,
+}
+
+impl fmt::Debug for LazyCompilationOptions {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("LazyCompilationOptions")
+      .field("entries", &self.entries)
+      .field("imports", &self.imports)
+      .field("test", &self.test)
+      .finish_non_exhaustive()
+  }
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct LazyCompilationPlugin {
+  options: LazyCompilationOptions,
+}
+
+impl LazyCompilationPlugin {
+  pub fn new(options: LazyCompilationOptions) -> Self {
+    Self::new_inner(options)
+  }
+
+  async fn should_proxy(
+    &self,
+    data: &ModuleFactoryCreateData,
+    create_data: &NormalModuleCreateData,
+    module: &dyn Module,
+  ) -> Result<bool> {
+    let should_proxy = match data.dependency.dependency_type() {
+      DependencyType::DynamicImport => self.options.imports,
+      DependencyType::Entry => self.options.entries,
+      _ => false,
+    };
+    if !should_proxy || HOT_CLIENT.is_match(&create_data.resource_resolve_data.resource) {
+      return Ok(false);
+    }
+    match &self.options.test {
+      Some(LazyCompilationTest::Regex(regex)) => Ok(
+        module
+          .name_for_condition()
+          .is_some_and(|name| regex.test(&name)),
+      ),
+      Some(LazyCompilationTest::Fn(test)) => test(module).await,
+      None => Ok(true),
+    }
+  }
+}
+
+#[plugin_hook(CompilerCompilation for LazyCompilationPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::LazyImport,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+#[plugin_hook(NormalModuleFactoryModule for LazyCompilationPlugin)]
+async fn normal_module_factory_module(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  create_data: &mut NormalModuleCreateData,
+  module: &mut BoxModule,
+) -> Result<()> {
+  if !self
+    .should_proxy(data, create_data, module.as_ref())
+    .await?
+  {
+    return Ok(());
+  }
+  let resource = create_data.resource_resolve_data.resource_path.clone();
+  let info = (self.options.backend)(LazyCompilationModuleArgs {
+    module: module.identifier().to_string(),
+    path: resource.to_string_lossy().to_string(),
+  })
+  .await?;
+  *module = Box::new(LazyCompilationProxyModule::new(
+    module.as_ref(),
+    data.context.clone(),
+    create_data.raw_request.clone(),
+    *data.dependency.category(),
+    resource,
+    info.client,
+    info.data,
+    info.active,
+  ));
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for LazyCompilationPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.LazyCompilationPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .module
+      .tap(normal_module_factory_module::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,292 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use rspack_core::{
+  impl_module_meta_info, impl_source_map_config, module_namespace_promise, module_raw,
+  rspack_sources::{RawSource, Source, SourceExt},
+  AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, BoxDependency, BuildContext, BuildInfo,
+  BuildMeta, BuildResult, CodeGenerationResult, Compilation, ConcatenationScope, Context,
+  DependenciesBlock, DependencyCategory, DependencyId, FactoryMeta, Module, ModuleIdentifier,
+  ModuleType, RuntimeGlobals, RuntimeSpec, SourceType, TemplateContext,
+};
+use rspack_error::{impl_empty_diagnosable_trait, Diagnostic, Result};
+use rspack_hash::RspackHash;
+use rspack_identifier::Identifiable;
+use rspack_util::source_map::SourceMapKind;
+use rustc_hash::FxHashSet as HashSet;
+
+use super::dependency::LazyCompilationDependency;
+
+/// Takes the place of a module until it's requested by the client, then the
+/// proxied module is built and loaded through an async block.
+#[impl_source_map_config]
+#[derive(Debug)]
+pub struct LazyCompilationProxyModule {
+  blocks: Vec<AsyncDependenciesBlockIdentifier>,
+  dependencies: Vec<DependencyId>,
+  identifier: ModuleIdentifier,
+  original_module: ModuleIdentifier,
+  name_for_condition: Option<Box<str>>,
+  context: Context,
+  request: String,
+  category: DependencyCategory,
+  resource: PathBuf,
+  client: String,
+  data: String,
+  active: bool,
+  factory_meta: Option<FactoryMeta>,
+  build_info: Option<BuildInfo>,
+  build_meta: Option<BuildMeta>,
+}
+
+impl LazyCompilationProxyModule {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    original_module: &dyn Module,
+    context: Context,
+    request: String,
+    category: DependencyCategory,
+    resource: PathBuf,
+    client: String,
+    data: String,
+    active: bool,
+  ) -> Self {
+    Self {
+      blocks: Default::default(),
+      dependencies: Default::default(),
+      identifier: ModuleIdentifier::from(format!(
+        "lazy-compilation-proxy|{}",
+        original_module.identifier()
+      )),
+      original_module: original_module.identifier(),
+      name_for_condition: original_module.name_for_condition(),
+      context,
+      request,
+      category,
+      resource,
+      client,
+      data,
+      active,
+      factory_meta: None,
+      build_info: None,
+      build_meta: None,
+      source_map_kind: SourceMapKind::empty(),
+    }
+  }
+}
+
+impl Identifiable for LazyCompilationProxyModule {
+  fn identifier(&self) -> ModuleIdentifier {
+    self.identifier
+  }
+}
+
+impl DependenciesBlock for LazyCompilationProxyModule {
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.blocks.push(block)
+  }
+
+  fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
+    &self.blocks
+  }
+
+  fn add_dependency_id(&mut self, dependency: DependencyId) {
+    self.dependencies.push(dependency)
+  }
+
+  fn get_dependencies(&self) -> &[DependencyId] {
+    &self.dependencies
+  }
+}
+
+#[async_trait]
+impl Module for LazyCompilationProxyModule {
+  impl_module_meta_info!();
+
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::Js
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    &[SourceType::JavaScript]
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn get_diagnostics(&self) -> Vec<Diagnostic> {
+    vec![]
+  }
+
+  fn readable_identifier(&self, context: &Context) -> Cow<str> {
+    Cow::Owned(format!(
+      "lazy-compilation-proxy {}",
+      context.shorten(&self.original_module)
+    ))
+  }
+
+  fn name_for_condition(&self) -> Option<Box<str>> {
+    self.name_for_condition.clone()
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    200.0
+  }
+
+  fn get_context(&self) -> Option<Box<Context>> {
+    Some(Box::new(self.context.clone()))
+  }
+
+  // the backend invalidates the compiler with the resource of the module
+  // once it's requested, so the proxy is recreated as active
+  fn is_available(&self, modified_file: &HashSet<PathBuf>) -> bool {
+    !modified_file.contains(&self.resource)
+  }
+
+  async fn build(
+    &mut self,
+    build_context: BuildContext<'_>,
+    _: Option<&Compilation>,
+  ) -> Result<BuildResult> {
+    let mut hasher = RspackHash::from(&build_context.compiler_options.output);
+    self.update_hash(&mut hasher);
+
+    let build_info = BuildInfo {
+      strict: true,
+      hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+      ..Default::default()
+    };
+
+    let dependencies: Vec<BoxDependency> = vec![Box::new(LazyCompilationDependency::client(
+      self.client.clone(),
+    ))];
+    let mut blocks = vec![];
+    if self.active {
+      let dep = LazyCompilationDependency::original(self.request.clone(), self.category);
+      blocks.push(AsyncDependenciesBlock::new(
+        self.identifier,
+        None,
+        None,
+        vec![Box::new(dep)],
+      ));
+    }
+
+    Ok(BuildResult {
+      build_info,
+      build_meta: Default::default(),
+      dependencies,
+      blocks,
+      analyze_result: Default::default(),
+      optimization_bailouts: vec![],
+    })
+  }
+
+  fn code_generation(
+    &self,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+    _: Option<ConcatenationScope>,
+  ) -> Result<CodeGenerationResult> {
+    let mut cgr = CodeGenerationResult::default();
+    let mut runtime_requirements = RuntimeGlobals::MODULE;
+    let client = format!(
+      "var client = {};\nvar data = {};",
+      module_raw(
+        compilation,
+        &mut runtime_requirements,
+        &self.dependencies[0],
+        &self.client,
+        false
+      ),
+      serde_json::to_string(&self.data).expect("should stringify the data")
+    );
+    let keep_active = format!(
+      "var dispose = client.keepAlive({{ data: data, active: {}, module: module, onError: onError }});",
+      self.active
+    );
+
+    let source = if let Some(block_id) = self.blocks.first() {
+      let module_graph = compilation.get_module_graph();
+      let block = module_graph
+        .block_by_id(block_id)
+        .expect("should have the block of the proxied module");
+      let dep_id = block.get_dependencies()[0];
+      let original_module_id = module_graph
+        .module_identifier_by_dependency_id(&dep_id)
+        .and_then(|m| compilation.chunk_graph.get_module_id(*m).as_deref())
+        .unwrap_or_default();
+      let mut init_fragments = Vec::new();
+      let mut template_context = TemplateContext {
+        compilation,
+        module: self,
+        runtime_requirements: &mut runtime_requirements,
+        init_fragments: &mut init_fragments,
+        runtime,
+        concatenation_scope: None,
+      };
+      let promise = module_namespace_promise(
+        &mut template_context,
+        &dep_id,
+        Some(block_id),
+        &self.request,
+        "import()",
+        false,
+      );
+      format!(
+        r#"{client}
+module.exports = {promise};
+if (module.hot) {{
+  module.hot.accept();
+  module.hot.accept({}, function() {{ module.hot.invalidate(); }});
+  module.hot.dispose(function(data) {{ delete data.resolveSelf; dispose(data); }});
+  if (module.hot.data && module.hot.data.resolveSelf) module.hot.data.resolveSelf(module.exports);
+}}
+function onError() {{ /* ignore */ }}
+{keep_active}"#,
+        serde_json::to_string(original_module_id).expect("should stringify the module id")
+      )
+    } else {
+      format!(
+        r#"{client}
+var resolveSelf, onError;
+module.exports = new Promise(function(resolve, reject) {{ resolveSelf = resolve; onError = reject; }});
+if (module.hot) {{
+  module.hot.accept();
+  if (module.hot.data && module.hot.data.resolveSelf) module.hot.data.resolveSelf(module.exports);
+  module.hot.dispose(function(data) {{ data.resolveSelf = resolveSelf; dispose(data); }});
+}}
+{keep_active}"#
+      )
+    };
+
+    cgr.runtime_requirements = runtime_requirements;
+    cgr.add(SourceType::JavaScript, RawSource::from(source).boxed());
+    cgr.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(cgr)
+  }
+}
+
+impl_empty_diagnosable_trait!(LazyCompilationProxyModule);
+
+impl Hash for LazyCompilationProxyModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__LazyCompilationProxyModule".hash(state);
+    self.identifier().hash(state);
+    self.active.hash(state);
+  }
+}
+
+impl PartialEq for LazyCompilationProxyModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for LazyCompilationProxyModule {}
@@ -2,7 +2,11 @@
 mod helpers;
 pub use helpers::*;
 mod lazy_compilation;
-pub use lazy_compilation::LazyCompilationPlugin;
+pub use lazy_compilation::{
+  LazyCompilationBackend, LazyCompilationDependency, LazyCompilationModuleArgs,
+  LazyCompilationModuleInfo, LazyCompilationOptions, LazyCompilationPlugin,
+  LazyCompilationProxyModule, LazyCompilationTest, LazyCompilationTestFn,
+};
 mod common_js_chunk_format;
 pub use common_js_chunk_format::CommonJsChunkFormatPlugin;
 mod runtime_plugin;
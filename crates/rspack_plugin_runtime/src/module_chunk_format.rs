@@ -4,7 +4,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use rspack_core::rspack_sources::{ConcatSource, RawSource, SourceExt};
 use rspack_core::{
-  ApplyContext, ChunkKind, ChunkUkey, Compilation, CompilationAdditionalChunkRuntimeRequirements,
+  ApplyContext, ChunkUkey, Compilation, CompilationAdditionalChunkRuntimeRequirements,
   CompilationParams, CompilerCompilation, CompilerOptions, Plugin, PluginContext, RuntimeGlobals,
 };
 use rspack_error::Result;
@@ -55,11 +55,9 @@ impl JavascriptModulesPluginPlugin for ModuleChunkFormatJavascriptModulesPluginP
     let compilation = args.compilation;
     let drive = JsPlugin::get_compilation_drives(compilation);
     let chunk = args.chunk();
-    let base_chunk_output_name = get_chunk_output_name(chunk, compilation)?;
-    if matches!(chunk.kind, ChunkKind::HotUpdate) {
-      unreachable!("HMR is not implemented for module chunk format yet");
-    }
 
+    // hot update chunks share the format of the normal chunks, the `modules`
+    // and `runtime` exports are read by `loadUpdateChunk` after `import()`
     let mut sources = ConcatSource::default();
     sources.add(RawSource::from(format!(
       "export const ids = ['{}'];\n",
@@ -79,6 +77,7 @@ impl JavascriptModulesPluginPlugin for ModuleChunkFormatJavascriptModulesPluginP
     }
 
     if chunk.has_entry_module(&compilation.chunk_graph) {
+      let base_chunk_output_name = get_chunk_output_name(chunk, compilation)?;
       let runtime_chunk_output_name = get_runtime_chunk_output_name(args)?;
       sources.add(RawSource::from(format!(
         "import __webpack_require__ from '{}';\n",
@@ -87,31 +87,33 @@ fn auto_public_path_template(filename: &str, output: &OutputOptions) -> String {
     "#
     .to_string()
   } else {
+    // `self.location` in workers, the current script or the last http(s) script in documents
     format!(
       r#"var scriptUrl;
     if ({global}.importScripts) scriptUrl = {global}.location + "";
     var document = {global}.document;
     if (!scriptUrl && document) {{
-      if (document.currentScript) scriptUrl = document.currentScript.src;
-        if (!scriptUrl) {{
-          var scripts = document.getElementsByTagName("script");
-              if (scripts.length) {{
-                var i = scripts.length - 1;
-                while (i > -1 && !scriptUrl) scriptUrl = scripts[i--].src;
-              }}
+      if (document.currentScript && document.currentScript.tagName.toUpperCase() === 'SCRIPT')
+        scriptUrl = document.currentScript.src;
+      if (!scriptUrl) {{
+        var scripts = document.getElementsByTagName("script");
+        if (scripts.length) {{
+          var i = scripts.length - 1;
+          while (i > -1 && (!scriptUrl || !/^http(s?):/.test(scriptUrl))) scriptUrl = scripts[i--].src;
         }}
       }}
+    }}
     "#
     )
   };
   format!(
     r#"
     {script_url_template}
-    // When supporting browsers where an automatic publicPath is not supported you must specify an output.publicPath manually via configuration",
-    // or pass an empty string ("") and set the __webpack_public_path__ variable from your code to use your own logic.',
+    // When supporting browsers where an automatic publicPath is not supported you must specify an output.publicPath manually via configuration
+    // or pass an empty string ("") and set the __webpack_public_path__ variable from your code to use your own logic.
     if (!scriptUrl) throw new Error("Automatic publicPath is not supported in this browser");
     scriptUrl = scriptUrl.replace(/#.*$/, "").replace(/\?.*$/, "").replace(/\/[^\/]+$/, "/");
-    {assign}
+    {assign};
     "#
   )
 }
@@ -40,4 +40,8 @@ impl RuntimeModule for GetFullHashRuntimeModule {
   fn cacheable(&self) -> bool {
     false
   }
+
+  fn full_hash(&self) -> bool {
+    true
+  }
 }
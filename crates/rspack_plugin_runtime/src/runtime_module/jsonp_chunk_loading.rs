@@ -175,6 +175,14 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
         include_str!("runtime/jsonp_chunk_loading_with_preload.js")
           .replace("$JS_MATCHER$", &js_matcher)
           .replace("$CROSS_ORIGIN$", cross_origin.as_str())
+          .replace(
+            "$CHARSET$",
+            if compilation.options.output.charset {
+              "link.charset = 'utf-8';"
+            } else {
+              ""
+            },
+          )
           .replace("$SCRIPT_TYPE_LINK_PRE$", script_type_link_pre.as_str())
           .replace("$SCRIPT_TYPE_LINK_POST$", script_type_link_post),
       ));
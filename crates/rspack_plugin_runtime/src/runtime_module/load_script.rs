@@ -1,7 +1,8 @@
 use rspack_core::{
   impl_runtime_module,
   rspack_sources::{BoxSource, RawSource, SourceExt},
-  Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
+  ChunkLoading, ChunkLoadingType, Compilation, CompilerOptions, CrossOriginLoading, RuntimeGlobals,
+  RuntimeModule,
 };
 use rspack_identifier::Identifier;
 use rspack_util::source_map::SourceMapKind;
@@ -26,12 +27,45 @@ impl LoadScriptRuntimeModule {
   }
 }
 
+/// Whether scripts are loaded in node.js instead of with a `<script>` tag.
+pub fn is_node_script_loading(options: &CompilerOptions) -> bool {
+  match &options.output.chunk_loading {
+    ChunkLoading::Enable(ChunkLoadingType::Require | ChunkLoadingType::AsyncNode) => true,
+    // chunks are loaded with `import()` when `output.module` is enabled in node.js
+    ChunkLoading::Enable(ChunkLoadingType::Import) => options.target.node,
+    _ => false,
+  }
+}
+
 impl RuntimeModule for LoadScriptRuntimeModule {
   fn name(&self) -> Identifier {
     self.id
   }
 
   fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    // there is no document in node.js, scripts (e.g. remote containers) are read
+    // from the file system or fetched over http(s) and evaluated with `vm`
+    if is_node_script_loading(&compilation.options) {
+      let load_node_require = if compilation.options.output.module {
+        r#"function (callback) {
+	import("module").then(function (m) {
+		callback(m.createRequire(import.meta.url), import.meta.url);
+	});
+}"#
+      } else {
+        r#"function (callback) {
+	callback(require, require("url").pathToFileURL(__filename).href);
+}"#
+      };
+      return Ok(
+        RawSource::from(
+          include_str!("runtime/load_script_node.js")
+            .replace("$LOAD_NODE_REQUIRE$", load_node_require),
+        )
+        .boxed(),
+      );
+    }
+
     let url = if self.with_create_script_url {
       format!("{}(url)", RuntimeGlobals::CREATE_SCRIPT_URL)
     } else {
@@ -72,33 +106,58 @@ impl RuntimeModule for LoadScriptRuntimeModule {
       ))
     };
 
-    Ok(RawSource::from(
-      include_str!("runtime/load_script.js")
-        .replace(
-          "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
-          &cross_origin_loading,
-        )
-        .replace("$URL$", &url)
-        .replace("$SCRIPT_TYPE$", &script_type)
-        .replace(
-          "$UNIQUE_GET_ATTRIBUTE$",
-          match unique_prefix {
-            Some(_) => r#"s.getAttribute("src") == url || s.getAttribute("data-webpack") == dataWebpackPrefix + key"#,
-            None => r#"s.getAttribute("src") == url"#,
-          },
-        )
-        .replace(
-          "$UNIQUE_SET_ATTRIBUTE$",
-          match unique_prefix {
-            Some(_) => r#"script.setAttribute("data-webpack", dataWebpackPrefix + key);"#,
-            None => "",
-          },
-        )
-        .replace(
-          "$UNIQUE_PREFIX$",
-          unique_prefix.unwrap_or_default().as_str(),
-        ),
-    )
-    .boxed())
+    let chunk_load_timeout = compilation.options.output.chunk_load_timeout;
+    let mut source = include_str!("runtime/load_script.js")
+      .replace(
+        "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
+        &cross_origin_loading,
+      )
+      .replace("$URL$", &url)
+      .replace("$SCRIPT_TYPE$", &script_type)
+      .replace(
+        "$CHUNK_LOAD_TIMEOUT_IN_SECONDS$",
+        &(chunk_load_timeout / 1000).to_string(),
+      )
+      .replace("$CHUNK_LOAD_TIMEOUT$", &chunk_load_timeout.to_string())
+      .replace(
+        "$CHARSET$",
+        if compilation.options.output.charset {
+          "script.charset = 'utf-8';"
+        } else {
+          ""
+        },
+      )
+      .replace(
+        "$UNIQUE_GET_ATTRIBUTE$",
+        match unique_prefix {
+          Some(_) => r#"s.getAttribute("src") == url || s.getAttribute("data-webpack") == dataWebpackPrefix + key"#,
+          None => r#"s.getAttribute("src") == url"#,
+        },
+      )
+      .replace(
+        "$UNIQUE_SET_ATTRIBUTE$",
+        match unique_prefix {
+          Some(_) => r#"script.setAttribute("data-webpack", dataWebpackPrefix + key);"#,
+          None => "",
+        },
+      )
+      .replace(
+        "$UNIQUE_PREFIX$",
+        unique_prefix.unwrap_or_default().as_str(),
+      );
+
+    if let Some(retry) = &compilation.options.output.chunk_load_retry {
+      source.push_str(
+        &include_str!("runtime/load_script_with_retry.js")
+          .replace(
+            "$RETRY_PUBLIC_PATHS$",
+            &serde_json::to_string(&retry.public_paths).expect("invalid json to_string"),
+          )
+          .replace("$RETRY_TIMES$", &retry.times.to_string())
+          .replace("$RETRY_DELAY$", &retry.delay.to_string()),
+      );
+    }
+
+    Ok(RawSource::from(source).boxed())
   }
 }
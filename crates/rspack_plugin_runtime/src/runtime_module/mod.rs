@@ -63,7 +63,7 @@ pub use harmony_module_decorator::HarmonyModuleDecoratorRuntimeModule;
 pub use has_own_property::HasOwnPropertyRuntimeModule;
 pub use import_scripts_chunk_loading::ImportScriptsChunkLoadingRuntimeModule;
 pub use jsonp_chunk_loading::JsonpChunkLoadingRuntimeModule;
-pub use load_script::LoadScriptRuntimeModule;
+pub use load_script::{is_node_script_loading, LoadScriptRuntimeModule};
 pub use make_namespace_object::MakeNamespaceObjectRuntimeModule;
 pub use module_chunk_loading::ModuleChunkLoadingRuntimeModule;
 pub use node_module_decorator::NodeModuleDecoratorRuntimeModule;
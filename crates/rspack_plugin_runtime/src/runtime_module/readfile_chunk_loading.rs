@@ -161,9 +161,10 @@ impl RuntimeModule for ReadFileChunkLoadingRuntimeModule {
     }
 
     if with_hmr {
-      source.add(RawSource::from(include_str!(
-        "runtime/readfile_chunk_loading_with_hmr.js"
-      )));
+      source.add(RawSource::from(
+        include_str!("runtime/readfile_chunk_loading_with_hmr.js")
+          .replace("$OUTPUT_DIR$", &root_output_dir),
+      ));
       source.add(RawSource::from(generate_javascript_hmr_runtime(
         "readFileVm",
       )));
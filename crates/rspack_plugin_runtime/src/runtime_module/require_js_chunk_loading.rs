@@ -155,16 +155,18 @@ impl RuntimeModule for RequireChunkLoadingRuntimeModule {
     }
 
     if with_hmr {
-      source.add(RawSource::from(include_str!(
-        "runtime/require_chunk_loading_with_hmr.js"
-      )));
+      source.add(RawSource::from(
+        include_str!("runtime/require_chunk_loading_with_hmr.js")
+          .replace("$OUTPUT_DIR$", &root_output_dir),
+      ));
       source.add(RawSource::from(generate_javascript_hmr_runtime("require")));
     }
 
     if with_hmr_manifest {
-      source.add(RawSource::from(include_str!(
-        "runtime/require_chunk_loading_with_hmr_manifest.js"
-      )));
+      source.add(RawSource::from(
+        include_str!("runtime/require_chunk_loading_with_hmr_manifest.js")
+          .replace("$OUTPUT_DIR$", &root_output_dir),
+      ));
     }
 
     Ok(source.boxed())
@@ -3,7 +3,7 @@ __webpack_require__.H.j = function (chunkId) {
     installedChunks[chunkId] = null;
     var link = document.createElement('link');
     $SCRIPT_TYPE_LINK_PRE$
-    link.charset = 'utf-8';
+    $CHARSET$
     if (__webpack_require__.nc) {
       link.setAttribute("nonce", __webpack_require__.nc);
     }
@@ -0,0 +1,99 @@
+var inProgress = {};
+// node.js modules are required lazily, as `require` is not available in es modules
+var loadNodeRequire = $LOAD_NODE_REQUIRE$;
+// loadScript function to load a script in node.js, the script is read from the
+// file system or fetched over http(s) and evaluated with `vm`
+__webpack_require__.l = function (url, done, key, chunkId) {
+	if (inProgress[url]) {
+		inProgress[url].push(done);
+		return;
+	}
+	inProgress[url] = [done];
+	var onScriptComplete = function (event) {
+		var doneFns = inProgress[url];
+		delete inProgress[url];
+		doneFns &&
+			doneFns.forEach(function (fn) {
+				return fn(event);
+			});
+	};
+	var onError = function (type, error) {
+		onScriptComplete({ type: type, target: { src: url }, error: error });
+	};
+	var run = function (require, content, filename, dirname) {
+		var module = { exports: {} };
+		// scripts assigning a global with `var` would only declare a local variable
+		// inside the wrapper, so return it explicitly
+		var globalName =
+			typeof key === "string" && /^[A-Za-z_$][\w$]*$/.test(key) ? key : undefined;
+		try {
+			var result = require("vm").runInThisContext(
+				"(function(exports, require, module, __filename, __dirname) {" +
+					content +
+					"\n;return " +
+					(globalName
+						? "typeof " + globalName + " !== 'undefined' ? " + globalName + " : undefined"
+						: "undefined") +
+					";\n})",
+				filename
+			)(module.exports, require, module, filename, dirname);
+		} catch (e) {
+			return onError("error", e);
+		}
+		if (key !== undefined && typeof globalThis[key] === "undefined") {
+			var exported = result !== undefined ? result : module.exports;
+			if (exported && Object.keys(exported).length) globalThis[key] = exported;
+		}
+		onScriptComplete({ type: "load", target: { src: url } });
+	};
+	loadNodeRequire(function (require, scriptUrl) {
+		var target;
+		try {
+			// relative urls are resolved against the public path like in browsers
+			target = new URL(url, new URL(__webpack_require__.p, scriptUrl));
+		} catch (e) {
+			return onError("error", e);
+		}
+		if (target.protocol === "file:") {
+			var filename = require("url").fileURLToPath(target);
+			require("fs").readFile(filename, "utf-8", function (err, content) {
+				if (err) return onError("missing", err);
+				run(require, content, filename, require("path").dirname(filename));
+			});
+			return;
+		}
+		if (target.protocol !== "http:" && target.protocol !== "https:") {
+			return onError(
+				"error",
+				new Error(
+					"Loading scripts over " + target.protocol + " is not supported in node.js"
+				)
+			);
+		}
+		require(target.protocol.slice(0, -1))
+			.get(target, function (res) {
+				if (res.statusCode !== 200) {
+					res.resume();
+					return onError(
+						"missing",
+						new Error("Request failed with status code " + res.statusCode)
+					);
+				}
+				var chunks = [];
+				res.on("data", function (chunk) {
+					chunks.push(chunk);
+				});
+				res.on("end", function () {
+					run(
+						require,
+						Buffer.concat(chunks).toString("utf-8"),
+						target.href,
+						new URL(".", target).href
+					);
+				});
+			})
+			.on("error", function (err) {
+				onError("error", err);
+			});
+	});
+};
@@ -0,0 +1,32 @@
+// retry the failed scripts with an exponential backoff, optionally from the alternate public paths
+var loadScriptOnce = __webpack_require__.l;
+var retryPublicPaths = $RETRY_PUBLIC_PATHS$;
+__webpack_require__.l = function (url, done, key, chunkId) {
+	var attempt = 0;
+	var load = function (currentUrl) {
+		loadScriptOnce(
+			currentUrl,
+			function (event) {
+				if (!event || event.type === "load" || attempt >= $RETRY_TIMES$) {
+					return done(event);
+				}
+				var nextUrl = url;
+				if (
+					retryPublicPaths.length &&
+					url.indexOf(__webpack_require__.p) === 0
+				) {
+					nextUrl =
+						retryPublicPaths[attempt % retryPublicPaths.length] +
+						url.slice(__webpack_require__.p.length);
+				}
+				var delay = $RETRY_DELAY$ * Math.pow(2, attempt++);
+				setTimeout(function () {
+					load(nextUrl);
+				}, delay);
+			},
+			key,
+			chunkId
+		);
+	};
+	load(url);
+};
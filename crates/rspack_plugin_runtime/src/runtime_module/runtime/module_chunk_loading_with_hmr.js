@@ -0,0 +1,13 @@
+function loadUpdateChunk(chunkId, updatedModulesList) {
+	return $IMPORT_FUNCTION_NAME$("$OUTPUT_DIR$" + __webpack_require__.hu(chunkId)).then(function (obj) {
+		var updatedModules = obj.modules;
+		var runtime = obj.runtime;
+		for (var moduleId in updatedModules) {
+			if (__webpack_require__.o(updatedModules, moduleId)) {
+				currentUpdate[moduleId] = updatedModules[moduleId];
+				if (updatedModulesList) updatedModulesList.push(moduleId);
+			}
+		}
+		if (runtime) currentUpdateRuntime.push(runtime);
+	});
+}
@@ -0,0 +1,14 @@
+__webpack_require__.hmrM = function () {
+	if (typeof fetch === "undefined")
+		throw new Error("No browser support: need fetch API");
+	return fetch(__webpack_require__.p + __webpack_require__.hmrF()).then(
+		function (response) {
+			if (response.status === 404) return; // no update available
+			if (!response.ok)
+				throw new Error(
+					"Failed to fetch update manifest " + response.statusText
+				);
+			return response.json();
+		}
+	);
+};
@@ -1,6 +1,6 @@
 function loadUpdateChunk(chunkId, updatedModulesList) {
 	return new Promise(function(resolve, reject) {
-		var filename = require('path').join(__dirname, "" + __webpack_require__.hu(chunkId));
+		var filename = require('path').join(__dirname, "$OUTPUT_DIR$" + __webpack_require__.hu(chunkId));
 		require('fs').readFile(filename, 'utf-8', function(err, content) {
 			if(err) return reject(err);
 			var update = {};
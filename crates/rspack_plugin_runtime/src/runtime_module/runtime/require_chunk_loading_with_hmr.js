@@ -1,5 +1,5 @@
 function loadUpdateChunk(chunkId, updatedModulesList) {
-	var update = require("./" + __webpack_require__.hu(chunkId));
+	var update = require("$OUTPUT_DIR$" + __webpack_require__.hu(chunkId));
 	var updatedModules = update.modules;
 	var runtime = update.runtime;
 	for (var moduleId in updatedModules) {
@@ -16,16 +16,17 @@ use rspack_plugin_javascript::{
 };
 
 use crate::runtime_module::{
-  chunk_has_css, is_enabled_for_chunk, AsyncRuntimeModule, AutoPublicPathRuntimeModule,
-  BaseUriRuntimeModule, ChunkNameRuntimeModule, ChunkPrefetchPreloadFunctionRuntimeModule,
-  CompatGetDefaultExportRuntimeModule, CreateFakeNamespaceObjectRuntimeModule,
-  CreateScriptUrlRuntimeModule, DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule,
-  GetChunkFilenameRuntimeModule, GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule,
-  GetMainFilenameRuntimeModule, GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule,
-  HarmonyModuleDecoratorRuntimeModule, HasOwnPropertyRuntimeModule, LoadScriptRuntimeModule,
-  MakeNamespaceObjectRuntimeModule, NodeModuleDecoratorRuntimeModule, NonceRuntimeModule,
-  NormalRuntimeModule, OnChunkLoadedRuntimeModule, PublicPathRuntimeModule,
-  RelativeUrlRuntimeModule, RuntimeIdRuntimeModule, SystemContextRuntimeModule,
+  chunk_has_css, is_enabled_for_chunk, is_node_script_loading, AsyncRuntimeModule,
+  AutoPublicPathRuntimeModule, BaseUriRuntimeModule, ChunkNameRuntimeModule,
+  ChunkPrefetchPreloadFunctionRuntimeModule, CompatGetDefaultExportRuntimeModule,
+  CreateFakeNamespaceObjectRuntimeModule, CreateScriptUrlRuntimeModule,
+  DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule, GetChunkFilenameRuntimeModule,
+  GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule, GetMainFilenameRuntimeModule,
+  GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule, HarmonyModuleDecoratorRuntimeModule,
+  HasOwnPropertyRuntimeModule, LoadScriptRuntimeModule, MakeNamespaceObjectRuntimeModule,
+  NodeModuleDecoratorRuntimeModule, NonceRuntimeModule, NormalRuntimeModule,
+  OnChunkLoadedRuntimeModule, PublicPathRuntimeModule, RelativeUrlRuntimeModule,
+  RuntimeIdRuntimeModule, SystemContextRuntimeModule,
 };
 
 static GLOBALS_ON_REQUIRE: Lazy<Vec<RuntimeGlobals>> = Lazy::new(|| {
@@ -226,6 +227,15 @@ fn runtime_requirements_in_tree(
     }
   }
 
+  // the retries replace the public path of the failed scripts with the alternate ones,
+  // and scripts loaded in node.js are resolved against the public path
+  if (compilation.options.output.chunk_load_retry.is_some()
+    || is_node_script_loading(&compilation.options))
+    && runtime_requirements.contains(RuntimeGlobals::LOAD_SCRIPT)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+  }
+
   if (runtime_requirements.contains(RuntimeGlobals::GET_CHUNK_UPDATE_SCRIPT_FILENAME)
     && has_hash_placeholder(
       compilation
@@ -254,9 +264,10 @@ fn runtime_requirements_in_tree(
     .and_then(|options| options.public_path.clone())
     .unwrap_or_else(|| compilation.options.output.public_path.clone());
 
-  // TODO check output.scriptType
+  // `import.meta.url` is used by the module scripts instead of the global object
   if matches!(public_path, PublicPath::Auto)
     && runtime_requirements.contains(RuntimeGlobals::PUBLIC_PATH)
+    && compilation.options.output.script_type != "module"
   {
     runtime_requirements_mut.insert(RuntimeGlobals::GLOBAL);
   }
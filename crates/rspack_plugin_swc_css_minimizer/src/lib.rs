@@ -4,14 +4,59 @@ use regex::Regex;
 use rspack_core::{rspack_sources::MapOptions, Compilation, CompilationProcessAssets, Plugin};
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
-use rspack_plugin_css::swc_css_compiler::{SwcCssCompiler, SwcCssSourceMapGenConfig};
+use rspack_plugin_css::swc_css_compiler::{
+  SwcCssCommentFilter, SwcCssCompiler, SwcCssMinifyOptions, SwcCssSourceMapGenConfig,
+};
+use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRules;
 
 static CSS_ASSET_REGEXP: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"\.css(\?.*)?$").expect("Invalid RegExp"));
 
+#[derive(Debug, Default)]
+pub struct SwcCssMinimizerRspackPluginOptions {
+  // Include all assets that pass test assertion.
+  pub test: Option<SwcJsMinimizerRules>,
+  // Include all assets matching any of these conditions.
+  pub include: Option<SwcJsMinimizerRules>,
+  // Exclude all assets matching any of these conditions.
+  pub exclude: Option<SwcJsMinimizerRules>,
+  // Keep the comments matching any of these conditions.
+  pub preserve_comments: Option<SwcJsMinimizerRules>,
+  pub minify_options: SwcCssMinifyOptions,
+}
+
+impl SwcCssMinimizerRspackPluginOptions {
+  fn match_object(&self, filename: &str) -> Result<bool> {
+    if let Some(condition) = &self.test {
+      if !condition.try_match(filename)? {
+        return Ok(false);
+      }
+    }
+    if let Some(condition) = &self.include {
+      if !condition.try_match(filename)? {
+        return Ok(false);
+      }
+    }
+    if let Some(condition) = &self.exclude {
+      if condition.try_match(filename)? {
+        return Ok(false);
+      }
+    }
+    Ok(true)
+  }
+}
+
 #[plugin]
 #[derive(Debug, Default)]
-pub struct SwcCssMinimizerRspackPlugin;
+pub struct SwcCssMinimizerRspackPlugin {
+  options: SwcCssMinimizerRspackPluginOptions,
+}
+
+impl SwcCssMinimizerRspackPlugin {
+  pub fn new(options: SwcCssMinimizerRspackPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
 
 #[plugin_hook(CompilationProcessAssets for SwcCssMinimizerRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_SIZE)]
 async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
@@ -20,7 +65,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     .par_iter_mut()
     .filter(|(filename, _)| CSS_ASSET_REGEXP.is_match(filename))
     .try_for_each(|(filename, original)| -> Result<()> {
-      if original.get_info().minimized {
+      if original.get_info().minimized || !self.options.match_object(filename)? {
         return Ok(());
       }
 
@@ -28,6 +73,11 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         let input = original_source.source().to_string();
         let input_source_map = original_source.map(&MapOptions::default());
         let enable_source_map = input_source_map.is_some();
+        let preserve_comment = self
+          .options
+          .preserve_comments
+          .as_ref()
+          .map(|condition| move |comment: &str| condition.try_match(comment));
         let minimized_source = SwcCssCompiler::default().minify(
           filename,
           input,
@@ -37,6 +87,8 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
             inline_sources_content: false,
             emit_columns: true,
           },
+          &self.options.minify_options,
+          preserve_comment.as_ref().map(|f| f as &SwcCssCommentFilter),
         )?;
         original.set_source(Some(minimized_source));
       }
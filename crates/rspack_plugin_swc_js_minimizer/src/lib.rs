@@ -2,19 +2,20 @@
 
 mod minify;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 use std::path::Path;
 use std::sync::{mpsc, Arc, Mutex};
 
 use once_cell::sync::{Lazy, OnceCell};
+use pathdiff::diff_paths;
 use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::rspack_sources::{ConcatSource, MapOptions, RawSource, SourceExt, SourceMap};
 use rspack_core::rspack_sources::{Source, SourceMapSource, SourceMapSourceOptions};
 use rspack_core::{
   AssetInfo, Compilation, CompilationAsset, CompilationParams, CompilationProcessAssets,
-  CompilerCompilation, Plugin, PluginContext,
+  CompilerCompilation, FilenameTemplate, PathData, Plugin, PluginContext,
 };
 use rspack_error::miette::IntoDiagnostic;
 use rspack_error::{Diagnostic, Result};
@@ -23,6 +24,7 @@ use rspack_plugin_javascript::{
   JavascriptModulesPluginPlugin, JsChunkHashArgs, JsPlugin, PluginJsChunkHashHookOutput,
 };
 use rspack_regex::RspackRegex;
+use rspack_util::infallible::ResultInfallibleExt;
 use rspack_util::try_any_sync;
 use swc_config::config_types::BoolOrDataConfig;
 use swc_core::base::config::JsMinifyFormatOptions;
@@ -33,6 +35,8 @@ use self::minify::{match_object, minify};
 
 const PLUGIN_NAME: &str = "rspack.SwcJsMinimizerRspackPlugin";
 
+const DEFAULT_EXTRACT_COMMENTS_FILENAME: &str = "[file].LICENSE.txt[query]";
+
 static JAVASCRIPT_ASSET_REGEXP: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"\.[cm]?js(\?.*)?$").expect("Invalid RegExp"));
 
@@ -41,6 +45,9 @@ pub struct SwcJsMinimizerRspackPluginOptions {
   pub extract_comments: Option<ExtractComments>,
   pub compress: BoolOrDataConfig<TerserCompressorOptions>,
   pub mangle: BoolOrDataConfig<MangleOptions>,
+  /// Reserves the quoted property names from property mangling,
+  /// the same as `mangle.properties.keep_quoted` of terser.
+  pub keep_quoted_props: bool,
   pub format: JsMinifyFormatOptions,
   pub test: Option<SwcJsMinimizerRules>,
   pub include: Option<SwcJsMinimizerRules>,
@@ -80,6 +87,7 @@ impl std::hash::Hash for SwcJsMinimizerRspackPluginOptions {
           .map(|v| serde_json::to_string(v).expect("Should be able to serialize"))
       })
       .hash(state);
+    self.keep_quoted_props.hash(state);
     self.test.hash(state);
     self.include.hash(state);
     self.exclude.hash(state);
@@ -129,12 +137,16 @@ pub enum OptionWrapper<T: std::fmt::Debug + Hash> {
 pub struct ExtractComments {
   pub condition: String,
   pub banner: OptionWrapper<String>,
+  /// The filename template of the extracted comments file, supports `[file]`, `[base]`,
+  /// `[name]` and `[query]`. Defaults to `[file].LICENSE.txt[query]`.
+  pub filename: Option<String>,
 }
 
 impl Hash for ExtractComments {
   fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
     self.condition.as_str().hash(state);
     self.banner.hash(state);
+    self.filename.hash(state);
   }
 }
 
@@ -190,7 +202,7 @@ async fn compilation(
 async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let minify_options = &self.options;
 
-  let (tx, rx) = mpsc::channel::<Vec<Diagnostic>>();
+  let (tx, rx) = mpsc::channel::<(String, Vec<Diagnostic>)>();
   // collect all extracted comments info
   let all_extracted_comments = Mutex::new(HashMap::new());
   let extract_comments_condition = minify_options
@@ -219,8 +231,8 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
 
       true
     })
-    .try_for_each_with(tx,|tx, (filename, original)| -> Result<()>  {
-      let filename = filename.split('?').next().expect("Should have filename");
+    .try_for_each_with(tx,|tx, (original_filename, original)| -> Result<()>  {
+      let filename = original_filename.split('?').next().expect("Should have filename");
       if let Some(original_source) = original.get_source() {
         let input = original_source.source().to_string();
         let input_source_map = original_source.map(&MapOptions::default());
@@ -236,6 +248,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         let js_minify_options = JsMinifyOptions {
           compress: minify_options.compress.clone(),
           mangle: minify_options.mangle.clone(),
+          keep_quoted_props: minify_options.keep_quoted_props,
           format: minify_options.format.clone(),
           source_map: BoolOrDataConfig::from_bool(input_source_map.is_some()),
           inline_sources_content: true, /* Using true so original_source can be None in SourceMapSource */
@@ -243,11 +256,22 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           ..Default::default()
           };
         let extract_comments_option = minify_options.extract_comments.as_ref().map(|extract_comments| {
-          let comments_filename = format!("{}.LICENSE.txt", filename);
+          let comments_filename = FilenameTemplate::from(
+            extract_comments
+              .filename
+              .clone()
+              .unwrap_or_else(|| DEFAULT_EXTRACT_COMMENTS_FILENAME.to_string()),
+          )
+          .render(PathData::default().filename(original_filename), None)
+          .always_ok();
           let banner = match &extract_comments.banner {
             OptionWrapper::Default => {
               let dir = Path::new(filename).parent().expect("should has parent");
-              let relative = Path::new(&comments_filename).strip_prefix(dir).expect("should has common prefix").to_string_lossy().to_string().replace('\\', "/");
+              let comments_path = comments_filename.split('?').next().expect("Should have filename");
+              let relative = diff_paths(comments_path, dir)
+                .unwrap_or_else(|| Path::new(comments_path).to_path_buf())
+                .to_string_lossy()
+                .replace('\\', "/");
               Some(format!("/*! For license information please see {relative} */"))
             },
             OptionWrapper::Disabled => None,
@@ -268,7 +292,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         ) {
           Ok(r) => r,
           Err(e) => {
-            tx.send(e.into()).into_diagnostic()?;
+            tx.send((filename.to_string(), e.into())).into_diagnostic()?;
             return Ok(())
           }
         };
@@ -306,19 +330,41 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       Ok(())
   })?;
 
-  compilation.push_batch_diagnostic(rx.into_iter().flatten().collect::<Vec<_>>());
-
-  // write all extracted comments to assets
+  // the assets are minified in parallel, sort the diagnostics by filename
+  let mut diagnostics = rx.into_iter().collect::<Vec<_>>();
+  diagnostics.sort_by(|a, b| a.0.cmp(&b.0));
+  compilation.push_batch_diagnostic(
+    diagnostics
+      .into_iter()
+      .flat_map(|(_, diagnostics)| diagnostics)
+      .collect::<Vec<_>>(),
+  );
+
+  // write all extracted comments to assets, the comments of the assets
+  // sharing the same comments file are merged
+  let mut comments_files: BTreeMap<String, Vec<String>> = BTreeMap::new();
   all_extracted_comments
     .lock()
     .expect("all_extracted_comments lock failed")
     .clone()
     .into_iter()
     .for_each(|(_, comments)| {
+      let content = comments.source.source().to_string();
+      let contents = comments_files
+        .entry(comments.comments_file_name)
+        .or_default();
+      if !contents.contains(&content) {
+        contents.push(content);
+      }
+    });
+  comments_files
+    .into_iter()
+    .for_each(|(comments_file_name, mut contents)| {
+      contents.sort();
       compilation.emit_asset(
-        comments.comments_file_name,
+        comments_file_name,
         CompilationAsset {
-          source: Some(comments.source),
+          source: Some(RawSource::from(contents.join("\n\n")).boxed()),
           info: AssetInfo {
             minimized: true,
             ..Default::default()
@@ -358,6 +404,7 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
 pub struct JsMinifyOptions {
   pub compress: BoolOrDataConfig<TerserCompressorOptions>,
   pub mangle: BoolOrDataConfig<MangleOptions>,
+  pub keep_quoted_props: bool,
   pub format: JsMinifyFormatOptions,
   pub ecma: TerserEcmaVersion,
   pub keep_class_names: bool,
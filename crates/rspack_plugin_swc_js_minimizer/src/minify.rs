@@ -26,8 +26,8 @@ use swc_core::{
     BytePos, FileName, Mark, SourceMap, GLOBALS,
   },
   ecma::{
-    ast::Ident,
-    atoms::Atom,
+    ast::{ComputedPropName, Expr, Ident, Lit, MemberProp, PropName},
+    atoms::{Atom, JsWord},
     parser::{EsConfig, Syntax},
     transforms::base::{
       fixer::{fixer, paren_remover},
@@ -205,6 +205,22 @@ pub fn minify(
             Default::default()
           };
 
+          // the quoted property names are reserved from property mangling
+          if opts.keep_quoted_props
+            && let Some(props) = min_opts
+              .mangle
+              .as_mut()
+              .and_then(|mangle| mangle.props.as_mut())
+          {
+            let mut v = QuotedPropsCollector {
+              names: Default::default(),
+            };
+
+            program.visit_with(&mut v);
+
+            props.reserved.extend(v.names);
+          }
+
           let unresolved_mark = Mark::new();
           let top_level_mark = Mark::new();
 
@@ -329,6 +345,30 @@ impl Visit for IdentCollector {
   }
 }
 
+struct QuotedPropsCollector {
+  names: Vec<JsWord>,
+}
+
+impl Visit for QuotedPropsCollector {
+  noop_visit_type!();
+
+  fn visit_prop_name(&mut self, name: &PropName) {
+    if let PropName::Str(s) = name {
+      self.names.push(s.value.clone());
+    }
+    name.visit_children_with(self);
+  }
+
+  fn visit_member_prop(&mut self, prop: &MemberProp) {
+    if let MemberProp::Computed(ComputedPropName { expr, .. }) = prop
+      && let Expr::Lit(Lit::Str(s)) = &**expr
+    {
+      self.names.push(s.value.clone());
+    }
+    prop.visit_children_with(self);
+  }
+}
+
 // keep this private to make sure with_rspack_error_handler is safety
 struct RspackErrorEmitter {
   tx: mpsc::Sender<rspack_error::Error>,
@@ -355,6 +395,7 @@ impl Emitter for RspackErrorEmitter {
             db.message(),
           )
           .with_kind(self.kind)
+          .with_code("MINIFY_ERROR")
           .into(),
         )
         .expect("Sender should drop after emit called");
@@ -77,10 +77,13 @@ fn seal(&self, compilation: &mut Compilation) -> Result<()> {
     if lower_map.values().len() > 1 {
       let mut case_modules = lower_map.values().map(|m| m.as_ref()).collect::<Vec<_>>();
       case_modules.sort_by_key(|m| m.identifier());
-      diagnostics.push(Diagnostic::warn(
-        "Sensitive Modules Warn".to_string(),
-        self.create_sensitive_modules_warning(&case_modules, &compilation.get_module_graph()),
-      ));
+      diagnostics.push(
+        Diagnostic::warn(
+          "Sensitive Modules Warn".to_string(),
+          self.create_sensitive_modules_warning(&case_modules, &compilation.get_module_graph()),
+        )
+        .with_diagnostic_code("CASE_SENSITIVE_MODULES"),
+      );
     }
   }
 
@@ -7,10 +7,10 @@ use rspack_core::DependencyType::WasmImport;
 use rspack_core::{
   AssetInfo, BoxDependency, BuildMetaExportsType, Compilation, FilenameTemplate, GenerateContext,
   Module, ModuleDependency, ModuleIdentifier, NormalModule, ParseContext, ParseResult,
-  ParserAndGenerator, PathData, RuntimeGlobals, SourceType, StaticExportsDependency,
+  ParserAndGenerator, PathData, RuntimeGlobals, RuntimeSpec, SourceType, StaticExportsDependency,
   StaticExportsSpec, UsedName,
 };
-use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_error::{error, Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::Identifier;
 use rspack_util::infallible::ResultInfallibleExt as _;
 use swc_core::atoms::Atom;
@@ -37,57 +37,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
     parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
 
     let source = parse_context.source;
-
-    let mut exports = Vec::with_capacity(1);
-    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
-    let mut diagnostic = Vec::with_capacity(1);
-
-    for payload in Parser::new(0).parse_all(&source.buffer()) {
-      match payload {
-        Ok(payload) => match payload {
-          Payload::ExportSection(s) => {
-            for export in s {
-              match export {
-                Ok(export) => exports.push(export.name.to_string()),
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Export Parse Error".into(),
-                  err.to_string(),
-                )),
-              };
-            }
-          }
-          Payload::ImportSection(s) => {
-            for import in s {
-              match import {
-                Ok(Import { module, name, ty }) => {
-                  dependencies.push(Box::new(WasmImportDependency::new(
-                    module.into(),
-                    name.into(),
-                    ty,
-                  )));
-                }
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Import Parse Error".into(),
-                  err.to_string(),
-                )),
-              }
-            }
-          }
-          _ => {}
-        },
-        Err(err) => {
-          diagnostic.push(Diagnostic::error(
-            "Wasm Parse Error".into(),
-            err.to_string(),
-          ));
-        }
-      }
-    }
-
-    dependencies.push(Box::new(StaticExportsDependency::new(
-      StaticExportsSpec::Array(exports.iter().cloned().map(Atom::from).collect::<Vec<_>>()),
-      false,
-    )));
+    let (dependencies, diagnostic) = parse_wasm(&source.buffer());
 
     Ok(
       ParseResult {
@@ -147,97 +97,12 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
         runtime_requirements.insert(RuntimeGlobals::EXPORTS);
         runtime_requirements.insert(RuntimeGlobals::INSTANTIATE_WASM);
 
-        let mut dep_modules = IndexMap::<ModuleIdentifier, (String, &str)>::new();
-        let mut wasm_deps_by_request = IndexMap::<&str, Vec<(Identifier, String, String)>>::new();
-        let mut promises: Vec<String> = vec![];
-
-        let module_graph = &compilation.get_module_graph();
-        let chunk_graph = &compilation.chunk_graph;
-
-        module
-          .get_dependencies()
-          .iter()
-          .map(|id| module_graph.dependency_by_id(id).expect("should be ok"))
-          .filter(|dep| dep.dependency_type() == &WasmImport)
-          .map(|dep| {
-            (
-              dep,
-              module_graph.module_graph_module_by_dependency_id(dep.id()),
-            )
-          })
-          .for_each(|(dep, mgm)| {
-            if let Some(mgm) = mgm {
-              if !dep_modules.contains_key(&mgm.module_identifier) {
-                let import_var = format!("WEBPACK_IMPORTED_MODULE_{}", dep_modules.len());
-                let val = (import_var.clone(), mgm.id(chunk_graph));
-
-                if matches!(module_graph.is_async(&mgm.module_identifier), Some(true)) {
-                  promises.push(import_var);
-                }
-                dep_modules.insert(mgm.module_identifier, val);
-              }
-
-              let dep = dep
-                .as_any()
-                .downcast_ref::<WasmImportDependency>()
-                .expect("should be wasm import dependency");
-
-              let dep_name = serde_json::to_string(dep.name()).expect("should be ok.");
-              let used_name = module_graph
-                .get_exports_info(&mgm.module_identifier)
-                .id
-                .get_used_name(module_graph, *runtime, UsedName::Str(dep.name().into()));
-              let Some(UsedName::Str(used_name)) = used_name else {
-                return;
-              };
-              let request = dep.request();
-              let val = (
-                mgm.module_identifier,
-                dep_name,
-                serde_json::to_string(&used_name).expect("should convert to json string"),
-              );
-              if let Some(deps) = wasm_deps_by_request.get_mut(&request) {
-                deps.push(val);
-              } else {
-                wasm_deps_by_request.insert(request, vec![val]);
-              }
-            }
-          });
-
-        let imports_code = dep_modules
-          .iter()
-          .map(|(_, val)| render_import_stmt(&val.0, val.1))
-          .collect::<Vec<_>>()
-          .join("");
-
-        let import_obj_request_items = wasm_deps_by_request
-          .into_iter()
-          .map(|(request, deps)| {
-            let deps = deps
-              .into_iter()
-              .map(|(id, name, used_name)| {
-                let import_var = dep_modules.get(&id).expect("should be ok");
-                let import_var = &import_var.0;
-                format!("{name}: {import_var}[{used_name}]")
-              })
-              .collect::<Vec<_>>()
-              .join(",\n");
-
-            format!(
-              "{}: {{\n{deps}\n}}",
-              serde_json::to_string(request).expect("should be ok")
-            )
-          })
-          .collect::<Vec<_>>();
-
-        let imports_obj = if !import_obj_request_items.is_empty() {
-          Some(format!(
-            ", {{\n{}\n}}",
-            &import_obj_request_items.join(",\n")
-          ))
-        } else {
-          None
-        };
+        let WasmImports {
+          imports_code,
+          imports_obj,
+          promises,
+        } = render_wasm_imports(module, compilation, *runtime);
+        let imports_obj = imports_obj.map(|imports_obj| format!(", {imports_obj}"));
 
         let instantiate_call = format!(
           "{}(exports, module.id, {} {})",
@@ -295,6 +160,121 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 }
 
+/// Browsers refuse to compile WebAssembly modules larger than this synchronously on the main thread.
+const SYNC_WASM_SIZE_LIMIT: usize = 4096;
+
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator;
+
+pub(crate) static SYNC_WASM_SOURCE_TYPE: &[SourceType; 1] = &[SourceType::JavaScript];
+
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    SYNC_WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+    let (dependencies, mut diagnostic) = parse_wasm(&source.buffer());
+
+    let size = source.size();
+    if size > SYNC_WASM_SIZE_LIMIT {
+      diagnostic.push(Diagnostic::warn(
+        "Wasm Size Limit".into(),
+        format!(
+          "The synchronous WebAssembly module {} is {size} bytes, which exceeds the {SYNC_WASM_SIZE_LIMIT} bytes limit of synchronous compilation on the main thread of browsers.\nConsider using `experiments.asyncWebAssembly` instead.",
+          parse_context.resource_data.resource
+        ),
+      ));
+    }
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        source,
+        analyze_result: Default::default(),
+        side_effects_bailout: None,
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: &SourceType) -> f64 {
+    match source_type {
+      // The wasm binary is inlined as base64
+      SourceType::JavaScript => {
+        200.0
+          + module
+            .original_source()
+            .map_or(0, |source| source.size() * 4 / 3) as f64
+      }
+      _ => 0.0,
+    }
+  }
+
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let GenerateContext {
+      compilation,
+      runtime,
+      ..
+    } = generate_context;
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+
+        let WasmImports {
+          imports_code,
+          imports_obj,
+          promises,
+        } = render_wasm_imports(module, compilation, *runtime);
+        if !promises.is_empty() {
+          return Err(error!(
+            "The synchronous WebAssembly module {} can't import async modules, use `experiments.asyncWebAssembly` instead",
+            module.identifier()
+          ));
+        }
+
+        let wasm_code = serde_json::to_string(&rspack_base64::encode_to_string(source.buffer()))
+          .expect("should convert to json string");
+        let source = format!(
+          r#"{imports_code}var wasmCode = {wasm_code};
+var wasmBinary = typeof Buffer !== "undefined" ? Buffer.from(wasmCode, "base64") : Uint8Array.from(atob(wasmCode), function (c) {{ return c.charCodeAt(0); }});
+var wasmModule = new WebAssembly.Module(wasmBinary);
+module.exports = new WebAssembly.Instance(wasmModule, {}).exports;
+"#,
+          imports_obj.as_deref().unwrap_or("{}")
+        );
+
+        Ok(RawSource::from(source).boxed())
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+
+  fn get_concatenation_bailout_reason(
+    &self,
+    _module: &dyn Module,
+    _mg: &rspack_core::ModuleGraph,
+    _cg: &rspack_core::ChunkGraph,
+  ) -> Option<String> {
+    Some(String::from(
+      "Module Concatenation is not implemented for SyncWasmParserAndGenerator",
+    ))
+  }
+}
+
 fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
@@ -312,6 +292,172 @@ fn render_wasm_name(
     .always_ok()
 }
 
+/// Collect the exports and the imports of a wasm binary.
+fn parse_wasm(binary: &[u8]) -> (Vec<BoxDependency>, Vec<Diagnostic>) {
+  let mut exports = Vec::with_capacity(1);
+  let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+  let mut diagnostic = Vec::with_capacity(1);
+
+  for payload in Parser::new(0).parse_all(binary) {
+    match payload {
+      Ok(payload) => match payload {
+        Payload::ExportSection(s) => {
+          for export in s {
+            match export {
+              Ok(export) => exports.push(export.name.to_string()),
+              Err(err) => diagnostic.push(
+                Diagnostic::error("Wasm Export Parse Error".into(), err.to_string())
+                  .with_diagnostic_code("WASM_PARSE_ERROR"),
+              ),
+            };
+          }
+        }
+        Payload::ImportSection(s) => {
+          for import in s {
+            match import {
+              Ok(Import { module, name, ty }) => {
+                dependencies.push(Box::new(WasmImportDependency::new(
+                  module.into(),
+                  name.into(),
+                  ty,
+                )));
+              }
+              Err(err) => diagnostic.push(
+                Diagnostic::error("Wasm Import Parse Error".into(), err.to_string())
+                  .with_diagnostic_code("WASM_PARSE_ERROR"),
+              ),
+            }
+          }
+        }
+        _ => {}
+      },
+      Err(err) => {
+        diagnostic.push(
+          Diagnostic::error("Wasm Parse Error".into(), err.to_string())
+            .with_diagnostic_code("WASM_PARSE_ERROR"),
+        );
+      }
+    }
+  }
+
+  dependencies.push(Box::new(StaticExportsDependency::new(
+    StaticExportsSpec::Array(exports.iter().cloned().map(Atom::from).collect::<Vec<_>>()),
+    false,
+  )));
+
+  (dependencies, diagnostic)
+}
+
+struct WasmImports {
+  /// Requires of the imported modules.
+  imports_code: String,
+  /// The import object passed to the instantiation, `None` if there is no import.
+  imports_obj: Option<String>,
+  /// Import variables of the async imported modules.
+  promises: Vec<String>,
+}
+
+fn render_wasm_imports(
+  module: &dyn Module,
+  compilation: &Compilation,
+  runtime: Option<&RuntimeSpec>,
+) -> WasmImports {
+  let mut dep_modules = IndexMap::<ModuleIdentifier, (String, &str)>::new();
+  let mut wasm_deps_by_request = IndexMap::<&str, Vec<(Identifier, String, String)>>::new();
+  let mut promises: Vec<String> = vec![];
+
+  let module_graph = &compilation.get_module_graph();
+  let chunk_graph = &compilation.chunk_graph;
+
+  module
+    .get_dependencies()
+    .iter()
+    .map(|id| module_graph.dependency_by_id(id).expect("should be ok"))
+    .filter(|dep| dep.dependency_type() == &WasmImport)
+    .map(|dep| {
+      (
+        dep,
+        module_graph.module_graph_module_by_dependency_id(dep.id()),
+      )
+    })
+    .for_each(|(dep, mgm)| {
+      if let Some(mgm) = mgm {
+        if !dep_modules.contains_key(&mgm.module_identifier) {
+          let import_var = format!("WEBPACK_IMPORTED_MODULE_{}", dep_modules.len());
+          let val = (import_var.clone(), mgm.id(chunk_graph));
+
+          if matches!(module_graph.is_async(&mgm.module_identifier), Some(true)) {
+            promises.push(import_var);
+          }
+          dep_modules.insert(mgm.module_identifier, val);
+        }
+
+        let dep = dep
+          .as_any()
+          .downcast_ref::<WasmImportDependency>()
+          .expect("should be wasm import dependency");
+
+        let dep_name = serde_json::to_string(dep.name()).expect("should be ok.");
+        let used_name = module_graph
+          .get_exports_info(&mgm.module_identifier)
+          .id
+          .get_used_name(module_graph, runtime, UsedName::Str(dep.name().into()));
+        let Some(UsedName::Str(used_name)) = used_name else {
+          return;
+        };
+        let request = dep.request();
+        let val = (
+          mgm.module_identifier,
+          dep_name,
+          serde_json::to_string(&used_name).expect("should convert to json string"),
+        );
+        if let Some(deps) = wasm_deps_by_request.get_mut(&request) {
+          deps.push(val);
+        } else {
+          wasm_deps_by_request.insert(request, vec![val]);
+        }
+      }
+    });
+
+  let imports_code = dep_modules
+    .iter()
+    .map(|(_, val)| render_import_stmt(&val.0, val.1))
+    .collect::<Vec<_>>()
+    .join("");
+
+  let import_obj_request_items = wasm_deps_by_request
+    .into_iter()
+    .map(|(request, deps)| {
+      let deps = deps
+        .into_iter()
+        .map(|(id, name, used_name)| {
+          let import_var = dep_modules.get(&id).expect("should be ok");
+          let import_var = &import_var.0;
+          format!("{name}: {import_var}[{used_name}]")
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+
+      format!(
+        "{}: {{\n{deps}\n}}",
+        serde_json::to_string(request).expect("should be ok")
+      )
+    })
+    .collect::<Vec<_>>();
+
+  let imports_obj = if !import_obj_request_items.is_empty() {
+    Some(format!("{{\n{}\n}}", &import_obj_request_items.join(",\n")))
+  } else {
+    None
+  };
+
+  WasmImports {
+    imports_code,
+    imports_obj,
+    promises,
+  }
+}
+
 fn render_import_stmt(import_var: &str, module_id: &str) -> String {
   let module_id = serde_json::to_string(&module_id).expect("TODO");
   format!("var {import_var} = __webpack_require__({module_id});\n",)
@@ -10,7 +10,7 @@ use rspack_core::{
 use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 
-use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName};
+use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName, SyncWasmParserAndGenerator};
 
 pub struct EnableWasmLoadingPlugin;
 
@@ -118,3 +118,46 @@ impl Plugin for AsyncWasmPlugin {
     Ok(())
   }
 }
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct SyncWasmPlugin;
+
+#[plugin_hook(CompilerCompilation for SyncWasmPlugin)]
+async fn sync_wasm_compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::WasmImport,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for SyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.WebAssemblyModulesPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(sync_wasm_compilation::new(self));
+
+    ctx.context.register_parser_and_generator_builder(
+      ModuleType::WasmSync,
+      Box::new(|_, _| Box::new(SyncWasmParserAndGenerator) as Box<dyn ParserAndGenerator>),
+    );
+
+    Ok(())
+  }
+}
@@ -6,7 +6,33 @@ import {
 	ensureEnvObject,
 	setBuiltinEnvArg
 } from "../utils/options";
+import { createHash } from "crypto";
 import { MultiStats, Stats } from "@rspack/core";
+import type { StatsError, StatsWarnings } from "@rspack/core";
+
+/**
+ * Collect the content hash of each emitted asset, the assets of a multi
+ * compiler are prefixed with the index of the compiler.
+ */
+function collectAssetHashes(stats: Stats | MultiStats) {
+	const hashes = new Map<string, string>();
+	const list = stats instanceof MultiStats ? stats.stats : [stats];
+	list.forEach((stats, index) => {
+		const prefix = list.length > 1 ? `${index}:` : "";
+		for (const asset of stats.compilation.getAssets()) {
+			const hash = createHash("sha256")
+				.update(asset.source.buffer())
+				.digest("hex");
+			hashes.set(`${prefix}${asset.name}`, hash);
+		}
+	});
+	return hashes;
+}
+
+function diffAssetHashes(a: Map<string, string>, b: Map<string, string>) {
+	const names = new Set([...a.keys(), ...b.keys()]);
+	return [...names].filter(name => a.get(name) !== b.get(name)).sort();
+}
 
 export class BuildCommand implements RspackCommand {
 	async apply(cli: RspackCLI): Promise<void> {
@@ -22,6 +48,19 @@ export class BuildCommand implements RspackCommand {
 					},
 					json: {
 						describe: "emit stats json"
+					},
+					"check-determinism": {
+						type: "boolean",
+						default: false,
+						describe:
+							"build twice and fail if the emitted assets are different"
+					},
+					"diagnostics-format": {
+						type: "string",
+						choices: ["pretty", "json"],
+						default: "pretty",
+						describe:
+							"print the errors and warnings as json lines instead of the stats"
 					}
 				}),
 			async options => {
@@ -62,7 +101,31 @@ export class BuildCommand implements RspackCommand {
 						: compiler.options
 							? compiler.options.stats
 							: undefined;
-					if (options.json && createJsonStringifyStream) {
+					if (options.diagnosticsFormat === "json") {
+						const { errors = [], warnings = [] } = stats.toJson({
+							all: false,
+							errors: true,
+							warnings: true
+						});
+						const print = (
+							severity: "error" | "warning",
+							diagnostic: StatsError | StatsWarnings
+						) => {
+							process.stdout.write(
+								JSON.stringify({
+									severity,
+									code: diagnostic.code,
+									message: diagnostic.message,
+									moduleIdentifier: diagnostic.moduleIdentifier,
+									moduleName: diagnostic.moduleName,
+									moduleId: diagnostic.moduleId,
+									compilerPath: diagnostic.compilerPath
+								}) + "\n"
+							);
+						};
+						errors.forEach(error => print("error", error));
+						warnings.forEach(warning => print("warning", warning));
+					} else if (options.json && createJsonStringifyStream) {
 						const handleWriteError = (error: Error) => {
 							logger.error(error);
 							process.exit(2);
@@ -107,6 +170,48 @@ export class BuildCommand implements RspackCommand {
 				if (!compiler) return;
 				if (cli.isWatch(compiler)) {
 					return;
+				} else if (options.checkDeterminism) {
+					compiler.run((error, stats) => {
+						errorHandler(error, stats);
+						if (error || !stats) {
+							return;
+						}
+						const hashes = collectAssetHashes(stats);
+						// build again with a fresh compiler, so nothing is reused
+						// from the first build
+						compiler.close(async () => {
+							const secondCompiler = await cli.createCompiler(
+								rspackOptions,
+								"build"
+							);
+							if (!secondCompiler) return;
+							secondCompiler.run((error, secondStats) => {
+								if (error || !secondStats) {
+									logger.error(error);
+									process.exit(2);
+								}
+								const changed = diffAssetHashes(
+									hashes,
+									collectAssetHashes(secondStats)
+								);
+								if (changed.length > 0) {
+									logger.error(
+										`The output is not deterministic, ${
+											changed.length
+										} assets are different between two builds:\n${changed
+											.map(name => `  ${name}`)
+											.join("\n")}`
+									);
+									process.exitCode = 1;
+								} else {
+									logger.success(
+										"The output is identical between two builds"
+									);
+								}
+								secondCompiler.close(() => {});
+							});
+						});
+					});
 				} else {
 					compiler.run(errorHandler);
 				}
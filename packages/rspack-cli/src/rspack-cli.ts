@@ -206,14 +206,7 @@ export class RspackCLI {
 			} else if (typeof item.stats === "boolean") {
 				item.stats = item.stats ? { preset: "normal" } : { preset: "none" };
 			} else if (typeof item.stats === "string") {
-				item.stats = {
-					preset: item.stats as
-						| "normal"
-						| "none"
-						| "verbose"
-						| "errors-only"
-						| "errors-warnings"
-				};
+				item.stats = { preset: item.stats };
 			}
 			if (
 				this.colors.isColorSupported &&
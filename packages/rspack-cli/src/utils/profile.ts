@@ -25,6 +25,10 @@ The full syntax, remember update this when you change something in this file.
 
 `RSPACK_PROFILE=LOGGING rspack build`: only enable stats.logging, and use default options for stats.logging
 
+`RSPACK_PROFILE='HOOKS=output=./rspack.hooks' rspack build`: only enable hook timings, the output defaults to `./.rspack-profile-${timestamp}/hooks.json`
+
+`RSPACK_PROFILE=HOOKS rspack build`: only enable hook timings, and use default options for hook timings
+
 `RSPACK_PROFILE=ALL rspack build`: enable all, and use default options
 
 `RSPACK_PROFILE=[rspack_node,rspack_core] rspack build`: enable all, but customize trace filter
@@ -38,6 +42,8 @@ import { URLSearchParams } from "url";
 import {
 	experimental_registerGlobalTrace as registerGlobalTrace,
 	experimental_cleanupGlobalTrace as cleanupGlobalTrace,
+	experimental_enableHookTiming as enableHookTiming,
+	experimental_takeHookTimings as takeHookTimings,
 	RspackOptions,
 	type Compiler
 } from "@rspack/core";
@@ -59,10 +65,15 @@ type LoggingOutputOptions = string;
 type LoggingOptions = {
 	output: LoggingOutputOptions;
 };
+type HooksOutputOptions = string;
+type HooksOptions = {
+	output: HooksOutputOptions;
+};
 type ProfileOptions = {
 	TRACE?: RustTraceOptions;
 	JSCPU?: JSCPUProfileOptions;
 	LOGGING?: LoggingOptions;
+	HOOKS?: HooksOptions;
 };
 
 const timestamp = Date.now();
@@ -79,6 +90,7 @@ const defaultRustTraceLoggerOutput = `stdout`;
 const defaultRustTraceFilter = "trace";
 const defaultRustTraceLayer = "chrome";
 const defaultLoggingOutput = path.join(defaultOutputDirname, `./logging.json`);
+const defaultHooksOutput = path.join(defaultOutputDirname, `./hooks.json`);
 
 function resolveProfile(value: string): ProfileOptions {
 	if (value.toUpperCase() === "ALL") {
@@ -89,14 +101,16 @@ function resolveProfile(value: string): ProfileOptions {
 				output: defaultRustTraceChromeOutput
 			},
 			JSCPU: { output: defaultJSCPUProfileOutput },
-			LOGGING: { output: defaultLoggingOutput }
+			LOGGING: { output: defaultLoggingOutput },
+			HOOKS: { output: defaultHooksOutput }
 		};
 	}
 	if (value.startsWith("[") && value.endsWith("]")) {
 		return {
 			TRACE: resolveRustTraceOptions(value.slice(1, value.length - 1)),
 			JSCPU: { output: defaultJSCPUProfileOutput },
-			LOGGING: { output: defaultLoggingOutput }
+			LOGGING: { output: defaultLoggingOutput },
+			HOOKS: { output: defaultHooksOutput }
 		};
 	}
 	return value.split("|").reduce<ProfileOptions>((acc, cur) => {
@@ -109,6 +123,9 @@ function resolveProfile(value: string): ProfileOptions {
 		if (cur.toUpperCase().startsWith("LOGGING")) {
 			acc.LOGGING = resolveLoggingOptions(cur.slice(8));
 		}
+		if (cur.toUpperCase().startsWith("HOOKS")) {
+			acc.HOOKS = resolveHooksOptions(cur.slice(6));
+		}
 		return acc;
 	}, {});
 }
@@ -165,6 +182,17 @@ function resolveLoggingOptions(value: string): LoggingOptions {
 	return { output: value || defaultLoggingOutput };
 }
 
+// HOOKS=value
+function resolveHooksOptions(value: string): HooksOptions {
+	// output=filepath
+	if (value.includes("=")) {
+		const parsed = new URLSearchParams(value);
+		return { output: parsed.get("output") || defaultHooksOutput };
+	}
+	// filepath
+	return { output: value || defaultHooksOutput };
+}
+
 class RspackProfileJSCPUProfilePlugin {
 	constructor(private output: string) {}
 
@@ -210,6 +238,108 @@ class RspackProfileLoggingPlugin {
 	}
 }
 
+type HookTiming = {
+	hook: string;
+	tap: string;
+	calls: number;
+	duration: number;
+};
+
+type TimedHook = {
+	taps: { name: string }[];
+	intercept(interceptor: { register(tap: any): any }): void;
+};
+
+class RspackProfileHooksPlugin {
+	#timings = new Map<string, HookTiming>();
+	#intercepted = new WeakSet<object>();
+
+	constructor(private output: string) {}
+
+	#record(hook: string, tap: string, start: number) {
+		const key = `${hook}|${tap}`;
+		let timing = this.#timings.get(key);
+		if (timing === undefined) {
+			timing = { hook, tap, calls: 0, duration: 0 };
+			this.#timings.set(key, timing);
+		}
+		timing.calls++;
+		timing.duration += performance.now() - start;
+	}
+
+	// only the hooks with taps are intercepted, an interceptor makes the hook
+	// used, which registers the hook to the Rust side
+	#intercept(hooks: Record<string, unknown>, prefix: string) {
+		if (this.#intercepted.has(hooks)) return;
+		this.#intercepted.add(hooks);
+		for (const [name, value] of Object.entries(hooks)) {
+			const hook = value as TimedHook;
+			if (!Array.isArray(hook?.taps) || hook.taps.length === 0) continue;
+			const hookName = `${prefix}.${name}`;
+			hook.intercept({
+				register: tap => {
+					const fn = tap.fn;
+					if (tap.type === "sync") {
+						tap.fn = (...args: any[]) => {
+							const start = performance.now();
+							try {
+								return fn(...args);
+							} finally {
+								this.#record(hookName, tap.name, start);
+							}
+						};
+					} else if (tap.type === "promise") {
+						tap.fn = (...args: any[]) => {
+							const start = performance.now();
+							return Promise.resolve(fn(...args)).finally(() =>
+								this.#record(hookName, tap.name, start)
+							);
+						};
+					} else if (tap.type === "async") {
+						tap.fn = (...args: any[]) => {
+							const callback = args.pop();
+							const start = performance.now();
+							return fn(...args, (...results: any[]) => {
+								this.#record(hookName, tap.name, start);
+								callback(...results);
+							});
+						};
+					}
+					return tap;
+				}
+			});
+		}
+	}
+
+	apply(compiler: Compiler) {
+		enableHookTiming(true);
+		// all the plugins have been applied at this point
+		compiler.hooks.initialize.tap(RspackProfileHooksPlugin.name, () => {
+			this.#intercept(compiler.hooks, "compiler");
+		});
+		compiler.hooks.compilation.tap(
+			{ name: RspackProfileHooksPlugin.name, stage: Infinity },
+			compilation => {
+				this.#intercept(compilation.hooks, "compilation");
+			}
+		);
+		compiler.hooks.done.tapAsync(
+			RspackProfileHooksPlugin.name,
+			(_stats, callback) => {
+				if (compiler.watchMode) return callback();
+				const byDuration = (a: HookTiming, b: HookTiming) =>
+					b.duration - a.duration;
+				const timings = {
+					rust: takeHookTimings(),
+					javascript: Array.from(this.#timings.values()).sort(byDuration)
+				};
+				fs.writeFileSync(this.output, JSON.stringify(timings, null, 2));
+				return callback();
+			}
+		);
+	}
+}
+
 export async function applyProfile(profileValue: string, item: RspackOptions) {
 	const { default: exitHook } = await import("exit-hook");
 	const entries = Object.entries(resolveProfile(profileValue));
@@ -225,6 +355,8 @@ export async function applyProfile(profileValue: string, item: RspackOptions) {
 			);
 		} else if (kind === "LOGGING") {
 			(item.plugins ??= []).push(new RspackProfileLoggingPlugin(value.output));
+		} else if (kind === "HOOKS") {
+			(item.plugins ??= []).push(new RspackProfileHooksPlugin(value.output));
 		}
 	});
 }
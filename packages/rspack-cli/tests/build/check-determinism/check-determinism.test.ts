@@ -0,0 +1,19 @@
+import { run } from "../../utils/test-utils";
+
+describe("check determinism", () => {
+	it("should pass when the output is identical between two builds", async () => {
+		const { exitCode, stdout } = await run(__dirname, ["--check-determinism"]);
+		expect(exitCode).toBe(0);
+		expect(stdout).toContain("The output is identical between two builds");
+	});
+
+	it("should fail when the output is different between two builds", async () => {
+		const { exitCode, stderr } = await run(__dirname, [
+			"--check-determinism",
+			"--config",
+			"./random.config.js"
+		]);
+		expect(exitCode).toBe(1);
+		expect(stderr).toContain("The output is not deterministic");
+	});
+});
@@ -0,0 +1,15 @@
+// the config function is called for each build, so the output is different
+module.exports = () => ({
+	mode: "production",
+	entry: "./src/index.js",
+	output: { clean: true },
+	plugins: [
+		{
+			apply(compiler) {
+				new compiler.webpack.DefinePlugin({
+					BUILD_VALUE: JSON.stringify(String(Math.random()))
+				}).apply(compiler);
+			}
+		}
+	]
+});
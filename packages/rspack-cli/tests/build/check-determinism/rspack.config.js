@@ -0,0 +1,14 @@
+module.exports = /** @type {import('@rspack/cli').Configuration} */ {
+	mode: "production",
+	entry: "./src/index.js",
+	output: { clean: true },
+	plugins: [
+		{
+			apply(compiler) {
+				new compiler.webpack.DefinePlugin({
+					BUILD_VALUE: JSON.stringify("value")
+				}).apply(compiler);
+			}
+		}
+	]
+};
@@ -0,0 +1 @@
+export const value = BUILD_VALUE;
@@ -0,0 +1 @@
+import("./async").then(({ value }) => console.log(value));
@@ -51,7 +51,8 @@ module.exports = class RspackMinifyPlugin {
 		compiler.hooks.thisCompilation.tap("RspackMinifyPlugin", compilation => {
 			compilation.hooks.processAssets.tapPromise(
 				{
-					name: "RspackMinifyPlugin"
+					name: "RspackMinifyPlugin",
+					stage: compiler.webpack.Compilation.PROCESS_ASSETS_STAGE_OPTIMIZE_SIZE
 				},
 				async _ => {
 					const assets = compilation.getAssets().filter(asset => {
@@ -0,0 +1,37 @@
+const { createFsFromVolume, Volume } = require("memfs");
+
+const outputFileSystem = createFsFromVolume(new Volume());
+let error;
+
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description: "should fail out on the first error if bail is true",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./missing-file",
+			output: {
+				path: "/"
+			},
+			bail: true
+		};
+	},
+	async compiler(context, compiler) {
+		compiler.outputFileSystem = outputFileSystem;
+	},
+	async build(context, compiler) {
+		await new Promise(resolve => {
+			compiler.run(err => {
+				error = err;
+				resolve();
+			});
+		});
+	},
+	async check() {
+		expect(error).toBeInstanceOf(Error);
+		expect(error.message).toContain("./missing-file.js");
+		expect(error.message).toContain("Can't resolve './nonexistentfile'");
+		expect(error.message).toContain('require("./nonexistentfile")');
+		expect(outputFileSystem.existsSync("/main.js")).toBe(false);
+	}
+};
@@ -0,0 +1,27 @@
+const { createFsFromVolume, Volume } = require("memfs");
+
+const outputFileSystem = createFsFromVolume(new Volume());
+
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description: "should not emit the assets on errors if emitOnErrors is false",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./missing-file",
+			output: {
+				path: "/"
+			},
+			optimization: {
+				emitOnErrors: false
+			}
+		};
+	},
+	async compiler(context, compiler) {
+		compiler.outputFileSystem = outputFileSystem;
+	},
+	async check(_, __, stats) {
+		expect(stats.hasErrors()).toBe(true);
+		expect(outputFileSystem.existsSync("/main.js")).toBe(false);
+	}
+};
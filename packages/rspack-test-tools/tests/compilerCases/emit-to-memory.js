@@ -0,0 +1,48 @@
+const fs = require("fs");
+const path = require("path");
+
+let files = [];
+let content = null;
+let stats = null;
+
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description: "should emit assets into memory in watch mode",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./a",
+			output: {
+				path: context.getDist("emit-to-memory"),
+				filename: "bundle.js"
+			},
+			experiments: {
+				emitToMemory: true
+			}
+		};
+	},
+	async build(context, compiler) {
+		await new Promise((resolve, reject) => {
+			const watching = compiler.watch({}, err => {
+				if (err) return reject(err);
+				const fs = compiler.memoryOutputFileSystem;
+				const file = path.join(context.getDist("emit-to-memory"), "bundle.js");
+				files = fs.files();
+				content = fs.readFileSync(file);
+				stats = {
+					file: fs.statSync(file).isFile(),
+					dir: fs.statSync(context.getDist("emit-to-memory")).isDirectory(),
+					missing: fs.existsSync(file + ".missing")
+				};
+				watching.close(resolve);
+			});
+		});
+	},
+	async check(context) {
+		const file = path.join(context.getDist("emit-to-memory"), "bundle.js");
+		expect(files).toContain(file);
+		expect(content.toString("utf-8")).toContain("This is a");
+		expect(fs.existsSync(file)).toBe(false);
+		expect(stats).toEqual({ file: true, dir: true, missing: false });
+	}
+};
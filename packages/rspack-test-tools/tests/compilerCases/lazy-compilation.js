@@ -0,0 +1,26 @@
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description: "should proxy the dynamic imports with lazy compilation",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./chunk-b",
+			experiments: {
+				lazyCompilation: {
+					entries: false
+				}
+			}
+		};
+	},
+	async check(_, __, stats) {
+		const { modules } = stats.toJson({ modules: true });
+		const proxy = modules.find(m =>
+			m.identifier.startsWith("lazy-compilation-proxy|")
+		);
+		expect(proxy).toBeTruthy();
+		expect(proxy.name).toContain("lazy-compilation-proxy");
+		expect(modules.some(m => m.name === "./chunk-b.js")).toBeTruthy();
+		// the proxied module is not built until it's requested
+		expect(modules.some(m => m.name === "./b.js")).toBeFalsy();
+	}
+};
@@ -0,0 +1,40 @@
+const { LiveReloadPlugin } = require("@rspack/core");
+
+let messages = [];
+let modules = [];
+
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description: "should add the live reload client in watch mode",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./a",
+			plugins: [
+				new LiveReloadPlugin({
+					transport: {
+						client: "./b",
+						send(message) {
+							messages.push(message);
+						}
+					}
+				})
+			]
+		};
+	},
+	async build(context, compiler) {
+		await new Promise((resolve, reject) => {
+			const watching = compiler.watch({}, (err, stats) => {
+				if (err) return reject(err);
+				modules = stats.toJson({ modules: true }).modules;
+				watching.close(resolve);
+			});
+		});
+	},
+	async check() {
+		expect(modules.some(m => m.name === "./a.js")).toBeTruthy();
+		expect(modules.some(m => m.name === "./b.js")).toBeTruthy();
+		// nothing is sent for the first build
+		expect(messages).toEqual([]);
+	}
+};
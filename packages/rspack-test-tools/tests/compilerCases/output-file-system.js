@@ -0,0 +1,30 @@
+const fs = require("fs");
+const { createFsFromVolume, Volume } = require("memfs");
+
+const outputFileSystem = createFsFromVolume(new Volume());
+
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description: "should emit assets to a custom output file system",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./a",
+			output: {
+				path: "/memory/dist",
+				filename: "bundle.js"
+			}
+		};
+	},
+	async compiler(context, compiler) {
+		compiler.outputFileSystem = outputFileSystem;
+		compiler.intermediateFileSystem = outputFileSystem;
+	},
+	async check() {
+		expect(outputFileSystem.existsSync("/memory/dist/bundle.js")).toBe(true);
+		expect(
+			outputFileSystem.readFileSync("/memory/dist/bundle.js", "utf-8")
+		).toContain("This is a");
+		expect(fs.existsSync("/memory/dist/bundle.js")).toBe(false);
+	}
+};
@@ -0,0 +1,41 @@
+const fs = require("fs");
+
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description: "should revive the module and chunk ids from the records",
+	options(context) {
+		const recordsPath = context.getDist("records.json");
+		fs.mkdirSync(context.getDist(), { recursive: true });
+		fs.writeFileSync(
+			recordsPath,
+			JSON.stringify({
+				modules: { byIdentifier: { "./a.js": "recorded-a" } },
+				chunks: { byName: { main: "recorded-main" } }
+			})
+		);
+		return {
+			context: context.getSource(),
+			entry: "./chunks",
+			recordsPath,
+			optimization: {
+				moduleIds: "deterministic",
+				chunkIds: "deterministic"
+			}
+		};
+	},
+	async check(context, _, stats) {
+		const { modules, chunks } = stats.toJson({ modules: true, chunks: true });
+		expect(modules.find(m => m.name === "./a.js").id).toBe("recorded-a");
+		expect(chunks.find(c => c.names.includes("main")).id).toBe(
+			"recorded-main"
+		);
+
+		const records = JSON.parse(
+			fs.readFileSync(context.getDist("records.json"), "utf-8")
+		);
+		expect(records.modules.byIdentifier["./a.js"]).toBe("recorded-a");
+		expect(records.modules.byIdentifier["./chunks.js"]).toBeDefined();
+		expect(records.chunks.byName.main).toBe("recorded-main");
+		expect(records.chunks.usedIds).toContain("recorded-main");
+	}
+};
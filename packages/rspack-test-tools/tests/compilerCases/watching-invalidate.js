@@ -0,0 +1,52 @@
+const path = require("path");
+
+const builds = [];
+
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description: "should invalidate, suspend and resume the watching",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./a"
+		};
+	},
+	async build(context, compiler) {
+		const file = path.join(context.getSource(), "a.js");
+		let onBuild;
+		const nextBuild = () => new Promise(resolve => (onBuild = resolve));
+		const idle = () => new Promise(resolve => setTimeout(resolve, 500));
+
+		let built = nextBuild();
+		const watching = compiler.watch({ aggregateTimeout: 1000 }, err => {
+			builds.push({
+				err,
+				modifiedFiles: [...(compiler.modifiedFiles || [])]
+			});
+			onBuild();
+		});
+		await built;
+
+		built = nextBuild();
+		watching.invalidate([file]);
+		await built;
+
+		watching.suspend();
+		watching.invalidate();
+		await idle();
+		expect(builds.length).toBe(2);
+
+		built = nextBuild();
+		watching.resume();
+		await built;
+
+		await new Promise(resolve => watching.close(resolve));
+	},
+	async check() {
+		expect(builds.length).toBe(3);
+		expect(builds.every(({ err }) => !err)).toBe(true);
+		expect(builds[1].modifiedFiles).toContain(
+			path.join(__dirname, "../fixtures/a.js")
+		);
+	}
+};
@@ -0,0 +1,7 @@
+import ICON from "./icon.svg";
+
+it("should use the result of `generator.dataUrl` function as the inlined content", () => {
+	expect(ICON).toBe(
+		"data:image/svg+xml;icon.svg;" + encodeURIComponent('<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"><rect width="10" height="10" fill="red"/></svg>\n')
+	);
+});
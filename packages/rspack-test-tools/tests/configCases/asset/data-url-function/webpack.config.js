@@ -0,0 +1,24 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /\.svg$/,
+				type: "asset/inline",
+				generator: {
+					dataUrl: (content, { filename, module }) => {
+						if (module.resource !== filename) {
+							throw new Error("`module.resource` should equal to `filename`");
+						}
+						return `data:image/svg+xml;${path.basename(
+							filename
+						)};${encodeURIComponent(content)}`;
+					}
+				}
+			}
+		]
+	}
+};
@@ -0,0 +1,10 @@
+import url from "../_images/file.png";
+
+it("should compute the same url for client and server builds", () => {
+	expect(url).toEqual("images/file.png");
+});
+
+it("should only emit the asset in the client build", () => {
+	const emitted = __STATS__.assets.some(asset => asset.name === url);
+	expect(emitted).toBe(__TARGET__ === "client");
+});
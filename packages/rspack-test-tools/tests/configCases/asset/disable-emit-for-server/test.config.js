@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle: i => {
+		return [i === 0 ? "./client.js" : "./server.js"];
+	}
+};
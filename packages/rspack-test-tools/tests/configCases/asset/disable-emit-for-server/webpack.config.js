@@ -0,0 +1,29 @@
+const { DefinePlugin } = require("@rspack/core");
+
+const config = (name, emit) => ({
+	name,
+	context: __dirname,
+	output: {
+		filename: `${name}.js`,
+		assetModuleFilename: "images/file[ext]"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.png$/,
+				type: "asset/resource",
+				generator: {
+					emit
+				}
+			}
+		]
+	},
+	plugins: [
+		new DefinePlugin({
+			__TARGET__: JSON.stringify(name)
+		})
+	]
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [config("client", true), config("server", false)];
@@ -0,0 +1,10 @@
+import templateUrl from "../_images/file.png?template=1#hash";
+import functionUrl from "../_images/file.png?v=2";
+
+it("should render `[query]`, `[fragment]` and `[base]` from the resource of the asset module", () => {
+	expect(templateUrl).toEqual("file-file.png?template=1#hash");
+});
+
+it("should support a function as the asset filename", () => {
+	expect(functionUrl).toEqual("function/file.png");
+});
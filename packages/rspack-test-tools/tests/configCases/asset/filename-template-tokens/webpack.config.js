@@ -0,0 +1,25 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	output: {
+		assetModuleFilename: pathData =>
+			`function/${pathData.filename.replace(/^.*\//, "").replace(/\?.*$/, "")}`
+	},
+	module: {
+		rules: [
+			{
+				test: /\.png$/,
+				resourceQuery: /template/,
+				type: "asset/resource",
+				generator: {
+					filename: "[name]-[base][query][fragment]"
+				}
+			},
+			{
+				test: /\.png$/,
+				resourceQuery: { not: [/template/] },
+				type: "asset/resource"
+			}
+		]
+	}
+};
@@ -0,0 +1,10 @@
+import url from "../_images/file.png";
+import fs from "fs";
+import path from "path";
+
+it("should emit the asset into `generator.outputPath` and keep the url relative to `generator.publicPath`", () => {
+	expect(url).toEqual("https://cdn.example.com/images/file.png");
+	expect(
+		fs.existsSync(path.join(__STATS__.outputPath, "static/images/file.png"))
+	).toBe(true);
+});
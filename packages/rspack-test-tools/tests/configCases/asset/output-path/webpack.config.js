@@ -0,0 +1,17 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /\.png$/,
+				type: "asset/resource",
+				generator: {
+					filename: "file[ext]",
+					outputPath: "static/images/",
+					publicPath: "https://cdn.example.com/images/"
+				}
+			}
+		]
+	}
+};
@@ -0,0 +1,27 @@
+import image from "./image.png";
+import fs from "fs";
+import path from "path";
+
+it("should export the descriptor of responsive images", () => {
+	expect(image.src).toBe("images/image.png");
+	expect(image.width).toBe(40);
+	expect(image.height).toBe(20);
+	expect(image.srcSet).toBe(
+		"images/image-10w.png 10w,images/image-20w.png 20w,images/image-40w.png 40w"
+	);
+	expect(image.sources.webp).toBe(
+		"images/image-10w.webp 10w,images/image-20w.webp 20w,images/image-40w.webp 40w"
+	);
+	expect(image.images).toContainEqual({
+		path: "images/image-20w.webp",
+		width: 20,
+		height: 10,
+		format: "webp"
+	});
+});
+
+it("should emit the original image and all resized images", () => {
+	for (const { path: p } of [{ path: image.src }, ...image.images]) {
+		expect(fs.existsSync(path.join(__STATS__.outputPath, p))).toBe(true);
+	}
+});
@@ -0,0 +1,18 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.png$/,
+				type: "asset/resource",
+				generator: {
+					filename: "images/[name][ext]",
+					responsive: {
+						sizes: [10, 20, 100],
+						formats: ["png", "webp"]
+					}
+				}
+			}
+		]
+	}
+};
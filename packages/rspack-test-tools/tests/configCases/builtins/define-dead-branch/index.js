@@ -0,0 +1,22 @@
+it("should not build the modules required in the dead branches", () => {
+	let target;
+	if (process.env.TARGET === "node") {
+		target = require("./does-not-exist-node");
+	} else {
+		target = require("./web");
+	}
+	expect(target).toBe("web");
+
+	const mode = DEBUG ? require("./does-not-exist-debug") : "release";
+	expect(mode).toBe("release");
+
+	const logger = DEBUG && require("./does-not-exist-logger");
+	expect(logger).toBe(false);
+});
+
+it("should keep the declarations hoisted from the dead branches", () => {
+	if (process.env.TARGET !== "web") {
+		var hoisted = require("./does-not-exist-hoisted");
+	}
+	expect(hoisted).toBeUndefined();
+});
@@ -0,0 +1,12 @@
+const fs = require("fs");
+const path = require("path");
+
+it("[minify-css-exclude]: chunk a should be minified", () => {
+	const content = fs.readFileSync(path.resolve(__dirname, "a.css"), "utf-8");
+	expect(content).not.toMatch("\n");
+});
+
+it("[minify-css-exclude]: chunk b should not be minified", () => {
+	const content = fs.readFileSync(path.resolve(__dirname, "b.css"), "utf-8");
+	expect(content).toMatch("\n");
+});
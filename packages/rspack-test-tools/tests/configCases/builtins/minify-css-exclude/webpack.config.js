@@ -0,0 +1,22 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		a: "./a",
+		b: "./b",
+		main: "./index"
+	},
+	output: {
+		filename: "[name].js",
+		cssFilename: "[name].css"
+	},
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new rspack.SwcCssMinimizerRspackPlugin({
+				exclude: [/b\.css/]
+			})
+		]
+	}
+};
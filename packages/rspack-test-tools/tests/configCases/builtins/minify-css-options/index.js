@@ -0,0 +1,31 @@
+const fs = require("fs");
+const path = require("path");
+
+it("[minify-css-options]: should keep the preserved comments", () => {
+	const content = fs.readFileSync(path.resolve(__dirname, "a.css"), "utf-8");
+	expect(content).toMatch("/*! license of a */");
+	expect(content).not.toMatch("comment of a");
+	expect(content.indexOf("/*! comment before a */")).toBeGreaterThan(
+		content.indexOf("@keyframes used")
+	);
+	expect(content.indexOf("/*! comment before a */")).toBeLessThan(
+		content.indexOf(".a{")
+	);
+});
+
+it("[minify-css-options]: should round the computed numbers", () => {
+	const content = fs.readFileSync(path.resolve(__dirname, "a.css"), "utf-8");
+	expect(content).toMatch("width:33.33%");
+});
+
+it("[minify-css-options]: should discard the unused keyframes", () => {
+	const content = fs.readFileSync(path.resolve(__dirname, "a.css"), "utf-8");
+	expect(content).toMatch("@keyframes used");
+	expect(content).not.toMatch("@keyframes unused");
+});
+
+it("[minify-css-options]: should only remove whitespaces without compress", () => {
+	const content = fs.readFileSync(path.resolve(__dirname, "b.css"), "utf-8");
+	expect(content).toMatch("margin:0px 0px 0px 0px");
+	expect(content).not.toMatch("\n");
+});
@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle: (i, options) => {
+		return ["main.js"];
+	}
+};
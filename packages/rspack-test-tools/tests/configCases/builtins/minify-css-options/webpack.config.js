@@ -0,0 +1,29 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		a: "./a",
+		b: "./b",
+		main: "./index"
+	},
+	output: {
+		filename: "[name].js",
+		cssFilename: "[name].css"
+	},
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new rspack.SwcCssMinimizerRspackPlugin({
+				include: [/a\.css/],
+				preserveComments: "!",
+				discardUnusedKeyframes: true,
+				calcPrecision: 2
+			}),
+			new rspack.SwcCssMinimizerRspackPlugin({
+				include: [/b\.css/],
+				compress: false
+			})
+		]
+	}
+};
@@ -0,0 +1,28 @@
+const fs = require("fs");
+const path = require("path");
+
+/**
+ * Utility functions for the foo package.
+ * @license Apache-2.0
+ */
+
+/*! Legal Foo */
+
+it("should extract comments to the file of the custom filename", () => {
+	const content = fs.readFileSync(
+		path.resolve(__dirname, "licenses/bundle0.txt"),
+		"utf-8"
+	);
+	expect(content).toContain("@license Apache-2.0");
+	expect(content).not.toContain("Legal Foo");
+
+	const bundle = fs.readFileSync(__filename, "utf-8");
+	expect(
+		bundle.startsWith(
+			"/*! For license information please see licenses/bundle0.txt */"
+		)
+	).toBe(true);
+	expect(fs.existsSync(path.resolve(__dirname, "bundle0.js.LICENSE.txt"))).toBe(
+		false
+	);
+});
@@ -0,0 +1,16 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new rspack.SwcJsMinimizerRspackPlugin({
+				extractComments: {
+					condition: /@license/,
+					filename: "licenses/[name].txt"
+				}
+			})
+		]
+	}
+};
@@ -0,0 +1,17 @@
+const obj = {
+	_mangled: 1,
+	_reserved: 2,
+	_quoted: 3,
+	notMatched: 4
+};
+obj["_quoted"] = 5;
+
+it("should mangle the property names matching the regex", () => {
+	const keys = Object.keys(obj);
+	expect(keys).not.toContain("_mangled");
+	expect(keys).toContain("_reserved");
+	expect(keys).toContain("_quoted");
+	expect(keys).toContain("notMatched");
+	expect(obj._mangled).toBe(1);
+	expect(obj._quoted).toBe(5);
+});
@@ -0,0 +1,19 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new rspack.SwcJsMinimizerRspackPlugin({
+				mangle: {
+					properties: {
+						regex: /^_[a-z]/,
+						reserved: ["_reserved"],
+						keep_quoted: true
+					}
+				}
+			})
+		]
+	}
+};
@@ -0,0 +1,13 @@
+const fs = require("fs");
+
+console.log("test-console");
+
+function main() {}
+
+it("should map the terser options onto the minifier", () => {
+	expect(main.name).toBe("main");
+	const content = fs.readFileSync(__filename, "utf-8");
+	// "test-console", encoded to not be kept in the output by the assertion itself
+	const log = Buffer.from("dGVzdC1jb25zb2xl", "base64").toString();
+	expect(content).not.toContain(log);
+});
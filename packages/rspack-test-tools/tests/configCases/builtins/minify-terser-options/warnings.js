@@ -0,0 +1,3 @@
+module.exports = [
+	[/terser options are not supported and ignored: .*compress\.hoist_exports/]
+];
@@ -0,0 +1,28 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new rspack.SwcJsMinimizerRspackPlugin({
+				terserOptions: {
+					compress: {
+						drop_console: true,
+						// not supported, ignored with a warning
+						hoist_exports: true
+					},
+					mangle: {
+						properties: false
+					},
+					keep_fnames: true,
+					output: {
+						comments: /@preserve/,
+						wrap_iife: true
+					},
+					nameCache: {}
+				}
+			})
+		]
+	}
+};
@@ -0,0 +1,21 @@
+it("should retry loading the failed chunks from the alternate public paths", async function () {
+	const scripts = [];
+	const appendChild = document.head.appendChild;
+	document.head.appendChild = function (node) {
+		scripts.push(node);
+		return appendChild.call(this, node);
+	};
+
+	const promise = import("./chunk" /* webpackChunkName: "chunk" */);
+	expect(scripts.length).toBe(1);
+	expect(scripts[0].src).toBe("https://test.cases/path/chunk.js");
+	expect(scripts[0].timeout).toBe(30);
+	scripts[0].onerror({ type: "error", target: scripts[0] });
+
+	const module = await promise;
+	expect(module.default).toBe("chunk");
+	await new Promise(resolve => setTimeout(resolve, 50));
+	document.head.appendChild = appendChild;
+	expect(scripts.length).toBe(2);
+	expect(scripts[1].src).toBe("https://test.cases/path/./chunk.js");
+});
@@ -0,0 +1,14 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	output: {
+		publicPath: "https://test.cases/path/",
+		chunkFilename: "[name].js",
+		chunkLoadTimeout: 30000,
+		chunkLoadRetry: {
+			times: 1,
+			delay: 10,
+			publicPaths: ["https://test.cases/path/./"]
+		}
+	}
+};
@@ -0,0 +1,5 @@
+const { greeting } = require("./greeting");
+
+module.exports = {
+	message: `${greeting}, build time`
+};
@@ -0,0 +1 @@
+exports.greeting = "hello";
@@ -0,0 +1,7 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should emit the exports of the module executed at build time", () => {
+	const content = fs.readFileSync(path.join(__dirname, "data.json"), "utf-8");
+	expect(JSON.parse(content)).toEqual({ message: "hello, build time" });
+});
@@ -0,0 +1,28 @@
+const { RawSource } = require("webpack-sources");
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			let data;
+			compilation.hooks.finishModules.tapPromise(pluginName, async () => {
+				data = await compilation.importModule("./data.js");
+			});
+			compilation.hooks.processAssets.tap(pluginName, () => {
+				compilation.emitAsset(
+					"data.json",
+					new RawSource(JSON.stringify(data))
+				);
+				expect(
+					compilation.fileDependencies.has(require.resolve("./greeting"))
+				).toBe(true);
+			});
+		});
+	}
+}
+
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	context: __dirname,
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,7 @@
+import { a } from "./a";
+import { b } from "./b";
+
+it("should rebuild all the modules requested in the same tick", () => {
+	expect(a).toEqual(2);
+	expect(b).toEqual(2);
+});
@@ -0,0 +1,5 @@
+const times = {};
+module.exports = function loader(content) {
+	times[this.resourcePath] = (times[this.resourcePath] || 0) + 1;
+	return content.replace("1", times[this.resourcePath]);
+};
@@ -0,0 +1,57 @@
+const pluginName = "plugin";
+
+function rebuild(compilation, module) {
+	return new Promise((resolve, reject) => {
+		compilation.rebuildModule(module, (err, m) => {
+			if (err) {
+				reject(err);
+			} else {
+				resolve(m);
+			}
+		});
+	});
+}
+
+class Plugin {
+	apply(compiler) {
+		let initial = true;
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.finishModules.tapPromise(pluginName, async modules => {
+				if (!initial) return;
+				initial = false;
+				const targets = [...modules].filter(item =>
+					/[ab]\.js$/.test(item.resource || "")
+				);
+				expect(targets.length).toBe(2);
+
+				// both rebuilds are merged into a single call to the binding
+				const rebuilt = await Promise.all(
+					targets.map(module => rebuild(compilation, module))
+				);
+				expect(rebuilt.map(m => m.identifier())).toEqual(
+					targets.map(m => m.identifier())
+				);
+				for (const m of rebuilt) {
+					expect(m.originalSource.source().includes("= 2")).toBe(true);
+				}
+			});
+		});
+	}
+}
+
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /[ab]\.js$/,
+				use: [
+					{
+						loader: "./loader"
+					}
+				]
+			}
+		]
+	},
+	plugins: [new Plugin()]
+};
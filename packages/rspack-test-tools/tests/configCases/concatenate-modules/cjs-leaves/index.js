@@ -0,0 +1,9 @@
+import { result, fromThis } from "./lib";
+
+it("should inline the commonjs leaves into the concatenated module", () => {
+	expect(result).toBe(43);
+	expect(fromThis).toBe(true);
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	expect(content).toContain("// INLINED MODULE: ./leaf.js");
+	expect(content).toContain("// INLINED MODULE: ./replaced.js");
+});
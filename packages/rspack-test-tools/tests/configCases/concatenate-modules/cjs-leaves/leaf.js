@@ -0,0 +1,4 @@
+"use strict";
+
+exports.value = 42;
+this.fromThis = true;
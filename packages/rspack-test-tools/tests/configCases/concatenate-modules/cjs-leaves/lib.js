@@ -0,0 +1,5 @@
+import { value, fromThis } from "./leaf";
+import add from "./replaced";
+
+export const result = add(value, 1);
+export { fromThis };
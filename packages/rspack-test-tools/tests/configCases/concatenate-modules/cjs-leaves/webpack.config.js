@@ -0,0 +1,10 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	optimization: {
+		concatenateModules: {
+			allowCjsLeaves: true
+		},
+		minimize: false
+	}
+};
@@ -0,0 +1,39 @@
+it("should use the fallback when the container fails to load", () => {
+	return import("missing/value").then(({ default: value }) => {
+		expect(value).toBe("fallback value");
+	});
+});
+
+it("should use the fallback for every module of the remote", () => {
+	return import("missing/other").then(({ default: value }) => {
+		expect(value).toBe("fallback value");
+	});
+});
+
+it("should use the fallback of an exposed module", () => {
+	return import("partial/value").then(({ default: value }) => {
+		expect(value).toBe("fallback value");
+	});
+});
+
+it("should still reject for exposed modules without a fallback", () => {
+	return import("partial/other").then(
+		() => {
+			throw new Error("should not load");
+		},
+		err => {
+			expect(err.name).toBe("ScriptExternalLoadError");
+		}
+	);
+});
+
+it("should still reject for remotes without a fallback", () => {
+	return import("broken/value").then(
+		() => {
+			throw new Error("should not load");
+		},
+		err => {
+			expect(err.name).toBe("ScriptExternalLoadError");
+		}
+	);
+});
@@ -0,0 +1,22 @@
+const { ContainerReferencePlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	plugins: [
+		new ContainerReferencePlugin({
+			remoteType: "script",
+			remotes: {
+				missing: {
+					external: "missing@./missing-container.js",
+					fallback: "./fallback.js"
+				},
+				partial: {
+					external: "partial@./missing-container.js",
+					fallback: { "./value": "./fallback.js" }
+				},
+				broken: "broken@./broken-container.js"
+			}
+		})
+	]
+};
@@ -0,0 +1,3 @@
+it("should import the correct modules", () => {
+	return import("./module").then(({ test }) => test());
+});
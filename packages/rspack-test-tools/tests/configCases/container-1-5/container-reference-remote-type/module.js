@@ -0,0 +1,9 @@
+import abc from "abc/hello-world";
+import def from "def/hello-world";
+import ghi from "ghi/hello-world";
+
+export function test() {
+	expect(abc).toBe("abc ./hello-world");
+	expect(def).toBe("def ./hello-world");
+	expect(ghi).toBe("ghi ./hello-world");
+}
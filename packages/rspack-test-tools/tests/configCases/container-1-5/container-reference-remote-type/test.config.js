@@ -0,0 +1,13 @@
+const createContainer = name => ({
+	get(module) {
+		return Promise.resolve(() => name + " " + module);
+	}
+});
+
+module.exports = {
+	moduleScope(scope) {
+		scope.ABC = createContainer("abc");
+		scope.DEF = createContainer("def");
+		scope.GHI = createContainer("ghi");
+	}
+};
@@ -0,0 +1,17 @@
+const { ContainerReferencePlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new ContainerReferencePlugin({
+			remoteType: "var",
+			remotes: {
+				abc: "ABC",
+				// the type prefix overrides `remoteType`
+				def: "promise Promise.resolve(DEF)",
+				// the first item fails, so the fallback is used
+				ghi: ["MISSING", "GHI"]
+			}
+		})
+	]
+};
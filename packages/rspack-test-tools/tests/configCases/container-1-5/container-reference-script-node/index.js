@@ -0,0 +1,52 @@
+import fs from "fs";
+import http from "http";
+import path from "path";
+
+it("should load the var container from the file system", () => {
+	return import("var/value").then(({ default: value }) => {
+		expect(value).toBe("remote value");
+	});
+});
+
+it("should load the commonjs container from the file system", () => {
+	return import("cjs/value").then(({ default: value }) => {
+		expect(value).toBe("remote value");
+	});
+});
+
+it("should reject when the container file does not exist", () => {
+	return import("missing/value").then(
+		() => {
+			throw new Error("should not load");
+		},
+		err => {
+			expect(err.name).toBe("ScriptExternalLoadError");
+			expect(err.type).toBe("missing");
+			expect(err.cause.code).toBe("ENOENT");
+		}
+	);
+});
+
+it("should fetch and evaluate containers over http", async () => {
+	const server = http.createServer((req, res) => {
+		const file = path.join(__dirname, req.url);
+		if (!fs.existsSync(file)) {
+			res.statusCode = 404;
+			return res.end();
+		}
+		res.end(fs.readFileSync(file));
+	});
+	await new Promise(resolve => server.listen(PORT, resolve));
+	try {
+		const { default: value } = await import("http/value");
+		expect(value).toBe("remote value");
+		const err = await import("httpMissing/value").then(
+			() => new Error("should not load"),
+			err => err
+		);
+		expect(err.name).toBe("ScriptExternalLoadError");
+		expect(err.type).toBe("missing");
+	} finally {
+		server.close();
+	}
+});
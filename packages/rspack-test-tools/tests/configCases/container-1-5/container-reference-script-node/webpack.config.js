@@ -0,0 +1,60 @@
+const { DefinePlugin } = require("@rspack/core");
+const { ContainerPlugin, ContainerReferencePlugin } =
+	require("@rspack/core").container;
+
+const PORT = 19123;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [
+		new ContainerPlugin({
+			name: "varContainer",
+			filename: "var-container.js",
+			library: {
+				type: "var",
+				name: "varContainer"
+			},
+			exposes: {
+				"./value": "./value"
+			}
+		}),
+		new ContainerPlugin({
+			name: "cjsContainer",
+			filename: "cjs-container.js",
+			library: {
+				type: "commonjs-module"
+			},
+			exposes: {
+				"./value": "./value"
+			}
+		}),
+		new ContainerPlugin({
+			name: "httpContainer",
+			filename: "http-container.js",
+			library: {
+				type: "var",
+				name: "httpContainer"
+			},
+			exposes: {
+				"./value": "./value"
+			}
+		}),
+		new ContainerReferencePlugin({
+			remoteType: "script",
+			remotes: {
+				var: "varContainer@./var-container.js",
+				cjs: "cjsContainer@./cjs-container.js",
+				missing: "missingContainer@./missing-container.js",
+				http: `httpContainer@http://localhost:${PORT}/http-container.js`,
+				httpMissing: `httpMissingContainer@http://localhost:${PORT}/missing-container.js`
+			}
+		}),
+		new DefinePlugin({
+			PORT
+		})
+	]
+};
@@ -0,0 +1,5 @@
+import value from "dynamic/value";
+
+export function test() {
+	expect(value).toBe("remote value");
+}
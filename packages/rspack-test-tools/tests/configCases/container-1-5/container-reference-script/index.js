@@ -0,0 +1,7 @@
+it("should load the remote from the script url", () => {
+	return import("./module").then(({ test }) => test());
+});
+
+it("should load the remote from the promise", () => {
+	return import("./dynamic").then(({ test }) => test());
+});
@@ -0,0 +1,45 @@
+const { ContainerPlugin, ContainerReferencePlugin } =
+	require("@rspack/core").container;
+
+const CONTAINER_URL = "https://test.cases/path/container.js";
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	output: {
+		filename: "[name].js",
+		publicPath: "https://test.cases/path/"
+	},
+	plugins: [
+		new ContainerPlugin({
+			name: "container",
+			filename: "container.js",
+			library: {
+				type: "window",
+				name: "container"
+			},
+			exposes: {
+				"./value": "./value"
+			}
+		}),
+		new ContainerReferencePlugin({
+			remoteType: "script",
+			remotes: {
+				remote: `container@${CONTAINER_URL}`,
+				dynamic: `promise new Promise(function(resolve) {
+					var done = function() {
+						resolve({
+							get: function(request) { return window.container.get(request); },
+							init: function(scope) { return window.container.init(scope); }
+						});
+					};
+					if (window.container) return done();
+					var script = document.createElement("script");
+					script.src = ${JSON.stringify(CONTAINER_URL)};
+					script.onload = done;
+					document.head.appendChild(script);
+				})`
+			}
+		})
+	]
+};
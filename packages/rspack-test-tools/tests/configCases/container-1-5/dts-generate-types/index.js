@@ -0,0 +1,34 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+const zlib = __non_webpack_require__("zlib");
+
+function readArchive(buffer) {
+	const files = {};
+	let offset = 0;
+	while (offset + 512 <= buffer.length) {
+		const name = buffer.toString("utf-8", offset, offset + 100).replace(/\0.*$/s, "");
+		if (!name) break;
+		const size = parseInt(buffer.toString("utf-8", offset + 124, offset + 136), 8);
+		files[name] = buffer.toString("utf-8", offset + 512, offset + 512 + size);
+		offset += 512 + Math.ceil(size / 512) * 512;
+	}
+	return files;
+}
+
+const files = readArchive(
+	zlib.gunzipSync(fs.readFileSync(path.resolve(__dirname, "@mf-types.tgz")))
+);
+
+it("should emit the declarations of the exposed modules", () => {
+	expect(files["src/Button.d.ts"]).toContain("label: string;");
+});
+
+it("should emit an entry declaration for every expose", () => {
+	expect(files["Button.d.ts"]).toBe(
+		'export * from "./src/Button";\nexport { default } from "./src/Button";\n'
+	);
+});
+
+it("should only re-export the default export when there is one", () => {
+	expect(files["index.d.ts"]).toBe('export * from "./src/utils";\n');
+});
@@ -0,0 +1,7 @@
+export interface ButtonProps {
+	label: string;
+}
+
+export default function Button(props: ButtonProps): string {
+	return `Button ${props.label}`;
+}
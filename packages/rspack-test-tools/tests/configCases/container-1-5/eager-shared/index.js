@@ -0,0 +1,18 @@
+import lib from "lib";
+
+it("should consume the eager shared module synchronously", () => {
+	expect(lib).toBe("lib 1.0.0");
+});
+
+it("should bundle the eager shared module into the initial chunk", () => {
+	const initialConsumes =
+		__webpack_require__.consumesLoadingData.initialConsumes;
+	expect(initialConsumes.length).toBe(1);
+	expect(__webpack_modules__[initialConsumes[0]]).toBeDefined();
+});
+
+it("should register the eager shared module in the share scope", () => {
+	const [shared] = __webpack_require__.federation.initOptions.shared.lib;
+	expect(shared.version).toBe("1.0.0");
+	expect(shared.shareConfig.eager).toBe(true);
+});
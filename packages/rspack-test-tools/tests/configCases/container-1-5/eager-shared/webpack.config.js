@@ -0,0 +1,15 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "eager_shared",
+			shared: {
+				lib: {
+					eager: true
+				}
+			}
+		})
+	]
+};
@@ -0,0 +1,63 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+const manifest = JSON.parse(
+	fs.readFileSync(path.resolve(__dirname, "mf-manifest.json"), "utf-8")
+);
+
+it("should describe the container", () => {
+	expect(manifest.id).toBe("container");
+	expect(manifest.metaData).toEqual({
+		name: "container",
+		type: "app",
+		globalName: "container",
+		publicPath: "auto",
+		remoteEntry: {
+			name: "container.js",
+			path: "",
+			type: "commonjs-module"
+		}
+	});
+});
+
+it("should describe the exposes with their assets", () => {
+	expect(manifest.exposes.length).toBe(2);
+	const [button, card] = manifest.exposes;
+	expect(button.id).toBe("container:Button");
+	expect(button.name).toBe("Button");
+	expect(button.path).toBe("./Button");
+	expect(button.assets.js.sync.length).toBeGreaterThan(0);
+	expect(button.assets.js.sync).not.toContain("card.js");
+	for (const file of button.assets.js.sync) {
+		expect(fs.existsSync(path.resolve(__dirname, file))).toBe(true);
+	}
+	expect(card.id).toBe("container:Card");
+	expect(card.path).toBe("./Card");
+	expect(card.assets.js.sync).toContain("card.js");
+});
+
+it("should merge the provided versions of a shared module", () => {
+	const shared = manifest.shared.filter(item => item.name === "shared-lib");
+	expect(shared.length).toBe(1);
+	expect(shared[0]).toMatchObject({
+		id: "container:shared-lib",
+		version: "1.2.0",
+		singleton: true,
+		requiredVersion: "^1.0.0"
+	});
+	expect(shared[0].assets.js.sync.length).toBeGreaterThan(0);
+});
+
+it("should describe the remotes", () => {
+	expect(manifest.remotes).toEqual([
+		{
+			federationContainerName: "containerA",
+			moduleName: "Button",
+			alias: "containerA",
+			entry: "*"
+		}
+	]);
+	return import("containerA/Button").then(({ default: Button }) => {
+		expect(Button).toBe("Button with shared-lib");
+	});
+});
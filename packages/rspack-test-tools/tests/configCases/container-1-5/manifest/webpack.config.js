@@ -0,0 +1,40 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js",
+		uniqueName: "manifest"
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			library: { type: "commonjs-module" },
+			filename: "container.js",
+			manifest: true,
+			exposes: {
+				"./Button": "./Button",
+				"./Card": {
+					import: "./Card",
+					name: "card"
+				}
+			},
+			remotes: {
+				containerA: {
+					external: "./container.js"
+				}
+			},
+			shared: {
+				"shared-lib": {
+					singleton: true,
+					requiredVersion: "^1.0.0"
+				},
+				"shared-lib-next": {
+					shareKey: "shared-lib",
+					singleton: true,
+					requiredVersion: "^1.0.0"
+				}
+			}
+		})
+	]
+};
@@ -0,0 +1,17 @@
+it("should use the fallback when the remote fails to load", async () => {
+	const { default: value } = await import("missing/value");
+	expect(value).toBe("fallback value");
+});
+
+it("should use the fallback of an exposed module", async () => {
+	const { default: value } = await import("partial/value");
+	expect(value).toBe("fallback value");
+});
+
+it("should still reject for exposed modules without a fallback", async () => {
+	await expect(import("partial/other")).rejects.toEqual(
+		expect.objectContaining({
+			code: "ENOENT"
+		})
+	);
+});
@@ -0,0 +1,22 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			library: { type: "commonjs-module" },
+			filename: "container.js",
+			remotes: {
+				missing: {
+					external: "./missing-container.js",
+					fallback: "./fallback.js"
+				},
+				partial: {
+					external: "./missing-container.js",
+					fallback: { "./value": "./fallback.js" }
+				}
+			}
+		})
+	]
+};
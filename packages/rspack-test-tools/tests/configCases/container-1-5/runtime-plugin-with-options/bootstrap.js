@@ -0,0 +1,15 @@
+export function test(it) {
+	it("should pass the options to the runtime plugin", () => {
+		expect(__webpack_require__.federation.pluginOptions).toEqual({
+			retries: 3
+		});
+	});
+
+	it("should add the returned plugin to the init options", () => {
+		expect(
+			__webpack_require__.federation.initOptions.plugins.map(
+				plugin => plugin && plugin.name
+			)
+		).toContain("plugin-with-options");
+	});
+}
@@ -0,0 +1,6 @@
+export default function (options) {
+	__webpack_require__.federation.pluginOptions = options;
+	return {
+		name: "plugin-with-options"
+	};
+}
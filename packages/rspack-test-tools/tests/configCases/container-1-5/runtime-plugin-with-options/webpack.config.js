@@ -0,0 +1,10 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new ModuleFederationPlugin({
+			runtimePlugins: [["./plugin.js", { retries: 3 }]]
+		})
+	]
+};
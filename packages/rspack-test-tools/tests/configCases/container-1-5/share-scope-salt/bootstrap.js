@@ -0,0 +1,13 @@
+import lib from "lib";
+import other from "other";
+
+export function test() {
+	expect(lib).toBe("lib");
+	expect(other).toBe("other");
+	const { shared } = __webpack_require__.federation.initOptions;
+	expect(shared.lib[0].scope).toEqual(["default@v2"]);
+	expect(shared.other[0].scope).toEqual(["legacy@v2"]);
+	expect(Object.keys(__webpack_require__.S)).toContain("default@v2");
+	expect(Object.keys(__webpack_require__.S)).toContain("legacy@v2");
+	expect(Object.keys(__webpack_require__.S)).not.toContain("default");
+}
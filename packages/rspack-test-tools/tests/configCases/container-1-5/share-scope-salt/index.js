@@ -0,0 +1,3 @@
+it("should load the shared modules from the salted share scopes", () => {
+	return import("./bootstrap").then(({ test }) => test());
+});
@@ -0,0 +1,17 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "share_scope_salt",
+			shareScopeSalt: "v2",
+			shared: {
+				lib: {},
+				other: {
+					shareScope: "legacy"
+				}
+			}
+		})
+	]
+};
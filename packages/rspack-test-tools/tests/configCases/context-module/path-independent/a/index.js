@@ -0,0 +1,14 @@
+const fs = require("fs");
+
+it("should emit the same chunks for the copies in different directories", () => {
+	return import("./lazy").then(({ default: keys }) => {
+		expect(keys).toContain("./x.js");
+		const hashes = ["a", "b"].map(name =>
+			fs
+				.readdirSync(__dirname)
+				.find(file => file.startsWith(`${name}.`) && file !== `${name}.js`)
+				.slice(name.length + 1)
+		);
+		expect(hashes[0]).toBe(hashes[1]);
+	});
+});
@@ -0,0 +1,3 @@
+const context = require.context("./dir");
+
+export default context.keys();
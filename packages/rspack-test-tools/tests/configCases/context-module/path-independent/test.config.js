@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle: i => {
+		return [i === 0 ? "./a.js" : "./b.js"];
+	}
+};
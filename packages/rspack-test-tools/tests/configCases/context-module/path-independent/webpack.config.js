@@ -0,0 +1,20 @@
+const path = require("path");
+
+const config = name => ({
+	name,
+	context: path.resolve(__dirname, name),
+	entry: "./index.js",
+	node: {
+		__dirname: false
+	},
+	output: {
+		filename: `${name}.js`,
+		chunkFilename: `${name}.[contenthash].js`
+	},
+	experiments: {
+		pathIndependent: true
+	}
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [config("a"), config("b")];
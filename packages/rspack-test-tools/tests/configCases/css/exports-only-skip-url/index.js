@@ -0,0 +1,9 @@
+import * as styles from "./style.module.css";
+const fs = require("fs");
+
+it("should only export the locals and not emit assets referenced by the css", function () {
+	expect(typeof styles.logo).toBe("string");
+	const files = fs.readdirSync(__dirname);
+	expect(files.some(file => file.endsWith(".css"))).toBe(false);
+	expect(files.some(file => file.endsWith(".png"))).toBe(false);
+});
@@ -0,0 +1,19 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		generator: {
+			"css/auto": {
+				exportsOnly: true
+			}
+		},
+		rules: [
+			{
+				test: /\.png$/i,
+				type: "asset/resource"
+			}
+		]
+	},
+	experiments: {
+		css: true
+	}
+};
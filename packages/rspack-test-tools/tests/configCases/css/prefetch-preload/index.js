@@ -0,0 +1,26 @@
+// the chunk is only prefetched, not loaded
+export function loadPrefetched() {
+	return import(
+		/* webpackChunkName: "prefetched", webpackPrefetch: true */ "./prefetched"
+	);
+}
+
+const getLinks = rel =>
+	document.head._children.filter(
+		child => child._type === "link" && child.rel === rel
+	);
+
+it("should prefetch the css of the child chunks on startup", () => {
+	const links = getLinks("prefetch");
+	expect(links.map(link => link.as).sort()).toEqual(["script", "style"]);
+	const style = links.find(link => link.as === "style");
+	expect(style.href).toBe("https://test.cases/path/prefetched.css");
+});
+
+it("should preload the css of the child chunks when the parent chunk is loaded", () => {
+	import(/* webpackChunkName: "parent" */ "./parent").catch(() => {});
+	const links = getLinks("preload");
+	expect(links.map(link => link.as).sort()).toEqual(["script", "style"]);
+	const style = links.find(link => link.as === "style");
+	expect(style.href).toBe("https://test.cases/path/preloaded.css");
+});
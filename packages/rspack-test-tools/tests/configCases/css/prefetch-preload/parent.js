@@ -0,0 +1 @@
+import(/* webpackChunkName: "preloaded", webpackPreload: true */ "./preloaded");
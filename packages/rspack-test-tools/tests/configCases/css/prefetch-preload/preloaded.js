@@ -0,0 +1 @@
+import "./preloaded.css";
@@ -0,0 +1,12 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	output: {
+		chunkFilename: "[name].js",
+		cssChunkFilename: "[name].css",
+		crossOriginLoading: "anonymous"
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -41,7 +41,7 @@ module.exports = {
 								"bundle0.js",
 								new ConcatSource(
 									new RawSource(
-										`Object.assign(globalThis, { document: { currentScript: { src: "/" } } });\n`
+										`Object.assign(globalThis, { document: { currentScript: { src: "/", tagName: "script" } } });\n`
 									),
 									assets["bundle0.js"]
 								)
@@ -0,0 +1,17 @@
+require("./index.css");
+const fs = require("fs");
+const path = require("path");
+
+it("should rewrite the urls in image-set() and keep fragments", function () {
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	const a = /a: image-set\((.*)\);/.exec(css)[1];
+	expect(a.includes("./logo.png")).toBe(false);
+	expect(a).toMatch(/^"[^"]+\.png" 1x, "[^"]+\.png" 2x$/);
+	const b = /b: -webkit-image-set\((.*)\);/.exec(css)[1];
+	expect(b).toMatch(/^url\([^"]+\.png\) 1x$/);
+	const c = /c: image-set\((.*)\);/.exec(css)[1];
+	expect(c).toMatch(/^"[^"]+\.png" type\("image\/png"\)$/);
+	const d = /d: url\((.*)\);/.exec(css)[1];
+	expect(d.endsWith(".png#hash")).toBe(true);
+	expect(css).toContain("e: url()");
+});
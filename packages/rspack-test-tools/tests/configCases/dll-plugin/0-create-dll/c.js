@@ -0,0 +1,3 @@
+import { d } from "./d";
+
+export const c = "c" + d;
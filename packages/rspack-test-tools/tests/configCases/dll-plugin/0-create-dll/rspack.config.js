@@ -0,0 +1,21 @@
+const path = require("path");
+const rspack = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: ["./a", "./b", "./c"],
+	output: {
+		filename: "dll.js",
+		library: {
+			type: "commonjs2"
+		}
+	},
+	plugins: [
+		new rspack.DllPlugin({
+			path: path.resolve(
+				__dirname,
+				"../../../js/config/dll-plugin/manifest0.json"
+			)
+		})
+	]
+};
@@ -0,0 +1,28 @@
+import a from "../0-create-dll/a";
+import b from "../0-create-dll/b";
+import { c } from "../0-create-dll/c";
+
+const manifest = JSON.parse(
+	require("fs").readFileSync(
+		require("path").resolve(__dirname, "../manifest0.json"),
+		"utf-8"
+	)
+);
+
+it("should load the modules from the dll", () => {
+	expect(a).toBe("a");
+	expect(c).toBe("cd");
+});
+
+it("should delegate the modules to the dll", () => {
+	expect(String(b)).toBe(String(manifest.content["./b.js"].id));
+});
+
+it("should only include the entry modules in the manifest", () => {
+	expect(Object.keys(manifest.content).sort()).toEqual([
+		"./a.js",
+		"./b.js",
+		"./c.js"
+	]);
+	expect(manifest.content["./c.js"].exports).toEqual(["c"]);
+});
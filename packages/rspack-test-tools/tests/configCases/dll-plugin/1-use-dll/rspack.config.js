@@ -0,0 +1,17 @@
+const path = require("path");
+const rspack = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.DllReferencePlugin({
+			manifest: path.resolve(
+				__dirname,
+				"../../../js/config/dll-plugin/manifest0.json"
+			),
+			name: "../0-create-dll/dll.js",
+			context: path.resolve(__dirname, "../0-create-dll"),
+			sourceType: "commonjs2"
+		})
+	]
+};
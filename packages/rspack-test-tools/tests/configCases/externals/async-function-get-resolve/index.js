@@ -0,0 +1,8 @@
+import lib from "./vendor/lib";
+
+it("should externalize with the resolved resource", function () {
+	expect(lib).toEqual({
+		issuer: "index.js",
+		resource: "vendor/lib.js"
+	});
+});
@@ -0,0 +1,19 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: [
+		async function ({ context, request, contextInfo, getResolve }) {
+			if (!request.startsWith("./vendor")) {
+				return;
+			}
+			const resolve = getResolve();
+			const resource = await resolve(context, request);
+			const result = {
+				issuer: path.relative(__dirname, contextInfo.issuer),
+				resource: path.relative(__dirname, resource).replace(/\\/g, "/")
+			};
+			return `var ${JSON.stringify(result)}`;
+		}
+	]
+};
@@ -0,0 +1,8 @@
+import { join } from "path";
+import readFile from "fs-read-file";
+
+it("should create require for the commonjs externals in the esm output", function () {
+	// the assertions of the generated code are in the webpack.config.js
+	expect(typeof join).toBe("function");
+	expect(typeof readFile).toBe("function");
+});
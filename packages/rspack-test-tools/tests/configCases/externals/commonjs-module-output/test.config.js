@@ -0,0 +1,6 @@
+module.exports = {
+	findBundle: (i, options) => {
+		// the output of the web target requires the externals without node
+		return i === 0 ? ["main.mjs"] : [];
+	}
+};
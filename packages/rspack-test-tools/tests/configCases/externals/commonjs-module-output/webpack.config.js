@@ -0,0 +1,74 @@
+const assert = require("assert");
+
+const output = filename => ({
+	module: true,
+	chunkFormat: "module",
+	filename
+});
+
+const checkSource = (filename, check) => ({
+	apply(compiler) {
+		compiler.hooks.thisCompilation.tap("test", compilation => {
+			compilation.hooks.processAssets.tap("test", assets => {
+				check(assets[filename].source());
+			});
+		});
+	}
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		target: "node",
+		externals: {
+			path: "commonjs path",
+			"fs-read-file": ["commonjs fs", "readFile"]
+		},
+		output: output("[name].mjs"),
+		experiments: {
+			outputModule: true
+		},
+		plugins: [
+			checkSource("main.mjs", source => {
+				assert(
+					source.includes(
+						"import { createRequire as __WEBPACK_EXTERNAL_createRequire } from 'module';"
+					)
+				);
+				assert(
+					source.includes(
+						"__WEBPACK_EXTERNAL_createRequire(import.meta.url)('path')"
+					)
+				);
+				assert(
+					source.includes(
+						"__WEBPACK_EXTERNAL_createRequire(import.meta.url)('fs').readFile"
+					)
+				);
+				assert(!/[^_\w]require\(/.test(source));
+			})
+		]
+	},
+	{
+		// only the node-commonjs externals create require for the other targets
+		target: "web",
+		externals: {
+			path: "commonjs path",
+			"fs-read-file": ["node-commonjs fs", "readFile"]
+		},
+		output: output("[name].web.mjs"),
+		experiments: {
+			outputModule: true
+		},
+		plugins: [
+			checkSource("main.web.mjs", source => {
+				assert(source.includes("module.exports = require('path')"));
+				assert(
+					source.includes(
+						"__WEBPACK_EXTERNAL_createRequire(import.meta.url)('fs').readFile"
+					)
+				);
+			})
+		]
+	}
+];
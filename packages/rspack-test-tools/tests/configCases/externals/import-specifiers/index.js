@@ -0,0 +1,13 @@
+const fs = require("fs");
+
+function load() {
+	return import("external-lib");
+}
+
+it("should access the specifiers of the import externals", function () {
+	expect(typeof load).toBe("function");
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).toMatch(
+		/module\.exports = import\("external-lib"\)\.then\(function\(module\) \{ return module\["nested"\]\["value"\]; \}\)/
+	);
+});
@@ -0,0 +1,8 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	externals: {
+		"external-lib": ["external-lib", "nested", "value"]
+	},
+	externalsType: "import"
+};
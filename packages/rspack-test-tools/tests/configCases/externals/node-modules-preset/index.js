@@ -0,0 +1,22 @@
+const fs = require("fs");
+import bundled from "bundled-pkg";
+import local from "./local";
+import aliased from "aliased-pkg";
+
+function load() {
+	return [require("external-pkg"), require("@scope/external-pkg/index.js")];
+}
+
+it("should externalize the packages in node_modules", function () {
+	expect(typeof load).toBe("function");
+	expect(bundled).toBe("bundled-pkg");
+	expect(local).toBe("local");
+	expect(aliased).toBe("aliased");
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).toMatch(/module\.exports = require\('external-pkg'\)/);
+	expect(content).toMatch(
+		/module\.exports = require\('@scope\/external-pkg\/index\.js'\)/
+	);
+	expect(content).not.toMatch(/require\('bundled-pkg'\)/);
+	expect(content).not.toMatch(/require\('aliased-pkg'\)/);
+});
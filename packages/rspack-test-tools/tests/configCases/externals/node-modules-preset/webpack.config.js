@@ -0,0 +1,16 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	resolve: {
+		alias: {
+			"aliased-pkg": path.resolve(__dirname, "aliased.js")
+		}
+	},
+	externalsPresets: {
+		nodeModules: {
+			allowlist: ["bundled-pkg"]
+		}
+	}
+};
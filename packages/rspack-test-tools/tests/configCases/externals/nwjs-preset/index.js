@@ -0,0 +1,11 @@
+const fs = require("fs");
+
+function load() {
+	return require("nw.gui");
+}
+
+it("should externalize the nw.gui module", function () {
+	expect(typeof load).toBe("function");
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).toMatch(/module\.exports = require\('nw\.gui'\)/);
+});
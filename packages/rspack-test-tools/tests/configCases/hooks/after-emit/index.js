@@ -0,0 +1,3 @@
+it("should call afterEmit after the assets are emitted", () => {
+	expect(1).toBe(1);
+});
@@ -0,0 +1,34 @@
+const path = require("path");
+const assert = require("assert").strict;
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		const emitted = [];
+		let afterEmitCalled = false;
+		compiler.hooks.assetEmitted.tap(pluginName, (filename, info) => {
+			assert.equal(info.outputPath, compiler.outputPath);
+			assert.equal(info.targetPath, path.join(info.outputPath, filename));
+			emitted.push(filename);
+		});
+		compiler.hooks.afterEmit.tapPromise(pluginName, async compilation => {
+			assert(emitted.includes("bundle0.js"));
+			const content = compiler.outputFileSystem.readFileSync(
+				path.join(compiler.outputPath, "bundle0.js"),
+				"utf-8"
+			);
+			assert(content.includes("should call afterEmit"));
+			assert.equal(compilation, compiler.compilation);
+			afterEmitCalled = true;
+		});
+		compiler.hooks.done.tap(pluginName, () => {
+			assert(afterEmitCalled);
+		});
+	}
+}
+
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	context: __dirname,
+	plugins: [new Plugin()]
+};
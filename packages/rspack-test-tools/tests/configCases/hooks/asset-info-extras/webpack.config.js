@@ -0,0 +1,63 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		function plugin(compiler) {
+			const { RawSource } = compiler.webpack.sources;
+			compiler.hooks.compilation.tap("test", compilation => {
+				compilation.hooks.processAssets.tap(
+					{
+						name: "test",
+						stage: compiler.webpack.Compilation.PROCESS_ASSETS_STAGE_ADDITIONAL
+					},
+					() => {
+						compilation.emitAsset("extra.txt", new RawSource("extra"), {
+							development: true,
+							integrity: "sha384-abc"
+						});
+					}
+				);
+				compilation.hooks.processAssets.tap(
+					{
+						name: "test",
+						stage: compiler.webpack.Compilation.PROCESS_ASSETS_STAGE_ADDITIONS
+					},
+					() => {
+						const asset = compilation.getAsset("extra.txt");
+						expect(asset.info.integrity).toBe("sha384-abc");
+						expect(asset.info.development).toBe(true);
+
+						// the info object is merged into the existing info
+						compilation.updateAsset("extra.txt", new RawSource("updated"), {
+							custom: { nested: [1, 2] }
+						});
+						expect(compilation.getAsset("extra.txt").info).toMatchObject({
+							development: true,
+							integrity: "sha384-abc",
+							custom: { nested: [1, 2] }
+						});
+
+						// the info function receives the existing custom fields
+						compilation.updateAsset(
+							"extra.txt",
+							source => source,
+							info => ({ ...info, integrity: `${info.integrity}-updated` })
+						);
+						expect(compilation.getAsset("extra.txt").info.integrity).toBe(
+							"sha384-abc-updated"
+						);
+
+						compilation.renameAsset("extra.txt", "renamed.txt");
+						const renamed = compilation
+							.getAssets()
+							.find(asset => asset.name === "renamed.txt");
+						expect(renamed.info.integrity).toBe("sha384-abc-updated");
+						expect(renamed.source.source()).toBe("updated");
+
+						compilation.deleteAsset("renamed.txt");
+						expect(compilation.getAsset("renamed.txt")).toBeUndefined();
+					}
+				);
+			});
+		}
+	]
+};
@@ -0,0 +1,3 @@
+import tla from "./tla";
+
+export default `async ${tla}`;
@@ -0,0 +1,13 @@
+import value from "./esm";
+const cjs = require("./cjs");
+
+it("should keep the build info set by plugins", () => {
+	expect(value).toBe("esm");
+	expect(cjs).toBe("cjs");
+});
+
+it("should load the async modules", () => {
+	return import("./async-parent").then(({ default: value }) => {
+		expect(value).toBe("async tla");
+	});
+});
@@ -0,0 +1,5 @@
+module.exports = function (content) {
+	this._module.buildInfo.fromLoader = { resource: this.resourcePath };
+	this._module.buildMeta.sideEffectFree = true;
+	return content;
+};
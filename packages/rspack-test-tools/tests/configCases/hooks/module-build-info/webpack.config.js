@@ -0,0 +1,63 @@
+const path = require("path");
+const assert = require("assert").strict;
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.succeedModule.tap(pluginName, module => {
+				module.buildInfo.marked = { resource: module.resource };
+				// reading the build meta syncs it back to the module
+				module.buildMeta;
+			});
+			compilation.hooks.finishModules.tap(pluginName, modules => {
+				const byName = name =>
+					Array.from(modules).find(
+						m => m.resource === path.join(__dirname, name)
+					);
+				const esm = byName("esm.js");
+				const cjs = byName("cjs.js");
+				assert.deepEqual(esm.buildInfo.marked, { resource: esm.resource });
+				assert.deepEqual(cjs.buildInfo.marked, { resource: cjs.resource });
+				assert.equal(esm.buildInfo.cacheable, true);
+				assert.equal(esm.buildMeta.esModule, true);
+				assert.equal(esm.buildMeta.exportsType, "namespace");
+				assert.equal(cjs.buildMeta.esModule, false);
+				assert.equal(cjs.buildMeta.exportsType, undefined);
+				assert.deepEqual(esm.buildInfo.fromLoader, { resource: esm.resource });
+				assert.equal(esm.buildMeta.sideEffectFree, true);
+				assert.equal(cjs.buildInfo.fromLoader, undefined);
+			});
+			compilation.hooks.processAssets.tap(pluginName, () => {
+				const byName = name =>
+					Array.from(compilation.modules).find(
+						m => m.resource === path.join(__dirname, name)
+					);
+				// the async flag of the module graph, not only top level await
+				assert.equal(byName("tla.js").buildMeta.async, true);
+				assert.equal(byName("async-parent.js").buildMeta.async, true);
+				assert.equal(byName("esm.js").buildMeta.async, false);
+				// the async flag is not synced back as top level await
+				assert.equal(byName("tla.js").buildMeta.hasTopLevelAwait, true);
+				assert.equal(
+					byName("async-parent.js").buildMeta.hasTopLevelAwait,
+					false
+				);
+			});
+		});
+	}
+}
+
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /esm\.js$/,
+				use: [{ loader: "./loader.js" }]
+			}
+		]
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,3 @@
+it("should call the optimization hooks in order", () => {
+	expect(1).toBe(1);
+});
@@ -0,0 +1,52 @@
+const assert = require("assert").strict;
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		const calls = [];
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.optimizeModules.tap(pluginName, () => {
+				calls.push("optimizeModules");
+			});
+			compilation.hooks.afterOptimizeModules.tap(pluginName, () => {
+				calls.push("afterOptimizeModules");
+			});
+			compilation.hooks.optimizeTree.tap(pluginName, () => {
+				calls.push("optimizeTree");
+			});
+			compilation.hooks.afterOptimizeTree.tap(
+				{ name: pluginName, stage: 10 },
+				() => {
+					calls.push("afterOptimizeTree:10");
+				}
+			);
+			compilation.hooks.afterOptimizeTree.tap(
+				pluginName,
+				(chunks, modules) => {
+					assert(Array.from(chunks).length > 0);
+					assert(Array.from(modules).length > 0);
+					calls.push("afterOptimizeTree:0");
+				}
+			);
+			compilation.hooks.optimizeChunkModules.tap(pluginName, () => {
+				calls.push("optimizeChunkModules");
+			});
+		});
+		compiler.hooks.done.tap(pluginName, () => {
+			assert.deepEqual(calls, [
+				"optimizeModules",
+				"afterOptimizeModules",
+				"optimizeTree",
+				"afterOptimizeTree:0",
+				"afterOptimizeTree:10",
+				"optimizeChunkModules"
+			]);
+		});
+	}
+}
+
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	context: __dirname,
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,5 @@
+it("should ignore the resources rejected by checkResource", () => {
+	expect(require("./locale/en")).toBe("en");
+	expect(require("./locale/fr")).toBe("fr");
+	expect(() => require("./locale/de")).toThrow();
+});
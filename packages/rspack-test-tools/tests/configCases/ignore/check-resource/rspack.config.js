@@ -0,0 +1,17 @@
+const rspack = require("@rspack/core");
+
+const locales = ["en", "fr"];
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.IgnorePlugin({
+			checkResource(resource, context) {
+				if (context !== __dirname || !resource.startsWith("./locale/")) {
+					return false;
+				}
+				return !locales.some(locale => resource === `./locale/${locale}`);
+			}
+		})
+	]
+};
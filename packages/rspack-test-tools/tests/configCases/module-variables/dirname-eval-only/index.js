@@ -0,0 +1,6 @@
+const path = require("path");
+
+it("should keep __dirname and __filename of the output file", function () {
+	expect(path.basename(__filename)).toBe("bundle0.js");
+	expect(path.dirname(__filename)).toBe(__dirname);
+});
@@ -0,0 +1,4 @@
+module.exports = [
+	[/"__dirname" has been used, it will be undefined in next major version/],
+	[/"__filename" has been used, it will be undefined in next major version/]
+];
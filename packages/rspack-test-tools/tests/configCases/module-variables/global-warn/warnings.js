@@ -0,0 +1,3 @@
+module.exports = [
+	[/"global" has been used, it will be undefined in next major version/]
+];
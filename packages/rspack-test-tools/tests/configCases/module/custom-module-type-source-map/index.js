@@ -0,0 +1,13 @@
+import fs from "fs";
+import path from "path";
+import message from "./message.txt";
+
+it("should keep the source map returned by the custom `parse` function", () => {
+	expect(message).toBe("hello");
+	const map = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "bundle0.js.map"), "utf-8")
+	);
+	const index = map.sources.indexOf("webpack:///./message.txt");
+	expect(index).toBeGreaterThan(-1);
+	expect(map.sourcesContent[index]).toBe("hello\n");
+});
@@ -0,0 +1,31 @@
+const { CustomModuleTypeRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: "source-map",
+	module: {
+		rules: [
+			{
+				test: /\.txt$/,
+				type: "text"
+			}
+		]
+	},
+	plugins: [
+		new CustomModuleTypeRspackPlugin({
+			type: "text",
+			parse(source, { resource }) {
+				return {
+					code: `export default ${JSON.stringify(source.trim())};`,
+					map: {
+						version: 3,
+						sources: [resource],
+						sourcesContent: [source],
+						names: [],
+						mappings: "AAAA"
+					}
+				};
+			}
+		})
+	]
+};
@@ -0,0 +1,11 @@
+import query, { name } from "./query.graphql";
+import message from "./message.txt";
+
+it("should convert the module with the custom `parse` function", () => {
+	expect(query).toContain("hero");
+	expect(name).toBe("HeroName");
+});
+
+it("should post-process the generated code with the custom `generate` function", () => {
+	expect(message).toBe("HELLO");
+});
@@ -0,0 +1 @@
+export const operationName = query => /^\s*(?:query|mutation)\s+(\w+)/.exec(query)[1];
@@ -0,0 +1,39 @@
+const { CustomModuleTypeRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.graphql$/,
+				type: "graphql"
+			},
+			{
+				test: /\.txt$/,
+				type: "text"
+			}
+		]
+	},
+	plugins: [
+		new CustomModuleTypeRspackPlugin({
+			type: "graphql",
+			parse(source) {
+				return [
+					`import { operationName } from "./utils.js";`,
+					`const query = ${JSON.stringify(source)};`,
+					`export const name = operationName(query);`,
+					`export default query;`
+				].join("\n");
+			}
+		}),
+		new CustomModuleTypeRspackPlugin({
+			type: "text",
+			parse(source) {
+				return `export default ${JSON.stringify(source.trim())};`;
+			},
+			generate(code) {
+				return code.replace('"hello"', '"HELLO"');
+			}
+		})
+	]
+};
@@ -0,0 +1,12 @@
+import fs from "fs";
+import { name, version } from "./data.ini";
+
+it("should parse the module with the custom `parse` of json parser", () => {
+	expect(name).toBe("rspack");
+	expect(version).toBe(1);
+});
+
+it("should keep the tree shaking of json modules", () => {
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source.includes(["unused", "ini", "value"].join("-"))).toBe(false);
+});
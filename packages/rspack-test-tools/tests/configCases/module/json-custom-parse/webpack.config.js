@@ -0,0 +1,28 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	module: {
+		rules: [
+			{
+				test: /\.ini$/,
+				type: "json",
+				parser: {
+					parse(source) {
+						return Object.fromEntries(
+							source
+								.split("\n")
+								.filter(Boolean)
+								.map(line => {
+									const [key, value] = line.split("=").map(i => i.trim());
+									return [key, /^\d+$/.test(value) ? Number(value) : value];
+								})
+						);
+					}
+				}
+			}
+		]
+	},
+	optimization: {
+		minimize: false
+	}
+};
@@ -0,0 +1,8 @@
+it("should load css chunk with nonce 'nonce1234'", function () {
+	__webpack_nonce__ = "nonce1234";
+	import("./chunk.css").catch(() => {});
+
+	const link = document.head._children.find(child => child._type === "link");
+	expect(link.rel).toBe("stylesheet");
+	expect(link.getAttribute("nonce")).toBe("nonce1234");
+});
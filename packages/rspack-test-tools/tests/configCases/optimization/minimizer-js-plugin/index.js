@@ -0,0 +1,18 @@
+const fs = require("fs");
+
+it("should run the js minimizers before generating the source maps", () => {
+	const unminifiedVariableName = "not minified by the builtin minimizers";
+	expect(unminifiedVariableName).toBeTruthy();
+
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content.startsWith("/* minimized by BannerMinimizerPlugin */\n")).toBe(
+		true
+	);
+	expect(content).toContain("unminifiedVariableName");
+	expect(content).toContain("/* FunctionMinimizer */\n//# sourceMappingURL=");
+
+	const map = JSON.parse(fs.readFileSync(__filename + ".map", "utf-8"));
+	// the line of the banner has no mappings
+	expect(map.mappings.startsWith(";")).toBe(true);
+	expect(map.sources).toContain("webpack:///./index.js");
+});
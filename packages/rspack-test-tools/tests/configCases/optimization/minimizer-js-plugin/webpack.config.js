@@ -0,0 +1,60 @@
+const { Compilation, sources } = require("@rspack/core");
+
+const BANNER = "/* minimized by BannerMinimizerPlugin */\n";
+
+class BannerMinimizerPlugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap("BannerMinimizerPlugin", compilation => {
+			compilation.hooks.processAssets.tap(
+				{
+					name: "BannerMinimizerPlugin",
+					stage: Compilation.PROCESS_ASSETS_STAGE_OPTIMIZE_SIZE
+				},
+				() => {
+					for (const asset of compilation.getAssets()) {
+						if (!asset.name.endsWith(".js") || asset.info.minimized) {
+							continue;
+						}
+						compilation.updateAsset(
+							asset.name,
+							new sources.ConcatSource(BANNER, asset.source),
+							{ minimized: true }
+						);
+					}
+				}
+			);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: "source-map",
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new BannerMinimizerPlugin(),
+			function (compiler) {
+				compiler.hooks.compilation.tap("FunctionMinimizer", compilation => {
+					compilation.hooks.processAssets.tap(
+						{
+							name: "FunctionMinimizer",
+							stage: Compilation.PROCESS_ASSETS_STAGE_OPTIMIZE_SIZE + 1
+						},
+						() => {
+							for (const asset of compilation.getAssets()) {
+								// runs after `BannerMinimizerPlugin`
+								if (asset.name.endsWith(".js") && asset.info.minimized) {
+									compilation.updateAsset(
+										asset.name,
+										new sources.ConcatSource(asset.source, "\n/* FunctionMinimizer */")
+									);
+								}
+							}
+						}
+					);
+				});
+			}
+		]
+	}
+};
@@ -0,0 +1,19 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should load the hot update files relative to the output path", async () => {
+	const requireBundle = await fs.promises.readFile(
+		path.resolve(__dirname, "require/main.js"),
+		"utf-8"
+	);
+	const readFileBundle = await fs.promises.readFile(
+		path.resolve(__dirname, "read-file/main.js"),
+		"utf-8"
+	);
+	expect(requireBundle).toContain(
+		'require("../" + __webpack_require__.hu(chunkId))'
+	);
+	expect(requireBundle).toContain('require("../" + __webpack_require__.hmrF())');
+	expect(readFileBundle).toContain('"../" + __webpack_require__.hu(chunkId)');
+	expect(readFileBundle).toContain('"../" + __webpack_require__.hmrF()');
+});
@@ -0,0 +1,26 @@
+const { HotModuleReplacementPlugin } = require("@rspack/core");
+
+module.exports = [
+	{
+		entry: "./a.js",
+		output: {
+			filename: "require/[name].js"
+		},
+		plugins: [new HotModuleReplacementPlugin()],
+		target: "node"
+	},
+	{
+		entry: "./a.js",
+		output: {
+			filename: "read-file/[name].js"
+		},
+		plugins: [new HotModuleReplacementPlugin()],
+		target: "async-node"
+	},
+	{
+		entry: "./index.js",
+		output: {
+			filename: "index.js"
+		}
+	}
+];
@@ -0,0 +1,12 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should load hot update chunks with import()", async () => {
+	const a = await fs.promises.readFile(
+		path.resolve(__dirname, "a.mjs"),
+		"utf-8"
+	);
+	expect(a).toContain("function loadUpdateChunk(chunkId, updatedModulesList)");
+	expect(a).toContain("__webpack_require__.hu(chunkId)).then(function (obj)");
+	expect(a).toContain("__webpack_require__.hmrC.module");
+});
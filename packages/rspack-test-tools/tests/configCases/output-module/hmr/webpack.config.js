@@ -0,0 +1,23 @@
+const { HotModuleReplacementPlugin } = require("@rspack/core");
+
+module.exports = [
+	{
+		entry: "./a.js",
+		output: {
+			filename: "a.mjs",
+			chunkFormat: "module",
+			chunkLoading: "import"
+		},
+		experiments: {
+			outputModule: true
+		},
+		plugins: [new HotModuleReplacementPlugin()],
+		target: "web"
+	},
+	{
+		entry: "./index.js",
+		output: {
+			filename: "index.js"
+		}
+	}
+];
@@ -0,0 +1,11 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should use import.meta.url for the module scripts", () => {
+	const content = fs.readFileSync(path.resolve(__dirname, "module.js"), "utf-8");
+	expect(content).toContain(
+		'if (typeof import.meta.url === "string") scriptUrl = import.meta.url'
+	);
+	expect(content).not.toContain("document.currentScript");
+	expect(content).not.toContain("__webpack_require__.g = ");
+});
@@ -0,0 +1,12 @@
+it("should load the chunk from the public path of the current script", function () {
+	const promise = import("./chunk");
+
+	var script = document.head._children.pop();
+	__non_webpack_require__("./auto-public-path.web.js");
+	expect(script.src).toBe("https://test.cases/path/auto-public-path.web.js");
+	expect(__webpack_public_path__).toBe("https://test.cases/path/");
+
+	return promise.then(({ default: value }) => {
+		expect(value).toBe("chunk");
+	});
+});
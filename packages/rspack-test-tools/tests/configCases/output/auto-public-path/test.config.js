@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle: i => {
+		return [["./bundle0.js"], [], ["./check.js"]][i];
+	}
+};
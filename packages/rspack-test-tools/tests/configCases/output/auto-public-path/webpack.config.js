@@ -0,0 +1,30 @@
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		entry: "./index.js",
+		target: "web",
+		output: {
+			publicPath: "auto",
+			chunkFilename: "auto-public-path.web.js"
+		}
+	},
+	{
+		entry: "./module.js",
+		target: "web",
+		output: {
+			filename: "module.js",
+			chunkFilename: "[name].module.js",
+			scriptType: "module",
+			publicPath: "auto"
+		},
+		optimization: {
+			minimize: false
+		}
+	},
+	{
+		entry: "./check.js",
+		output: {
+			filename: "check.js"
+		}
+	}
+];
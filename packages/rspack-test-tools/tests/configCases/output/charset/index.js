@@ -0,0 +1,12 @@
+it("should set the charset of the script tags according to output.charset", function () {
+	const promise = import("./chunk" /* webpackChunkName: "chunk" */);
+
+	const script = document.head._children.pop();
+	expect(script._type).toBe("script");
+	expect(script.charset).toBe(CHARSET ? "utf-8" : undefined);
+	__non_webpack_require__(`./chunk-${CHARSET}.js`);
+
+	return promise.then(module => {
+		expect(module.default).toBe("chunk");
+	});
+});
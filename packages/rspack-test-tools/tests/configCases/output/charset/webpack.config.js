@@ -0,0 +1,11 @@
+const { DefinePlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [true, false].map(charset => ({
+	target: "web",
+	output: {
+		chunkFilename: `[name]-${charset}.js`,
+		charset
+	},
+	plugins: [new DefinePlugin({ CHARSET: JSON.stringify(charset) })]
+}));
@@ -0,0 +1,3 @@
+it("should hash with the custom hash function", () => {
+	expect(__webpack_hash__).toMatch(EXPECTED_HASH);
+});
@@ -0,0 +1,35 @@
+const rspack = require("@rspack/core");
+
+class ConstantHash {
+	update() {
+		return this;
+	}
+	digest(encoding) {
+		return Buffer.alloc(16, 0xab).toString(encoding);
+	}
+}
+
+/** @type {import('@rspack/core').Configuration[]} */
+module.exports = [
+	{
+		output: {
+			hashFunction: ConstantHash
+		},
+		plugins: [
+			new rspack.DefinePlugin({
+				EXPECTED_HASH: /^(ab){10,16}$/.toString()
+			})
+		]
+	},
+	{
+		output: {
+			hashFunction: "sha1",
+			hashDigestLength: 40
+		},
+		plugins: [
+			new rspack.DefinePlugin({
+				EXPECTED_HASH: /^[0-9a-f]{40}$/.toString()
+			})
+		]
+	}
+];
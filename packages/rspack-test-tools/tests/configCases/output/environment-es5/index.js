@@ -0,0 +1,31 @@
+const version = v => ({
+	get: () => () => `shared@${v}`
+});
+
+it("should parse the required version in the ES5 consumes runtime", () => {
+	__webpack_share_scopes__["default"] = {
+		shared: {
+			"1.5.0": version("1.5.0"),
+			"2.0.0": version("2.0.0"),
+			"3.1.4": version("3.1.4"),
+			"3.2.0": version("3.2.0")
+		}
+	};
+	expect(require("shared")).toBe("shared@3.1.4");
+});
+
+it("should not emit ES2015 syntax in the consumes runtime", () => {
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	const start = content.indexOf("var splitAndConvert = ");
+	const end = content.indexOf("var installedModules = ");
+	expect(start).toBeGreaterThan(-1);
+	expect(end).toBeGreaterThan(start);
+	// the runtime keeps its comments, which may mention arrows
+	const runtime = content
+		.slice(start, end)
+		.replace(/\/\*[\s\S]*?\*\//g, "")
+		.replace(/\/\/.*$/gm, "");
+	for (const syntax of ["=" + ">", "const ", "let ", "(?<" + "="]) {
+		expect(runtime).not.toContain(syntax);
+	}
+});
@@ -0,0 +1,29 @@
+const { ConsumeSharedPlugin } = require("@rspack/core").sharing;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		environment: {
+			arrowFunction: false,
+			bigIntLiteral: false,
+			const: false,
+			destructuring: false,
+			dynamicImport: false,
+			forOf: false,
+			globalThis: false,
+			module: false,
+			optionalChaining: false,
+			templateLiteral: false
+		}
+	},
+	plugins: [
+		new ConsumeSharedPlugin({
+			consumes: {
+				shared: {
+					import: false,
+					requiredVersion: ">= 1.0.0 <2.0.0 || ~3.1.0"
+				}
+			}
+		})
+	]
+};
@@ -0,0 +1,11 @@
+import data from "./data.txt";
+
+it("should declare the concatenated asset according to output.environment.const", function () {
+	expect(data).toBe("rspack\n");
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	expect(content).toMatch(
+		CONST
+			? /const __WEBPACK_NAMESPACE_OBJECT__ = "rspack\\n"/
+			: /var __WEBPACK_NAMESPACE_OBJECT__ = "rspack\\n"/
+	);
+});
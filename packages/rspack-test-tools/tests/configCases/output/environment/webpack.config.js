@@ -0,0 +1,19 @@
+const { DefinePlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [true, false].map(supportsConst => ({
+	output: {
+		environment: {
+			const: supportsConst
+		}
+	},
+	module: {
+		rules: [
+			{
+				test: /\.txt$/,
+				type: "asset/source"
+			}
+		]
+	},
+	plugins: [new DefinePlugin({ CONST: JSON.stringify(supportsConst) })]
+}));
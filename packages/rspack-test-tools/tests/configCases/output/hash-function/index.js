@@ -0,0 +1,9 @@
+import fs from "fs";
+import path from "path";
+
+it("should use the hash function and the digest of the output", async function () {
+	await import(/* webpackChunkName: "two" */ "./two");
+	const chunks = fs.readdirSync(path.resolve(__dirname, CHUNKS));
+	expect(chunks).toHaveLength(1);
+	expect(chunks[0]).toMatch(new RegExp(`^two\\.${HASH}\\.js$`));
+});
@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle: function (i, options) {
+		return [`./${options.output.filename}`];
+	}
+};
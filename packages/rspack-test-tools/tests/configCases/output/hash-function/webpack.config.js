@@ -0,0 +1,39 @@
+const { DefinePlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		entry: "./index.js",
+		target: "node",
+		output: {
+			filename: "hex.js",
+			chunkFilename: "hex/[name].[chunkhash].js",
+			hashFunction: "sha256",
+			hashDigestLength: 64
+		},
+		plugins: [
+			new DefinePlugin({
+				CHUNKS: JSON.stringify("hex"),
+				HASH: JSON.stringify("[0-9a-f]{64}")
+			})
+		]
+	},
+	{
+		entry: "./index.js",
+		target: "node",
+		output: {
+			filename: "base64url.js",
+			chunkFilename: "base64url/[name].[chunkhash].js",
+			hashFunction: "sha256",
+			hashDigest: "base64url",
+			hashDigestLength: 43,
+			hashSalt: "salt"
+		},
+		plugins: [
+			new DefinePlugin({
+				CHUNKS: JSON.stringify("base64url"),
+				HASH: JSON.stringify("[0-9A-Za-z_-]{43}")
+			})
+		]
+	}
+];
@@ -0,0 +1,12 @@
+it("should replace import.meta.env with an object of the defined values", () => {
+	const { MODE, DEV, UNKNOWN } = import.meta.env;
+	expect(MODE).toBe("test");
+	expect(DEV).toBe(false);
+	expect(UNKNOWN).toBeUndefined();
+	expect(typeof import.meta.env).toBe("object");
+});
+
+it("should replace the defined properties of import.meta.env", () => {
+	expect(import.meta.env.MODE).toBe("test");
+	expect(import.meta.env.UNKNOWN).toBeUndefined();
+});
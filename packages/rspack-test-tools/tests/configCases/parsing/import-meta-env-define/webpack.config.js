@@ -0,0 +1,18 @@
+const rspack = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		parser: {
+			javascript: {
+				importMetaEnv: "define"
+			}
+		}
+	},
+	plugins: [
+		new rspack.DefinePlugin({
+			"import.meta.env.MODE": JSON.stringify("test"),
+			"import.meta.env.DEV": "false"
+		})
+	]
+};
@@ -0,0 +1,6 @@
+// the current script is only available while the script is evaluated
+const url = import.meta.url;
+
+it("should use the url of the current script at runtime", () => {
+	expect(url).toBe("https://test.cases/path/index.js");
+});
@@ -0,0 +1,6 @@
+import path from "path";
+
+it("should use the url of the output file at runtime", () => {
+	expect(typeof import.meta.url).toBe("string");
+	expect(path.basename(new URL(import.meta.url).pathname)).toBe("bundle0.js");
+});
@@ -0,0 +1,11 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	module: {
+		parser: {
+			javascript: {
+				importMetaUrl: "runtime"
+			}
+		}
+	}
+};
@@ -0,0 +1,33 @@
+import fs from "fs";
+import path from "path";
+
+it("should emit the bundle analysis", async () => {
+	await Promise.all([import("./a"), import("./b")]);
+	const analysis = JSON.parse(
+		fs.readFileSync(
+			path.join(__STATS__.outputPath, "bundle-analysis.json"),
+			"utf-8"
+		)
+	);
+
+	const main = analysis.assets.find(asset => asset.name === "bundle0.js");
+	expect(main.chunks).toHaveLength(1);
+	const size = fs.statSync(path.join(__STATS__.outputPath, "bundle0.js")).size;
+	expect(main.size).toBe(size);
+	const index = main.modules.find(m => m.name === "./index.js");
+	expect(index.size).toBeGreaterThan(0);
+	expect(index.parsedSize).toBeGreaterThan(0);
+	const parsedSize = main.modules.reduce((sum, m) => sum + m.parsedSize, 0);
+	expect(Math.abs(parsedSize - size)).toBeLessThan(main.modules.length);
+
+	const shared = analysis.modules.find(m => m.name === "./shared.js");
+	expect(shared.chunks).toHaveLength(2);
+	const duplicate = analysis.duplicates.find(m => m.name === "./shared.js");
+	expect(duplicate.chunks).toEqual(shared.chunks);
+	expect(duplicate.duplicatedSize).toBe(shared.size);
+
+	const entry = analysis.chunks.find(chunk => chunk.names.includes("main"));
+	expect(entry.entry).toBe(true);
+	expect(entry.initial).toBe(true);
+	expect(entry.files).toEqual(["bundle0.js"]);
+});
@@ -0,0 +1 @@
+export default "shared between the async chunks";
@@ -0,0 +1,11 @@
+const rspack = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		minimize: true,
+		concatenateModules: false,
+		splitChunks: false
+	},
+	plugins: [new rspack.BundleAnalysisRspackPlugin()]
+};
@@ -0,0 +1,6 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should merge the assets of the child compilation into the parent", () => {
+	expect(fs.existsSync(path.resolve(__dirname, "child-output.js"))).toBe(true);
+});
@@ -0,0 +1,37 @@
+class TestPlugin {
+	apply(compiler) {
+		compiler.hooks.make.tapAsync(TestPlugin.name, (compilation, callback) => {
+			const child = compilation.createChildCompiler(
+				"TestChild",
+				{ filename: "[name].js" },
+				[
+					new compiler.webpack.EntryPlugin(compiler.context, "./child.js", {
+						name: "child-output"
+					})
+				]
+			);
+			child.runAsChild((err, entries, childCompilation) => {
+				if (err) return callback(err);
+				try {
+					expect(entries.map(chunk => chunk.name)).toEqual(["child-output"]);
+					expect(entries.flatMap(chunk => [...chunk.files])).toEqual([
+						"child-output.js"
+					]);
+					expect(
+						compilation.getAssetPath("[name].html", { chunk: entries[0] })
+					).toBe("child-output.html");
+					expect(childCompilation.getAsset("child-output.js")).toBeTruthy();
+				} catch (e) {
+					return callback(e);
+				}
+				callback();
+			});
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	plugins: [new TestPlugin()]
+};
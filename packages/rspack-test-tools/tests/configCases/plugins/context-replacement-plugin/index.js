@@ -0,0 +1,17 @@
+it("should only include the files matching the new regExp", function () {
+	const load = name => require("./locale/" + name + ".js");
+	expect(load("en")).toBe("en");
+	expect(load("zh")).toBe("zh");
+	expect(() => load("fr")).toThrow("Cannot find module './fr.js'");
+});
+
+it("should replace the resource and the recursive flag", function () {
+	const load = name => require("./pages/" + name);
+	expect(load("home")).toBe("mobile");
+	expect(() => load("nested/about")).toThrow();
+});
+
+it("should replace the request in a callback", function () {
+	const load = name => require("./views/" + name);
+	expect(load("list")).toBe("v2");
+});
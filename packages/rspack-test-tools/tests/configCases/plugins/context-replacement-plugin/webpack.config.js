@@ -0,0 +1,16 @@
+const path = require("path");
+const { ContextReplacementPlugin } = require("@rspack/core");
+
+module.exports = /** @type {import("@rspack/core").Configuration} */ ({
+	plugins: [
+		new ContextReplacementPlugin(/[/\\]locale$/, /(en|zh)\.js$/),
+		new ContextReplacementPlugin(
+			/[/\\]pages$/,
+			path.resolve(__dirname, "mobile-pages"),
+			false
+		),
+		new ContextReplacementPlugin(/^\.\/views$/, data => {
+			data.request = "./views-v2";
+		})
+	]
+});
@@ -0,0 +1,31 @@
+import fs from "fs";
+import path from "path";
+import shared from "shared";
+import excluded from "excluded";
+import dep from "dep";
+
+it("should report the duplicated packages", () => {
+	expect(shared + excluded).toBe("shared@1excluded@1");
+	expect(dep).toBe("shared@2excluded@2");
+
+	const report = JSON.parse(
+		fs.readFileSync(
+			path.join(__STATS__.outputPath, "duplicate-packages.json"),
+			"utf-8"
+		)
+	);
+	expect(report).toHaveLength(1);
+	const [duplicate] = report;
+	expect(duplicate.name).toBe("shared");
+	expect(duplicate.instances.map(instance => instance.version)).toEqual([
+		"1.0.0",
+		"2.0.0"
+	]);
+	expect(duplicate.instances[1].path).toMatch(
+		/node_modules[\\/]dep[\\/]node_modules[\\/]shared$/
+	);
+	const sizes = duplicate.instances.map(instance => instance.size);
+	expect(duplicate.duplicatedSize).toBe(
+		sizes.reduce((a, b) => a + b, 0) - Math.max(...sizes)
+	);
+});
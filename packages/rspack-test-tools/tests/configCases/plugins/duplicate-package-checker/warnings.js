@@ -0,0 +1 @@
+module.exports = [[/Multiple versions of "shared" found/]];
@@ -0,0 +1,11 @@
+const rspack = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.DuplicatePackageCheckerRspackPlugin({
+			filename: "duplicate-packages.json",
+			exclude: ["excluded"]
+		})
+	]
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	[/EnvironmentPlugin - RSPACK_TEST_ENV_MISSING environment variable is undefined/]
+];
@@ -0,0 +1,6 @@
+it("should define the environment variables with the defaults", () => {
+	expect(process.env.RSPACK_TEST_ENV_DEFINED).toBe("defined");
+	expect(process.env.RSPACK_TEST_ENV_DEFAULT).toBe("default");
+	expect(process.env.RSPACK_TEST_ENV_OPTIONAL).toBe(null);
+	expect(process.env.RSPACK_TEST_ENV_MISSING).toBe(undefined);
+});
@@ -0,0 +1,15 @@
+const rspack = require("@rspack/core");
+
+process.env.RSPACK_TEST_ENV_DEFINED = "defined";
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.EnvironmentPlugin({
+			RSPACK_TEST_ENV_DEFINED: "default",
+			RSPACK_TEST_ENV_DEFAULT: "default",
+			RSPACK_TEST_ENV_OPTIONAL: null,
+			RSPACK_TEST_ENV_MISSING: undefined
+		})
+	]
+};
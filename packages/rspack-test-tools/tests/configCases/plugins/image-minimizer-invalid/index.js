@@ -0,0 +1,16 @@
+import image from "./image.png";
+import broken from "./broken.png";
+import fs from "fs";
+import path from "path";
+
+it("should keep the assets that fail to be minimized", () => {
+	const original = fs.statSync(path.resolve(CONTEXT, "broken.png")).size;
+	const output = fs.statSync(path.join(__STATS__.outputPath, broken)).size;
+	expect(output).toBe(original);
+});
+
+it("should still minimize the other assets", () => {
+	const original = fs.statSync(path.resolve(CONTEXT, "image.png")).size;
+	const minimized = fs.statSync(path.join(__STATS__.outputPath, image)).size;
+	expect(minimized).toBeLessThan(original);
+});
@@ -0,0 +1 @@
+module.exports = [/Failed to minimize image broken\.png/];
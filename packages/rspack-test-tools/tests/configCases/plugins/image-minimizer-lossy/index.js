@@ -0,0 +1,11 @@
+import image from "./image.png";
+import fs from "fs";
+import path from "path";
+
+it("should quantize png assets with the lossy preset", () => {
+	const original = fs.statSync(path.resolve(CONTEXT, "image.png")).size;
+	const buffer = fs.readFileSync(path.join(__STATS__.outputPath, image));
+	expect(buffer.length).toBeLessThan(original);
+	// the color type of the IHDR chunk, 3 means indexed colors
+	expect(buffer[25]).toBe(3);
+});
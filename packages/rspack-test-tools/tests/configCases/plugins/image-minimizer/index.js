@@ -0,0 +1,16 @@
+import image from "./image.png";
+import excluded from "./excluded.png";
+import fs from "fs";
+import path from "path";
+
+it("should minimize png assets", () => {
+	const original = fs.statSync(path.resolve(CONTEXT, "image.png")).size;
+	const minimized = fs.statSync(path.join(__STATS__.outputPath, image)).size;
+	expect(minimized).toBeLessThan(original);
+});
+
+it("should not minimize excluded assets", () => {
+	const original = fs.statSync(path.resolve(CONTEXT, "excluded.png")).size;
+	const output = fs.statSync(path.join(__STATS__.outputPath, excluded)).size;
+	expect(output).toBe(original);
+});
@@ -0,0 +1,24 @@
+const rspack = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.png$/,
+				type: "asset/resource",
+				generator: {
+					filename: "[name][ext]"
+				}
+			}
+		]
+	},
+	plugins: [
+		new rspack.DefinePlugin({
+			CONTEXT: JSON.stringify(__dirname)
+		}),
+		new rspack.ImageMinimizerRspackPlugin({
+			exclude: /excluded\.png/
+		})
+	]
+};
@@ -0,0 +1,7 @@
+it("should provide Buffer even if the buffer module is not polyfilled", () => {
+	expect(Buffer.name).toBe("buffer");
+});
+
+it("should not provide process when it's disabled", () => {
+	expect(process.name).toBeUndefined();
+});
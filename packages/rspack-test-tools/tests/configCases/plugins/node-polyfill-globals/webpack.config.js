@@ -0,0 +1,14 @@
+const rspack = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	plugins: [
+		new rspack.NodePolyfillRspackPlugin({
+			includeAliases: [],
+			globals: {
+				process: false
+			}
+		})
+	]
+};
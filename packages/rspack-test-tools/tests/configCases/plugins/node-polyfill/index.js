@@ -0,0 +1,15 @@
+import path from "path";
+import os from "os";
+
+it("should resolve the node core modules to the polyfills", () => {
+	expect(path.name).toBe("path-browserify");
+});
+
+it("should provide the process and Buffer globals", () => {
+	expect(process.name).toBe("process");
+	expect(Buffer.name).toBe("buffer");
+});
+
+it("should not polyfill the modules with a user fallback", () => {
+	expect(os).toEqual({});
+});
@@ -0,0 +1,7 @@
+it("should replace the request with a string", function () {
+	expect(require("./env.development")).toBe("production");
+});
+
+it("should replace the resource with a relative path", function () {
+	expect(require("./config.default")).toBe("custom");
+});
@@ -0,0 +1,15 @@
+const { NormalModuleReplacementPlugin } = require("@rspack/core");
+
+module.exports = /** @type {import("@rspack/core").Configuration} */ ({
+	plugins: [
+		new NormalModuleReplacementPlugin(
+			/\/env\.development$/,
+			"./env.production"
+		),
+		// only matches the resolved resource, relative to its directory
+		new NormalModuleReplacementPlugin(
+			/config\.default\.js$/,
+			"config.custom.js"
+		)
+	]
+});
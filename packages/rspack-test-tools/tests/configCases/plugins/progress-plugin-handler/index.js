@@ -0,0 +1,31 @@
+import a from "./a";
+
+const fs = require("fs");
+const path = require("path");
+
+it("should report the progress to the handler", () => {
+	expect(a).toBe("a");
+	// the module counts are reported with and without the profile
+	const calls = JSON.parse(
+		fs.readFileSync(path.join(__dirname, PROGRESS_FILE), "utf-8")
+	);
+	const building = calls.filter(
+		({ msg, items }) => msg === "building" && items[0].endsWith("modules")
+	);
+	expect(building.length).toBeGreaterThan(0);
+	// all the modules are done at the end of the make phase
+	expect(building[building.length - 1].items[0]).toMatch(
+		/^(\d+)\/\1 modules$/
+	);
+	const sealing = calls.filter(({ msg }) => msg === "sealing");
+	expect(sealing.map(({ items }) => items[0])).toContain("module ids");
+	expect(
+		sealing.find(({ items }) => items[0] === "after asset optimization")
+			.percentage
+	).toBeGreaterThan(0.9);
+	expect(calls[calls.length - 1]).toEqual({
+		percentage: 1,
+		msg: "emitting",
+		items: ["after emit"]
+	});
+});
@@ -0,0 +1,30 @@
+const fs = require("fs");
+const path = require("path");
+const rspack = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [false, true].map(profile => {
+	const calls = [];
+	const file = profile ? "progress-profile.json" : "progress.json";
+	return {
+		plugins: [
+			new rspack.ProgressPlugin({
+				profile,
+				handler: (percentage, msg, ...items) => {
+					calls.push({ percentage, msg, items });
+				}
+			}),
+			new rspack.DefinePlugin({
+				PROGRESS_FILE: JSON.stringify(file)
+			}),
+			function plugin(compiler) {
+				compiler.hooks.done.tap("plugin", () => {
+					fs.writeFileSync(
+						path.join(compiler.options.output.path, file),
+						JSON.stringify(calls)
+					);
+				});
+			}
+		]
+	};
+});
@@ -0,0 +1,14 @@
+it("should provide a property of a module", () => {
+	expect($("a")).toBe("jQuery(a)");
+	expect($.fn.version).toBe("1.0.0");
+});
+
+it("should provide an identifier with dots", () => {
+	expect(window.jQuery("b")).toBe("jQuery(b)");
+	expect(window.jQuery.fn.version).toBe("1.0.0");
+	expect(window.jQuery).toBe($);
+});
+
+it("should provide a nested property with a non-identifier name", () => {
+	expect(kebabCase("Hello World")).toBe("hello-world");
+});
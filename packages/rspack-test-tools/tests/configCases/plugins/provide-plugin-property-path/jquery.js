@@ -0,0 +1,7 @@
+export default function jQuery(selector) {
+	return `jQuery(${selector})`;
+}
+
+jQuery.fn = {
+	version: "1.0.0"
+};
@@ -0,0 +1,5 @@
+module.exports = {
+	string: {
+		"kebab-case": str => str.replace(/\s+/g, "-").toLowerCase()
+	}
+};
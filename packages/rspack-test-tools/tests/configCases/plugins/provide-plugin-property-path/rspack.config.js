@@ -0,0 +1,12 @@
+const rspack = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.ProvidePlugin({
+			$: ["./jquery", "default"],
+			"window.jQuery": ["./jquery", "default"],
+			kebabCase: ["./lodash", "string", "kebab-case"]
+		})
+	]
+};
@@ -0,0 +1,17 @@
+import value from "./virtual/value";
+import json from "./virtual/data.json";
+import shadowed from "./shadowed";
+import written from "./virtual/written";
+
+it("should resolve and read the virtual modules", () => {
+	expect(value).toBe("virtual");
+	expect(json).toEqual({ virtual: true });
+});
+
+it("should shadow the files on the disk", () => {
+	expect(shadowed).toBe("memory");
+});
+
+it("should read the modules written by writeModule", () => {
+	expect(written).toBe("written");
+});
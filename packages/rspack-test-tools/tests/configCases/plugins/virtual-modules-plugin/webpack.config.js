@@ -0,0 +1,22 @@
+const { VirtualModulesPlugin } = require("@rspack/core");
+
+const virtualModules = new VirtualModulesPlugin({
+	"virtual/value.js": 'export default "virtual";',
+	"virtual/data.json": '{ "virtual": true }',
+	"shadowed.js": 'module.exports = "memory";'
+});
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		virtualModules,
+		compiler => {
+			compiler.hooks.beforeCompile.tap("test", () => {
+				virtualModules.writeModule(
+					"virtual/written.js",
+					'export default "written";'
+				);
+			});
+		}
+	]
+};
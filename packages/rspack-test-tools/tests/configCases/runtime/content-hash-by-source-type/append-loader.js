@@ -0,0 +1,3 @@
+module.exports = function (source) {
+	return source + this.getOptions().append;
+};
@@ -0,0 +1,14 @@
+import "./style.css";
+import fs from "fs";
+import path from "path";
+
+it("should not change the content hash of the javascript if only the css changes", () => {
+	const files = (dir, ext) =>
+		fs
+			.readdirSync(path.resolve(__dirname, "..", dir))
+			.filter(file => file.endsWith(ext));
+	expect(files("a", ".js")).toHaveLength(1);
+	expect(files("a", ".js")).toEqual(files("b", ".js"));
+	expect(files("a", ".css")).toHaveLength(1);
+	expect(files("a", ".css")).not.toEqual(files("b", ".css"));
+});
@@ -0,0 +1,12 @@
+const fs = require("fs");
+const path = require("path");
+
+module.exports = {
+	findBundle: function (i, options) {
+		const dir = i === 0 ? "a" : "b";
+		const bundle = fs
+			.readdirSync(path.resolve(options.output.path, dir))
+			.find(file => file.endsWith(".js"));
+		return [`./${dir}/${bundle}`];
+	}
+};
@@ -0,0 +1,30 @@
+const config = (dir, append) => ({
+	entry: "./index.js",
+	target: "node",
+	node: {
+		__dirname: false
+	},
+	output: {
+		filename: `${dir}/[name].[contenthash].js`,
+		cssFilename: `${dir}/[name].[contenthash].css`
+	},
+	module: {
+		rules: [
+			{
+				test: /style\.css$/,
+				use: [
+					{
+						loader: "./append-loader.js",
+						options: { append }
+					}
+				]
+			}
+		]
+	}
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	config("a", ""),
+	config("b", "\n.b { color: blue; }\n")
+];
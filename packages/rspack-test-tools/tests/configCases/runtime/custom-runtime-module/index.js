@@ -0,0 +1,17 @@
+it("should add the runtime module to the runtime chunk", () => {
+	expect(__webpack_require__.runtimeChunkName).toBe("main");
+});
+
+it("should add the runtime module when the runtime requirement is used", async () => {
+	const { default: value } = await import(/* webpackChunkName: "async" */ "./async");
+	expect(value).toBe("async");
+	expect(__webpack_require__.ensuredChunks).toHaveLength(1);
+});
+
+it("should not add the runtime module when the runtime requirement is not used", () => {
+	expect(__webpack_require__.unused).toBeUndefined();
+});
+
+it("should only add the runtime module to the specified chunks", () => {
+	expect(__webpack_require__.otherChunk).toBeUndefined();
+});
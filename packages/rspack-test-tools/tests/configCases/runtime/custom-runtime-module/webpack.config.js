@@ -0,0 +1,42 @@
+const { CustomRuntimeModuleRspackPlugin, RuntimeGlobals } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new CustomRuntimeModuleRspackPlugin({
+			name: "chunk-info",
+			generate({ chunkName }) {
+				return `${RuntimeGlobals.require}.runtimeChunkName = ${JSON.stringify(chunkName)};`;
+			}
+		}),
+		new CustomRuntimeModuleRspackPlugin({
+			name: "ensure-chunk-log",
+			stage: "attach",
+			runtimeRequirements: [RuntimeGlobals.ensureChunk],
+			generate() {
+				return [
+					`var ensureChunk = ${RuntimeGlobals.ensureChunk};`,
+					`${RuntimeGlobals.require}.ensuredChunks = [];`,
+					`${RuntimeGlobals.ensureChunk} = function(chunkId) {`,
+					`	${RuntimeGlobals.require}.ensuredChunks.push(chunkId);`,
+					`	return ensureChunk.apply(this, arguments);`,
+					`};`
+				].join("\n");
+			}
+		}),
+		new CustomRuntimeModuleRspackPlugin({
+			name: "unused",
+			runtimeRequirements: [RuntimeGlobals.instantiateWasm],
+			generate() {
+				return `${RuntimeGlobals.require}.unused = true;`;
+			}
+		}),
+		new CustomRuntimeModuleRspackPlugin({
+			name: "other-chunk",
+			chunks: ["other"],
+			generate() {
+				return `${RuntimeGlobals.require}.otherChunk = true;`;
+			}
+		})
+	]
+};
@@ -0,0 +1,3 @@
+import { referenced } from "./referenced";
+
+export const cycle = referenced;
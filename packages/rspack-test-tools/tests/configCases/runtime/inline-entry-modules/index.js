@@ -0,0 +1,29 @@
+import { value } from "./value";
+
+const fs = require("fs");
+const path = require("path");
+
+const read = name => fs.readFileSync(path.resolve(__dirname, name), "utf-8");
+
+it("should execute the inlined entry module", () => {
+	expect(value).toBe(42);
+});
+
+it("should inline the entry module into the startup code", () => {
+	const content = read("inlined.js");
+	expect(content).not.toContain('"./inlined.js": (function');
+	expect(content).not.toContain('__webpack_require__("./inlined.js")');
+	expect(content).toContain('"./value.js": (function');
+	expect(content).toContain("var __webpack_exports__ = {};\n!function() {");
+});
+
+it("should not inline the entry module which is required by other modules", () => {
+	const content = read("referenced.js");
+	expect(content).toContain('"./referenced.js": (function');
+	expect(content).toContain('__webpack_require__("./referenced.js")');
+});
+
+it("should not inline the entry module which uses module", () => {
+	const content = read("module.js");
+	expect(content).toContain('"./module.js": (function');
+});
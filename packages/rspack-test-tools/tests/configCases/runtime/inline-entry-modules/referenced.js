@@ -0,0 +1,3 @@
+import "./cycle";
+
+export const referenced = true;
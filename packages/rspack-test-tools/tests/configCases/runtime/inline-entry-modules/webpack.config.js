@@ -0,0 +1,21 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		inlined: "./inlined.js",
+		referenced: "./referenced.js",
+		module: "./module.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		minimize: false,
+		moduleIds: "named"
+	},
+	experiments: {
+		rspackFuture: {
+			inlineEntryModules: true
+		}
+	}
+};
@@ -0,0 +1,22 @@
+const fs = require("fs");
+const path = require("path");
+
+const read = name => fs.readFileSync(path.resolve(__dirname, name), "utf-8");
+
+it("should include the require function when the modules require other modules", () => {
+	expect(read("main.js")).toContain("function __webpack_require__(moduleId)");
+});
+
+it("should not include the require function and the module cache when no module is required", () => {
+	const content = read("plain.js");
+	expect(content).not.toContain("function __webpack_require__(moduleId)");
+	expect(content).not.toContain("__webpack_module_cache__");
+	expect(content).toContain('__webpack_modules__["./plain.js"]();');
+});
+
+it("should pass the exports to the entry module without the require function", () => {
+	const content = read("exports.js");
+	expect(content).not.toContain("function __webpack_require__(moduleId)");
+	expect(content).toContain("var __webpack_exports__ = {};");
+	expect(content).toContain('__webpack_modules__["./exports.js"](0, __webpack_exports__);');
+});
@@ -0,0 +1,15 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		plain: "./plain.js",
+		exports: "./exports.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		minimize: false,
+		moduleIds: "named"
+	}
+};
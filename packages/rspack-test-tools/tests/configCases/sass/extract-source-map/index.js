@@ -0,0 +1,27 @@
+const fs = require("fs");
+const path = require("path");
+const sourceMap = require("source-map");
+require("./index.scss");
+
+it("should map the extracted css back to the original scss", async () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+	const map = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "main.css.map"), "utf-8")
+	);
+	expect(css).not.toContain("__mini_css_extract_plugin_public_path_auto__");
+	expect(map.file).toBe("main.css");
+	expect(map.sources).toContain("webpack:///./index.scss");
+	const index = map.sources.indexOf("webpack:///./index.scss");
+	expect(map.sourcesContent[index]).toContain("$color");
+
+	const lines = css.split("\n");
+	const line = lines.findIndex(line => line.includes("color: #2ecc71"));
+	expect(line).toBeGreaterThan(-1);
+	const consumer = await new sourceMap.SourceMapConsumer(map);
+	const original = consumer.originalPositionFor({
+		line: line + 1,
+		column: lines[line].indexOf("color")
+	});
+	expect(original.source).toBe("webpack:///./index.scss");
+	expect(original.line).toBe(5);
+});
@@ -0,0 +1,34 @@
+const { CssExtractRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	output: {
+		filename: "[name].js",
+		publicPath: "auto"
+	},
+	devtool: "source-map",
+	module: {
+		rules: [
+			{
+				test: /\.s[ac]ss$/i,
+				use: [
+					CssExtractRspackPlugin.loader,
+					{ loader: "css-loader", options: { sourceMap: true } },
+					{ loader: "sass-loader", options: { sourceMap: true } }
+				],
+				type: "javascript/auto"
+			},
+			{
+				test: /\.svg$/,
+				type: "asset/resource"
+			}
+		]
+	},
+	plugins: [new CssExtractRspackPlugin({ filename: "[name].css" })],
+	externals: ["source-map"],
+	externalsType: "commonjs",
+	experiments: {
+		css: false
+	}
+};
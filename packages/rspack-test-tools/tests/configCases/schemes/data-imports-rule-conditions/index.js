@@ -0,0 +1,11 @@
+import text from "data:text/plain,hello";
+import upper from "data:text/x-upper,hello";
+import svg from 'data:image/svg+xml,<svg xmlns="http://www.w3.org/2000/svg"></svg>';
+
+it("should route data imports by `scheme` and `mimetype`", () => {
+	expect(text).toBe("hello");
+	expect(upper).toBe("HELLO");
+	expect(svg).toBe(
+		'data:image/svg+xml,<svg xmlns="http://www.w3.org/2000/svg"></svg>'
+	);
+});
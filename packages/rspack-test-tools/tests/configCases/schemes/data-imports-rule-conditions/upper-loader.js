@@ -0,0 +1,3 @@
+module.exports = function (content) {
+	return `export default ${JSON.stringify(content.toUpperCase())};`;
+};
@@ -0,0 +1,23 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				scheme: "data",
+				mimetype: "text/plain",
+				type: "asset/source"
+			},
+			{
+				scheme: "data",
+				mimetype: "text/x-upper",
+				use: "./upper-loader.js",
+				type: "javascript/auto"
+			},
+			{
+				scheme: /^data$/,
+				mimetype: /^image\/svg/,
+				type: "asset/inline"
+			}
+		]
+	}
+};
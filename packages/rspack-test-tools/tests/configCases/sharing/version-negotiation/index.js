@@ -0,0 +1,78 @@
+let warnings = [];
+let oldWarn;
+
+beforeEach(done => {
+	oldWarn = console.warn;
+	console.warn = m => warnings.push(m);
+	done();
+});
+
+afterEach(done => {
+	expectWarning();
+	console.warn = oldWarn;
+	done();
+});
+
+const expectWarning = regexp => {
+	if (!regexp) {
+		expect(warnings).toEqual([]);
+	} else {
+		expect(warnings.length).toBe(1);
+		expect(warnings[0]).toMatch(regexp);
+	}
+	warnings.length = 0;
+};
+
+const version = (name, v, from) => ({
+	get: () => () => `${name}@${v}`,
+	from
+});
+
+it("should negotiate shared versions in the configured share scope", () => {
+	__webpack_share_scopes__["custom"] = {
+		loose: {
+			"1.2.0": version("loose", "1.2.0"),
+			"1.5.0": version("loose", "1.5.0"),
+			"2.0.0": version("loose", "2.0.0")
+		},
+		mismatch: {
+			"0.9.0": version("mismatch", "0.9.0"),
+			"3.0.0": version("mismatch", "3.0.0", "app")
+		},
+		strict: {
+			"2.0.0": version("strict", "2.0.0", "app")
+		},
+		singleton: {
+			"1.1.0": version("singleton", "1.1.0"),
+			"2.1.0": version("singleton", "2.1.0", "app")
+		},
+		"strict-singleton": {
+			"3.0.0": version("strict-singleton", "3.0.0", "app")
+		}
+	};
+
+	// picks the highest version satisfying the required range
+	expect(require("loose")).toBe("loose@1.5.0");
+	expectWarning();
+
+	// without strictVersion a mismatch warns and uses the highest version
+	expect(require("mismatch")).toBe("mismatch@3.0.0");
+	expectWarning(
+		/No satisfying version \(\^1\.0\.0\) of shared module mismatch found in shared scope custom/
+	);
+
+	expect(() => require("strict")).toThrowError(
+		"No satisfying version (^1.0.0) of shared module strict found in shared scope custom.\n" +
+			"Available versions: 2.0.0 from app"
+	);
+
+	// singletons use the highest version and only warn on a mismatch
+	expect(require("singleton")).toBe("singleton@2.1.0");
+	expectWarning(
+		/Unsatisfied version 2\.1\.0 from app of shared singleton module singleton \(required \^1\.0\.0\)/
+	);
+
+	expect(() => require("strict-singleton")).toThrowError(
+		"Unsatisfied version 3.0.0 from app of shared singleton module strict-singleton (required ^1.0.0)"
+	);
+});
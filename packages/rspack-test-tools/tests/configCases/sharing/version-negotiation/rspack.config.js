@@ -0,0 +1,36 @@
+const { ConsumeSharedPlugin } = require("@rspack/core").sharing;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new ConsumeSharedPlugin({
+			shareScope: "custom",
+			consumes: {
+				loose: {
+					import: false,
+					requiredVersion: "^1.0.0"
+				},
+				mismatch: {
+					import: false,
+					requiredVersion: "^1.0.0"
+				},
+				strict: {
+					import: false,
+					requiredVersion: "^1.0.0",
+					strictVersion: true
+				},
+				singleton: {
+					import: false,
+					requiredVersion: "^1.0.0",
+					singleton: true
+				},
+				"strict-singleton": {
+					import: false,
+					requiredVersion: "^1.0.0",
+					singleton: true,
+					strictVersion: true
+				}
+			}
+		})
+	]
+};
@@ -0,0 +1,18 @@
+import { value } from "./module";
+
+it("should inline a source map for each module", () => {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(value).toBe(42);
+	const maps = [];
+	const regexp =
+		/sourceMappingURL\s*=\s*data:application\/json;charset=utf-8;base64,([A-Za-z0-9+/=]*)/g;
+	let match;
+	while ((match = regexp.exec(source))) {
+		maps.push(JSON.parse(Buffer.from(match[1], "base64").toString("utf-8")));
+	}
+	const sources = maps.map(map => map.sources).flat();
+	expect(sources).toContain("./index.js");
+	expect(sources).toContain("./module.js");
+	expect(source).toContain(`//# sourceURL=webpack-internal:///${module.id}`);
+});
@@ -0,0 +1,6 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: "eval-cheap-module-source-map",
+	externals: ["source-map"],
+	externalsType: "commonjs"
+};
@@ -2,9 +2,24 @@ it("basic", () => {
 	const fs = require("fs");
 	const source = fs.readFileSync(__filename, "utf-8");
 	const base64 =
-		/sourceMappingURL\s*=\s*data:application\/json;charset=utf-8;base64,(.*)\"\);/.exec(
+		/sourceMappingURL\s*=\s*data:application\/json;charset=utf-8;base64,([A-Za-z0-9+/=]*)/.exec(
 			source
 		)[1];
 	const map = JSON.parse(Buffer.from(base64, "base64").toString("utf-8"));
 	expect(map.sources).toContain("./index.js");
 });
+
+it("should name each eval wrapped module with its module id", () => {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toContain(`//# sourceURL=webpack-internal:///${module.id}`);
+});
+
+it("should not share the eval wrapped result between modules with the same source", () => {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(require("./same-a").default).toBe(require("./same-b").default);
+	for (const id of [require.resolve("./same-a"), require.resolve("./same-b")]) {
+		expect(source).toContain(`//# sourceURL=webpack-internal:///${id}`);
+	}
+});
@@ -0,0 +1,12 @@
+it("should make the sources relative to the context", function () {
+	var fs = require("fs");
+	var path = require("path");
+	var source = fs.readFileSync(__filename + ".map", "utf-8");
+	var map = JSON.parse(source);
+	expect(map.sources).toContain("./test.js");
+	for (var source of map.sources) {
+		expect(path.isAbsolute(source)).toBe(false);
+	}
+});
+
+if (Math.random() < 0) require("./test.js");
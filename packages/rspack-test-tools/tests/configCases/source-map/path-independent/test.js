@@ -0,0 +1,3 @@
+var foo = {};
+
+module.exports = foo;
@@ -0,0 +1,16 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	node: {
+		__dirname: false,
+		__filename: false
+	},
+	devtool: "source-map",
+	output: {
+		devtoolModuleFilenameTemplate(info) {
+			return info.absoluteResourcePath;
+		}
+	},
+	experiments: {
+		pathIndependent: true
+	}
+};
@@ -0,0 +1,13 @@
+it("should upload source maps to a separate host without leaking sources", function () {
+	const fs = require("fs");
+	const path = require("path");
+	const source = fs.readFileSync(__filename, "utf-8");
+	const match = /sourceMappingURL\s*=\s*(.*)/.exec(source);
+	expect(match[1]).toBe("https://cdn.example.com/maps/bundle0.js.map");
+	const map = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "../maps/bundle0.js.map"), "utf-8")
+	);
+	expect(map.file).toBe("js/bundle0.js");
+	expect(map.sources).toContain("webpack-internal:///./index.js");
+	expect(map.sourcesContent).toBeUndefined();
+});
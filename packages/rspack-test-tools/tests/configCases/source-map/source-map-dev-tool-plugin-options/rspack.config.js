@@ -0,0 +1,20 @@
+const { SourceMapDevToolPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: false,
+	output: {
+		filename: "js/bundle0.js"
+	},
+	plugins: [
+		new SourceMapDevToolPlugin({
+			filename: "maps/[file].map",
+			fileContext: "js",
+			publicPath: "https://cdn.example.com/",
+			moduleFilenameTemplate: "webpack-internal:///[resource-path]",
+			noSources: true,
+			append: pathData => `\n//# sourceMappingURL=${pathData.url}`,
+			exclude: /\.css$/
+		})
+	]
+};
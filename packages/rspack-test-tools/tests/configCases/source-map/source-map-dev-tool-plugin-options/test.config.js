@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle: function () {
+		return ["js/bundle0.js"];
+	}
+};
@@ -0,0 +1,12 @@
+import fs from "fs";
+import { used } from "./data.json";
+
+it("should only tree shake the json exports within `exportsDepth`", () => {
+	expect(used.nested).toBe("used-nested-value");
+
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source.includes(["unused", "nested", "value"].join("-"))).toBe(true);
+	expect(source.includes(["unused", "top", "level", "value"].join("-"))).toBe(
+		false
+	);
+});
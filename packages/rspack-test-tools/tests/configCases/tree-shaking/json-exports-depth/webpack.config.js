@@ -0,0 +1,14 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	module: {
+		parser: {
+			json: {
+				exportsDepth: 1
+			}
+		}
+	},
+	optimization: {
+		minimize: false
+	}
+};
@@ -0,0 +1,19 @@
+import fs from "fs";
+import path from "path";
+
+it("should instantiate async wasm modules with imported js functions", () => {
+	return import("./module").then(function (mod) {
+		expect(mod.result).toBe(42);
+	});
+});
+
+it("should emit the wasm binary with a content hash", () => {
+	const wasmAssets = __STATS__.assets
+		.map(asset => asset.name)
+		.filter(name => name.endsWith(".wasm"));
+	expect(wasmAssets).toHaveLength(1);
+	expect(wasmAssets[0]).toMatch(/^[0-9a-f]{16}\.module\.wasm$/);
+	expect(fs.existsSync(path.join(__STATS__.outputPath, wasmAssets[0]))).toBe(
+		true
+	);
+});
@@ -0,0 +1,13 @@
+import { getResult } from "./wasm.wasm";
+import { answer } from "./large.wasm";
+
+it("should instantiate sync wasm modules with imported js functions", () => {
+	expect(getResult(22)).toBe(42);
+});
+
+it("should inline the wasm binary instead of emitting it", () => {
+	expect(answer()).toBe(42);
+	expect(
+		__STATS__.assets.filter(asset => asset.name.endsWith(".wasm"))
+	).toHaveLength(0);
+});
@@ -0,0 +1,3 @@
+module.exports = [
+	[/large\.wasm is \d+ bytes, which exceeds the 4096 bytes limit/]
+];
@@ -0,0 +1,19 @@
+import fs from "fs";
+import path from "path";
+import init, { initSync, add_number } from "./pkg";
+
+it("should emit the wasm of wasm-bindgen output as an asset", () => {
+	expect(typeof init).toBe("function");
+	const wasmAssets = __STATS__.assets
+		.map(asset => asset.name)
+		.filter(name => name.endsWith(".wasm"));
+	expect(wasmAssets).toHaveLength(1);
+
+	initSync(fs.readFileSync(path.join(__STATS__.outputPath, wasmAssets[0])));
+	expect(add_number(22)).toBe(42);
+});
+
+it("should refer to the emitted wasm in the glue code", () => {
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).not.toContain(["new URL(", "foo_bg.wasm"].join('"'));
+});
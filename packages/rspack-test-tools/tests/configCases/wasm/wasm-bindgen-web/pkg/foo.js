@@ -0,0 +1,61 @@
+// Mimics the output of `wasm-bindgen --target web`
+import { getNumber } from "./snippets/foo-1a2b3c4d/inline0.js";
+
+let wasm;
+
+/**
+ * @param {number} a
+ * @returns {number}
+ */
+export function add_number(a) {
+	const ret = wasm.add_number(a);
+	return ret;
+}
+
+function __wbg_get_imports() {
+	const imports = {};
+	imports.wbg = {};
+	imports.wbg.__wbg_getNumber_1 = function () {
+		const ret = getNumber();
+		return ret;
+	};
+	return imports;
+}
+
+function __wbg_finalize_init(instance, module) {
+	wasm = instance.exports;
+	__wbg_init.__wbindgen_wasm_module = module;
+	return wasm;
+}
+
+function initSync(module) {
+	if (wasm !== undefined) return wasm;
+
+	const imports = __wbg_get_imports();
+	if (!(module instanceof WebAssembly.Module)) {
+		module = new WebAssembly.Module(module);
+	}
+	const instance = new WebAssembly.Instance(module, imports);
+	return __wbg_finalize_init(instance, module);
+}
+
+async function __wbg_init(input) {
+	if (wasm !== undefined) return wasm;
+
+	if (typeof input === "undefined") {
+		input = new URL("foo_bg.wasm", import.meta.url);
+	}
+	const imports = __wbg_get_imports();
+
+	if (typeof input === "string" || input instanceof URL) {
+		input = await fetch(input);
+	}
+	const { instance, module } = await WebAssembly.instantiate(
+		await input.arrayBuffer(),
+		imports
+	);
+	return __wbg_finalize_init(instance, module);
+}
+
+export { initSync };
+export default __wbg_init;
@@ -0,0 +1,2 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {};
@@ -2,5 +2,16 @@ module.exports = {
 	description: "records",
 	options: () => ({ recordsPath: "some-path" }),
 	diff: e =>
-		e.toMatchInlineSnapshot(`Compared values have no visual difference.`)
+		e.toMatchInlineSnapshot(`
+		- Expected
+		+ Received
+
+		@@ ... @@
+		-   "recordsInputPath": false,
+		-   "recordsOutputPath": false,
+		-   "recordsPath": undefined,
+		+   "recordsInputPath": "some-path",
+		+   "recordsOutputPath": "some-path",
+		+   "recordsPath": "some-path",
+	`)
 };
@@ -13,8 +13,9 @@ module.exports = {
 		+     "electronMain": true,
 		@@ ... @@
 		-     "node": false,
-		-     "web": true,
 		+     "node": true,
+		@@ ... @@
+		-     "web": true,
 		+     "web": false,
 		@@ ... @@
 		-         "exportsOnly": false,
@@ -34,8 +35,9 @@ module.exports = {
 		+     "global": false,
 		@@ ... @@
 		-     "chunkFormat": "array-push",
-		-     "chunkLoading": "jsonp",
 		+     "chunkFormat": "commonjs",
+		@@ ... @@
+		-     "chunkLoading": "jsonp",
 		+     "chunkLoading": "require",
 		@@ ... @@
 		-       "jsonp",
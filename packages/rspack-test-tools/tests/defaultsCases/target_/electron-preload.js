@@ -33,8 +33,9 @@ module.exports = {
 		+     "global": false,
 		@@ ... @@
 		-     "chunkFormat": "array-push",
-		-     "chunkLoading": "jsonp",
 		+     "chunkFormat": "commonjs",
+		@@ ... @@
+		-     "chunkLoading": "jsonp",
 		+     "chunkLoading": "require",
 		@@ ... @@
 		-       "jsonp",
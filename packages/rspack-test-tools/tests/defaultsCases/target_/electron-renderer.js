@@ -0,0 +1,35 @@
+module.exports = {
+	description: "target electron-renderer",
+	options: () => ({ target: "electron-renderer" }),
+	diff: e =>
+		e.toMatchInlineSnapshot(`
+		- Expected
+		+ Received
+
+		@@ ... @@
+		-     "electron": false,
+		+     "electron": true,
+		@@ ... @@
+		-     "electronRenderer": false,
+		-     "node": false,
+		+     "electronRenderer": true,
+		+     "node": true,
+		@@ ... @@
+		-     "__dirname": "warn-mock",
+		-     "__filename": "warn-mock",
+		-     "global": "warn",
+		+     "__dirname": "eval-only",
+		+     "__filename": "eval-only",
+		+     "global": false,
+		@@ ... @@
+		-     "globalObject": "self",
+		+     "globalObject": "global",
+		@@ ... @@
+		+       "node",
+		@@ ... @@
+		+       "electron",
+		@@ ... @@
+		-   "target": "web",
+		+   "target": "electron-renderer",
+	`)
+};
@@ -8,8 +8,9 @@ module.exports = {
 
 		@@ ... @@
 		-     "node": false,
-		-     "web": true,
 		+     "node": true,
+		@@ ... @@
+		-     "web": true,
 		+     "web": false,
 		@@ ... @@
 		-         "exportsOnly": false,
@@ -29,8 +30,9 @@ module.exports = {
 		+     "global": false,
 		@@ ... @@
 		-     "chunkFormat": "array-push",
-		-     "chunkLoading": "jsonp",
 		+     "chunkFormat": "commonjs",
+		@@ ... @@
+		-     "chunkLoading": "jsonp",
 		+     "chunkLoading": "require",
 		@@ ... @@
 		-       "jsonp",
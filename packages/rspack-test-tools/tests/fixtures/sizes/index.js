@@ -0,0 +1,4 @@
+import "./style.css";
+import url from "./file.txt";
+
+export default url;
@@ -0,0 +1,6 @@
+export default {
+	invalidate: function () {
+		module.hot.invalidate();
+	},
+	value: {}
+};
@@ -0,0 +1,53 @@
+import { a, b } from "./module";
+
+it("should only allow to apply in ready status", function () {
+	expect(module.hot.status()).toBe("idle");
+	return module.hot.apply().then(
+		function () {
+			throw new Error("apply() should be rejected in idle status");
+		},
+		function (err) {
+			expect(err.message).toBe(
+				"apply() is only allowed in ready status (state: idle)"
+			);
+		}
+	);
+});
+
+it("should apply the modules invalidated during apply", function (done) {
+	const oldA = a.value;
+	const oldB = b.value;
+	const statuses = [];
+	let invalidated = false;
+	const handler = function (status) {
+		statuses.push(status);
+		if (status === "dispose" && !invalidated) {
+			invalidated = true;
+			b.invalidate();
+		}
+	};
+	module.hot.addStatusHandler(handler);
+	a.invalidate();
+	expect(module.hot.status()).toBe("ready");
+	expect(function () {
+		module.hot.check();
+	}).toThrow("check() is only allowed in idle status");
+	module.hot
+		.apply()
+		.then(function () {
+			module.hot.removeStatusHandler(handler);
+			expect(statuses).toEqual([
+				"ready",
+				"dispose",
+				"apply",
+				"dispose",
+				"apply",
+				"idle"
+			]);
+			expect(module.hot.status()).toBe("idle");
+			expect(a.value).not.toBe(oldA);
+			expect(b.value).not.toBe(oldB);
+			done();
+		})
+		.catch(done);
+});
@@ -0,0 +1,4 @@
+export { default as a } from "./a";
+export { default as b } from "./b";
+
+module.hot.accept(["./a", "./b"]);
@@ -0,0 +1,9 @@
+export default {
+	invalidate: function () {
+		module.hot.invalidate();
+	},
+	value: {}
+};
+
+// invalidating a self-accepted module bubbles the update to its parents
+module.hot.accept();
@@ -0,0 +1,5 @@
+export { default as a } from "./a";
+export { default as b } from "./b";
+export { default as c } from "./c";
+
+module.hot.accept(["./a", "./b"]);
@@ -0,0 +1,19 @@
+var value = require("./file");
+
+it("should load the hot update files with the custom filenames", done => {
+	expect(value).toBe(1);
+	module.hot.accept("./file", () => {
+		value = require("./file");
+	});
+	NEXT(
+		require("../../update")(done, true, stats => {
+			expect(value).toBe(2);
+			const assets = stats.assets.map(asset => asset.name);
+			expect(assets.some(a => /^hot\/main\.main\.\w+\.js$/.test(a))).toBe(
+				true
+			);
+			expect(assets.some(a => /^hot\/main\.\w+\.json$/.test(a))).toBe(true);
+			done();
+		})
+	);
+});
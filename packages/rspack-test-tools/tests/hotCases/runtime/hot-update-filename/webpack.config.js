@@ -0,0 +1,7 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		hotUpdateChunkFilename: "hot/[runtime].[id].[fullhash].js",
+		hotUpdateMainFilename: "hot/[runtime].[fullhash].json"
+	}
+};
@@ -3,6 +3,7 @@
 require("../../dist/helper/legacy/warmup-webpack");
 const path = require("path");
 const webpack = require("@rspack/core");
+const { createFsFromVolume, Volume } = require("memfs");
 
 describe("MultiCompiler", function () {
 	jest.setTimeout(20000);
@@ -37,6 +38,120 @@ describe("MultiCompiler", function () {
 			});
 		});
 	}, 20000);
+
+	const createMultiCompiler = (configs, options) => {
+		const compiler = webpack(
+			Object.assign(
+				configs.map(config => ({
+					context: path.join(__dirname, "../fixtures"),
+					entry: "./a.js",
+					...config
+				})),
+				options
+			)
+		);
+		compiler.outputFileSystem = createFsFromVolume(new Volume());
+		return compiler;
+	};
+
+	it("should run the compilers after their dependencies", done => {
+		const compiler = createMultiCompiler([
+			{ name: "a", dependencies: ["b"] },
+			{ name: "b" }
+		]);
+		const events = [];
+		for (const c of compiler.compilers) {
+			c.hooks.run.tap("test", () => events.push(`${c.name} run`));
+			c.hooks.done.tap("test", () => events.push(`${c.name} done`));
+		}
+		compiler.run((err, stats) => {
+			if (err) return done(err);
+			expect(events).toEqual(["b run", "b done", "a run", "a done"]);
+			expect(stats.stats.map(s => s.compilation.name)).toEqual(["a", "b"]);
+			compiler.close(done);
+		});
+	});
+
+	it("should respect parallelism when running", done => {
+		const compiler = createMultiCompiler(
+			[{ name: "a" }, { name: "b" }, { name: "c" }],
+			{ parallelism: 1 }
+		);
+		let running = 0;
+		let maxRunning = 0;
+		for (const c of compiler.compilers) {
+			c.hooks.run.tap("test", () => {
+				running++;
+				maxRunning = Math.max(maxRunning, running);
+			});
+			c.hooks.done.tap("test", () => {
+				running--;
+			});
+		}
+		compiler.run(err => {
+			if (err) return done(err);
+			expect(maxRunning).toBe(1);
+			compiler.close(done);
+		});
+	});
+
+	it("should report circular dependencies and allow running again", done => {
+		const compiler = createMultiCompiler([
+			{ name: "a", dependencies: ["b"] },
+			{ name: "b", dependencies: ["a"] }
+		]);
+		compiler.run(err => {
+			expect(err.message).toContain(
+				"Circular dependency found in compiler dependencies."
+			);
+			// the failed validation should not leave the compiler running
+			compiler.run(err => {
+				expect(err.message).toContain(
+					"Circular dependency found in compiler dependencies."
+				);
+				done();
+			});
+		});
+	});
+
+	it("should wait for all compilers again when running twice", done => {
+		const compiler = createMultiCompiler(
+			[{ name: "a" }, { name: "b" }],
+			{ parallelism: 1 }
+		);
+		const doneCalls = [];
+		compiler.hooks.done.tap("test", stats => {
+			doneCalls.push(stats.stats.map(s => s.compilation.name));
+		});
+		compiler.run(err => {
+			if (err) return done(err);
+			compiler.run(err => {
+				if (err) return done(err);
+				expect(doneCalls).toEqual([
+					["a", "b"],
+					["a", "b"]
+				]);
+				compiler.close(done);
+			});
+		});
+	});
+
+	it("should allow running after closing the watching without a callback", done => {
+		const compiler = createMultiCompiler([{ name: "a" }, { name: "b" }]);
+		const watching = compiler.watch({}, err => {
+			if (err) return done(err);
+			compiler.hooks.watchClose.tap("test", () => {
+				expect(compiler.running).toBe(false);
+				setImmediate(() => {
+					compiler.run(err => {
+						if (err) return done(err);
+						compiler.close(done);
+					});
+				});
+			});
+			watching.close();
+		});
+	});
 });
 
 describe.skip("Pressure test", function () {
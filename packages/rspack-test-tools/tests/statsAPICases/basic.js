@@ -22,13 +22,14 @@ module.exports = {
 		"PublicPath: auto
 		asset main.js 211 bytes {909} [emitted] (name: main)
 		Entrypoint main 211 bytes = main.js
-		chunk {909} main.js (main) [entry]
-		  ./fixtures/a.js [585] {909}
+		chunk {909} main.js (main) 59 bytes [entry]
+		  > ./fixtures/a main
+		  ./fixtures/a.js [585] 59 bytes {909}
 		    [no exports]
 		    [no exports used]
 		    Statement with side_effects in source code at ./fixtures/a.js:1:0-3:2
 		    entry ./fixtures/a
-		./fixtures/a.js [585] {909}
+		./fixtures/a.js [585] 59 bytes {909}
 		  [no exports]
 		  [no exports used]
 		  Statement with side_effects in source code at ./fixtures/a.js:1:0-3:2
@@ -0,0 +1,59 @@
+let hasErrors;
+let statsJson;
+
+class TestPlugin {
+	apply(compiler) {
+		compiler.hooks.thisCompilation.tap(TestPlugin.name, compilation => {
+			compilation.hooks.processAssets.tapAsync(
+				TestPlugin.name,
+				async (assets, callback) => {
+					const child = compiler.createChildCompiler(
+						compilation,
+						"TestChild",
+						1,
+						compilation.outputOptions,
+						[
+							new compiler.webpack.EntryPlugin(
+								compiler.context,
+								"./fixtures/missing-file",
+								{ name: "TestChild" }
+							)
+						]
+					);
+					child.runAsChild(err => callback(err));
+				}
+			);
+		});
+		compiler.hooks.done.tap("test plugin", stats => {
+			hasErrors = stats.hasErrors();
+			statsJson = stats.toJson({
+				all: false,
+				children: true,
+				errors: true,
+				errorsCount: true
+			});
+		});
+	}
+}
+
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have the errors of child compilations in children",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/a",
+			plugins: [new TestPlugin()]
+		};
+	},
+	async check() {
+		expect(hasErrors).toBe(true);
+		expect(statsJson.errors).toHaveLength(0);
+		expect(statsJson.errorsCount).toBe(1);
+		expect(statsJson.children).toHaveLength(1);
+		const [child] = statsJson.children;
+		expect(child.name).toBe("TestChild");
+		expect(child.errors).toHaveLength(1);
+		expect(child.errors[0].message).toContain("nonexistentfile");
+	}
+};
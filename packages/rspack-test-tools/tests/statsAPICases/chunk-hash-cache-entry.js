@@ -0,0 +1,53 @@
+const { Stats } = require("@rspack/core");
+
+let firstChunkHash;
+
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should always re-hash the chunks with entry modules",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/chunk-b",
+			cache: true,
+			optimization: {
+				runtimeChunk: "single"
+			}
+		};
+	},
+	async build(context, compiler) {
+		await new Promise((resolve, reject) => {
+			compiler.run(err => {
+				if (err) {
+					return reject(err);
+				}
+				firstChunkHash = getChunkHash(compiler, "chunkB");
+				resolve();
+			});
+		});
+		await new Promise((resolve, reject) => {
+			compiler.__internal__rebuild(
+				new Set([context.getSource("./fixtures/chunk-b")]),
+				new Set(),
+				err => {
+					if (err) {
+						return reject(err);
+					}
+					resolve();
+				}
+			);
+		});
+	},
+	async check(_, compiler) {
+		const stats = new Stats(compiler.compilation).toString({
+			all: false,
+			logging: "verbose"
+		});
+		expect(stats).toContain("chunk hash cache: 50.0% (1/2)");
+		expect(getChunkHash(compiler, "chunkB")).toBe(firstChunkHash);
+	}
+};
+
+function getChunkHash(compiler, name) {
+	return compiler.compilation.chunks.find(chunk => chunk.name === name).hash;
+}
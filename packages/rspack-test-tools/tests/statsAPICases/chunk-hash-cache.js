@@ -0,0 +1,52 @@
+const { Stats } = require("@rspack/core");
+
+let firstChunkHash;
+
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description:
+		"should reuse the hashes of unaffected chunks in incremental rebuild mode",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/chunk-b",
+			cache: true
+		};
+	},
+	async build(context, compiler) {
+		await new Promise((resolve, reject) => {
+			compiler.run(err => {
+				if (err) {
+					return reject(err);
+				}
+				firstChunkHash = getChunkHash(compiler);
+				resolve();
+			});
+		});
+		await new Promise((resolve, reject) => {
+			compiler.__internal__rebuild(
+				new Set([context.getSource("./fixtures/chunk-b")]),
+				new Set(),
+				err => {
+					if (err) {
+						return reject(err);
+					}
+					resolve();
+				}
+			);
+		});
+	},
+	async check(_, compiler) {
+		const stats = new Stats(compiler.compilation).toString({
+			all: false,
+			logging: "verbose"
+		});
+		expect(stats).toContain("chunk hash cache: 100.0% (1/1)");
+		expect(getChunkHash(compiler)).toBe(firstChunkHash);
+	}
+};
+
+function getChunkHash(compiler) {
+	return compiler.compilation.chunks.find(chunk => chunk.name === "chunkB")
+		.hash;
+}
@@ -0,0 +1,40 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have the origins of chunks",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: {
+				main: "./fixtures/chunk-b"
+			}
+		};
+	},
+	async check(stats) {
+		const { chunks } = stats?.toJson({
+			all: false,
+			chunks: true,
+			chunkOrigins: true
+		});
+		const main = chunks.find(c => c.names.includes("main"));
+		expect(main.origins).toEqual([
+			expect.objectContaining({
+				module: "",
+				loc: "main",
+				request: "./fixtures/chunk-b"
+			})
+		]);
+		const chunkB = chunks.find(c => c.names.includes("chunkB"));
+		expect(chunkB.origins).toHaveLength(1);
+		const [origin] = chunkB.origins;
+		expect(origin.moduleName).toBe("./fixtures/chunk-b.js");
+		expect(origin.moduleIdentifier).toMatch(/chunk-b\.js$/);
+		expect(origin.request).toBe("./b");
+		expect(origin.loc).toMatch(/^2:\d+-\d+$/);
+
+		const { chunks: withoutOrigins } = stats?.toJson({
+			all: false,
+			chunks: true
+		});
+		expect(withoutOrigins[0].origins).toBeUndefined();
+	}
+};
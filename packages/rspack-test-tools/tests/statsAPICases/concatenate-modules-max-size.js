@@ -0,0 +1,33 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should report the modules exceeding the max concatenated size",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/esm/abc",
+			mode: "production",
+			optimization: {
+				concatenateModules: {
+					maxSize: 1
+				}
+			}
+		};
+	},
+	async check(stats) {
+		const { modules } = stats?.toJson({
+			all: false,
+			modules: true,
+			nestedModules: true,
+			optimizationBailout: true
+		});
+		expect(modules.every(m => m.modules === undefined)).toBe(true);
+		const abc = modules.find(m => m.name === "./fixtures/esm/abc.js");
+		expect(abc.optimizationBailout).toEqual(
+			expect.arrayContaining([
+				expect.stringMatching(
+					/Cannot concat with .*a\.js: Module \.\/fixtures\/esm\/a\.js would make the concatenated module exceed the max size/
+				)
+			])
+		);
+	}
+};
@@ -0,0 +1,34 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have the depth of modules when set stats.depth",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/abc",
+			plugins: [
+				{
+					apply(compiler) {
+						compiler.hooks.compilation.tap("TestPlugin", compilation => {
+							compilation.hooks.statsPrinter.tap("TestPlugin", printer => {
+								printer.hooks.print
+									.for("module.depth")
+									.tap("TestPlugin", depth => `<depth ${depth}>`);
+							});
+						});
+					}
+				}
+			]
+		};
+	},
+	async check(stats) {
+		const depthOf = (json, name) =>
+			json.modules.find(m => m.name === name).depth;
+		const json = stats?.toJson({ all: false, modules: true, depth: true });
+		expect(depthOf(json, "./fixtures/abc.js")).toBe(0);
+		expect(depthOf(json, "./fixtures/a.js")).toBe(1);
+		expect(stats?.toJson({ all: true }).modules[0].depth).toBeUndefined();
+		const output = stats?.toString({ all: false, modules: true, depth: true });
+		expect(output).toContain("<depth 0>");
+		expect(output).toContain("<depth 1>");
+	}
+};
@@ -0,0 +1,26 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have the stable codes of the wrapped parse diagnostics",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: {
+				a: "./fixtures/errors/has-syntax-error",
+				b: "./fixtures/errors/import-meta"
+			}
+		};
+	},
+	async check(stats) {
+		const { errors, warnings } = stats?.toJson({
+			all: false,
+			errors: true,
+			warnings: true
+		});
+		expect(errors.map(error => error.code)).toEqual([
+			"JAVASCRIPT_PARSE_ERROR"
+		]);
+		expect(warnings.map(warning => warning.code)).toEqual([
+			"CRITICAL_DEPENDENCY"
+		]);
+	}
+};
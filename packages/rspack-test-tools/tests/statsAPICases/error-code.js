@@ -0,0 +1,19 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have the stable codes of errors",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/missing-file"
+		};
+	},
+	async check(stats) {
+		const { errors } = stats?.toJson({
+			all: false,
+			errors: true
+		});
+		expect(errors).toHaveLength(1);
+		expect(errors[0].code).toBe("MODULE_NOT_FOUND");
+		expect(errors[0].message).toContain("nonexistentfile");
+	}
+};
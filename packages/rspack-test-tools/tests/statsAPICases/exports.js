@@ -32,16 +32,16 @@ module.exports = {
 		"PublicPath: auto
 		asset main.js 784 bytes [emitted] (name: main)
 		Entrypoint main 784 bytes = main.js
-		runtime modules 3 modules
-		./fixtures/esm/a.js
+		runtime modules 480 bytes 3 modules
+		./fixtures/esm/a.js 38 bytes
 		  [exports: a, default]
 		  [only some exports used: a]
-		./fixtures/esm/b.js
+		./fixtures/esm/b.js 38 bytes
 		  [exports: b, default]
 		  [only some exports used: default]
-		./fixtures/esm/c.js
+		./fixtures/esm/c.js 38 bytes
 		  [exports: c, default]
-		./fixtures/esm/abc.js
+		./fixtures/esm/abc.js 81 bytes
 		  [no exports]
 		  [no exports used]
 		Rspack compiled successfully (36dd148024fa89ee453a)"
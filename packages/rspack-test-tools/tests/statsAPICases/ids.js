@@ -18,8 +18,8 @@ module.exports = {
 		expect(stats?.toJson(options)).toMatchSnapshot();
 		expect(stats?.toString(options)).toMatchInlineSnapshot(`
 		"asset main.js 211 bytes {909} [emitted] (name: main)
-		chunk {909} main.js (main) [entry]
-		./fixtures/a.js [585] {909}"
+		chunk {909} main.js (main) 59 bytes [entry]
+		./fixtures/a.js [585] 59 bytes {909}"
 	`);
 	}
 };
@@ -0,0 +1,34 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have the modules nested in the concatenated module",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/esm/abc",
+			mode: "production",
+			optimization: {
+				concatenateModules: true
+			}
+		};
+	},
+	async check(stats) {
+		const { modules } = stats?.toJson({
+			all: false,
+			modules: true,
+			nestedModules: true
+		});
+		const concatenated = modules.find(m => m.modules);
+		expect(concatenated.modules.map(m => m.name)).toEqual(
+			expect.arrayContaining([
+				"./fixtures/esm/a.js",
+				"./fixtures/esm/abc.js",
+				"./fixtures/esm/b.js"
+			])
+		);
+		expect(
+			stats
+				?.toJson({ all: false, modules: true, nestedModules: false })
+				.modules.every(m => m.modules === undefined)
+		).toBe(true);
+	}
+};
@@ -0,0 +1,52 @@
+class CustomPresetPlugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap("CustomPresetPlugin", compilation => {
+			compilation.hooks.statsPreset
+				.for("only-assets")
+				.tap("CustomPresetPlugin", options => {
+					if (options.all === undefined) options.all = false;
+					if (options.assets === undefined) options.assets = true;
+				});
+		});
+	}
+}
+
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should support the named presets and the custom presets",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/a",
+			plugins: [new CustomPresetPlugin()]
+		};
+	},
+	async check(stats) {
+		const summary = stats?.toJson("summary");
+		expect(summary.errorsCount).toBe(0);
+		expect(summary.warningsCount).toBe(0);
+		expect(typeof summary.version).toBe("string");
+		expect(summary.assets).toBeUndefined();
+		expect(summary.modules).toBeUndefined();
+
+		const minimal = stats?.toJson("minimal");
+		expect(minimal.assets).toHaveLength(1);
+		expect(minimal.modules).toBeUndefined();
+		expect(minimal.filteredModules).toBe(1);
+		expect(minimal.chunks).toBeUndefined();
+
+		const detailed = stats?.toJson("detailed");
+		expect(detailed.chunks[0].origins).toHaveLength(1);
+		expect(detailed.chunks[0].modules).toBeUndefined();
+
+		const onlyAssets = stats?.toJson("only-assets");
+		expect(onlyAssets.assets).toHaveLength(1);
+		expect(onlyAssets.modules).toBeUndefined();
+		expect(onlyAssets.hash).toBeUndefined();
+
+		const withHash = stats?.toJson({ preset: "only-assets", hash: true });
+		expect(withHash.assets).toHaveLength(1);
+		expect(withHash.modules).toBeUndefined();
+		expect(typeof withHash.hash).toBe("string");
+	}
+};
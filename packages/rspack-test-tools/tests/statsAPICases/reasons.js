@@ -0,0 +1,29 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have the reasons of modules limited by stats.reasonsSpace",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/esm/abc"
+		};
+	},
+	async check(stats) {
+		const reasonsOf = options =>
+			stats
+				?.toJson({ all: false, modules: true, reasons: true, ...options })
+				.modules.find(m => m.name === "./fixtures/esm/a.js");
+		const { reasons, filteredReasons } = reasonsOf({});
+		expect(filteredReasons).toBeUndefined();
+		expect(reasons.length).toBeGreaterThan(1);
+		for (const reason of reasons) {
+			expect(reason.moduleName).toBe("./fixtures/esm/abc.js");
+			expect(reason.userRequest).toBe("./a");
+			expect(reason.type).toContain("esm import");
+		}
+		const limited = reasonsOf({ reasonsSpace: 1 });
+		expect(limited.filteredReasons).toBeGreaterThan(0);
+		expect(limited.reasons.length + limited.filteredReasons).toBe(
+			reasons.length
+		);
+	}
+};
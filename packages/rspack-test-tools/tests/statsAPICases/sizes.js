@@ -0,0 +1,37 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have the sizes of each source type",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/sizes/index",
+			module: {
+				rules: [{ test: /\.txt$/, type: "asset/resource" }]
+			}
+		};
+	},
+	async check(stats) {
+		const { modules, chunks } = stats?.toJson({
+			all: false,
+			modules: true,
+			chunks: true,
+			runtimeModules: true
+		});
+		const css = modules.find(m => m.name === "./fixtures/sizes/style.css");
+		expect(css.sizes.css).toBeGreaterThan(0);
+		const asset = modules.find(m => m.name === "./fixtures/sizes/file.txt");
+		expect(Object.keys(asset.sizes)).toContain("asset");
+		const runtime = modules.find(m => m.moduleType === "runtime");
+		expect(Object.keys(runtime.sizes)).toEqual(["runtime"]);
+
+		const [chunk] = chunks;
+		expect(Object.keys(chunk.sizes)).toEqual(
+			expect.arrayContaining(["asset", "css", "javascript", "runtime"])
+		);
+		expect(chunk.sizes.css).toBe(css.sizes.css);
+		const runtimeSize = modules
+			.filter(m => m.moduleType === "runtime")
+			.reduce((sum, m) => sum + m.sizes.runtime, 0);
+		expect(chunk.sizes.runtime).toBe(runtimeSize);
+	}
+};
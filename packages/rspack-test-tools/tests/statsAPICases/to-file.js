@@ -0,0 +1,24 @@
+const fs = require("fs");
+const path = require("path");
+
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should write the stats json to a file",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/a"
+		};
+	},
+	async check(stats, compiler) {
+		const filename = path.join(
+			compiler.options.output.path,
+			"to-file",
+			"stats.json"
+		);
+		const statsOptions = { all: false, assets: true, modules: true };
+		await stats?.toFile(filename, statsOptions);
+		const json = JSON.parse(fs.readFileSync(filename, "utf-8"));
+		expect(json).toEqual(stats?.toJson(statsOptions));
+	}
+};
@@ -0,0 +1,29 @@
+it("should be able to use dynamic defines in watch mode", function () {
+	const module = require("./module");
+	expect(module.default).toBe(WATCH_STEP);
+	expect(module.type).toBe("string");
+});
+
+it("should update a define when its value is changed without dependencies", function () {
+	const module2 = require("./module2");
+	expect(module2.default).toBe(WATCH_STEP);
+	expect(module2.type).toBe("string");
+});
+
+it("should update always when fileDependencies is true", function () {
+	const module3 = require("./module3");
+	expect(module3.default).toBe(WATCH_STEP);
+	expect(module3.type).toBe("string");
+});
+
+it("should allow to use an options object with fileDependencies", function () {
+	const module4 = require("./module4");
+	expect(module4.default).toBe(WATCH_STEP);
+	expect(module4.type).toBe("string");
+});
+
+it("should pass the key to the runtime value", function () {
+	const module5 = require("./module5");
+	expect(module5.default).toBe("TEST_VALUE5");
+	expect(module5.type).toBe("string");
+});
@@ -0,0 +1,2 @@
+export default TEST_VALUE;
+export const type = typeof TEST_VALUE;
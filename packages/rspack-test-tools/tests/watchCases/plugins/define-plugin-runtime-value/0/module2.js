@@ -0,0 +1,2 @@
+export default TEST_VALUE2;
+export const type = typeof TEST_VALUE2;
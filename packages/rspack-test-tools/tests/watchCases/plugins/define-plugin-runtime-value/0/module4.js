@@ -0,0 +1,2 @@
+export default TEST_VALUE4;
+export const type = typeof TEST_VALUE4;
@@ -0,0 +1,2 @@
+export default TEST_VALUE5;
+export const type = typeof TEST_VALUE5;
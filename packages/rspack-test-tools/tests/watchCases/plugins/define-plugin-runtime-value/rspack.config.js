@@ -0,0 +1,27 @@
+const fs = require("fs");
+const path = require("path");
+const rspack = require("@rspack/core");
+
+const valueFile = path.resolve(
+	__dirname,
+	"../../../js/temp/define-plugin-runtime-value/value.txt"
+);
+const readValue = () =>
+	JSON.stringify(fs.readFileSync(valueFile, "utf-8").trim());
+
+/** @type {import('@rspack/core').Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.DefinePlugin({
+			TEST_VALUE: rspack.DefinePlugin.runtimeValue(readValue, [valueFile]),
+			TEST_VALUE2: rspack.DefinePlugin.runtimeValue(readValue, []),
+			TEST_VALUE3: rspack.DefinePlugin.runtimeValue(readValue, true),
+			TEST_VALUE4: rspack.DefinePlugin.runtimeValue(readValue, {
+				fileDependencies: [valueFile]
+			}),
+			TEST_VALUE5: rspack.DefinePlugin.runtimeValue(({ key }) =>
+				JSON.stringify(key)
+			)
+		})
+	]
+};
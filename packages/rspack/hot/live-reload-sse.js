@@ -0,0 +1,16 @@
+/* global __resourceQuery */
+
+"use strict";
+
+if (typeof EventSource !== "function") {
+	throw new Error(
+		"Environment doesn't support live reload (requires EventSource)"
+	);
+}
+
+var handleMessage = require("./live-reload");
+
+var eventSource = new EventSource(decodeURIComponent(__resourceQuery.slice(1)));
+eventSource.onmessage = function (event) {
+	handleMessage(JSON.parse(event.data), module);
+};
@@ -0,0 +1,42 @@
+/* global __webpack_hash__ */
+
+"use strict";
+
+var log = require("./log");
+
+var reload = function reload(reason) {
+	log("info", "[LiveReload] " + reason + ", reloading...");
+	window.location.reload();
+};
+
+/**
+ * @param {{ type: "hash", hash: string } | { type: "reload" }} message message
+ * @param {module} module the module of the client
+ */
+module.exports = function (message, module) {
+	if (message.type === "reload") {
+		return reload("The page has changed");
+	}
+	if (message.type !== "hash" || message.hash === __webpack_hash__) return;
+	if (!module.hot) {
+		return reload("Hot Module Replacement is not enabled");
+	}
+	if (module.hot.status() !== "idle") return;
+	module.hot
+		.check(true)
+		.then(function (updatedModules) {
+			if (!updatedModules) {
+				return reload("Cannot find the update");
+			}
+			require("./log-apply-result")(updatedModules, updatedModules);
+		})
+		.catch(function (err) {
+			var status = module.hot.status();
+			if (["abort", "fail"].indexOf(status) >= 0) {
+				log("warning", "[LiveReload] " + log.formatError(err));
+				reload("Cannot apply the update");
+			} else {
+				log("warning", "[LiveReload] Update failed: " + log.formatError(err));
+			}
+		});
+};
@@ -8,6 +8,7 @@ export default {
 		"json-parse-even-better-errors",
 		"neo-async",
 		"graceful-fs",
+		"tar-stream",
 		{
 			name: "watchpack",
 			externals: {
@@ -49,11 +49,17 @@ import {
 } from "./Stats";
 import { StatsFactory } from "./stats/StatsFactory";
 import { StatsPrinter } from "./stats/StatsPrinter";
-import { concatErrorMsgAndStack, isJsStatsError, toJsAssetInfo } from "./util";
+import {
+	concatErrorMsgAndStack,
+	fromJsAssetInfo,
+	isJsStatsError,
+	toJsAssetInfo
+} from "./util";
 import { createRawFromSource, createSourceFromRaw } from "./util/createSource";
 import { createFakeCompilationDependencies } from "./util/fake";
 import MergeCaller from "./util/MergeCaller";
 import { memoizeValue } from "./util/memoize";
+import type { InputFileSystem } from "./util/fs";
 import { Chunk } from "./Chunk";
 import { CodeGenerationResult, Module } from "./Module";
 import { ChunkGraph } from "./ChunkGraph";
@@ -64,7 +70,7 @@ export type Assets = Record<string, Source>;
 export interface Asset {
 	name: string;
 	source: Source;
-	info: JsAssetInfo;
+	info: JsAssetInfo & AssetInfo;
 }
 
 export type PathData = JsPathData;
@@ -99,6 +105,21 @@ export interface ExecuteModuleContext {
 	__webpack_require__: (id: string) => any;
 }
 
+export interface ImportModuleOptions {
+	/**
+	 * The public path used by the executed modules, e.g. for the url of assets.
+	 */
+	publicPath?: string;
+	/**
+	 * The base uri used by the executed modules, e.g. for `new URL()`.
+	 */
+	baseUri?: string;
+	/**
+	 * The directory to resolve the request from, defaults to `context` of the compiler.
+	 */
+	context?: string;
+}
+
 type CreateStatsOptionsContext = KnownCreateStatsOptionsContext &
 	Record<string, any>;
 
@@ -116,6 +137,10 @@ export class Compilation {
 		optimizeTree: liteTapable.AsyncSeriesHook<
 			[Iterable<Chunk>, Iterable<Module>]
 		>;
+		afterOptimizeTree: liteTapable.SyncHook<
+			[Iterable<Chunk>, Iterable<Module>],
+			void
+		>;
 		optimizeChunkModules: liteTapable.AsyncSeriesBailHook<
 			[Iterable<Chunk>, Iterable<Module>],
 			void
@@ -125,6 +150,12 @@ export class Compilation {
 		processWarnings: tapable.SyncWaterfallHook<[Error[]]>;
 		succeedModule: liteTapable.SyncHook<[Module], void>;
 		stillValidModule: liteTapable.SyncHook<[Module], void>;
+		statsPreset: tapable.HookMap<
+			tapable.SyncHook<
+				[Partial<StatsOptions>, CreateStatsOptionsContext],
+				void
+			>
+		>;
 		statsFactory: tapable.SyncHook<[StatsFactory, StatsOptions], void>;
 		statsPrinter: tapable.SyncHook<[StatsPrinter, StatsOptions], void>;
 		buildModule: liteTapable.SyncHook<[Module]>;
@@ -138,7 +169,7 @@ export class Compilation {
 	outputOptions: OutputNormalized;
 	compiler: Compiler;
 	resolverFactory: ResolverFactory;
-	inputFileSystem: any;
+	inputFileSystem: InputFileSystem | null;
 	logging: Map<string, LogEntry[]>;
 	name?: string;
 	childrenCounters: Record<string, number> = {};
@@ -224,6 +255,7 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 			optimizeModules: new liteTapable.SyncBailHook(["modules"]),
 			afterOptimizeModules: new liteTapable.SyncBailHook(["modules"]),
 			optimizeTree: new liteTapable.AsyncSeriesHook(["chunks", "modules"]),
+			afterOptimizeTree: new liteTapable.SyncHook(["chunks", "modules"]),
 			optimizeChunkModules: new liteTapable.AsyncSeriesBailHook([
 				"chunks",
 				"modules"
@@ -233,6 +265,9 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 			processWarnings: new tapable.SyncWaterfallHook(["warnings"]),
 			succeedModule: new liteTapable.SyncHook(["module"]),
 			stillValidModule: new liteTapable.SyncHook(["module"]),
+			statsPreset: new tapable.HookMap(
+				() => new tapable.SyncHook(["options", "context"])
+			),
 			statsFactory: new tapable.SyncHook(["statsFactory", "options"]),
 			statsPrinter: new tapable.SyncHook(["statsPrinter", "options"]),
 			buildModule: new liteTapable.SyncHook(["module"]),
@@ -339,6 +374,12 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 			options = Object.assign({}, optionsOrPreset);
 		}
 
+		const { preset } = options;
+		if (preset !== undefined) {
+			delete options.preset;
+			this.hooks.statsPreset.for(preset).call(options, context);
+		}
+
 		const all = options.all;
 		const optionOrLocalFallback = <V, D>(v: V, def: D) =>
 			v !== undefined ? v : all !== undefined ? all : def;
@@ -356,6 +397,10 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 			options.chunkRelations,
 			!context.forToString
 		);
+		options.chunkOrigins = optionOrLocalFallback(
+			options.chunkOrigins,
+			!context.forToString
+		);
 		options.modules = optionOrLocalFallback(options.modules, true);
 		options.runtimeModules = optionOrLocalFallback(
 			options.runtimeModules,
@@ -404,6 +449,8 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 			!context.forToString
 		);
 		options.source = optionOrLocalFallback(options.source, false);
+		// `all` doesn't include the depth, same as webpack
+		options.depth = optionsOrFallback(options.depth, false);
 		options.logging = optionOrLocalFallback(
 			options.logging,
 			context.forToString ? "info" : true
@@ -416,7 +463,9 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 			.concat(optionsOrFallback(options.loggingDebug, []) || [])
 			.map(normalizeFilter);
 		options.modulesSpace =
-			options.modulesSpace || (context.forToString ? 15 : Infinity);
+			options.modulesSpace ?? (context.forToString ? 15 : Infinity);
+		options.reasonsSpace =
+			options.reasonsSpace ?? (context.forToString ? 15 : Infinity);
 		options.ids = optionOrLocalFallback(options.ids, !context.forToString);
 		options.children = optionOrLocalFallback(
 			options.children,
@@ -479,8 +528,15 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 			assetInfoUpdateOrFunction === undefined
 				? assetInfoUpdateOrFunction
 				: typeof assetInfoUpdateOrFunction === "function"
-					? jsAssetInfo => toJsAssetInfo(assetInfoUpdateOrFunction(jsAssetInfo))
-					: toJsAssetInfo(assetInfoUpdateOrFunction)
+					? jsAssetInfo =>
+							toJsAssetInfo(
+								assetInfoUpdateOrFunction(fromJsAssetInfo(jsAssetInfo))
+							)
+					: jsAssetInfo =>
+							toJsAssetInfo({
+								...fromJsAssetInfo(jsAssetInfo),
+								...assetInfoUpdateOrFunction
+							})
 		);
 	}
 
@@ -520,9 +576,13 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 		const assets = this.#inner.getAssets();
 
 		return assets.map(asset => {
-			return Object.defineProperty(asset, "source", {
-				get: () => this.__internal__getAssetSource(asset.name)
-			}) as Asset;
+			return Object.defineProperty(
+				{ ...asset, info: fromJsAssetInfo(asset.info) },
+				"source",
+				{
+					get: () => this.__internal__getAssetSource(asset.name)
+				}
+			) as Asset;
 		});
 	}
 
@@ -531,9 +591,13 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 		if (!asset) {
 			return;
 		}
-		return Object.defineProperty(asset, "source", {
-			get: () => this.__internal__getAssetSource(asset.name)
-		}) as Asset;
+		return Object.defineProperty(
+			{ ...asset, info: fromJsAssetInfo(asset.info) },
+			"source",
+			{
+				get: () => this.__internal__getAssetSource(asset.name)
+			}
+		) as Asset;
 	}
 
 	pushDiagnostic(
@@ -1047,7 +1111,10 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 						if (m) {
 							callback(err, Module.__from_binding(m));
 						} else {
-							callback(err || new Error("module no found"), null as any);
+							callback(
+								err || new Error(`Module "${id}" not found`),
+								null as any
+							);
 						}
 					}
 				}
@@ -1059,6 +1126,48 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 		this._rebuildModuleCaller.push([m.identifier(), f]);
 	}
 
+	/**
+	 * Builds the module of `request` with its dependencies, executes it at
+	 * build time and returns its exports. It's available until the
+	 * `processAssets` hook, the dependencies of the executed modules are
+	 * watched and the emitted assets are added to the compilation.
+	 */
+	importModule(request: string, options?: ImportModuleOptions): Promise<any>;
+	importModule(
+		request: string,
+		options: ImportModuleOptions,
+		callback: (err: Error | null, exports?: any) => void
+	): void;
+	importModule(
+		request: string,
+		options: ImportModuleOptions = {},
+		callback?: (err: Error | null, exports?: any) => void
+	): Promise<any> | void {
+		if (!callback) {
+			return new Promise((resolve, reject) => {
+				this.importModule(request, options, (err, exports) => {
+					if (err) reject(err);
+					else resolve(exports);
+				});
+			});
+		}
+		this.#inner.importModule(
+			request,
+			options.publicPath,
+			options.baseUri,
+			undefined,
+			options.context ?? this.compiler.context,
+			(err, res) => {
+				if (err) callback(err);
+				else
+					callback(
+						null,
+						this.compiler.__internal__getModuleExecutionResult(res.id)
+					);
+			}
+		);
+	}
+
 	/**
 	 * Get the `Source` of a given asset filename.
 	 *
@@ -28,6 +28,7 @@ import { ContextModuleFactory } from "./ContextModuleFactory";
 import ResolverFactory = require("./ResolverFactory");
 import ConcurrentCompilationError from "./error/ConcurrentCompilationError";
 import { createThreadsafeNodeFSFromRaw } from "./fileSystem";
+import { MemoryOutputFileSystem } from "./MemoryOutputFileSystem";
 import Cache = require("./lib/Cache");
 import CacheFacade = require("./lib/CacheFacade");
 import { Logger } from "./logging/Logger";
@@ -35,7 +36,12 @@ import {
 	NormalModuleCreateData,
 	NormalModuleFactory
 } from "./NormalModuleFactory";
-import { WatchFileSystem } from "./util/fs";
+import {
+	InputFileSystem,
+	IntermediateFileSystem,
+	OutputFileSystem,
+	WatchFileSystem
+} from "./util/fs";
 import { checkVersion } from "./util/bindingVersionCheck";
 import { Watching } from "./Watching";
 import {
@@ -50,6 +56,7 @@ import { tryRunOrWebpackError } from "./lib/HookWebpackError";
 import {
 	CodeGenerationResult,
 	ContextModuleFactoryAfterResolveResult,
+	ContextModuleFactoryBeforeResolveResult,
 	Module,
 	ResolveData
 } from "./Module";
@@ -68,6 +75,9 @@ export interface AssetEmittedInfo {
 
 class Compiler {
 	#instance?: binding.Rspack;
+	// virtual modules written before the instance is created
+	#virtualModules = new Map<string, string | Buffer>();
+	#memoryOutputFileSystem?: MemoryOutputFileSystem;
 
 	webpack = rspack;
 	compilation?: Compilation;
@@ -83,12 +93,12 @@ class Compiler {
 	watching?: Watching;
 	outputPath!: string;
 	name?: string;
-	inputFileSystem: any;
-	outputFileSystem: typeof import("fs");
+	inputFileSystem: InputFileSystem | null;
+	outputFileSystem: OutputFileSystem;
 	ruleSet: RuleSetCompiler;
 	// @ts-expect-error
 	watchFileSystem: WatchFileSystem;
-	intermediateFileSystem: any;
+	intermediateFileSystem: IntermediateFileSystem | null;
 	// @ts-expect-error
 	watchMode: boolean;
 	context: string;
@@ -111,7 +121,7 @@ class Compiler {
 		normalModuleFactory: tapable.SyncHook<NormalModuleFactory>;
 		contextModuleFactory: tapable.SyncHook<ContextModuleFactory>;
 		initialize: tapable.SyncHook<[]>;
-		shouldEmit: liteTapable.SyncBailHook<[Compilation], boolean>;
+		shouldEmit: liteTapable.SyncBailHook<[Compilation], boolean | void>;
 		infrastructureLog: tapable.SyncBailHook<[string, string, any[]], true>;
 		beforeRun: tapable.AsyncSeriesHook<[Compiler]>;
 		run: tapable.AsyncSeriesHook<[Compiler]>;
@@ -141,7 +151,9 @@ class Compiler {
 	#moduleExecutionResultsMap: Map<number, any>;
 
 	constructor(context: string, options: RspackOptionsNormalized) {
+		this.inputFileSystem = null;
 		this.outputFileSystem = fs;
+		this.intermediateFileSystem = null;
 		this.options = options;
 		this.cache = new Cache();
 		this.compilerPath = "";
@@ -325,20 +337,29 @@ class Compiler {
 			registerCompilationBuildModuleTaps: this.#createHookRegisterTaps(
 				binding.RegisterJsTapKind.CompilationBuildModule,
 				() => this.compilation!.hooks.buildModule,
-				queired => (m: binding.JsModule) =>
-					queired.call(Module.__from_binding(m))
+				queired => (m: binding.JsModule) => {
+					const module = Module.__from_binding(m);
+					queired.call(module);
+					return module.__internal__getBuildResult();
+				}
 			),
 			registerCompilationStillValidModuleTaps: this.#createHookRegisterTaps(
 				binding.RegisterJsTapKind.CompilationStillValidModule,
 				() => this.compilation!.hooks.stillValidModule,
-				queired => (m: binding.JsModule) =>
-					queired.call(Module.__from_binding(m))
+				queired => (m: binding.JsModule) => {
+					const module = Module.__from_binding(m);
+					queired.call(module);
+					return module.__internal__getBuildResult();
+				}
 			),
 			registerCompilationSucceedModuleTaps: this.#createHookRegisterTaps(
 				binding.RegisterJsTapKind.CompilationSucceedModule,
 				() => this.compilation!.hooks.succeedModule,
-				queired => (m: binding.JsModule) =>
-					queired.call(Module.__from_binding(m))
+				queired => (m: binding.JsModule) => {
+					const module = Module.__from_binding(m);
+					queired.call(module);
+					return module.__internal__getBuildResult();
+				}
 			),
 			registerCompilationExecuteModuleTaps: this.#createHookRegisterTaps(
 				binding.RegisterJsTapKind.CompilationExecuteModule,
@@ -438,6 +459,12 @@ class Compiler {
 						this.compilation!.modules
 					)
 			),
+			registerCompilationAfterOptimizeTreeTaps: this.#createHookRegisterTaps(
+				binding.RegisterJsTapKind.CompilationAfterOptimizeTree,
+				() => this.compilation!.hooks.afterOptimizeTree,
+				queried => () =>
+					queried.call(this.compilation!.chunks, this.compilation!.modules)
+			),
 			registerCompilationOptimizeChunkModulesTaps: this.#createHookRegisterTaps(
 				binding.RegisterJsTapKind.CompilationOptimizeChunkModules,
 				() => this.compilation!.hooks.optimizeChunkModules,
@@ -541,7 +568,26 @@ class Compiler {
 								| false
 								| binding.JsContextModuleFactoryBeforeResolveData
 						) => {
-							return queried.promise(bindingData);
+							const data = bindingData
+								? ({
+										context: bindingData.context,
+										request: bindingData.request,
+										recursive: bindingData.recursive,
+										regExp: bindingData.regExp
+											? toRegExp(bindingData.regExp)
+											: undefined
+									} satisfies ContextModuleFactoryBeforeResolveResult)
+								: false;
+							const ret = await queried.promise(data);
+							const result = ret
+								? ({
+										context: ret.context,
+										request: ret.request,
+										recursive: ret.recursive,
+										regExp: ret.regExp?.toString()
+									} satisfies binding.JsContextModuleFactoryBeforeResolveData)
+								: false;
+							return result;
 						}
 				),
 			registerContextModuleFactoryAfterResolveTaps:
@@ -558,10 +604,11 @@ class Compiler {
 								? ({
 										resource: bindingData.resource,
 										regExp: bindingData.regExp
-											? new RegExp(bindingData.regExp)
+											? toRegExp(bindingData.regExp)
 											: undefined,
 										request: bindingData.request,
 										context: bindingData.context,
+										recursive: bindingData.recursive,
 										// TODO: Dependencies are not fully supported yet; this is a placeholder to prevent errors in moment-locales-webpack-plugin.
 										dependencies: []
 									} satisfies ContextModuleFactoryAfterResolveResult)
@@ -572,6 +619,7 @@ class Compiler {
 										resource: ret.resource,
 										context: ret.context,
 										request: ret.request,
+										recursive: ret.recursive,
 										regExp: ret.regExp?.toString()
 									} satisfies binding.JsContextModuleFactoryAfterResolveData)
 								: false;
@@ -580,12 +628,20 @@ class Compiler {
 				)
 		};
 
+		// the assets are kept in memory of the binding in watch mode
+		const emitToMemory = options.experiments.emitToMemory && this.watchMode;
 		this.#instance = new instanceBinding.Rspack(
 			rawOptions,
 			this.builtinPlugins,
 			this.#registers,
-			createThreadsafeNodeFSFromRaw(this.outputFileSystem)
+			emitToMemory
+				? null
+				: createThreadsafeNodeFSFromRaw(this.outputFileSystem)
 		);
+		for (const [path, content] of this.#virtualModules) {
+			this.#instance.writeVirtualModule(path, content);
+		}
+		this.#virtualModules.clear();
 
 		callback(null, this.#instance);
 	}
@@ -705,9 +761,11 @@ class Compiler {
 				}
 			}
 
+			// the entry chunks, same as webpack, e.g. html-webpack-plugin uses them to
+			// generate the filenames of the compiled templates
 			const entries = [];
 			for (const ep of compilation.entrypoints.values()) {
-				entries.push(...ep.getFiles());
+				entries.push(...ep.chunks);
 			}
 
 			return finalCallback(null, entries, compilation);
@@ -1121,9 +1179,40 @@ class Compiler {
 		this.builtinPlugins.push(plugin);
 	}
 
+	/**
+	 * Note: This is not a webpack public API, maybe removed in future.
+	 * @internal
+	 */
+	__internal__writeVirtualModule(path: string, content: string | Buffer) {
+		if (this.#instance) {
+			this.#instance.writeVirtualModule(path, content);
+		} else {
+			this.#virtualModules.set(path, content);
+		}
+	}
+
+	/**
+	 * The file system of the assets emitted into memory when `experiments.emitToMemory`
+	 * is enabled in watch mode, it's `null` otherwise.
+	 */
+	get memoryOutputFileSystem(): MemoryOutputFileSystem | null {
+		if (!this.options.experiments.emitToMemory || !this.watchMode) {
+			return null;
+		}
+		return (this.#memoryOutputFileSystem ??= new MemoryOutputFileSystem(
+			() => this.#instance
+		));
+	}
+
 	__internal__getModuleExecutionResult(id: number) {
 		return this.#moduleExecutionResultsMap.get(id);
 	}
 }
 
+// the binding passes regular expressions in the `/source/flags` form
+function toRegExp(regExp: string) {
+	const end = regExp.lastIndexOf("/");
+	return new RegExp(regExp.slice(1, end), regExp.slice(end + 1));
+}
+
 export { Compiler };
@@ -0,0 +1,110 @@
+import type * as binding from "@rspack/binding";
+import path from "path";
+
+export interface MemoryOutputFileStats {
+	size: number;
+	isFile(): boolean;
+	isDirectory(): boolean;
+}
+
+function createNotFoundError(syscall: string, file: string) {
+	const error: NodeJS.ErrnoException = new Error(
+		`ENOENT: no such file or directory, ${syscall} '${file}'`
+	);
+	error.code = "ENOENT";
+	error.errno = -2;
+	error.syscall = syscall;
+	error.path = file;
+	return error;
+}
+
+/**
+ * A read only file system of the assets emitted into memory by `experiments.emitToMemory`,
+ * it implements the methods of node's `fs` a dev middleware needs to serve the assets.
+ */
+export class MemoryOutputFileSystem {
+	#getInstance: () => binding.Rspack | undefined;
+
+	constructor(getInstance: () => binding.Rspack | undefined) {
+		this.#getInstance = getInstance;
+	}
+
+	/**
+	 * Absolute paths of the emitted files.
+	 */
+	files(): string[] {
+		return this.#getInstance()?.memoryOutputFiles() ?? [];
+	}
+
+	existsSync(file: string): boolean {
+		return this.#stat(file) !== null;
+	}
+
+	readFileSync(file: string): Buffer;
+	readFileSync(file: string, encoding: BufferEncoding): string;
+	readFileSync(file: string, encoding?: BufferEncoding): Buffer | string {
+		const content = this.#getInstance()?.readMemoryOutputFile(file);
+		if (!content) {
+			throw createNotFoundError("open", file);
+		}
+		return encoding ? content.toString(encoding) : content;
+	}
+
+	readFile(
+		file: string,
+		callback: (error: NodeJS.ErrnoException | null, content?: Buffer) => void
+	) {
+		let content: Buffer;
+		try {
+			content = this.readFileSync(file);
+		} catch (e: any) {
+			return callback(e);
+		}
+		callback(null, content);
+	}
+
+	statSync(file: string): MemoryOutputFileStats {
+		const stats = this.#stat(file);
+		if (!stats) {
+			throw createNotFoundError("stat", file);
+		}
+		return stats;
+	}
+
+	stat(
+		file: string,
+		callback: (
+			error: NodeJS.ErrnoException | null,
+			stats?: MemoryOutputFileStats
+		) => void
+	) {
+		let stats: MemoryOutputFileStats;
+		try {
+			stats = this.statSync(file);
+		} catch (e: any) {
+			return callback(e);
+		}
+		callback(null, stats);
+	}
+
+	#stat(file: string): MemoryOutputFileStats | null {
+		const content = this.#getInstance()?.readMemoryOutputFile(file);
+		if (content) {
+			return {
+				size: content.length,
+				isFile: () => true,
+				isDirectory: () => false
+			};
+		}
+		// a directory only exists if it contains any emitted file
+		const dir = file.endsWith(path.sep) ? file : file + path.sep;
+		if (this.files().some(f => f.startsWith(dir))) {
+			return {
+				size: 0,
+				isFile: () => false,
+				isDirectory: () => true
+			};
+		}
+		return null;
+	}
+}
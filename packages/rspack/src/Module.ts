@@ -1,8 +1,11 @@
 import {
+	JsBuildInfo,
+	JsBuildMeta,
 	JsCodegenerationResult,
 	JsCodegenerationResults,
 	JsCreateData,
-	JsModule
+	JsModule,
+	JsModuleBuildResult
 } from "@rspack/binding";
 import { Source } from "webpack-sources";
 import { createSourceFromRaw } from "./util/createSource";
@@ -31,6 +34,8 @@ export type ContextModuleFactoryBeforeResolveResult =
 	| {
 			context: string;
 			request?: string;
+			recursive: boolean;
+			regExp?: RegExp;
 	  };
 
 export type ContextModuleFactoryAfterResolveResult =
@@ -39,12 +44,82 @@ export type ContextModuleFactoryAfterResolveResult =
 			resource: string;
 			context: string;
 			request: string;
+			recursive: boolean;
 			regExp?: RegExp;
 			dependencies: Array<any>;
 	  };
 
+export type BuildInfo = {
+	cacheable: boolean;
+	strict: boolean;
+	[key: string]: any;
+};
+
+export type BuildMeta = {
+	strictHarmonyModule: boolean;
+	hasTopLevelAwait: boolean;
+	/**
+	 * Whether the module is an async module, it's not synced back to the module.
+	 */
+	async: boolean;
+	esModule: boolean;
+	exportsType?: "default" | "namespace" | "flagged" | "dynamic";
+	defaultObject: false | "redirect" | "redirect-warn";
+	moduleArgument: JsBuildMeta["moduleArgument"];
+	exportsArgument: JsBuildMeta["exportsArgument"];
+	sideEffectFree?: boolean;
+};
+
+export function toBuildInfo(buildInfo: JsBuildInfo | undefined): BuildInfo {
+	return {
+		...buildInfo?.extras,
+		cacheable: buildInfo?.cacheable ?? true,
+		strict: buildInfo?.strict ?? false
+	};
+}
+
+export function toBuildMeta(buildMeta: JsBuildMeta | undefined): BuildMeta {
+	return {
+		strictHarmonyModule: buildMeta?.strictHarmonyModule ?? false,
+		hasTopLevelAwait: buildMeta?.hasTopLevelAwait ?? false,
+		async: buildMeta?.isAsync ?? buildMeta?.hasTopLevelAwait ?? false,
+		esModule: buildMeta?.esModule ?? false,
+		exportsType:
+			!buildMeta || buildMeta.exportsType === "unset"
+				? undefined
+				: buildMeta.exportsType,
+		defaultObject:
+			!buildMeta || buildMeta.defaultObject === "false"
+				? false
+				: buildMeta.defaultObject,
+		moduleArgument: buildMeta?.moduleArgument ?? "module",
+		exportsArgument: buildMeta?.exportsArgument ?? "exports",
+		sideEffectFree: buildMeta?.sideEffectFree
+	};
+}
+
+export function toJsBuildInfo(buildInfo: BuildInfo): JsBuildInfo {
+	const { cacheable, strict, ...extras } = buildInfo;
+	return { cacheable, strict, extras };
+}
+
+export function toJsBuildMeta(buildMeta: BuildMeta): JsBuildMeta {
+	return {
+		strictHarmonyModule: buildMeta.strictHarmonyModule,
+		hasTopLevelAwait: buildMeta.hasTopLevelAwait,
+		esModule: buildMeta.esModule,
+		exportsType: buildMeta.exportsType ?? "unset",
+		defaultObject: buildMeta.defaultObject || "false",
+		moduleArgument: buildMeta.moduleArgument,
+		exportsArgument: buildMeta.exportsArgument,
+		sideEffectFree: buildMeta.sideEffectFree
+	};
+}
+
 export class Module {
 	#inner: JsModule;
+	#buildInfo?: BuildInfo;
+	#buildMeta?: BuildMeta;
 	_originalSource?: Source;
 
 	rawRequest?: string;
@@ -76,6 +151,37 @@ export class Module {
 		}
 	}
 
+	/**
+	 * The changes are synced to the module after the `succeedModule` hook.
+	 */
+	get buildInfo(): BuildInfo {
+		if (!this.#buildInfo) {
+			this.#buildInfo = toBuildInfo(this.#inner.buildInfo);
+		}
+		return this.#buildInfo;
+	}
+
+	/**
+	 * The changes are synced to the module after the `succeedModule` hook.
+	 */
+	get buildMeta(): BuildMeta {
+		if (!this.#buildMeta) {
+			this.#buildMeta = toBuildMeta(this.#inner.buildMeta);
+		}
+		return this.#buildMeta;
+	}
+
+	/**
+	 * @internal
+	 */
+	__internal__getBuildResult(): JsModuleBuildResult | undefined {
+		if (!this.#buildInfo && !this.#buildMeta) return undefined;
+		return {
+			buildInfo: this.#buildInfo && toJsBuildInfo(this.#buildInfo),
+			buildMeta: this.#buildMeta && toJsBuildMeta(this.#buildMeta)
+		};
+	}
+
 	identifier(): string {
 		return this.#inner.moduleIdentifier;
 	}
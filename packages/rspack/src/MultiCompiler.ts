@@ -10,7 +10,12 @@
 
 import { Compiler, RspackOptions, Stats } from ".";
 import ResolverFactory = require("./ResolverFactory");
-import { WatchFileSystem } from "./util/fs";
+import {
+	InputFileSystem,
+	IntermediateFileSystem,
+	OutputFileSystem,
+	WatchFileSystem
+} from "./util/fs";
 import { Watching } from "./Watching";
 import { AsyncSeriesHook, Callback, MultiHook, SyncHook } from "tapable";
 import MultiStats from "./MultiStats";
@@ -74,6 +79,7 @@ export class MultiCompiler {
 	watching: Watching;
 	// @ts-expect-error
 	watchMode: boolean;
+	#resetDoneCompilers: () => void;
 
 	constructor(
 		compilers: Compiler[] | Record<string, Compiler>,
@@ -110,14 +116,14 @@ export class MultiCompiler {
 		this.running = false;
 
 		const compilerStats: (Stats | null)[] = this.compilers.map(() => null);
+		const compilerDone: boolean[] = this.compilers.map(() => false);
 		let doneCompilers = 0;
 		for (let index = 0; index < this.compilers.length; index++) {
 			const compiler = this.compilers[index];
 			const compilerIndex = index;
-			let compilerDone = false;
 			compiler.hooks.done.tap("MultiCompiler", stats => {
-				if (!compilerDone) {
-					compilerDone = true;
+				if (!compilerDone[compilerIndex]) {
+					compilerDone[compilerIndex] = true;
 					doneCompilers++;
 				}
 				compilerStats[compilerIndex] = stats;
@@ -126,12 +132,26 @@ export class MultiCompiler {
 				}
 			});
 			compiler.hooks.invalid.tap("MultiCompiler", () => {
-				if (compilerDone) {
-					compilerDone = false;
+				if (compilerDone[compilerIndex]) {
+					compilerDone[compilerIndex] = false;
 					doneCompilers--;
 				}
 			});
 		}
+		this.#resetDoneCompilers = () => {
+			compilerStats.fill(null);
+			compilerDone.fill(false);
+			doneCompilers = 0;
+		};
+	}
+
+	/**
+	 * Resets the state of the previous run or watch, so that the done hook
+	 * waits for all the compilers again.
+	 */
+	#reset() {
+		this.running = false;
+		this.#resetDoneCompilers();
 	}
 
 	get options() {
@@ -169,16 +189,16 @@ export class MultiCompiler {
 	}
 
 	get intermediateFileSystem() {
-		throw new Error("Cannot read outputFileSystem of a MultiCompiler");
+		throw new Error("Cannot read intermediateFileSystem of a MultiCompiler");
 	}
 
-	set inputFileSystem(value) {
+	set inputFileSystem(value: InputFileSystem | null) {
 		for (const compiler of this.compilers) {
 			compiler.inputFileSystem = value;
 		}
 	}
 
-	set outputFileSystem(value: typeof import("fs")) {
+	set outputFileSystem(value: OutputFileSystem) {
 		for (const compiler of this.compilers) {
 			compiler.outputFileSystem = value;
 		}
@@ -190,7 +210,7 @@ export class MultiCompiler {
 		}
 	}
 
-	set intermediateFileSystem(value: any) {
+	set intermediateFileSystem(value: IntermediateFileSystem | null) {
 		for (const compiler of this.compilers) {
 			compiler.intermediateFileSystem = value;
 		}
@@ -493,6 +513,7 @@ export class MultiCompiler {
 		if (this.running) {
 			return handler(new ConcurrentCompilationError()) as never;
 		}
+		this.#reset();
 		this.running = true;
 
 		if (this.validateDependencies(handler)) {
@@ -522,6 +543,7 @@ export class MultiCompiler {
 			return new MultiWatching(watchings, this);
 		}
 
+		this.#reset();
 		return new MultiWatching([], this);
 	}
 
@@ -529,6 +551,7 @@ export class MultiCompiler {
 		if (this.running) {
 			return callback(new ConcurrentCompilationError());
 		}
+		this.#reset();
 		this.running = true;
 
 		if (this.validateDependencies(callback)) {
@@ -536,13 +559,15 @@ export class MultiCompiler {
 				() => {},
 				(compiler, _, callback) => compiler.run(callback),
 				(err, stats) => {
-					this.running = false;
+					this.#reset();
 
 					if (callback !== undefined) {
 						return callback(err, stats);
 					}
 				}
 			);
+		} else {
+			this.#reset();
 		}
 	}
 
@@ -560,8 +585,11 @@ export class MultiCompiler {
 			(compiler, cb) => {
 				compiler.close(cb);
 			},
-			// @ts-expect-error
-			callback
+			err => {
+				this.#reset();
+				// @ts-expect-error
+				callback(err);
+			}
 		);
 	}
 }
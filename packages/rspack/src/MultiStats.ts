@@ -13,6 +13,7 @@ import { StatsCompilation } from "./stats/statsFactoryUtils";
 import { Stats } from "./Stats";
 import { indent } from "./util";
 import * as identifierUtils from "./util/identifier";
+import { writeJsonFile } from "./util/writeJsonFile";
 
 export default class MultiStats {
 	stats: Stats[];
@@ -132,6 +133,10 @@ export default class MultiStats {
 		return obj;
 	}
 
+	toFile(filename: string, options?: any): Promise<void> {
+		return writeJsonFile(filename, this.toJson(options));
+	}
+
 	toString(options: any) {
 		options = this.#createChildOptions(options, { forToString: true });
 		const results = this.stats.map((stat, idx) => {
@@ -11,6 +11,7 @@
 import { MultiCompiler } from "./MultiCompiler";
 import { Watching } from "./Watching";
 import asyncLib from "neo-async";
+import { Callback } from "tapable";
 
 class MultiWatching {
 	watchings: Watching[];
@@ -24,17 +25,35 @@ class MultiWatching {
 		this.watchings = watchings;
 		this.compiler = compiler;
 	}
-	// @ts-expect-error
-	invalidate(callback) {
+	invalidate(callback?: Callback<Error, void>): void;
+	invalidate(files: Iterable<string>, callback?: Callback<Error, void>): void;
+	invalidate(
+		filesOrCallback?: Iterable<string> | Callback<Error, void>,
+		callback?: Callback<Error, void>
+	) {
+		let files: string[] | undefined;
+		if (typeof filesOrCallback === "function") {
+			callback = filesOrCallback;
+		} else if (filesOrCallback) {
+			files = Array.from(filesOrCallback);
+		}
+		const invalidate = (
+			watching: Watching,
+			callback?: Callback<Error, void>
+		) =>
+			files
+				? watching.invalidate(files, callback)
+				: watching.invalidate(callback);
 		if (callback) {
 			asyncLib.each(
 				this.watchings,
-				(watching, callback) => watching.invalidate(callback),
+				(watching, callback) => invalidate(watching, callback),
+				// @ts-expect-error
 				callback
 			);
 		} else {
 			for (const watching of this.watchings) {
-				watching.invalidate();
+				invalidate(watching);
 			}
 		}
 	}
@@ -51,9 +70,9 @@ class MultiWatching {
 				watching.close(finishedCallback);
 			},
 			err => {
+				this.compiler.running = false;
 				this.compiler.hooks.watchClose.call();
 				if (typeof callback === "function") {
-					this.compiler.running = false;
 					callback(err);
 				}
 			}
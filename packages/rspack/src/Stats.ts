@@ -11,6 +11,7 @@ import type * as binding from "@rspack/binding";
 import { Compilation, FilterItemTypes } from ".";
 import { StatsValue, StatsOptions } from "./config";
 import type { StatsCompilation } from "./stats/statsFactoryUtils";
+import { writeJsonFile } from "./util/writeJsonFile";
 
 export type {
 	StatsCompilation,
@@ -47,11 +48,17 @@ export class Stats {
 	}
 
 	hasErrors() {
-		return this.#inner.getErrors().length > 0;
+		return (
+			this.#inner.getErrors().length > 0 ||
+			this.compilation.children.some(child => child.getStats().hasErrors())
+		);
 	}
 
 	hasWarnings() {
-		return this.#inner.getWarnings().length > 0;
+		return (
+			this.#inner.getWarnings().length > 0 ||
+			this.compilation.children.some(child => child.getStats().hasWarnings())
+		);
 	}
 
 	toJson(opts?: StatsValue, forToString?: boolean): StatsCompilation {
@@ -80,6 +87,13 @@ export class Stats {
 		return stats as StatsCompilation;
 	}
 
+	/**
+	 * Writes the stats json to a file, the file is written off the main thread.
+	 */
+	toFile(filename: string, opts?: StatsValue): Promise<void> {
+		return writeJsonFile(filename, this.toJson(opts));
+	}
+
 	toString(opts?: StatsValue) {
 		const options = this.compilation.createStatsOptions(opts, {
 			forToString: true
@@ -120,9 +134,8 @@ export function normalizeStatsPreset(options?: StatsValue): StatsOptions {
 		return presetToOptions(options);
 	else if (!options) return {};
 	else {
-		let obj = { ...presetToOptions(options.preset), ...options };
-		delete obj.preset;
-		return obj;
+		const { preset, ...rest } = options;
+		return { ...presetToOptions(preset), ...rest };
 	}
 }
 
@@ -136,7 +149,50 @@ function presetToOptions(name?: boolean | string): StatsOptions {
 		case "verbose":
 			return {
 				all: true,
-				modulesSpace: Infinity
+				modulesSpace: Infinity,
+				reasonsSpace: Infinity
+			};
+		case "detailed":
+			return {
+				hash: true,
+				builtAt: true,
+				entrypoints: true,
+				chunkGroups: true,
+				ids: true,
+				chunks: true,
+				chunkRelations: true,
+				chunkModules: false,
+				chunkOrigins: true,
+				depth: true,
+				usedExports: true,
+				providedExports: true,
+				optimizationBailout: true,
+				publicPath: true,
+				logging: true,
+				runtimeModules: true,
+				modulesSpace: 1000,
+				reasonsSpace: 1000
+			};
+		case "minimal":
+			return {
+				all: false,
+				version: true,
+				timings: true,
+				modules: true,
+				modulesSpace: 0,
+				assets: true,
+				errors: true,
+				errorsCount: true,
+				warnings: true,
+				warningsCount: true,
+				logging: "warn"
+			};
+		case "summary":
+			return {
+				all: false,
+				version: true,
+				errorsCount: true,
+				warningsCount: true
 			};
 		case "errors-only":
 			return {
@@ -155,8 +211,14 @@ function presetToOptions(name?: boolean | string): StatsOptions {
 				warningsCount: true,
 				logging: "warn"
 			};
-		default:
+		case "normal":
+		case true:
+		case undefined:
 			return {};
+		default:
+			// the presets registered by plugins are applied when the stats options
+			// are created, see `compilation.hooks.statsPreset`
+			return typeof name === "string" ? { preset: name } : {};
 	}
 }
 
@@ -182,7 +182,21 @@ export class Watching {
 		}
 	}
 
-	invalidate(callback?: Callback<Error, void>) {
+	/**
+	 * Triggers a rebuild, the files are marked as modified, so only the modules
+	 * depending on them are rebuilt.
+	 */
+	invalidate(callback?: Callback<Error, void>): void;
+	invalidate(files: Iterable<string>, callback?: Callback<Error, void>): void;
+	invalidate(
+		filesOrCallback?: Iterable<string> | Callback<Error, void>,
+		callback?: Callback<Error, void>
+	) {
+		if (typeof filesOrCallback === "function") {
+			callback = filesOrCallback;
+		} else if (filesOrCallback) {
+			this.#mergeWithCollected(new Set(filesOrCallback), new Set());
+		}
 		if (callback) {
 			this.callbacks.push(callback);
 		}
@@ -307,6 +321,16 @@ export class Watching {
 			for (const cb of cbs) cb(err);
 		};
 
+		if (
+			this.invalid &&
+			!this.suspended &&
+			!this.blocked &&
+			!(this.isBlocked?.() && (this.blocked = true))
+		) {
+			this.#go();
+			return;
+		}
+
 		const cbs = this.callbacks;
 		this.callbacks = [];
 		const startTime = this.startTime; // store last startTime for compilation
@@ -362,6 +386,10 @@ export class Watching {
 		}
 	}
 
+	/**
+	 * Pauses the rebuilds, the changes are still collected and built after
+	 * `resume` is called.
+	 */
 	suspend() {
 		this.suspended = true;
 	}
@@ -0,0 +1,22 @@
+import {
+	BuiltinPluginName,
+	RawBundleAnalysisRspackPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type BundleAnalysisRspackPluginOptions = {
+	/**
+	 * The filename of the emitted analysis, relative to the output path.
+	 * @default "bundle-analysis.json"
+	 */
+	filename?: string;
+};
+
+export const BundleAnalysisRspackPlugin = create(
+	BuiltinPluginName.BundleAnalysisRspackPlugin,
+	(
+		options: BundleAnalysisRspackPluginOptions = {}
+	): RawBundleAnalysisRspackPluginOptions => ({
+		filename: options.filename ?? "bundle-analysis.json"
+	})
+);
@@ -0,0 +1,79 @@
+import {
+	BuiltinPluginName,
+	RawCustomModuleTypeResult,
+	RawCustomModuleTypeRspackPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type CustomModuleTypeContext = {
+	resource: string;
+	/**
+	 * The source map of the input code.
+	 */
+	map?: string;
+};
+
+export type CustomModuleTypeResult =
+	| string
+	| {
+			code: string;
+			/**
+			 * The source map from the input code to `code`, the mappings of the
+			 * input are kept only when it's provided.
+			 */
+			map?: string | object;
+	  };
+
+export type CustomModuleTypeRspackPluginOptions = {
+	/**
+	 * The module type, used by `Rule.type` to select the modules.
+	 */
+	type: string;
+	/**
+	 * Converts the module source into javascript code.
+	 */
+	parse?: (
+		source: string,
+		context: CustomModuleTypeContext
+	) => CustomModuleTypeResult;
+	/**
+	 * Post-processes the generated javascript code of the module.
+	 */
+	generate?: (
+		code: string,
+		context: CustomModuleTypeContext
+	) => CustomModuleTypeResult;
+};
+
+const toRawResult = (
+	result: CustomModuleTypeResult
+): RawCustomModuleTypeResult => {
+	if (typeof result === "string") {
+		return { code: result };
+	}
+	const { code, map } = result;
+	return {
+		code,
+		map: typeof map === "object" ? JSON.stringify(map) : map
+	};
+};
+
+export const CustomModuleTypeRspackPlugin = create(
+	BuiltinPluginName.CustomModuleTypeRspackPlugin,
+	(
+		options: CustomModuleTypeRspackPluginOptions
+	): RawCustomModuleTypeRspackPluginOptions => {
+		const { type, parse, generate } = options;
+		return {
+			type,
+			parse: parse
+				? ({ source, map, resource }) =>
+						toRawResult(parse(source, { resource, map }))
+				: undefined,
+			generate: generate
+				? ({ source, map, resource }) =>
+						toRawResult(generate(source, { resource, map }))
+				: undefined
+		};
+	}
+);
@@ -0,0 +1,52 @@
+import {
+	BuiltinPluginName,
+	RawCustomRuntimeModuleRspackPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type CustomRuntimeModuleContext = {
+	chunkId?: string;
+	chunkName?: string;
+};
+
+export type CustomRuntimeModuleRspackPluginOptions = {
+	/**
+	 * The name of the runtime module, the module identifier is `webpack/runtime/${name}`.
+	 */
+	name: string;
+	/**
+	 * The stage of the runtime module, the runtime modules are ordered by stage.
+	 * @default "normal"
+	 */
+	stage?: "normal" | "basic" | "attach" | "trigger";
+	/**
+	 * The names of the runtime chunks to add the runtime module to. All the runtime chunks by default.
+	 */
+	chunks?: string[];
+	/**
+	 * Only adds the runtime module when one of these runtime requirements is used, e.g. `RuntimeGlobals.ensureChunk`.
+	 * The runtime module is always added by default.
+	 */
+	runtimeRequirements?: string[];
+	/**
+	 * Generates the code of the runtime module.
+	 */
+	generate: (context: CustomRuntimeModuleContext) => string;
+};
+
+export const CustomRuntimeModuleRspackPlugin = create(
+	BuiltinPluginName.CustomRuntimeModuleRspackPlugin,
+	(
+		options: CustomRuntimeModuleRspackPluginOptions
+	): RawCustomRuntimeModuleRspackPluginOptions => {
+		const { name, stage, chunks, runtimeRequirements, generate } = options;
+		return {
+			name,
+			stage: stage ?? "normal",
+			chunks,
+			runtimeRequirements: runtimeRequirements ?? [],
+			generate: ({ chunkId, chunkName }) => generate({ chunkId, chunkName })
+		};
+	},
+	"compilation"
+);
@@ -1,17 +1,94 @@
-import { BuiltinPluginName } from "@rspack/binding";
-import { create } from "./base";
-
-export type DefinePluginOptions = Record<string, string | boolean | undefined>;
-export const DefinePlugin = create(
-	BuiltinPluginName.DefinePlugin,
-	(define: DefinePluginOptions): Record<string, string> => {
-		const entries = Object.entries(define).map(([key, value]) => {
-			if (typeof value !== "string") {
-				value = value === undefined ? "undefined" : JSON.stringify(value);
+import {
+	BuiltinPlugin,
+	BuiltinPluginName,
+	RawDefinePluginOptions
+} from "@rspack/binding";
+import { RspackBuiltinPlugin, createBuiltinPlugin } from "./base";
+
+type DefinePrimitive = string | boolean | undefined;
+
+export type RuntimeValueContext = {
+	key: string;
+};
+
+/**
+ * `true` makes the modules using the value uncacheable,
+ * an array is the same as `{ fileDependencies }`
+ */
+export type RuntimeValueOptions =
+	| true
+	| string[]
+	| {
+			fileDependencies?: string[];
+	  };
+
+export class RuntimeValue {
+	fileDependencies: string[];
+	cacheable: boolean;
+
+	constructor(
+		public fn: (context: RuntimeValueContext) => DefinePrimitive,
+		options?: RuntimeValueOptions
+	) {
+		if (options === true) {
+			this.fileDependencies = [];
+			this.cacheable = false;
+		} else if (Array.isArray(options)) {
+			this.fileDependencies = options;
+			this.cacheable = true;
+		} else {
+			this.fileDependencies = options?.fileDependencies ?? [];
+			this.cacheable = true;
+		}
+	}
+}
+
+const toCode = (value: DefinePrimitive): string => {
+	if (typeof value === "string") {
+		return value;
+	}
+	return value === undefined ? "undefined" : JSON.stringify(value);
+};
+
+export type DefinePluginOptions = Record<
+	string,
+	DefinePrimitive | RuntimeValue
+>;
+export class DefinePlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.DefinePlugin;
+	affectedHooks = "compilation" as const;
+
+	/**
+	 * The value is computed again for each compilation, the modules using it
+	 * are built again when its file dependencies are changed
+	 */
+	static runtimeValue(
+		fn: (context: RuntimeValueContext) => DefinePrimitive,
+		options?: RuntimeValueOptions
+	) {
+		return new RuntimeValue(fn, options);
+	}
+
+	constructor(private define: DefinePluginOptions) {
+		super();
+	}
+
+	raw(): BuiltinPlugin {
+		const options: RawDefinePluginOptions = {
+			define: {},
+			runtimeValues: {}
+		};
+		for (const [key, value] of Object.entries(this.define)) {
+			if (value instanceof RuntimeValue) {
+				options.runtimeValues[key] = {
+					get: () => toCode(value.fn({ key })),
+					fileDependencies: value.fileDependencies,
+					cacheable: value.cacheable
+				};
+			} else {
+				options.define[key] = toCode(value);
 			}
-			return [key, value];
-		});
-		return Object.fromEntries(entries);
-	},
-	"compilation"
-);
+		}
+		return createBuiltinPlugin(this.name, options);
+	}
+}
@@ -0,0 +1,25 @@
+import {
+	BuiltinPluginName,
+	RawDllReferenceAgencyPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type DllReferenceAgencyPluginOptions = Omit<
+	RawDllReferenceAgencyPluginOptions,
+	"content"
+> & {
+	content: Record<string, unknown>;
+};
+
+export const DllReferenceAgencyPlugin = create(
+	BuiltinPluginName.DllReferenceAgencyPlugin,
+	(
+		options: DllReferenceAgencyPluginOptions
+	): RawDllReferenceAgencyPluginOptions => {
+		return {
+			...options,
+			content: JSON.stringify(options.content)
+		};
+	},
+	"compilation"
+);
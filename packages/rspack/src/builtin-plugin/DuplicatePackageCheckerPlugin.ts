@@ -0,0 +1,34 @@
+import {
+	BuiltinPluginName,
+	RawDuplicatePackageCheckerRspackPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type DuplicatePackageCheckerRspackPluginOptions = {
+	/**
+	 * Reports the duplicated packages as errors instead of warnings.
+	 * @default false
+	 */
+	emitError?: boolean;
+	/**
+	 * The filename of the emitted report, relative to the output path.
+	 * No report is emitted if it's not set.
+	 */
+	filename?: string;
+	/**
+	 * The names of the packages which are allowed to have multiple versions.
+	 * @default []
+	 */
+	exclude?: string[];
+};
+
+export const DuplicatePackageCheckerRspackPlugin = create(
+	BuiltinPluginName.DuplicatePackageCheckerRspackPlugin,
+	(
+		options: DuplicatePackageCheckerRspackPluginOptions = {}
+	): RawDuplicatePackageCheckerRspackPluginOptions => ({
+		emitError: options.emitError ?? false,
+		filename: options.filename,
+		exclude: options.exclude ?? []
+	})
+);
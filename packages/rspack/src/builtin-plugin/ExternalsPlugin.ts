@@ -1,33 +1,61 @@
-import { BuiltinPluginName, RawExternalsPluginOptions } from "@rspack/binding";
-import { create } from "./base";
-import { ExternalItem, ExternalItemValue, Externals } from "..";
+import {
+	BuiltinPlugin,
+	BuiltinPluginName,
+	RawExternalItemFnCtx,
+	RawExternalsPluginOptions
+} from "@rspack/binding";
+import { RspackBuiltinPlugin, createBuiltinPlugin } from "./base";
+import {
+	Compiler,
+	ExternalItem,
+	ExternalItemFunctionData,
+	ExternalItemValue,
+	Externals
+} from "..";
+import { ResolveOptions } from "../config/zod";
 
-export const ExternalsPlugin = create(
-	BuiltinPluginName.ExternalsPlugin,
-	(type: string, externals: Externals): RawExternalsPluginOptions => {
-		return {
-			type,
-			externals: (Array.isArray(externals) ? externals : [externals])
+export class ExternalsPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.ExternalsPlugin;
+
+	constructor(
+		private type: string,
+		private externals: Externals
+	) {
+		super();
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin {
+		const raw: RawExternalsPluginOptions = {
+			type: this.type,
+			externals: (Array.isArray(this.externals)
+				? this.externals
+				: [this.externals]
+			)
 				.filter(Boolean)
-				.map(getRawExternalItem)
+				.map(item => getRawExternalItem(compiler, item))
 		};
+		return createBuiltinPlugin(this.name, raw);
 	}
-);
+}
 
 type ArrayType<T> = T extends (infer R)[] ? R : never;
 type RecordValue<T> = T extends Record<any, infer R> ? R : never;
 type RawExternalItem = ArrayType<RawExternalsPluginOptions["externals"]>;
 type RawExternalItemValue = RecordValue<RawExternalItem>;
 
-function getRawExternalItem(item: ExternalItem | undefined): RawExternalItem {
+function getRawExternalItem(
+	compiler: Compiler,
+	item: ExternalItem | undefined
+): RawExternalItem {
 	if (typeof item === "string" || item instanceof RegExp) {
 		return item;
 	}
 
 	if (typeof item === "function") {
-		return async ctx => {
+		return async (ctx: RawExternalItemFnCtx) => {
+			const data = getExternalItemFunctionData(compiler, ctx);
 			return await new Promise((resolve, reject) => {
-				const promise = item(ctx, (err, result, type) => {
+				const promise = item(data, (err, result, type) => {
 					if (err) reject(err);
 					resolve({
 						result: getRawExternalItemValueFormFnResult(result),
@@ -55,6 +83,33 @@ function getRawExternalItem(item: ExternalItem | undefined): RawExternalItem {
 	throw new TypeError(`Unexpected type of external item: ${typeof item}`);
 }
 
+function getExternalItemFunctionData(
+	compiler: Compiler,
+	ctx: RawExternalItemFnCtx
+): ExternalItemFunctionData {
+	return {
+		...ctx,
+		getResolve(options?: ResolveOptions) {
+			const resolver = compiler.resolverFactory.get("normal", {
+				dependencyType: ctx.dependencyType
+			});
+			const child = options ? resolver.withOptions(options) : resolver;
+			return (context, request, callback) => {
+				if (callback) {
+					child.resolve({}, context, request, {}, callback);
+				} else {
+					return new Promise((resolve, reject) => {
+						child.resolve({}, context, request, {}, (err, result) => {
+							if (err) reject(err);
+							else resolve(result);
+						});
+					});
+				}
+			};
+		}
+	};
+}
+
 function getRawExternalItemValueFormFnResult(result?: ExternalItemValue) {
 	return result === undefined ? result : getRawExternalItemValue(result);
 }
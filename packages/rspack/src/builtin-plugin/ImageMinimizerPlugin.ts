@@ -0,0 +1,74 @@
+import { z } from "zod";
+import {
+	BuiltinPluginName,
+	RawImageMinimizerFormatOptions,
+	RawImageMinimizerRspackPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+const rule = z.string().or(z.instanceof(RegExp));
+const rules = rule.or(rule.array());
+
+const imageMinimizerPreset = z.enum(["lossless", "lossy"]);
+export type ImageMinimizerPreset = z.infer<typeof imageMinimizerPreset>;
+
+const imageMinimizerFormatOptions = z.strictObject({
+	preset: imageMinimizerPreset.optional(),
+	quality: z.number().int().min(1).max(100).optional()
+});
+export type ImageMinimizerFormatOptions = z.infer<
+	typeof imageMinimizerFormatOptions
+>;
+
+// Only a lossless encoder is available for webp
+const imageMinimizerWebpOptions = z.strictObject({
+	preset: z.literal("lossless").optional()
+});
+export type ImageMinimizerWebpOptions = z.infer<
+	typeof imageMinimizerWebpOptions
+>;
+
+const imageMinimizerRspackPluginOptions = z.strictObject({
+	test: rules.optional(),
+	include: rules.optional(),
+	exclude: rules.optional(),
+	preset: imageMinimizerPreset.optional(),
+	png: z.literal(false).or(imageMinimizerFormatOptions).optional(),
+	jpeg: z.literal(false).or(imageMinimizerFormatOptions).optional(),
+	webp: z.literal(false).or(imageMinimizerWebpOptions).optional()
+});
+export type ImageMinimizerRspackPluginOptions = z.infer<
+	typeof imageMinimizerRspackPluginOptions
+>;
+
+export const ImageMinimizerRspackPlugin = create(
+	BuiltinPluginName.ImageMinimizerRspackPlugin,
+	(
+		options: ImageMinimizerRspackPluginOptions = {}
+	): RawImageMinimizerRspackPluginOptions => {
+		const preset = options.preset ?? "lossless";
+		const getRawFormatOptions = (
+			format: ImageMinimizerFormatOptions | false | undefined
+		): RawImageMinimizerFormatOptions | undefined => {
+			if (format === false) {
+				return undefined;
+			}
+			return {
+				preset: format?.preset ?? preset,
+				quality: format?.quality ?? 75
+			};
+		};
+
+		return {
+			test: options.test,
+			include: options.include,
+			exclude: options.exclude,
+			png: getRawFormatOptions(options.png),
+			jpeg: getRawFormatOptions(options.jpeg),
+			webp:
+				options.webp === false
+					? undefined
+					: { preset: "lossless", quality: 100 }
+		};
+	}
+);
@@ -1,8 +1,28 @@
-import { BuiltinPluginName } from "@rspack/binding";
+import {
+	BuiltinPluginName,
+	RawModuleConcatenationPluginOptions
+} from "@rspack/binding";
 import { create } from "./base";
 
+export type ModuleConcatenationPluginOptions = {
+	/**
+	 * The max size of a concatenated module in bytes.
+	 */
+	maxSize?: number;
+	/**
+	 * Whether to inline the CommonJS modules without dependencies into the
+	 * concatenated modules importing them.
+	 */
+	allowCjsLeaves?: boolean;
+};
+
 export const ModuleConcatenationPlugin = create(
 	BuiltinPluginName.ModuleConcatenationPlugin,
-	() => {},
+	(
+		options: ModuleConcatenationPluginOptions = {}
+	): RawModuleConcatenationPluginOptions => ({
+		maxSize: options.maxSize,
+		allowCjsLeaves: options.allowCjsLeaves
+	}),
 	"compilation"
 );
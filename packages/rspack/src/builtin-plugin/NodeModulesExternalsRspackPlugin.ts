@@ -0,0 +1,18 @@
+import {
+	BuiltinPluginName,
+	RawNodeModulesExternalsRspackPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export const NodeModulesExternalsRspackPlugin = create(
+	BuiltinPluginName.NodeModulesExternalsRspackPlugin,
+	(
+		allowlist: (string | RegExp)[],
+		importType: string
+	): RawNodeModulesExternalsRspackPluginOptions => {
+		return {
+			allowlist,
+			importType
+		};
+	}
+);
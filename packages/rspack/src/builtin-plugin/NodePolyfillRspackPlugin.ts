@@ -0,0 +1,52 @@
+import {
+	BuiltinPlugin,
+	BuiltinPluginName,
+	RawNodePolyfillRspackPluginOptions
+} from "@rspack/binding";
+import { RspackBuiltinPlugin, createBuiltinPlugin } from "./base";
+import { Compiler } from "../Compiler";
+
+export type NodePolyfillRspackPluginOptions = {
+	/**
+	 * Only polyfill these node core modules, all of them are polyfilled if it's not set.
+	 */
+	includeAliases?: string[];
+	/**
+	 * The node core modules which are not polyfilled.
+	 * @default []
+	 */
+	excludeAliases?: string[];
+	/**
+	 * Provide the `process` and `Buffer` globals with the polyfills, they are only injected into the modules using them.
+	 * @default { process: true, Buffer: true }
+	 */
+	globals?: {
+		process?: boolean;
+		Buffer?: boolean;
+	};
+};
+
+export class NodePolyfillRspackPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.NodePolyfillRspackPlugin;
+
+	constructor(private options: NodePolyfillRspackPluginOptions = {}) {
+		super();
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin {
+		const fallback = compiler.options.resolve.fallback ?? {};
+		const raw: RawNodePolyfillRspackPluginOptions = {
+			includeAliases: this.options.includeAliases,
+			// the modules with a `resolve.fallback` configured by the user are not polyfilled
+			excludeAliases: [
+				...(this.options.excludeAliases ?? []),
+				...Object.keys(fallback)
+			],
+			globals: {
+				process: this.options.globals?.process ?? true,
+				Buffer: this.options.globals?.Buffer ?? true
+			}
+		};
+		return createBuiltinPlugin(this.name, raw);
+	}
+}
@@ -1,13 +1,33 @@
 import { BuiltinPluginName, RawProgressPluginOptions } from "@rspack/binding";
 import { create } from "./base";
 
+export type ProgressPluginHandler = (
+	percentage: number,
+	msg: string,
+	...args: string[]
+) => void;
+
+export type ProgressPluginOptions = Partial<
+	Omit<RawProgressPluginOptions, "handler">
+> & {
+	handler?: ProgressPluginHandler;
+};
+
 export type ProgressPluginArgument =
-	| Partial<RawProgressPluginOptions>
+	| ProgressPluginOptions
+	| ProgressPluginHandler
 	| undefined;
 export const ProgressPlugin = create(
 	BuiltinPluginName.ProgressPlugin,
-	(progress: ProgressPluginArgument = {}): RawProgressPluginOptions => ({
-		prefix: progress.prefix ?? "",
-		profile: progress.profile ?? false
-	})
+	(progress: ProgressPluginArgument = {}): RawProgressPluginOptions => {
+		const { handler, ...options }: ProgressPluginOptions =
+			typeof progress === "function" ? { handler: progress } : progress;
+		return {
+			prefix: options.prefix ?? "",
+			profile: options.profile ?? false,
+			handler: handler
+				? (percent, msg, items) => handler(percent, msg, ...items)
+				: undefined
+		};
+	}
 );
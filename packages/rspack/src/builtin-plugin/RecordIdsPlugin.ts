@@ -0,0 +1,25 @@
+import {
+	BuiltinPluginName,
+	RawRecordIdsPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type RecordIdsPluginOptions = {
+	/**
+	 * The records file to read the module and chunk ids from.
+	 */
+	inputPath?: string;
+	/**
+	 * The records file to write the module and chunk ids to.
+	 */
+	outputPath?: string;
+};
+
+export const RecordIdsPlugin = create(
+	BuiltinPluginName.RecordIdsPlugin,
+	(options: RecordIdsPluginOptions): RawRecordIdsPluginOptions => ({
+		inputPath: options.inputPath,
+		outputPath: options.outputPath
+	}),
+	"compilation"
+);
@@ -1,7 +1,51 @@
-import { BuiltinPluginName } from "@rspack/binding";
+import {
+	BuiltinPluginName,
+	RawSwcCssMinimizerRspackPluginOptions
+} from "@rspack/binding";
 import { create } from "./base";
 
+type MinifyCondition = string | RegExp;
+type MinifyConditions = MinifyCondition | MinifyCondition[];
+
+export type SwcCssMinimizerRspackPluginOptions = {
+	test?: MinifyConditions;
+	exclude?: MinifyConditions;
+	include?: MinifyConditions;
+	/**
+	 * Keep the comments matching the conditions, the conditions are tested
+	 * against the text between `/*` and `*\/`.
+	 */
+	preserveComments?: MinifyConditions;
+	/**
+	 * Set to `false` to only remove the whitespaces without compressing.
+	 * @default true
+	 */
+	compress?: boolean;
+	/**
+	 * Remove the `@keyframes` which are not referenced by any animation.
+	 * @default false
+	 */
+	discardUnusedKeyframes?: boolean;
+	/**
+	 * The number of decimal places the numbers computed from `calc()`, `min()`,
+	 * `max()` and `clamp()` are rounded to, they are not rounded by default.
+	 */
+	calcPrecision?: number;
+};
+
 export const SwcCssMinimizerRspackPlugin = create(
 	BuiltinPluginName.SwcCssMinimizerRspackPlugin,
-	(options?: any /* TODO: extend more options */) => undefined
+	(
+		options?: SwcCssMinimizerRspackPluginOptions
+	): RawSwcCssMinimizerRspackPluginOptions => {
+		return {
+			test: options?.test,
+			include: options?.include,
+			exclude: options?.exclude,
+			preserveComments: options?.preserveComments,
+			compress: options?.compress,
+			discardUnusedKeyframes: options?.discardUnusedKeyframes,
+			calcPrecision: options?.calcPrecision
+		};
+	}
 );
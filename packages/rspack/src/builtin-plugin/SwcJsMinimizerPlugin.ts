@@ -1,20 +1,22 @@
 import {
+	BuiltinPlugin,
 	BuiltinPluginName,
 	RawExtractComments,
 	RawSwcJsMinimizerRspackPluginOptions
 } from "@rspack/binding";
-import { create } from "./base";
+import { Compiler } from "../Compiler";
+import { RspackBuiltinPlugin, createBuiltinPlugin } from "./base";
 
 type MinifyCondition = string | RegExp;
 type MinifyConditions = MinifyCondition | MinifyCondition[];
 
 type ExtractCommentsCondition = boolean | RegExp;
 type ExtractCommentsBanner = string | boolean;
-// type ExtractFilename = string;
+type ExtractFilename = string;
 type ExtractCommentsObject = {
 	condition?: ExtractCommentsCondition | undefined;
 	banner?: ExtractCommentsBanner | undefined;
-	// filename?: ExtractFilename | undefined
+	filename?: ExtractFilename | undefined;
 };
 type ExtractCommentsOptions = ExtractCommentsCondition | ExtractCommentsObject;
 
@@ -56,6 +58,33 @@ export type SwcJsMinimizerRspackPluginOptions = {
 	test?: MinifyConditions;
 	exclude?: MinifyConditions;
 	include?: MinifyConditions;
+	/**
+	 * The options of terser, they are mapped onto the options of the SWC minifier,
+	 * the options set explicitly take precedence over them.
+	 */
+	terserOptions?: TerserOptions;
+};
+
+/**
+ * The options of [terser](https://terser.org/docs/options/),
+ * the unsupported fields are ignored with a compilation warning.
+ */
+export type TerserOptions = {
+	compress?: (TerserCompressOptions & Record<string, any>) | boolean;
+	mangle?: (TerserMangleOptions & Record<string, any>) | boolean;
+	format?: Record<string, any>;
+	/**
+	 * @deprecated Deprecated in terser, use `format` instead
+	 */
+	output?: Record<string, any>;
+	ecma?: TerserEcmaVersion;
+	module?: boolean;
+	toplevel?: boolean;
+	keep_classnames?: boolean;
+	keep_fnames?: boolean;
+	ie8?: boolean;
+	safari10?: boolean;
+	[key: string]: any;
 };
 
 /**
@@ -234,6 +263,10 @@ export interface TerserCompressOptions {
 }
 export interface TerserMangleOptions {
 	props?: TerserManglePropertiesOptions;
+	/**
+	 * @alias props
+	 */
+	properties?: TerserManglePropertiesOptions;
 	toplevel?: boolean;
 	keep_classnames?: boolean;
 	keep_fnames?: boolean;
@@ -242,7 +275,37 @@ export interface TerserMangleOptions {
 	safari10?: boolean;
 	reserved?: string[];
 }
-export interface TerserManglePropertiesOptions {}
+export interface TerserManglePropertiesOptions {
+	/**
+	 * Only mangle the property names matching the regex
+	 */
+	regex?: string | RegExp;
+	/**
+	 * Do not mangle the property names listed
+	 */
+	reserved?: string[];
+	/**
+	 * Mangle the property names of the undeclared variables
+	 */
+	undeclared?: boolean;
+	/**
+	 * Do not mangle the quoted property names, e.g. `obj["foo"]` and `{ "foo": 1 }`
+	 */
+	keep_quoted?: boolean;
+}
+
+function getRawManglePropertiesOptions(
+	options?: TerserManglePropertiesOptions
+) {
+	if (!options) {
+		return undefined;
+	}
+	const { regex, ...rest } = options;
+	return {
+		...rest,
+		regex: regex instanceof RegExp ? regex.source : regex
+	};
+}
 
 function getRawCompressOptions(options?: SwcJsMinimizerRspackPluginOptions) {
 	function _inner(): TerserCompressOptions | boolean {
@@ -292,10 +355,12 @@ function getRawMangleOptions(options?: SwcJsMinimizerRspackPluginOptions) {
 		}
 
 		if (options?.mangle && typeof options.mangle === "object") {
+			const { properties, props, ...rest } = options.mangle;
 			return {
 				// TODO: deprecate default merging in 0.4
 				..._default,
-				...options.mangle
+				...rest,
+				props: getRawManglePropertiesOptions(props ?? properties)
 			};
 		}
 
@@ -328,6 +393,287 @@ function getRawFormatOptions(options?: SwcJsMinimizerRspackPluginOptions) {
 	return JSON.stringify(_inner());
 }
 
+const TERSER_COMPRESS_OPTIONS = new Set([
+	"arguments",
+	"arrows",
+	"booleans",
+	"booleans_as_integers",
+	"collapse_vars",
+	"comparisons",
+	"computed_props",
+	"conditionals",
+	"dead_code",
+	"defaults",
+	"directives",
+	"drop_console",
+	"drop_debugger",
+	"ecma",
+	"evaluate",
+	"expression",
+	"global_defs",
+	"hoist_funs",
+	"hoist_props",
+	"hoist_vars",
+	"ie8",
+	"if_return",
+	"inline",
+	"join_vars",
+	"keep_classnames",
+	"keep_fargs",
+	"keep_fnames",
+	"keep_infinity",
+	"loops",
+	"negate_iife",
+	"passes",
+	"properties",
+	"pure_getters",
+	"pure_funcs",
+	"reduce_funcs",
+	"reduce_vars",
+	"sequences",
+	"side_effects",
+	"switches",
+	"top_retain",
+	"toplevel",
+	"typeofs",
+	"unsafe",
+	"unsafe_arrows",
+	"unsafe_comps",
+	"unsafe_function",
+	"unsafe_math",
+	"unsafe_symbols",
+	"unsafe_methods",
+	"unsafe_proto",
+	"unsafe_regexp",
+	"unsafe_undefined",
+	"unused",
+	"module",
+	"const_to_let"
+]);
+
+const TERSER_MANGLE_OPTIONS = new Set([
+	"props",
+	"properties",
+	"toplevel",
+	"keep_classnames",
+	"keep_fnames",
+	"keep_private_props",
+	"ie8",
+	"safari10",
+	"reserved",
+	"eval"
+]);
+
+const TERSER_MANGLE_PROPERTIES_OPTIONS = new Set([
+	"regex",
+	"reserved",
+	"undeclared",
+	"keep_quoted"
+]);
+
+const TERSER_FORMAT_OPTIONS = new Set([
+	"ascii_only",
+	"beautify",
+	"braces",
+	"comments",
+	"ecma",
+	"indent_level",
+	"inline_script",
+	"keep_numbers",
+	"keep_quoted_props",
+	"max_line_len",
+	"preamble",
+	"quote_keys",
+	"quote_style",
+	"preserve_annotations",
+	"safari10",
+	"semicolons",
+	"shebang",
+	"webkit",
+	"wrap_iife",
+	"wrap_func_args"
+]);
+
+const TERSER_OPTIONS = new Set([
+	"compress",
+	"mangle",
+	"format",
+	"output",
+	"ecma",
+	"module",
+	"toplevel",
+	"keep_classnames",
+	"keep_fnames",
+	"ie8",
+	"safari10"
+]);
+
+function pickSupported(
+	options: Record<string, any>,
+	supported: Set<string>,
+	path: string,
+	unsupported: string[]
+) {
+	const picked: Record<string, any> = {};
+	for (const [key, value] of Object.entries(options)) {
+		if (supported.has(key)) {
+			picked[key] = value;
+		} else {
+			unsupported.push(`${path}.${key}`);
+		}
+	}
+	return picked;
+}
+
+/**
+ * Maps the terser options onto the options of the SWC minifier.
+ */
+function getSwcOptionsFromTerserOptions(
+	terserOptions: TerserOptions,
+	// the paths of the unsupported fields, they are ignored
+	unsupported: string[] = []
+): Pick<
+	SwcJsMinimizerRspackPluginOptions,
+	"compress" | "mangle" | "format" | "module"
+> {
+	for (const key of Object.keys(terserOptions)) {
+		if (!TERSER_OPTIONS.has(key)) {
+			unsupported.push(key);
+		}
+	}
+	const {
+		ecma,
+		module,
+		toplevel,
+		keep_classnames,
+		keep_fnames,
+		ie8,
+		safari10
+	} = terserOptions;
+	const withoutUndefined = (options: Record<string, any>) =>
+		Object.fromEntries(
+			Object.entries(options).filter(([, value]) => value !== undefined)
+		);
+
+	let compress: SwcJsMinimizerRspackPluginOptions["compress"];
+	if (terserOptions.compress === false) {
+		compress = false;
+	} else {
+		compress = {
+			...withoutUndefined({
+				ecma,
+				module,
+				toplevel,
+				keep_classnames,
+				keep_fnames,
+				ie8
+			}),
+			...(isObject(terserOptions.compress)
+				? pickSupported(
+						terserOptions.compress,
+						TERSER_COMPRESS_OPTIONS,
+						"compress",
+						unsupported
+					)
+				: {})
+		};
+	}
+
+	let mangle: SwcJsMinimizerRspackPluginOptions["mangle"];
+	if (terserOptions.mangle === false) {
+		mangle = false;
+	} else {
+		const picked = isObject(terserOptions.mangle)
+			? pickSupported(
+					terserOptions.mangle,
+					TERSER_MANGLE_OPTIONS,
+					"mangle",
+					unsupported
+				)
+			: {};
+		const { props, properties, ...rest } = picked;
+		const mangleProps = props ?? properties;
+		const resolvedMangle: TerserMangleOptions = {
+			...withoutUndefined({
+				toplevel,
+				keep_classnames,
+				keep_fnames,
+				ie8,
+				safari10
+			}),
+			...rest
+		};
+		if (isObject(mangleProps)) {
+			resolvedMangle.props = pickSupported(
+				mangleProps,
+				TERSER_MANGLE_PROPERTIES_OPTIONS,
+				props ? "mangle.props" : "mangle.properties",
+				unsupported
+			);
+		} else if (mangleProps === true) {
+			resolvedMangle.props = {};
+		}
+		mangle = resolvedMangle;
+	}
+
+	const formatOptions = {
+		...terserOptions.output,
+		...terserOptions.format
+	};
+	const format: Record<string, any> = withoutUndefined({ ecma, safari10 });
+	for (const [key, value] of Object.entries(
+		pickSupported(
+			formatOptions,
+			TERSER_FORMAT_OPTIONS,
+			terserOptions.format ? "format" : "output",
+			unsupported
+		)
+	)) {
+		if (key === "comments") {
+			if (value === true || value === "all") {
+				format.comments = "all";
+			} else if (value === false) {
+				format.comments = false;
+			} else {
+				if (value !== "some") {
+					unsupported.push("format.comments");
+				}
+				format.comments = "some";
+			}
+			continue;
+		}
+		// some of the snake case fields are not aliased in SWC
+		format[key.replace(/_([a-z])/g, (_, c: string) => c.toUpperCase())] =
+			value;
+	}
+
+	return withoutUndefined({
+		compress,
+		mangle,
+		format,
+		module
+	});
+}
+
+function resolveTerserOptions(
+	options: SwcJsMinimizerRspackPluginOptions | undefined,
+	// the paths of the unsupported terser options, they are ignored
+	unsupported: string[]
+): SwcJsMinimizerRspackPluginOptions | undefined {
+	if (!options?.terserOptions) {
+		return options;
+	}
+	const { terserOptions, ...rest } = options;
+	const swcOptions = getSwcOptionsFromTerserOptions(terserOptions, unsupported);
+	const resolved: SwcJsMinimizerRspackPluginOptions = { ...swcOptions };
+	for (const [key, value] of Object.entries(rest)) {
+		if (value !== undefined) {
+			(resolved as any)[key] = value;
+		}
+	}
+	return resolved;
+}
+
 function isObject(value: any): value is Object {
 	const type = typeof value;
 
@@ -368,7 +714,8 @@ function getRawExtractCommentsOptions(
 		} else {
 			const res = {
 				condition: conditionStr(extractComments.condition),
-				banner: extractComments.banner
+				banner: extractComments.banner,
+				filename: extractComments.filename
 			};
 			return res;
 		}
@@ -377,12 +724,27 @@ function getRawExtractCommentsOptions(
 	}
 }
 
-export const SwcJsMinimizerRspackPlugin = create(
-	BuiltinPluginName.SwcJsMinimizerRspackPlugin,
-	(
-		options?: SwcJsMinimizerRspackPluginOptions
-	): RawSwcJsMinimizerRspackPluginOptions => {
-		return {
+class UnsupportedTerserOptionsWarning extends Error {
+	constructor(paths: string[]) {
+		super(
+			`SwcJsMinimizerRspackPlugin - The following terser options are not supported and ignored: ${paths.join(
+				", "
+			)}`
+		);
+		this.name = "UnsupportedTerserOptionsWarning";
+	}
+}
+
+export class SwcJsMinimizerRspackPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.SwcJsMinimizerRspackPlugin;
+	affectedHooks = "compilation" as const;
+	_options: RawSwcJsMinimizerRspackPluginOptions;
+	_unsupported: string[] = [];
+
+	constructor(rawOptions?: SwcJsMinimizerRspackPluginOptions) {
+		super();
+		const options = resolveTerserOptions(rawOptions, this._unsupported);
+		this._options = {
 			extractComments: getRawExtractCommentsOptions(options?.extractComments),
 			compress: getRawCompressOptions(options),
 			mangle: getRawMangleOptions(options),
@@ -392,6 +754,16 @@ export const SwcJsMinimizerRspackPlugin = create(
 			include: options?.include,
 			exclude: options?.exclude
 		};
-	},
-	"compilation"
-);
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin {
+		if (this._unsupported.length > 0) {
+			compiler.hooks.thisCompilation.tap(this.name, compilation => {
+				compilation.warnings.push(
+					new UnsupportedTerserOptionsWarning(this._unsupported)
+				);
+			});
+		}
+		return createBuiltinPlugin(this.name, this._options);
+	}
+}
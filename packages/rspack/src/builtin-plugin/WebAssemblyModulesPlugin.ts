@@ -0,0 +1,8 @@
+import { BuiltinPluginName } from "@rspack/binding";
+import { create } from "./base";
+
+export const WebAssemblyModulesPlugin = create(
+	BuiltinPluginName.WebAssemblyModulesPlugin,
+	() => {},
+	"compilation"
+);
@@ -11,6 +11,7 @@ export * from "./ExternalsPlugin";
 export * from "./NodeTargetPlugin";
 export * from "./ElectronTargetPlugin";
 export * from "./HttpExternalsRspackPlugin";
+export * from "./NodeModulesExternalsRspackPlugin";
 export * from "./EnableChunkLoadingPlugin";
 export * from "./EnableLibraryPlugin";
 export * from "./EnableWasmLoadingPlugin";
@@ -28,6 +29,7 @@ export * from "./NamedModuleIdsPlugin";
 export * from "./DeterministicModuleIdsPlugin";
 export * from "./NamedChunkIdsPlugin";
 export * from "./DeterministicChunkIdsPlugin";
+export * from "./RecordIdsPlugin";
 export * from "./RealContentHashPlugin";
 export * from "./RemoveEmptyChunksPlugin";
 export * from "./EnsureChunkConditionsPlugin";
@@ -39,6 +41,7 @@ export * from "./JsonModulesPlugin";
 export * from "./InferAsyncModulesPlugin";
 export * from "./JavascriptModulesPlugin";
 export * from "./AsyncWebAssemblyModulesPlugin";
+export * from "./WebAssemblyModulesPlugin";
 export * from "./AssetModulesPlugin";
 export * from "./SourceMapDevToolPlugin";
 export * from "./EvalSourceMapDevToolPlugin";
@@ -53,11 +56,22 @@ export * from "./CssModulesPlugin";
 export * from "./APIPlugin";
 export * from "./RuntimeChunkPlugin";
 export * from "./SizeLimitsPlugin";
+export * from "./DllEntryPlugin";
+export * from "./LibManifestPlugin";
+export * from "./FlagAllModulesAsUsedPlugin";
+export * from "./DllReferenceAgencyPlugin";
+export * from "./lazy-compilation";
 
 export * from "./HtmlRspackPlugin";
 export * from "./CopyRspackPlugin";
 export * from "./SwcJsMinimizerPlugin";
 export * from "./SwcCssMinimizerPlugin";
+export * from "./ImageMinimizerPlugin";
+export * from "./BundleAnalysisPlugin";
+export * from "./DuplicatePackageCheckerPlugin";
+export * from "./NodePolyfillRspackPlugin";
+export * from "./CustomModuleTypeRspackPlugin";
+export * from "./CustomRuntimeModuleRspackPlugin";
 
 export * from "./JsLoaderRspackPlugin";
 export * from "./css-extract";
@@ -0,0 +1,178 @@
+/**
+ * The following code is modified based on
+ * https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/hmr/lazyCompilationBackend.js
+ *
+ * MIT Licensed
+ * Author Tobias Koppers @sokra
+ * Copyright (c) JS Foundation and other contributors
+ * https://github.com/webpack/webpack/blob/main/LICENSE
+ */
+import type {
+	IncomingMessage,
+	ServerOptions as HttpServerOptions,
+	ServerResponse
+} from "http";
+import type { ServerOptions as HttpsServerOptions } from "https";
+import type { AddressInfo, ListenOptions, Server, Socket } from "net";
+import type { Compiler } from "../../Compiler";
+
+export interface LazyCompilationBackendOptions {
+	client: string;
+	listen?: number | ListenOptions | ((server: Server) => void);
+	protocol?: "http" | "https";
+	server?: HttpServerOptions | HttpsServerOptions | (() => Server);
+}
+
+export interface LazyCompilationModuleArgs {
+	/** the identifier of the proxied module */
+	module: string;
+	/** the resource path of the proxied module */
+	path: string;
+}
+
+export interface LazyCompilationModuleInfo {
+	active: boolean;
+	data: string;
+	client: string;
+}
+
+export interface LazyCompilationBackend {
+	dispose(callback: (err?: Error | null) => void): void;
+	module(args: LazyCompilationModuleArgs): LazyCompilationModuleInfo;
+}
+
+const getKey = (identifier: string) =>
+	encodeURIComponent(
+		identifier.replace(/\\/g, "/").replace(/@/g, "_")
+	).replace(/%(2F|3A|24|26|2B|2C|3B|3D|3A)/g, decodeURIComponent);
+
+export const lazyCompilationBackend =
+	(options: LazyCompilationBackendOptions) =>
+	(
+		compiler: Compiler,
+		callback: (err: Error | null, backend?: LazyCompilationBackend) => void
+	) => {
+		const logger = compiler.getInfrastructureLogger("LazyCompilationBackend");
+		const activeModules = new Map<string, number>();
+		// the resource paths of the proxied modules, which are invalidated once
+		// the modules are requested
+		const modulePaths = new Map<string, string>();
+		const prefix = "/lazy-compilation-using-";
+
+		const isHttps =
+			options.protocol === "https" ||
+			(typeof options.server === "object" &&
+				("key" in options.server || "pfx" in options.server));
+
+		const createServer =
+			typeof options.server === "function"
+				? options.server
+				: (() => {
+						const http = isHttps ? require("https") : require("http");
+						return http.createServer.bind(http, options.server);
+					})();
+		const listen =
+			typeof options.listen === "function"
+				? options.listen
+				: (server: Server) => {
+						let listen = options.listen;
+						if (typeof listen === "object" && !("port" in listen))
+							listen = { ...listen, port: undefined };
+						server.listen(listen);
+					};
+
+		const protocol = options.protocol || (isHttps ? "https" : "http");
+
+		const requestListener = (req: IncomingMessage, res: ServerResponse) => {
+			const keys = req.url!.slice(prefix.length).split("@");
+			req.socket.on("close", () => {
+				setTimeout(() => {
+					for (const key of keys) {
+						const oldValue = activeModules.get(key) || 0;
+						activeModules.set(key, oldValue - 1);
+						if (oldValue === 1) {
+							logger.log(
+								`${key} is no longer in use. Next compilation will skip this module.`
+							);
+						}
+					}
+				}, 120000);
+			});
+			req.socket.setNoDelay(true);
+			res.writeHead(200, {
+				"content-type": "text/event-stream",
+				"Access-Control-Allow-Origin": "*",
+				"Access-Control-Allow-Methods": "*",
+				"Access-Control-Allow-Headers": "*"
+			});
+			res.write("\n");
+			const activatedPaths = new Set<string>();
+			for (const key of keys) {
+				const oldValue = activeModules.get(key) || 0;
+				activeModules.set(key, oldValue + 1);
+				if (oldValue === 0) {
+					logger.log(`${key} is now in use and will be compiled.`);
+					const path = modulePaths.get(key);
+					if (path) activatedPaths.add(path);
+				}
+			}
+			if (activatedPaths.size && compiler.watching) {
+				compiler.watching.invalidate(activatedPaths);
+			}
+		};
+
+		const server = createServer() as Server;
+		server.on("request", requestListener);
+
+		let isClosing = false;
+		const sockets = new Set<Socket>();
+		server.on("connection", socket => {
+			sockets.add(socket);
+			socket.on("close", () => {
+				sockets.delete(socket);
+			});
+			if (isClosing) socket.destroy();
+		});
+		server.on("clientError", e => {
+			if (e.message !== "Server is disposing") logger.warn(e);
+		});
+		server.on("listening", (err?: Error) => {
+			if (err) return callback(err);
+			const addr = server.address() as AddressInfo;
+			if (typeof addr === "string")
+				throw new Error("addr must not be a string");
+			const urlBase =
+				addr.address === "::" || addr.address === "0.0.0.0"
+					? `${protocol}://localhost:${addr.port}`
+					: addr.family === "IPv6"
+						? `${protocol}://[${addr.address}]:${addr.port}`
+						: `${protocol}://${addr.address}:${addr.port}`;
+			logger.log(
+				`Server-Sent-Events server for lazy compilation open at ${urlBase}.`
+			);
+			callback(null, {
+				dispose(callback) {
+					isClosing = true;
+					// Removing the listener is a workaround for a memory leak in node.js
+					server.off("request", requestListener);
+					server.close(err => {
+						callback(err);
+					});
+					for (const socket of sockets) {
+						socket.destroy(new Error("Server is disposing"));
+					}
+				},
+				module({ module, path }) {
+					const key = getKey(module);
+					modulePaths.set(key, path);
+					const active = (activeModules.get(key) || 0) > 0;
+					return {
+						client: `${options.client}?${encodeURIComponent(urlBase + prefix)}`,
+						data: key,
+						active
+					};
+				}
+			});
+		});
+		listen(server);
+	};
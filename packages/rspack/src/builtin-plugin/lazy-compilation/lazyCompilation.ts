@@ -0,0 +1,30 @@
+import {
+	BuiltinPluginName,
+	JsModule,
+	RawLazyCompilationModuleArgs,
+	RawLazyCompilationModuleInfo,
+	RawLazyCompilationOptions
+} from "@rspack/binding";
+import { Module } from "../../Module";
+import { create } from "../base";
+
+export const BuiltinLazyCompilationPlugin = create(
+	BuiltinPluginName.LazyCompilationPlugin,
+	(
+		module: (
+			args: RawLazyCompilationModuleArgs
+		) => RawLazyCompilationModuleInfo,
+		entries: boolean,
+		imports: boolean,
+		test?: RegExp | ((module: Module) => boolean)
+	): RawLazyCompilationOptions => ({
+		module,
+		entries,
+		imports,
+		test:
+			typeof test === "function"
+				? (module: JsModule) => test(Module.__from_binding(module))
+				: test
+	}),
+	"thisCompilation"
+);
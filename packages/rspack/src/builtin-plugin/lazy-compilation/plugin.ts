@@ -0,0 +1,63 @@
+/**
+ * The following code is modified based on
+ * https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/hmr/LazyCompilationPlugin.js
+ *
+ * MIT Licensed
+ * Author Tobias Koppers @sokra
+ * Copyright (c) JS Foundation and other contributors
+ * https://github.com/webpack/webpack/blob/main/LICENSE
+ */
+import type { Compiler } from "../../Compiler";
+import type { Module } from "../../Module";
+import type {
+	LazyCompilationBackend,
+	LazyCompilationBackendOptions
+} from "./backend";
+import { lazyCompilationBackend } from "./backend";
+import { BuiltinLazyCompilationPlugin } from "./lazyCompilation";
+
+export interface LazyCompilationPluginOptions {
+	backend: LazyCompilationBackendOptions;
+	entries: boolean;
+	imports: boolean;
+	test?: RegExp | ((module: Module) => boolean);
+}
+
+export class LazyCompilationPlugin {
+	constructor(private options: LazyCompilationPluginOptions) {}
+
+	apply(compiler: Compiler) {
+		let backend: LazyCompilationBackend | undefined;
+		compiler.hooks.beforeCompile.tapAsync(
+			"LazyCompilationPlugin",
+			(_params, callback) => {
+				if (backend !== undefined) return callback();
+				lazyCompilationBackend(this.options.backend)(
+					compiler,
+					(err, result) => {
+						if (err) return callback(err);
+						backend = result;
+						callback();
+					}
+				);
+			}
+		);
+		compiler.hooks.shutdown.tapAsync("LazyCompilationPlugin", callback => {
+			if (backend === undefined) return callback();
+			backend.dispose(callback);
+		});
+		new BuiltinLazyCompilationPlugin(
+			args => {
+				if (backend === undefined) {
+					throw new Error(
+						"The lazy compilation backend should be started before the compilation"
+					);
+				}
+				return backend.module(args);
+			},
+			this.options.entries,
+			this.options.imports,
+			this.options.test
+		).apply(compiler);
+	}
+}
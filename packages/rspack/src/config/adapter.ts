@@ -10,6 +10,7 @@ import type {
 	RawAssetParserDataUrl,
 	RawAssetInlineGeneratorOptions,
 	RawAssetResourceGeneratorOptions,
+	RawAssetGeneratorDataUrlFnArgs,
 	RawModuleRuleUses,
 	RawFuncUseCtx,
 	RawRspackFuture,
@@ -22,13 +23,16 @@ import type {
 	RawCssGeneratorOptions,
 	RawCssAutoGeneratorOptions,
 	RawCssModuleGeneratorOptions,
-	RawJavascriptParserOptions
+	RawJavascriptParserOptions,
+	RawJsonParserOptions
 } from "@rspack/binding";
 import assert from "assert";
 import { Compiler } from "../Compiler";
+import { Module } from "../Module";
 import { normalizeStatsPreset } from "../Stats";
 import { isNil } from "../util";
 import { parseResource } from "../util/identifier";
+import { createHash } from "../util/createHash";
 import {
 	ComposeJsUseOptions,
 	LoaderContext,
@@ -66,7 +70,9 @@ import {
 	CssModuleParserOptions,
 	CssGeneratorOptions,
 	CssAutoGeneratorOptions,
-	CssModuleGeneratorOptions
+	CssModuleGeneratorOptions,
+	JsonParserOptions,
+	HashFunction
 } from "./zod";
 import {
 	ExperimentsNormalized,
@@ -202,6 +208,23 @@ function getRawCrossOriginLoading(
 	return { type: "string", stringPayload: crossOriginLoading };
 }
 
+const BUILTIN_HASH_FUNCTIONS = ["md4", "xxhash64", "sha256"];
+
+function getRawHashFunction(
+	hashFunction: HashFunction
+): RawOptions["output"]["hashFunction"] {
+	if (
+		typeof hashFunction === "string" &&
+		BUILTIN_HASH_FUNCTIONS.includes(hashFunction)
+	) {
+		return hashFunction;
+	}
+	// other hash functions, e.g. a hash of node's crypto or a custom hash class,
+	// are called from rust with all the data of the hash at once
+	return (data: Buffer) =>
+		Buffer.from(createHash(hashFunction).update(data).digest("hex"), "hex");
+}
+
 function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 	const chunkLoading = output.chunkLoading!;
 	const wasmLoading = output.wasmLoading!;
@@ -217,6 +240,7 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 		chunkFilename: output.chunkFilename!,
 		chunkLoading: getRawChunkLoading(chunkLoading),
 		crossOriginLoading: getRawCrossOriginLoading(output.crossOriginLoading!),
+		charset: output.charset!,
 		cssFilename: output.cssFilename!,
 		cssChunkFilename: output.cssChunkFilename!,
 		hotUpdateChunkFilename: output.hotUpdateChunkFilename!,
@@ -236,8 +260,14 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 		enabledChunkLoadingTypes: output.enabledChunkLoadingTypes!,
 		webassemblyModuleFilename: output.webassemblyModuleFilename!,
 		trustedTypes: output.trustedTypes!,
+		chunkLoadTimeout: output.chunkLoadTimeout!,
+		chunkLoadRetry: output.chunkLoadRetry && {
+			times: output.chunkLoadRetry.times!,
+			delay: output.chunkLoadRetry.delay!,
+			publicPaths: output.chunkLoadRetry.publicPaths!
+		},
 		sourceMapFilename: output.sourceMapFilename!,
-		hashFunction: output.hashFunction!,
+		hashFunction: getRawHashFunction(output.hashFunction!),
 		hashDigest: output.hashDigest!,
 		hashDigestLength: output.hashDigestLength!,
 		hashSalt: output.hashSalt!,
@@ -247,7 +277,8 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 		workerWasmLoading:
 			workerWasmLoading === false ? "false" : workerWasmLoading,
 		workerPublicPath: output.workerPublicPath!,
-		scriptType: output.scriptType === false ? "false" : output.scriptType!
+		scriptType: output.scriptType === false ? "false" : output.scriptType!,
+		environment: output.environment!
 	};
 }
 
@@ -611,6 +642,11 @@ function getRawParserOptions(
 			type: "css/module",
 			cssModule: getRawCssParserOptions(parser)
 		};
+	} else if (type === "json") {
+		return {
+			type: "json",
+			json: getRawJsonParserOptions(parser)
+		};
 	}
 	// FIXME: shouldn't depend on module type, for example: `rules: [{ test: /\.css/, generator: {..} }]` will error
 	throw new Error(`unreachable: unknow module type: ${type}`);
@@ -630,7 +666,9 @@ function getRawJavascriptParserOptions(
 					? parser.url
 					: "true",
 		exprContextCritical: parser.exprContextCritical ?? true,
-		wrappedContextCritical: parser.wrappedContextCritical ?? false
+		wrappedContextCritical: parser.wrappedContextCritical ?? false,
+		importMetaUrl: parser.importMetaUrl ?? "resolved",
+		importMetaEnv: parser.importMetaEnv ?? "undefined"
 	};
 }
 
@@ -668,6 +706,18 @@ function getRawCssParserOptions(
 	};
 }
 
+function getRawJsonParserOptions(
+	parser: JsonParserOptions = {}
+): RawJsonParserOptions {
+	const { parse } = parser;
+	return {
+		exportsDepth: parser.exportsDepth,
+		parse: parse
+			? (source: string) => JSON.stringify(parse(source))
+			: undefined
+	};
+}
+
 function getRawGeneratorOptions(
 	generator: { [k: string]: any },
 	type: string
@@ -740,7 +790,15 @@ function getRawAssetResourceGeneratorOptions(
 	return {
 		emit: options.emit,
 		filename: options.filename,
-		publicPath: options.publicPath
+		outputPath: options.outputPath,
+		publicPath: options.publicPath,
+		responsive: options.responsive
+			? {
+					sizes: options.responsive.sizes,
+					formats: options.responsive.formats ?? [],
+					quality: options.responsive.quality ?? 80
+				}
+			: undefined
 	};
 }
 
@@ -753,7 +811,8 @@ function getRawAssetGeneratorDataUrl(dataUrl: AssetGeneratorDataUrl) {
 		} as const;
 	}
 	if (typeof dataUrl === "function" && dataUrl !== null) {
-		return dataUrl;
+		return ({ content, filename, module }: RawAssetGeneratorDataUrlFnArgs) =>
+			dataUrl(content, { filename, module: Module.__from_binding(module) });
 	}
 	throw new Error(
 		`unreachable: AssetGeneratorDataUrl type should be one of "options", "function", but got ${dataUrl}`
@@ -830,7 +889,8 @@ function getRawSnapshotOptions(
 function getRawExperiments(
 	experiments: ExperimentsNormalized
 ): RawOptions["experiments"] {
-	const { newSplitChunks, topLevelAwait, rspackFuture } = experiments;
+	const { newSplitChunks, topLevelAwait, pathIndependent, rspackFuture } =
+		experiments;
 	assert(
 		!isNil(newSplitChunks) && !isNil(topLevelAwait) && !isNil(rspackFuture)
 	);
@@ -838,6 +898,7 @@ function getRawExperiments(
 	return {
 		newSplitChunks,
 		topLevelAwait,
+		pathIndependent,
 		rspackFuture: getRawRspackFutureOptions(rspackFuture)
 	};
 }
@@ -846,8 +907,10 @@ function getRawRspackFutureOptions(
 	future: RspackFutureOptions
 ): RawRspackFuture {
 	assert(!isNil(future.newTreeshaking));
+	assert(!isNil(future.inlineEntryModules));
 	return {
-		newTreeshaking: future.newTreeshaking
+		newTreeshaking: future.newTreeshaking,
+		inlineEntryModules: future.inlineEntryModules
 	};
 }
 
@@ -7,6 +7,7 @@ import type {
 import { ResolveRequest } from "enhanced-resolve";
 
 import { Compiler } from "../Compiler";
+import { BuildInfo, BuildMeta } from "../Module";
 import { Logger } from "../logging/Logger";
 import Hash = require("../util/hash");
 import { Mode, Resolve, RuleSetUseItem, RuleSetLoaderWithOptions } from "./zod";
@@ -155,6 +156,14 @@ export interface LoaderContext<OptionsType = {}> {
 	data: unknown;
 	_compiler: Compiler;
 	_compilation: Compiler["compilation"];
+	/**
+	 * The module being built, only `buildInfo` and `buildMeta` are available,
+	 * the changes are synced to the module once the loaders are done.
+	 */
+	_module: {
+		buildInfo: BuildInfo;
+		buildMeta: BuildMeta;
+	};
 	/**
 	 * Internal field for interoperability.
 	 * Do not use this in anywhere else.
@@ -77,6 +77,8 @@ export const applyRspackOptionsDefaults = (
 	D(options, "watch", false);
 	D(options, "profile", false);
 	D(options, "bail", false);
+	D(options, "recordsInputPath", false);
+	D(options, "recordsOutputPath", false);
 
 	const futureDefaults = options.experiments.futureDefaults ?? false;
 	F(options, "cache", () => development);
@@ -89,6 +91,7 @@ export const applyRspackOptionsDefaults = (
 
 	applyModuleDefaults(options.module, {
 		asyncWebAssembly: options.experiments.asyncWebAssembly!,
+		syncWebAssembly: options.experiments.syncWebAssembly!,
 		css: options.experiments.css!,
 		targetProperties
 	});
@@ -179,7 +182,9 @@ const applyExperimentsDefaults = (
 	{ cache }: { cache: boolean }
 ) => {
 	D(experiments, "lazyCompilation", false);
+	D(experiments, "emitToMemory", false);
 	D(experiments, "asyncWebAssembly", false);
+	D(experiments, "syncWebAssembly", false);
 	D(experiments, "newSplitChunks", true);
 	D(experiments, "css", true); // we not align with webpack about the default value for better DX
 	D(experiments, "topLevelAwait", true);
@@ -187,6 +192,7 @@ const applyExperimentsDefaults = (
 	D(experiments, "rspackFuture", {});
 	if (typeof experiments.rspackFuture === "object") {
 		D(experiments.rspackFuture, "newTreeshaking", true);
+		D(experiments.rspackFuture, "inlineEntryModules", false);
 		D(experiments.rspackFuture, "bundlerInfo", {});
 		if (typeof experiments.rspackFuture.bundlerInfo === "object") {
 			D(
@@ -251,16 +257,20 @@ const applyJavascriptParserOptionsDefaults = (
 		"wrappedContextCritical",
 		fallback?.wrappedContextCritical ?? false
 	);
+	D(parserOptions, "importMetaUrl", fallback?.importMetaUrl ?? "resolved");
+	D(parserOptions, "importMetaEnv", fallback?.importMetaEnv ?? "undefined");
 };
 
 const applyModuleDefaults = (
 	module: ModuleOptions,
 	{
 		asyncWebAssembly,
+		syncWebAssembly,
 		css,
 		targetProperties
 	}: {
 		asyncWebAssembly: boolean;
+		syncWebAssembly: boolean;
 		css: boolean;
 		targetProperties: any;
 	}
@@ -430,6 +440,28 @@ const applyModuleDefaults = (
 				mimetype: "application/wasm",
 				...wasm
 			});
+		} else if (syncWebAssembly) {
+			const wasm = {
+				type: "webassembly/sync",
+				rules: [
+					{
+						descriptionData: {
+							type: "module"
+						},
+						resolve: {
+							fullySpecified: true
+						}
+					}
+				]
+			};
+			rules.push({
+				test: /\.wasm$/i,
+				...wasm
+			});
+			rules.push({
+				mimetype: "application/wasm",
+				...wasm
+			});
 		}
 
 		if (css) {
@@ -698,12 +730,41 @@ const applyOutputDefaults = (
 	D(output, "importFunctionName", "import");
 	F(output, "clean", () => !!output.clean);
 	D(output, "crossOriginLoading", false);
+	D(output, "charset", true);
+	D(output, "chunkLoadTimeout", 120000);
 	D(output, "workerPublicPath", "");
 	F(output, "sourceMapFilename", () => {
 		return "[file].map";
 	});
 	F(output, "scriptType", () => (output.module ? "module" : false));
 
+	const environment = output.environment!;
+	const optimistic = (v?: boolean) => v || v === undefined;
+	const conditionallyOptimistic = (v?: boolean, c?: boolean) =>
+		(v === undefined && c) || v;
+	F(environment, "globalThis", () => tp && tp.globalThis);
+	F(environment, "bigIntLiteral", () => tp && tp.bigIntLiteral);
+	F(environment, "const", () => tp && optimistic(tp.const));
+	F(environment, "arrowFunction", () => tp && optimistic(tp.arrowFunction));
+	F(environment, "forOf", () => tp && optimistic(tp.forOf));
+	F(environment, "destructuring", () => tp && optimistic(tp.destructuring));
+	F(
+		environment,
+		"optionalChaining",
+		() => tp && optimistic(tp.optionalChaining)
+	);
+	F(
+		environment,
+		"templateLiteral",
+		() => tp && optimistic(tp.templateLiteral)
+	);
+	F(environment, "dynamicImport", () =>
+		conditionallyOptimistic(tp && tp.dynamicImport, output.module)
+	);
+	F(environment, "module", () =>
+		conditionallyOptimistic(tp && tp.module, output.module)
+	);
+
 	const { trustedTypes } = output;
 	if (trustedTypes) {
 		F(
@@ -714,6 +775,13 @@ const applyOutputDefaults = (
 		);
 	}
 
+	const { chunkLoadRetry } = output;
+	if (chunkLoadRetry) {
+		D(chunkLoadRetry, "times", 3);
+		D(chunkLoadRetry, "delay", 1000);
+		D(chunkLoadRetry, "publicPaths", []);
+	}
+
 	const forEachEntry = (fn: (desc: EntryDescriptionNormalized) => void) => {
 		if (typeof entry === "function") {
 			return;
@@ -798,6 +866,8 @@ const applyExternalsPresetsDefaults = (
 			targetProperties.electron &&
 			targetProperties.electronRenderer
 	);
+	D(externalsPresets, "nwjs", targetProperties && targetProperties.nwjs);
+	D(externalsPresets, "nodeModules", false);
 };
 
 const applyNodeDefaults = (
@@ -37,6 +37,9 @@ import type {
 	DevServer,
 	Profile,
 	Bail,
+	RecordsPath,
+	RecordsInputPath,
+	RecordsOutputPath,
 	Builtins,
 	EntryRuntime,
 	ChunkLoading,
@@ -47,6 +50,7 @@ import type {
 	Filename,
 	ChunkFilename,
 	CrossOriginLoading,
+	Charset,
 	CssFilename,
 	CssChunkFilename,
 	HotUpdateMainFilename,
@@ -64,6 +68,9 @@ import type {
 	EnabledWasmLoadingTypes,
 	WebassemblyModuleFilename,
 	TrustedTypes,
+	ChunkLoadTimeout,
+	ChunkLoadRetry,
+	Environment,
 	SourceMapFilename,
 	HashDigest,
 	HashDigestLength,
@@ -74,6 +81,7 @@ import type {
 	ParserOptionsByModuleType,
 	GeneratorOptionsByModuleType,
 	RspackFutureOptions,
+	LazyCompilationOptions,
 	HotUpdateGlobal,
 	ScriptType,
 	NoParseOption,
@@ -136,6 +144,7 @@ export const getNormalizedRspackOptions = (
 				chunkLoading: output.chunkLoading,
 				chunkFilename: output.chunkFilename,
 				crossOriginLoading: output.crossOriginLoading,
+				charset: output.charset,
 				cssFilename: output.cssFilename,
 				cssChunkFilename: output.cssChunkFilename,
 				hotUpdateMainFilename: output.hotUpdateMainFilename,
@@ -195,6 +204,14 @@ export const getNormalizedRspackOptions = (
 						return { ...trustedTypes };
 					}
 				),
+				chunkLoadTimeout: output.chunkLoadTimeout,
+				chunkLoadRetry: optionalNestedConfig(
+					output.chunkLoadRetry,
+					chunkLoadRetry => {
+						if (chunkLoadRetry === true) return {};
+						return { ...chunkLoadRetry };
+					}
+				),
 				hashDigest: output.hashDigest,
 				hashDigestLength: output.hashDigestLength,
 				hashFunction: output.hashFunction,
@@ -204,6 +221,7 @@ export const getNormalizedRspackOptions = (
 				workerWasmLoading: output.workerWasmLoading,
 				workerPublicPath: output.workerPublicPath,
 				scriptType: output.scriptType,
+				environment: cloneObject(output.environment),
 				devtoolNamespace: output.devtoolNamespace,
 				devtoolModuleFilenameTemplate: output.devtoolModuleFilenameTemplate,
 				devtoolFallbackModuleFilenameTemplate:
@@ -297,13 +315,20 @@ export const getNormalizedRspackOptions = (
 		performance: config.performance,
 		plugins: nestedArray(config.plugins, p => [...p]),
 		experiments: nestedConfig(config.experiments, experiments => ({
-			...experiments
+			...experiments,
+			lazyCompilation: optionalNestedConfig(
+				experiments.lazyCompilation,
+				options => (options === true ? {} : options)
+			)
 		})),
 		watch: config.watch,
 		watchOptions: cloneObject(config.watchOptions),
 		devServer: config.devServer,
 		profile: config.profile,
 		bail: config.bail,
+		recordsInputPath: config.recordsInputPath ?? config.recordsPath,
+		recordsOutputPath: config.recordsOutputPath ?? config.recordsPath,
+		recordsPath: config.recordsPath,
 		builtins: nestedConfig(config.builtins, builtins => ({
 			...builtins
 		}))
@@ -452,6 +477,7 @@ export interface OutputNormalized {
 	filename?: Filename;
 	chunkFilename?: ChunkFilename;
 	crossOriginLoading?: CrossOriginLoading;
+	charset?: Charset;
 	cssFilename?: CssFilename;
 	cssChunkFilename?: CssChunkFilename;
 	hotUpdateMainFilename?: HotUpdateMainFilename;
@@ -474,6 +500,8 @@ export interface OutputNormalized {
 	chunkLoading?: string | false;
 	enabledChunkLoadingTypes?: string[];
 	trustedTypes?: TrustedTypes;
+	chunkLoadTimeout?: ChunkLoadTimeout;
+	chunkLoadRetry?: ChunkLoadRetry;
 	sourceMapFilename?: SourceMapFilename;
 	hashDigest?: HashDigest;
 	hashDigestLength?: HashDigestLength;
@@ -484,6 +512,7 @@ export interface OutputNormalized {
 	workerWasmLoading?: WasmLoading;
 	workerPublicPath?: WorkerPublicPath;
 	scriptType?: ScriptType;
+	environment?: Environment;
 	devtoolNamespace?: DevtoolNamespace;
 	devtoolModuleFilenameTemplate?: DevtoolModuleFilenameTemplate;
 	devtoolFallbackModuleFilenameTemplate?: DevtoolFallbackModuleFilenameTemplate;
@@ -498,13 +527,16 @@ export interface ModuleOptionsNormalized {
 }
 
 export interface ExperimentsNormalized {
-	lazyCompilation?: boolean;
+	lazyCompilation?: false | LazyCompilationOptions;
+	emitToMemory?: boolean;
 	asyncWebAssembly?: boolean;
+	syncWebAssembly?: boolean;
 	outputModule?: boolean;
 	newSplitChunks?: boolean;
 	topLevelAwait?: boolean;
 	css?: boolean;
 	futureDefaults?: boolean;
+	pathIndependent?: boolean;
 	rspackFuture?: RspackFutureOptions;
 }
 
@@ -549,5 +581,8 @@ export interface RspackOptionsNormalized {
 	performance?: Performance;
 	profile?: Profile;
 	bail?: Bail;
+	recordsInputPath?: RecordsInputPath;
+	recordsOutputPath?: RecordsOutputPath;
+	recordsPath?: RecordsPath;
 	builtins: Builtins;
 }
@@ -7,6 +7,10 @@ import { deprecatedWarn } from "../util";
 import { Module } from "../Module";
 import { Chunk } from "../Chunk";
 import { PathData } from "../Compilation";
+import type { ListenOptions, Server } from "net";
+import type { ServerOptions as HttpServerOptions } from "http";
+import type { ServerOptions as HttpsServerOptions } from "https";
+import Hash = require("../util/hash");
 
 //#region Name
 const name = z.string();
@@ -201,7 +205,7 @@ export type Path = z.infer<typeof path>;
 const pathinfo = z.boolean().or(z.literal("verbose"));
 export type Pathinfo = z.infer<typeof pathinfo>;
 
-const assetModuleFilename = z.string();
+const assetModuleFilename = filename;
 export type AssetModuleFilename = z.infer<typeof assetModuleFilename>;
 
 const webassemblyModuleFilename = z.string();
@@ -217,6 +221,9 @@ const crossOriginLoading = z
 	.or(z.enum(["anonymous", "use-credentials"]));
 export type CrossOriginLoading = z.infer<typeof crossOriginLoading>;
 
+const charset = z.boolean();
+export type Charset = z.infer<typeof charset>;
+
 const cssFilename = filename;
 export type CssFilename = z.infer<typeof cssFilename>;
 
@@ -283,13 +290,40 @@ const trustedTypes = z.strictObject({
 });
 export type TrustedTypes = z.infer<typeof trustedTypes>;
 
-const hashDigest = z.string();
+const environment = z.strictObject({
+	arrowFunction: z.boolean().optional(),
+	bigIntLiteral: z.boolean().optional(),
+	const: z.boolean().optional(),
+	destructuring: z.boolean().optional(),
+	dynamicImport: z.boolean().optional(),
+	forOf: z.boolean().optional(),
+	globalThis: z.boolean().optional(),
+	module: z.boolean().optional(),
+	optionalChaining: z.boolean().optional(),
+	templateLiteral: z.boolean().optional()
+});
+export type Environment = z.infer<typeof environment>;
+
+const chunkLoadTimeout = z.number();
+export type ChunkLoadTimeout = z.infer<typeof chunkLoadTimeout>;
+
+const chunkLoadRetry = z.strictObject({
+	times: z.number().int().nonnegative().optional(),
+	delay: z.number().nonnegative().optional(),
+	publicPaths: z.array(z.string()).optional()
+});
+export type ChunkLoadRetry = z.infer<typeof chunkLoadRetry>;
+
+const hashDigest = z.enum(["hex", "base64", "base64url"]);
 export type HashDigest = z.infer<typeof hashDigest>;
 
 const hashDigestLength = z.number();
 export type HashDigestLength = z.infer<typeof hashDigestLength>;
 
-const hashFunction = z.enum(["md4", "xxhash64"]);
+const hashFunction = z
+	.enum(["md4", "xxhash64", "sha256"])
+	.or(z.string())
+	.or(z.custom<typeof Hash>(value => typeof value === "function"));
 export type HashFunction = z.infer<typeof hashFunction>;
 
 const hashSalt = z.string();
@@ -322,6 +356,7 @@ const output = z.strictObject({
 	filename: filename.optional(),
 	chunkFilename: chunkFilename.optional(),
 	crossOriginLoading: crossOriginLoading.optional(),
+	charset: charset.optional(),
 	cssFilename: cssFilename.optional(),
 	cssChunkFilename: cssChunkFilename.optional(),
 	hotUpdateMainFilename: hotUpdateMainFilename.optional(),
@@ -350,6 +385,8 @@ const output = z.strictObject({
 	chunkLoading: chunkLoading.optional(),
 	enabledChunkLoadingTypes: enabledChunkLoadingTypes.optional(),
 	trustedTypes: z.literal(true).or(z.string()).or(trustedTypes).optional(),
+	chunkLoadTimeout: chunkLoadTimeout.optional(),
+	chunkLoadRetry: z.literal(true).or(chunkLoadRetry).optional(),
 	sourceMapFilename: sourceMapFilename.optional(),
 	hashDigest: hashDigest.optional(),
 	hashDigestLength: hashDigestLength.optional(),
@@ -360,6 +397,7 @@ const output = z.strictObject({
 	workerWasmLoading: wasmLoading.optional(),
 	workerPublicPath: workerPublicPath.optional(),
 	scriptType: scriptType.optional(),
+	environment: environment.optional(),
 	devtoolNamespace: devtoolNamespace.optional(),
 	devtoolModuleFilenameTemplate: devtoolModuleFilenameTemplate.optional(),
 	devtoolFallbackModuleFilenameTemplate:
@@ -560,6 +598,12 @@ const cssModuleParserOptions = z.strictObject({
 });
 export type CssModuleParserOptions = z.infer<typeof cssModuleParserOptions>;
 
+const jsonParserOptions = z.strictObject({
+	exportsDepth: z.number().optional(),
+	parse: z.function().args(z.string()).returns(z.any()).optional()
+});
+export type JsonParserOptions = z.infer<typeof jsonParserOptions>;
+
 //TODO: "weak", "lazy-once"
 const dynamicImportMode = z.enum(["eager", "lazy"]);
 const dynamicImportPreload = z.union([z.boolean(), z.number()]);
@@ -567,6 +611,8 @@ const dynamicImportPrefetch = z.union([z.boolean(), z.number()]);
 const javascriptParserUrl = z.union([z.literal("relative"), z.boolean()]);
 const exprContextCritical = z.boolean();
 const wrappedContextCritical = z.boolean();
+const importMetaUrl = z.enum(["resolved", "runtime"]);
+const importMetaEnv = z.enum(["undefined", "define"]);
 
 const javascriptParserOptions = z.strictObject({
 	dynamicImportMode: dynamicImportMode.optional(),
@@ -574,7 +620,9 @@ const javascriptParserOptions = z.strictObject({
 	dynamicImportPrefetch: dynamicImportPrefetch.optional(),
 	url: javascriptParserUrl.optional(),
 	exprContextCritical: exprContextCritical.optional(),
-	wrappedContextCritical: wrappedContextCritical.optional()
+	wrappedContextCritical: wrappedContextCritical.optional(),
+	importMetaUrl: importMetaUrl.optional(),
+	importMetaEnv: importMetaEnv.optional()
 });
 export type JavascriptParserOptions = z.infer<typeof javascriptParserOptions>;
 
@@ -586,7 +634,8 @@ const parserOptionsByModuleTypeKnown = z.strictObject({
 	javascript: javascriptParserOptions.optional(),
 	"javascript/auto": javascriptParserOptions.optional(),
 	"javascript/dynamic": javascriptParserOptions.optional(),
-	"javascript/esm": javascriptParserOptions.optional()
+	"javascript/esm": javascriptParserOptions.optional(),
+	json: jsonParserOptions.optional()
 });
 
 export type ParserOptionsByModuleTypeKnown = z.infer<
@@ -616,9 +665,10 @@ export type AssetGeneratorDataUrlOptions = z.infer<
 const assetGeneratorDataUrlFunction = z
 	.function()
 	.args(
+		z.string(),
 		z.strictObject({
-			content: z.string(),
-			filename: z.string()
+			filename: z.string(),
+			module: z.instanceof(Module)
 		})
 	)
 	.returns(z.string());
@@ -638,10 +688,21 @@ export type AssetInlineGeneratorOptions = z.infer<
 	typeof assetInlineGeneratorOptions
 >;
 
+const assetGeneratorResponsiveOptions = z.strictObject({
+	sizes: z.array(z.number().int().positive()),
+	formats: z.array(z.enum(["png", "jpeg", "webp"])).optional(),
+	quality: z.number().int().min(1).max(100).optional()
+});
+export type AssetGeneratorResponsiveOptions = z.infer<
+	typeof assetGeneratorResponsiveOptions
+>;
+
 const assetResourceGeneratorOptions = z.strictObject({
 	emit: z.boolean().optional(),
-	filename: filenameTemplate.optional(),
-	publicPath: publicPath.optional()
+	filename: filename.optional(),
+	outputPath: filenameTemplate.optional(),
+	publicPath: publicPath.optional(),
+	responsive: assetGeneratorResponsiveOptions.optional()
 });
 export type AssetResourceGeneratorOptions = z.infer<
 	typeof assetResourceGeneratorOptions
@@ -856,7 +917,30 @@ export type ExternalItemObjectUnknown = z.infer<
 const externalItemFunctionData = z.strictObject({
 	context: z.string().optional(),
 	dependencyType: z.string().optional(),
-	request: z.string().optional()
+	request: z.string().optional(),
+	contextInfo: z
+		.strictObject({
+			issuer: z.string()
+		})
+		.optional(),
+	getResolve: z
+		.function()
+		.args(resolveOptions.optional())
+		.returns(
+			z
+				.function()
+				.args(
+					z.string(),
+					z.string(),
+					z
+						.function()
+						.args(z.instanceof(Error).nullish(), z.string().optional())
+						.returns(z.void())
+						.optional()
+				)
+				.returns(z.promise(z.string()).or(z.void()))
+		)
+		.optional()
 });
 export type ExternalItemFunctionData = z.infer<typeof externalItemFunctionData>;
 
@@ -899,7 +983,17 @@ const externalsPresets = z.strictObject({
 	electron: z.boolean().optional(),
 	electronMain: z.boolean().optional(),
 	electronPreload: z.boolean().optional(),
-	electronRenderer: z.boolean().optional()
+	electronRenderer: z.boolean().optional(),
+	nwjs: z.boolean().optional(),
+	nodeModules: z
+		.boolean()
+		.or(
+			z.strictObject({
+				allowlist: z.string().or(z.instanceof(RegExp)).array().optional(),
+				importType: externalsType.optional()
+			})
+		)
+		.optional()
 });
 export type ExternalsPresets = z.infer<typeof externalsPresets>;
 //#endregion
@@ -1002,11 +1096,20 @@ export type CacheOptions = z.infer<typeof cacheOptions>;
 //#endregion
 
 //#region Stats
+const statsPresets = z.enum([
+	"normal",
+	"none",
+	"verbose",
+	"detailed",
+	"minimal",
+	"summary",
+	"errors-only",
+	"errors-warnings"
+]);
+
 const statsOptions = z.strictObject({
 	all: z.boolean().optional(),
-	preset: z
-		.enum(["normal", "none", "verbose", "errors-only", "errors-warnings"])
-		.optional(),
+	preset: statsPresets.or(z.string()).optional(),
 	assets: z.boolean().optional(),
 	chunks: z.boolean().optional(),
 	modules: z.boolean().optional(),
@@ -1020,10 +1123,12 @@ const statsOptions = z.strictObject({
 	hash: z.boolean().optional(),
 	version: z.boolean().optional(),
 	reasons: z.boolean().optional(),
+	reasonsSpace: z.number().optional(),
 	publicPath: z.boolean().optional(),
 	outputPath: z.boolean().optional(),
 	chunkModules: z.boolean().optional(),
 	chunkRelations: z.boolean().optional(),
+	chunkOrigins: z.boolean().optional(),
 	ids: z.boolean().optional(),
 	timings: z.boolean().optional(),
 	builtAt: z.boolean().optional(),
@@ -1031,6 +1136,7 @@ const statsOptions = z.strictObject({
 	modulesSpace: z.number().optional(),
 	nestedModules: z.boolean().optional(),
 	source: z.boolean().optional(),
+	depth: z.boolean().optional(),
 	logging: z
 		.enum(["none", "error", "warn", "info", "log", "verbose"])
 		.or(z.boolean())
@@ -1046,8 +1152,8 @@ const statsOptions = z.strictObject({
 });
 export type StatsOptions = z.infer<typeof statsOptions>;
 
-const statsValue = z
-	.enum(["none", "errors-only", "errors-warnings", "normal", "verbose"])
+const statsValue = statsPresets
+	.or(z.string())
 	.or(z.boolean())
 	.or(statsOptions);
 export type StatsValue = z.infer<typeof statsValue>;
@@ -1164,6 +1270,14 @@ export type OptimizationSplitChunksOptions = z.infer<
 	typeof optimizationSplitChunksOptions
 >;
 
+const optimizationConcatenateModulesOptions = z.strictObject({
+	maxSize: z.number().optional(),
+	allowCjsLeaves: z.boolean().optional()
+});
+export type OptimizationConcatenateModulesOptions = z.infer<
+	typeof optimizationConcatenateModulesOptions
+>;
+
 const optimization = z.strictObject({
 	moduleIds: z.enum(["named", "deterministic"]).optional(),
 	chunkIds: z.enum(["named", "deterministic"]).optional(),
@@ -1177,11 +1291,15 @@ const optimization = z.strictObject({
 	realContentHash: z.boolean().optional(),
 	sideEffects: z.enum(["flag"]).or(z.boolean()).optional(),
 	providedExports: z.boolean().optional(),
-	concatenateModules: z.boolean().optional(),
+	concatenateModules: z
+		.boolean()
+		.or(optimizationConcatenateModulesOptions)
+		.optional(),
 	innerGraph: z.boolean().optional(),
 	usedExports: z.enum(["global"]).or(z.boolean()).optional(),
 	mangleExports: z.enum(["size", "deterministic"]).or(z.boolean()).optional(),
-	nodeEnv: z.union([z.string(), z.literal(false)]).optional()
+	nodeEnv: z.union([z.string(), z.literal(false)]).optional(),
+	emitOnErrors: z.boolean().optional()
 });
 export type Optimization = z.infer<typeof optimization>;
 //#endregion
@@ -1189,6 +1307,7 @@ export type Optimization = z.infer<typeof optimization>;
 //#region Experiments
 const rspackFutureOptions = z.strictObject({
 	newTreeshaking: z.boolean().optional(),
+	inlineEntryModules: z.boolean().optional(),
 	bundlerInfo: z
 		.strictObject({
 			version: z.string().optional(),
@@ -1201,9 +1320,37 @@ const rspackFutureOptions = z.strictObject({
 });
 export type RspackFutureOptions = z.infer<typeof rspackFutureOptions>;
 
+const lazyCompilationListen = z
+	.number()
+	.or(z.custom<ListenOptions>())
+	.or(z.function().args(z.custom<Server>()).returns(z.void()));
+
+const lazyCompilationOptions = z.strictObject({
+	backend: z
+		.strictObject({
+			client: z.string().optional(),
+			listen: lazyCompilationListen.optional(),
+			protocol: z.enum(["http", "https"]).optional(),
+			server: z
+				.custom<HttpsServerOptions | HttpServerOptions>()
+				.or(z.function().returns(z.custom<Server>()))
+				.optional()
+		})
+		.optional(),
+	entries: z.boolean().optional(),
+	imports: z.boolean().optional(),
+	test: z
+		.instanceof(RegExp)
+		.or(z.function().args(z.instanceof(Module)).returns(z.boolean()))
+		.optional()
+});
+export type LazyCompilationOptions = z.infer<typeof lazyCompilationOptions>;
+
 const experiments = z.strictObject({
-	lazyCompilation: z.boolean().optional(),
+	lazyCompilation: z.boolean().or(lazyCompilationOptions).optional(),
+	emitToMemory: z.boolean().optional(),
 	asyncWebAssembly: z.boolean().optional(),
+	syncWebAssembly: z.boolean().optional(),
 	outputModule: z.boolean().optional(),
 	topLevelAwait: z.boolean().optional(),
 	newSplitChunks: z
@@ -1222,6 +1369,7 @@ const experiments = z.strictObject({
 		}),
 	css: z.boolean().optional(),
 	futureDefaults: z.boolean().optional(),
+	pathIndependent: z.boolean().optional(),
 	rspackFuture: rspackFutureOptions.optional()
 });
 export type Experiments = z.infer<typeof experiments>;
@@ -1276,6 +1424,17 @@ const bail = z.boolean();
 export type Bail = z.infer<typeof bail>;
 //#endregion
 
+//#region Records
+const recordsPath = z.literal(false).or(z.string());
+export type RecordsPath = z.infer<typeof recordsPath>;
+
+const recordsInputPath = z.literal(false).or(z.string());
+export type RecordsInputPath = z.infer<typeof recordsInputPath>;
+
+const recordsOutputPath = z.literal(false).or(z.string());
+export type RecordsOutputPath = z.infer<typeof recordsOutputPath>;
+//#endregion
+
 //#region Performance
 const performance = z
 	.strictObject({
@@ -1323,6 +1482,9 @@ export const rspackOptions = z.strictObject({
 	module: moduleOptions.optional(),
 	profile: profile.optional(),
 	bail: bail.optional(),
+	recordsPath: recordsPath.optional(),
+	recordsInputPath: recordsInputPath.optional(),
+	recordsOutputPath: recordsOutputPath.optional(),
 	performance: performance.optional()
 });
 export type RspackOptions = z.infer<typeof rspackOptions>;
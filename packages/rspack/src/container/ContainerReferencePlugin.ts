@@ -28,7 +28,13 @@ export type RemotesObject = {
 export type RemotesConfig = {
 	external: RemotesItem | RemotesItems;
 	shareScope?: string;
+	fallback?: RemotesFallback;
 };
+/**
+ * A module used in place of every module of the remote, or a map from the
+ * exposed module requests of the remote (e.g. `./Button`) to their fallbacks.
+ */
+export type RemotesFallback = string | Record<string, string>;
 
 export class ContainerReferencePlugin extends RspackBuiltinPlugin {
 	name = BuiltinPluginName.ContainerReferencePlugin;
@@ -42,13 +48,15 @@ export class ContainerReferencePlugin extends RspackBuiltinPlugin {
 				options.remotes,
 				item => ({
 					external: Array.isArray(item) ? item : [item],
-					shareScope: options.shareScope || "default"
+					shareScope: options.shareScope || "default",
+					fallback: undefined
 				}),
 				item => ({
 					external: Array.isArray(item.external)
 						? item.external
 						: [item.external],
-					shareScope: item.shareScope || options.shareScope || "default"
+					shareScope: item.shareScope || options.shareScope || "default",
+					fallback: item.fallback
 				})
 			),
 			enhanced: options.enhanced ?? false
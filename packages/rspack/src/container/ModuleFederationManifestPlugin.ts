@@ -0,0 +1,12 @@
+import {
+	BuiltinPluginName,
+	RawModuleFederationManifestPluginOptions
+} from "@rspack/binding";
+import { create } from "../builtin-plugin/base";
+
+export const ModuleFederationManifestPlugin = create(
+	BuiltinPluginName.ModuleFederationManifestPlugin,
+	(
+		options: RawModuleFederationManifestPluginOptions
+	): RawModuleFederationManifestPluginOptions => options
+);
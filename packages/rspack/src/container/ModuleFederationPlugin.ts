@@ -1,23 +1,38 @@
+import { RawModuleFederationManifestPluginOptions } from "@rspack/binding";
 import { ExternalsType, externalsType } from "../config";
 import { Compiler } from "../Compiler";
-import { type ModuleFederationPluginV1Options } from "./ModuleFederationPluginV1";
+import {
+	type ModuleFederationPluginV1Options,
+	applyShareScopeSalt
+} from "./ModuleFederationPluginV1";
 import { parseOptions } from "./options";
 import { isValidate } from "../util/validate";
 import { ModuleFederationRuntimePlugin } from "./ModuleFederationRuntimePlugin";
+import { ModuleFederationManifestPlugin } from "./ModuleFederationManifestPlugin";
+import {
+	DtsOptions,
+	ModuleFederationTypesPlugin
+} from "./ModuleFederationTypesPlugin";
 
 export interface ModuleFederationPluginOptions
 	extends Omit<ModuleFederationPluginV1Options, "enhanced"> {
 	runtimePlugins?: RuntimePlugins;
 	implementation?: string;
+	manifest?: boolean | ManifestOptions;
+	dts?: boolean | DtsOptions;
+}
+export type RuntimePlugins = (string | [string, Record<string, unknown>])[];
+export interface ManifestOptions {
+	fileName?: string;
 }
-export type RuntimePlugins = string[];
 
 export class ModuleFederationPlugin {
 	constructor(private _options: ModuleFederationPluginOptions) {}
 
 	apply(compiler: Compiler) {
 		const { webpack } = compiler;
-		const paths = getPaths(this._options);
+		const options = applyShareScopeSalt(this._options);
+		const paths = getPaths(options);
 		compiler.options.resolve.alias = {
 			"@module-federation/runtime-tools": paths.runtimeTools,
 			"@module-federation/runtime": paths.runtime,
@@ -26,15 +41,25 @@ export class ModuleFederationPlugin {
 		compiler.hooks.afterPlugins.tap(ModuleFederationPlugin.name, () => {
 			new webpack.EntryPlugin(
 				compiler.context,
-				getDefaultEntryRuntime(paths, this._options, compiler),
+				getDefaultEntryRuntime(paths, options, compiler),
 				{ name: undefined }
 			).apply(compiler);
 		});
 		new ModuleFederationRuntimePlugin().apply(compiler);
 		new webpack.container.ModuleFederationPluginV1({
-			...this._options,
+			...options,
 			enhanced: true
 		}).apply(compiler);
+		if (options.manifest) {
+			new ModuleFederationManifestPlugin(
+				getManifestOptions(options)
+			).apply(compiler);
+		}
+		if (options.dts) {
+			new ModuleFederationTypesPlugin(getTypesOptions(options)).apply(
+				compiler
+			);
+		}
 	}
 }
 
@@ -134,6 +159,70 @@ function getRemoteInfos(options: ModuleFederationPluginOptions): RemoteInfos {
 	return remoteInfos;
 }
 
+function getManifestOptions(
+	options: ModuleFederationPluginOptions
+): RawModuleFederationManifestPluginOptions {
+	const manifest = typeof options.manifest === "object" ? options.manifest : {};
+	const library = options.library || { type: "var", name: options.name };
+	const globalName =
+		typeof library.name === "string" ? library.name : options.name;
+	const hasExposes =
+		options.exposes &&
+		(Array.isArray(options.exposes)
+			? options.exposes.length > 0
+			: Object.keys(options.exposes).length > 0);
+	const remotes = Object.values(getRemoteInfos(options)).flatMap(infos =>
+		infos.map(({ alias, name, entry }) => ({ alias, name, entry }))
+	);
+	return {
+		name: options.name,
+		globalName,
+		fileName: manifest.fileName ?? "mf-manifest.json",
+		remoteEntry: hasExposes
+			? {
+					name:
+						typeof options.filename === "string"
+							? options.filename
+							: `${options.name}.js`,
+					path: "",
+					type: library.type
+				}
+			: undefined,
+		remotes
+	};
+}
+
+function getTypesOptions(options: ModuleFederationPluginOptions) {
+	const dts = typeof options.dts === "object" ? options.dts : {};
+	const normalize = <T extends object>(value: boolean | T | undefined) =>
+		value === false ? undefined : typeof value === "object" ? value : {};
+	const exposes: [string, string][] = [];
+	if (options.exposes) {
+		for (const [key, config] of parseOptions(
+			options.exposes,
+			item => ({ import: Array.isArray(item) ? item : [item] }),
+			item => ({
+				import: Array.isArray(item.import) ? item.import : [item.import]
+			})
+		)) {
+			exposes.push([key, config.import[0]]);
+		}
+	}
+	const remotes = Object.values(getRemoteInfos(options))
+		.flat()
+		.flatMap(({ alias, entry, externalType }) =>
+			externalType === "script" && entry && /^https?:\/\//.test(entry)
+				? [{ alias, entry }]
+				: []
+		);
+	return {
+		exposes,
+		remotes,
+		generateTypes: normalize(dts.generateTypes),
+		consumeTypes: normalize(dts.consumeTypes)
+	};
+}
+
 function getRuntimePlugins(options: ModuleFederationPluginOptions) {
 	return options.runtimePlugins ?? [];
 }
@@ -167,10 +256,22 @@ function getDefaultEntryRuntime(
 	const runtimePluginVars = [];
 	for (let i = 0; i < runtimePlugins.length; i++) {
 		const runtimePluginVar = `__module_federation_runtime_plugin_${i}__`;
+		const runtimePlugin = runtimePlugins[i];
+		const [runtimePluginPath, runtimePluginOptions] = Array.isArray(
+			runtimePlugin
+		)
+			? runtimePlugin
+			: [runtimePlugin, undefined];
 		runtimePluginImports.push(
-			`import ${runtimePluginVar} from ${JSON.stringify(runtimePlugins[i])}`
+			`import ${runtimePluginVar} from ${JSON.stringify(runtimePluginPath)}`
+		);
+		runtimePluginVars.push(
+			`${runtimePluginVar}(${
+				runtimePluginOptions === undefined
+					? ""
+					: JSON.stringify(runtimePluginOptions)
+			})`
 		);
-		runtimePluginVars.push(`${runtimePluginVar}()`);
 	}
 	const content = [
 		`import __module_federation_bundler_runtime__ from ${JSON.stringify(
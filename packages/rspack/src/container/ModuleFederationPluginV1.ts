@@ -5,10 +5,14 @@ import {
 	ExternalsType,
 	externalsType
 } from "../config";
-import { SharePlugin, Shared } from "../sharing/SharePlugin";
+import { SharePlugin, Shared, SharedConfig } from "../sharing/SharePlugin";
 import { isValidate } from "../util/validate";
 import { ContainerPlugin, Exposes } from "./ContainerPlugin";
-import { ContainerReferencePlugin, Remotes } from "./ContainerReferencePlugin";
+import {
+	ContainerReferencePlugin,
+	Remotes,
+	RemotesConfig
+} from "./ContainerReferencePlugin";
 import { ShareRuntimePlugin } from "../sharing/ShareRuntimePlugin";
 
 export interface ModuleFederationPluginV1Options {
@@ -21,6 +25,7 @@ export interface ModuleFederationPluginV1Options {
 	runtime?: EntryRuntime;
 	shareScope?: string;
 	shared?: Shared;
+	shareScopeSalt?: string;
 	enhanced?: boolean;
 }
 
@@ -28,7 +33,7 @@ export class ModuleFederationPluginV1 {
 	constructor(private _options: ModuleFederationPluginV1Options) {}
 
 	apply(compiler: Compiler) {
-		const { _options: options } = this;
+		const options = applyShareScopeSalt(this._options);
 		const enhanced = options.enhanced ?? false;
 
 		const library = options.library || { type: "var", name: options.name };
@@ -44,7 +49,7 @@ export class ModuleFederationPluginV1 {
 			compiler.options.output.enabledLibraryTypes!.push(library.type);
 		}
 		compiler.hooks.afterPlugins.tap("ModuleFederationPlugin", () => {
-			new ShareRuntimePlugin(this._options.enhanced).apply(compiler);
+			new ShareRuntimePlugin(options.enhanced).apply(compiler);
 			if (
 				options.exposes &&
 				(Array.isArray(options.exposes)
@@ -84,3 +89,46 @@ export class ModuleFederationPluginV1 {
 		});
 	}
 }
+
+type ConfigItems<C> = (string | Record<string, string | string[] | C>)[];
+
+/**
+ * Appends `shareScopeSalt` to every share scope of the build, so builds with
+ * different salts never share modules while builds with the same salt do.
+ */
+export function applyShareScopeSalt<T extends ModuleFederationPluginV1Options>(
+	options: T
+): T {
+	const salt = options.shareScopeSalt;
+	if (!salt) return options;
+	const shareScope = `${options.shareScope || "default"}@${salt}`;
+	const saltConfigs = <C extends { shareScope?: string }>(
+		value: ConfigItems<C> | Record<string, string | string[] | C> | undefined
+	) => {
+		if (!value) return value;
+		const items = Array.isArray(value) ? value : [value];
+		return items.map(item => {
+			if (typeof item === "string") return item;
+			const result: Record<string, string | string[] | C> = {};
+			for (const [key, config] of Object.entries(item)) {
+				result[key] =
+					typeof config === "string" || Array.isArray(config)
+						? config
+						: {
+								...config,
+								shareScope: config.shareScope
+									? `${config.shareScope}@${salt}`
+									: shareScope
+							};
+			}
+			return result;
+		});
+	};
+	return {
+		...options,
+		shareScope,
+		shareScopeSalt: undefined,
+		remotes: saltConfigs(options.remotes as ConfigItems<RemotesConfig>),
+		shared: saltConfigs(options.shared as ConfigItems<SharedConfig>)
+	};
+}
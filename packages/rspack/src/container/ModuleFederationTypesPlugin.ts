@@ -0,0 +1,242 @@
+import fs from "fs";
+import path from "path";
+import zlib from "zlib";
+import tar from "tar-stream";
+import { Compiler } from "../Compiler";
+import { Compilation } from "../Compilation";
+
+export const TYPES_ARCHIVE_NAME = "@mf-types.tgz";
+
+export interface DtsGenerateOptions {
+	tsConfigPath?: string;
+}
+
+export interface DtsConsumeOptions {
+	typesFolder?: string;
+}
+
+export interface DtsOptions {
+	generateTypes?: boolean | DtsGenerateOptions;
+	consumeTypes?: boolean | DtsConsumeOptions;
+}
+
+export interface ModuleFederationTypesPluginOptions {
+	exposes: [string, string][];
+	remotes: { alias: string; entry: string }[];
+	generateTypes?: DtsGenerateOptions;
+	consumeTypes?: DtsConsumeOptions;
+}
+
+const PLUGIN_NAME = "ModuleFederationTypesPlugin";
+
+/**
+ * Emits the declaration files of the exposed modules as a tarball, and
+ * downloads the tarballs of the remotes into the types folder.
+ */
+export class ModuleFederationTypesPlugin {
+	constructor(private _options: ModuleFederationTypesPluginOptions) {}
+
+	apply(compiler: Compiler) {
+		const { generateTypes, consumeTypes } = this._options;
+		if (generateTypes && this._options.exposes.length > 0) {
+			compiler.hooks.thisCompilation.tap(PLUGIN_NAME, compilation => {
+				compilation.hooks.processAssets.tapPromise(
+					{
+						name: PLUGIN_NAME,
+						stage: Compilation.PROCESS_ASSETS_STAGE_ADDITIONAL
+					},
+					async () => {
+						try {
+							const files = generateDeclarations(
+								compiler.context,
+								this._options.exposes,
+								generateTypes
+							);
+							compilation.emitAsset(
+								TYPES_ARCHIVE_NAME,
+								new compiler.webpack.sources.RawSource(
+									zlib.gzipSync(await pack(files))
+								)
+							);
+						} catch (e: any) {
+							compilation.warnings.push(
+								new Error(`Failed to generate federation types: ${e.message}`)
+							);
+						}
+					}
+				);
+			});
+		}
+		if (consumeTypes && this._options.remotes.length > 0) {
+			let consumed = false;
+			compiler.hooks.beforeCompile.tapPromise(PLUGIN_NAME, async () => {
+				if (consumed) return;
+				consumed = true;
+				const logger = compiler.getInfrastructureLogger(PLUGIN_NAME);
+				const typesFolder = path.resolve(
+					compiler.context,
+					consumeTypes.typesFolder ?? "@mf-types"
+				);
+				await Promise.all(
+					this._options.remotes.map(async ({ alias, entry }) => {
+						const url = new URL(TYPES_ARCHIVE_NAME, entry).href;
+						try {
+							const files = await unpack(zlib.gunzipSync(await download(url)));
+							const outputDir = path.join(typesFolder, alias);
+							for (const [name, content] of files) {
+								const filename = path.join(outputDir, name);
+								if (!filename.startsWith(outputDir + path.sep)) continue;
+								fs.mkdirSync(path.dirname(filename), { recursive: true });
+								fs.writeFileSync(filename, content);
+							}
+						} catch (e: any) {
+							logger.warn(
+								`Failed to consume types of remote "${alias}" from ${url}: ${e.message}`
+							);
+						}
+					})
+				);
+			});
+		}
+	}
+}
+
+function generateDeclarations(
+	context: string,
+	exposes: [string, string][],
+	options: DtsGenerateOptions
+): Map<string, string> {
+	const ts = require(require.resolve("typescript", { paths: [context] }));
+	const configPath = options.tsConfigPath
+		? path.resolve(context, options.tsConfigPath)
+		: ts.findConfigFile(context, ts.sys.fileExists);
+	let compilerOptions = {};
+	if (configPath) {
+		const { config } = ts.readConfigFile(configPath, ts.sys.readFile);
+		compilerOptions = ts.parseJsonConfigFileContent(
+			config,
+			ts.sys,
+			path.dirname(configPath)
+		).options;
+	}
+	const outDir = path.join(context, "@mf-types");
+	const entries = exposes.map(
+		([name, request]) =>
+			[exposeName(name), resolveExpose(context, request)] as const
+	);
+	const program = ts.createProgram(
+		entries.map(([, file]) => file),
+		{
+			...compilerOptions,
+			declaration: true,
+			emitDeclarationOnly: true,
+			declarationMap: false,
+			noEmit: false,
+			rootDir: context,
+			outDir
+		}
+	);
+
+	const files = new Map<string, string>();
+	program.emit(undefined, (filename: string, content: string) => {
+		files.set(toPosix(path.relative(outDir, filename)), content);
+	});
+	// every exposed module gets an entry declaration named by its expose key,
+	// re-exporting what the module actually exports
+	const checker = program.getTypeChecker();
+	for (const [name, file] of entries) {
+		const declaration = toPosix(path.relative(context, file)).replace(
+			/(\.d)?\.[cm]?[jt]sx?$/,
+			""
+		);
+		if (!files.has(`${declaration}.d.ts`)) continue;
+		const request = JSON.stringify(
+			path.posix.relative(path.posix.dirname(name), declaration) || "."
+		).replace(/^"(?!\.)/, '"./');
+		const sourceFile = program.getSourceFile(file);
+		const symbol = sourceFile && checker.getSymbolAtLocation(sourceFile);
+		const exportNames: string[] = symbol
+			? checker
+					.getExportsOfModule(symbol)
+					.map((item: { escapedName: string }) => item.escapedName)
+			: [];
+		let content = "";
+		if (exportNames.some(item => item !== "default")) {
+			content += `export * from ${request};\n`;
+		}
+		if (exportNames.includes("default")) {
+			content += `export { default } from ${request};\n`;
+		}
+		files.set(`${name}.d.ts`, content || "export {};\n");
+	}
+	return files;
+}
+
+// "./Button" is emitted as "Button.d.ts", the root expose "." as "index.d.ts"
+function exposeName(name: string) {
+	return name === "." ? "index" : name.replace(/^\.\//, "");
+}
+
+const EXTENSIONS = [".ts", ".tsx", ".d.ts", ".js", ".jsx", ".mjs", ".cjs"];
+
+function resolveExpose(context: string, request: string) {
+	const file = path.resolve(context, request);
+	const candidates = [
+		file,
+		...EXTENSIONS.map(ext => file + ext),
+		...EXTENSIONS.map(ext => path.join(file, `index${ext}`))
+	];
+	const resolved = candidates.find(
+		candidate => fs.existsSync(candidate) && fs.statSync(candidate).isFile()
+	);
+	if (!resolved) {
+		throw new Error(`Can't resolve exposed module "${request}"`);
+	}
+	return resolved;
+}
+
+function toPosix(p: string) {
+	return p.split(path.sep).join("/");
+}
+
+function download(url: string): Promise<Buffer> {
+	return new Promise((resolve, reject) => {
+		const client = url.startsWith("https:") ? require("https") : require("http");
+		client
+			.get(url, (res: any) => {
+				if (res.statusCode !== 200) {
+					res.resume();
+					return reject(new Error(`Request failed with ${res.statusCode}`));
+				}
+				const chunks: Buffer[] = [];
+				res.on("data", (chunk: Buffer) => chunks.push(chunk));
+				res.on("end", () => resolve(Buffer.concat(chunks)));
+			})
+			.on("error", reject);
+	});
+}
+
+async function pack(files: Map<string, string>): Promise<Buffer> {
+	const archive = tar.pack();
+	for (const [name, content] of files) {
+		archive.entry({ name }, content);
+	}
+	archive.finalize();
+	const chunks: Buffer[] = [];
+	for await (const chunk of archive) chunks.push(chunk);
+	return Buffer.concat(chunks);
+}
+
+async function unpack(buffer: Buffer): Promise<Map<string, Buffer>> {
+	const files = new Map<string, Buffer>();
+	const extract = tar.extract();
+	extract.end(buffer);
+	for await (const entry of extract) {
+		const chunks: Buffer[] = [];
+		for await (const chunk of entry) chunks.push(chunk);
+		if (entry.header.type === "file") {
+			files.set(entry.header.name, Buffer.concat(chunks));
+		}
+	}
+	return files;
+}
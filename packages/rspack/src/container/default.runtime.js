@@ -91,7 +91,15 @@ module.exports = function () {
 				for (let stage of stages) {
 					if (typeof stage === "object" && stage !== null) {
 						const { name, version, factory, eager } = stage;
-						const options = { version, scope: [scope], get: factory };
+						// eager shared modules are bundled in the initial chunk, so the
+						// runtime is able to consume them synchronously
+						const shareConfig = { eager: !!eager };
+						const options = {
+							version,
+							scope: [scope],
+							shareConfig,
+							get: factory
+						};
 						if (shared[name]) {
 							shared[name].push(options);
 						} else {
@@ -172,10 +180,11 @@ module.exports = function () {
 			__webpack_require__.federation.attachShareScopeMap(__webpack_require__);
 		}
 
-		override(__webpack_require__.f, "remotes", (chunkId, promises) =>
+		override(__webpack_require__.f, "remotes", (chunkId, promises) => {
+			const remotesPromises = [];
 			__webpack_require__.federation.bundlerRuntime.remotes({
 				chunkId,
-				promises,
+				promises: remotesPromises,
 				chunkMapping: remotesLoadingChunkMapping,
 				idToExternalAndNameMapping:
 					__webpack_require__.federation.bundlerRuntimeOptions.remotes
@@ -184,8 +193,41 @@ module.exports = function () {
 					__webpack_require__.federation.bundlerRuntimeOptions.remotes
 						.idToRemoteMap,
 				webpackRequire: __webpack_require__
-			})
-		);
+			});
+			const ids = remotesLoadingChunkMapping[chunkId] ?? [];
+			const hasFallback = id =>
+				remotesLoadingModuleIdToRemoteDataMapping[id].fallback !== undefined;
+			if (!ids.some(hasFallback)) {
+				promises.push(...remotesPromises);
+				return;
+			}
+			// the fallback is bundled with the remote module, use it when the
+			// remote module fails to load
+			const useFallbacks = () => {
+				for (const id of ids.filter(hasFallback)) {
+					const factory = __webpack_require__.m[id];
+					if (factory?.fallback !== undefined) continue;
+					const fallback =
+						remotesLoadingModuleIdToRemoteDataMapping[id].fallback;
+					const factoryWithFallback = module => {
+						if (factory) {
+							try {
+								return factory(module);
+							} catch (_) {}
+						}
+						module.exports = __webpack_require__(fallback);
+					};
+					factoryWithFallback.fallback = fallback;
+					__webpack_require__.m[id] = factoryWithFallback;
+				}
+			};
+			promises.push(
+				Promise.all(remotesPromises).then(useFallbacks, error => {
+					useFallbacks();
+					if (ids.some(id => !__webpack_require__.m[id])) throw error;
+				})
+			);
+		});
 		override(__webpack_require__.f, "consumes", (chunkId, promises) =>
 			__webpack_require__.federation.bundlerRuntime.consumes({
 				chunkId,
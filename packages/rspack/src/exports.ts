@@ -9,7 +9,8 @@ export type {
 	AssetInfo,
 	Assets,
 	LogEntry,
-	CompilationParams
+	CompilationParams,
+	ImportModuleOptions
 } from "./Compilation";
 
 export { MultiCompiler } from "./MultiCompiler";
@@ -37,7 +38,7 @@ export type { ChunkGroup } from "./ChunkGroup";
 
 export type { NormalModuleFactory } from "./NormalModuleFactory";
 
-export type { Module } from "./Module";
+export type { Module, BuildInfo, BuildMeta } from "./Module";
 
 export { NormalModule } from "./NormalModule";
 
@@ -52,6 +53,16 @@ export { Template };
 export const WebpackError = Error;
 
 export type { Watching } from "./Watching";
+export type {
+	InputFileSystem,
+	IntermediateFileSystem,
+	OutputFileSystem,
+	WatchFileSystem
+} from "./util/fs";
+export type {
+	MemoryOutputFileSystem,
+	MemoryOutputFileStats
+} from "./MemoryOutputFileSystem";
 
 const sources = require("webpack-sources"); // use require to avoid wrong types, @types/webpack-sources is outdate
 export { sources };
@@ -83,7 +94,9 @@ export const util = { createHash, cleverMerge };
 
 export {
 	registerGlobalTrace as experimental_registerGlobalTrace,
-	cleanupGlobalTrace as experimental_cleanupGlobalTrace
+	cleanupGlobalTrace as experimental_cleanupGlobalTrace,
+	enableHookTiming as experimental_enableHookTiming,
+	takeHookTimings as experimental_takeHookTimings
 } from "@rspack/binding";
 
 export { default as EntryOptionPlugin } from "./lib/EntryOptionPlugin";
@@ -98,10 +111,18 @@ export { ProvidePlugin } from "./builtin-plugin";
 export type { ProvidePluginOptions } from "./builtin-plugin";
 
 export { DefinePlugin } from "./builtin-plugin";
-export type { DefinePluginOptions } from "./builtin-plugin";
+export type {
+	DefinePluginOptions,
+	RuntimeValueContext,
+	RuntimeValueOptions
+} from "./builtin-plugin";
 
 export { ProgressPlugin } from "./builtin-plugin";
-export type { ProgressPluginArgument } from "./builtin-plugin";
+export type {
+	ProgressPluginArgument,
+	ProgressPluginHandler,
+	ProgressPluginOptions
+} from "./builtin-plugin";
 
 export { EntryPlugin } from "./builtin-plugin";
 export type { EntryOptions } from "./builtin-plugin";
@@ -117,9 +138,34 @@ export { LoaderOptionsPlugin } from "./lib/LoaderOptionsPlugin";
 export { LoaderTargetPlugin } from "./lib/LoaderTargetPlugin";
 
 export { EnvironmentPlugin } from "./lib/EnvironmentPlugin";
+export type { EnvironmentPluginOptions } from "./lib/EnvironmentPlugin";
 
 export { NormalModuleReplacementPlugin } from "./lib/NormalModuleReplacementPlugin";
 
+export { NoEmitOnErrorsPlugin } from "./lib/NoEmitOnErrorsPlugin";
+
+export { ContextReplacementPlugin } from "./lib/ContextReplacementPlugin";
+
+export { DllPlugin, type DllPluginOptions } from "./lib/DllPlugin";
+
+export {
+	DllReferencePlugin,
+	type DllReferencePluginOptions,
+	type DllReferencePluginOptionsSourceType,
+	type DllReferencePluginOptionsContent,
+	type DllReferencePluginOptionsManifest
+} from "./lib/DllReferencePlugin";
+
+export { VirtualModulesPlugin } from "./lib/VirtualModulesPlugin";
+
+export { LiveReloadPlugin } from "./lib/LiveReloadPlugin";
+export type {
+	LiveReloadMessage,
+	LiveReloadPluginOptions,
+	LiveReloadServerOptions,
+	LiveReloadTransport
+} from "./lib/LiveReloadPlugin";
+
 import NodeTemplatePlugin from "./node/NodeTemplatePlugin";
 import { NodeTargetPlugin } from "./builtin-plugin";
 import NodeEnvironmentPlugin from "./node/NodeEnvironmentPlugin";
@@ -245,6 +291,19 @@ export { SwcJsMinimizerRspackPlugin } from "./builtin-plugin";
 export type { SwcJsMinimizerRspackPluginOptions } from "./builtin-plugin";
 
 export { SwcCssMinimizerRspackPlugin } from "./builtin-plugin";
+export type { SwcCssMinimizerRspackPluginOptions } from "./builtin-plugin";
+
+export { ImageMinimizerRspackPlugin } from "./builtin-plugin";
+export type { ImageMinimizerRspackPluginOptions } from "./builtin-plugin";
+
+export { BundleAnalysisRspackPlugin } from "./builtin-plugin";
+export type { BundleAnalysisRspackPluginOptions } from "./builtin-plugin";
+
+export { DuplicatePackageCheckerRspackPlugin } from "./builtin-plugin";
+export type { DuplicatePackageCheckerRspackPluginOptions } from "./builtin-plugin";
+
+export { NodePolyfillRspackPlugin } from "./builtin-plugin";
+export type { NodePolyfillRspackPluginOptions } from "./builtin-plugin";
 
 export { CopyRspackPlugin } from "./builtin-plugin";
 export type { CopyRspackPluginOptions } from "./builtin-plugin";
@@ -258,6 +317,19 @@ export type { EvalDevToolModulePluginOptions } from "./builtin-plugin";
 
 export { CssExtractRspackPlugin } from "./builtin-plugin";
 
+export { CustomModuleTypeRspackPlugin } from "./builtin-plugin";
+export type {
+	CustomModuleTypeRspackPluginOptions,
+	CustomModuleTypeContext,
+	CustomModuleTypeResult
+} from "./builtin-plugin";
+
+export { CustomRuntimeModuleRspackPlugin } from "./builtin-plugin";
+export type {
+	CustomRuntimeModuleRspackPluginOptions,
+	CustomRuntimeModuleContext
+} from "./builtin-plugin";
+
 ///// Rspack Postfixed Internal Loaders /////
 export type {
 	SwcLoaderOptions,
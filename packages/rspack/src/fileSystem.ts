@@ -1,6 +1,7 @@
 import util from "util";
 import { join } from "path";
 import { memoizeFn } from "./util/memoize";
+import type { OutputFileSystem } from "./util/fs";
 
 export interface ThreadsafeWritableNodeFS {
 	writeFile: (...args: any[]) => any;
@@ -11,7 +12,7 @@ export interface ThreadsafeWritableNodeFS {
 }
 
 function createThreadsafeNodeFSFromRaw(
-	fs: typeof import("fs")
+	fs: OutputFileSystem
 ): ThreadsafeWritableNodeFS {
 	let writeFile = memoizeFn(() => util.promisify(fs.writeFile.bind(fs)));
 	let removeFile = memoizeFn(() => util.promisify(fs.unlink.bind(fs)));
@@ -32,7 +33,7 @@ function createThreadsafeNodeFSFromRaw(
 	};
 }
 
-const rmrfBuild = (fs: typeof import("fs")) => {
+const rmrfBuild = (fs: OutputFileSystem) => {
 	async function exists(path: string) {
 		try {
 			await util.promisify(fs.access.bind(fs))(path);
@@ -0,0 +1,124 @@
+/**
+ * Based on [webpack/lib/ContextReplacementPlugin.js]{@link https://github.com/webpack/webpack/blob/29cc4ead7eb6aafc3a5f6d0b10ce41d33d1ad874/lib/ContextReplacementPlugin.js}
+ * Licensed with [MIT License]{@link http://www.opensource.org/licenses/mit-license.php}
+ * Original Author Tobias Koppers @sokra
+ */
+
+import { Compiler } from "../Compiler";
+import {
+	ContextModuleFactoryAfterResolveResult,
+	ContextModuleFactoryBeforeResolveResult
+} from "../Module";
+import * as NodePath from "node:path";
+
+type ContextReplacer = (
+	data: Exclude<
+		| ContextModuleFactoryBeforeResolveResult
+		| ContextModuleFactoryAfterResolveResult,
+		false
+	>
+) => void;
+
+const isAbsolute = (path: string) =>
+	NodePath.posix.isAbsolute(path) || NodePath.win32.isAbsolute(path);
+
+export class ContextReplacementPlugin {
+	newContentCallback?: ContextReplacer;
+	newContentResource?: string;
+	newContentRecursive?: boolean;
+	newContentRegExp?: RegExp;
+
+	/**
+	 * @param resourceRegExp A regular expression that determines which files will be selected
+	 * @param newContentResource A new resource to replace the match, or a callback to modify the data
+	 * @param newContentRecursive If true, all subdirectories are searched for matches
+	 * @param newContentRegExp A regular expression that determines which files will be selected
+	 */
+	constructor(
+		resourceRegExp: RegExp,
+		newContentResource?: string | boolean | RegExp | ContextReplacer,
+		newContentRecursive?: boolean | RegExp,
+		newContentRegExp?: RegExp
+	);
+	constructor(
+		public readonly resourceRegExp: RegExp,
+		newContentResource?: any,
+		newContentRecursive?: any,
+		newContentRegExp?: any
+	) {
+		if (typeof newContentResource === "function") {
+			this.newContentCallback = newContentResource;
+		} else {
+			if (typeof newContentResource !== "string") {
+				newContentRegExp = newContentRecursive;
+				newContentRecursive = newContentResource;
+				newContentResource = undefined;
+			}
+			if (typeof newContentRecursive !== "boolean") {
+				newContentRegExp = newContentRecursive;
+				newContentRecursive = undefined;
+			}
+			this.newContentResource = newContentResource;
+			this.newContentRecursive = newContentRecursive;
+			this.newContentRegExp = newContentRegExp;
+		}
+	}
+
+	apply(compiler: Compiler) {
+		const {
+			resourceRegExp,
+			newContentCallback,
+			newContentResource,
+			newContentRecursive,
+			newContentRegExp
+		} = this;
+
+		compiler.hooks.contextModuleFactory.tap("ContextReplacementPlugin", cmf => {
+			cmf.hooks.beforeResolve.tap("ContextReplacementPlugin", result => {
+				if (!result) return;
+				if (resourceRegExp.test(result.request || "")) {
+					if (newContentResource !== undefined) {
+						result.request = newContentResource;
+					}
+					if (newContentRecursive !== undefined) {
+						result.recursive = newContentRecursive;
+					}
+					if (newContentRegExp !== undefined) {
+						result.regExp = newContentRegExp;
+					}
+					if (typeof newContentCallback === "function") {
+						newContentCallback(result);
+					}
+				}
+				return result;
+			});
+			cmf.hooks.afterResolve.tap("ContextReplacementPlugin", result => {
+				if (!result) return;
+				if (resourceRegExp.test(result.resource)) {
+					if (newContentResource !== undefined) {
+						result.resource = isAbsolute(newContentResource)
+							? newContentResource
+							: NodePath.join(result.resource, newContentResource);
+					}
+					if (newContentRecursive !== undefined) {
+						result.recursive = newContentRecursive;
+					}
+					if (newContentRegExp !== undefined) {
+						result.regExp = newContentRegExp;
+					}
+					if (typeof newContentCallback === "function") {
+						const origResource = result.resource;
+						newContentCallback(result);
+						if (
+							result.resource !== origResource &&
+							!isAbsolute(result.resource)
+						) {
+							result.resource = NodePath.join(origResource, result.resource);
+						}
+					}
+				}
+				return result;
+			});
+		});
+	}
+}
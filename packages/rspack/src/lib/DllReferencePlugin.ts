@@ -0,0 +1,241 @@
+/**
+ * The following code is modified based on
+ * https://github.com/webpack/webpack/blob/4b4ca3b/lib/DllReferencePlugin.js
+ *
+ * MIT Licensed
+ * Author Tobias Koppers @sokra
+ * Copyright (c) JS Foundation and other contributors
+ * https://github.com/webpack/webpack/blob/main/LICENSE
+ */
+
+import { z } from "zod";
+import { CompilationParams } from "../Compilation";
+import { Compiler } from "../Compiler";
+import { DllReferenceAgencyPlugin, ExternalsPlugin } from "../builtin-plugin";
+import { ExternalsType, externalsType } from "../config/zod";
+import { validate } from "../util/validate";
+
+export type DllReferencePluginOptionsContent = {
+	/**
+	 * Module info.
+	 */
+	[k: string]: {
+		/**
+		 * Meta information about the module.
+		 */
+		buildMeta?: {
+			[k: string]: any;
+		};
+		/**
+		 * Information about the provided exports of the module.
+		 */
+		exports?: string[] | true;
+		/**
+		 * Module ID.
+		 */
+		id: number | string;
+	};
+};
+
+export type DllReferencePluginOptionsManifest = {
+	/**
+	 * The mappings from request to module info.
+	 */
+	content: DllReferencePluginOptionsContent;
+	/**
+	 * The name where the dll is exposed (external name).
+	 */
+	name?: string;
+	/**
+	 * The type how the dll is exposed (external type).
+	 */
+	type?: DllReferencePluginOptionsSourceType;
+};
+
+/**
+ * The type how the dll is exposed (external type).
+ */
+export type DllReferencePluginOptionsSourceType = ExternalsType;
+
+export type DllReferencePluginOptions =
+	| {
+			/**
+			 * Context of requests in the manifest (or content property) as absolute path.
+			 */
+			context?: string;
+			/**
+			 * Extensions used to resolve modules in the dll bundle (only used when using 'scope').
+			 */
+			extensions?: string[];
+			/**
+			 * An object containing content and name or a string to the absolute path of the JSON manifest to be loaded upon compilation.
+			 */
+			manifest: string | DllReferencePluginOptionsManifest;
+			/**
+			 * The name where the dll is exposed (external name, defaults to manifest.name).
+			 */
+			name?: string;
+			/**
+			 * Prefix which is used for accessing the content of the dll.
+			 */
+			scope?: string;
+			/**
+			 * How the dll is exposed (libraryTarget, defaults to manifest.type).
+			 */
+			sourceType?: DllReferencePluginOptionsSourceType;
+			/**
+			 * The way how the export of the dll bundle is used.
+			 */
+			type?: "require" | "object";
+	  }
+	| {
+			/**
+			 * The mappings from request to module info.
+			 */
+			content: DllReferencePluginOptionsContent;
+			/**
+			 * Context of requests in the manifest (or content property) as absolute path.
+			 */
+			context?: string;
+			/**
+			 * Extensions used to resolve modules in the dll bundle (only used when using 'scope').
+			 */
+			extensions?: string[];
+			/**
+			 * The name where the dll is exposed (external name).
+			 */
+			name: string;
+			/**
+			 * Prefix which is used for accessing the content of the dll.
+			 */
+			scope?: string;
+			/**
+			 * How the dll is exposed (libraryTarget).
+			 */
+			sourceType?: DllReferencePluginOptionsSourceType;
+			/**
+			 * The way how the export of the dll bundle is used.
+			 */
+			type?: "require" | "object";
+	  };
+
+const dllReferencePluginOptionsContent = z.record(
+	z.object({
+		buildMeta: z.record(z.any()).optional(),
+		exports: z.array(z.string()).or(z.literal(true)).optional(),
+		id: z.union([z.number(), z.string()])
+	})
+);
+
+const dllReferencePluginOptionsManifest = z.object({
+	content: dllReferencePluginOptionsContent,
+	name: z.string().optional(),
+	type: externalsType.optional()
+});
+
+const DllReferencePluginOptions = z.union([
+	z.object({
+		context: z.string().optional(),
+		extensions: z.array(z.string()).optional(),
+		manifest: z.string().or(dllReferencePluginOptionsManifest),
+		name: z.string().optional(),
+		scope: z.string().optional(),
+		sourceType: externalsType.optional(),
+		type: z.enum(["require", "object"]).optional()
+	}),
+	z.object({
+		content: dllReferencePluginOptionsContent,
+		context: z.string().optional(),
+		extensions: z.array(z.string()).optional(),
+		name: z.string(),
+		scope: z.string().optional(),
+		sourceType: externalsType.optional(),
+		type: z.enum(["require", "object"]).optional()
+	})
+]);
+
+class DllManifestError extends Error {
+	constructor(filename: string, message: string) {
+		super(`Dll manifest ${filename}\n${message}`);
+		this.name = "DllManifestError";
+	}
+}
+
+export class DllReferencePlugin {
+	private options: DllReferencePluginOptions;
+
+	constructor(options: DllReferencePluginOptions) {
+		validate(options, DllReferencePluginOptions);
+		this.options = options;
+	}
+
+	apply(compiler: Compiler) {
+		let registered = false;
+		const register = (manifest?: DllReferencePluginOptionsManifest) => {
+			if (registered) return;
+			registered = true;
+			const name = this.options.name ?? manifest?.name;
+			const sourceType = this.options.sourceType ?? manifest?.type;
+			const content =
+				"content" in this.options ? this.options.content : manifest?.content;
+
+			const source = `dll-reference ${name}`;
+			new ExternalsPlugin(sourceType || "var", { [source]: name! }).apply(
+				compiler
+			);
+			new DllReferenceAgencyPlugin({
+				context: this.options.context,
+				name: name!,
+				extensions: this.options.extensions ?? ["", ".js", ".json", ".wasm"],
+				scope: this.options.scope,
+				type: this.options.type || "require",
+				content: content!
+			}).apply(compiler);
+		};
+
+		if (!("manifest" in this.options)) {
+			register();
+			return;
+		}
+
+		const manifestParameter = this.options.manifest;
+		if (typeof manifestParameter !== "string") {
+			register(manifestParameter);
+			return;
+		}
+
+		const errors = new WeakMap<CompilationParams, DllManifestError>();
+		compiler.hooks.beforeCompile.tapAsync(
+			"DllReferencePlugin",
+			(params, callback) => {
+				compiler.inputFileSystem!.readFile(
+					manifestParameter,
+					(err: Error | null, result?: Buffer) => {
+						if (err) return callback(err);
+						try {
+							// builtin plugins are registered before the first compilation
+							// creates the native compiler, later reads only report errors
+							register(JSON.parse(result!.toString("utf-8")));
+						} catch (e: any) {
+							errors.set(
+								params,
+								new DllManifestError(manifestParameter, e.message)
+							);
+						}
+						callback();
+					}
+				);
+			}
+		);
+		compiler.hooks.compilation.tap(
+			"DllReferencePlugin",
+			(compilation, params) => {
+				compilation.fileDependencies.add(manifestParameter);
+				const error = errors.get(params);
+				if (error) {
+					compilation.errors.push(error);
+				}
+			}
+		);
+	}
+}
@@ -0,0 +1,62 @@
+/**
+ * Based on [webpack/lib/EnvironmentPlugin.js]{@link https://github.com/webpack/webpack/blob/29cc4ead7eb6aafc3a5f6d0b10ce41d33d1ad874/lib/EnvironmentPlugin.js}
+ * Licensed with [MIT License]{@link http://www.opensource.org/licenses/mit-license.php}
+ * Authors Simen Brekken @simenbrekken, Einar Löve @einarlove
+ */
+
+import { Compiler } from "../Compiler";
+import { DefinePlugin, DefinePluginOptions } from "../builtin-plugin";
+
+/**
+ * The keys of `process.env`, or an object mapping the keys to their default values
+ */
+export type EnvironmentPluginOptions = string[] | Record<string, unknown>;
+
+class EnvVariableNotDefinedError extends Error {
+	constructor(key: string) {
+		super(
+			`EnvironmentPlugin - ${key} environment variable is undefined.\n\n` +
+				"You can pass an object with default values to suppress this warning.\n" +
+				"See https://webpack.js.org/plugins/environment-plugin for example."
+		);
+		this.name = "EnvVariableNotDefinedError";
+	}
+}
+
+export class EnvironmentPlugin {
+	keys: string[];
+	defaultValues: Record<string, unknown>;
+
+	constructor(...keys: (string | EnvironmentPluginOptions)[]) {
+		if (keys.length === 1 && Array.isArray(keys[0])) {
+			this.keys = keys[0];
+			this.defaultValues = {};
+		} else if (keys.length === 1 && keys[0] && typeof keys[0] === "object") {
+			this.keys = Object.keys(keys[0]);
+			this.defaultValues = keys[0] as Record<string, unknown>;
+		} else {
+			this.keys = keys as string[];
+			this.defaultValues = {};
+		}
+	}
+
+	apply(compiler: Compiler) {
+		const definitions: DefinePluginOptions = {};
+		for (const key of this.keys) {
+			const value =
+				process.env[key] !== undefined
+					? process.env[key]
+					: this.defaultValues[key];
+
+			if (value === undefined) {
+				compiler.hooks.thisCompilation.tap("EnvironmentPlugin", compilation => {
+					compilation.errors.push(new EnvVariableNotDefinedError(key));
+				});
+			}
+
+			definitions[`process.env.${key}`] =
+				value === undefined ? "undefined" : JSON.stringify(value);
+		}
+		new DefinePlugin(definitions).apply(compiler);
+	}
+}
@@ -0,0 +1,162 @@
+import type {
+	IncomingMessage,
+	ServerOptions as HttpServerOptions,
+	ServerResponse
+} from "http";
+import type { ServerOptions as HttpsServerOptions } from "https";
+import type { AddressInfo, ListenOptions, Server } from "net";
+import type { Compiler } from "../Compiler";
+import { EntryPlugin } from "../builtin-plugin";
+
+export type LiveReloadMessage =
+	/** The compilation is done, the clients check for the hot updates */
+	| { type: "hash"; hash: string }
+	/** The clients can't be updated, e.g. the HTML changed */
+	| { type: "reload" };
+
+export interface LiveReloadTransport {
+	/** The request of the client module, which is added to all the entries */
+	client: string;
+	/** Sends the message to all the connected clients */
+	send(message: LiveReloadMessage): void;
+	close?(callback: (err?: Error | null) => void): void;
+}
+
+export interface LiveReloadServerOptions {
+	listen?: number | ListenOptions;
+	protocol?: "http" | "https";
+	server?: HttpServerOptions | HttpsServerOptions | (() => Server);
+}
+
+export interface LiveReloadPluginOptions {
+	/**
+	 * How the messages are sent to the clients, a Server-Sent Events server is
+	 * started with the options by default.
+	 */
+	transport?: LiveReloadServerOptions | LiveReloadTransport;
+}
+
+const PLUGIN_NAME = "LiveReloadPlugin";
+
+const isTransport = (
+	transport: LiveReloadServerOptions | LiveReloadTransport
+): transport is LiveReloadTransport => "send" in transport;
+
+const createSseTransport = (
+	compiler: Compiler,
+	options: LiveReloadServerOptions,
+	callback: (err: Error | null, transport?: LiveReloadTransport) => void
+) => {
+	const logger = compiler.getInfrastructureLogger(PLUGIN_NAME);
+	const isHttps =
+		options.protocol === "https" ||
+		(typeof options.server === "object" &&
+			("key" in options.server || "pfx" in options.server));
+	const protocol = options.protocol || (isHttps ? "https" : "http");
+	const server: Server =
+		typeof options.server === "function"
+			? options.server()
+			: (isHttps ? require("https") : require("http")).createServer(
+					options.server
+				);
+
+	const responses = new Set<ServerResponse>();
+	server.on("request", (req: IncomingMessage, res: ServerResponse) => {
+		res.writeHead(200, {
+			"content-type": "text/event-stream",
+			"Access-Control-Allow-Origin": "*"
+		});
+		res.write("\n");
+		responses.add(res);
+		req.socket.on("close", () => responses.delete(res));
+	});
+	server.on("listening", () => {
+		const addr = server.address() as AddressInfo;
+		const url =
+			addr.address === "::" || addr.address === "0.0.0.0"
+				? `${protocol}://localhost:${addr.port}`
+				: addr.family === "IPv6"
+					? `${protocol}://[${addr.address}]:${addr.port}`
+					: `${protocol}://${addr.address}:${addr.port}`;
+		logger.log(`Server-Sent-Events server for live reload open at ${url}.`);
+		const client = require.resolve("../../hot/live-reload-sse.js");
+		callback(null, {
+			client: `${client}?${encodeURIComponent(url)}`,
+			send(message) {
+				const data = `data: ${JSON.stringify(message)}\n\n`;
+				for (const res of responses) res.write(data);
+			},
+			close(callback) {
+				for (const res of responses) res.end();
+				responses.clear();
+				server.close(err => callback(err));
+			}
+		});
+	});
+	server.on("error", err => callback(err));
+	let listen = options.listen;
+	if (typeof listen === "object" && !("port" in listen))
+		listen = { ...listen, port: undefined };
+	server.listen(listen);
+};
+
+/**
+ * Reloads the pages when they can't be updated in place, the pages are
+ * updated with hot module replacement if it's enabled, or reloaded on every
+ * change otherwise. It's only applied in watch mode.
+ */
+export class LiveReloadPlugin {
+	constructor(private options: LiveReloadPluginOptions = {}) {}
+
+	apply(compiler: Compiler) {
+		let transport: LiveReloadTransport | undefined;
+		let htmlAssets: Map<string, string> | undefined;
+
+		// the client is added once the transport is ready, which is before the
+		// first compilation starts
+		compiler.hooks.beforeCompile.tapAsync(PLUGIN_NAME, (_params, callback) => {
+			if (transport !== undefined || !compiler.watchMode) return callback();
+			const onTransport = (err: Error | null, result?: LiveReloadTransport) => {
+				if (err) return callback(err);
+				transport = result!;
+				new EntryPlugin(compiler.context, transport.client, {
+					name: undefined
+				}).apply(compiler);
+				callback();
+			};
+			const options = this.options.transport ?? {};
+			if (isTransport(options)) {
+				onTransport(null, options);
+			} else {
+				createSseTransport(compiler, options, onTransport);
+			}
+		});
+
+		compiler.hooks.done.tap(PLUGIN_NAME, stats => {
+			if (transport === undefined || stats.hasErrors()) return;
+			const compilation = stats.compilation;
+			const assets = new Map<string, string>();
+			for (const asset of compilation.getAssets()) {
+				if (asset.name.endsWith(".html")) {
+					assets.set(asset.name, asset.source.source().toString());
+				}
+			}
+			const previous = htmlAssets;
+			htmlAssets = assets;
+			if (previous === undefined) return;
+			const htmlChanged =
+				previous.size !== assets.size ||
+				Array.from(assets).some(([name, content]) => {
+					return previous.get(name) !== content;
+				});
+			transport.send(
+				htmlChanged ? { type: "reload" } : { type: "hash", hash: stats.hash! }
+			);
+		});
+
+		compiler.hooks.shutdown.tapAsync(PLUGIN_NAME, callback => {
+			if (transport?.close === undefined) return callback();
+			transport.close(callback);
+		});
+	}
+}
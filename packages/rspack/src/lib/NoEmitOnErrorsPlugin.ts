@@ -0,0 +1,15 @@
+/**
+ * Based on [webpack/lib/NoEmitOnErrorsPlugin.js]{@link https://github.com/webpack/webpack/blob/29cc4ead7eb6aafc3a5f6d0b10ce41d33d1ad874/lib/NoEmitOnErrorsPlugin.js}
+ * Licensed with [MIT License]{@link http://www.opensource.org/licenses/mit-license.php}
+ * Original Author Tobias Koppers @sokra
+ */
+
+import { Compiler } from "../Compiler";
+
+export class NoEmitOnErrorsPlugin {
+	apply(compiler: Compiler) {
+		compiler.hooks.shouldEmit.tap("NoEmitOnErrorsPlugin", compilation => {
+			if (compilation.getStats().hasErrors()) return false;
+		});
+	}
+}
@@ -0,0 +1,41 @@
+import * as NodePath from "node:path";
+import { Compiler } from "../Compiler";
+
+/**
+ * Modules that only exist in memory, they are resolved and read like files on
+ * the disk, and shadow the files with the same path.
+ */
+export class VirtualModulesPlugin {
+	#compiler?: Compiler;
+
+	/**
+	 * @param modules the initial modules, keyed by paths relative to `context`
+	 */
+	constructor(private _modules: Record<string, string> = {}) {}
+
+	apply(compiler: Compiler) {
+		this.#compiler = compiler;
+		for (const [path, content] of Object.entries(this._modules)) {
+			compiler.__internal__writeVirtualModule(
+				NodePath.resolve(compiler.context, path),
+				content
+			);
+		}
+	}
+
+	/**
+	 * Writes a module, the watching compiler is invalidated with the module
+	 * marked as modified, so only the modules depending on it are rebuilt.
+	 */
+	writeModule(path: string, content: string | Buffer) {
+		const compiler = this.#compiler;
+		if (!compiler) {
+			throw new Error(
+				"VirtualModulesPlugin should be applied before writing modules"
+			);
+		}
+		const filename = NodePath.resolve(compiler.context, path);
+		compiler.__internal__writeVirtualModule(filename, content);
+		compiler.watching?.invalidate([filename]);
+	}
+}
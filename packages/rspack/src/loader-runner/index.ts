@@ -19,6 +19,12 @@ import {
 import { Compiler } from "../Compiler";
 import { NormalModule } from "../NormalModule";
 import { Compilation } from "../Compilation";
+import {
+	toBuildInfo,
+	toBuildMeta,
+	toJsBuildInfo,
+	toJsBuildMeta
+} from "../Module";
 import {
 	LoaderContext,
 	LoaderObject,
@@ -591,6 +597,10 @@ export async function runLoaders(
 	};
 	loaderContext._compiler = compiler;
 	loaderContext._compilation = compiler.compilation;
+	loaderContext._module = {
+		buildInfo: toBuildInfo(rawContext.buildInfo),
+		buildMeta: toBuildMeta(rawContext.buildMeta)
+	};
 	loaderContext.getOptions = function () {
 		const loader = getCurrentLoader(loaderContext);
 		let options = loader?.options;
@@ -647,7 +657,9 @@ export async function runLoaders(
 					assetFilenames,
 					isPitching: loaderContext.__internal__context.isPitching,
 					additionalDataExternal:
-						loaderContext.__internal__context.additionalDataExternal
+						loaderContext.__internal__context.additionalDataExternal,
+					buildInfo: toJsBuildInfo(loaderContext._module.buildInfo),
+					buildMeta: toJsBuildMeta(loaderContext._module.buildMeta)
 				});
 			});
 		} else {
@@ -681,7 +693,9 @@ export async function runLoaders(
 						assetFilenames,
 						isPitching: loaderContext.__internal__context.isPitching,
 						additionalDataExternal:
-							loaderContext.__internal__context.additionalDataExternal
+							loaderContext.__internal__context.additionalDataExternal,
+						buildInfo: toJsBuildInfo(loaderContext._module.buildInfo),
+						buildMeta: toJsBuildMeta(loaderContext._module.buildMeta)
 					});
 				}
 			);
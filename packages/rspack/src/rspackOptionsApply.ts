@@ -14,12 +14,14 @@ import {
 	RspackPluginFunction
 } from ".";
 import fs from "graceful-fs";
+import path from "path";
 
 import { DefaultStatsFactoryPlugin } from "./stats/DefaultStatsFactoryPlugin";
 import { DefaultStatsPrinterPlugin } from "./stats/DefaultStatsPrinterPlugin";
 import { cleverMerge } from "./util/cleverMerge";
 import assert from "assert";
 import IgnoreWarningsPlugin from "./lib/ignoreWarningsPlugin";
+import { NoEmitOnErrorsPlugin } from "./lib/NoEmitOnErrorsPlugin";
 import EntryOptionPlugin from "./lib/EntryOptionPlugin";
 import {
 	ArrayPushCallbackChunkFormatPlugin,
@@ -30,6 +32,7 @@ import {
 	EnableWasmLoadingPlugin,
 	ExternalsPlugin,
 	HttpExternalsRspackPlugin,
+	NodeModulesExternalsRspackPlugin,
 	ModuleChunkFormatPlugin,
 	NodeTargetPlugin,
 	DefinePlugin,
@@ -40,6 +43,7 @@ import {
 	DeterministicModuleIdsPlugin,
 	NamedChunkIdsPlugin,
 	DeterministicChunkIdsPlugin,
+	RecordIdsPlugin,
 	RealContentHashPlugin,
 	RemoveEmptyChunksPlugin,
 	EnsureChunkConditionsPlugin,
@@ -49,6 +53,7 @@ import {
 	JavascriptModulesPlugin,
 	JsonModulesPlugin,
 	AsyncWebAssemblyModulesPlugin,
+	WebAssemblyModulesPlugin,
 	RuntimePlugin,
 	InferAsyncModulesPlugin,
 	WorkerPlugin,
@@ -66,7 +71,8 @@ import {
 	JsLoaderRspackPlugin,
 	CssModulesPlugin,
 	APIPlugin,
-	RuntimeChunkPlugin
+	RuntimeChunkPlugin,
+	LazyCompilationPlugin
 } from "./builtin-plugin";
 import { assertNotNill } from "./util/assertNotNil";
 
@@ -111,6 +117,17 @@ export class RspackOptionsApply {
 		) {
 			new ElectronTargetPlugin().apply(compiler);
 		}
+		if (options.externalsPresets.nwjs) {
+			new ExternalsPlugin("node-commonjs", "nw.gui").apply(compiler);
+		}
+		if (options.externalsPresets.nodeModules) {
+			const nodeModules = options.externalsPresets.nodeModules;
+			new NodeModulesExternalsRspackPlugin(
+				(nodeModules !== true && nodeModules.allowlist) || [],
+				(nodeModules !== true && nodeModules.importType) ||
+					(options.output.module ? "module" : "commonjs")
+			).apply(compiler);
+		}
 		if (
 			options.externalsPresets.web ||
 			options.externalsPresets.webAsync ||
@@ -205,9 +222,30 @@ export class RspackOptionsApply {
 		if (options.experiments.asyncWebAssembly) {
 			new AsyncWebAssemblyModulesPlugin().apply(compiler);
 		}
+		if (options.experiments.syncWebAssembly) {
+			new WebAssemblyModulesPlugin().apply(compiler);
+		}
 		if (options.experiments.css) {
 			new CssModulesPlugin().apply(compiler);
 		}
+		if (options.experiments.lazyCompilation) {
+			const lazyOptions = options.experiments.lazyCompilation;
+			new LazyCompilationPlugin({
+				backend: {
+					...lazyOptions.backend,
+					client:
+						lazyOptions.backend?.client ||
+						require.resolve(
+							`../hot/lazy-compilation-${
+								options.externalsPresets.node ? "node" : "web"
+							}.js`
+						)
+				},
+				entries: lazyOptions.entries !== false,
+				imports: lazyOptions.imports !== false,
+				test: lazyOptions.test
+			}).apply(compiler);
+		}
 
 		new EntryOptionPlugin().apply(compiler);
 		assertNotNill(options.context);
@@ -247,7 +285,11 @@ export class RspackOptionsApply {
 				).apply(compiler);
 			}
 			if (options.optimization.concatenateModules) {
-				new ModuleConcatenationPlugin().apply(compiler);
+				new ModuleConcatenationPlugin(
+					typeof options.optimization.concatenateModules === "object"
+						? options.optimization.concatenateModules
+						: {}
+				).apply(compiler);
 			}
 			if (options.optimization.mangleExports) {
 				new MangleExportsPlugin(
@@ -274,6 +316,17 @@ export class RspackOptionsApply {
 		if (options.optimization.realContentHash) {
 			new RealContentHashPlugin().apply(compiler);
 		}
+		// the recorded ids are revived before the ids are assigned by the other plugins
+		if (options.recordsInputPath || options.recordsOutputPath) {
+			new RecordIdsPlugin({
+				inputPath: options.recordsInputPath
+					? path.resolve(compiler.context, options.recordsInputPath)
+					: undefined,
+				outputPath: options.recordsOutputPath
+					? path.resolve(compiler.context, options.recordsOutputPath)
+					: undefined
+			}).apply(compiler);
+		}
 		const moduleIds = options.optimization.moduleIds;
 		if (moduleIds) {
 			switch (moduleIds) {
@@ -340,6 +393,11 @@ export class RspackOptionsApply {
 			new IgnoreWarningsPlugin(options.ignoreWarnings).apply(compiler);
 		}
 
+		// the assets of a failed compilation are never emitted in bail mode
+		if (options.optimization.emitOnErrors === false || options.bail) {
+			new NoEmitOnErrorsPlugin().apply(compiler);
+		}
+
 		compiler.hooks.afterPlugins.call(compiler);
 		if (!compiler.inputFileSystem) {
 			throw new Error("No input filesystem provided");
@@ -358,7 +358,6 @@ const SORTERS: Record<
 	// "chunk.modules": MODULES_SORTER,
 	// "module.modules": MODULES_SORTER
 	// not support module.reasons (missing Module.identifier())
-	// not support chunk.origins (missing compilation.chunkGraph)
 };
 
 const SIMPLE_EXTRACTORS: SimpleExtractors = {
@@ -597,6 +596,7 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				.getChunks(
 					options.chunkModules!,
 					options.chunkRelations!,
+					options.chunkOrigins!,
 					options.reasons!,
 					options.moduleAssets!,
 					options.nestedModules!,
@@ -753,6 +753,9 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			object.type = module.type;
 			object.moduleType = module.moduleType;
 			object.size = module.size;
+			object.sizes = Object.fromEntries(
+				module.sizes.map(({ sourceType, size }) => [sourceType, size])
+			);
 			Object.assign(object, factory.create(`${type}$visible`, module, context));
 		}
 	},
@@ -783,13 +786,29 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 		moduleAssets: (object, module) => {
 			object.assets = module.assets;
 		},
+		depth: (object, module) => {
+			object.depth = module.depth ?? null;
+		},
+		nestedModules: (object, module, context, options, factory) => {
+			const { type } = context;
+			if (module.modules) {
+				object.modules = factory.create(
+					`${type.slice(0, -8)}.modules`,
+					module.modules,
+					context
+				);
+			}
+		},
 		reasons: (object, module, context, options, factory) => {
 			const { type } = context;
-			object.reasons = factory.create(
+			const groupsReasons = factory.create(
 				`${type.slice(0, -8)}.reasons`,
 				module.reasons,
 				context
 			);
+			const limited = spaceLimited(groupsReasons, options.reasonsSpace!);
+			object.reasons = limited.children;
+			object.filteredReasons = limited.filteredChildren;
 		},
 		source: (object, module) => {
 			object.source = module.source;
@@ -858,6 +877,9 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			object.initial = chunk.initial;
 			object.entry = chunk.entry;
 			object.size = chunk.size;
+			object.sizes = Object.fromEntries(
+				chunk.sizes.map(({ sourceType, size }) => [sourceType, size])
+			);
 			object.names = chunk.names;
 			object.files = chunk.files;
 			object.auxiliaryFiles = chunk.auxiliaryFiles;
@@ -871,6 +893,14 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			object.parents = chunk.parents;
 			object.children = chunk.children;
 		},
+		chunkOrigins: (object, chunk, context, options, factory) => {
+			const { type } = context;
+			object.origins = factory.create(
+				`${type}.origins`,
+				chunk.origins,
+				context
+			);
+		},
 		chunkModules: (object, chunk, context, options, factory) => {
 			const { type } = context;
 			object.modules = factory.create(
@@ -879,6 +909,18 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				context
 			);
 		}
+	},
+	chunkOrigin: {
+		_: (object, origin) => {
+			object.module = origin.module;
+			object.moduleIdentifier = origin.moduleIdentifier;
+			object.moduleName = origin.moduleName;
+			object.loc = origin.loc;
+			object.request = origin.request;
+		},
+		ids: (object, origin) => {
+			object.moduleId = origin.moduleId;
+		}
 	}
 };
 
@@ -11,7 +11,9 @@ import type { StatsFactory, StatsFactoryContext } from "./StatsFactory";
 
 export type KnownStatsChunkGroup = binding.JsStatsChunkGroup;
 
-export type KnownStatsChunk = binding.JsStatsChunk;
+export type KnownStatsChunk = Omit<binding.JsStatsChunk, "sizes"> & {
+	sizes: Record<string, number>;
+};
 
 export type StatsChunkGroup = binding.JsStatsChunkGroup & Record<string, any>;
 
@@ -23,9 +25,18 @@ export type StatsChunk = KnownStatsChunk & Record<string, any>;
 
 export type KnownStatsModule = Omit<
 	binding.JsStatsModule,
-	"usedExports" | "providedExports" | "optimizationBailout"
+	| "usedExports"
+	| "providedExports"
+	| "optimizationBailout"
+	| "modules"
+	| "depth"
+	| "sizes"
 > & {
+	sizes: Record<string, number>;
 	profile?: StatsProfile;
+	depth?: null | number;
+	modules?: StatsModule[];
+	filteredReasons?: number;
 	usedExports?: null | string[] | boolean;
 	providedExports?: null | string[];
 	optimizationBailout?: null | string[];
@@ -154,7 +165,10 @@ export type SimpleExtractors = {
 		StatsModuleReason
 	>;
 	chunk: ExtractorsByOption<StatsChunk, KnownStatsChunk>;
-	// chunkOrigin: ExtractorsByOption<OriginRecord, StatsChunkOrigin>;
+	chunkOrigin: ExtractorsByOption<
+		binding.JsOriginRecord,
+		KnownStatsChunkOrigin
+	>;
 	// error: ExtractorsByOption<binding.JsStatsError, StatsError>;
 	// warning: ExtractorsByOption<binding.JsStatsWarning, StatsError>;
 };
@@ -504,12 +518,12 @@ export const assetGroup = (children: StatsAsset[]) => {
 
 export const moduleGroup = (children: KnownStatsModule[]) => {
 	let size = 0;
-	const sizes = {};
+	const sizes: Record<string, number> = {};
 	for (const module of children) {
 		size += module.size;
-		// for (const key of Object.keys(module.sizes)) {
-		// 	sizes[key] = (sizes[key] || 0) + module.sizes[key];
-		// }
+		for (const key of Object.keys(module.sizes)) {
+			sizes[key] = (sizes[key] || 0) + module.sizes[key];
+		}
 	}
 	return {
 		size,
@@ -39,3 +39,24 @@ export interface WatchFileSystem {
 		callbackUndelayed: (fileName: string, changeTime: number) => void
 	): Watcher;
 }
+
+export interface InputFileSystem {
+	readFile: (...args: any[]) => any;
+	readdir: (...args: any[]) => any;
+	stat: (...args: any[]) => any;
+	lstat?: (...args: any[]) => any;
+	readlink?: (...args: any[]) => any;
+	realpath?: (...args: any[]) => any;
+	purge?: (files?: string | string[] | Set<string>) => void;
+}
+
+export type OutputFileSystem = Pick<
+	typeof import("fs"),
+	"writeFile" | "mkdir" | "unlink" | "rmdir" | "readdir" | "lstat" | "access"
+>;
+
+export type IntermediateFileSystem = OutputFileSystem &
+	Pick<
+		typeof import("fs"),
+		"readFile" | "stat" | "rename" | "createReadStream" | "createWriteStream"
+	>;
@@ -99,8 +99,20 @@ export function asArray<T>(item: T | T[]): T[] {
 	return Array.isArray(item) ? item : [item];
 }
 
+const JS_ASSET_INFO_KEYS = new Set([
+	"immutable",
+	"minimized",
+	"development",
+	"hotModuleReplacement",
+	"related",
+	"chunkHash",
+	"contentHash",
+	"sourceFilename",
+	"javascriptModule"
+]);
+
 export function toJsAssetInfo(info?: AssetInfo): JsAssetInfo {
-	return {
+	const jsAssetInfo: JsAssetInfo = {
 		immutable: false,
 		minimized: false,
 		development: false,
@@ -108,8 +120,23 @@ export function toJsAssetInfo(info?: AssetInfo): JsAssetInfo {
 		related: {},
 		chunkHash: [],
 		contentHash: [],
-		...info
+		extras: {}
 	};
+	// custom fields are not known by rust, pass them through `extras`
+	for (const [key, value] of Object.entries(info ?? {})) {
+		if (key === "extras") {
+			Object.assign(jsAssetInfo.extras, value);
+		} else if (JS_ASSET_INFO_KEYS.has(key)) {
+			(jsAssetInfo as any)[key] = value;
+		} else if (value !== undefined) {
+			jsAssetInfo.extras[key] = value;
+		}
+	}
+	return jsAssetInfo;
+}
+
+export function fromJsAssetInfo(info: JsAssetInfo): JsAssetInfo & AssetInfo {
+	return { ...info.extras, ...info };
 }
 const getDeprecationStatus = () => {
 	const defaultEnableDeprecatedWarning = true;
@@ -0,0 +1,12 @@
+import * as fs from "fs";
+import * as path from "path";
+
+// Cloning a large value to a worker costs as much as stringifying it, so the
+// value is stringified here and only the writing happens in the thread pool.
+export const writeJsonFile = async (
+	filename: string,
+	value: unknown
+): Promise<void> => {
+	await fs.promises.mkdir(path.dirname(filename), { recursive: true });
+	await fs.promises.writeFile(filename, JSON.stringify(value));
+};
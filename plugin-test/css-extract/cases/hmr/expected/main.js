@@ -781,6 +781,9 @@ var createStylesheet = function (
 	var linkTag = document.createElement("link");
 	
 	linkTag.rel = "stylesheet";
+	if (__webpack_require__.nc) {
+		linkTag.nonce = __webpack_require__.nc;
+	}
 	linkTag.type="text/css";
 	var onLinkComplete = function (event) {
 		// avoid mem leaks.
@@ -55,19 +55,23 @@ const pluginSupportStatusList: PluginSupportStatus[] = [
   },
   {
     name: 'ContextReplacementPlugin',
-    status: SupportStatus.NotSupported,
+    status: SupportStatus.PartiallySupported,
+    notes: {
+      en: 'The `newContentCreateContextMap` argument is not supported',
+      zh: '不支持 `newContentCreateContextMap` 参数',
+    },
   },
   {
     name: 'DefinePlugin',
     status: SupportStatus.PartiallySupported,
     notes: {
-      en: '`rspack.DefinePlugin.runtimeValue` function not supported',
-      zh: '不支持 `rspack.DefinePlugin.runtimeValue` 函数',
+      en: 'The `version` option of `rspack.DefinePlugin.runtimeValue` is not supported',
+      zh: '不支持 `rspack.DefinePlugin.runtimeValue` 的 `version` 选项',
     },
   },
   {
     name: 'DllPlugin',
-    status: SupportStatus.NotSupported,
+    status: SupportStatus.FullySupported,
   },
   {
     name: 'EnvironmentPlugin',
@@ -295,7 +299,11 @@ const pluginSupportStatusList: PluginSupportStatus[] = [
   },
   {
     name: 'ContextReplacementPlugin',
-    status: SupportStatus.NotSupported,
+    status: SupportStatus.PartiallySupported,
+    notes: {
+      en: 'The `newContentCreateContextMap` argument is not supported',
+      zh: '不支持 `newContentCreateContextMap` 参数',
+    },
   },
   {
     name: 'DelegatedPlugin',
@@ -319,7 +327,7 @@ const pluginSupportStatusList: PluginSupportStatus[] = [
   },
   {
     name: 'DllReferencePlugin',
-    status: SupportStatus.NotSupported,
+    status: SupportStatus.FullySupported,
   },
   {
     name: 'DynamicEntryPlugin',
@@ -375,7 +383,7 @@ const pluginSupportStatusList: PluginSupportStatus[] = [
   },
   {
     name: 'LibManifestPlugin',
-    status: SupportStatus.NotSupported,
+    status: SupportStatus.FullySupported,
   },
   {
     name: 'LoaderOptionsPlugin',